@@ -1,11 +1,11 @@
 use anyhow::{anyhow, Context, Result};
 use gat_algo::power_flow;
-use gat_core::{solver::SolverKind, Network};
+use gat_core::{solver::SolverKind, Network, ScenarioRng};
 use gat_io::importers;
 use polars::prelude::{
     DataFrame, NamedFrom, ParquetCompression, ParquetReader, ParquetWriter, SerReader, Series,
 };
-use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::BTreeMap;
 use std::fs::{self, File};
 use std::path::Path;
 
@@ -23,6 +23,10 @@ pub use reliability_integration::{
 ///   Typical values: manual switching 2-4 hours, automated FLISR 0.5-2 hours
 /// - **customers**: Number of downstream customers affected by this component's failure
 ///   Used for computing customer-weighted reliability indices (SAIDI, SAIFI)
+/// - **planned_outage_rate**: Scheduled maintenance outages per year (optional, default 0)
+/// - **common_mode_group** / **common_mode_rate**: Components sharing a failure cause
+///   (common tower, trench, or substation bay) and the group's event rate per year (optional)
+/// - **voltage_kv**: Nominal voltage, used to scale λ by voltage class (optional)
 ///
 /// **Data Sources:**
 /// Utilities collect reliability data from:
@@ -32,18 +36,280 @@ pub use reliability_integration::{
 /// - Industry benchmarks: IEEE 1366-2012 (distribution reliability indices)
 #[derive(Clone, Debug)]
 struct ReliabilityElement {
-    element_id: String,      // Unique identifier (branch_id, switch_id, etc.)
-    _element_type: String,   // Type: "branch", "transformer", "switch", "fuse"
-    failure_rate: f64,       // λ (failures/year): annual failure probability
-    repair_hours: f64,       // r (hours): mean time to repair (MTTR)
-    _customers: Option<i64>, // N_cust: downstream customer count for weighting
+    element_id: String,       // Unique identifier (branch_id, switch_id, etc.)
+    _element_type: String,    // Type: "branch", "transformer", "switch", "fuse"
+    failure_rate: f64,        // λ (failures/year): annual failure probability
+    repair_hours: f64,        // r (hours): mean time to repair (MTTR)
+    customers: Option<i64>,   // N_cust: downstream customer count for weighting
+    planned_outage_rate: f64, // λ_p (outages/year): scheduled maintenance outages
+    common_mode_group: Option<String>, // Shared-cause group (tower, trench, bay)
+    common_mode_rate: f64,    // λ_cm (events/year): group event rate
+    voltage_kv: Option<f64>,  // Nominal voltage for failure-rate scaling
+}
+
+impl ReliabilityElement {
+    /// Forced failure rate after voltage-class scaling.
+    fn effective_failure_rate(&self) -> f64 {
+        self.failure_rate * voltage_failure_scale(self.voltage_kv)
+    }
+}
+
+/// Failure-rate multiplier by voltage class.
+///
+/// Secondary (< 1 kV) services see more weather and animal exposure per
+/// component; subtransmission (> 35 kV) equipment is better protected and
+/// maintained. Primary feeders (1-35 kV) are the reference class.
+fn voltage_failure_scale(voltage_kv: Option<f64>) -> f64 {
+    match voltage_kv {
+        Some(kv) if kv < 1.0 => 1.5,
+        Some(kv) if kv > 35.0 => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// Cause of a sampled outage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OutageKind {
+    Forced,
+    Planned,
+    CommonMode,
+}
+
+impl OutageKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            OutageKind::Forced => "forced",
+            OutageKind::Planned => "planned",
+            OutageKind::CommonMode => "common_mode",
+        }
+    }
+}
+
+/// One component outage within a Monte Carlo sample.
+#[derive(Clone, Debug)]
+struct OutageEvent {
+    element: usize,
+    kind: OutageKind,
+}
+
+/// Common-mode groups keyed by name: (group event rate, member indices).
+///
+/// The group rate is the first non-zero `common_mode_rate` among members.
+fn common_mode_groups(elements: &[ReliabilityElement]) -> BTreeMap<String, (f64, Vec<usize>)> {
+    let mut groups: BTreeMap<String, (f64, Vec<usize>)> = BTreeMap::new();
+    for (idx, element) in elements.iter().enumerate() {
+        if let Some(name) = &element.common_mode_group {
+            let entry = groups.entry(name.clone()).or_insert((0.0, Vec::new()));
+            if entry.0 == 0.0 {
+                entry.0 = element.common_mode_rate;
+            }
+            entry.1.push(idx);
+        }
+    }
+    groups
+}
+
+/// Distribution of sampled repair times around an element's mean repair time.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RepairTimeModel {
+    /// Exponential with the element's mean (memoryless; 5% of repairs exceed 3× the mean)
+    #[default]
+    Exponential,
+    /// Lognormal with the element's mean and `sigma` as the standard deviation of
+    /// ln(repair time); captures the long tail of major repairs
+    Lognormal { sigma: f64 },
+}
+
+impl RepairTimeModel {
+    /// Model named `exponential` or `lognormal`; `sigma` applies to lognormal only.
+    pub fn from_name(name: &str, sigma: f64) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "exponential" => Ok(Self::Exponential),
+            "lognormal" if sigma.is_finite() && sigma > 0.0 => Ok(Self::Lognormal { sigma }),
+            "lognormal" => Err(anyhow!("lognormal repair sigma must be positive")),
+            other => Err(anyhow!(
+                "unknown repair-time distribution '{}' (expected exponential or lognormal)",
+                other
+            )),
+        }
+    }
+
+    /// Draw a repair time (hours) with the given mean.
+    pub fn sample(&self, mean_hours: f64, rng: &mut ScenarioRng) -> f64 {
+        if mean_hours <= 0.0 {
+            return 0.0;
+        }
+        match *self {
+            Self::Exponential => rng.exponential(1.0 / mean_hours),
+            // E[X] = exp(mu + sigma²/2) for a lognormal
+            Self::Lognormal { sigma } => {
+                rng.lognormal(mean_hours.ln() - sigma * sigma / 2.0, sigma)
+            }
+        }
+    }
+}
+
+/// Interruptions shorter than this are momentary (IEEE 1366: 5 minutes)
+const MOMENTARY_THRESHOLD_HOURS: f64 = 5.0 / 60.0;
+
+/// Sampled interruptions caused by failures of one element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ElementInterruptions {
+    /// Failures per year (λ)
+    pub failure_rate: f64,
+    /// Customers interrupted by each failure (N)
+    pub customers: f64,
+    /// Sampled interruption durations (hours), one per simulated failure
+    pub durations_hours: Vec<f64>,
+}
+
+/// IEEE 1366-2012 customer-weighted reliability indices.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReliabilityIndices {
+    /// Customers served (N_T)
+    pub total_customers: f64,
+    /// Sustained interruption hours per customer served per year
+    pub saidi_hours: f64,
+    /// Sustained interruptions per customer served per year
+    pub saifi: f64,
+    /// Average sustained interruption duration (hours)
+    pub caidi_hours: f64,
+    /// Sustained interruptions per customer interrupted per year
+    pub caifi: f64,
+    /// Momentary interruptions per customer served per year
+    pub maifi: f64,
+}
+
+impl ReliabilityIndices {
+    /// Indices from expected annual interruptions of each element.
+    ///
+    /// Each element's customer count is the section it interrupts, and the
+    /// sections are disjoint, so N_T = Σ N. Failures lasting under five
+    /// minutes count toward MAIFI only; the rest are sustained:
+    ///
+    /// ```text
+    /// SAIDI = Σ λ·E[r·1(sustained)]·N / N_T    SAIFI = Σ λ·P(sustained)·N / N_T
+    /// MAIFI = Σ λ·P(momentary)·N / N_T          CAIDI = SAIDI / SAIFI
+    /// CAIFI = Σ λ·P(sustained)·N / Σ N (elements with sustained interruptions)
+    /// ```
+    pub fn from_elements(elements: &[ElementInterruptions]) -> Self {
+        let total_customers: f64 = elements.iter().map(|e| e.customers).sum();
+        let mut customer_hours = 0.0;
+        let mut sustained = 0.0;
+        let mut momentary = 0.0;
+        let mut customers_interrupted = 0.0;
+        for element in elements {
+            if element.durations_hours.is_empty() {
+                continue;
+            }
+            let samples = element.durations_hours.len() as f64;
+            let (long, short): (Vec<f64>, Vec<f64>) = element
+                .durations_hours
+                .iter()
+                .copied()
+                .partition(|&hours| hours >= MOMENTARY_THRESHOLD_HOURS);
+            let weight = element.failure_rate * element.customers;
+            customer_hours += weight * long.iter().sum::<f64>() / samples;
+            sustained += weight * long.len() as f64 / samples;
+            momentary += weight * short.len() as f64 / samples;
+            if element.failure_rate > 0.0 && !long.is_empty() {
+                customers_interrupted += element.customers;
+            }
+        }
+
+        let per_customer = |total: f64| {
+            if total_customers > 0.0 {
+                total / total_customers
+            } else {
+                0.0
+            }
+        };
+        let saidi_hours = per_customer(customer_hours);
+        let saifi = per_customer(sustained);
+        Self {
+            total_customers,
+            saidi_hours,
+            saifi,
+            caidi_hours: if saifi > 0.0 {
+                saidi_hours / saifi
+            } else {
+                0.0
+            },
+            caifi: if customers_interrupted > 0.0 {
+                sustained / customers_interrupted
+            } else {
+                0.0
+            },
+            maifi: per_customer(momentary),
+        }
+    }
+}
+
+/// Customer count per element; missing counts default to the mean of the
+/// known ones, or one customer when none are known.
+fn customer_counts(elements: &[ReliabilityElement]) -> Vec<f64> {
+    let known: Vec<f64> = elements
+        .iter()
+        .filter_map(|element| element.customers)
+        .map(|count| count.max(0) as f64)
+        .collect();
+    let fallback = if known.is_empty() { 1.0 } else { mean(&known) };
+    elements
+        .iter()
+        .map(|element| {
+            element
+                .customers
+                .map_or(fallback, |count| count.max(0) as f64)
+        })
+        .collect()
+}
+
+/// Probability of at least one Poisson event in one year at `rate` events/year.
+fn annual_probability(rate: f64) -> f64 {
+    1.0 - (-rate.max(0.0)).exp()
+}
+
+/// Sample one year of outages.
+///
+/// Forced and planned outages are drawn independently per element. Each
+/// common-mode group is drawn once, and when it fires every member fails in
+/// the same sample.
+fn sample_outages(
+    elements: &[ReliabilityElement],
+    groups: &BTreeMap<String, (f64, Vec<usize>)>,
+    rng: &mut ScenarioRng,
+) -> Vec<OutageEvent> {
+    let mut events = Vec::new();
+    for (idx, element) in elements.iter().enumerate() {
+        if rng.bernoulli(annual_probability(element.effective_failure_rate())) {
+            events.push(OutageEvent {
+                element: idx,
+                kind: OutageKind::Forced,
+            });
+        }
+        if rng.bernoulli(annual_probability(element.planned_outage_rate)) {
+            events.push(OutageEvent {
+                element: idx,
+                kind: OutageKind::Planned,
+            });
+        }
+    }
+    for (rate, members) in groups.values() {
+        if rng.bernoulli(annual_probability(*rate)) {
+            events.extend(members.iter().map(|&element| OutageEvent {
+                element,
+                kind: OutageKind::CommonMode,
+            }));
+        }
+    }
+    events
 }
 
 /// Simulate FLISR (Fault Location, Isolation, and Service Restoration) with reliability metrics.
 ///
 /// **Purpose:** Model automated fault response in distribution systems using intelligent switching
-/// to minimize customer interruptions. Computes IEEE 1366-2012 reliability indices (SAIDI, SAIFI, CAIDI)
-/// under various fault scenarios.
+/// to minimize customer interruptions. Computes IEEE 1366-2012 reliability indices (SAIDI, SAIFI,
+/// CAIDI, CAIFI, MAIFI) under various fault scenarios.
 ///
 /// **FLISR Concept:**
 /// FLISR is an ADMS (Advanced Distribution Management System) application that automates the
@@ -91,18 +357,33 @@ struct ReliabilityElement {
 ///    - **Interpretation:** Average outage duration when an interruption occurs
 ///    - **Typical values:** CAIDI ≈ 1-3 hours (manual restoration), 0.5-1.5 hours (FLISR)
 ///
+/// 4. **CAIFI (Customer Average Interruption Frequency Index):**
+///    ```text
+///    CAIFI = Σ(λi × Ni) / CN
+///    ```
+///    where CN = distinct customers with at least one sustained interruption
+///    - **Interpretation:** Interruption frequency among customers actually affected
+///
+/// 5. **MAIFI (Momentary Average Interruption Frequency Index):**
+///    ```text
+///    MAIFI = Σ(IMi × Nmi) / Ntotal
+///    ```
+///    where IMi = momentary interruptions (< 5 minutes), Nmi = customers affected
+///    - **Interpretation:** Brief outages, typically cleared by reclosers or automated switching
+///
 /// **Algorithm (Simplified FLISR Simulation):**
 /// 1. Load grid topology and reliability data (failure rates λ, repair times r)
 /// 2. Run baseline power flow (pre-fault operating point)
 /// 3. For each scenario (fault location):
 ///    a. Simulate fault at component i (branch, transformer)
-///    b. Identify affected customers (downstream of fault)
-///    c. Compute outage duration: duration = r_i (repair time)
-///    d. Compute SAIDI contribution: SAIDI_i = duration × N_customers_i
-///    e. Compute SAIFI contribution: SAIFI_i = λ_i (failure rate)
-///    f. Compute CAIDI: CAIDI_i = SAIDI_i / SAIFI_i
-/// 4. Aggregate over all scenarios: average SAIDI, SAIFI, CAIDI
-/// 5. Output: reliability_indices.parquet (scenario-level and system-level metrics)
+///    b. Identify affected customers N_i (the `customers` column; missing counts default to the
+///       mean of the known counts)
+///    c. Sample outage duration from the repair-time model with mean r_i; under five minutes is
+///       momentary
+/// 4. Weight each element's sampled durations by λ_i × N_i and compute the indices with
+///    [`ReliabilityIndices::from_elements`]
+/// 5. Output: flisr_runs.parquet (one row per scenario) and reliability_indices.parquet
+///    (system-level indices)
 ///
 /// **Limitations (Simplified Model):**
 /// - **No switching optimization**: Assumes fixed restoration strategy (not optimal switching sequence)
@@ -131,11 +412,12 @@ struct ReliabilityElement {
 ///
 /// **Example Output Interpretation:**
 /// ```text
-/// Scenario 0: failed_element=branch_123, SAIDI=2.5 hrs, SAIFI=0.02, CAIDI=125 hrs
-/// Scenario 1: failed_element=branch_456, SAIDI=1.2 hrs, SAIFI=0.015, CAIDI=80 hrs
-/// Average: SAIDI=150 min/year, SAIFI=1.5 interruptions/year, CAIDI=100 min/interruption
+/// Scenario 0: failed_element=branch_123, customers=450, duration=2.5 hrs
+/// Scenario 1: failed_element=branch_456, customers=80, duration=0.05 hrs (momentary)
+/// System: SAIDI=2.5 hrs/year, SAIFI=1.5 interruptions/year, CAIDI=100 min/interruption
 /// ```
 /// Lower SAIDI/SAIFI = better reliability. CAIDI shows if outages are short (good FLISR) or long (manual).
+#[allow(clippy::too_many_arguments)]
 pub fn flisr_sim(
     grid_file: &Path,
     reliability_file: Option<&Path>,
@@ -144,6 +426,8 @@ pub fn flisr_sim(
     solver: SolverKind,
     tol: f64,
     max_iter: u32,
+    repair: RepairTimeModel,
+    seed: Option<u64>,
 ) -> Result<()> {
     fs::create_dir_all(out_dir).with_context(|| {
         format!(
@@ -167,34 +451,43 @@ pub fn flisr_sim(
         .map(read_reliability)
         .transpose()?
         .unwrap_or_else(default_reliability);
+    let mut rng = ScenarioRng::from_seed_or_entropy(seed);
+    let customers = customer_counts(&elements);
+    let mut interruptions: Vec<ElementInterruptions> = elements
+        .iter()
+        .zip(&customers)
+        .map(|(element, &customers)| ElementInterruptions {
+            failure_rate: element.failure_rate,
+            customers,
+            durations_hours: Vec::new(),
+        })
+        .collect();
 
     let mut scenario_ids = Vec::new();
     let mut branch_failures = Vec::new();
-    let mut saidi = Vec::new();
-    let mut saifi = Vec::new();
-    let mut caidi = Vec::new();
+    let mut customers_interrupted = Vec::new();
+    let mut durations = Vec::new();
+    let mut momentary = Vec::new();
 
     for scenario in 0..iterations {
-        let element = &elements[scenario % elements.len()];
-        let duration = element.repair_hours;
-        let interruption = (element.failure_rate * duration).max(1.0);
+        let idx = scenario % elements.len();
+        let element = &elements[idx];
+        let duration = repair.sample(element.repair_hours, &mut rng);
+        interruptions[idx].durations_hours.push(duration);
         scenario_ids.push(scenario as i64);
         branch_failures.push(element.element_id.clone());
-        saidi.push(interruption);
-        saifi.push(element.failure_rate);
-        caidi.push(if element.failure_rate == 0.0 {
-            0.0
-        } else {
-            interruption / element.failure_rate
-        });
+        customers_interrupted.push(customers[idx]);
+        durations.push(duration);
+        momentary.push(duration < MOMENTARY_THRESHOLD_HOURS);
     }
+    let indices = ReliabilityIndices::from_elements(&interruptions);
 
     let mut runs = DataFrame::new(vec![
         Series::new("scenario_id", scenario_ids),
         Series::new("failed_element", branch_failures),
-        Series::new("saidi_hours", saidi.clone()),
-        Series::new("saifi_interruptions", saifi.clone()),
-        Series::new("caidi_hours", caidi.clone()),
+        Series::new("customers", customers_interrupted),
+        Series::new("duration_hours", durations),
+        Series::new("momentary", momentary),
     ])?;
     let flisr_runs_path = out_dir.join("flisr_runs.parquet");
     persist_dataframe(&flisr_runs_path, &mut runs)?;
@@ -205,9 +498,12 @@ pub fn flisr_sim(
             vec![format!("flisr_{grid}", grid = grid_file.display())],
         ),
         Series::new("scenarios", vec![iterations as i64]),
-        Series::new("average_saidi", vec![mean(&saidi)]),
-        Series::new("average_saifi", vec![mean(&saifi)]),
-        Series::new("average_caidi", vec![mean(&caidi)]),
+        Series::new("total_customers", vec![indices.total_customers]),
+        Series::new("saidi_hours", vec![indices.saidi_hours]),
+        Series::new("saifi", vec![indices.saifi]),
+        Series::new("caidi_hours", vec![indices.caidi_hours]),
+        Series::new("caifi", vec![indices.caifi]),
+        Series::new("maifi", vec![indices.maifi]),
     ])?;
     let indices_path = out_dir.join("reliability_indices.parquet");
     persist_dataframe(&indices_path, &mut summary)?;
@@ -383,11 +679,13 @@ pub fn vvo_plan(
 ///    - **Probability of k failures in time T:** P(k) = (λT)^k × exp(-λT) / k!
 ///    - **Weather sensitivity:** λ increases during storms (λ_storm ≈ 10× λ_normal for overhead lines)
 ///
-/// 2. **Repair Time (Exponential):**
-///    - Time to repair follows exponential distribution with mean r (hours)
+/// 2. **Repair Time ([`RepairTimeModel`]):**
+///    - Exponential (default) with mean r (hours)
 ///    - **Interpretation:** If r = 3 hours, 63% of repairs complete within 3 hours, 95% within 9 hours
 ///    - **Memoryless property:** P(repair in next hour | already waited 2 hours) = constant
-///    - **Real distributions:** Repair times are often log-normal (not exponential), but exponential is tractable
+///    - **Lognormal:** Real repair times are closer to log-normal: most repairs are routine, but
+///      major repairs (pole replacement, cable splicing) form a long tail. Same mean r, with σ
+///      setting the spread of ln(repair time)
 ///
 /// **Historical Context:**
 /// Reliability planning evolved from deterministic to probabilistic:
@@ -396,15 +694,19 @@ pub fn vvo_plan(
 /// - **2000s-present**: Distribution reliability simulation (FLISR, DER impact, storm restoration)
 ///
 /// **Algorithm (Monte Carlo Outage Simulation):**
-/// 1. Load reliability data for all components (λ_i, r_i for component i)
-/// 2. For each Monte Carlo sample (1 to N):
-///    a. Randomly select a component i (weighted by failure rate λ_i)
-///    b. Sample number of failures: k ~ Poisson(λ_i)
-///    c. Sample repair time: duration ~ Exponential(r_i)
-///    d. Compute outage impact: unserved_energy = P_load × duration
-///    e. Record scenario: (scenario_id, component_id, unserved_mw, repair_hours)
-/// 3. Aggregate statistics: mean, std dev, percentiles (5th, 50th, 95th)
-/// 4. Output: outage_samples.parquet (individual scenarios), outage_stats.parquet (summary)
+/// 1. Load reliability data for all components (λ_i, r_i, planned rate, common-mode group, kV)
+/// 2. Scale each λ_i by voltage class (secondary 1.5×, primary 1.0×, subtransmission 0.5×)
+/// 3. For each Monte Carlo sample (one year of operation, 1 to N):
+///    a. Forced outage of component i with probability 1 - exp(-λ_i)
+///    b. Planned outage of component i with probability 1 - exp(-λ_p,i), sampled separately
+///    c. Common-mode event for group g with probability 1 - exp(-λ_cm,g); all members fail together
+///    d. Sample each outage's repair time from the repair-time model (mean r_i) and compute
+///       the impact of forced and common-mode events: unserved = Σ λ × repair time
+///       (planned work is switched around and does not count as unserved)
+///    e. Record scenario: (scenario_id, unserved_mw, repair_hours, forced/planned counts)
+/// 4. Aggregate statistics: mean unserved, mean repair, mean forced/planned outages
+/// 5. Output: outage_samples.parquet (per scenario), outage_events.parquet (per outage, with
+///    kind "forced" / "planned" / "common_mode"), outage_stats.parquet (summary)
 ///
 /// **Applications:**
 /// - **Crew staffing**: How many repair crews needed to meet SAIDI targets during storms?
@@ -424,14 +726,14 @@ pub fn vvo_plan(
 /// - **Discrepancies indicate:** Missing failure modes (tree contact, animal faults), incorrect λ/r estimates
 ///
 /// **Limitations (Current Model):**
-/// - **Independent failures**: Assumes components fail independently (real: cascading, common-mode)
+/// - **No cascading failures**: Only explicit common-mode groups fail together
 /// - **No weather correlation**: Failure rate is constant (real: λ varies 10-100x during storms)
 /// - **No crew constraints**: Assumes infinite crews (real: limited crews → longer repair queues)
 /// - **No restoration topology**: Doesn't model switching to reduce unserved load
 ///
 /// **Extensions (Future Work):**
 /// - **Weather-dependent λ**: Model storms as high-λ periods (Poisson process with time-varying rate)
-/// - **Correlated failures**: Use copulas or spatial correlation for nearby component failures
+/// - **Spatial correlation**: Use copulas for nearby component failures beyond explicit groups
 /// - **Crew dispatch simulation**: Queue model for repair crews (M/M/k queueing theory)
 /// - **FLISR integration**: Model automated switching to restore unfaulted sections
 ///
@@ -459,6 +761,7 @@ pub fn outage_mc(
     out_dir: &Path,
     samples: usize,
     seed: Option<u64>,
+    repair: RepairTimeModel,
 ) -> Result<()> {
     fs::create_dir_all(out_dir).with_context(|| {
         format!(
@@ -467,33 +770,91 @@ pub fn outage_mc(
         )
     })?;
     let elements = read_reliability(reliability_file)?;
-    let mut rng = seed
-        .map(StdRng::seed_from_u64)
-        .unwrap_or_else(StdRng::from_entropy);
+    let groups = common_mode_groups(&elements);
+    let mut rng = ScenarioRng::from_seed_or_entropy(seed);
     let mut scenario_ids = Vec::new();
     let mut unserved = Vec::new();
     let mut durations = Vec::new();
+    let mut forced_counts = Vec::new();
+    let mut planned_counts = Vec::new();
+
+    let mut event_scenarios = Vec::new();
+    let mut event_elements = Vec::new();
+    let mut event_kinds = Vec::new();
+    let mut event_groups = Vec::new();
+    let mut event_hours = Vec::new();
 
     for scenario in 0..samples {
-        let draw = elements[rng.gen_range(0..elements.len())].clone();
-        let outages = rng.gen_range(1..=3) as f64;
-        let lost = draw.failure_rate * draw.repair_hours * outages;
+        let events = sample_outages(&elements, &groups, &mut rng);
+        let mut lost = 0.0;
+        let mut longest = 0.0_f64;
+        let mut forced = 0_i64;
+        let mut planned = 0_i64;
+        for event in &events {
+            let element = &elements[event.element];
+            let hours = repair.sample(element.repair_hours, &mut rng);
+            match event.kind {
+                OutageKind::Planned => planned += 1,
+                OutageKind::Forced => {
+                    forced += 1;
+                    lost += element.effective_failure_rate() * hours;
+                    longest = longest.max(hours);
+                }
+                OutageKind::CommonMode => {
+                    // Every member is weighted by the group's event rate,
+                    // which need not be recorded on each member
+                    let group_rate = element
+                        .common_mode_group
+                        .as_ref()
+                        .and_then(|name| groups.get(name))
+                        .map_or(0.0, |(rate, _)| *rate);
+                    forced += 1;
+                    lost += group_rate * hours;
+                    longest = longest.max(hours);
+                }
+            }
+            event_scenarios.push(scenario as i64);
+            event_elements.push(element.element_id.clone());
+            event_kinds.push(event.kind.as_str());
+            event_groups.push(match event.kind {
+                OutageKind::CommonMode => element.common_mode_group.clone(),
+                _ => None,
+            });
+            event_hours.push(hours);
+        }
         scenario_ids.push(scenario as i64);
         unserved.push(lost);
-        durations.push(draw.repair_hours);
+        durations.push(longest);
+        forced_counts.push(forced);
+        planned_counts.push(planned);
     }
 
     let mut sample_df = DataFrame::new(vec![
         Series::new("scenario_id", scenario_ids.clone()),
         Series::new("unserved_mw", unserved.clone()),
         Series::new("repair_hours", durations.clone()),
+        Series::new("forced_outages", forced_counts.clone()),
+        Series::new("planned_outages", planned_counts.clone()),
     ])?;
     let samples_path = out_dir.join("outage_samples.parquet");
     persist_dataframe(&samples_path, &mut sample_df)?;
 
+    let mut event_df = DataFrame::new(vec![
+        Series::new("scenario_id", event_scenarios),
+        Series::new("element_id", event_elements),
+        Series::new("outage_kind", event_kinds),
+        Series::new("common_mode_group", event_groups),
+        Series::new("duration_hours", event_hours),
+    ])?;
+    let events_path = out_dir.join("outage_events.parquet");
+    persist_dataframe(&events_path, &mut event_df)?;
+
+    let as_f64 = |counts: &[i64]| counts.iter().map(|&c| c as f64).collect::<Vec<_>>();
     let mut stats = DataFrame::new(vec![
         Series::new("mean_unserved", vec![mean(&unserved)]),
         Series::new("mean_repair", vec![mean(&durations)]),
+        Series::new("mean_forced_outages", vec![mean(&as_f64(&forced_counts))]),
+        Series::new("mean_planned_outages", vec![mean(&as_f64(&planned_counts))]),
         Series::new("samples", vec![samples as i64]),
     ])?;
     let stats_path = out_dir.join("outage_stats.parquet");
@@ -641,6 +1002,10 @@ fn read_reliability(path: &Path) -> Result<Vec<ReliabilityElement>> {
     let rates = column_f64(&df, "lambda", 0.01)?;
     let repair = column_f64(&df, "repair_hours", 1.0)?;
     let customers = column_i64(&df, "customers")?;
+    let planned = column_f64(&df, "planned_outage_rate", 0.0)?;
+    let groups = column_utf8(&df, "common_mode_group")?;
+    let group_rates = column_f64(&df, "common_mode_rate", 0.0)?;
+    let voltages = column_f64_opt(&df, "voltage_kv")?;
     let mut result = Vec::new();
     for idx in 0..df.height() {
         result.push(ReliabilityElement {
@@ -648,7 +1013,11 @@ fn read_reliability(path: &Path) -> Result<Vec<ReliabilityElement>> {
             _element_type: types[idx].clone().unwrap_or_else(|| "unknown".to_string()),
             failure_rate: rates[idx],
             repair_hours: repair[idx],
-            _customers: customers[idx],
+            customers: customers[idx],
+            planned_outage_rate: planned[idx],
+            common_mode_group: groups[idx].clone(),
+            common_mode_rate: group_rates[idx],
+            voltage_kv: voltages[idx],
         });
     }
     Ok(result)
@@ -660,7 +1029,11 @@ fn default_reliability() -> Vec<ReliabilityElement> {
         _element_type: "branch".to_string(),
         failure_rate: 0.02,
         repair_hours: 4.0,
-        _customers: Some(120),
+        customers: Some(120),
+        planned_outage_rate: 0.0,
+        common_mode_group: None,
+        common_mode_rate: 0.0,
+        voltage_kv: None,
     }]
 }
 
@@ -701,6 +1074,17 @@ fn column_f64(df: &DataFrame, column: &str, default: f64) -> Result<Vec<f64>> {
     }
 }
 
+fn column_f64_opt(df: &DataFrame, column: &str) -> Result<Vec<Option<f64>>> {
+    if let Ok(series) = df.column(column) {
+        let chunked = series
+            .f64()
+            .with_context(|| format!("column '{}' must be float", column))?;
+        Ok(chunked.into_iter().collect())
+    } else {
+        Ok(vec![None; df.height()])
+    }
+}
+
 fn column_i64(df: &DataFrame, column: &str) -> Result<Vec<Option<i64>>> {
     if let Ok(series) = df.column(column) {
         let chunked = series
@@ -42,6 +42,7 @@ fn create_test_network(name: &str, gen_capacity: f64, load_capacity: f64) -> Net
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(load_capacity),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network.graph.add_edge(
@@ -0,0 +1,198 @@
+use gat_adms::{outage_mc, RepairTimeModel};
+use gat_core::ScenarioRng;
+use polars::prelude::{DataFrame, NamedFrom, ParquetReader, ParquetWriter, SerReader, Series};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("gat_adms_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_parquet(path: &Path, df: &mut DataFrame) {
+    let mut file = File::create(path).unwrap();
+    ParquetWriter::new(&mut file).finish(df).unwrap();
+}
+
+fn read_parquet(path: &Path) -> DataFrame {
+    ParquetReader::new(File::open(path).unwrap())
+        .finish()
+        .unwrap()
+}
+
+/// Two cables sharing a trench (common-mode group), plus a transformer that
+/// only ever goes out for planned maintenance. Forced rates are zero so every
+/// outage of the cables must come from the shared group event. Only the first
+/// cable records the group rate.
+fn reliability_table(dir: &Path) -> PathBuf {
+    let mut df = DataFrame::new(vec![
+        Series::new("element_id", vec!["cable_a", "cable_b", "xfmr_1"]),
+        Series::new("element_type", vec!["branch", "branch", "transformer"]),
+        Series::new("lambda", vec![0.0, 0.0, 0.0]),
+        Series::new("repair_hours", vec![6.0, 6.0, 8.0]),
+        Series::new("planned_outage_rate", vec![0.0, 0.0, 0.7]),
+        Series::new(
+            "common_mode_group",
+            vec![Some("trench_1"), Some("trench_1"), None],
+        ),
+        Series::new("common_mode_rate", vec![0.7, 0.0, 0.0]),
+        Series::new("voltage_kv", vec![12.47, 12.47, 69.0]),
+    ])
+    .unwrap();
+    let path = dir.join("reliability.parquet");
+    write_parquet(&path, &mut df);
+    path
+}
+
+#[test]
+fn test_outage_mc_common_mode_cofail_and_planned_distinct() {
+    let dir = scratch_dir("outage_mc_common_mode");
+    let input = reliability_table(&dir);
+    let out = dir.join("out");
+    let samples = 400;
+
+    outage_mc(&input, &out, samples, Some(7), RepairTimeModel::default()).unwrap();
+
+    let events = read_parquet(&out.join("outage_events.parquet"));
+    let scenarios: Vec<i64> = events
+        .column("scenario_id")
+        .unwrap()
+        .i64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect();
+    let elements: Vec<String> = events
+        .column("element_id")
+        .unwrap()
+        .utf8()
+        .unwrap()
+        .into_no_null_iter()
+        .map(str::to_string)
+        .collect();
+    let hours: Vec<f64> = events
+        .column("duration_hours")
+        .unwrap()
+        .f64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect();
+    let kinds: Vec<String> = events
+        .column("outage_kind")
+        .unwrap()
+        .utf8()
+        .unwrap()
+        .into_no_null_iter()
+        .map(str::to_string)
+        .collect();
+
+    let mut cable_outages: BTreeMap<i64, BTreeSet<String>> = BTreeMap::new();
+    let mut cable_hours: BTreeMap<i64, f64> = BTreeMap::new();
+    let mut planned_scenarios = BTreeSet::new();
+    for (((scenario, element), kind), duration) in
+        scenarios.iter().zip(&elements).zip(&kinds).zip(&hours)
+    {
+        match element.as_str() {
+            "cable_a" | "cable_b" => {
+                assert_eq!(kind, "common_mode");
+                *cable_hours.entry(*scenario).or_default() += duration;
+                cable_outages
+                    .entry(*scenario)
+                    .or_default()
+                    .insert(element.clone());
+            }
+            "xfmr_1" => {
+                assert_eq!(kind, "planned");
+                planned_scenarios.insert(*scenario);
+            }
+            other => panic!("unexpected element {}", other),
+        }
+    }
+
+    // Grouped cables fail together, in roughly 1 - exp(-0.7) ≈ 50% of samples
+    assert!(!cable_outages.is_empty());
+    assert!(cable_outages.len() < samples);
+    for members in cable_outages.values() {
+        assert_eq!(members.len(), 2, "common-mode members must co-fail");
+    }
+    let group_fraction = cable_outages.len() as f64 / samples as f64;
+    assert!((group_fraction - 0.503).abs() < 0.1, "{}", group_fraction);
+
+    // Planned outages are reported separately and excluded from unserved energy
+    let sample_df = read_parquet(&out.join("outage_samples.parquet"));
+    let planned: Vec<i64> = sample_df
+        .column("planned_outages")
+        .unwrap()
+        .i64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect();
+    let forced: Vec<i64> = sample_df
+        .column("forced_outages")
+        .unwrap()
+        .i64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(
+        planned.iter().filter(|&&p| p > 0).count(),
+        planned_scenarios.len()
+    );
+    assert!(!planned_scenarios.is_empty());
+    let unserved: Vec<f64> = sample_df
+        .column("unserved_mw")
+        .unwrap()
+        .f64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect();
+    for (scenario, &count) in forced.iter().enumerate() {
+        if count == 0 {
+            assert_eq!(unserved[scenario], 0.0);
+        }
+        let expected = if cable_outages.contains_key(&(scenario as i64)) {
+            2
+        } else {
+            0
+        };
+        assert_eq!(count, expected);
+
+        // Both cables count at the group rate
+        let expected_unserved = 0.7 * cable_hours.get(&(scenario as i64)).unwrap_or(&0.0);
+        assert!((unserved[scenario] - expected_unserved).abs() < 1e-9);
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_lognormal_repair_matches_mean_with_heavier_tail() {
+    let mean_hours = 4.0;
+    let n = 50_000;
+    let draw = |model: RepairTimeModel| {
+        let mut rng = ScenarioRng::new(11);
+        let mut hours: Vec<f64> = (0..n).map(|_| model.sample(mean_hours, &mut rng)).collect();
+        hours.sort_by(f64::total_cmp);
+        hours
+    };
+    let exponential = draw(RepairTimeModel::Exponential);
+    let lognormal = draw(RepairTimeModel::from_name("lognormal", 1.5).unwrap());
+
+    let mean = |hours: &[f64]| hours.iter().sum::<f64>() / hours.len() as f64;
+    assert!((mean(&exponential) - mean_hours).abs() < 0.1);
+    assert!(
+        (mean(&lognormal) - mean_hours).abs() / mean_hours < 0.05,
+        "lognormal mean {}",
+        mean(&lognormal)
+    );
+
+    // Same mean, but more of it sits in the far tail
+    let quantile = |hours: &[f64], q: f64| hours[(q * hours.len() as f64) as usize];
+    assert!(quantile(&lognormal, 0.999) > quantile(&exponential, 0.999));
+    assert!(quantile(&lognormal, 0.5) < quantile(&exponential, 0.5));
+
+    assert!(RepairTimeModel::from_name("weibull", 1.0).is_err());
+    assert!(RepairTimeModel::from_name("lognormal", 0.0).is_err());
+}
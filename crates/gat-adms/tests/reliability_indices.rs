@@ -0,0 +1,50 @@
+use gat_adms::{ElementInterruptions, ReliabilityIndices};
+
+fn element(failure_rate: f64, customers: f64, durations_hours: Vec<f64>) -> ElementInterruptions {
+    ElementInterruptions {
+        failure_rate,
+        customers,
+        durations_hours,
+    }
+}
+
+#[test]
+fn test_saidi_weights_by_customers_interrupted() {
+    // A long outage on a small lateral and a short one on a large trunk section
+    let elements = [
+        element(0.5, 100.0, vec![6.0, 6.0]),
+        element(0.5, 900.0, vec![1.0, 1.0]),
+    ];
+    let indices = ReliabilityIndices::from_elements(&elements);
+
+    // Σ λ·r·N / N_T = (0.5·6·100 + 0.5·1·900) / 1000
+    assert_eq!(indices.total_customers, 1000.0);
+    assert!((indices.saidi_hours - 0.75).abs() < 1e-12);
+    // Unweighted mean of λ·r over the two elements would be 1.75
+    let unweighted = (0.5 * 6.0 + 0.5 * 1.0) / 2.0;
+    assert!((indices.saidi_hours - unweighted).abs() > 0.5);
+
+    assert!((indices.saifi - 0.5).abs() < 1e-12);
+    assert!((indices.caidi_hours - 1.5).abs() < 1e-12);
+    assert!((indices.caifi - 0.5).abs() < 1e-12);
+    assert_eq!(indices.maifi, 0.0);
+}
+
+#[test]
+fn test_short_interruptions_count_as_momentary() {
+    // Half of the failures on the second section clear within five minutes
+    let elements = [
+        element(1.0, 200.0, vec![2.0]),
+        element(2.0, 200.0, vec![0.05, 3.0]),
+        element(0.0, 600.0, vec![4.0]),
+    ];
+    let indices = ReliabilityIndices::from_elements(&elements);
+
+    // Sustained: 1·200 + 2·0.5·200 = 400 customer interruptions over 1000 served
+    assert!((indices.saifi - 0.4).abs() < 1e-12);
+    assert!((indices.maifi - 0.2).abs() < 1e-12);
+    // Customer hours: 1·2·200 + 2·(3/2)·200 = 1000
+    assert!((indices.saidi_hours - 1.0).abs() < 1e-12);
+    // Only the two failing sections (400 customers) were interrupted
+    assert!((indices.caifi - 1.0).abs() < 1e-12);
+}
@@ -1,3 +1,5 @@
+use crate::opf::ac_nlp::AcOpfProblem;
+use crate::opf::OpfMethod;
 use gat_core::{Edge, Gen, Network, Node};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -33,6 +35,50 @@ pub enum AcOpfError {
     /// Method not yet implemented
     #[error("OPF method not implemented: {0}")]
     NotImplemented(String),
+
+    /// The backend `method` needs is not compiled into this build or not
+    /// installed; `reason` says what to enable
+    #[error("{method} OPF solver unavailable: {reason}")]
+    SolverUnavailable { method: OpfMethod, reason: String },
+}
+
+/// Quantity minimized by [`AcOpfSolver`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AcObjective {
+    /// Total generation cost (economic dispatch)
+    #[default]
+    MinCost,
+    /// Total active branch losses Σ I²R, as used for volt/VAR optimization
+    MinLosses,
+    /// Σ(V − 1)² over all buses, flattening the voltage profile
+    MinVoltageDeviation,
+}
+
+impl std::fmt::Display for AcObjective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            AcObjective::MinCost => "min_cost",
+            AcObjective::MinLosses => "min_losses",
+            AcObjective::MinVoltageDeviation => "min_voltage_deviation",
+        };
+        f.write_str(name)
+    }
+}
+
+impl std::str::FromStr for AcObjective {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "min_cost" | "cost" => Ok(AcObjective::MinCost),
+            "min_loss" | "min_losses" | "loss" | "losses" => Ok(AcObjective::MinLosses),
+            "min_voltage_deviation" | "voltage_deviation" => Ok(AcObjective::MinVoltageDeviation),
+            _ => Err(format!(
+                "Unknown AC-OPF objective: {} (expected min_cost, min_loss, or min_voltage_deviation)",
+                s
+            )),
+        }
+    }
 }
 
 /// AC OPF Solution
@@ -40,14 +86,32 @@ pub enum AcOpfError {
 pub struct AcOpfSolution {
     /// Did the solver converge?
     pub converged: bool,
-    /// Objective value (total cost in $)
+    /// Objective that was minimized
+    pub objective: AcObjective,
+    /// Objective value ($/hr for cost, MW for losses, p.u.² for voltage deviation)
     pub objective_value: f64,
     /// Generator outputs by name: (bus, MW)
     pub generator_outputs: HashMap<String, f64>,
+    /// Generator reactive outputs by name (MVAr). Empty for merit-order
+    /// dispatch.
+    pub generator_reactive_outputs: HashMap<String, f64>,
     /// Bus voltages by name: (bus, pu)
     pub bus_voltages: HashMap<String, f64>,
     /// Branch flows by name: (branch, MW)
     pub branch_flows: HashMap<String, f64>,
+    /// Optimized transformer tap ratios by branch name. Empty unless tap
+    /// control was enabled with [`AcOpfSolver::with_tap_control`].
+    pub transformer_taps: HashMap<String, f64>,
+    /// Voltage outside the bus band in p.u. by bus name (positive above
+    /// `v_max`, negative below `v_min`). Empty unless soft voltage limits were
+    /// enabled with [`AcOpfSolver::with_soft_voltage_limits`].
+    pub voltage_violations: HashMap<String, f64>,
+    /// Total active power losses (MW)
+    pub total_losses_mw: f64,
+    /// Cost of reactive production ($/hr) from generators with a
+    /// `reactive_cost`. Part of `objective_value` when minimizing cost; zero
+    /// for merit-order dispatch, which does not dispatch reactive power.
+    pub reactive_cost: f64,
     /// Number of iterations
     pub iterations: usize,
     /// Solve time in milliseconds
@@ -56,21 +120,22 @@ pub struct AcOpfSolution {
 
 /// Optimal Power Flow solver using economic dispatch
 ///
-/// This solver currently implements a simplified DC-OPF approximation using
+/// By default this solver implements a simplified DC-OPF approximation using
 /// merit-order economic dispatch. It:
 /// - Ignores reactive power and voltage constraints (DC approximation)
 /// - Dispatches generators in order of marginal cost (merit order)
 /// - Respects generator Pmin/Pmax limits
 /// - Estimates losses at 1% of load
 ///
-/// Future versions may implement full AC-OPF with nonlinear constraints.
+/// Selecting an objective with [`AcOpfSolver::with_objective`] switches to the
+/// full nonlinear AC-OPF (IPOPT when built with `solver-ipopt`, otherwise the
+/// penalty L-BFGS solver), which models losses and voltages explicitly.
 pub struct AcOpfSolver {
-    // Note: These fields are reserved for future AC-OPF implementation.
-    // Currently, the solver uses merit-order dispatch which doesn't need them.
-    #[allow(dead_code)]
     max_iterations: usize,
-    #[allow(dead_code)]
     tolerance: f64,
+    objective: Option<AcObjective>,
+    tap_range: Option<(f64, f64)>,
+    soft_voltage_weight: Option<f64>,
 }
 
 impl AcOpfSolver {
@@ -79,27 +144,77 @@ impl AcOpfSolver {
         Self {
             max_iterations: 100,
             tolerance: 1e-6,
+            objective: None,
+            tap_range: None,
+            soft_voltage_weight: None,
         }
     }
 
-    /// Set maximum iterations (reserved for future AC-OPF implementation)
+    /// Set maximum iterations of the nonlinear AC-OPF
     ///
-    /// Note: Currently unused - the merit-order dispatch converges in one pass.
+    /// Note: Unused by the merit-order dispatch, which converges in one pass.
     pub fn with_max_iterations(mut self, max_iter: usize) -> Self {
         self.max_iterations = max_iter;
         self
     }
 
-    /// Set convergence tolerance (reserved for future AC-OPF implementation)
+    /// Set convergence tolerance of the nonlinear AC-OPF
     ///
-    /// Note: Currently unused - the merit-order dispatch is deterministic.
+    /// Note: Unused by the merit-order dispatch, which is deterministic.
     pub fn with_tolerance(mut self, tol: f64) -> Self {
         self.tolerance = tol;
         self
     }
 
+    /// Minimize `objective` with the full nonlinear AC-OPF instead of running
+    /// merit-order dispatch.
+    pub fn with_objective(mut self, objective: AcObjective) -> Self {
+        self.objective = Some(objective);
+        self
+    }
+
+    /// Optimize the tap ratio of every transformer branch within
+    /// `[tap_min, tap_max]` (e.g. 0.9–1.1) instead of holding it fixed.
+    ///
+    /// Non-transformer branches keep their fixed tap. Tap control needs the
+    /// nonlinear AC-OPF, so it minimizes cost unless another objective is
+    /// selected, and always runs on the penalty L-BFGS solver.
+    pub fn with_tap_control(mut self, tap_min: f64, tap_max: f64) -> Self {
+        self.tap_range = Some((tap_min, tap_max));
+        self
+    }
+
+    /// Penalize bus voltages outside their bands by `weight·excess²` (objective
+    /// units per p.u.²) instead of enforcing them, and report the excursions in
+    /// [`AcOpfSolution::voltage_violations`].
+    ///
+    /// Lets a case with no voltage-feasible operating point still solve. Like
+    /// tap control it needs the nonlinear AC-OPF, so it minimizes cost unless
+    /// another objective is selected, and runs on the penalty L-BFGS solver.
+    pub fn with_soft_voltage_limits(mut self, weight: f64) -> Self {
+        self.soft_voltage_weight = Some(weight);
+        self
+    }
+
     /// Validate network before solving
     fn validate_network(&self, network: &Network) -> Result<(), AcOpfError> {
+        if let Some((tap_min, tap_max)) = self.tap_range {
+            if !(tap_min > 0.0 && tap_min <= tap_max) {
+                return Err(AcOpfError::DataValidation(format!(
+                    "Tap range [{}, {}] must be positive and ordered",
+                    tap_min, tap_max
+                )));
+            }
+        }
+        if let Some(weight) = self.soft_voltage_weight {
+            if !(weight.is_finite() && weight > 0.0) {
+                return Err(AcOpfError::DataValidation(format!(
+                    "Soft voltage weight {} must be positive and finite",
+                    weight
+                )));
+            }
+        }
+
         let mut has_bus = false;
         let mut has_generator = false;
 
@@ -137,6 +252,15 @@ impl AcOpfSolver {
                             gen.name, gen.active_power.value()
                         )));
                     }
+
+                    if let Some(cost) = gen.reactive_cost {
+                        if !(cost.is_finite() && cost >= 0.0) {
+                            return Err(AcOpfError::DataValidation(format!(
+                                "Generator {}: reactive cost {} must be non-negative",
+                                gen.name, cost
+                            )));
+                        }
+                    }
                 }
                 Node::Load(_load) => {
                     // Loads are optional, basic validation only
@@ -268,10 +392,16 @@ impl AcOpfSolver {
         // Build solution
         let mut solution = AcOpfSolution {
             converged: true,
+            objective: AcObjective::MinCost,
             objective_value,
             generator_outputs: HashMap::new(),
+            generator_reactive_outputs: HashMap::new(),
             bus_voltages: HashMap::new(),
             branch_flows: HashMap::new(),
+            transformer_taps: HashMap::new(),
+            voltage_violations: HashMap::new(),
+            total_losses_mw: loss_estimate,
+            reactive_cost: 0.0,
             iterations: 1,
             solve_time_ms: start.elapsed().as_millis(),
         };
@@ -354,13 +484,79 @@ impl AcOpfSolver {
         Ok(dispatch)
     }
 
-    /// Solve AC OPF using merit-order economic dispatch
+    /// Solve the nonlinear AC-OPF for the selected objective
+    fn solve_nonlinear(
+        &self,
+        network: &Network,
+        objective: AcObjective,
+    ) -> Result<AcOpfSolution, AcOpfError> {
+        let mut problem = AcOpfProblem::from_network(network)?.with_objective(objective);
+        if let Some((tap_min, tap_max)) = self.tap_range {
+            problem = problem.with_tap_control(network, tap_min, tap_max);
+        }
+        if let Some(weight) = self.soft_voltage_weight {
+            problem = problem.with_soft_voltage_limits(weight);
+        }
+
+        #[cfg(feature = "solver-ipopt")]
+        let opf = if problem.tap_controls.is_empty() && problem.soft_voltage_weight.is_none() {
+            crate::opf::ac_nlp::solve_with_ipopt(
+                &problem,
+                Some(self.max_iterations),
+                Some(self.tolerance),
+            )?
+        } else {
+            crate::opf::ac_nlp::solve_ac_opf(&problem, self.max_iterations, self.tolerance)?
+        };
+        #[cfg(not(feature = "solver-ipopt"))]
+        let opf = crate::opf::ac_nlp::solve_ac_opf(&problem, self.max_iterations, self.tolerance)?;
+
+        let reactive_cost = network
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Gen(gen) => gen.reactive_cost.map(|cost| (gen, cost)),
+                _ => None,
+            })
+            .filter_map(|(gen, cost)| {
+                opf.generator_q
+                    .get(&gen.name)
+                    .map(|q_mvar| cost * q_mvar.abs())
+            })
+            .sum();
+
+        Ok(AcOpfSolution {
+            converged: opf.converged,
+            objective,
+            objective_value: opf.objective_value,
+            generator_outputs: opf.generator_p,
+            generator_reactive_outputs: opf.generator_q,
+            bus_voltages: opf.bus_voltage_mag,
+            branch_flows: opf.branch_p_flow,
+            transformer_taps: opf.transformer_taps,
+            voltage_violations: opf.voltage_violations,
+            total_losses_mw: opf.total_losses_mw,
+            reactive_cost,
+            iterations: opf.iterations,
+            solve_time_ms: opf.solve_time_ms,
+        })
+    }
+
+    /// Solve AC OPF
+    ///
+    /// Uses merit-order economic dispatch unless an objective was selected with
+    /// [`AcOpfSolver::with_objective`] or tap control or soft voltage limits
+    /// were enabled.
     pub fn solve(&self, network: &Network) -> Result<AcOpfSolution, AcOpfError> {
         // Validate first
         self.validate_network(network)?;
 
-        // Solve using economic dispatch
-        self.solve_economic_dispatch(network)
+        let nonlinear = self.tap_range.is_some() || self.soft_voltage_weight.is_some();
+        match self.objective {
+            Some(objective) => self.solve_nonlinear(network, objective),
+            None if nonlinear => self.solve_nonlinear(network, AcObjective::MinCost),
+            None => self.solve_economic_dispatch(network),
+        }
     }
 }
 
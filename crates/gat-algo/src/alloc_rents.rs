@@ -203,24 +203,16 @@ fn compute_branch_rents(opf_df: &DataFrame, network: &gat_core::Network) -> Resu
         }
     }
 
-    // Build branch_id -> (from_bus, to_bus) lookup from network graph edges
-    // Each edge in the graph represents a branch connecting two buses
+    // Build branch_id -> (from_bus, to_bus) lookup from the branch terminals.
+    // Flows follow the from→to convention (see `opf::normalize_flow_signs`), so
+    // the graph edge's insertion order must not be used here.
     let mut branch_map: HashMap<i64, (i64, i64)> = HashMap::new();
-    for edge_idx in network.graph.edge_indices() {
-        if let gat_core::Edge::Branch(branch) = &network.graph[edge_idx] {
-            let endpoints = network.graph.edge_endpoints(edge_idx).unwrap();
-            // Get bus IDs from the Node endpoints
-            let from_bus_id = if let gat_core::Node::Bus(bus) = &network.graph[endpoints.0] {
-                bus.id.value() as i64
-            } else {
-                continue; // Skip if not a bus
-            };
-            let to_bus_id = if let gat_core::Node::Bus(bus) = &network.graph[endpoints.1] {
-                bus.id.value() as i64
-            } else {
-                continue;
-            };
-            branch_map.insert(branch.id.value() as i64, (from_bus_id, to_bus_id));
+    for edge in network.graph.edge_weights() {
+        if let gat_core::Edge::Branch(branch) = edge {
+            branch_map.insert(
+                branch.id.value() as i64,
+                (branch.from_bus.value() as i64, branch.to_bus.value() as i64),
+            );
         }
     }
 
@@ -2,7 +2,7 @@ use crate::io::{persist_dataframe, OutputStage};
 use crate::power_flow::branch_flow_dataframe;
 use anyhow::{anyhow, Context, Result};
 use csv::ReaderBuilder;
-use gat_core::{solver::LinearSystemBackend, Network, Node};
+use gat_core::{solver::LinearSystemBackend, Network, Node, ScenarioRng};
 use polars::prelude::*;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -333,7 +333,7 @@ fn compute_ds(
 /// of the two upper bounds (handling sign of ptdf correctly).
 ///
 /// **Returns:** Some(ΔP_max) if a positive bound exists, None otherwise
-fn branch_bound(limit: f64, flow: f64, ptdf: f64) -> Option<f64> {
+pub(crate) fn branch_bound(limit: f64, flow: f64, ptdf: f64) -> Option<f64> {
     let mut candidate = f64::INFINITY;
 
     // Helper to check if a computed bound is valid (positive and finite)
@@ -473,6 +473,93 @@ fn collect_bus_ids(network: &Network) -> Vec<usize> {
         .collect()
 }
 
+/// Renewable technology for [`synthetic_renewable_profile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenewableKind {
+    Solar,
+    Wind,
+}
+
+/// Solar: first and last hour of daylight; output is zero outside them
+const SUNRISE_HOUR: f64 = 6.0;
+const SUNSET_HOUR: f64 = 18.0;
+
+/// Solar: capacity factor at noon under a clear sky
+const SOLAR_PEAK_CF: f64 = 0.85;
+
+/// Wind: hour-to-hour correlation of the underlying wind speed
+const WIND_SPEED_AUTOCORRELATION: f64 = 0.9;
+
+/// Wind: mean and standard deviation of hub-height wind speed (m/s)
+const WIND_MEAN_SPEED: f64 = 8.0;
+const WIND_SPEED_STD_DEV: f64 = 3.0;
+
+/// Wind: cut-in, rated and cut-out speeds of the turbine power curve (m/s)
+const CUT_IN_SPEED: f64 = 3.0;
+const RATED_SPEED: f64 = 12.0;
+const CUT_OUT_SPEED: f64 = 25.0;
+
+/// Generate an hourly capacity-factor profile for quick studies.
+///
+/// Values are normalized to nameplate, in `[0, 1]`, starting at midnight.
+/// Multiply by a unit's `pmax` to get availability caps for the
+/// time-series OPF driver (`p_available_mw`). The profile is fully
+/// determined by `seed`.
+///
+/// - **Solar** follows a half-sine between 06:00 and 18:00, scaled by a
+///   clearness index drawn once per day and a slowly varying cloud factor;
+///   night hours are exactly zero.
+/// - **Wind** runs an AR(1) wind speed with lag-1 correlation
+///   [`WIND_SPEED_AUTOCORRELATION`] through a cubic turbine power curve
+///   (cut-in 3 m/s, rated 12 m/s, cut-out 25 m/s).
+pub fn synthetic_renewable_profile(kind: RenewableKind, hours: usize, seed: u64) -> Vec<f64> {
+    let mut rng = ScenarioRng::new(seed);
+    match kind {
+        RenewableKind::Solar => {
+            let mut clearness = 1.0;
+            let mut cloud = 0.0;
+            (0..hours)
+                .map(|hour| {
+                    if hour % 24 == 0 {
+                        clearness = rng.uniform_range(0.4, 1.0);
+                    }
+                    cloud = 0.7 * cloud + 0.3 * rng.normal(0.0, 0.3);
+                    let hour_of_day = (hour % 24) as f64;
+                    if hour_of_day <= SUNRISE_HOUR || hour_of_day >= SUNSET_HOUR {
+                        return 0.0;
+                    }
+                    let elevation = (std::f64::consts::PI * (hour_of_day - SUNRISE_HOUR)
+                        / (SUNSET_HOUR - SUNRISE_HOUR))
+                        .sin();
+                    (SOLAR_PEAK_CF * elevation * clearness * (1.0 - cloud.abs())).clamp(0.0, 1.0)
+                })
+                .collect()
+        }
+        RenewableKind::Wind => {
+            let phi = WIND_SPEED_AUTOCORRELATION;
+            let innovation = (1.0 - phi * phi).sqrt();
+            let mut z = rng.normal(0.0, 1.0);
+            (0..hours)
+                .map(|_| {
+                    z = phi * z + innovation * rng.normal(0.0, 1.0);
+                    turbine_power_curve(WIND_MEAN_SPEED + WIND_SPEED_STD_DEV * z)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Normalized turbine output at wind speed `speed` (m/s).
+fn turbine_power_curve(speed: f64) -> f64 {
+    if !(CUT_IN_SPEED..CUT_OUT_SPEED).contains(&speed) {
+        0.0
+    } else if speed >= RATED_SPEED {
+        1.0
+    } else {
+        (speed.powi(3) - CUT_IN_SPEED.powi(3)) / (RATED_SPEED.powi(3) - CUT_IN_SPEED.powi(3))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -494,4 +581,50 @@ mod tests {
         );
         assert!(column_to_string(Some(&series), 1).unwrap().is_none());
     }
+
+    /// Lag-1 autocorrelation of a series.
+    fn lag1_autocorrelation(values: &[f64]) -> f64 {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+        let covariance: f64 = values
+            .windows(2)
+            .map(|w| (w[0] - mean) * (w[1] - mean))
+            .sum();
+        covariance / variance
+    }
+
+    #[test]
+    fn solar_profile_is_zero_at_night_and_reproducible() {
+        let profile = synthetic_renewable_profile(RenewableKind::Solar, 24 * 30, 7);
+        assert_eq!(profile.len(), 24 * 30);
+        for (hour, &cf) in profile.iter().enumerate() {
+            let hour_of_day = hour % 24;
+            if hour_of_day <= 6 || hour_of_day >= 18 {
+                assert_eq!(cf, 0.0, "hour {} should be dark", hour);
+            }
+            assert!((0.0..=1.0).contains(&cf));
+        }
+        assert!(profile.iter().any(|&cf| cf > 0.3));
+
+        assert_eq!(
+            profile,
+            synthetic_renewable_profile(RenewableKind::Solar, 24 * 30, 7)
+        );
+        assert_ne!(
+            profile,
+            synthetic_renewable_profile(RenewableKind::Solar, 24 * 30, 8)
+        );
+    }
+
+    #[test]
+    fn wind_profile_is_bounded_and_autocorrelated() {
+        let profile = synthetic_renewable_profile(RenewableKind::Wind, 24 * 365, 42);
+        assert!(profile.iter().all(|cf| (0.0..=1.0).contains(cf)));
+
+        // Hourly wind output is strongly persistent but not constant
+        let rho = lag1_autocorrelation(&profile);
+        assert!((0.7..0.98).contains(&rho), "lag-1 autocorrelation {}", rho);
+        let mean = profile.iter().sum::<f64>() / profile.len() as f64;
+        assert!((0.2..0.6).contains(&mean), "mean capacity factor {}", mean);
+    }
 }
@@ -505,3 +505,392 @@ fn extract_string_value(df: &DataFrame, column: &str, idx: usize) -> Result<Opti
         Err(_) => Ok(None),
     }
 }
+
+/// Load scales screened by [`reactive_adequacy`]: base case plus 10% and 20% stress.
+const REACTIVE_STRESS_SCALES: [f64; 3] = [1.0, 1.1, 1.2];
+/// Bisection stops once the shortfall is bracketed this tightly (Mvar).
+const REACTIVE_SHORTFALL_TOLERANCE_MVAR: f64 = 0.1;
+/// Voltages this far (p.u.) below `vmin` still count as within bounds.
+const VOLTAGE_TOLERANCE_PU: f64 = 1e-4;
+
+/// A bus whose voltage cannot be held at its lower bound.
+#[derive(Debug, Clone)]
+pub struct ReactiveDeficientBus {
+    pub bus: String,
+    /// Voltage reached with the lower bound relaxed (p.u.)
+    pub voltage_pu: f64,
+    /// Lower bound it falls short of (p.u.)
+    pub vmin_pu: f64,
+    /// Reactive injection needed at this bus to restore it (Mvar)
+    pub shortfall_mvar: f64,
+}
+
+/// Reactive adequacy at one load stress level.
+#[derive(Debug, Clone)]
+pub struct ReactiveScenarioResult {
+    /// Multiplier applied to every load's P and Q
+    pub load_scale: f64,
+    pub deficient_buses: Vec<ReactiveDeficientBus>,
+    /// Total Mvar missing across deficient buses
+    pub shortfall_mvar: f64,
+    /// False when even the bisection ceiling of extra Mvar could not restore
+    /// voltages (e.g. active power is short too); the shortfall is then a floor.
+    pub resolved: bool,
+}
+
+/// Reactive adequacy across stress levels, from [`reactive_adequacy`].
+#[derive(Debug, Clone)]
+pub struct ReactiveAdequacyReport {
+    pub scenarios: Vec<ReactiveScenarioResult>,
+}
+
+impl ReactiveAdequacyReport {
+    /// Whether every stress level holds all voltages within bounds.
+    pub fn is_adequate(&self) -> bool {
+        self.scenarios.iter().all(|s| s.deficient_buses.is_empty())
+    }
+
+    /// Largest total shortfall across stress levels (Mvar).
+    pub fn worst_shortfall_mvar(&self) -> f64 {
+        self.scenarios
+            .iter()
+            .map(|s| s.shortfall_mvar)
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Check whether reactive resources can hold bus voltages under stressed load.
+///
+/// Screens the base case and 10%/20% load increases; see
+/// [`reactive_adequacy_at`] to choose the stress levels.
+pub fn reactive_adequacy(network: &gat_core::Network) -> Result<ReactiveAdequacyReport> {
+    reactive_adequacy_at(network, &REACTIVE_STRESS_SCALES)
+}
+
+/// Check reactive adequacy with loads scaled by each of `load_scales`.
+///
+/// **Algorithm:** For each stress level, using the SOCP relaxation of AC-OPF
+/// (generator Q limits and shunts included):
+/// 1. Solve with voltage bounds enforced. If it solves, resources are adequate.
+/// 2. Otherwise re-solve with lower voltage bounds relaxed; buses that end up
+///    below `vmin` are deficient.
+/// 3. Place a virtual reactive source at each deficient bus and bisect on its
+///    Mvar ceiling until the bounded problem solves. The sources' output at the
+///    smallest feasible ceiling is the shortfall.
+///
+/// Because SOCP relaxes the AC equations, shortfalls are approximate.
+pub fn reactive_adequacy_at(
+    network: &gat_core::Network,
+    load_scales: &[f64],
+) -> Result<ReactiveAdequacyReport> {
+    let scenarios = load_scales
+        .iter()
+        .map(|&scale| reactive_scenario(network, scale))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ReactiveAdequacyReport { scenarios })
+}
+
+fn reactive_scenario(
+    network: &gat_core::Network,
+    load_scale: f64,
+) -> Result<ReactiveScenarioResult> {
+    use gat_core::Node;
+
+    let mut stressed = network.clone();
+    for node in stressed.graph.node_weights_mut() {
+        if let Node::Load(load) = node {
+            load.active_power = gat_core::Megawatts(load.active_power.value() * load_scale);
+            load.reactive_power = gat_core::Megavars(load.reactive_power.value() * load_scale);
+        }
+    }
+
+    let adequate = ReactiveScenarioResult {
+        load_scale,
+        deficient_buses: Vec::new(),
+        shortfall_mvar: 0.0,
+        resolved: true,
+    };
+    if voltage_feasible(&stressed).is_some() {
+        return Ok(adequate);
+    }
+
+    // Relax lower voltage bounds to see which buses sag
+    let vmin: HashMap<String, f64> = stressed
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => Some((
+                bus.name.clone(),
+                bus.vmin_pu.map(|v| v.value()).unwrap_or(0.9),
+            )),
+            _ => None,
+        })
+        .collect();
+    let mut relaxed = stressed.clone();
+    for node in relaxed.graph.node_weights_mut() {
+        if let Node::Bus(bus) = node {
+            bus.vmin_pu = Some(gat_core::PerUnit(0.0));
+        }
+    }
+    let relaxed_solution = socp(&relaxed).context("relaxed-voltage SOCP failed")?;
+    let mut deficient: Vec<ReactiveDeficientBus> = relaxed_solution
+        .bus_voltage_mag
+        .iter()
+        .filter(|&(name, &v)| v < vmin[name.as_str()] - VOLTAGE_TOLERANCE_PU)
+        .map(|(name, &v)| ReactiveDeficientBus {
+            bus: name.clone(),
+            voltage_pu: v,
+            vmin_pu: vmin[name.as_str()],
+            shortfall_mvar: 0.0,
+        })
+        .collect();
+    deficient.sort_by(|a, b| a.bus.cmp(&b.bus));
+    if deficient.is_empty() {
+        return Err(anyhow!(
+            "SOCP infeasible at load scale {} but no bus falls below vmin when relaxed",
+            load_scale
+        ));
+    }
+
+    // Bisect on a common Mvar ceiling for the virtual sources
+    let total_q_load: f64 = stressed
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Load(load) => Some(load.reactive_power.value().abs()),
+            _ => None,
+        })
+        .sum();
+    let mut hi = total_q_load + 100.0;
+    let mut best = with_reactive_sources(&stressed, &deficient, hi);
+    let resolved = best.is_some();
+    if resolved {
+        let mut lo = 0.0;
+        while hi - lo > REACTIVE_SHORTFALL_TOLERANCE_MVAR {
+            let mid = 0.5 * (lo + hi);
+            match with_reactive_sources(&stressed, &deficient, mid) {
+                Some(injections) => {
+                    hi = mid;
+                    best = Some(injections);
+                }
+                None => lo = mid,
+            }
+        }
+    }
+    let injections = best.unwrap_or_default();
+    for bus in &mut deficient {
+        bus.shortfall_mvar = injections.get(&bus.bus).copied().unwrap_or(hi);
+    }
+
+    Ok(ReactiveScenarioResult {
+        load_scale,
+        shortfall_mvar: deficient.iter().map(|b| b.shortfall_mvar).sum(),
+        deficient_buses: deficient,
+        resolved,
+    })
+}
+
+/// Solve with a virtual reactive source of `ceiling_mvar` at each deficient bus.
+/// Returns each source's output (Mvar) by bus name if voltages can be held.
+fn with_reactive_sources(
+    network: &gat_core::Network,
+    deficient: &[ReactiveDeficientBus],
+    ceiling_mvar: f64,
+) -> Option<HashMap<String, f64>> {
+    use gat_core::{Gen, GenId, Node};
+
+    let mut supported = network.clone();
+    let bus_ids: HashMap<String, gat_core::BusId> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => Some((bus.name.clone(), bus.id)),
+            _ => None,
+        })
+        .collect();
+    let first_id = network.graph.node_count();
+    for (k, bus) in deficient.iter().enumerate() {
+        supported.graph.add_node(Node::Gen(
+            Gen::new(
+                GenId::new(first_id + k),
+                format!("reactive_support@{}", bus.bus),
+                bus_ids[&bus.bus],
+            )
+            .with_p_limits(0.0, 0.0)
+            .with_q_limits(0.0, ceiling_mvar),
+        ));
+    }
+
+    let solution = voltage_feasible(&supported)?;
+    Some(
+        deficient
+            .iter()
+            .map(|bus| {
+                let name = format!("reactive_support@{}", bus.bus);
+                let q = solution.generator_q.get(&name).copied().unwrap_or(0.0);
+                (bus.bus.clone(), q.max(0.0))
+            })
+            .collect(),
+    )
+}
+
+/// SOCP solution if it solves with every bus voltage within its bounds.
+fn voltage_feasible(network: &gat_core::Network) -> Option<crate::OpfSolution> {
+    let solution = socp(network).ok()?;
+    let within_bounds = network.graph.node_weights().all(|node| match node {
+        gat_core::Node::Bus(bus) => {
+            let vmin = bus.vmin_pu.map(|v| v.value()).unwrap_or(0.9);
+            solution
+                .bus_voltage_mag
+                .get(&bus.name)
+                .map_or(true, |&v| v >= vmin - VOLTAGE_TOLERANCE_PU)
+        }
+        _ => true,
+    });
+    within_bounds.then_some(solution)
+}
+
+fn socp(network: &gat_core::Network) -> Result<crate::OpfSolution> {
+    let solution = crate::OpfSolver::new()
+        .with_method(crate::OpfMethod::SocpRelaxation)
+        .solve(network)
+        .map_err(|e| anyhow!("SOCP solve failed: {}", e))?;
+    if !solution.converged {
+        return Err(anyhow!(
+            "SOCP solve stopped at its iteration cap ({} iterations)",
+            solution.iterations
+        ));
+    }
+    Ok(solution)
+}
+
+/// Frequency limits checked by [`inertia_adequacy`].
+#[derive(Debug, Clone)]
+pub struct InertiaCriteria {
+    /// Nominal system frequency (Hz)
+    pub nominal_hz: f64,
+    /// Largest acceptable initial rate of change of frequency (Hz/s)
+    pub max_rocof_hz_per_s: f64,
+    /// Lowest acceptable frequency nadir (Hz), e.g. the first UFLS stage
+    pub min_nadir_hz: f64,
+    /// Time for primary frequency response to reach the lost power (s)
+    pub response_time_s: f64,
+}
+
+impl Default for InertiaCriteria {
+    fn default() -> Self {
+        Self {
+            nominal_hz: 60.0,
+            max_rocof_hz_per_s: 1.0,
+            min_nadir_hz: 59.3,
+            response_time_s: 10.0,
+        }
+    }
+}
+
+/// Frequency response after the largest contingency, from [`inertia_adequacy`].
+#[derive(Debug, Clone)]
+pub struct InertiaAdequacyReport {
+    /// Power lost in the contingency (MW)
+    pub contingency_mw: f64,
+    /// Kinetic energy stored in online synchronous machines (MW·s)
+    pub kinetic_energy_mws: f64,
+    /// Smallest kinetic energy that meets both frequency limits (MW·s)
+    pub required_kinetic_energy_mws: f64,
+    /// Initial rate of change of frequency (Hz/s, magnitude)
+    pub rocof_hz_per_s: f64,
+    /// Lowest frequency reached (Hz)
+    pub nadir_hz: f64,
+    pub rocof_ok: bool,
+    pub nadir_ok: bool,
+}
+
+impl InertiaAdequacyReport {
+    /// Whether both RoCoF and nadir stay within limits.
+    pub fn is_adequate(&self) -> bool {
+        self.rocof_ok && self.nadir_ok
+    }
+}
+
+/// Screen frequency response to losing `largest_contingency_mw` with default
+/// [`InertiaCriteria`] (60 Hz, 1 Hz/s RoCoF, 59.3 Hz nadir).
+pub fn inertia_adequacy(
+    network: &gat_core::Network,
+    largest_contingency_mw: f64,
+) -> Result<InertiaAdequacyReport> {
+    inertia_adequacy_with(network, largest_contingency_mw, &InertiaCriteria::default())
+}
+
+/// Screen frequency response to losing `largest_contingency_mw` of supply.
+///
+/// **Model:** The online synchronous fleet, synchronous condensers included,
+/// is one aggregate machine with kinetic energy `E = Σ Hᵢ·Sᵢ`, where `Sᵢ` is
+/// `mbase` (falling back to `pmax`, then output). Units without an inertia
+/// constant (inverter-based resources) contribute nothing. The swing equation
+/// `(2E/f₀)·df/dt = −ΔP + R(t)` then gives:
+/// - RoCoF = `ΔP·f₀ / 2E` at the instant of the trip, before any response;
+/// - with primary response ramping linearly to `ΔP` over `T`, frequency
+///   bottoms out at `t = T` with nadir `f₀ − RoCoF·T/2`.
+///
+/// Load damping is ignored, so the nadir is conservative. The kinetic energy
+/// is that of `network` as given; remove the tripped unit first to screen
+/// post-contingency inertia.
+pub fn inertia_adequacy_with(
+    network: &gat_core::Network,
+    largest_contingency_mw: f64,
+    criteria: &InertiaCriteria,
+) -> Result<InertiaAdequacyReport> {
+    use gat_core::Node;
+
+    if largest_contingency_mw.is_nan() || largest_contingency_mw < 0.0 {
+        return Err(anyhow!(
+            "largest contingency must be a non-negative MW value, got {}",
+            largest_contingency_mw
+        ));
+    }
+    if criteria.min_nadir_hz >= criteria.nominal_hz {
+        return Err(anyhow!("nadir limit must be below nominal frequency"));
+    }
+
+    let kinetic_energy_mws: f64 = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Gen(gen) if gen.status => gen.inertia_h.map(|h| {
+                let rating = gen
+                    .mbase
+                    .map(|s| s.value())
+                    .filter(|s| *s > 0.0)
+                    .or_else(|| Some(gen.pmax.value()).filter(|p| p.is_finite() && *p > 0.0))
+                    .unwrap_or_else(|| gen.active_power.value().abs());
+                h * rating
+            }),
+            _ => None,
+        })
+        .sum();
+
+    let f0 = criteria.nominal_hz;
+    let delta_p = largest_contingency_mw;
+    let (rocof_hz_per_s, nadir_hz) = if delta_p == 0.0 {
+        (0.0, f0)
+    } else if kinetic_energy_mws > 0.0 {
+        let rocof = delta_p * f0 / (2.0 * kinetic_energy_mws);
+        (rocof, f0 - rocof * criteria.response_time_s / 2.0)
+    } else {
+        (f64::INFINITY, f64::NEG_INFINITY)
+    };
+
+    // Invert both formulas for the kinetic energy that just meets each limit
+    let for_rocof = delta_p * f0 / (2.0 * criteria.max_rocof_hz_per_s);
+    let for_nadir = delta_p * f0 * criteria.response_time_s / (4.0 * (f0 - criteria.min_nadir_hz));
+
+    Ok(InertiaAdequacyReport {
+        contingency_mw: delta_p,
+        kinetic_energy_mws,
+        required_kinetic_energy_mws: for_rocof.max(for_nadir),
+        rocof_hz_per_s,
+        nadir_hz,
+        rocof_ok: rocof_hz_per_s <= criteria.max_rocof_hz_per_s,
+        nadir_ok: nadir_hz >= criteria.min_nadir_hz,
+    })
+}
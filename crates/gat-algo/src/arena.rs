@@ -2,8 +2,18 @@
 //!
 //! Provides arena-backed collections that allocate from a bumpalo arena,
 //! enabling O(1) bulk deallocation between Monte Carlo scenarios.
+//!
+//! The context also owns a [`DcScratch`] working set for repeated DC solves.
+//! Unlike arena allocations, these buffers survive [`ArenaContext::reset`] so
+//! that contingency screening reuses the same storage instead of reallocating
+//! per outage set. Other hot loops have no dense DC solve to share: Monte
+//! Carlo adequacy checks only connectivity (with arena temporaries), and
+//! time-series OPF steps go through [`OpfSolver`](crate::OpfSolver), whose
+//! backends manage their own buffers.
 
+use anyhow::{anyhow, Result};
 use bumpalo::Bump;
+use std::cell::{RefCell, RefMut};
 
 /// Arena context for phase-scoped allocations.
 ///
@@ -25,12 +35,16 @@ use bumpalo::Bump;
 /// ```
 pub struct ArenaContext {
     bump: Bump,
+    dc: RefCell<DcScratch>,
 }
 
 impl ArenaContext {
     /// Create new arena context.
     pub fn new() -> Self {
-        Self { bump: Bump::new() }
+        Self {
+            bump: Bump::new(),
+            dc: RefCell::new(DcScratch::default()),
+        }
     }
 
     /// Reset arena for reuse (O(1) operation).
@@ -60,6 +74,176 @@ impl ArenaContext {
     ) -> hashbrown::HashMap<K, V, hashbrown::DefaultHashBuilder, &Bump> {
         hashbrown::HashMap::new_in(&self.bump)
     }
+
+    /// Borrow the DC working buffers, sized for a reduced system of `dim` buses.
+    ///
+    /// The buffers are zeroed on every call but only reallocated when `dim`
+    /// exceeds the largest system seen so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scratch is already borrowed (e.g. nested DC solves on
+    /// the same context).
+    pub fn dc_scratch(&self, dim: usize) -> RefMut<'_, DcScratch> {
+        let mut scratch = self.dc.borrow_mut();
+        scratch.prepare(dim);
+        scratch
+    }
+
+    /// Number of times the reusable solver buffers had to grow.
+    ///
+    /// Stays constant across repeated solves of the same network size, which
+    /// is what makes the context worth threading through hot loops.
+    pub fn buffer_allocations(&self) -> usize {
+        self.dc.borrow().grow_count
+    }
+}
+
+/// Reusable working buffers for a dense DC power-flow solve.
+///
+/// Holds the reduced B' matrix (the DC analogue of the Newton Jacobian, with
+/// the slack row/column removed), the right-hand side injection vector, and
+/// the pivot permutation used by LU factorization. Index 0 of the full bus
+/// ordering is treated as the slack bus.
+///
+/// After [`DcScratch::solve`] the `rhs` buffer holds the non-slack angles.
+#[derive(Debug, Default)]
+pub struct DcScratch {
+    dim: usize,
+    /// Reduced B' matrix in row-major order (`dim × dim`), overwritten by its LU factors
+    pub matrix: Vec<f64>,
+    /// Net injections on entry, bus angles on exit (`dim` entries)
+    pub rhs: Vec<f64>,
+    /// Row permutation from partial pivoting (`dim` entries)
+    pub perm: Vec<usize>,
+    /// Scratch for the permuted right-hand side (`dim` entries)
+    work: Vec<f64>,
+    grow_count: usize,
+}
+
+impl DcScratch {
+    /// Create standalone buffers for a reduced system of `dim` buses.
+    pub fn with_dim(dim: usize) -> Self {
+        let mut scratch = Self::default();
+        scratch.prepare(dim);
+        scratch
+    }
+
+    /// Dimension of the reduced system currently held.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    fn prepare(&mut self, dim: usize) {
+        if self.matrix.capacity() < dim * dim || self.rhs.capacity() < dim {
+            self.grow_count += 1;
+        }
+        self.dim = dim;
+        self.matrix.clear();
+        self.matrix.resize(dim * dim, 0.0);
+        self.rhs.clear();
+        self.rhs.resize(dim, 0.0);
+        self.work.clear();
+        self.work.resize(dim, 0.0);
+        self.perm.clear();
+        self.perm.extend(0..dim);
+    }
+
+    /// Stamp a branch susceptance between full-system bus indices `i` and `j`.
+    ///
+    /// Entries touching the slack bus (index 0) are dropped, which yields the
+    /// reduced matrix directly without assembling the singular full B'.
+    #[inline]
+    pub fn add_branch(&mut self, i: usize, j: usize, b: f64) {
+        let n = self.dim;
+        if i > 0 {
+            self.matrix[(i - 1) * n + (i - 1)] += b;
+        }
+        if j > 0 {
+            self.matrix[(j - 1) * n + (j - 1)] += b;
+        }
+        if i > 0 && j > 0 {
+            self.matrix[(i - 1) * n + (j - 1)] -= b;
+            self.matrix[(j - 1) * n + (i - 1)] -= b;
+        }
+    }
+
+    /// Set the injection at full-system bus index `i` (ignored for the slack bus).
+    #[inline]
+    pub fn set_injection(&mut self, i: usize, p: f64) {
+        if i > 0 {
+            self.rhs[i - 1] = p;
+        }
+    }
+
+    /// Angle at full-system bus index `i` after [`DcScratch::solve`] (slack = 0).
+    #[inline]
+    pub fn angle(&self, i: usize) -> f64 {
+        if i == 0 {
+            0.0
+        } else {
+            self.rhs[i - 1]
+        }
+    }
+
+    /// Solve the reduced system in place using LU with partial pivoting.
+    pub fn solve(&mut self) -> Result<()> {
+        let n = self.dim;
+        let a = &mut self.matrix;
+
+        for k in 0..n {
+            let mut max_val = a[k * n + k].abs();
+            let mut max_row = k;
+            for i in (k + 1)..n {
+                if a[i * n + k].abs() > max_val {
+                    max_val = a[i * n + k].abs();
+                    max_row = i;
+                }
+            }
+
+            if max_val < 1e-12 {
+                return Err(anyhow!("Singular matrix in DC power flow"));
+            }
+
+            if max_row != k {
+                for col in 0..n {
+                    a.swap(k * n + col, max_row * n + col);
+                }
+                self.perm.swap(k, max_row);
+            }
+
+            for i in (k + 1)..n {
+                a[i * n + k] /= a[k * n + k];
+                let factor = a[i * n + k];
+                for j in (k + 1)..n {
+                    a[i * n + j] -= factor * a[k * n + j];
+                }
+            }
+        }
+
+        // Permute b into the work buffer
+        for i in 0..n {
+            self.work[i] = self.rhs[self.perm[i]];
+        }
+
+        // Forward substitution
+        for i in 0..n {
+            for j in 0..i {
+                self.work[i] -= a[i * n + j] * self.work[j];
+            }
+        }
+
+        // Back substitution (result lands in rhs)
+        for i in (0..n).rev() {
+            let mut x = self.work[i];
+            for j in (i + 1)..n {
+                x -= a[i * n + j] * self.rhs[j];
+            }
+            self.rhs[i] = x / a[i * n + i];
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for ArenaContext {
@@ -117,6 +301,40 @@ mod tests {
         assert_eq!(vec2[0], 42);
     }
 
+    #[test]
+    fn test_dc_scratch_reused_across_reset() {
+        let mut ctx = ArenaContext::new();
+        for _ in 0..10 {
+            let mut scratch = ctx.dc_scratch(3);
+            scratch.add_branch(0, 1, 10.0);
+            scratch.add_branch(1, 2, 10.0);
+            scratch.add_branch(2, 3, 10.0);
+            scratch.set_injection(3, -1.0);
+            scratch.set_injection(1, 1.0);
+            scratch.solve().unwrap();
+            drop(scratch);
+            ctx.reset();
+        }
+        assert_eq!(ctx.buffer_allocations(), 1);
+
+        // Growing the system forces exactly one more allocation
+        drop(ctx.dc_scratch(10));
+        assert_eq!(ctx.buffer_allocations(), 2);
+    }
+
+    #[test]
+    fn test_dc_scratch_solve_chain() {
+        // 3-bus chain 0-1-2, b = 10 on each branch, 1 MW withdrawn at bus 2
+        let mut scratch = DcScratch::with_dim(2);
+        scratch.add_branch(0, 1, 10.0);
+        scratch.add_branch(1, 2, 10.0);
+        scratch.set_injection(2, -1.0);
+        scratch.solve().unwrap();
+        assert!((scratch.angle(0)).abs() < 1e-12);
+        assert!((scratch.angle(1) + 0.1).abs() < 1e-12);
+        assert!((scratch.angle(2) + 0.2).abs() < 1e-12);
+    }
+
     #[test]
     fn test_arena_context_is_send() {
         fn assert_send<T: Send>() {}
@@ -1,8 +1,15 @@
+use crate::opf::OpfSolution;
+use crate::power_flow::{compute_dc_angles_sparse, dc_power_flow_angles};
+use crate::reliability_monte_carlo::{failed_components, EventLog, LossOfLoadEvent};
 use crate::{arena::ArenaContext, OutageScenario, ReliabilityMetrics};
 use anyhow::{anyhow, Result};
-use gat_core::{Network, Node, NodeIndex};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Kilovolts, Load, LoadId, Megavars,
+    MegavoltAmperes, Megawatts, Network, Node, NodeIndex,
+};
 use rayon::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
 
 /// Area identifier for multi-area systems
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -213,8 +220,8 @@ impl AreaLoleMetrics {
 struct ScenarioResult {
     /// Whether this scenario has any shortfall
     has_shortfall: bool,
-    /// Per-area shortfall contributions: (LOLE contribution, EUE contribution)
-    area_shortfalls: HashMap<AreaId, (f64, f64)>,
+    /// Per-area shortfall: (LOLE contribution, EUE contribution, unserved MW)
+    area_shortfalls: HashMap<AreaId, (f64, f64, f64)>,
     /// Per-corridor utilization for this scenario
     corridor_utils: HashMap<usize, f64>,
 }
@@ -226,9 +233,10 @@ impl ScenarioResult {
     }
 
     /// Add area shortfall contribution
-    fn add_area_shortfall(&mut self, area_id: AreaId, lole: f64, eue: f64) {
+    fn add_area_shortfall(&mut self, area_id: AreaId, lole: f64, eue: f64, unserved_mw: f64) {
         self.has_shortfall = true;
-        self.area_shortfalls.insert(area_id, (lole, eue));
+        self.area_shortfalls
+            .insert(area_id, (lole, eue, unserved_mw));
     }
 
     /// Add corridor utilization
@@ -243,6 +251,8 @@ pub struct MultiAreaMonteCarlo {
     pub num_scenarios: usize,
     /// Hours per year for LOLE calculation
     pub hours_per_year: f64,
+    /// Parquet file receiving one row per area shortfall event; off when `None`
+    pub event_log: Option<PathBuf>,
 }
 
 impl MultiAreaMonteCarlo {
@@ -251,9 +261,53 @@ impl MultiAreaMonteCarlo {
         Self {
             num_scenarios,
             hours_per_year: 365.25 * 24.0,
+            event_log: None,
         }
     }
 
+    /// Log every area shortfall to a Parquet file at `path`.
+    ///
+    /// A scenario short in two areas yields two rows. Failed components
+    /// list the area's outaged elements plus every offline corridor.
+    pub fn with_event_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.event_log = Some(path.into());
+        self
+    }
+
+    /// Write `(sample, area, unserved MW)` shortfalls to the event log, if enabled
+    fn write_event_log(
+        &self,
+        system: &MultiAreaSystem,
+        scenarios: &[MultiAreaOutageScenario],
+        mut shortfalls: Vec<(usize, AreaId, f64)>,
+    ) -> Result<()> {
+        let Some(path) = &self.event_log else {
+            return Ok(());
+        };
+        shortfalls.sort_by_key(|&(sample, area, _)| (sample, area));
+
+        let mut events = Vec::with_capacity(shortfalls.len());
+        for (sample, area, unserved_mw) in shortfalls {
+            let scenario = &scenarios[sample];
+            let area_scenario = &scenario.area_scenarios[&area];
+            let mut failed = failed_components(&system.areas[&area], area_scenario);
+            let mut corridors: Vec<&usize> = scenario.offline_corridors.iter().collect();
+            corridors.sort();
+            failed.extend(corridors.into_iter().map(|id| format!("corridor:{}", id)));
+            events.push(LossOfLoadEvent {
+                sample,
+                area: Some(area.0),
+                failed_components: failed,
+                unserved_mw,
+                duration_hours: area_scenario.probability * self.hours_per_year,
+            });
+        }
+
+        let mut log = EventLog::create(path)?;
+        log.write(&events)?;
+        log.finish()
+    }
+
     /// Generate multi-area outage scenarios
     pub fn generate_multiarea_scenarios(
         &self,
@@ -358,7 +412,8 @@ impl MultiAreaMonteCarlo {
         }
 
         // Analyze each scenario
-        for scenario in &scenarios {
+        let mut shortfalls = Vec::new();
+        for (sample, scenario) in scenarios.iter().enumerate() {
             let mut any_shortfall = false;
 
             // Per-area analysis
@@ -406,6 +461,7 @@ impl MultiAreaMonteCarlo {
                         *metrics.area_eue.get_mut(area_id).unwrap() += eue_contribution;
                         *metrics.zone_to_zone_lole.get_mut(area_id).unwrap() += lole_contribution;
 
+                        shortfalls.push((sample, *area_id, shortfall));
                         any_shortfall = true;
                     }
                 }
@@ -446,6 +502,8 @@ impl MultiAreaMonteCarlo {
             *util /= self.num_scenarios as f64;
         }
 
+        self.write_event_log(system, &scenarios, shortfalls)?;
+
         Ok(metrics)
     }
 
@@ -472,6 +530,20 @@ impl MultiAreaMonteCarlo {
             })
             .collect();
 
+        if self.event_log.is_some() {
+            let shortfalls = scenario_results
+                .iter()
+                .enumerate()
+                .flat_map(|(sample, result)| {
+                    result
+                        .area_shortfalls
+                        .iter()
+                        .map(move |(area, &(_, _, unserved))| (sample, *area, unserved))
+                })
+                .collect();
+            self.write_event_log(system, &scenarios, shortfalls)?;
+        }
+
         // Reduce results into metrics
         self.reduce_scenario_results(system, scenario_results)
     }
@@ -531,7 +603,12 @@ impl MultiAreaMonteCarlo {
                     let lole_contribution = area_scenario.probability;
                     let eue_contribution = shortfall * area_scenario.probability;
 
-                    result.add_area_shortfall(*area_id, lole_contribution, eue_contribution);
+                    result.add_area_shortfall(
+                        *area_id,
+                        lole_contribution,
+                        eue_contribution,
+                        shortfall,
+                    );
                 }
             }
         }
@@ -572,7 +649,7 @@ impl MultiAreaMonteCarlo {
                 metrics.scenarios_with_shortfall += 1;
             }
 
-            for (area_id, (lole, eue)) in &result.area_shortfalls {
+            for (area_id, (lole, eue, _)) in &result.area_shortfalls {
                 *metrics.area_lole.get_mut(area_id).unwrap() += lole;
                 *metrics.area_eue.get_mut(area_id).unwrap() += eue;
                 *metrics.zone_to_zone_lole.get_mut(area_id).unwrap() += lole;
@@ -633,6 +710,7 @@ impl MultiAreaMonteCarlo {
             } else {
                 0.0
             },
+            outage_cost: None,
         })
     }
 }
@@ -642,3 +720,425 @@ impl Default for MultiAreaMonteCarlo {
         Self::new(1000)
     }
 }
+
+/// Equivalent tie between two areas of a reduced network
+#[derive(Debug, Clone)]
+pub struct AreaTie {
+    /// Lower-numbered area (the equivalent branch's from bus)
+    pub area_a: AreaId,
+    /// Higher-numbered area (the equivalent branch's to bus)
+    pub area_b: AreaId,
+    /// Original in-service branches crossing between the two areas
+    pub branches: Vec<BranchId>,
+    /// Base-case DC flow from `area_a` to `area_b` (MW)
+    pub base_flow_mw: f64,
+    /// Equivalent series reactance of the tie (p.u.)
+    pub reactance_pu: f64,
+}
+
+/// Area-level equivalent of a bus-level network
+#[derive(Debug)]
+pub struct AreaReduction {
+    /// One bus per area, numbered by area ID, joined by equivalent ties
+    pub network: Network,
+    /// Retained ties, ordered by area pair
+    pub ties: Vec<AreaTie>,
+}
+
+/// Area of a bus from `Bus::area_id`
+fn area_of(bus: &Bus) -> Result<AreaId> {
+    match bus.area_id {
+        Some(id) if id >= 0 => Ok(AreaId(id as usize)),
+        Some(id) => Err(anyhow!("bus {} has negative area {}", bus.id.value(), id)),
+        None => Err(anyhow!("bus {} has no area assigned", bus.id.value())),
+    }
+}
+
+/// Reduce a network to one equivalent bus per area.
+///
+/// Buses are grouped by `Bus::area_id`. Each area's generators and loads are
+/// summed onto its equivalent bus, so the area keeps its net injection. The
+/// branches crossing between two areas collapse into one equivalent tie whose
+/// reactance reproduces the base-case DC inter-area flow: with the area angle
+/// taken as the mean of its bus angles, `x = (θ_a - θ_b) / F_ab`. Since every
+/// area's injection equals its net tie export, the reduced DC power flow
+/// returns those same angles and flows, meshed area graphs included.
+///
+/// Where that ratio is not usable (no base flow, or flow against the angle
+/// difference) the tie falls back to the parallel reactance of its branches.
+/// Equivalent generators carry no cost curve.
+pub fn reduce_to_areas(network: &Network) -> Result<AreaReduction> {
+    let mut bus_area: HashMap<usize, AreaId> = HashMap::new();
+    let mut base_kv: HashMap<AreaId, f64> = HashMap::new();
+    for node in network.graph.node_weights() {
+        if let Node::Bus(bus) = node {
+            let area = area_of(bus)?;
+            bus_area.insert(bus.id.value(), area);
+            let kv = base_kv.entry(area).or_insert(0.0);
+            *kv = kv.max(bus.base_kv.value());
+        }
+    }
+    if bus_area.is_empty() {
+        return Err(anyhow!("network has no buses to reduce"));
+    }
+
+    let angles = dc_power_flow_angles(network)?;
+    let mean_angle = {
+        let mut sums: HashMap<AreaId, (f64, usize)> = HashMap::new();
+        for (bus, area) in &bus_area {
+            let entry = sums.entry(*area).or_insert((0.0, 0));
+            entry.0 += angles.get(bus).copied().unwrap_or(0.0);
+            entry.1 += 1;
+        }
+        sums.into_iter()
+            .map(|(area, (sum, count))| (area, sum / count as f64))
+            .collect::<HashMap<_, _>>()
+    };
+
+    // Accumulate tie branches per area pair, oriented from the lower area
+    struct TieAcc {
+        branches: Vec<BranchId>,
+        flow_mw: f64,
+        susceptance: f64,
+        rating_mva: Option<f64>,
+    }
+    let mut ties: BTreeMap<(AreaId, AreaId), TieAcc> = BTreeMap::new();
+    for edge in network.graph.edge_weights() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        if !branch.status {
+            continue;
+        }
+        let from = branch.from_bus.value();
+        let to = branch.to_bus.value();
+        let (Some(&area_from), Some(&area_to)) = (bus_area.get(&from), bus_area.get(&to)) else {
+            continue;
+        };
+        if area_from == area_to {
+            continue;
+        }
+        // Same DC branch model as the angle solve
+        let reactance = branch.reactance * branch.tap_ratio;
+        let reactance = if reactance.abs() < 1e-12 {
+            1e-6_f64.copysign(reactance)
+        } else {
+            reactance
+        };
+        let theta_from = angles.get(&from).copied().unwrap_or(0.0);
+        let theta_to = angles.get(&to).copied().unwrap_or(0.0);
+        let (key, sign) = if area_from < area_to {
+            ((area_from, area_to), 1.0)
+        } else {
+            ((area_to, area_from), -1.0)
+        };
+        let acc = ties.entry(key).or_insert(TieAcc {
+            branches: Vec::new(),
+            flow_mw: 0.0,
+            susceptance: 0.0,
+            rating_mva: Some(0.0),
+        });
+        acc.branches.push(branch.id);
+        acc.flow_mw += sign * (theta_from - theta_to) / reactance;
+        acc.susceptance += 1.0 / reactance;
+        let rating = branch.s_max.or(branch.rating_a).map(|r| r.value());
+        acc.rating_mva = acc.rating_mva.zip(rating).map(|(sum, r)| sum + r);
+    }
+
+    // Equivalent buses, generators and loads
+    let mut reduced = Network::new();
+    let areas: BTreeSet<AreaId> = bus_area.values().copied().collect();
+    let mut area_nodes: HashMap<AreaId, NodeIndex> = HashMap::new();
+    for area in &areas {
+        let node = reduced.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(area.0),
+            name: format!("area{}", area.0),
+            base_kv: Kilovolts(base_kv[area]),
+            area_id: Some(area.0 as i64),
+            ..Bus::default()
+        }));
+        area_nodes.insert(*area, node);
+    }
+
+    let mut gens: BTreeMap<AreaId, Gen> = BTreeMap::new();
+    let mut loads: BTreeMap<AreaId, (f64, f64)> = BTreeMap::new();
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Gen(gen) => {
+                let Some(&area) = bus_area.get(&gen.bus.value()) else {
+                    continue;
+                };
+                let equiv = gens.entry(area).or_insert_with(|| Gen {
+                    id: GenId::new(area.0),
+                    name: format!("area{}_gen", area.0),
+                    bus: BusId::new(area.0),
+                    pmin: Megawatts(0.0),
+                    pmax: Megawatts(0.0),
+                    qmin: Megavars(0.0),
+                    qmax: Megavars(0.0),
+                    cost_model: CostModel::NoCost,
+                    ..Gen::default()
+                });
+                equiv.active_power = equiv.active_power + gen.active_power;
+                equiv.reactive_power = equiv.reactive_power + gen.reactive_power;
+                equiv.pmin = equiv.pmin + gen.pmin;
+                equiv.pmax = equiv.pmax + gen.pmax;
+                equiv.qmin = equiv.qmin + gen.qmin;
+                equiv.qmax = equiv.qmax + gen.qmax;
+            }
+            Node::Load(load) => {
+                if let Some(&area) = bus_area.get(&load.bus.value()) {
+                    let equiv = loads.entry(area).or_insert((0.0, 0.0));
+                    equiv.0 += load.active_power.value();
+                    equiv.1 += load.reactive_power.value();
+                }
+            }
+            _ => {}
+        }
+    }
+    for gen in gens.into_values() {
+        reduced.graph.add_node(Node::Gen(gen));
+    }
+    for (area, (p, q)) in loads {
+        reduced.graph.add_node(Node::Load(Load {
+            id: LoadId::new(area.0),
+            name: format!("area{}_load", area.0),
+            bus: BusId::new(area.0),
+            active_power: Megawatts(p),
+            reactive_power: Megavars(q),
+            zip: None,
+        }));
+    }
+
+    // Equivalent ties
+    let mut retained = Vec::with_capacity(ties.len());
+    for (id, ((area_a, area_b), acc)) in ties.into_iter().enumerate() {
+        let angle_diff = mean_angle[&area_a] - mean_angle[&area_b];
+        let matched = angle_diff / acc.flow_mw;
+        let reactance_pu = if acc.flow_mw.abs() > 1e-9 && matched.is_finite() && matched > 0.0 {
+            matched
+        } else {
+            1.0 / acc.susceptance
+        };
+        let mut tie = Branch::new(
+            BranchId::new(id),
+            format!("tie_area{}_area{}", area_a.0, area_b.0),
+            BusId::new(area_a.0),
+            BusId::new(area_b.0),
+            0.0,
+            reactance_pu,
+        );
+        if let Some(rating) = acc.rating_mva {
+            tie.s_max = Some(MegavoltAmperes(rating));
+            tie.rating_a = Some(MegavoltAmperes(rating));
+        }
+        reduced
+            .graph
+            .add_edge(area_nodes[&area_a], area_nodes[&area_b], Edge::Branch(tie));
+        retained.push(AreaTie {
+            area_a,
+            area_b,
+            branches: acc.branches,
+            base_flow_mw: acc.flow_mw,
+            reactance_pu,
+        });
+    }
+
+    Ok(AreaReduction {
+        network: reduced,
+        ties: retained,
+    })
+}
+
+/// Share of one inter-area branch's flow carried for each area-to-area
+/// transaction
+#[derive(Debug, Clone)]
+pub struct TieFlowAttribution {
+    /// The tie branch
+    pub branch: BranchId,
+    /// Area of the branch's from bus
+    pub from_area: AreaId,
+    /// Area of the branch's to bus
+    pub to_area: AreaId,
+    /// DC flow from `from_area` to `to_area` (MW)
+    pub flow_mw: f64,
+    /// Flow carried for each (source area, sink area) transaction (MW);
+    /// sums to `flow_mw`
+    pub contributions: BTreeMap<(AreaId, AreaId), f64>,
+}
+
+/// Tie flows of a dispatch decomposed into area-to-area transactions
+#[derive(Debug, Clone)]
+pub struct TieFlowAllocation {
+    /// Scheduled MW of each (source area, sink area) transaction
+    pub transactions: BTreeMap<(AreaId, AreaId), f64>,
+    /// In-service branches between areas, ordered by branch ID
+    pub ties: Vec<TieFlowAttribution>,
+}
+
+/// Attribute the inter-area tie flows of a dispatch to area-to-area
+/// transactions.
+///
+/// Generation is matched to load pro rata: generator `g` serves load `l`
+/// with `P_g · L_l / ΣL` MW, where loads are net of any shedding in
+/// `solution`. Each transaction flows along its PTDF path, so the share of
+/// tie `t` carried for it is `P_g · L_l / ΣL · (PTDF_t,g − PTDF_t,l)`. Summed
+/// by source and sink area this gives one DC power flow per area pair, and
+/// because DC flows are linear the shares of every tie add up to its flow.
+/// Transactions within an area can still load the ties through loop flow.
+///
+/// The dispatch must balance the served load; losses are not attributed.
+pub fn tie_flow_attribution(
+    network: &Network,
+    solution: &OpfSolution,
+) -> Result<TieFlowAllocation> {
+    let mut bus_area: HashMap<usize, AreaId> = HashMap::new();
+    for node in network.graph.node_weights() {
+        if let Node::Bus(bus) = node {
+            bus_area.insert(bus.id.value(), area_of(bus)?);
+        }
+    }
+
+    // Per-area injection patterns of generation and served load
+    let mut gen_by_area: BTreeMap<AreaId, HashMap<usize, f64>> = BTreeMap::new();
+    let mut load_by_area: BTreeMap<AreaId, HashMap<usize, f64>> = BTreeMap::new();
+    for node in network.graph.node_weights() {
+        let (bus, mw, by_area) = match node {
+            Node::Gen(gen) if gen.status => {
+                let Some(&p) = solution.generator_p.get(&gen.name) else {
+                    continue;
+                };
+                (gen.bus, p, &mut gen_by_area)
+            }
+            Node::Load(load) => {
+                let shed = solution.load_shed_mw.get(&load.name).copied();
+                (
+                    load.bus,
+                    load.active_power.value() - shed.unwrap_or(0.0),
+                    &mut load_by_area,
+                )
+            }
+            _ => continue,
+        };
+        let area = *bus_area
+            .get(&bus.value())
+            .ok_or_else(|| anyhow!("bus {} is not in the network", bus.value()))?;
+        *by_area
+            .entry(area)
+            .or_default()
+            .entry(bus.value())
+            .or_insert(0.0) += mw;
+    }
+
+    let area_total = |by_area: &BTreeMap<AreaId, HashMap<usize, f64>>| -> BTreeMap<AreaId, f64> {
+        by_area
+            .iter()
+            .map(|(area, buses)| (*area, buses.values().sum()))
+            .collect()
+    };
+    let gen_total = area_total(&gen_by_area);
+    let load_total = area_total(&load_by_area);
+    let total_gen: f64 = gen_total.values().sum();
+    let total_load: f64 = load_total.values().sum();
+    if total_load <= 0.0 {
+        return Err(anyhow!("network has no served load to attribute"));
+    }
+    if (total_gen - total_load).abs() > 1e-6 * total_load.max(1.0) {
+        return Err(anyhow!(
+            "dispatch of {:.3} MW does not balance {:.3} MW of load",
+            total_gen,
+            total_load
+        ));
+    }
+
+    // Tie branches with the same DC model as the reduction
+    let ties: BTreeMap<BranchId, (usize, usize, f64, AreaId, AreaId)> = network
+        .graph
+        .edge_weights()
+        .filter_map(|edge| match edge {
+            Edge::Branch(branch) if branch.status => Some(branch),
+            _ => None,
+        })
+        .filter_map(|branch| {
+            let from = branch.from_bus.value();
+            let to = branch.to_bus.value();
+            let (&area_from, &area_to) = (bus_area.get(&from)?, bus_area.get(&to)?);
+            if area_from == area_to {
+                return None;
+            }
+            let reactance = branch.reactance * branch.tap_ratio;
+            let reactance = if reactance.abs() < 1e-12 {
+                1e-6_f64.copysign(reactance)
+            } else {
+                reactance
+            };
+            Some((branch.id, (from, to, reactance, area_from, area_to)))
+        })
+        .collect();
+
+    // DC flow on each tie for a balanced injection pattern
+    let tie_flows = |injections: &HashMap<usize, f64>| -> Result<Vec<f64>> {
+        let angles = compute_dc_angles_sparse(network, injections)?;
+        Ok(ties
+            .values()
+            .map(|&(from, to, reactance, _, _)| {
+                let theta_from = angles.get(&from).copied().unwrap_or(0.0);
+                let theta_to = angles.get(&to).copied().unwrap_or(0.0);
+                (theta_from - theta_to) / reactance
+            })
+            .collect())
+    };
+
+    let mut net_injections: HashMap<usize, f64> = HashMap::new();
+    for (buses, sign) in gen_by_area
+        .values()
+        .map(|buses| (buses, 1.0))
+        .chain(load_by_area.values().map(|buses| (buses, -1.0)))
+    {
+        for (bus, mw) in buses {
+            *net_injections.entry(*bus).or_insert(0.0) += sign * mw;
+        }
+    }
+    let base_flows = tie_flows(&net_injections)?;
+
+    let mut allocation = TieFlowAllocation {
+        transactions: BTreeMap::new(),
+        ties: ties
+            .iter()
+            .zip(base_flows)
+            .map(
+                |((&branch, &(_, _, _, from_area, to_area)), flow_mw)| TieFlowAttribution {
+                    branch,
+                    from_area,
+                    to_area,
+                    flow_mw,
+                    contributions: BTreeMap::new(),
+                },
+            )
+            .collect(),
+    };
+
+    for (source, gens) in &gen_by_area {
+        for (sink, loads) in &load_by_area {
+            let scheduled = gen_total[source] * load_total[sink] / total_load;
+            if scheduled.abs() < 1e-12 {
+                continue;
+            }
+            allocation.transactions.insert((*source, *sink), scheduled);
+
+            // Σ_g Σ_l P_g·L_l/ΣL (e_g − e_l), collected by bus
+            let mut injections: HashMap<usize, f64> = HashMap::new();
+            for (bus, p) in gens {
+                *injections.entry(*bus).or_insert(0.0) += p * load_total[sink] / total_load;
+            }
+            for (bus, l) in loads {
+                *injections.entry(*bus).or_insert(0.0) -= l * gen_total[source] / total_load;
+            }
+            for (tie, share) in allocation.ties.iter_mut().zip(tie_flows(&injections)?) {
+                tie.contributions.insert((*source, *sink), share);
+            }
+        }
+    }
+
+    Ok(allocation)
+}
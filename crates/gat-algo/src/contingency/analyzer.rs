@@ -0,0 +1,204 @@
+//! Reusable contingency analysis for interactive what-if queries.
+//!
+//! [`screen_n1`](super::screen_n1) and [`NkScreener::new`] rebuild PTDF and
+//! LODF on every call, and the caller has to supply base-case flows.
+//! [`ContingencyAnalyzer`] factors the network once, solves its base case with
+//! the same PTDF, and answers every later query from those factors, so asking
+//! "what if this branch trips" costs one pass over the branch list.
+//!
+//! The factors describe the network as it was when analyzed. Any later change
+//! to it (topology, impedances or injections) makes them stale:
+//! [`ContingencyAnalyzer::is_valid_for`] compares network fingerprints and
+//! [`ContingencyAnalyzer::refresh`] re-analyzes a changed network.
+
+use super::n_k::{
+    collect_injections, Contingency, NkScreener, NkScreeningConfig, NkScreeningResults,
+    ScreeningResult,
+};
+use crate::sparse::SparsePtdf;
+use anyhow::Result;
+use gat_core::{BranchId, Network};
+use std::collections::HashMap;
+
+/// PTDF/LODF factors and base-case flows of one network, kept for repeated
+/// contingency screening.
+pub struct ContingencyAnalyzer {
+    screener: NkScreener,
+    config: NkScreeningConfig,
+    fingerprint: u64,
+}
+
+impl ContingencyAnalyzer {
+    /// Factor `network` and solve its base-case DC flows from the generator
+    /// and load injections it holds.
+    pub fn new(network: &Network, config: NkScreeningConfig) -> Result<Self> {
+        let ptdf = SparsePtdf::compute_ptdf(network)?;
+        let lodf = SparsePtdf::compute_lodf(network, &ptdf)?;
+
+        let injections = collect_injections(network);
+        let base_flows = ptdf
+            .branch_ids
+            .iter()
+            .enumerate()
+            .map(|(row, &branch)| {
+                let flow = ptdf
+                    .bus_ids
+                    .iter()
+                    .enumerate()
+                    .map(|(col, bus)| {
+                        ptdf.get_by_idx(row, col) * injections.get(bus).copied().unwrap_or(0.0)
+                    })
+                    .sum();
+                (branch, flow)
+            })
+            .collect();
+
+        Ok(Self {
+            screener: NkScreener::from_factors(ptdf, lodf, base_flows, config.clone()),
+            config,
+            fingerprint: network.fingerprint(),
+        })
+    }
+
+    /// Base-case flow on each branch in MW.
+    pub fn base_flows(&self) -> &HashMap<BranchId, f64> {
+        self.screener.base_flows()
+    }
+
+    /// Whether `network` is still the network that was analyzed.
+    pub fn is_valid_for(&self, network: &Network) -> bool {
+        network.fingerprint() == self.fingerprint
+    }
+
+    /// Re-analyze `network` if it changed since it was analyzed, keeping the
+    /// screening configuration. Returns whether the factors were rebuilt.
+    pub fn refresh(&mut self, network: &Network) -> Result<bool> {
+        if self.is_valid_for(network) {
+            return Ok(false);
+        }
+        *self = Self::new(network, self.config.clone())?;
+        Ok(true)
+    }
+
+    /// Screen one contingency against the post-contingency limits.
+    pub fn screen(&self, contingency: &Contingency) -> ScreeningResult {
+        self.screener.screen_contingency(contingency)
+    }
+
+    /// Screen the base case and every N-1 outage, plus every N-2 outage when
+    /// the configuration's `max_k` is at least 2.
+    pub fn screen_all(&self) -> NkScreeningResults {
+        let mut results = self.screener.screen_n1_n2();
+        results.base_case = Some(self.screener.screen_base_case());
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::ArenaContext;
+    use crate::contingency::{dc_branch_flows_in, screen_nk_contingencies};
+    use gat_core::{
+        Branch, Bus, BusId, Gen, GenId, Load, LoadId, Megavars, MegavoltAmperes, Megawatts, Node,
+    };
+
+    /// Four-bus ring with a chord, 150 MW from bus 1 to load at buses 3 and 4.
+    fn ring() -> Network {
+        let mut network = Network::new();
+        for i in 1..=4 {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                ..Bus::default()
+            }));
+        }
+        for (id, from, to, x) in [
+            (1, 1, 2, 0.1),
+            (2, 2, 3, 0.1),
+            (3, 3, 4, 0.2),
+            (4, 4, 1, 0.1),
+            (5, 1, 3, 0.3),
+        ] {
+            let mut branch = Branch::new(
+                BranchId::new(id),
+                format!("line{}_{}", from, to),
+                BusId::new(from),
+                BusId::new(to),
+                0.0,
+                x,
+            );
+            branch.rating_a = Some(MegavoltAmperes(80.0));
+            network.add_branch_checked(branch).unwrap();
+        }
+        let mut gen = Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1));
+        gen.active_power = Megawatts(150.0);
+        network.graph.add_node(Node::Gen(gen));
+        for (id, bus, mw) in [(1, 3, 90.0), (2, 4, 60.0)] {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(id),
+                name: format!("load{}", bus),
+                bus: BusId::new(bus),
+                active_power: Megawatts(mw),
+                reactive_power: Megavars(0.0),
+                zip: None,
+            }));
+        }
+        network
+    }
+
+    #[test]
+    fn test_repeated_queries_match_fresh_analysis() {
+        let network = ring();
+        let config = NkScreeningConfig {
+            threshold_fraction: 1.0,
+            ..NkScreeningConfig::default()
+        }
+        .with_rating_policy(&network, Default::default());
+        let analyzer = ContingencyAnalyzer::new(&network, config.clone()).unwrap();
+
+        // Base case solved independently of the analyzer's factors
+        let fresh_flows = dc_branch_flows_in(
+            &network,
+            &collect_injections(&network),
+            &[],
+            &mut ArenaContext::new(),
+        )
+        .unwrap();
+        for (branch, flow) in &fresh_flows {
+            assert!((analyzer.base_flows()[branch] - flow).abs() < 1e-6);
+        }
+
+        let fresh = screen_nk_contingencies(&network, fresh_flows, config).unwrap();
+        assert!(fresh.num_flagged > 0);
+        for expected in &fresh.results {
+            // Ask twice to make sure queries leave the factors untouched
+            for _ in 0..2 {
+                let result = analyzer.screen(&expected.contingency);
+                assert_eq!(result.flagged, expected.flagged);
+                assert_eq!(result.most_loaded_branch, expected.most_loaded_branch);
+                assert!((result.max_loading_fraction - expected.max_loading_fraction).abs() < 1e-9);
+                assert_eq!(result.violations.len(), expected.violations.len());
+            }
+        }
+        let all = analyzer.screen_all();
+        assert_eq!(all.total_screened, fresh.total_screened);
+        assert_eq!(all.num_flagged, fresh.num_flagged);
+        assert!(all.base_case.is_some());
+    }
+
+    #[test]
+    fn test_changed_network_invalidates_analyzer() {
+        let mut network = ring();
+        let mut analyzer =
+            ContingencyAnalyzer::new(&network, NkScreeningConfig::default()).unwrap();
+        assert!(analyzer.is_valid_for(&network));
+        assert!(!analyzer.refresh(&network).unwrap());
+
+        network.set_branch_status(BranchId::new(5), false).unwrap();
+        assert!(!analyzer.is_valid_for(&network));
+        assert!(analyzer.refresh(&network).unwrap());
+        assert!(analyzer.is_valid_for(&network));
+        assert!(!analyzer.base_flows().contains_key(&BranchId::new(5)));
+    }
+}
@@ -0,0 +1,288 @@
+//! Parquet export of contingency violations.
+//!
+//! Violations are written one row per violated element per contingency, so a
+//! notebook can pivot them directly in DuckDB
+//! (`SELECT contingency_id, COUNT(*) ... GROUP BY 1`):
+//!
+//! | Column           | Type   | Meaning                                          |
+//! |------------------|--------|--------------------------------------------------|
+//! | `contingency_id` | string | Contingency label, else its outaged elements     |
+//! | `element_type`   | string | Violated element kind (`branch`)                 |
+//! | `element_id`     | int64  | ID of the violated element                       |
+//! | `quantity`       | string | Violated quantity (`flow_mw`)                    |
+//! | `limit`          | double | Limit in the quantity's units                    |
+//! | `value`          | double | Post-contingency value                           |
+//! | `overload_pct`   | double | Percent above the limit                          |
+//!
+//! The footer's key-value metadata records what the violations are relative to:
+//!
+//! | Key                       | Meaning                                          |
+//! |---------------------------|--------------------------------------------------|
+//! | `gat_result_type`         | `contingency`                                    |
+//! | `gat_schema_version`      | Result schema version                            |
+//! | `solver`                  | `dc-nminusk`                                     |
+//! | `base_case`               | Base case the outages were applied to            |
+//! | `limit_set`               | Limit set the violations are measured against    |
+//! | `contingencies_evaluated` | Contingencies evaluated, violated or not         |
+
+use super::n_k::{Contingency, NkEvaluationResults};
+use anyhow::{Context, Result};
+use gat_schemas::result::{key_value, result_types, writer_properties, ResultMetadata};
+use parquet::data_type::{ByteArray, ByteArrayType, DoubleType, Int64Type};
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+const VIOLATION_SCHEMA: &str = "message violations {
+    required binary contingency_id (UTF8);
+    required binary element_type (UTF8);
+    required int64 element_id;
+    required binary quantity (UTF8);
+    required double limit;
+    required double value;
+    required double overload_pct;
+}";
+
+/// Solver tag for LODF screening followed by DC flow evaluation
+const CONTINGENCY_SOLVER: &str = "dc-nminusk";
+
+/// Identifies the study a violations file belongs to.
+#[derive(Debug, Clone)]
+pub struct ViolationExportTags {
+    /// Base case the contingencies were applied to (e.g. `ieee14_summer_peak`)
+    pub base_case: String,
+    /// Limit set the violations are measured against (e.g. `rate_a`)
+    pub limit_set: String,
+}
+
+impl ViolationExportTags {
+    pub fn new(base_case: impl Into<String>, limit_set: impl Into<String>) -> Self {
+        Self {
+            base_case: base_case.into(),
+            limit_set: limit_set.into(),
+        }
+    }
+}
+
+/// Write every violation in `results` to `path` in the standard violation schema.
+pub fn write_violations_parquet(
+    results: &NkEvaluationResults,
+    path: &Path,
+    tags: &ViolationExportTags,
+) -> Result<()> {
+    let mut contingency_ids = Vec::new();
+    let mut element_ids = Vec::new();
+    let mut limits = Vec::new();
+    let mut values = Vec::new();
+    let mut overload_pcts = Vec::new();
+    for evaluation in &results.evaluations {
+        let id = contingency_id(&evaluation.contingency);
+        for violation in &evaluation.violations {
+            contingency_ids.push(ByteArray::from(id.as_str()));
+            element_ids.push(violation.branch_id.value() as i64);
+            limits.push(violation.limit_mw);
+            values.push(violation.flow_mw);
+            overload_pcts.push((violation.loading_fraction - 1.0) * 100.0);
+        }
+    }
+    let element_types = vec![ByteArray::from("branch"); element_ids.len()];
+    let quantities = vec![ByteArray::from("flow_mw"); element_ids.len()];
+
+    let result_tags =
+        ResultMetadata::new(result_types::CONTINGENCY).with_solver(CONTINGENCY_SOLVER);
+    let metadata = vec![
+        key_value("base_case", &tags.base_case),
+        key_value("limit_set", &tags.limit_set),
+        key_value("contingencies_evaluated", results.evaluations.len()),
+    ];
+    let schema = parse_message_type(VIOLATION_SCHEMA).context("building violation schema")?;
+    let props = writer_properties(&result_tags, metadata);
+
+    let file = File::create(path)
+        .with_context(|| format!("creating Parquet file at {}", path.display()))?;
+    let mut writer = SerializedFileWriter::new(file, Arc::new(schema), Arc::new(props))
+        .context("creating Parquet writer")?;
+    let mut row_group = writer.next_row_group().context("starting row group")?;
+    for (strings, column) in [
+        (&contingency_ids, "contingency_id"),
+        (&element_types, "element_type"),
+    ] {
+        let mut col = row_group
+            .next_column()?
+            .context("violation schema has too few columns")?;
+        col.typed::<ByteArrayType>()
+            .write_batch(strings, None, None)
+            .with_context(|| format!("writing {} column", column))?;
+        col.close()?;
+    }
+    let mut col = row_group
+        .next_column()?
+        .context("violation schema has too few columns")?;
+    col.typed::<Int64Type>()
+        .write_batch(&element_ids, None, None)
+        .context("writing element_id column")?;
+    col.close()?;
+    let mut col = row_group
+        .next_column()?
+        .context("violation schema has too few columns")?;
+    col.typed::<ByteArrayType>()
+        .write_batch(&quantities, None, None)
+        .context("writing quantity column")?;
+    col.close()?;
+    for (numbers, column) in [
+        (&limits, "limit"),
+        (&values, "value"),
+        (&overload_pcts, "overload_pct"),
+    ] {
+        let mut col = row_group
+            .next_column()?
+            .context("violation schema has too few columns")?;
+        col.typed::<DoubleType>()
+            .write_batch(numbers, None, None)
+            .with_context(|| format!("writing {} column", column))?;
+        col.close()?;
+    }
+    row_group.close()?;
+    writer.close().context("finalizing Parquet file")?;
+    Ok(())
+}
+
+/// The contingency's label, or its outaged elements as in contingency specs.
+fn contingency_id(contingency: &Contingency) -> String {
+    if let Some(label) = &contingency.label {
+        return label.clone();
+    }
+    contingency
+        .outaged_branches
+        .iter()
+        .map(|id| format!("branch {}", id.value()))
+        .chain(
+            contingency
+                .outaged_generators
+                .iter()
+                .map(|id| format!("gen {}", id.value())),
+        )
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contingency::{BranchViolation, ContingencyEvaluation};
+    use gat_core::BranchId;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::RowAccessor;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn evaluation(
+        contingency: Contingency,
+        violations: &[(usize, f64, f64)],
+    ) -> ContingencyEvaluation {
+        ContingencyEvaluation {
+            contingency,
+            converged: true,
+            branch_flows: HashMap::new(),
+            max_loading: 0.0,
+            critical_branch: None,
+            violations: violations
+                .iter()
+                .map(|&(branch, flow, limit)| BranchViolation {
+                    branch_id: BranchId::new(branch),
+                    flow_mw: flow,
+                    limit_mw: limit,
+                    loading_fraction: flow / limit,
+                })
+                .collect(),
+            load_shed_mw: 0.0,
+            eue_contribution_mwh: 0.0,
+            severity_index: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_violations_round_trip() {
+        let results = NkEvaluationResults {
+            evaluations: vec![
+                evaluation(
+                    Contingency::single(BranchId::new(1)).with_label("Line 1-2"),
+                    &[(2, 120.0, 100.0), (3, 55.0, 50.0)],
+                ),
+                evaluation(Contingency::single(BranchId::new(2)), &[]),
+                evaluation(
+                    Contingency::double(BranchId::new(1), BranchId::new(4)),
+                    &[(3, 75.0, 50.0)],
+                ),
+            ],
+            num_violated: 2,
+            num_non_convergent: 0,
+            worst_loading: 1.5,
+            worst_contingency: None,
+            total_eue_mwh: 0.0,
+            total_load_shed_mw: 0.0,
+        };
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("violations.parquet");
+        let tags = ViolationExportTags::new("ieee14_peak", "rate_a");
+        write_violations_parquet(&results, &path, &tags).unwrap();
+
+        let reader = SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+        let file_metadata = reader.metadata().file_metadata();
+        let names: Vec<&str> = file_metadata
+            .schema_descr()
+            .root_schema()
+            .get_fields()
+            .iter()
+            .map(|f| f.name())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "contingency_id",
+                "element_type",
+                "element_id",
+                "quantity",
+                "limit",
+                "value",
+                "overload_pct"
+            ]
+        );
+        let tag = |key: &str| {
+            file_metadata
+                .key_value_metadata()
+                .and_then(|kvs| kvs.iter().find(|kv| kv.key == key))
+                .and_then(|kv| kv.value.clone())
+        };
+        assert_eq!(tag("gat_result_type").as_deref(), Some("contingency"));
+        assert_eq!(
+            tag("gat_schema_version").as_deref(),
+            Some(gat_schemas::result::RESULT_SCHEMA_VERSION)
+        );
+        assert_eq!(tag("solver").as_deref(), Some(CONTINGENCY_SOLVER));
+        assert_eq!(tag("base_case").as_deref(), Some("ieee14_peak"));
+        assert_eq!(tag("limit_set").as_deref(), Some("rate_a"));
+        assert_eq!(tag("contingencies_evaluated").as_deref(), Some("3"));
+
+        // Violations per contingency, as the notebook's GROUP BY would count them
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for row in reader.get_row_iter(None).unwrap() {
+            let row = row.unwrap();
+            *counts
+                .entry(row.get_string(0).unwrap().clone())
+                .or_default() += 1;
+            assert_eq!(row.get_string(1).unwrap(), "branch");
+            assert_eq!(row.get_string(3).unwrap(), "flow_mw");
+            if row.get_long(2).unwrap() == 2 {
+                assert_eq!(row.get_double(4).unwrap(), 100.0);
+                assert_eq!(row.get_double(5).unwrap(), 120.0);
+                assert!((row.get_double(6).unwrap() - 20.0).abs() < 1e-9);
+            }
+        }
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["Line 1-2"], 2);
+        assert_eq!(counts["branch 1 + branch 4"], 1);
+    }
+}
@@ -22,17 +22,57 @@
 //! 3. Flag combinations where estimated flows exceed 90% of limits
 //! 4. Run full DC power flow only on flagged cases (~1-5% of total)
 //!
+//! ## Interactive Queries
+//!
+//! [`ContingencyAnalyzer`] keeps the PTDF/LODF factors and base-case flows of one
+//! network, so repeated single-outage queries skip the factorization.
+//!
+//! ## Corrective Redispatch
+//!
+//! [`suggest_redispatch`] goes one step further for a violating outage: it solves a
+//! minimum-cost DC redispatch on the post-contingency topology and returns the
+//! generator MW adjustments that clear the overloads, or reports infeasibility.
+//!
+//! ## Sequential (N-1-1) Outages
+//!
+//! [`screen_n1_1`] chains the two: it redispatches after a first outage and then
+//! screens second outages against the adjusted dispatch, as in NERC TPL-001 category P6.
+//!
+//! ## Contingency Lists
+//!
+//! [`ContingencySpec`] reads the YAML/JSON outage lists passed to analysis commands and
+//! resolves them against a network into [`Contingency`] values.
+//!
+//! ## Violation Export
+//!
+//! [`write_violations_parquet`] writes evaluation results as one row per violated element,
+//! in the column layout notebooks and DuckDB queries expect.
+//!
 //! ## References
 //!
 //! - Wood & Wollenberg, "Power Generation, Operation and Control", Ch. 9
 //! - Alsac et al., "Fast Calculation of LODF and Application to Branch Outage Studies"
 
+pub mod analyzer;
+pub mod export;
 pub mod n_k;
+pub mod redispatch;
+pub mod sequential;
+pub mod spec;
 
 // Re-export from sparse module for backwards compatibility at module level
 pub use crate::sparse::{LodfMatrix, PtdfMatrix, SparsePtdf};
+pub use analyzer::ContingencyAnalyzer;
+pub use export::{write_violations_parquet, ViolationExportTags};
 pub use n_k::{
-    collect_branch_limits, collect_branch_terminals, collect_injections, screen_nk_contingencies,
-    BranchViolation, Contingency, ContingencyEvaluation, NkEvaluationResults, NkEvaluator,
-    NkScreener, NkScreeningConfig, NkScreeningResults, OutageProbabilityConfig, ScreeningResult,
+    collect_branch_limits, collect_branch_limits_for, collect_branch_terminals, collect_injections,
+    dc_branch_flows_in, screen_n1, screen_nk_contingencies, BranchViolation, Contingency,
+    ContingencyEvaluation, NkEvaluationResults, NkEvaluator, NkScreener, NkScreeningConfig,
+    NkScreeningResults, OutageProbabilityConfig, RatingPolicy, RatingTier, ScreeningResult,
+};
+pub use redispatch::{
+    suggest_redispatch, suggest_redispatch_with, GenAdjustment, RedispatchConfig, RedispatchStatus,
+    RedispatchSuggestion,
 };
+pub use sequential::{screen_n1_1, screen_n1_1_with, SecondStageResult, SequentialResult};
+pub use spec::{ContingencyEntry, ContingencySpec};
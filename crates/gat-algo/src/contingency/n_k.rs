@@ -25,7 +25,7 @@
 use crate::arena::ArenaContext;
 use crate::sparse::{LodfMatrix, PtdfMatrix, SparsePtdf};
 use anyhow::Result;
-use gat_core::{BranchId, BusId, Edge, Network, Node};
+use gat_core::{Branch, BranchId, BusId, Edge, GenId, Network, Node};
 use rayon::prelude::*;
 use std::collections::HashMap;
 
@@ -36,10 +36,14 @@ pub struct NkScreeningConfig {
     pub max_k: usize,
     /// Flag threshold as fraction of limit (e.g., 0.9 = 90%)
     pub threshold_fraction: f64,
-    /// Branch thermal limits (BranchId → MVA limit)
+    /// Branch thermal limits for post-contingency flows (BranchId → MVA limit)
     pub branch_limits: HashMap<BranchId, f64>,
+    /// Branch thermal limits for base-case flows; branches missing here use `branch_limits`
+    pub base_case_limits: HashMap<BranchId, f64>,
     /// Default limit if not specified (0 = no limit)
     pub default_limit_mva: f64,
+    /// Rating multipliers for derated branches, applied to every limit above
+    pub deratings: HashMap<BranchId, f64>,
 }
 
 impl Default for NkScreeningConfig {
@@ -48,7 +52,93 @@ impl Default for NkScreeningConfig {
             max_k: 2,
             threshold_fraction: 0.9,
             branch_limits: HashMap::new(),
+            base_case_limits: HashMap::new(),
             default_limit_mva: 0.0,
+            deratings: HashMap::new(),
+        }
+    }
+}
+
+impl NkScreeningConfig {
+    /// Take base-case and post-contingency limits from the network's branch
+    /// ratings, as selected by `policy`.
+    pub fn with_rating_policy(mut self, network: &Network, policy: RatingPolicy) -> Self {
+        self.base_case_limits = collect_branch_limits_for(network, policy.pre_contingency);
+        self.branch_limits = collect_branch_limits_for(network, policy.post_contingency);
+        self
+    }
+
+    /// Screen `branch` at `factor` times its rating (0.5 for one of two
+    /// circuits out) without changing the network's stored ratings.
+    pub fn with_derating(mut self, branch: BranchId, factor: f64) -> Self {
+        self.deratings.insert(branch, factor);
+        self
+    }
+}
+
+/// One of the three branch ratings carried in case data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RatingTier {
+    /// Rate A: continuous (normal) rating
+    #[default]
+    Normal,
+    /// Rate B: short-term emergency rating
+    ShortTermEmergency,
+    /// Rate C: long-term emergency rating
+    LongTermEmergency,
+}
+
+impl RatingTier {
+    /// Limit of `branch` at this tier in MVA.
+    ///
+    /// A missing emergency rating falls back to the next lower tier
+    /// (C → B → A), and a missing rate A falls back to `s_max`. Emergency
+    /// ratings are never below normal ones, so each fallback is conservative.
+    pub fn limit_mva(self, branch: &Branch) -> Option<f64> {
+        let ratings = [branch.rating_a, branch.rating_b, branch.rating_c];
+        let top = match self {
+            RatingTier::Normal => 0,
+            RatingTier::ShortTermEmergency => 1,
+            RatingTier::LongTermEmergency => 2,
+        };
+        ratings[..=top]
+            .iter()
+            .rev()
+            .chain(std::iter::once(&branch.s_max))
+            .flatten()
+            .map(|r| r.value())
+            .find(|&r| r > 0.1)
+    }
+}
+
+/// Which rating each stage of a contingency study is checked against.
+///
+/// Operators keep base-case flows within normal ratings and let
+/// post-contingency flows use short-term emergency ratings until redispatch,
+/// which is the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RatingPolicy {
+    /// Rating for base-case (pre-contingency) flows
+    pub pre_contingency: RatingTier,
+    /// Rating for post-contingency flows
+    pub post_contingency: RatingTier,
+}
+
+impl Default for RatingPolicy {
+    fn default() -> Self {
+        Self {
+            pre_contingency: RatingTier::Normal,
+            post_contingency: RatingTier::ShortTermEmergency,
+        }
+    }
+}
+
+impl RatingPolicy {
+    /// Check every flow against normal ratings.
+    pub fn normal_only() -> Self {
+        Self {
+            pre_contingency: RatingTier::Normal,
+            post_contingency: RatingTier::Normal,
         }
     }
 }
@@ -58,6 +148,8 @@ impl Default for NkScreeningConfig {
 pub struct Contingency {
     /// Branch IDs that are out in this contingency
     pub outaged_branches: Vec<BranchId>,
+    /// Generator IDs that are out in this contingency (ignored by LODF screening)
+    pub outaged_generators: Vec<GenId>,
     /// Probability of this contingency occurring (per year or per exposure time)
     pub probability: Option<f64>,
     /// Human-readable label
@@ -69,6 +161,7 @@ impl Contingency {
     pub fn single(branch_id: BranchId) -> Self {
         Self {
             outaged_branches: vec![branch_id],
+            outaged_generators: Vec::new(),
             probability: None,
             label: None,
         }
@@ -78,6 +171,7 @@ impl Contingency {
     pub fn double(branch_id1: BranchId, branch_id2: BranchId) -> Self {
         Self {
             outaged_branches: vec![branch_id1, branch_id2],
+            outaged_generators: Vec::new(),
             probability: None,
             label: None,
         }
@@ -95,9 +189,9 @@ impl Contingency {
         self
     }
 
-    /// Order of this contingency (k in N-k).
+    /// Order of this contingency (k in N-k), counting every outaged element.
     pub fn order(&self) -> usize {
-        self.outaged_branches.len()
+        self.outaged_branches.len() + self.outaged_generators.len()
     }
 
     /// Compute probability from Forced Outage Rates (FOR) assuming independence.
@@ -171,6 +265,8 @@ pub struct ScreeningResult {
 /// Results from N-k screening.
 #[derive(Debug)]
 pub struct NkScreeningResults {
+    /// Base case checked against pre-contingency limits, when screened
+    pub base_case: Option<ScreeningResult>,
     /// All screened contingencies
     pub results: Vec<ScreeningResult>,
     /// Number flagged for detailed evaluation
@@ -220,15 +316,29 @@ impl NkScreener {
     ) -> Result<Self> {
         let ptdf = SparsePtdf::compute_ptdf(network)?;
         let lodf = SparsePtdf::compute_lodf(network, &ptdf)?;
-        let branch_ids = ptdf.branch_ids.clone();
+        Ok(Self::from_factors(ptdf, lodf, base_flows, config))
+    }
 
-        Ok(Self {
+    /// Create a screener from sensitivity factors computed by the caller.
+    pub(crate) fn from_factors(
+        ptdf: PtdfMatrix,
+        lodf: LodfMatrix,
+        base_flows: HashMap<BranchId, f64>,
+        config: NkScreeningConfig,
+    ) -> Self {
+        let branch_ids = ptdf.branch_ids.clone();
+        Self {
             ptdf,
             lodf,
             branch_ids,
             base_flows,
             config,
-        })
+        }
+    }
+
+    /// Pre-contingency flow on each branch the screener was built with.
+    pub(crate) fn base_flows(&self) -> &HashMap<BranchId, f64> {
+        &self.base_flows
     }
 
     /// Generate all N-1 contingencies.
@@ -253,6 +363,33 @@ impl NkScreener {
 
     /// Screen a single contingency using LODF estimation.
     pub fn screen_contingency(&self, contingency: &Contingency) -> ScreeningResult {
+        self.screen_with_limits(contingency, |id| {
+            self.config.branch_limits.get(&id).copied()
+        })
+    }
+
+    /// Check base-case flows against the pre-contingency limits.
+    pub fn screen_base_case(&self) -> ScreeningResult {
+        let base_case = Contingency {
+            outaged_branches: Vec::new(),
+            outaged_generators: Vec::new(),
+            probability: None,
+            label: Some("base case".to_string()),
+        };
+        self.screen_with_limits(&base_case, |id| {
+            self.config
+                .base_case_limits
+                .get(&id)
+                .or_else(|| self.config.branch_limits.get(&id))
+                .copied()
+        })
+    }
+
+    fn screen_with_limits(
+        &self,
+        contingency: &Contingency,
+        limit_of: impl Fn(BranchId) -> Option<f64>,
+    ) -> ScreeningResult {
         let mut max_loading = 0.0;
         let mut most_loaded = None;
         let mut violations = Vec::new();
@@ -278,13 +415,9 @@ impl NkScreener {
                 }
             }
 
-            // Check against limit
-            let limit = self
-                .config
-                .branch_limits
-                .get(&branch_l)
-                .copied()
-                .unwrap_or(self.config.default_limit_mva);
+            // Check against limit, derated where requested
+            let limit = limit_of(branch_l).unwrap_or(self.config.default_limit_mva)
+                * self.config.deratings.get(&branch_l).copied().unwrap_or(1.0);
 
             if limit > 0.0 {
                 let loading = estimated_flow.abs() / limit;
@@ -320,6 +453,7 @@ impl NkScreener {
         let total = results.len();
 
         NkScreeningResults {
+            base_case: None,
             results,
             num_flagged,
             total_screened: total,
@@ -327,6 +461,14 @@ impl NkScreener {
         }
     }
 
+    /// Screen the base case and all N-1 contingencies, each against the
+    /// limits for its stage.
+    pub fn screen_n1(&self) -> NkScreeningResults {
+        let mut results = self.screen_all(&self.generate_n1());
+        results.base_case = Some(self.screen_base_case());
+        results
+    }
+
     /// Screen N-1 and N-2 contingencies.
     pub fn screen_n1_n2(&self) -> NkScreeningResults {
         let mut contingencies = self.generate_n1();
@@ -347,6 +489,19 @@ pub fn screen_nk_contingencies(
     Ok(screener.screen_n1_n2())
 }
 
+/// Convenience function: screen the base case and all N-1 outages, with
+/// limits taken from the network's ratings under `policy`.
+pub fn screen_n1(
+    network: &Network,
+    base_flows: HashMap<BranchId, f64>,
+    config: NkScreeningConfig,
+    policy: RatingPolicy,
+) -> Result<NkScreeningResults> {
+    let config = config.with_rating_policy(network, policy);
+    let screener = NkScreener::new(network, base_flows, config)?;
+    Ok(screener.screen_n1())
+}
+
 // =============================================================================
 // Full N-k Evaluation (for flagged contingencies)
 // =============================================================================
@@ -723,16 +878,27 @@ impl<'a> NkEvaluator<'a> {
     pub fn evaluate_all_with_arena(&self, contingencies: &[Contingency]) -> NkEvaluationResults {
         let evaluations: Vec<ContingencyEvaluation> = contingencies
             .par_iter()
-            .map_init(ArenaContext::new, |ctx, c| {
-                let result = self.evaluate_with_arena(c, ctx);
-                ctx.reset(); // O(1) bulk deallocation
-                result
-            })
+            .map_init(ArenaContext::new, |ctx, c| self.evaluate_in(c, ctx))
             .collect();
 
         self.build_evaluation_results(evaluations)
     }
 
+    /// Evaluate a single contingency reusing a caller-owned [`ArenaContext`].
+    ///
+    /// Produces the same result as [`NkEvaluator::evaluate`], but the reduced
+    /// B' matrix, injection vector and pivot buffers are taken from the
+    /// context and kept across calls. The arena is reset before returning.
+    pub fn evaluate_in(
+        &self,
+        contingency: &Contingency,
+        ctx: &mut ArenaContext,
+    ) -> ContingencyEvaluation {
+        let result = self.evaluate_with_arena(contingency, ctx);
+        ctx.reset(); // O(1) bulk deallocation
+        result
+    }
+
     /// Evaluate a single contingency using arena-allocated temporaries.
     fn evaluate_with_arena(
         &self,
@@ -957,94 +1123,118 @@ impl<'a> NkEvaluator<'a> {
     /// Compute DC flows with arena-allocated temporaries.
     ///
     /// This version uses arena-backed collections for bus_ids, bus_to_idx,
-    /// branches, and flows to reduce allocation overhead in parallel loops.
+    /// branches, and flows, and solves in the context's [`DcScratch`] buffers
+    /// (reduced B', RHS, pivots) so no per-call matrix allocation happens.
+    ///
+    /// [`DcScratch`]: crate::arena::DcScratch
     fn compute_dc_flows_with_outages_arena<'b>(
         &self,
-        outaged: &hashbrown::HashSet<BranchId, hashbrown::DefaultHashBuilder, &bumpalo::Bump>,
+        outaged: &ArenaBranchSet<'_>,
         ctx: &'b ArenaContext,
-    ) -> Result<hashbrown::HashMap<BranchId, f64, hashbrown::DefaultHashBuilder, &'b bumpalo::Bump>>
-    {
-        // Build susceptance matrix excluding outaged branches
-        let mut bus_ids = ctx.alloc_vec::<BusId>();
-        for idx in self.network.graph.node_indices() {
-            if let Node::Bus(bus) = &self.network.graph[idx] {
-                bus_ids.push(bus.id);
-            }
-        }
-        bus_ids.sort_unstable_by_key(|id| id.value());
+    ) -> Result<ArenaFlowMap<'b>> {
+        dc_flows_arena(self.network, &self.injections, outaged, ctx)
+    }
+}
 
-        let n = bus_ids.len();
-        if n < 2 {
-            return Ok(ctx.alloc_hashmap());
-        }
+type ArenaBranchSet<'a> =
+    hashbrown::HashSet<BranchId, hashbrown::DefaultHashBuilder, &'a bumpalo::Bump>;
+type ArenaFlowMap<'a> =
+    hashbrown::HashMap<BranchId, f64, hashbrown::DefaultHashBuilder, &'a bumpalo::Bump>;
 
-        let mut bus_to_idx = ctx.alloc_hashmap::<BusId, usize>();
-        for (idx, &id) in bus_ids.iter().enumerate() {
-            bus_to_idx.insert(id, idx);
+/// Solve DC branch flows for `injections` reusing the working buffers in `ctx`.
+///
+/// This is the allocation-light entry point for hot loops that re-solve the
+/// same network many times with different injections or outage sets, as
+/// contingency screening does. The reduced B' matrix, injection vector and
+/// LU pivots are reused from the context's [`DcScratch`]; only the returned
+/// flow map is freshly allocated. The arena is reset before returning.
+///
+/// Bus ordering, slack choice (lowest bus ID) and MW scaling match
+/// [`NkEvaluator::evaluate`], so results are identical to the allocating path.
+///
+/// [`DcScratch`]: crate::arena::DcScratch
+pub fn dc_branch_flows_in(
+    network: &Network,
+    injections: &HashMap<BusId, f64>,
+    outaged_branches: &[BranchId],
+    ctx: &mut ArenaContext,
+) -> Result<HashMap<BranchId, f64>> {
+    let flows: HashMap<BranchId, f64> = {
+        let mut outaged = ctx.alloc_hashset::<BranchId>();
+        outaged.extend(outaged_branches.iter().copied());
+        let flows = dc_flows_arena(network, injections, &outaged, ctx)?;
+        flows.iter().map(|(&k, &v)| (k, v)).collect()
+    };
+    ctx.reset();
+    Ok(flows)
+}
+
+fn dc_flows_arena<'b>(
+    network: &Network,
+    injections: &HashMap<BusId, f64>,
+    outaged: &ArenaBranchSet<'_>,
+    ctx: &'b ArenaContext,
+) -> Result<ArenaFlowMap<'b>> {
+    // Build susceptance matrix excluding outaged branches
+    let mut bus_ids = ctx.alloc_vec::<BusId>();
+    for idx in network.graph.node_indices() {
+        if let Node::Bus(bus) = &network.graph[idx] {
+            bus_ids.push(bus.id);
         }
+    }
+    bus_ids.sort_unstable_by_key(|id| id.value());
 
-        // Build B' matrix (standard Vec - small relative to hash allocations)
-        let mut b_matrix = vec![vec![0.0; n]; n];
-        let mut branches = ctx.alloc_vec::<(BranchId, BusId, BusId, f64)>();
+    let n = bus_ids.len();
+    if n < 2 {
+        return Ok(ctx.alloc_hashmap());
+    }
 
-        for edge in self.network.graph.edge_references() {
-            if let Edge::Branch(branch) = edge.weight() {
-                if !branch.status || outaged.contains(&branch.id) {
-                    continue;
-                }
-                let from = branch.from_bus;
-                let to = branch.to_bus;
-                let x = (branch.reactance * branch.tap_ratio).abs().max(1e-6);
+    let mut bus_to_idx = ctx.alloc_hashmap::<BusId, usize>();
+    for (idx, &id) in bus_ids.iter().enumerate() {
+        bus_to_idx.insert(id, idx);
+    }
 
-                if let (Some(&i), Some(&j)) = (bus_to_idx.get(&from), bus_to_idx.get(&to)) {
-                    let b = 1.0 / x;
-                    b_matrix[i][j] -= b;
-                    b_matrix[j][i] -= b;
-                    b_matrix[i][i] += b;
-                    b_matrix[j][j] += b;
-                }
-                branches.push((branch.id, from, to, x));
-            }
-        }
+    // Reduced B' matrix and RHS live in the context's reusable DC scratch,
+    // so repeated solves on the same network perform no matrix allocations.
+    let mut scratch = ctx.dc_scratch(n - 1);
+    let mut branches = ctx.alloc_vec::<(BranchId, BusId, BusId, f64)>();
 
-        // Build RHS from injections
-        let mut rhs = vec![0.0; n];
-        for (&bus_id, &inj) in &self.injections {
-            if let Some(&idx) = bus_to_idx.get(&bus_id) {
-                rhs[idx] = inj;
+    for edge in network.graph.edge_references() {
+        if let Edge::Branch(branch) = edge.weight() {
+            if !branch.status || outaged.contains(&branch.id) {
+                continue;
             }
-        }
+            let from = branch.from_bus;
+            let to = branch.to_bus;
+            let x = (branch.reactance * branch.tap_ratio).abs().max(1e-6);
 
-        // Solve reduced system (slack = first bus)
-        let m = n - 1;
-        let mut reduced = vec![vec![0.0; m]; m];
-        let mut reduced_rhs = vec![0.0; m];
-        for i in 0..m {
-            for j in 0..m {
-                reduced[i][j] = b_matrix[i + 1][j + 1];
+            if let (Some(&i), Some(&j)) = (bus_to_idx.get(&from), bus_to_idx.get(&to)) {
+                scratch.add_branch(i, j, 1.0 / x);
             }
-            reduced_rhs[i] = rhs[i + 1];
+            branches.push((branch.id, from, to, x));
         }
+    }
 
-        let angles = solve_linear_system(&reduced, &reduced_rhs)?;
-
-        // Full angles (slack = 0)
-        let mut theta = vec![0.0; n];
-        for i in 0..m {
-            theta[i + 1] = angles[i];
+    // Build RHS from injections (slack = first bus)
+    for (&bus_id, &inj) in injections {
+        if let Some(&idx) = bus_to_idx.get(&bus_id) {
+            scratch.set_injection(idx, inj);
         }
+    }
 
-        // Compute branch flows into arena-allocated HashMap
-        let mut flows = ctx.alloc_hashmap::<BranchId, f64>();
-        for &(id, from, to, x) in branches.iter() {
-            let i = *bus_to_idx.get(&from).unwrap();
-            let j = *bus_to_idx.get(&to).unwrap();
-            let flow = (theta[i] - theta[j]) / x * 100.0; // Convert to MW (base 100 MVA)
-            flows.insert(id, flow);
-        }
+    scratch.solve()?;
 
-        Ok(flows)
+    // Compute branch flows into arena-allocated HashMap
+    let mut flows = ctx.alloc_hashmap::<BranchId, f64>();
+    for &(id, from, to, x) in branches.iter() {
+        let i = *bus_to_idx.get(&from).unwrap();
+        let j = *bus_to_idx.get(&to).unwrap();
+        // Convert to MW (base 100 MVA)
+        let flow = (scratch.angle(i) - scratch.angle(j)) / x * 100.0;
+        flows.insert(id, flow);
     }
+
+    Ok(flows)
 }
 
 /// Solve linear system Ax = b using LU decomposition.
@@ -1159,6 +1349,24 @@ pub fn collect_branch_limits(network: &Network) -> HashMap<BranchId, f64> {
     limits
 }
 
+/// Extract branch thermal limits at a rating tier, with the fallbacks of
+/// [`RatingTier::limit_mva`].
+///
+/// Returns a map of BranchId → thermal limit in MVA for in-service branches.
+pub fn collect_branch_limits_for(network: &Network, tier: RatingTier) -> HashMap<BranchId, f64> {
+    let mut limits = HashMap::new();
+    for edge in network.graph.edge_references() {
+        if let Edge::Branch(branch) = edge.weight() {
+            if branch.status {
+                if let Some(limit) = tier.limit_mva(branch) {
+                    limits.insert(branch.id, limit);
+                }
+            }
+        }
+    }
+    limits
+}
+
 /// Collect branch terminal buses for result mapping.
 ///
 /// Returns a map of BranchId → (from_bus, to_bus).
@@ -1337,6 +1545,74 @@ mod tests {
         assert!(results.total_screened > 0);
     }
 
+    #[test]
+    fn test_emergency_rating_policy_post_contingency() {
+        let mut network = create_test_network();
+        for edge in network.graph.edge_weights_mut() {
+            if let Edge::Branch(branch) = edge {
+                branch.rating_b = Some(gat_core::MegavoltAmperes(130.0));
+            }
+        }
+        // Consistent DC flows: any single outage pushes 120 MW onto a survivor,
+        // over rate A (100) but within rate B (130)
+        let base_flows = HashMap::from([
+            (BranchId::new(1), 60.0),
+            (BranchId::new(2), 60.0),
+            (BranchId::new(3), 60.0),
+        ]);
+        let config = NkScreeningConfig {
+            threshold_fraction: 1.0,
+            ..NkScreeningConfig::default()
+        };
+
+        let emergency = screen_n1(
+            &network,
+            base_flows.clone(),
+            config.clone(),
+            RatingPolicy::default(),
+        )
+        .unwrap();
+        assert_eq!(emergency.total_screened, 3);
+        assert_eq!(emergency.num_flagged, 0);
+        let base = emergency.base_case.as_ref().unwrap();
+        assert!(!base.flagged);
+        assert!((base.max_loading_fraction - 0.6).abs() < 1e-9);
+        for r in &emergency.results {
+            assert!((r.max_loading_fraction - 120.0 / 130.0).abs() < 1e-6);
+        }
+
+        let normal = screen_n1(&network, base_flows, config, RatingPolicy::normal_only()).unwrap();
+        assert_eq!(normal.num_flagged, 3);
+    }
+
+    #[test]
+    fn test_rating_tier_fallback() {
+        let mut branch = Branch {
+            rating_a: Some(gat_core::MegavoltAmperes(100.0)),
+            ..Branch::default()
+        };
+        assert_eq!(
+            RatingTier::LongTermEmergency.limit_mva(&branch),
+            Some(100.0)
+        );
+        branch.rating_b = Some(gat_core::MegavoltAmperes(120.0));
+        assert_eq!(
+            RatingTier::LongTermEmergency.limit_mva(&branch),
+            Some(120.0)
+        );
+        assert_eq!(RatingTier::Normal.limit_mva(&branch), Some(100.0));
+
+        let branch = Branch {
+            s_max: Some(gat_core::MegavoltAmperes(80.0)),
+            ..Branch::default()
+        };
+        assert_eq!(
+            RatingTier::ShortTermEmergency.limit_mva(&branch),
+            Some(80.0)
+        );
+        assert_eq!(RatingTier::Normal.limit_mva(&Branch::default()), None);
+    }
+
     #[test]
     fn test_screen_n2() {
         let network = create_test_network();
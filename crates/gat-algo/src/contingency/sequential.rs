@@ -0,0 +1,282 @@
+//! N-1-1 (sequential) contingency screening.
+//!
+//! N-2 screening assumes both elements trip at once. NERC TPL-001 category P6
+//! instead studies an N-1 event, the operator's corrective response, and then a
+//! second N-1 event on the adjusted system. The second outage therefore meets a
+//! different dispatch than it would in the base case, and a first outage that is
+//! comfortably survivable can leave the system exposed to a second one.
+//!
+//! For each first-stage outage [`screen_n1_1`]:
+//!
+//! 1. Solves a corrective redispatch with [`suggest_redispatch_with`]
+//! 2. Applies the outage and the redispatched generator outputs
+//! 3. Runs DC flows for every second-stage outage on that state and reports the
+//!    branches left over their limits
+//!
+//! No redispatch follows the second outage; its violations are what the system
+//! would see immediately after the second event.
+
+use super::n_k::{BranchViolation, Contingency};
+use super::redispatch::{
+    suggest_redispatch_with, RedispatchConfig, RedispatchData, RedispatchStatus,
+    RedispatchSuggestion,
+};
+use anyhow::Result;
+use gat_core::{Edge, Megawatts, Network, Node};
+use std::collections::HashSet;
+
+/// Result of one second outage following a redispatched first outage.
+#[derive(Debug, Clone)]
+pub struct SecondStageResult {
+    pub contingency: Contingency,
+    /// Overloads immediately after the second outage, worst first
+    pub violations: Vec<BranchViolation>,
+    /// Why flows could not be computed (e.g. the outage islands the network)
+    pub message: Option<String>,
+}
+
+/// Result of one first-stage outage and the second outages screened after it.
+#[derive(Debug, Clone)]
+pub struct SequentialResult {
+    /// Corrective redispatch after the first outage
+    pub first_stage: RedispatchSuggestion,
+    /// Second-stage results; empty when the first stage could not be corrected.
+    /// Outages sharing a branch with the first stage are skipped.
+    pub second_stage: Vec<SecondStageResult>,
+}
+
+impl SequentialResult {
+    /// Whether any second outage leads to overloads or cannot be solved.
+    pub fn has_cascading_violations(&self) -> bool {
+        self.second_stage
+            .iter()
+            .any(|s| !s.violations.is_empty() || s.message.is_some())
+    }
+}
+
+/// Screen N-1-1 sequences using Rate A limits.
+///
+/// See [`screen_n1_1_with`] for details.
+pub fn screen_n1_1(
+    network: &Network,
+    first_stage: &[Contingency],
+    second_stage: &[Contingency],
+) -> Result<Vec<SequentialResult>> {
+    screen_n1_1_with(
+        network,
+        first_stage,
+        second_stage,
+        &RedispatchConfig::default(),
+    )
+}
+
+/// Screen every pairing of a `first_stage` outage with a `second_stage` outage.
+///
+/// Generator `active_power` values are the pre-event dispatch. Only branch
+/// outages are applied. A first outage whose redispatch is infeasible is reported
+/// with an empty second stage, since the system would not reach a secure state
+/// to study. Returns an error only for malformed input, such as an unknown branch.
+pub fn screen_n1_1_with(
+    network: &Network,
+    first_stage: &[Contingency],
+    second_stage: &[Contingency],
+    config: &RedispatchConfig,
+) -> Result<Vec<SequentialResult>> {
+    let mut results = Vec::with_capacity(first_stage.len());
+    for first in first_stage {
+        let suggestion = suggest_redispatch_with(network, first, config)?;
+        if suggestion.status == RedispatchStatus::Infeasible {
+            results.push(SequentialResult {
+                first_stage: suggestion,
+                second_stage: Vec::new(),
+            });
+            continue;
+        }
+
+        let adjusted = post_redispatch_network(network, &suggestion);
+        let first_out: HashSet<_> = first.outaged_branches.iter().collect();
+        let mut screened = Vec::new();
+        for second in second_stage {
+            if second
+                .outaged_branches
+                .iter()
+                .any(|id| first_out.contains(id))
+            {
+                continue;
+            }
+            let data = RedispatchData::extract(&adjusted, second, config)?;
+            let result = match data.base_flows() {
+                Ok(flows) => SecondStageResult {
+                    contingency: second.clone(),
+                    violations: data.violations(&flows),
+                    message: None,
+                },
+                Err(err) => SecondStageResult {
+                    contingency: second.clone(),
+                    violations: Vec::new(),
+                    message: Some(err.to_string()),
+                },
+            };
+            screened.push(result);
+        }
+
+        results.push(SequentialResult {
+            first_stage: suggestion,
+            second_stage: screened,
+        });
+    }
+    Ok(results)
+}
+
+/// The network after the first outage, with the suggested dispatch applied.
+fn post_redispatch_network(network: &Network, suggestion: &RedispatchSuggestion) -> Network {
+    let mut adjusted = network.clone();
+    let outaged: HashSet<_> = suggestion.contingency.outaged_branches.iter().collect();
+    for edge in adjusted.graph.edge_weights_mut() {
+        if let Edge::Branch(branch) = edge {
+            if outaged.contains(&branch.id) {
+                branch.status = false;
+            }
+        }
+    }
+    for node in adjusted.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if let Some(adj) = suggestion.adjustments.iter().find(|a| a.gen_id == gen.id) {
+                gen.active_power = Megawatts(adj.new_mw);
+            }
+        }
+    }
+    adjusted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{
+        Branch, BranchId, Bus, BusId, CostModel, Gen, GenId, Load, LoadId, Megavars,
+        MegavoltAmperes,
+    };
+
+    /// Ring 1-2-3-4 with a chord 1-3, all x = 0.1. A cheap unit at bus 1
+    /// serves 150 MW at bus 3; an expensive unit at bus 2 is idle. Only
+    /// line 1-2 is tight (60 MW).
+    ///
+    /// Losing the chord splits the 150 MW evenly over 1-2-3 and 1-4-3, so 1-2
+    /// carries 75 MW. Each MW moved to bus 2 relieves 1-2 by 3/4 MW, so the
+    /// redispatch moves 20 MW and leaves 130 MW at bus 1. A second loss of 1-4
+    /// then forces all 130 MW over 1-2.
+    fn create_ring() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=4)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("Bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+
+        let lines = [
+            (1, 1, 2, 60.0),
+            (2, 2, 3, 200.0),
+            (3, 1, 3, 200.0),
+            (4, 1, 4, 200.0),
+            (5, 4, 3, 200.0),
+        ];
+        for (id, from, to, rating) in lines {
+            network.graph.add_edge(
+                buses[from - 1],
+                buses[to - 1],
+                Edge::Branch(Branch {
+                    id: BranchId::new(id),
+                    name: format!("Line{}-{}", from, to),
+                    from_bus: BusId::new(from),
+                    to_bus: BusId::new(to),
+                    reactance: 0.1,
+                    rating_a: Some(MegavoltAmperes(rating)),
+                    ..Branch::default()
+                }),
+            );
+        }
+
+        let mut cheap = Gen::new(GenId::new(1), "Cheap".into(), BusId::new(1))
+            .with_p_limits(0.0, 200.0)
+            .with_cost(CostModel::linear(0.0, 10.0));
+        cheap.active_power = Megawatts(150.0);
+        network.graph.add_node(Node::Gen(cheap));
+
+        let expensive = Gen::new(GenId::new(2), "Expensive".into(), BusId::new(2))
+            .with_p_limits(0.0, 200.0)
+            .with_cost(CostModel::linear(0.0, 30.0));
+        network.graph.add_node(Node::Gen(expensive));
+
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load3".into(),
+            bus: BusId::new(3),
+            active_power: Megawatts(150.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+
+        network
+    }
+
+    #[test]
+    fn test_second_outage_overloads_after_redispatch() {
+        let network = create_ring();
+        let first = [Contingency::single(BranchId::new(3))];
+        let second = [
+            Contingency::single(BranchId::new(2)),
+            Contingency::single(BranchId::new(3)),
+            Contingency::single(BranchId::new(4)),
+        ];
+
+        let results = screen_n1_1(&network, &first, &second).unwrap();
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+
+        // The first outage alone is survivable once 20 MW moves to bus 2
+        assert_eq!(result.first_stage.status, RedispatchStatus::Resolved);
+        assert!((result.first_stage.total_shift_mw() - 20.0).abs() < 1e-3);
+
+        // The repeated chord outage is skipped; 2-3 is harmless, 1-4 is not
+        assert_eq!(result.second_stage.len(), 2);
+        let by_branch = |id: usize| {
+            result
+                .second_stage
+                .iter()
+                .find(|s| s.contingency.outaged_branches == [BranchId::new(id)])
+                .unwrap()
+        };
+        assert!(by_branch(2).violations.is_empty());
+
+        let cascade = by_branch(4);
+        assert_eq!(cascade.violations.len(), 1);
+        assert_eq!(cascade.violations[0].branch_id, BranchId::new(1));
+        assert!((cascade.violations[0].flow_mw - 130.0).abs() < 1e-2);
+        assert!(result.has_cascading_violations());
+    }
+
+    #[test]
+    fn test_infeasible_first_stage_skips_second() {
+        let mut network = create_ring();
+        // With bus 2's unit unable to move, nothing can relieve 1-2
+        for node in network.graph.node_weights_mut() {
+            if let Node::Gen(gen) = node {
+                if gen.id == GenId::new(2) {
+                    gen.pmax = Megawatts(0.0);
+                }
+            }
+        }
+        let results = screen_n1_1(
+            &network,
+            &[Contingency::single(BranchId::new(3))],
+            &[Contingency::single(BranchId::new(4))],
+        )
+        .unwrap();
+        assert_eq!(results[0].first_stage.status, RedispatchStatus::Infeasible);
+        assert!(results[0].second_stage.is_empty());
+    }
+}
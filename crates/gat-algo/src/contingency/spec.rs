@@ -0,0 +1,182 @@
+//! Contingency list files shared by the analysis commands.
+//!
+//! A spec lists outages by element ID, in YAML or JSON:
+//!
+//! ```yaml
+//! contingencies:
+//!   - label: "Line 1-2"
+//!     branches: [0]
+//!   - generators: [1]
+//!   - label: "Tower 4 with unit 2"
+//!     branches: [3, 6]
+//!     generators: [2]
+//! ```
+//!
+//! Entries may mix branches and generators; an entry without a label is named after its
+//! elements. [`ContingencySpec::resolve`] checks every ID against a network before handing out
+//! typed [`Contingency`] values, so a stale list fails up front rather than mid-study.
+
+use anyhow::{anyhow, bail, Context, Result};
+use gat_core::{BranchId, Edge, GenId, Network, Node};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use super::Contingency;
+
+/// Parsed contingency list file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContingencySpec {
+    #[serde(default)]
+    pub contingencies: Vec<ContingencyEntry>,
+}
+
+/// One outage event in a [`ContingencySpec`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContingencyEntry {
+    pub label: Option<String>,
+    /// Branch IDs taken out of service
+    #[serde(default)]
+    pub branches: Vec<usize>,
+    /// Generator IDs taken out of service
+    #[serde(default)]
+    pub generators: Vec<usize>,
+    pub probability: Option<f64>,
+}
+
+impl ContingencyEntry {
+    fn display_label(&self) -> String {
+        if let Some(label) = &self.label {
+            return label.clone();
+        }
+        self.branches
+            .iter()
+            .map(|id| format!("branch {}", id))
+            .chain(self.generators.iter().map(|id| format!("gen {}", id)))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+impl ContingencySpec {
+    /// Read a spec from `path`, choosing YAML or JSON by extension (YAML, then JSON, otherwise).
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let data = fs::read_to_string(path)
+            .with_context(|| format!("reading contingency spec '{}'", path.display()))?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") => {
+                serde_yaml::from_str(&data).context("parsing contingency spec yaml")
+            }
+            Some(ext) if ext.eq_ignore_ascii_case("json") => {
+                serde_json::from_str(&data).context("parsing contingency spec json")
+            }
+            _ => serde_yaml::from_str(&data)
+                .or_else(|_| serde_json::from_str(&data))
+                .context("parsing contingency spec"),
+        }
+    }
+
+    /// Check every referenced element against `network` and build the contingencies.
+    ///
+    /// Out-of-service elements are accepted, since the list may be written for a different
+    /// operating state. Empty entries and IDs missing from the network are errors; all unknown
+    /// IDs are reported together.
+    pub fn resolve(&self, network: &Network) -> Result<Vec<Contingency>> {
+        let mut branch_ids = HashSet::new();
+        let mut gen_ids = HashSet::new();
+        for edge in network.graph.edge_weights() {
+            if let Edge::Branch(branch) = edge {
+                branch_ids.insert(branch.id.value());
+            }
+        }
+        for node in network.graph.node_weights() {
+            if let Node::Gen(gen) = node {
+                gen_ids.insert(gen.id.value());
+            }
+        }
+
+        let mut unknown = Vec::new();
+        let mut contingencies = Vec::with_capacity(self.contingencies.len());
+        for (index, entry) in self.contingencies.iter().enumerate() {
+            let label = entry.display_label();
+            if entry.branches.is_empty() && entry.generators.is_empty() {
+                bail!("contingency #{} '{}' lists no elements", index + 1, label);
+            }
+            for id in entry.branches.iter().filter(|id| !branch_ids.contains(id)) {
+                unknown.push(format!("branch {} (in '{}')", id, label));
+            }
+            for id in entry.generators.iter().filter(|id| !gen_ids.contains(id)) {
+                unknown.push(format!("generator {} (in '{}')", id, label));
+            }
+
+            contingencies.push(Contingency {
+                outaged_branches: entry.branches.iter().map(|&id| BranchId::new(id)).collect(),
+                outaged_generators: entry.generators.iter().map(|&id| GenId::new(id)).collect(),
+                probability: entry.probability,
+                label: Some(label),
+            });
+        }
+
+        if !unknown.is_empty() {
+            return Err(anyhow!(
+                "contingency spec references elements not in the network: {}",
+                unknown.join(", ")
+            ));
+        }
+        Ok(contingencies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_io::importers::load_matpower_network;
+    use std::path::PathBuf;
+    use tempfile::tempdir;
+
+    fn ieee14() -> Network {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+        load_matpower_network(&path).expect("parse case14")
+    }
+
+    #[test]
+    fn test_spec_resolves_against_ieee14() -> Result<()> {
+        let tmp = tempdir()?;
+        let path = tmp.path().join("contingencies.yaml");
+        fs::write(
+            &path,
+            "contingencies:\n  - label: Line 1-2\n    branches: [0]\n  - generators: [1]\n",
+        )?;
+
+        let contingencies = ContingencySpec::from_path(&path)?.resolve(&ieee14())?;
+        assert_eq!(contingencies.len(), 2);
+        assert_eq!(contingencies[0].outaged_branches, vec![BranchId::new(0)]);
+        assert!(contingencies[0].outaged_generators.is_empty());
+        assert_eq!(contingencies[0].label.as_deref(), Some("Line 1-2"));
+        assert_eq!(contingencies[1].outaged_generators, vec![GenId::new(1)]);
+        assert_eq!(contingencies[1].label.as_deref(), Some("gen 1"));
+        assert_eq!(contingencies[1].order(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_ids_are_named() -> Result<()> {
+        let tmp = tempdir()?;
+        let path = tmp.path().join("contingencies.json");
+        fs::write(
+            &path,
+            r#"{"contingencies": [{"label": "bad", "branches": [0, 999], "generators": [42]}]}"#,
+        )?;
+
+        let err = ContingencySpec::from_path(&path)?
+            .resolve(&ieee14())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("branch 999"), "{}", err);
+        assert!(err.contains("generator 42"), "{}", err);
+        assert!(!err.contains("branch 0 "), "{}", err);
+        Ok(())
+    }
+}
@@ -1,8 +1,299 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use gat_core::{BusId, Edge, Gen, Load, LoadId, Megavars, Megawatts, Network, Node};
 use polars::prelude::*;
+use std::collections::HashMap;
 use std::path::Path;
 
+use crate::analytics_ds::branch_bound;
 use crate::io::{persist_dataframe, OutputStage};
+use crate::reliability_monte_carlo::{MonteCarlo, MonteCarloProgress};
+use crate::sparse::SparsePtdf;
+
+/// Bisection iterations on the added load
+const ELCC_MAX_ITERATIONS: usize = 20;
+
+/// Bisection stops once the bracket is this fraction of the resource capacity
+const ELCC_BRACKET_TOLERANCE: f64 = 1e-3;
+
+/// Two-sided 95% normal quantile, matching [`MonteCarloProgress`] intervals
+const CI_Z_95: f64 = 1.96;
+
+/// One bisection step of [`elcc`].
+#[derive(Debug, Clone)]
+pub struct ElccIteration {
+    /// Firm load added on top of the original demand (MW)
+    pub added_load_mw: f64,
+    /// LOLE with the resource and the added load (hours per year)
+    pub lole: f64,
+    /// 95% sampling interval on that LOLE (hours per year)
+    pub lole_ci: (f64, f64),
+}
+
+/// Effective load carrying capability of one resource with its uncertainty.
+#[derive(Debug, Clone)]
+pub struct ElccResult {
+    /// Nameplate capacity of the resource (MW)
+    pub capacity_mw: f64,
+    /// Load the resource can carry at baseline reliability (MW)
+    pub elcc_mw: f64,
+    /// `elcc_mw / capacity_mw`
+    pub elcc_fraction: f64,
+    /// 95% confidence interval on `elcc_mw`
+    pub elcc_ci_mw: (f64, f64),
+    /// LOLE of the system without the resource (hours per year)
+    pub baseline_lole: f64,
+    /// 95% sampling interval on the baseline LOLE
+    pub baseline_lole_ci: (f64, f64),
+    /// Bisection steps in order, for convergence plots
+    pub trace: Vec<ElccIteration>,
+}
+
+/// ELCC of `resource` added to `network`, by Monte Carlo adequacy sampling.
+///
+/// ELCC is the firm load that can be added alongside the resource while
+/// keeping LOLE at its level without the resource. The resource's capacity is
+/// its `pmax`, all of it available whenever the unit is not on outage. The
+/// added load is a new load at the bus of the lowest-ID existing load and is
+/// scaled with demand like the rest; it is found by bisection on
+/// `[0, capacity]`, with every step recorded in [`ElccResult::trace`].
+///
+/// The interval comes from the sampling variance of the two LOLE estimates
+/// that define the crossing, the baseline and the with-resource estimate at
+/// the solution, mapped to MW through the average LOLE slope over the
+/// bracket (delta method). The two runs draw outages independently because
+/// the extra generator shifts the random stream, so their variances add.
+pub fn elcc(network: &Network, resource: &Gen, mc: &MonteCarlo) -> Result<ElccResult> {
+    let capacity_mw = resource.pmax.value();
+    if !(capacity_mw > 0.0 && capacity_mw.is_finite()) {
+        return Err(anyhow!("ELCC resource must have a positive, finite pmax"));
+    }
+    let load_bus = network
+        .loads_sorted()
+        .first()
+        .map(|load| load.bus)
+        .ok_or_else(|| anyhow!("Network has no load"))?;
+
+    let baseline = lole_estimate(mc, network)?;
+
+    let mut with_resource = network.clone();
+    // The sampler counts a unit's active power as its available capacity
+    with_resource.graph.add_node(Node::Gen(Gen {
+        active_power: Megawatts(capacity_mw),
+        ..resource.clone()
+    }));
+    let next_load_id = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Load(load) => Some(load.id.value() + 1),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let added_load = with_resource.graph.add_node(Node::Load(Load {
+        id: LoadId::new(next_load_id),
+        name: "elcc_added_load".to_string(),
+        bus: load_bus,
+        active_power: Megawatts(0.0),
+        reactive_power: Megavars(0.0),
+        zip: None,
+    }));
+
+    let mut trace = Vec::new();
+    let mut evaluate = |added_mw: f64| -> Result<MonteCarloProgress> {
+        if let Some(Node::Load(load)) = with_resource.graph.node_weight_mut(added_load) {
+            load.active_power = Megawatts(added_mw);
+        }
+        let estimate = lole_estimate(mc, &with_resource)?;
+        trace.push(ElccIteration {
+            added_load_mw: added_mw,
+            lole: estimate.lole,
+            lole_ci: estimate.lole_ci,
+        });
+        Ok(estimate)
+    };
+
+    let at_zero = evaluate(0.0)?;
+    let at_capacity = evaluate(capacity_mw)?;
+
+    // LOLE is non-decreasing in added load; find the crossing of the baseline
+    let (mut lo, mut hi) = (0.0, capacity_mw);
+    let mut crossing = at_capacity.clone();
+    if at_zero.lole >= baseline.lole {
+        hi = 0.0;
+        crossing = at_zero.clone();
+    } else if at_capacity.lole <= baseline.lole {
+        lo = capacity_mw;
+    }
+    let mut iterations = 0;
+    while hi - lo > ELCC_BRACKET_TOLERANCE * capacity_mw && iterations < ELCC_MAX_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        let estimate = evaluate(mid)?;
+        if estimate.lole <= baseline.lole {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+        crossing = estimate;
+        iterations += 1;
+    }
+    let elcc_mw = 0.5 * (lo + hi);
+
+    let slope = (at_capacity.lole - at_zero.lole) / capacity_mw;
+    let elcc_ci_mw = if slope > 0.0 {
+        let sd_lole =
+            (standard_error(&baseline).powi(2) + standard_error(&crossing).powi(2)).sqrt();
+        let half = CI_Z_95 * sd_lole / slope;
+        ((elcc_mw - half).max(0.0), (elcc_mw + half).min(capacity_mw))
+    } else {
+        // Added load never changed LOLE in the sample: no information on ELCC
+        (0.0, capacity_mw)
+    };
+
+    Ok(ElccResult {
+        capacity_mw,
+        elcc_mw,
+        elcc_fraction: elcc_mw / capacity_mw,
+        elcc_ci_mw,
+        baseline_lole: baseline.lole,
+        baseline_lole_ci: baseline.lole_ci,
+        trace,
+    })
+}
+
+/// ELCC of a resource discounted by how much of its output can be delivered.
+#[derive(Debug, Clone)]
+pub struct DeliverableElccResult {
+    /// ELCC ignoring transmission limits
+    pub raw: ElccResult,
+    /// Share of the resource's capacity deliverable to load in the stress case
+    pub deliverable_fraction: f64,
+    /// Branch whose rating caps the delivery, if any does
+    pub limiting_branch: Option<String>,
+    /// `raw.elcc_mw * deliverable_fraction` (MW)
+    pub adjusted_elcc_mw: f64,
+    /// `raw.elcc_ci_mw` scaled by `deliverable_fraction`
+    pub adjusted_elcc_ci_mw: (f64, f64),
+}
+
+/// ELCC of `resource`, discounted by its deliverable fraction under
+/// transmission constraints.
+///
+/// The deliverable fraction is the Deliverability Score (DS) of
+/// [`deliverability_scores_dc`](crate::deliverability_scores_dc) for one
+/// stress case built from the network: demand at the top of the Monte Carlo
+/// demand range, served by the existing in-service generators in proportion
+/// to their capacity. From those DC flows, the resource's output is
+/// delivered to the loads in proportion to their demand, and the largest
+/// injection keeping every rated branch (`s_max` or Rate A) within limits,
+/// divided by the resource capacity and capped at 1, is the fraction. The
+/// adjusted ELCC is `DS × ELCC`; the raw result is kept alongside it.
+pub fn deliverability_constrained(
+    network: &Network,
+    resource: &Gen,
+    mc: &MonteCarlo,
+) -> Result<DeliverableElccResult> {
+    let raw = elcc(network, resource, mc)?;
+    let (deliverable_fraction, limiting_branch) =
+        deliverable_fraction(network, resource, mc.scenario_gen.demand_range.1)?;
+    Ok(DeliverableElccResult {
+        adjusted_elcc_mw: raw.elcc_mw * deliverable_fraction,
+        adjusted_elcc_ci_mw: (
+            raw.elcc_ci_mw.0 * deliverable_fraction,
+            raw.elcc_ci_mw.1 * deliverable_fraction,
+        ),
+        raw,
+        deliverable_fraction,
+        limiting_branch,
+    })
+}
+
+/// Deliverable share of `resource` at `peak_scale` times nominal demand, and
+/// the branch that limits it.
+fn deliverable_fraction(
+    network: &Network,
+    resource: &Gen,
+    peak_scale: f64,
+) -> Result<(f64, Option<String>)> {
+    let capacity_mw = resource.pmax.value();
+    let mut capacity: HashMap<BusId, f64> = HashMap::new();
+    let mut demand: HashMap<BusId, f64> = HashMap::new();
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Gen(gen) if gen.status => {
+                *capacity.entry(gen.bus).or_default() += gen.active_power.value();
+            }
+            Node::Load(load) => {
+                *demand.entry(load.bus).or_default() += load.active_power.value();
+            }
+            _ => {}
+        }
+    }
+    let total_capacity: f64 = capacity.values().sum();
+    let total_demand: f64 = demand.values().sum();
+    if total_demand <= 0.0 {
+        return Err(anyhow!("Network has no load"));
+    }
+
+    // Stress case: peak demand, or as much of it as existing units can serve
+    let served = (total_demand * peak_scale).min(total_capacity);
+    let mut injection: HashMap<BusId, f64> = HashMap::new();
+    for (bus, mw) in &capacity {
+        *injection.entry(*bus).or_default() += served * mw / total_capacity;
+    }
+    for (bus, mw) in &demand {
+        *injection.entry(*bus).or_default() -= served * mw / total_demand;
+    }
+
+    let ptdf = SparsePtdf::compute_ptdf(network)
+        .map_err(|e| anyhow!("PTDF for deliverability failed: {}", e))?;
+    let factor = |branch, bus| ptdf.get(branch, bus).unwrap_or(0.0);
+
+    let mut deliverable_mw = f64::INFINITY;
+    let mut limiting_branch = None;
+    for edge in network.graph.edge_weights() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        let Some(limit) = branch.s_max.or(branch.rating_a).map(|r| r.value()) else {
+            continue;
+        };
+        let flow: f64 = injection
+            .iter()
+            .map(|(bus, mw)| factor(branch.id, *bus) * mw)
+            .sum();
+        // Resource bus to loads, shared in proportion to demand
+        let transfer = factor(branch.id, resource.bus)
+            - demand
+                .iter()
+                .map(|(bus, mw)| factor(branch.id, *bus) * mw / total_demand)
+                .sum::<f64>();
+        if let Some(bound) = branch_bound(limit, flow, transfer) {
+            if bound < deliverable_mw {
+                deliverable_mw = bound;
+                limiting_branch = Some(branch.name.clone());
+            }
+        }
+    }
+    if deliverable_mw >= capacity_mw {
+        return Ok((1.0, None));
+    }
+    Ok((deliverable_mw / capacity_mw, limiting_branch))
+}
+
+/// Full-run LOLE estimate with its sampling interval.
+fn lole_estimate(mc: &MonteCarlo, network: &Network) -> Result<MonteCarloProgress> {
+    let mut last = None;
+    mc.compute_reliability_with_progress(network, mc.num_scenarios.max(1), |progress| {
+        last = Some(progress.clone())
+    })?;
+    last.ok_or_else(|| anyhow!("Monte Carlo run evaluated no scenarios"))
+}
+
+/// Standard error of the LOLE estimate, recovered from its 95% interval.
+fn standard_error(estimate: &MonteCarloProgress) -> f64 {
+    (estimate.lole_ci.1 - estimate.lole) / CI_Z_95
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ElccSummary {
@@ -1,7 +1,33 @@
 use anyhow::Result;
-use gat_core::Network;
+use gat_core::{BusId, Network};
 use std::path::Path;
 
+/// Mean Earth radius (km) used for great-circle distances
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// WGS84 coordinate in decimal degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/// Point feature (substation, tower, facility) from a point layer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointFeature {
+    pub id: String,
+    pub location: GeoPoint,
+}
+
+/// Nearest point feature to a bus. Both fields are `None` when the bus has
+/// no coordinate or the layer is empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearestFeature {
+    pub bus_id: BusId,
+    pub feature_id: Option<String>,
+    pub distance_km: Option<f64>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct GeoJoinSummary {
     pub num_buses: usize,
@@ -26,3 +52,85 @@ pub fn perform_spatial_join(
         num_unmapped: 0,
     })
 }
+
+/// Great-circle (haversine) distance between two coordinates in km
+pub fn haversine_km(a: GeoPoint, b: GeoPoint) -> f64 {
+    let (lat_a, lat_b) = (a.lat.to_radians(), b.lat.to_radians());
+    let half_dlat = (lat_b - lat_a) / 2.0;
+    let half_dlon = (b.lon - a.lon).to_radians() / 2.0;
+    let h = half_dlat.sin().powi(2) + lat_a.cos() * lat_b.cos() * half_dlon.sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+}
+
+/// Nearest feature of `features` to each bus, with its haversine distance.
+///
+/// Rows follow the order of `buses`; ties go to the feature listed first.
+pub fn nearest_features(
+    buses: &[(BusId, Option<GeoPoint>)],
+    features: &[PointFeature],
+) -> Vec<NearestFeature> {
+    buses
+        .iter()
+        .map(|&(bus_id, location)| {
+            let nearest = location.and_then(|location| {
+                features
+                    .iter()
+                    .map(|feature| (feature, haversine_km(location, feature.location)))
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+            });
+            NearestFeature {
+                bus_id,
+                feature_id: nearest.map(|(feature, _)| feature.id.clone()),
+                distance_km: nearest.map(|(_, km)| km),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lon: f64) -> GeoPoint {
+        GeoPoint { lat, lon }
+    }
+
+    #[test]
+    fn test_nearest_features_assign_closest_point() {
+        let features = vec![
+            PointFeature {
+                id: "sub_a".to_string(),
+                location: point(0.0, 0.0),
+            },
+            PointFeature {
+                id: "sub_b".to_string(),
+                location: point(0.0, 1.0),
+            },
+        ];
+        let buses = vec![
+            (BusId::new(1), Some(point(0.0, 0.2))),
+            (BusId::new(2), Some(point(0.0, 0.9))),
+            (BusId::new(3), None),
+        ];
+
+        let nearest = nearest_features(&buses, &features);
+        // Along the equator the distance is R·Δλ
+        let km_per_degree = EARTH_RADIUS_KM * 1f64.to_radians();
+        assert_eq!(nearest[0].feature_id.as_deref(), Some("sub_a"));
+        assert!((nearest[0].distance_km.unwrap() - 0.2 * km_per_degree).abs() < 1e-9);
+        assert_eq!(nearest[1].feature_id.as_deref(), Some("sub_b"));
+        assert!((nearest[1].distance_km.unwrap() - 0.1 * km_per_degree).abs() < 1e-9);
+        assert_eq!(
+            nearest[2],
+            NearestFeature {
+                bus_id: BusId::new(3),
+                feature_id: None,
+                distance_km: None,
+            }
+        );
+
+        // One degree of latitude apart, off the equator
+        let km = haversine_km(point(45.0, -120.0), point(46.0, -120.0));
+        assert!((km - km_per_degree).abs() < 1e-9);
+    }
+}
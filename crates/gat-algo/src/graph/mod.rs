@@ -3,6 +3,7 @@
 //! This module provides graph-theoretic algorithms for power systems, including:
 //! - **Partitioning**: Split networks into regions for distributed optimization
 //! - **Connectivity**: Find islands, articulation points, and bridges
+//! - **Radialization**: Open loops at minimum spanning-tree cost ([`radialize`])
 //!
 //! # Partitioning for Distributed OPF
 //!
@@ -27,7 +28,9 @@
 //! ```
 
 pub mod partition;
+pub mod radial;
 
 pub use partition::{
     partition_network, NetworkPartition, PartitionError, PartitionStrategy, TieLine,
 };
+pub use radial::{radialize, RadialConfiguration, RadialError, RadialWeight};
@@ -812,6 +812,7 @@ mod tests {
                 vmax_pu: None,
                 area_id: None,
                 zone_id: zone,
+                bus_type: None,
             }));
             bus_indices.push(idx);
         }
@@ -832,6 +833,7 @@ mod tests {
                 bus: BusId::new(i),
                 active_power: Megawatts(25.0),
                 reactive_power: Megavars(10.0),
+                zip: None,
             }));
         }
 
@@ -0,0 +1,192 @@
+//! Radialization of meshed feeders by minimum spanning tree.
+//!
+//! Distribution feeders are built with loops (tie switches) but operated
+//! radially. [`radialize`] picks the minimum-weight spanning tree of the
+//! in-service branches with Kruskal's algorithm and returns the branches to
+//! open. Every bus stays connected to the buses it could reach before, so the
+//! number of islands does not change.
+//!
+//! Transformers are never opened: they are added to the tree first, and only
+//! lines compete for the remaining connections. Branches already out of
+//! service are ignored.
+//!
+//! ```ignore
+//! use gat_algo::graph::{radialize, RadialWeight};
+//!
+//! let config = radialize(&network, RadialWeight::Impedance)?;
+//! println!("open: {:?}", config.opened);
+//! ```
+
+use std::collections::HashMap;
+
+use gat_core::{Branch, Edge, Network};
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::EdgeRef;
+use thiserror::Error;
+
+/// Error type for radialization.
+#[derive(Debug, Error)]
+pub enum RadialError {
+    /// A branch has no entry in the length map
+    #[error("No length given for branch {0}")]
+    MissingLength(String),
+}
+
+/// Per-branch cost minimized by the spanning tree.
+#[derive(Debug, Clone)]
+pub enum RadialWeight {
+    /// Series impedance magnitude `|r + jx|` (p.u.)
+    Impedance,
+    /// Series resistance (p.u.), a proxy for losses
+    Resistance,
+    /// Line length keyed by branch name, in any consistent unit
+    Length(HashMap<String, f64>),
+}
+
+impl RadialWeight {
+    fn of(&self, branch: &Branch) -> Result<f64, RadialError> {
+        match self {
+            RadialWeight::Impedance => Ok(branch.resistance.hypot(branch.reactance)),
+            RadialWeight::Resistance => Ok(branch.resistance),
+            RadialWeight::Length(lengths) => lengths
+                .get(&branch.name)
+                .copied()
+                .ok_or_else(|| RadialError::MissingLength(branch.name.clone())),
+        }
+    }
+}
+
+/// A radial operating configuration.
+#[derive(Debug, Clone, Default)]
+pub struct RadialConfiguration {
+    /// Branches to open, sorted by name
+    pub opened: Vec<String>,
+    /// In-service branches that stay closed, sorted by name
+    pub closed: Vec<String>,
+    /// Total weight of the closed branches
+    pub total_weight: f64,
+}
+
+/// Find the branches to open to make `network` radial at minimum `weight`.
+///
+/// Ties between equal weights are broken by branch name, so the result is
+/// deterministic.
+pub fn radialize(
+    network: &Network,
+    weight: RadialWeight,
+) -> Result<RadialConfiguration, RadialError> {
+    let graph = &network.graph;
+    let mut forest = UnionFind::new(graph.node_count());
+
+    // (weight, name, endpoints) for each in-service line
+    let mut lines = Vec::new();
+    for edge in graph.edge_references() {
+        let (from, to) = (edge.source().index(), edge.target().index());
+        match edge.weight() {
+            Edge::Transformer(_) => {
+                forest.union(from, to);
+            }
+            Edge::Branch(branch) if branch.status => {
+                lines.push((weight.of(branch)?, branch.name.as_str(), from, to));
+            }
+            Edge::Branch(_) => {}
+        }
+    }
+    lines.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+
+    let mut config = RadialConfiguration::default();
+    for (w, name, from, to) in lines {
+        if forest.union(from, to) {
+            config.closed.push(name.to_string());
+            config.total_weight += w;
+        } else {
+            config.opened.push(name.to_string());
+        }
+    }
+    config.opened.sort();
+    config.closed.sort();
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{BranchId, Bus, BusId, Node};
+    use petgraph::algo::connected_components;
+
+    /// Feeder 1-2-3-4 with a tie switch 4-1 closing the loop. The tie has
+    /// the highest impedance, section 2-3 the highest resistance.
+    fn single_loop_feeder() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=4)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        let sections = [
+            (1, 2, 0.01, 0.02),
+            (2, 3, 0.05, 0.02),
+            (3, 4, 0.01, 0.02),
+            (4, 1, 0.02, 0.2),
+        ];
+        for (k, &(from, to, r, x)) in sections.iter().enumerate() {
+            network.graph.add_edge(
+                buses[from - 1],
+                buses[to - 1],
+                Edge::Branch(Branch::new(
+                    BranchId::new(k),
+                    format!("line{}_{}", from, to),
+                    BusId::new(from),
+                    BusId::new(to),
+                    r,
+                    x,
+                )),
+            );
+        }
+        network
+    }
+
+    /// Copy of `network` without the `opened` branches.
+    fn open(network: &Network, opened: &[String]) -> Network {
+        let mut result = network.clone();
+        result.graph.retain_edges(|graph, edge| match &graph[edge] {
+            Edge::Branch(branch) => !opened.contains(&branch.name),
+            Edge::Transformer(_) => true,
+        });
+        result
+    }
+
+    #[test]
+    fn test_single_loop_opens_one_branch() {
+        let network = single_loop_feeder();
+        let config = radialize(&network, RadialWeight::Impedance).unwrap();
+
+        assert_eq!(config.opened, vec!["line4_1".to_string()]);
+        assert_eq!(config.closed.len(), 3);
+
+        let radial = open(&network, &config.opened);
+        // A tree: connected, with one fewer edge than buses
+        assert_eq!(connected_components(&radial.graph), 1);
+        assert_eq!(radial.graph.edge_count(), radial.graph.node_count() - 1);
+    }
+
+    #[test]
+    fn test_weight_choice_changes_opened_branch() {
+        let network = single_loop_feeder();
+        let config = radialize(&network, RadialWeight::Resistance).unwrap();
+        assert_eq!(config.opened, vec!["line2_3".to_string()]);
+
+        let lengths: HashMap<String, f64> = [("line1_2", 1.0), ("line2_3", 1.0)]
+            .into_iter()
+            .map(|(name, km)| (name.to_string(), km))
+            .collect();
+        assert!(matches!(
+            radialize(&network, RadialWeight::Length(lengths)),
+            Err(RadialError::MissingLength(_))
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::{Context, Result};
+use gat_schemas::result::{result_types, write_result_metadata, ResultMetadata};
 use polars::frame::group_by::GroupsIndicator;
 use polars::prelude::{DataFrame, IdxCa, IdxSize, NamedFrom, ParquetWriter};
 use std::{
@@ -48,6 +49,36 @@ impl OutputStage {
             OutputStage::FeaturizeKpi => "featurize-kpi",
         }
     }
+
+    /// Result tags for stages that hold solver results; `None` for analytics
+    /// and featurization stages.
+    pub fn result_tags(&self) -> Option<ResultMetadata> {
+        let (result_type, solver) = match self {
+            OutputStage::PfDc => (result_types::PF, "dc-pf"),
+            OutputStage::PfAc => (result_types::PF, "ac-pf"),
+            OutputStage::OpfDc => (result_types::OPF, "dc-opf"),
+            OutputStage::OpfAc => (result_types::OPF, "ac-opf"),
+            OutputStage::Nminus1Dc => (result_types::CONTINGENCY, "dc-nminus1"),
+            OutputStage::SeWls => (result_types::STATE_ESTIMATION, "wls"),
+            _ => return None,
+        };
+        Some(ResultMetadata::new(result_type).with_solver(solver))
+    }
+
+    /// The stage whose [`as_str`](Self::as_str) is `stage`, if it has result tags.
+    fn tags_for(stage: &str) -> Option<ResultMetadata> {
+        [
+            OutputStage::PfDc,
+            OutputStage::PfAc,
+            OutputStage::OpfDc,
+            OutputStage::OpfAc,
+            OutputStage::Nminus1Dc,
+            OutputStage::SeWls,
+        ]
+        .into_iter()
+        .find(|candidate| candidate.as_str() == stage)
+        .and_then(|candidate| candidate.result_tags())
+    }
 }
 
 pub fn staged_output_path(output: &Path, stage: &str) -> PathBuf {
@@ -63,6 +94,7 @@ pub fn persist_dataframe(
     stage: &str,
 ) -> Result<()> {
     let staged = staged_output_path(output, stage);
+    let tags = OutputStage::tags_for(stage);
     if partitions.is_empty() {
         if let Some(parent) = staged.parent() {
             fs::create_dir_all(parent)
@@ -73,6 +105,9 @@ pub fn persist_dataframe(
         ParquetWriter::new(&mut file)
             .finish(df)
             .context("writing Parquet output")?;
+        if let Some(tags) = &tags {
+            write_result_metadata(&staged, tags)?;
+        }
         if let Some(parent) = output.parent() {
             fs::create_dir_all(parent)
                 .with_context(|| format!("creating output directory '{}'", parent.display()))?;
@@ -80,12 +115,17 @@ pub fn persist_dataframe(
         fs::copy(&staged, output)
             .with_context(|| format!("copying {} to {}", staged.display(), output.display()))?;
     } else {
-        write_partitions(df, &staged, partitions)?;
+        write_partitions(df, &staged, partitions, tags.as_ref())?;
     }
     Ok(())
 }
 
-fn write_partitions(df: &DataFrame, output: &Path, partitions: &[String]) -> Result<()> {
+fn write_partitions(
+    df: &DataFrame,
+    output: &Path,
+    partitions: &[String],
+    tags: Option<&ResultMetadata>,
+) -> Result<()> {
     let group_by = df.group_by(partitions)?;
     let groups = group_by.get_groups();
     for (i, group) in groups.iter().enumerate() {
@@ -97,12 +137,17 @@ fn write_partitions(df: &DataFrame, output: &Path, partitions: &[String]) -> Res
             GroupsIndicator::Slice([first, len]) => (df.slice(first as i64, len as usize), first),
         };
         let dir = partition_dir(output, partitions, df, first)?;
-        write_partition_file(&mut partition_df, &dir, i)?;
+        write_partition_file(&mut partition_df, &dir, i, tags)?;
     }
     Ok(())
 }
 
-fn write_partition_file(df: &mut DataFrame, dir: &Path, index: usize) -> Result<()> {
+fn write_partition_file(
+    df: &mut DataFrame,
+    dir: &Path,
+    index: usize,
+    tags: Option<&ResultMetadata>,
+) -> Result<()> {
     fs::create_dir_all(dir)
         .with_context(|| format!("creating partition directory '{}'", dir.display()))?;
     let file_path = dir.join(format!("part-{index:04}.parquet"));
@@ -111,6 +156,9 @@ fn write_partition_file(df: &mut DataFrame, dir: &Path, index: usize) -> Result<
     ParquetWriter::new(&mut file)
         .finish(df)
         .with_context(|| format!("writing partition file '{}'", file_path.display()))?;
+    if let Some(tags) = tags {
+        write_result_metadata(&file_path, tags)?;
+    }
     Ok(())
 }
 
@@ -11,6 +11,7 @@
 //! | Method | Description | Problem Class |
 //! |--------|-------------|---------------|
 //! | [`OpfMethod::EconomicDispatch`] | Merit-order dispatch without network | Linear |
+//! | [`OpfMethod::Copperplate`] | Single-node dispatch with one system price | Convex |
 //! | [`OpfMethod::DcOpf`] | Linear DC approximation with PTDF flows | Linear |
 //! | [`OpfMethod::SocpRelaxation`] | Convex SOCP relaxation of AC-OPF | Conic |
 //! | [`OpfMethod::AcOpf`] | Full nonlinear AC-OPF | Nonlinear |
@@ -108,30 +109,37 @@ pub mod power_flow;
 #[cfg(feature = "desktop")]
 pub mod reliability_monte_carlo;
 #[cfg(feature = "desktop")]
+pub mod ts_opf;
+#[cfg(feature = "desktop")]
 pub mod workflows;
 
 // GPU-accelerated modules (optional feature)
 #[cfg(feature = "gpu")]
 pub mod gpu_monte_carlo;
 #[cfg(feature = "gpu")]
-pub use gpu_monte_carlo::GpuMonteCarlo;
+pub use gpu_monte_carlo::{BackendParity, GpuMonteCarlo};
 
 // Core re-exports (always available)
-pub use ac_opf::{AcOpfError, AcOpfSolution, AcOpfSolver, OpfError};
+pub use ac_opf::{AcObjective, AcOpfError, AcOpfSolution, AcOpfSolver, OpfError};
 pub use arena::ArenaContext;
-pub use graph::{partition_network, NetworkPartition, PartitionError, PartitionStrategy, TieLine};
-pub use opf::{ConstraintInfo, ConstraintType, OpfMethod, OpfSolution, OpfSolver};
+pub use graph::{
+    partition_network, radialize, NetworkPartition, PartitionError, PartitionStrategy,
+    RadialConfiguration, RadialError, RadialWeight, TieLine,
+};
+pub use opf::{ConstraintId, ConstraintInfo, ConstraintType, OpfMethod, OpfSolution, OpfSolver};
 pub use sparse::{
     IncrementalSolver, LodfMatrix, PtdfMatrix, SparsePtdf, SparseSusceptance, SparseYBus,
-    SusceptanceError, WoodburyUpdate, YBusError,
+    SusceptanceError, WarmDcSolution, WarmSolver, WarmSolverError, WoodburyUpdate, YBusError,
 };
 pub use tep::{
-    solve_tep, CandidateId, CandidateLine, LineBuildDecision, TepError, TepProblem,
-    TepProblemBuilder, TepSolution, TepSolverConfig,
+    solve_tep, CandidateId, CandidateLine, LineBuildDecision, TepError, TepHeuristicConfig,
+    TepProblem, TepProblemBuilder, TepSolution, TepSolverConfig,
 };
 pub use validation::{
-    compute_opf_violations, compute_opf_violations_from_solution, compute_pf_errors,
-    OPFViolationMetrics, ObjectiveGap, PFErrorMetrics, PFReferenceSolution,
+    check_base_consistency, check_ramp_feasibility, compute_opf_violations,
+    compute_opf_violations_from_solution, compute_pf_errors, BaseConsistencyResult, BaseIssue,
+    BaseIssueKind, OPFViolationMetrics, ObjectiveGap, PFErrorMetrics, PFReferenceSolution,
+    RampFeasibilityResult, RampViolation,
 };
 
 // Desktop-only re-exports
@@ -145,8 +153,9 @@ pub use analytics_ds::*;
 pub use analytics_reliability::*;
 #[cfg(feature = "desktop")]
 pub use canos_multiarea::{
-    AreaId, AreaLoleMetrics, Corridor, MultiAreaMonteCarlo, MultiAreaOutageScenario,
-    MultiAreaSystem,
+    reduce_to_areas, tie_flow_attribution, AreaId, AreaLoleMetrics, AreaReduction, AreaTie,
+    Corridor, MultiAreaMonteCarlo, MultiAreaOutageScenario, MultiAreaSystem, TieFlowAllocation,
+    TieFlowAttribution,
 };
 #[cfg(feature = "desktop")]
 pub use elcc::*;
@@ -164,8 +173,8 @@ pub use io::*;
 pub use power_flow::*;
 #[cfg(feature = "desktop")]
 pub use reliability_monte_carlo::{
-    DeliverabilityScore, DeliverabilityScoreConfig, MonteCarlo, OutageGenerator, OutageScenario,
-    ReliabilityMetrics,
+    DeliverabilityScore, DeliverabilityScoreConfig, LossOfLoadEvent, MonteCarlo,
+    MonteCarloProgress, OutageCost, OutageGenerator, OutageScenario, ReliabilityMetrics, Voll,
 };
 #[cfg(feature = "desktop")]
 pub use workflows::PowerFlowAnalysis;
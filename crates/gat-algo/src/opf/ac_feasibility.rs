@@ -0,0 +1,320 @@
+//! AC feasibility re-check of an OPF solution.
+//!
+//! Relaxed and approximate formulations (SOCP, DC, linearized AC) return
+//! setpoints that need not be realizable by the real network. When the SOCP
+//! relaxation is inexact, for instance, part of its "losses" are fictitious
+//! and the true losses differ. [`verify_ac_feasibility`] fixes the generator
+//! active power and voltage setpoints from a solution, runs a full AC power
+//! flow, and checks the resulting operating point against the network limits:
+//!
+//! - bus voltage magnitudes against `[v_min, v_max]`
+//! - branch apparent power at either end against `s_max` (or Rate A)
+//! - generator active power against `[pmin, pmax]` (the slack unit absorbs
+//!   any loss mismatch)
+//! - generator reactive power against `[qmin, qmax]`
+//!
+//! Limits are checked with small tolerances ([`VOLTAGE_TOLERANCE_PU`],
+//! [`POWER_TOLERANCE_MW`]) so solver round-off is not reported. A slack pickup
+//! above [`SLACK_MISMATCH_MW`] is not a violation but is reported as a
+//! [`SolverWarning::SlackAbsorbedLargeMismatch`].
+
+use super::ac_nlp::{compute_branch_apparent_power, AcOpfProblem};
+use super::{OpfSolution, SolverWarning};
+use crate::power_flow::ac_pf::AcPowerFlowSolver;
+use crate::OpfError;
+use gat_core::{BusId, Edge, Megawatts, Network, Node, PerUnit};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Voltage limit tolerance (p.u.)
+pub const VOLTAGE_TOLERANCE_PU: f64 = 1e-4;
+
+/// Power limit tolerance for generator and branch checks (MW, MVAr, MVA)
+pub const POWER_TOLERANCE_MW: f64 = 1e-2;
+
+/// Slack pickup (MW) beyond which the solution's losses are reported as
+/// far from the AC losses
+pub const SLACK_MISMATCH_MW: f64 = 1.0;
+
+/// Convergence tolerance for the AC power flow (p.u. mismatch)
+const PF_TOLERANCE: f64 = 1e-8;
+
+/// Iteration limit for the AC power flow
+const PF_MAX_ITERATIONS: usize = 50;
+
+/// A limit exceeded by the AC operating point.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AcViolation {
+    /// Bus voltage magnitude outside `[v_min, v_max]`
+    Voltage {
+        bus: String,
+        vm_pu: f64,
+        limit_pu: f64,
+    },
+    /// Branch apparent power above its thermal rating (larger of both ends)
+    Thermal {
+        branch: String,
+        flow_mva: f64,
+        limit_mva: f64,
+    },
+    /// Generator active power outside `[pmin, pmax]`
+    GeneratorP {
+        generator: String,
+        p_mw: f64,
+        limit_mw: f64,
+    },
+    /// Generator reactive power outside `[qmin, qmax]`
+    GeneratorQ {
+        generator: String,
+        q_mvar: f64,
+        limit_mvar: f64,
+    },
+}
+
+impl AcViolation {
+    /// Amount by which the limit is exceeded, in the unit of the limit.
+    pub fn excess(&self) -> f64 {
+        match self {
+            AcViolation::Voltage {
+                vm_pu, limit_pu, ..
+            } => (vm_pu - limit_pu).abs(),
+            AcViolation::Thermal {
+                flow_mva,
+                limit_mva,
+                ..
+            } => flow_mva - limit_mva,
+            AcViolation::GeneratorP { p_mw, limit_mw, .. } => (p_mw - limit_mw).abs(),
+            AcViolation::GeneratorQ {
+                q_mvar, limit_mvar, ..
+            } => (q_mvar - limit_mvar).abs(),
+        }
+    }
+}
+
+impl fmt::Display for AcViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AcViolation::Voltage {
+                bus,
+                vm_pu,
+                limit_pu,
+            } => write!(
+                f,
+                "bus {} voltage {:.4} p.u. beyond limit {:.4} p.u.",
+                bus, vm_pu, limit_pu
+            ),
+            AcViolation::Thermal {
+                branch,
+                flow_mva,
+                limit_mva,
+            } => write!(
+                f,
+                "branch {} flow {:.2} MVA above rating {:.2} MVA",
+                branch, flow_mva, limit_mva
+            ),
+            AcViolation::GeneratorP {
+                generator,
+                p_mw,
+                limit_mw,
+            } => write!(
+                f,
+                "generator {} output {:.2} MW beyond limit {:.2} MW",
+                generator, p_mw, limit_mw
+            ),
+            AcViolation::GeneratorQ {
+                generator,
+                q_mvar,
+                limit_mvar,
+            } => write!(
+                f,
+                "generator {} output {:.2} MVAr beyond limit {:.2} MVAr",
+                generator, q_mvar, limit_mvar
+            ),
+        }
+    }
+}
+
+/// Outcome of re-simulating an OPF solution with AC power flow.
+#[derive(Debug, Clone, Default)]
+pub struct AcFeasibilityReport {
+    /// True when the power flow converged and no limit is violated
+    pub feasible: bool,
+    /// Whether the AC power flow converged at the fixed setpoints
+    pub pf_converged: bool,
+    /// Limits exceeded by the AC operating point
+    pub violations: Vec<AcViolation>,
+    /// AC bus voltage magnitudes (p.u.), keyed by bus name
+    pub bus_voltage_mag: HashMap<String, f64>,
+    /// AC generator active power (MW), including the slack pickup
+    pub generator_p: HashMap<String, f64>,
+    /// AC generator reactive power (MVAr)
+    pub generator_q: HashMap<String, f64>,
+    /// Slack units that absorbed more than [`SLACK_MISMATCH_MW`]
+    pub warnings: Vec<SolverWarning>,
+}
+
+impl AcFeasibilityReport {
+    /// Largest violation excess, or 0 when there are none.
+    pub fn max_violation(&self) -> f64 {
+        self.violations
+            .iter()
+            .fold(0.0, |acc, violation| acc.max(violation.excess()))
+    }
+}
+
+/// Re-simulate `solution` with AC power flow on `network` and check limits.
+///
+/// Generators keep the active power and bus voltage magnitude from the
+/// solution; units missing from it keep their network values. The first
+/// generator's bus is the slack, so its output absorbs the difference between
+/// the solution's losses and the true AC losses. A power flow that does not
+/// converge is reported as infeasible with no violation list.
+pub fn verify_ac_feasibility(
+    network: &Network,
+    solution: &OpfSolution,
+) -> Result<AcFeasibilityReport, OpfError> {
+    let bus_names: HashMap<BusId, String> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => Some((bus.id, bus.name.clone())),
+            _ => None,
+        })
+        .collect();
+
+    let mut fixed = network.clone();
+    for node in fixed.graph.node_weights_mut() {
+        let Node::Gen(gen) = node else {
+            continue;
+        };
+        if let Some(&p) = solution.generator_p.get(&gen.name) {
+            gen.active_power = Megawatts(p);
+        }
+        let vm = bus_names
+            .get(&gen.bus)
+            .and_then(|name| solution.bus_voltage_mag.get(name));
+        if let Some(&vm) = vm {
+            gen.voltage_setpoint = Some(PerUnit(vm));
+        }
+    }
+
+    let pf = match AcPowerFlowSolver::new()
+        .with_tolerance(PF_TOLERANCE)
+        .with_max_iterations(PF_MAX_ITERATIONS)
+        .solve(&fixed)
+    {
+        Ok(pf) if pf.converged => pf,
+        _ => return Ok(AcFeasibilityReport::default()),
+    };
+
+    let mut report = AcFeasibilityReport {
+        pf_converged: true,
+        ..Default::default()
+    };
+
+    // Bus voltages
+    let problem = AcOpfProblem::from_network(&fixed)?;
+    let vm_of = |id: &BusId| pf.bus_voltage_magnitude.get(id).copied().unwrap_or(1.0);
+    for bus in &problem.buses {
+        let vm = vm_of(&bus.id);
+        report.bus_voltage_mag.insert(bus.name.clone(), vm);
+        let limit_pu = if vm > bus.v_max + VOLTAGE_TOLERANCE_PU {
+            bus.v_max
+        } else if vm < bus.v_min - VOLTAGE_TOLERANCE_PU {
+            bus.v_min
+        } else {
+            continue;
+        };
+        report.violations.push(AcViolation::Voltage {
+            bus: bus.name.clone(),
+            vm_pu: vm,
+            limit_pu,
+        });
+    }
+
+    // Branch thermal limits
+    let ratings: HashMap<&str, f64> = fixed
+        .graph
+        .edge_weights()
+        .filter_map(|edge| match edge {
+            Edge::Branch(branch) => branch
+                .s_max
+                .or(branch.rating_a)
+                .map(|rating| (branch.name.as_str(), rating.value())),
+            _ => None,
+        })
+        .collect();
+    let v: Vec<f64> = problem.buses.iter().map(|bus| vm_of(&bus.id)).collect();
+    let theta: Vec<f64> = problem
+        .buses
+        .iter()
+        .map(|bus| pf.bus_voltage_angle.get(&bus.id).copied().unwrap_or(0.0))
+        .collect();
+    let apparent = compute_branch_apparent_power(&problem, &v, &theta);
+    for (branch, (s_from, s_to)) in problem.branches.iter().zip(apparent) {
+        let Some(&limit_mva) = ratings.get(branch.name.as_str()) else {
+            continue;
+        };
+        let flow_mva = s_from.max(s_to);
+        if limit_mva > 0.0 && flow_mva > limit_mva + POWER_TOLERANCE_MW {
+            report.violations.push(AcViolation::Thermal {
+                branch: branch.name.clone(),
+                flow_mva,
+                limit_mva,
+            });
+        }
+    }
+
+    // Generator limits
+    for node in fixed.graph.node_weights() {
+        let Node::Gen(gen) = node else {
+            continue;
+        };
+        if !gen.status {
+            continue;
+        }
+        let p = pf.generator_p_mw.get(&gen.id).copied().unwrap_or(0.0);
+        let q = pf.generator_q_mvar.get(&gen.id).copied().unwrap_or(0.0);
+        report.generator_p.insert(gen.name.clone(), p);
+        report.generator_q.insert(gen.name.clone(), q);
+
+        let mismatch_mw = p - gen.active_power.value();
+        if pf.slack_buses.contains(&gen.bus) && mismatch_mw.abs() > SLACK_MISMATCH_MW {
+            report
+                .warnings
+                .push(SolverWarning::SlackAbsorbedLargeMismatch {
+                    generator: gen.name.clone(),
+                    mismatch_mw,
+                });
+        }
+
+        if let Some(limit_mw) = outside(p, gen.pmin.value(), gen.pmax.value()) {
+            report.violations.push(AcViolation::GeneratorP {
+                generator: gen.name.clone(),
+                p_mw: p,
+                limit_mw,
+            });
+        }
+        if let Some(limit_mvar) = outside(q, gen.qmin.value(), gen.qmax.value()) {
+            report.violations.push(AcViolation::GeneratorQ {
+                generator: gen.name.clone(),
+                q_mvar: q,
+                limit_mvar,
+            });
+        }
+    }
+
+    report.feasible = report.violations.is_empty();
+    Ok(report)
+}
+
+/// The bound `value` exceeds by more than [`POWER_TOLERANCE_MW`], if any.
+fn outside(value: f64, min: f64, max: f64) -> Option<f64> {
+    if value > max + POWER_TOLERANCE_MW {
+        Some(max)
+    } else if value < min - POWER_TOLERANCE_MW {
+        Some(min)
+    } else {
+        None
+    }
+}
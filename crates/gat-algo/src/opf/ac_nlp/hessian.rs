@@ -28,10 +28,11 @@
 //!       V      │ H_VV      │ H_Vθ      │ 0         │ 0
 //!       θ      │ H_θV      │ H_θθ      │ 0         │ 0
 //!       P_g    │ 0         │ 0         │ H_PP      │ 0
-//!       Q_g    │ 0         │ 0         │ 0         │ 0
+//!       Q_g    │ 0         │ 0         │ 0         │ H_QQ
 //! ```
 //!
 //! - **H_PP** comes from quadratic objective (diagonal: 2·c₂·S_base²)
+//! - **H_QQ** comes from the smoothed reactive cost (diagonal)
 //! - **H_VV, H_Vθ, H_θθ** come from power balance constraint Hessians
 //!
 //! ## Power Balance Constraint Hessians
@@ -60,6 +61,7 @@
 //! - Sparse Hessian: O(nnz(Y-bus) + m)
 
 use super::{AcOpfProblem, BranchData, BusData, YBus};
+use crate::AcObjective;
 
 /// Compute the sparsity pattern of the Hessian (lower triangular).
 ///
@@ -81,11 +83,12 @@ pub fn hessian_sparsity(problem: &AcOpfProblem) -> (Vec<usize>, Vec<usize>) {
     // - θ-V block: n_bus * n_bus (where row >= col)
     // - θ-θ block: n_bus * (n_bus + 1) / 2 (lower triangular)
     // - P_g diagonal: n_gen
+    // - Q_g diagonal: n_gen
     // Note: Thermal constraint entries overlap with dense pattern above
     let vv_nnz = n_bus * (n_bus + 1) / 2;
     let tv_nnz = n_bus * n_bus; // conservative estimate
     let tt_nnz = n_bus * (n_bus + 1) / 2;
-    let nnz_estimate = vv_nnz + tv_nnz + tt_nnz + n_gen;
+    let nnz_estimate = vv_nnz + tv_nnz + tt_nnz + 2 * n_gen;
     let mut rows = Vec::with_capacity(nnz_estimate);
     let mut cols = Vec::with_capacity(nnz_estimate);
 
@@ -144,7 +147,15 @@ pub fn hessian_sparsity(problem: &AcOpfProblem) -> (Vec<usize>, Vec<usize>) {
         cols.push(problem.pg_offset + i);
     }
 
-    // Q_g has no Hessian contribution (not in objective with quadratic terms)
+    // ========================================================================
+    // BLOCK 4b: Q_g diagonal (from smoothed reactive cost)
+    // ========================================================================
+    // ∂²f/∂Q_g² = c_q·ε²/(Q² + ε²)^{3/2}·S_base² (diagonal only)
+
+    for i in 0..n_gen {
+        rows.push(problem.qg_offset + i);
+        cols.push(problem.qg_offset + i);
+    }
 
     // ========================================================================
     // BLOCK 5: Thermal constraint Hessians (already covered by dense pattern)
@@ -192,12 +203,14 @@ pub fn hessian_values(
     // θ-V: n_bus*n_bus (but only lower triangular counted)
     // θ-θ: n_bus*(n_bus+1)/2
     // P_g: n_gen
+    // Q_g: n_gen
     let n_vv = n_bus * (n_bus + 1) / 2;
     let n_theta_v = n_bus * n_bus; // Will be filtered to lower triangular
     let n_theta_theta = n_bus * (n_bus + 1) / 2;
     let n_pg = n_gen;
+    let n_qg = n_gen;
 
-    let nnz_estimate = n_vv + n_theta_v + n_theta_theta + n_pg;
+    let nnz_estimate = n_vv + n_theta_v + n_theta_theta + n_pg + n_qg;
     let mut vals = vec![0.0; nnz_estimate];
 
     // Extract voltages and angles
@@ -257,16 +270,53 @@ pub fn hessian_values(
     // But P_g is in per-unit, so we need to scale:
     // If P_MW = P_pu · S_base, then ∂f/∂P_pu = ∂f/∂P_MW · S_base
     // And ∂²f/∂P_pu² = ∂²f/∂P_MW² · S_base²
+    //
+    // The smoothed reactive cost adds its curvature on the Q_g diagonal the
+    // same way.
+
+    match problem.objective {
+        AcObjective::MinCost => {
+            let pg_start = n_vv + count_lower_triangular(n_bus, n_bus) + n_theta_theta;
+            let qg_start = pg_start + n_pg;
+            let s_base_sq = problem.base_mva * problem.base_mva;
 
-    let pg_start = n_vv + count_lower_triangular(n_bus, n_bus) + n_theta_theta;
-    let s_base_sq = problem.base_mva * problem.base_mva;
+            for (i, gen) in problem.generators.iter().enumerate() {
+                // Get quadratic coefficient
+                let c2 = gen.cost_coeffs.get(2).copied().unwrap_or(0.0);
 
-    for (i, gen) in problem.generators.iter().enumerate() {
-        // Get quadratic coefficient
-        let c2 = gen.cost_coeffs.get(2).copied().unwrap_or(0.0);
+                // ∂²f/∂P_g² = 2·c₂·S_base²
+                vals[pg_start + i] += obj_factor * 2.0 * c2 * s_base_sq;
 
-        // ∂²f/∂P_g² = 2·c₂·S_base²
-        vals[pg_start + i] += obj_factor * 2.0 * c2 * s_base_sq;
+                let (_, _, d2q) =
+                    gen.smoothed_reactive_cost(x[problem.qg_offset + i] * problem.base_mva);
+                vals[qg_start + i] += obj_factor * d2q * s_base_sq;
+            }
+        }
+        AcObjective::MinLosses => {
+            // loss = S_base · Σ (P_ij + P_ji), so its Hessian is the sum of the
+            // from- and to-side real power Hessians of every branch.
+            for branch in &problem.branches {
+                let (i, j) = (branch.from_idx, branch.to_idx);
+                let (_, _, _, d2p_from, _) =
+                    branch_derivs_from(branch, v[i], v[j], theta[i], theta[j]);
+                let (_, _, _, d2p_to, _) = branch_derivs_to(branch, v[i], v[j], theta[i], theta[j]);
+                let d2_loss: [f64; 10] = std::array::from_fn(|k| d2p_from[k] + d2p_to[k]);
+                add_branch_hessian(
+                    obj_factor * problem.base_mva,
+                    &d2_loss,
+                    i,
+                    j,
+                    n_bus,
+                    &mut vals,
+                );
+            }
+        }
+        AcObjective::MinVoltageDeviation => {
+            // ∂²/∂V_i² Σ(V − 1)² = 2 on the V-V diagonal
+            for i in 0..n_bus {
+                vals[hessian_index(i, i, n_bus)] += obj_factor * 2.0;
+            }
+        }
     }
 
     vals.truncate(compute_actual_nnz(n_bus, n_gen));
@@ -459,6 +509,21 @@ fn compute_thermal_hessian(
     }
 }
 
+/// Hessian entry indices (lower triangular) within the 4 branch variables
+/// [Vi, Vj, θi, θj], in the order of the `d2p`/`d2q` arrays.
+const BRANCH_HESS_PAIRS: [(usize, usize); 10] = [
+    (0, 0),
+    (1, 0),
+    (1, 1),
+    (2, 0),
+    (2, 1),
+    (2, 2),
+    (3, 0),
+    (3, 1),
+    (3, 2),
+    (3, 3),
+];
+
 /// Add thermal constraint Hessian contributions to the appropriate blocks.
 ///
 /// The Hessian entry (row, col) maps to:
@@ -487,22 +552,7 @@ fn add_thermal_hessian_contributions(
     // Variable indices in the full problem
     let vars = [bus_i, bus_j, n_bus + bus_i, n_bus + bus_j]; // [Vi, Vj, θi, θj]
 
-    // Hessian entry indices (lower triangular within the 4 variables)
-    // (0,0), (1,0), (1,1), (2,0), (2,1), (2,2), (3,0), (3,1), (3,2), (3,3)
-    let hess_pairs = [
-        (0, 0),
-        (1, 0),
-        (1, 1),
-        (2, 0),
-        (2, 1),
-        (2, 2),
-        (3, 0),
-        (3, 1),
-        (3, 2),
-        (3, 3),
-    ];
-
-    for (k, &(r, c)) in hess_pairs.iter().enumerate() {
+    for (k, &(r, c)) in BRANCH_HESS_PAIRS.iter().enumerate() {
         // Compute ∂²h/∂x_r∂x_c
         let grad_term = 2.0 * (dp[r] * dp[c] + dq[r] * dq[c]);
         let hess_term = 2.0 * (p * d2p[k] + q * d2q[k]);
@@ -512,40 +562,59 @@ fn add_thermal_hessian_contributions(
             continue;
         }
 
-        // Map to global row/col indices
-        let global_row = vars[r];
-        let global_col = vars[c];
+        vals[hessian_index(vars[r], vars[c], n_bus)] += h_val;
+    }
+}
 
-        // Ensure lower triangular
-        let (row, col) = if global_row >= global_col {
-            (global_row, global_col)
-        } else {
-            (global_col, global_row)
-        };
-
-        // Find the index in the vals array
-        // V-V block: row, col both in [0, n_bus)
-        // θ-V block: row in [n_bus, 2*n_bus), col in [0, n_bus)
-        // θ-θ block: row, col both in [n_bus, 2*n_bus)
-
-        let idx = if row < n_bus {
-            // V-V block: lower triangular, row*(row+1)/2 + col
-            row * (row + 1) / 2 + col
-        } else if col < n_bus {
-            // θ-V block: dense, after V-V block
-            let n_vv = n_bus * (n_bus + 1) / 2;
-            let theta_row = row - n_bus;
-            n_vv + theta_row * n_bus + col
-        } else {
-            // θ-θ block: lower triangular, after V-V and θ-V blocks
-            let n_vv = n_bus * (n_bus + 1) / 2;
-            let n_theta_v = n_bus * n_bus;
-            let theta_row = row - n_bus;
-            let theta_col = col - n_bus;
-            n_vv + n_theta_v + theta_row * (theta_row + 1) / 2 + theta_col
-        };
-
-        vals[idx] += h_val;
+/// Add `scale · d2` for a function of (V_i, V_j, θ_i, θ_j) to the Hessian blocks.
+///
+/// `d2` holds the 10 lower-triangular second derivatives in the order used by
+/// [`branch_derivs_from`].
+fn add_branch_hessian(
+    scale: f64,
+    d2: &[f64; 10],
+    bus_i: usize,
+    bus_j: usize,
+    n_bus: usize,
+    vals: &mut [f64],
+) {
+    let vars = [bus_i, bus_j, n_bus + bus_i, n_bus + bus_j];
+    for (k, &(r, c)) in BRANCH_HESS_PAIRS.iter().enumerate() {
+        if d2[k] != 0.0 {
+            vals[hessian_index(vars[r], vars[c], n_bus)] += scale * d2[k];
+        }
+    }
+}
+
+/// Position of variable pair (row, col) in the Hessian value array.
+///
+/// Variables are indexed as V in [0, n_bus) and θ in [n_bus, 2·n_bus).
+fn hessian_index(global_row: usize, global_col: usize, n_bus: usize) -> usize {
+    // Ensure lower triangular
+    let (row, col) = if global_row >= global_col {
+        (global_row, global_col)
+    } else {
+        (global_col, global_row)
+    };
+
+    // V-V block: row, col both in [0, n_bus)
+    // θ-V block: row in [n_bus, 2*n_bus), col in [0, n_bus)
+    // θ-θ block: row, col both in [n_bus, 2*n_bus)
+    if row < n_bus {
+        // V-V block: lower triangular, row*(row+1)/2 + col
+        row * (row + 1) / 2 + col
+    } else if col < n_bus {
+        // θ-V block: dense, after V-V block
+        let n_vv = n_bus * (n_bus + 1) / 2;
+        let theta_row = row - n_bus;
+        n_vv + theta_row * n_bus + col
+    } else {
+        // θ-θ block: lower triangular, after V-V and θ-V blocks
+        let n_vv = n_bus * (n_bus + 1) / 2;
+        let n_theta_v = n_bus * n_bus;
+        let theta_row = row - n_bus;
+        let theta_col = col - n_bus;
+        n_vv + n_theta_v + theta_row * (theta_row + 1) / 2 + theta_col
     }
 }
 
@@ -976,7 +1045,8 @@ fn compute_actual_nnz(n_bus: usize, n_gen: usize) -> usize {
     let n_theta_v = n_bus * n_bus;
     let n_theta_theta = n_bus * (n_bus + 1) / 2;
     let n_pg = n_gen;
-    n_vv + n_theta_v + n_theta_theta + n_pg
+    let n_qg = n_gen;
+    n_vv + n_theta_v + n_theta_theta + n_pg + n_qg
 }
 
 #[cfg(test)]
@@ -1032,8 +1102,9 @@ mod tests {
         // θ-V block: 2*2 = 4 entries
         // θ-θ block: 2*(2+1)/2 = 3 entries
         // P_g block: 1 entry
-        // Total: 3 + 4 + 3 + 1 = 11 entries
-        assert_eq!(rows.len(), 11);
+        // Q_g block: 1 entry
+        // Total: 3 + 4 + 3 + 1 + 1 = 12 entries
+        assert_eq!(rows.len(), 12);
 
         // All entries should be in lower triangular (row >= col)
         for (r, c) in rows.iter().zip(cols.iter()) {
@@ -1090,7 +1161,7 @@ mod tests {
         // The P_g Hessian entry should be 2 * c2 * S_base^2
         // c2 = 0.05, S_base = 100
         // Expected: 2 * 0.05 * 100^2 = 1000
-        let pg_idx = compute_actual_nnz(problem.n_bus, problem.n_gen) - 1;
+        let pg_idx = compute_actual_nnz(problem.n_bus, problem.n_gen) - problem.n_gen - 1;
         assert!(
             (vals[pg_idx] - 1000.0).abs() < 1.0,
             "P_g Hessian entry: expected ~1000, got {}",
@@ -14,7 +14,7 @@
 #![cfg(feature = "solver-ipopt")]
 
 use super::{hessian, jacobian, AcOpfProblem};
-use crate::opf::{OpfMethod, OpfSolution};
+use crate::opf::{OpfMethod, OpfSolution, OpfTolerances};
 use crate::OpfError;
 use gat_ipopt_sys::{BasicProblem, ConstrainedProblem, Index, Ipopt, Number, SolveStatus};
 
@@ -109,7 +109,7 @@ impl<'a> BasicProblem for IpoptAcOpf<'a> {
 
     fn objective_grad(&self, x: &[Number], _new_x: bool, grad_f: &mut [Number]) -> bool {
         // Use analytical gradient for performance (O(n_gen) vs O(n_var) for finite-diff)
-        // The objective only depends on generator P, so most entries are zero
+        // The objective only depends on generator P and Q, so most entries are zero
         let grad = self.problem.objective_gradient(x);
         grad_f.copy_from_slice(&grad);
         true
@@ -206,6 +206,19 @@ impl<'a> ConstrainedProblem for IpoptAcOpf<'a> {
     }
 }
 
+/// The analytical Jacobian and Hessian assume fixed taps, so tap-controlled
+/// problems are left to the penalty solver.
+#[cfg(feature = "solver-ipopt")]
+fn ensure_fixed_taps(problem: &AcOpfProblem) -> Result<(), OpfError> {
+    if problem.tap_controls.is_empty() {
+        Ok(())
+    } else {
+        Err(OpfError::NotImplemented(
+            "IPOPT AC-OPF does not support tap control; use solve_ac_opf".to_string(),
+        ))
+    }
+}
+
 /// Solve AC-OPF using IPOPT.
 ///
 /// # Arguments
@@ -222,6 +235,36 @@ pub fn solve_with_ipopt(
     max_iter: Option<usize>,
     tol: Option<f64>,
 ) -> Result<OpfSolution, OpfError> {
+    solve_ipopt(problem, max_iter, tol.unwrap_or(1e-6), None, None)
+}
+
+/// Solve AC-OPF using IPOPT with per-component tolerances.
+///
+/// `primal` sets `tol`, `dual` sets `dual_inf_tol`, `feasibility` sets
+/// `constr_viol_tol` and `gap` sets `compl_inf_tol`.
+#[cfg(feature = "solver-ipopt")]
+pub fn solve_with_ipopt_tolerances(
+    problem: &AcOpfProblem,
+    max_iter: Option<usize>,
+    tolerances: &OpfTolerances,
+) -> Result<OpfSolution, OpfError> {
+    solve_ipopt(problem, max_iter, tolerances.primal, Some(tolerances), None)
+}
+
+/// Solve AC-OPF using IPOPT, optionally with per-component tolerances and a
+/// user objective scaling factor.
+///
+/// With `objective_scale` set, IPOPT's gradient-based scaling is replaced by
+/// user scaling of the objective alone; the per-unit constraints need none.
+#[cfg(feature = "solver-ipopt")]
+pub(crate) fn solve_ipopt(
+    problem: &AcOpfProblem,
+    max_iter: Option<usize>,
+    tol: f64,
+    tolerances: Option<&OpfTolerances>,
+    objective_scale: Option<f64>,
+) -> Result<OpfSolution, OpfError> {
+    ensure_fixed_taps(problem)?;
     let ipopt_problem = IpoptAcOpf::new(problem);
 
     let mut solver = Ipopt::new(ipopt_problem)
@@ -229,7 +272,12 @@ pub fn solve_with_ipopt(
 
     // Configure solver options
     solver.set_option("max_iter", max_iter.unwrap_or(500) as i32);
-    solver.set_option("tol", tol.unwrap_or(1e-6));
+    solver.set_option("tol", tol);
+    if let Some(tolerances) = tolerances {
+        solver.set_option("dual_inf_tol", tolerances.dual);
+        solver.set_option("constr_viol_tol", tolerances.feasibility);
+        solver.set_option("compl_inf_tol", tolerances.gap);
+    }
     // Print level: 0=quiet, 3=medium, 5=verbose
     let print_level = std::env::var("IPOPT_PRINT_LEVEL")
         .ok()
@@ -259,7 +307,17 @@ pub fn solve_with_ipopt(
     // This enables quadratic convergence for better performance on large networks.
 
     // NLP scaling helps with ill-conditioned problems (power balance in p.u.)
-    solver.set_option("nlp_scaling_method", "gradient-based");
+    match objective_scale {
+        Some(scale) => {
+            solver.set_option("nlp_scaling_method", "user-scaling");
+            if !solver.set_problem_scaling(scale, None, None) {
+                return Err(OpfError::NumericalIssue(
+                    "IPOPT rejected the objective scaling".to_string(),
+                ));
+            }
+        }
+        None => solver.set_option("nlp_scaling_method", "gradient-based"),
+    }
 
     // Barrier parameter tuning for power systems
     // mu_init=1e-4 matches PowerModels.jl warm-start settings
@@ -290,6 +348,8 @@ pub fn solve_with_ipopt(
                 iterations: result.iterations as usize,
                 solve_time_ms: result.solve_time_ms.round() as u128,
                 objective_value: problem.objective(x),
+                total_losses_mw: problem.total_losses_mw(x),
+                objective_scale,
                 ..Default::default()
             };
 
@@ -617,6 +677,8 @@ pub fn solve_with_dc_warm_start(
     warm_start: &DcWarmStart,
     config: &IpoptConfig,
 ) -> Result<OpfSolution, OpfError> {
+    ensure_fixed_taps(problem)?;
+
     // Create initial point from DC solution
     let x0 = warm_start_from_dc(warm_start, problem);
 
@@ -711,6 +773,8 @@ pub fn solve_with_socp_warm_start(
     warm_start: &SocpWarmStart,
     config: &IpoptConfig,
 ) -> Result<OpfSolution, OpfError> {
+    ensure_fixed_taps(problem)?;
+
     // Create initial point from SOCP solution
     let x0 = warm_start_from_socp(warm_start, problem);
 
@@ -790,6 +854,7 @@ fn extract_solution(
         iterations: iterations as usize,
         solve_time_ms: solve_time_ms.round() as u128,
         objective_value: problem.objective(x),
+        total_losses_mw: problem.total_losses_mw(x),
         ..Default::default()
     };
 
@@ -199,15 +199,19 @@ pub use multiperiod::{
 pub use power_equations::PowerEquations;
 pub use problem::{
     interpolate_q_limits, AcOpfProblem, BranchData, BusData, CapabilityCurvePoint, GenData,
+    TapControl,
 };
 pub use solver::{solve as solve_ac_opf, solve_with_start as solve_ac_opf_warm_start};
 pub use sparse_ybus::SparseYBus;
 pub use ybus::{YBus, YBusBuilder};
 
+#[cfg(feature = "solver-ipopt")]
+pub(crate) use ipopt_solver::solve_ipopt;
 #[cfg(feature = "solver-ipopt")]
 pub use ipopt_solver::{
-    solve_with_dc_warm_start, solve_with_ipopt, solve_with_socp_warm_start, warm_start_from_dc,
-    warm_start_from_socp, IpoptAcOpfWarmStart, IpoptConfig,
+    solve_with_dc_warm_start, solve_with_ipopt, solve_with_ipopt_tolerances,
+    solve_with_socp_warm_start, warm_start_from_dc, warm_start_from_socp, IpoptAcOpfWarmStart,
+    IpoptConfig,
 };
 
 #[cfg(feature = "solver-ipopt")]
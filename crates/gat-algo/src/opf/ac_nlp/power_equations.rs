@@ -509,6 +509,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
 
         let bus2_idx = network.graph.add_node(Node::Bus(Bus {
@@ -521,6 +522,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
 
         let bus3_idx = network.graph.add_node(Node::Bus(Bus {
@@ -533,6 +535,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
 
         // Add branches (triangle topology)
@@ -9,11 +9,11 @@
 //! The optimization variables are laid out in a single vector `x` as:
 //!
 //! ```text
-//! x = [ V₁, V₂, ..., V_n,  θ₁, θ₂, ..., θ_n,  P_g1, ..., P_gm,  Q_g1, ..., Q_gm ]
-//!     |<─── voltages ───>|<─── angles ───>|<── real power ─>|<── reactive ──>|
-//!     |      n_bus       |     n_bus      |      n_gen      |      n_gen     |
-//!     |<─────────────────────────────────────────────────────────────────────>|
-//!                                    n_var = 2*n_bus + 2*n_gen
+//! x = [ V₁, V₂, ..., V_n,  θ₁, θ₂, ..., θ_n,  P_g1, ..., P_gm,  Q_g1, ..., Q_gm,  a₁, ..., a_k ]
+//!     |<─── voltages ───>|<─── angles ───>|<── real power ─>|<── reactive ──>|<─ taps ─>|
+//!     |      n_bus       |     n_bus      |      n_gen      |      n_gen     |  n_tap   |
+//!     |<────────────────────────────────────────────────────────────────────────────────>|
+//!                               n_var = 2*n_bus + 2*n_gen + n_tap
 //! ```
 //!
 //! **Variable groups:**
@@ -21,6 +21,8 @@
 //! - **θ (voltage angles)**: Phase angle at each bus in radians (-π/2 to +π/2)
 //! - **P_g (real power)**: Generator active power dispatch in per-unit
 //! - **Q_g (reactive power)**: Generator reactive power dispatch in per-unit
+//! - **a (tap ratios)**: Off-nominal ratio of each transformer whose tap is a control
+//!   variable (see [`AcOpfProblem::with_tap_control`]); empty by default
 //!
 //! ## Objective Function
 //!
@@ -91,7 +93,8 @@
 
 use super::{PowerEquations, YBus, YBusBuilder};
 use crate::opf::OpfError;
-use gat_core::{BusId, CostModel, Edge, Network, Node};
+use crate::AcObjective;
+use gat_core::{Branch, BusId, CostModel, Edge, Network, Node};
 use std::collections::HashMap;
 
 // ============================================================================
@@ -195,6 +198,34 @@ pub struct GenData {
     /// Capability curve points (if empty, use rectangular limits).
     /// Points should be sorted by p_mw in ascending order.
     pub capability_curve: Vec<CapabilityCurvePoint>,
+
+    /// Reactive production cost ($/MVAr·h) on |Q_g|, added to the
+    /// [`AcObjective::MinCost`] objective. Zero leaves Q free.
+    ///
+    /// The objective uses the smooth form
+    /// c_q·(√(Q² + ε²) − ε) with ε = [`REACTIVE_COST_SMOOTHING_MVAR`], so its
+    /// gradient and Hessian stay continuous through Q = 0.
+    pub reactive_cost: f64,
+}
+
+/// Smoothing width ε (MVAr) of the reactive cost term. Below ~ε the cost
+/// bends into a parabola instead of the |Q| kink; elsewhere it trails
+/// c_q·|Q| by less than c_q·ε.
+pub const REACTIVE_COST_SMOOTHING_MVAR: f64 = 1.0;
+
+impl GenData {
+    /// Smoothed reactive cost c_q·(√(Q² + ε²) − ε) at `q_mvar`, with its first
+    /// and second derivatives with respect to Q in MVAr.
+    pub(super) fn smoothed_reactive_cost(&self, q_mvar: f64) -> (f64, f64, f64) {
+        let eps = REACTIVE_COST_SMOOTHING_MVAR;
+        let root = (q_mvar * q_mvar + eps * eps).sqrt();
+        let c_q = self.reactive_cost;
+        (
+            c_q * (root - eps),
+            c_q * q_mvar / root,
+            c_q * eps * eps / (root * root * root),
+        )
+    }
 }
 
 /// Branch data for thermal limit constraints.
@@ -226,6 +257,20 @@ pub struct BranchData {
     pub angle_diff_max: f64,
 }
 
+/// Transformer whose tap ratio is optimized rather than fixed.
+///
+/// The Y-bus is built with the branch's nominal tap; the power balance
+/// equations correct its terminal flows for the tap held in `x`.
+#[derive(Debug, Clone)]
+pub struct TapControl {
+    /// Index into [`AcOpfProblem::branches`]
+    pub branch_idx: usize,
+    /// Lowest allowed tap ratio (p.u.)
+    pub tap_min: f64,
+    /// Highest allowed tap ratio (p.u.)
+    pub tap_max: f64,
+}
+
 /// Bus data extracted from network for OPF optimization.
 ///
 /// Represents an electrical node with load injection and voltage limits.
@@ -269,6 +314,14 @@ pub struct BusData {
     pub bs_pu: f64,
 }
 
+/// In-service branches in graph order, the order of [`AcOpfProblem::branches`].
+fn in_service_branches(network: &Network) -> impl Iterator<Item = &Branch> {
+    network.graph.edge_weights().filter_map(|edge| match edge {
+        Edge::Branch(branch) if branch.status => Some(branch),
+        _ => None,
+    })
+}
+
 // ============================================================================
 // AC-OPF PROBLEM DEFINITION
 // ============================================================================
@@ -324,7 +377,7 @@ pub struct AcOpfProblem {
     /// Number of generators (dispatchable units)
     pub n_gen: usize,
 
-    /// Total number of optimization variables = 2*n_bus + 2*n_gen
+    /// Total number of optimization variables = 2*n_bus + 2*n_gen + n_tap
     pub n_var: usize,
 
     // ========================================================================
@@ -345,6 +398,9 @@ pub struct AcOpfProblem {
     /// Offset to generator Q: x[qg_offset + g] = Q_g
     pub qg_offset: usize,
 
+    /// Offset to controlled tap ratios: x[tap_offset + k] = a_k
+    pub tap_offset: usize,
+
     // ========================================================================
     // GENERATOR-BUS MAPPING
     // ========================================================================
@@ -357,8 +413,24 @@ pub struct AcOpfProblem {
 
     /// Number of branches in the network
     pub n_branch: usize,
+
+    /// Quantity minimized by [`objective`](Self::objective). Defaults to generation cost.
+    pub objective: AcObjective,
+
+    /// Transformers with optimized taps, in the order of their variables.
+    /// Empty unless [`with_tap_control`](Self::with_tap_control) was called.
+    pub tap_controls: Vec<TapControl>,
+
+    /// Weight on squared voltage excursions outside each bus band, in
+    /// objective units per p.u.². `None` enforces the bands as hard bounds.
+    /// Set with [`with_soft_voltage_limits`](Self::with_soft_voltage_limits).
+    pub soft_voltage_weight: Option<f64>,
 }
 
+/// Hard voltage envelope (p.u.) kept in soft voltage mode, so the solver
+/// cannot wander to physically meaningless magnitudes.
+pub const SOFT_VOLTAGE_ENVELOPE: (f64, f64) = (0.5, 1.5);
+
 impl AcOpfProblem {
     /// Build OPF problem from Network graph.
     ///
@@ -494,6 +566,7 @@ impl AcOpfProblem {
                     cost_coeffs,
                     cost_model: gen.cost_model.clone(),
                     capability_curve: Vec::new(), // Default: use rectangular limits
+                    reactive_cost: gen.reactive_cost.unwrap_or(0.0),
                 });
             }
         }
@@ -537,27 +610,22 @@ impl AcOpfProblem {
         // - Angle difference constraints
 
         let mut branches = Vec::new();
-        for edge_idx in network.graph.edge_indices() {
-            if let Edge::Branch(branch) = &network.graph[edge_idx] {
-                if !branch.status {
-                    continue; // Skip offline branches
-                }
-                let from_idx = *bus_map.get(&branch.from_bus).unwrap_or(&0);
-                let to_idx = *bus_map.get(&branch.to_bus).unwrap_or(&0);
-
-                branches.push(BranchData {
-                    name: branch.name.clone(),
-                    from_idx,
-                    to_idx,
-                    r: branch.resistance,
-                    x: branch.reactance,
-                    b_charging: branch.charging_b.value(),
-                    tap: branch.tap_ratio,
-                    shift: branch.phase_shift.value(),
-                    rate_mva: branch.rating_a.and_then(|v| Some(v.value())).unwrap_or(0.0),
-                    angle_diff_max: 0.0, // Default: no limit (could be extracted from Branch if available)
-                });
-            }
+        for branch in in_service_branches(network) {
+            let from_idx = *bus_map.get(&branch.from_bus).unwrap_or(&0);
+            let to_idx = *bus_map.get(&branch.to_bus).unwrap_or(&0);
+
+            branches.push(BranchData {
+                name: branch.name.clone(),
+                from_idx,
+                to_idx,
+                r: branch.resistance,
+                x: branch.reactance,
+                b_charging: branch.charging_b.value(),
+                tap: branch.tap_ratio,
+                shift: branch.phase_shift.value(),
+                rate_mva: branch.rating_a.and_then(|v| Some(v.value())).unwrap_or(0.0),
+                angle_diff_max: 0.0, // Default: no limit (could be extracted from Branch if available)
+            });
         }
         let n_branch = branches.len();
 
@@ -576,14 +644,91 @@ impl AcOpfProblem {
             theta_offset: n_bus,
             pg_offset: 2 * n_bus,
             qg_offset: 2 * n_bus + n_gen,
+            tap_offset: n_var,
 
             gen_bus_idx,
 
             branches,
             n_branch,
+
+            objective: AcObjective::MinCost,
+            tap_controls: Vec::new(),
+            soft_voltage_weight: None,
         })
     }
 
+    /// Select the quantity the solver minimizes.
+    pub fn with_objective(mut self, objective: AcObjective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// Treat the tap ratio of every in-service transformer in `network` as a
+    /// continuous control variable within `[tap_min, tap_max]`.
+    ///
+    /// `network` must be the network the problem was built from. A branch is a
+    /// transformer when its element type says so or it has an off-nominal tap
+    /// or phase shift; all other branches keep their fixed tap. The phase shift
+    /// itself stays fixed.
+    pub fn with_tap_control(mut self, network: &Network, tap_min: f64, tap_max: f64) -> Self {
+        self.tap_controls = in_service_branches(network)
+            .enumerate()
+            .filter(|(_, branch)| {
+                branch.element_type == "transformer"
+                    || branch.tap_ratio != 1.0
+                    || branch.phase_shift.value() != 0.0
+            })
+            .map(|(branch_idx, _)| TapControl {
+                branch_idx,
+                tap_min,
+                tap_max,
+            })
+            .collect();
+        self.tap_offset = 2 * self.n_bus + 2 * self.n_gen;
+        self.n_var = self.tap_offset + self.tap_controls.len();
+        self
+    }
+
+    /// Penalize bus voltages outside `[v_min, v_max]` by `weight·excess²`
+    /// instead of enforcing the band.
+    ///
+    /// Only [`SOFT_VOLTAGE_ENVELOPE`] stays a hard bound, so a case with no
+    /// voltage-feasible operating point still solves and reports the
+    /// overshoot. Larger weights keep the excursions smaller.
+    pub fn with_soft_voltage_limits(mut self, weight: f64) -> Self {
+        self.soft_voltage_weight = Some(weight);
+        self
+    }
+
+    /// Voltage outside each bus band at `x` in p.u.: positive above `v_max`,
+    /// negative below `v_min`, zero within the band.
+    pub fn voltage_band_excess(&self, x: &[f64]) -> Vec<f64> {
+        self.buses
+            .iter()
+            .enumerate()
+            .map(|(i, bus)| {
+                let v = x[self.v_offset + i];
+                if v > bus.v_max {
+                    v - bus.v_max
+                } else if v < bus.v_min {
+                    v - bus.v_min
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Tap ratio of every branch at `x`: the optimized value for controlled
+    /// transformers and the fixed input tap for all others.
+    pub fn branch_taps(&self, x: &[f64]) -> Vec<f64> {
+        let mut taps: Vec<f64> = self.branches.iter().map(|b| b.tap).collect();
+        for (k, control) in self.tap_controls.iter().enumerate() {
+            taps[control.branch_idx] = x[self.tap_offset + k];
+        }
+        taps
+    }
+
     /// Generate a "flat start" initial point.
     ///
     /// A flat start assumes:
@@ -676,6 +821,15 @@ impl AcOpfProblem {
             x[self.qg_offset + i] = qmin_pu + q_frac * (qmax_pu - qmin_pu);
         }
 
+        // ====================================================================
+        // TAP RATIOS: START FROM THE INPUT TAP
+        // ====================================================================
+
+        for (k, control) in self.tap_controls.iter().enumerate() {
+            let tap = self.branches[control.branch_idx].tap;
+            x[self.tap_offset + k] = tap.clamp(control.tap_min, control.tap_max);
+        }
+
         x
     }
 
@@ -776,6 +930,19 @@ impl AcOpfProblem {
             // If Q not available (DC-OPF), keep the midpoint from flat-start
         }
 
+        // ====================================================================
+        // TAP RATIOS FROM SOLUTION
+        // ====================================================================
+        //
+        // Only an AC-OPF with tap control reports optimized taps.
+
+        for (k, control) in self.tap_controls.iter().enumerate() {
+            let name = &self.branches[control.branch_idx].name;
+            if let Some(&tap) = solution.transformer_taps.get(name) {
+                x[self.tap_offset + k] = tap.clamp(control.tap_min, control.tap_max);
+            }
+        }
+
         x
     }
 
@@ -796,25 +963,30 @@ impl AcOpfProblem {
         (v, theta)
     }
 
-    /// Evaluate the objective function (total generation cost).
+    /// Evaluate the objective function selected by [`AcOpfProblem::objective`].
+    ///
+    /// - [`AcObjective::MinCost`]: total generation cost in $/hr
+    /// - [`AcObjective::MinLosses`]: total branch I²R losses in MW
+    /// - [`AcObjective::MinVoltageDeviation`]: Σ(V_i − 1)² in p.u.²
+    pub fn objective(&self, x: &[f64]) -> f64 {
+        match self.objective {
+            AcObjective::MinCost => self.generation_cost(x) + self.reactive_cost(x),
+            AcObjective::MinLosses => self.total_losses_mw(x),
+            AcObjective::MinVoltageDeviation => self.voltage_deviation(x),
+        }
+    }
+
+    /// Total generation cost in $/hr.
     ///
     /// Supports both polynomial and piecewise-linear cost models:
     /// - **Polynomial**: f(P) = c₀ + c₁·P + c₂·P² + ...
     /// - **Piecewise-linear**: Interpolated from (MW, $/hr) breakpoints
     ///
-    /// # Arguments
-    ///
-    /// * `x` - Decision variable vector
-    ///
-    /// # Returns
-    ///
-    /// Total generation cost in $/hr
-    ///
     /// # Note
     ///
     /// Generator dispatch values P_g are stored in per-unit in x, but cost
     /// models are defined in MW. We convert back to MW for cost evaluation.
-    pub fn objective(&self, x: &[f64]) -> f64 {
+    pub fn generation_cost(&self, x: &[f64]) -> f64 {
         let mut cost = 0.0;
 
         for (i, gen) in self.generators.iter().enumerate() {
@@ -830,35 +1002,116 @@ impl AcOpfProblem {
         cost
     }
 
+    /// Total reactive production cost in $/hr: Σ c_q·|Q_g| over generators
+    /// with a reactive cost, smoothed near Q = 0 (see
+    /// [`REACTIVE_COST_SMOOTHING_MVAR`]). Absorbing and producing VArs are
+    /// priced alike.
+    pub fn reactive_cost(&self, x: &[f64]) -> f64 {
+        self.generators
+            .iter()
+            .enumerate()
+            .map(|(i, gen)| {
+                gen.smoothed_reactive_cost(x[self.qg_offset + i] * self.base_mva)
+                    .0
+            })
+            .sum()
+    }
+
+    /// Total active power losses over all in-service branches in MW.
+    ///
+    /// For a π-model branch the series I²R loss equals P_ij + P_ji; the line
+    /// charging is purely reactive, so the sum simplifies to
+    /// ```text
+    /// loss = g · (Vi²/a² + Vj² − 2·(Vi·Vj/a)·cos(θi − θj − θs))
+    /// ```
+    pub fn total_losses_mw(&self, x: &[f64]) -> f64 {
+        let (v, theta) = self.extract_v_theta(x);
+        let taps = self.branch_taps(x);
+        let losses_pu: f64 = self
+            .branches
+            .iter()
+            .zip(&taps)
+            .map(|(branch, &tap)| {
+                let (i, j) = (branch.from_idx, branch.to_idx);
+                let (p_ij, _) =
+                    self.branch_flow_from_at(branch, tap, v[i], v[j], theta[i], theta[j]);
+                let (p_ji, _) = self.branch_flow_to_at(branch, tap, v[i], v[j], theta[i], theta[j]);
+                p_ij + p_ji
+            })
+            .sum();
+        losses_pu * self.base_mva
+    }
+
+    /// Sum of squared voltage deviations from 1.0 p.u.
+    pub fn voltage_deviation(&self, x: &[f64]) -> f64 {
+        (0..self.n_bus)
+            .map(|i| (x[self.v_offset + i] - 1.0).powi(2))
+            .sum()
+    }
+
     /// Compute the gradient of the objective function.
     ///
-    /// Supports both polynomial and piecewise-linear cost models:
+    /// For [`AcObjective::MinCost`], supports both polynomial and piecewise-linear cost models:
     /// - **Polynomial**: ∂f/∂P = c₁ + 2·c₂·P + ... (marginal cost)
     /// - **Piecewise-linear**: Slope of the segment containing current P
+    /// - **Reactive cost**: ∂f/∂Q = c_q·Q/√(Q² + ε²), the smoothed ±c_q
     ///
     /// The scaling by S_base accounts for the per-unit representation:
     /// ```text
     /// ∂f/∂P_pu = marginal_cost(P_MW) · S_base
     /// ```
     ///
+    /// Loss and voltage-deviation objectives depend only on V and θ.
+    ///
     /// # Arguments
     ///
     /// * `x` - Decision variable vector
     ///
     /// # Returns
     ///
-    /// Gradient vector of length n_var. Only entries at pg_offset are non-zero.
+    /// Gradient vector of length n_var.
     pub fn objective_gradient(&self, x: &[f64]) -> Vec<f64> {
         let mut grad = vec![0.0; self.n_var];
 
-        for (i, gen) in self.generators.iter().enumerate() {
-            let pg_pu = x[self.pg_offset + i];
-            let pg_mw = pg_pu * self.base_mva;
-
-            // Use marginal_cost() which handles both polynomial and
-            // piecewise-linear costs correctly
-            // Chain rule: ∂f/∂P_pu = ∂f/∂P_MW · ∂P_MW/∂P_pu = marginal_cost · S_base
-            grad[self.pg_offset + i] = gen.cost_model.marginal_cost(pg_mw) * self.base_mva;
+        match self.objective {
+            AcObjective::MinCost => {
+                for (i, gen) in self.generators.iter().enumerate() {
+                    let pg_pu = x[self.pg_offset + i];
+                    let pg_mw = pg_pu * self.base_mva;
+
+                    // Use marginal_cost() which handles both polynomial and
+                    // piecewise-linear costs correctly
+                    // Chain rule: ∂f/∂P_pu = ∂f/∂P_MW · ∂P_MW/∂P_pu = marginal_cost · S_base
+                    grad[self.pg_offset + i] = gen.cost_model.marginal_cost(pg_mw) * self.base_mva;
+
+                    // Smoothed reactive cost: slope runs from -c_q to c_q across Q = 0
+                    let (_, dq, _) =
+                        gen.smoothed_reactive_cost(x[self.qg_offset + i] * self.base_mva);
+                    grad[self.qg_offset + i] = dq * self.base_mva;
+                }
+            }
+            AcObjective::MinLosses => {
+                let (v, theta) = self.extract_v_theta(x);
+                for branch in &self.branches {
+                    let (i, j) = (branch.from_idx, branch.to_idx);
+                    let z_sq = branch.r * branch.r + branch.x * branch.x;
+                    let g = branch.r / z_sq * self.base_mva;
+                    let a = if branch.tap > 0.0 { branch.tap } else { 1.0 };
+                    let delta = theta[i] - theta[j] - branch.shift;
+                    let (sin_d, cos_d) = delta.sin_cos();
+
+                    grad[self.v_offset + i] += g * (2.0 * v[i] / (a * a) - 2.0 * v[j] * cos_d / a);
+                    grad[self.v_offset + j] += g * (2.0 * v[j] - 2.0 * v[i] * cos_d / a);
+                    let d_theta = g * 2.0 * v[i] * v[j] * sin_d / a;
+                    grad[self.theta_offset + i] += d_theta;
+                    grad[self.theta_offset + j] -= d_theta;
+                }
+            }
+            AcObjective::MinVoltageDeviation => {
+                for i in 0..self.n_bus {
+                    grad[self.v_offset + i] = 2.0 * (x[self.v_offset + i] - 1.0);
+                }
+            }
         }
 
         grad
@@ -900,6 +1153,19 @@ impl AcOpfProblem {
         vj: f64,
         theta_i: f64,
         theta_j: f64,
+    ) -> (f64, f64) {
+        self.branch_flow_from_at(branch, branch.tap, vi, vj, theta_i, theta_j)
+    }
+
+    /// [`branch_flow_from`](Self::branch_flow_from) with the tap ratio overridden by `tap`.
+    fn branch_flow_from_at(
+        &self,
+        branch: &BranchData,
+        tap: f64,
+        vi: f64,
+        vj: f64,
+        theta_i: f64,
+        theta_j: f64,
     ) -> (f64, f64) {
         // Compute series admittance g + jb = 1/(r + jx)
         let z_sq = branch.r * branch.r + branch.x * branch.x;
@@ -907,7 +1173,7 @@ impl AcOpfProblem {
         let b = -branch.x / z_sq;
 
         // Tap ratio (default 1.0 for lines)
-        let a = if branch.tap > 0.0 { branch.tap } else { 1.0 };
+        let a = if tap > 0.0 { tap } else { 1.0 };
         let a_sq = a * a;
 
         // Angle difference including phase shift
@@ -948,6 +1214,19 @@ impl AcOpfProblem {
         vj: f64,
         theta_i: f64,
         theta_j: f64,
+    ) -> (f64, f64) {
+        self.branch_flow_to_at(branch, branch.tap, vi, vj, theta_i, theta_j)
+    }
+
+    /// [`branch_flow_to`](Self::branch_flow_to) with the tap ratio overridden by `tap`.
+    fn branch_flow_to_at(
+        &self,
+        branch: &BranchData,
+        tap: f64,
+        vi: f64,
+        vj: f64,
+        theta_i: f64,
+        theta_j: f64,
     ) -> (f64, f64) {
         // Compute series admittance
         let z_sq = branch.r * branch.r + branch.x * branch.x;
@@ -955,7 +1234,7 @@ impl AcOpfProblem {
         let b = -branch.x / z_sq;
 
         // Tap ratio
-        let a = if branch.tap > 0.0 { branch.tap } else { 1.0 };
+        let a = if tap > 0.0 { tap } else { 1.0 };
 
         // Angle difference (reversed direction, plus phase shift)
         let theta_diff = theta_j - theta_i + branch.shift;
@@ -1092,7 +1371,30 @@ impl AcOpfProblem {
         let (v, theta) = self.extract_v_theta(x);
 
         // Compute power injections from AC power flow equations
-        let (p_inj, q_inj) = PowerEquations::compute_injections(&self.ybus, &v, &theta);
+        let (mut p_inj, mut q_inj) = PowerEquations::compute_injections(&self.ybus, &v, &theta);
+
+        // ====================================================================
+        // TAP CONTROL CORRECTION
+        // ====================================================================
+        //
+        // The Y-bus holds each transformer at its input tap. For controlled
+        // taps, swap that branch's terminal flows for the flows at x's tap.
+
+        for (k, control) in self.tap_controls.iter().enumerate() {
+            let branch = &self.branches[control.branch_idx];
+            let tap = x[self.tap_offset + k];
+            let (i, j) = (branch.from_idx, branch.to_idx);
+            let (vi, vj, ti, tj) = (v[i], v[j], theta[i], theta[j]);
+
+            let (pf0, qf0) = self.branch_flow_from(branch, vi, vj, ti, tj);
+            let (pt0, qt0) = self.branch_flow_to(branch, vi, vj, ti, tj);
+            let (pf, qf) = self.branch_flow_from_at(branch, tap, vi, vj, ti, tj);
+            let (pt, qt) = self.branch_flow_to_at(branch, tap, vi, vj, ti, tj);
+            p_inj[i] += pf - pf0;
+            q_inj[i] += qf - qf0;
+            p_inj[j] += pt - pt0;
+            q_inj[j] += qt - qt0;
+        }
 
         // Pre-allocate constraint vector
         // Layout: [P balance for each bus | Q balance for each bus | ref angle]
@@ -1176,9 +1478,16 @@ impl AcOpfProblem {
         // - Too low: voltage collapse, motor stalling
         // - Too high: insulation breakdown, equipment damage
 
+        // In soft mode the bands move into the objective and only the wide
+        // envelope remains a bound.
+
         for (i, bus) in self.buses.iter().enumerate() {
-            lb[self.v_offset + i] = bus.v_min;
-            ub[self.v_offset + i] = bus.v_max;
+            let (v_min, v_max) = match self.soft_voltage_weight {
+                Some(_) => SOFT_VOLTAGE_ENVELOPE,
+                None => (bus.v_min, bus.v_max),
+            };
+            lb[self.v_offset + i] = v_min;
+            ub[self.v_offset + i] = v_max;
         }
 
         // ====================================================================
@@ -1226,6 +1535,17 @@ impl AcOpfProblem {
             ub[self.qg_offset + i] = gen.qmax / self.base_mva;
         }
 
+        // ====================================================================
+        // TAP RATIO LIMITS
+        // ====================================================================
+        //
+        // a_min ≤ a_k ≤ a_max, the range of the on-load tap changer
+
+        for (k, control) in self.tap_controls.iter().enumerate() {
+            lb[self.tap_offset + k] = control.tap_min;
+            ub[self.tap_offset + k] = control.tap_max;
+        }
+
         (lb, ub)
     }
 }
@@ -1282,6 +1602,7 @@ mod tests {
                 (100.0, 1100.0), // $1100/hr at 100 MW (avg $10/MWh)
             ]),
             capability_curve: Vec::new(),
+            reactive_cost: 0.0,
         };
 
         // Create a GenData with polynomial cost (linear: $100 + $10*P)
@@ -1295,6 +1616,7 @@ mod tests {
             cost_coeffs: vec![100.0, 10.0, 0.0],
             cost_model: CostModel::linear(100.0, 10.0),
             capability_curve: Vec::new(),
+            reactive_cost: 0.0,
         };
 
         // For linear PWL, both should give the same cost at any P
@@ -1406,4 +1728,117 @@ mod tests {
         let q_midpoint = (gen.qmin + gen.qmax) / 2.0 / problem.base_mva;
         assert!((x0[problem.qg_offset] - q_midpoint).abs() < 1e-9);
     }
+
+    #[test]
+    fn test_loss_and_voltage_objective_gradients() {
+        use gat_core::{Branch, BranchId, Bus, Edge, Gen, GenId, Network, Node};
+
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus1".to_string(),
+            base_kv: gat_core::Kilovolts(12.47),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "Bus2".to_string(),
+            base_kv: gat_core::Kilovolts(12.47),
+            ..Bus::default()
+        }));
+        network.graph.add_node(Node::Gen(Gen {
+            pmax: gat_core::Megawatts(50.0),
+            ..Gen::new(GenId::new(1), "Gen1".to_string(), BusId::new(1))
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch {
+                tap_ratio: 1.02,
+                phase_shift: gat_core::Radians(0.01),
+                ..Branch::new(
+                    BranchId::new(1),
+                    "Line1-2".to_string(),
+                    BusId::new(1),
+                    BusId::new(2),
+                    0.05,
+                    0.08,
+                )
+            }),
+        );
+
+        let mut x = AcOpfProblem::from_network(&network)
+            .unwrap()
+            .initial_point();
+        x[0] = 1.03;
+        x[1] = 0.97;
+        x[3] = -0.05;
+
+        for objective in [AcObjective::MinLosses, AcObjective::MinVoltageDeviation] {
+            let problem = AcOpfProblem::from_network(&network)
+                .unwrap()
+                .with_objective(objective);
+            let grad = problem.objective_gradient(&x);
+            let h = 1e-6;
+            for k in 0..problem.n_var {
+                let mut up = x.clone();
+                let mut down = x.clone();
+                up[k] += h;
+                down[k] -= h;
+                let fd = (problem.objective(&up) - problem.objective(&down)) / (2.0 * h);
+                assert!(
+                    (grad[k] - fd).abs() < 1e-5,
+                    "{objective}: d/dx[{k}] analytic {} vs finite difference {fd}",
+                    grad[k]
+                );
+            }
+        }
+
+        // Losses equal the sum of both branch-end real power flows
+        let problem = AcOpfProblem::from_network(&network)
+            .unwrap()
+            .with_objective(AcObjective::MinLosses);
+        let branch = &problem.branches[0];
+        let (p_ij, _) = problem.branch_flow_from(branch, x[0], x[1], x[2], x[3]);
+        let (p_ji, _) = problem.branch_flow_to(branch, x[0], x[1], x[2], x[3]);
+        assert!((problem.objective(&x) - (p_ij + p_ji) * problem.base_mva).abs() < 1e-9);
+        assert!(problem.objective(&x) > 0.0);
+    }
+
+    #[test]
+    fn test_smoothed_reactive_cost_derivatives() {
+        let gen = GenData {
+            name: "Q_Gen".to_string(),
+            bus_id: BusId::new(1),
+            pmin: 0.0,
+            pmax: 100.0,
+            qmin: -50.0,
+            qmax: 50.0,
+            cost_coeffs: vec![0.0, 10.0, 0.0],
+            cost_model: CostModel::linear(0.0, 10.0),
+            capability_curve: Vec::new(),
+            reactive_cost: 2.0,
+        };
+
+        // Flat and smooth at Q = 0, approaching c_q·|Q| away from it
+        let (cost, slope, _) = gen.smoothed_reactive_cost(0.0);
+        assert_eq!((cost, slope), (0.0, 0.0));
+        let (cost, slope, _) = gen.smoothed_reactive_cost(-40.0);
+        assert!((cost - 2.0 * 40.0).abs() < 2.0 * REACTIVE_COST_SMOOTHING_MVAR);
+        assert!((slope + 2.0).abs() < 1e-3);
+
+        let h = 1e-5;
+        for q in [-5.0, -0.5, 0.0, 0.3, 5.0] {
+            let (_, slope, curvature) = gen.smoothed_reactive_cost(q);
+            let value = |q| gen.smoothed_reactive_cost(q).0;
+            let derivative = |q| gen.smoothed_reactive_cost(q).1;
+            let fd_slope = (value(q + h) - value(q - h)) / (2.0 * h);
+            let fd_curvature = (derivative(q + h) - derivative(q - h)) / (2.0 * h);
+            assert!((slope - fd_slope).abs() < 1e-6, "slope at Q = {q}");
+            assert!(
+                (curvature - fd_curvature).abs() < 1e-5,
+                "curvature at Q = {q}"
+            );
+        }
+    }
 }
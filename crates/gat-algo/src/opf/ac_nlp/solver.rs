@@ -135,8 +135,8 @@
 //!   DOI: [10.1145/192115.192132](https://doi.org/10.1145/192115.192132)
 
 use super::compute_single_branch_flow;
-use super::AcOpfProblem;
-use crate::opf::{OpfError, OpfMethod, OpfSolution};
+use super::{AcOpfProblem, BranchData};
+use crate::opf::{OpfError, OpfMethod, OpfSolution, SolverWarning};
 use argmin::core::{CostFunction, Executor, Gradient, State};
 use argmin::solver::linesearch::MoreThuenteLineSearch;
 use argmin::solver::quasinewton::LBFGS;
@@ -226,6 +226,21 @@ impl<'a> CostFunction for PenaltyProblem<'a> {
             }
         }
 
+        // ====================================================================
+        // SOFT VOLTAGE BAND PENALTY
+        // ====================================================================
+        //
+        // In soft voltage mode the bus bands are not bounds. Excursions cost
+        //   w · excess²
+        // with the fixed user weight w rather than μ, so they stay part of the
+        // trade-off instead of being driven to zero by the outer loop.
+
+        if let Some(weight) = self.problem.soft_voltage_weight {
+            for excess in self.problem.voltage_band_excess(x) {
+                cost += weight * excess * excess;
+            }
+        }
+
         // ====================================================================
         // THERMAL LIMIT PENALTY
         // ====================================================================
@@ -238,7 +253,8 @@ impl<'a> CostFunction for PenaltyProblem<'a> {
         // Scale factor 1e-6 improves numerical conditioning (MVA² units are large).
 
         let (v, theta) = self.problem.extract_v_theta(x);
-        for br in &self.problem.branches {
+        let taps = self.problem.branch_taps(x);
+        for (br, &tap) in self.problem.branches.iter().zip(&taps) {
             if br.rate_mva <= 0.0 {
                 continue; // No thermal limit
             }
@@ -247,7 +263,14 @@ impl<'a> CostFunction for PenaltyProblem<'a> {
             let vj = v[br.to_idx];
             let theta_ij = theta[br.from_idx] - theta[br.to_idx];
 
-            // Compute power flows at both ends of branch
+            // Compute power flows at both ends of branch, at the optimized tap if controlled
+            let tapped;
+            let br = if tap != br.tap {
+                tapped = BranchData { tap, ..br.clone() };
+                &tapped
+            } else {
+                br
+            };
             let (pf, qf, pt, qt) =
                 compute_single_branch_flow(br, vi, vj, theta_ij, self.problem.base_mva);
 
@@ -629,8 +652,14 @@ pub fn solve_with_start(
         iterations: total_iterations,
         solve_time_ms: start.elapsed().as_millis(),
         objective_value: problem.objective(&x),
+        total_losses_mw: problem.total_losses_mw(&x),
         ..Default::default()
     };
+    if !converged {
+        solution.warnings.push(SolverWarning::IterationCapHit {
+            iterations: total_iterations,
+        });
+    }
 
     // Extract generator dispatch (convert from per-unit to MW/MVAr)
     for (i, gen) in problem.generators.iter().enumerate() {
@@ -648,6 +677,23 @@ pub fn solve_with_start(
             .insert(bus.name.clone(), theta[i].to_degrees());
     }
 
+    // Extract optimized tap ratios of controlled transformers
+    for (k, control) in problem.tap_controls.iter().enumerate() {
+        solution.transformer_taps.insert(
+            problem.branches[control.branch_idx].name.clone(),
+            x[problem.tap_offset + k],
+        );
+    }
+
+    // Report voltage band excursions accepted in soft voltage mode
+    if problem.soft_voltage_weight.is_some() {
+        for (bus, excess) in problem.buses.iter().zip(problem.voltage_band_excess(&x)) {
+            if excess != 0.0 {
+                solution.voltage_violations.insert(bus.name.clone(), excess);
+            }
+        }
+    }
+
     // ========================================================================
     // LMP ESTIMATION
     // ========================================================================
@@ -765,6 +811,7 @@ mod tests {
             cost_coeffs: vec![0.0, 10.0, 0.0],
             cost_model: gat_core::CostModel::linear(0.0, 10.0),
             capability_curve: Vec::new(),
+            reactive_cost: 0.0,
         }];
 
         // Create a branch with angle difference limit
@@ -794,9 +841,13 @@ mod tests {
             theta_offset: 2,
             pg_offset: 4,
             qg_offset: 5,
+            tap_offset: 6,
             gen_bus_idx: vec![0],
             branches,
             n_branch: 1,
+            objective: crate::AcObjective::MinCost,
+            tap_controls: Vec::new(),
+            soft_voltage_weight: None,
         };
 
         // Test: Verify angle violation penalty is applied correctly
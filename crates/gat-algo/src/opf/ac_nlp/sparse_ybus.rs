@@ -176,6 +176,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
         let bus2_idx = network.graph.add_node(Node::Bus(Bus {
             id: BusId::new(2),
@@ -187,6 +188,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
         let bus3_idx = network.graph.add_node(Node::Bus(Bus {
             id: BusId::new(3),
@@ -198,6 +200,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
 
         // Add branches (forming a triangle topology)
@@ -265,6 +268,7 @@ mod tests {
             bus: BusId::new(3),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(20.0),
+            zip: None,
         }));
 
         network
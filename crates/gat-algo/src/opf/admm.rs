@@ -40,7 +40,7 @@ use thiserror::Error;
 
 use crate::graph::{partition_network, NetworkPartition, PartitionError, PartitionStrategy};
 use crate::opf::gpu_branch_flow::GpuBranchFlowCalculator;
-use crate::opf::{OpfMethod, OpfSolution, OpfSolver};
+use crate::opf::{FlowDirection, OpfMethod, OpfSolution, OpfSolver};
 use crate::OpfError;
 
 /// ADMM solver configuration parameters.
@@ -516,6 +516,7 @@ fn extract_subnetwork(
                 bus: new_bus_id,
                 active_power: Megawatts(*p_inj),
                 reactive_power: Megavars(*q_inj),
+                zip: None,
             };
             next_load_id += 1;
             subnetwork.graph.add_node(Node::Load(boundary_load));
@@ -921,12 +922,19 @@ impl AdmmOpfSolver {
                 // would modify the objective function directly, but that requires
                 // changes to the OpfSolver interface.
                 match solver.solve(&extracted.network) {
-                    Ok(solution) => {
+                    Ok(solution) if solution.converged => {
                         // Remap solution variables back to original bus/gen names
                         let remapped =
                             self.remap_solution(&solution, partition, &extracted.bus_name_to_id);
                         Ok((remapped, partition))
                     }
+                    Ok(solution) => Err(AdmmError::SubproblemFailed {
+                        partition: part_idx,
+                        message: format!(
+                            "stopped unconverged after {} iterations",
+                            solution.iterations
+                        ),
+                    }),
                     Err(e) => Err(AdmmError::SubproblemFailed {
                         partition: part_idx,
                         message: format!("{}", e),
@@ -1295,13 +1303,32 @@ impl From<AdmmSolution> for OpfSolution {
             objective_value: admm.objective,
             generator_p: admm.generator_p,
             generator_q: admm.generator_q,
+            generator_q_binding: HashMap::new(),
             bus_voltage_mag: admm.bus_voltage_mag,
             bus_voltage_ang: admm.bus_voltage_ang,
+            slack_buses: Vec::new(),
             branch_p_flow: admm.branch_p_flow,
             branch_q_flow: admm.branch_q_flow,
+            flow_direction: FlowDirection::FromTo,
+            renewable_curtailment: HashMap::new(),
+            transformer_taps: HashMap::new(),
             bus_lmp: HashMap::new(), // TODO: Derive from dual variables
+            system_price: None,
+            constraint_duals: HashMap::new(),
             binding_constraints: Vec::new(),
             total_losses_mw: admm.total_losses_mw,
+            total_emissions_t: 0.0,
+            constrained_generators: Vec::new(),
+            tie_flows: HashMap::new(),
+            load_shed_mw: HashMap::new(),
+            branch_ratings: HashMap::new(),
+            voltage_violations: HashMap::new(),
+            warnings: Vec::new(),
+            objective_scale: None,
+            lmp_sensitivity: None,
+            reactive_estimate: None,
+            reactive_shortfall_mvar: None,
+            dispatch_trace: None,
         }
     }
 }
@@ -1331,6 +1358,7 @@ mod tests {
                 vmax_pu: Some(PerUnit(1.05)),
                 area_id: None,
                 zone_id: zone,
+                bus_type: None,
             }));
             bus_indices.push(idx);
         }
@@ -1355,6 +1383,7 @@ mod tests {
                 bus: BusId::new(i),
                 active_power: Megawatts(20.0),
                 reactive_power: Megavars(5.0),
+                zip: None,
             }));
         }
 
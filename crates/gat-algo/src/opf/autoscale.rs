@@ -0,0 +1,80 @@
+//! Objective scaling from cost statistics.
+//!
+//! Interior-point solvers compare the objective gradient against constraint
+//! residuals in per-unit, so cost curves far from the usual $10–$1000/MWh
+//! (penalty prices, costs entered in the wrong currency unit) leave the KKT
+//! system badly conditioned. When the largest marginal cost of any in-service
+//! unit falls outside [`AUTOSCALE_MIN_MARGINAL_COST`]..[`AUTOSCALE_MAX_MARGINAL_COST`],
+//! the objective is multiplied by the factor that brings it to
+//! [`REFERENCE_MARGINAL_COST`]. Scaling the objective leaves the optimal
+//! dispatch unchanged and multiplies every dual by the same factor, which the
+//! solvers divide back out.
+
+use gat_core::{Network, Node};
+
+/// Marginal cost ($/MWh) the largest unit marginal cost is scaled to
+pub const REFERENCE_MARGINAL_COST: f64 = 100.0;
+
+/// Largest marginal cost ($/MWh) below which the objective is scaled up
+pub const AUTOSCALE_MIN_MARGINAL_COST: f64 = 1.0;
+
+/// Largest marginal cost ($/MWh) above which the objective is scaled down
+pub const AUTOSCALE_MAX_MARGINAL_COST: f64 = 1e4;
+
+/// Objective factor for `network`, or `None` when its costs are well scaled
+/// or all zero.
+///
+/// The statistic is the largest absolute marginal cost of an in-service
+/// unit over its dispatch range, evaluated at both ends.
+pub(crate) fn objective_scale(network: &Network) -> Option<f64> {
+    let largest = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Gen(gen) if gen.status => Some(gen),
+            _ => None,
+        })
+        .flat_map(|gen| {
+            [gen.dispatch_pmin(), gen.dispatch_pmax()]
+                .map(|p| gen.cost_model.marginal_cost(p).abs())
+        })
+        .filter(|mc| mc.is_finite())
+        .fold(0.0_f64, f64::max);
+
+    let well_scaled =
+        (AUTOSCALE_MIN_MARGINAL_COST..=AUTOSCALE_MAX_MARGINAL_COST).contains(&largest);
+    (largest > 0.0 && !well_scaled).then(|| REFERENCE_MARGINAL_COST / largest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{BusId, CostModel, Gen, GenId};
+
+    fn network_with_cost(cost: CostModel) -> Network {
+        let mut network = Network::new();
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1))
+                .with_p_limits(0.0, 100.0)
+                .with_cost(cost),
+        ));
+        network
+    }
+
+    #[test]
+    fn test_scale_only_outside_normal_cost_range() {
+        assert_eq!(
+            objective_scale(&network_with_cost(CostModel::linear(0.0, 30.0))),
+            None
+        );
+        assert_eq!(objective_scale(&network_with_cost(CostModel::NoCost)), None);
+
+        // 2e6 + 2·1e4·100 = 4e6 $/MWh at Pmax
+        let scale =
+            objective_scale(&network_with_cost(CostModel::quadratic(0.0, 2e6, 1e4))).unwrap();
+        assert!((scale - 100.0 / 4e6).abs() < 1e-15);
+
+        let scale = objective_scale(&network_with_cost(CostModel::linear(0.0, 0.01))).unwrap();
+        assert!((scale - 1e4).abs() < 1e-9);
+    }
+}
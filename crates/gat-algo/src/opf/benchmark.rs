@@ -0,0 +1,177 @@
+//! Repeatable solver timing.
+//!
+//! [`benchmark`] solves one network with each requested method several times
+//! and reports wall-clock statistics, so solver comparisons do not depend on
+//! `/usr/bin/time` around a whole CLI run. Each method is solved once before
+//! timing starts, so lazy initialization and cold caches do not skew the first
+//! repeat. Results can be written to Parquet with
+//! [`BenchmarkResult::write_parquet`], one row per method.
+
+use crate::opf::{OpfMethod, OpfSolver};
+use crate::OpfError;
+use anyhow::{Context, Result};
+use gat_core::Network;
+use parquet::basic::{Compression, ZstdLevel};
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+use serde::Serialize;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+use web_time::Instant;
+
+/// Timing of one method over the benchmark repeats.
+#[derive(Debug, Clone, Serialize)]
+pub struct MethodTiming {
+    pub method: OpfMethod,
+    /// Number of timed solves (the warm-up solve is not counted)
+    pub repeats: usize,
+    /// Median wall-clock solve time (ms)
+    pub median_ms: f64,
+    /// Fastest solve (ms)
+    pub min_ms: f64,
+    /// Slowest solve (ms)
+    pub max_ms: f64,
+    /// Objective of the last timed solve ($/hr)
+    pub objective_value: f64,
+    /// Whether the last timed solve converged
+    pub converged: bool,
+}
+
+/// Timings for every benchmarked method, in the order requested.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BenchmarkResult {
+    pub timings: Vec<MethodTiming>,
+}
+
+impl BenchmarkResult {
+    /// Timing for `method`, if it was benchmarked.
+    pub fn get(&self, method: OpfMethod) -> Option<&MethodTiming> {
+        self.timings.iter().find(|timing| timing.method == method)
+    }
+
+    /// Write one row per method with columns `method`, `repeats`, `median_ms`,
+    /// `min_ms`, `max_ms`, `objective_value` and `converged`.
+    pub fn write_parquet(&self, path: &Path) -> Result<()> {
+        let schema = parse_message_type(
+            "message benchmark {
+                required binary method (UTF8);
+                required int64 repeats;
+                required double median_ms;
+                required double min_ms;
+                required double max_ms;
+                required double objective_value;
+                required boolean converged;
+            }",
+        )
+        .context("building benchmark schema")?;
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+
+        let file = File::create(path)
+            .with_context(|| format!("creating Parquet file at {}", path.display()))?;
+        let mut writer = SerializedFileWriter::new(file, Arc::new(schema), Arc::new(props))
+            .context("creating Parquet writer")?;
+        let mut row_group = writer.next_row_group().context("starting row group")?;
+
+        let methods: Vec<ByteArray> = self
+            .timings
+            .iter()
+            .map(|timing| ByteArray::from(timing.method.to_string().as_str()))
+            .collect();
+        let mut col = row_group
+            .next_column()?
+            .context("benchmark schema has too few columns")?;
+        col.typed::<ByteArrayType>()
+            .write_batch(&methods, None, None)
+            .context("writing method column")?;
+        col.close()?;
+
+        let repeats: Vec<i64> = self.timings.iter().map(|t| t.repeats as i64).collect();
+        let mut col = row_group
+            .next_column()?
+            .context("benchmark schema has too few columns")?;
+        col.typed::<Int64Type>()
+            .write_batch(&repeats, None, None)
+            .context("writing repeats column")?;
+        col.close()?;
+
+        let columns: [fn(&MethodTiming) -> f64; 4] = [
+            |t| t.median_ms,
+            |t| t.min_ms,
+            |t| t.max_ms,
+            |t| t.objective_value,
+        ];
+        for column in columns {
+            let values: Vec<f64> = self.timings.iter().map(column).collect();
+            let mut col = row_group
+                .next_column()?
+                .context("benchmark schema has too few columns")?;
+            col.typed::<DoubleType>()
+                .write_batch(&values, None, None)
+                .context("writing timing column")?;
+            col.close()?;
+        }
+
+        let converged: Vec<bool> = self.timings.iter().map(|t| t.converged).collect();
+        let mut col = row_group
+            .next_column()?
+            .context("benchmark schema has too few columns")?;
+        col.typed::<BoolType>()
+            .write_batch(&converged, None, None)
+            .context("writing converged column")?;
+        col.close()?;
+
+        row_group.close()?;
+        writer.close().context("finalizing Parquet file")?;
+        Ok(())
+    }
+}
+
+/// Time each of `methods` on `network` over `repeats` solves (at least one).
+///
+/// Every method gets one untimed warm-up solve first. A failing solve aborts
+/// the benchmark with its error.
+pub fn benchmark(
+    network: &Network,
+    methods: &[OpfMethod],
+    repeats: usize,
+) -> Result<BenchmarkResult, OpfError> {
+    let repeats = repeats.max(1);
+    let mut result = BenchmarkResult::default();
+    for &method in methods {
+        let solver = OpfSolver::new().with_method(method);
+        solver.solve(network)?;
+
+        let mut times_ms = Vec::with_capacity(repeats);
+        let mut last = None;
+        for _ in 0..repeats {
+            let start = Instant::now();
+            let solution = solver.solve(network)?;
+            times_ms.push(start.elapsed().as_secs_f64() * 1e3);
+            last = Some(solution);
+        }
+        let last = last.expect("at least one repeat");
+
+        times_ms.sort_by(f64::total_cmp);
+        let mid = repeats / 2;
+        let median_ms = if repeats % 2 == 0 {
+            (times_ms[mid - 1] + times_ms[mid]) / 2.0
+        } else {
+            times_ms[mid]
+        };
+        result.timings.push(MethodTiming {
+            method,
+            repeats,
+            median_ms,
+            min_ms: times_ms[0],
+            max_ms: times_ms[repeats - 1],
+            objective_value: last.objective_value,
+            converged: last.converged,
+        });
+    }
+    Ok(result)
+}
@@ -0,0 +1,141 @@
+//! Carbon pricing for OPF objectives.
+//!
+//! A carbon price of `price` $/t adds `price * emissions_rate` $/MWh to each
+//! emitting unit's marginal cost. Rather than teach every formulation about
+//! emissions, the price is folded into a copy of the network's cost models so
+//! all solve methods see the same carbon-aware objective.
+
+use gat_core::{CostModel, Network, Node};
+
+/// Copy `network` with each unit's cost raised by its emissions cost at `price` ($/tCO2).
+pub(crate) fn price_emissions(network: &Network, price: f64) -> Network {
+    let mut priced = network.clone();
+    for node in priced.graph.node_weights_mut() {
+        let Node::Gen(gen) = node else {
+            continue;
+        };
+        if let Some(rate) = gen.emissions_rate {
+            gen.cost_model = add_marginal_cost(&gen.cost_model, price * rate);
+        }
+    }
+    priced
+}
+
+/// Add a constant `adder` ($/MWh) to a cost model's marginal cost.
+fn add_marginal_cost(cost: &CostModel, adder: f64) -> CostModel {
+    match cost {
+        CostModel::NoCost => CostModel::linear(0.0, adder),
+        CostModel::Polynomial(coeffs) => {
+            let mut coeffs = coeffs.clone();
+            if coeffs.len() < 2 {
+                coeffs.resize(2, 0.0);
+            }
+            coeffs[1] += adder;
+            CostModel::Polynomial(coeffs)
+        }
+        CostModel::PiecewiseLinear(points) => CostModel::PiecewiseLinear(
+            points
+                .iter()
+                .map(|&(mw, cost)| (mw, cost + adder * mw))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opf::{OpfMethod, OpfSolver};
+    use gat_core::{
+        Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Kilovolts, Load, LoadId, Megavars,
+        Megawatts,
+    };
+
+    /// Cheap coal ($20/MWh, 1.0 t/MWh) and pricier gas ($35/MWh, 0.4 t/MWh)
+    /// at bus 1 serving 100 MW at bus 2.
+    fn coal_and_gas_network() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=2)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: Kilovolts(230.0),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        network.graph.add_edge(
+            buses[0],
+            buses[1],
+            Edge::Branch(Branch::new(
+                BranchId::new(0),
+                "line1_2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        for (id, name, cost, rate) in [(1, "coal", 20.0, 1.0), (2, "gas", 35.0, 0.4)] {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(id), name.to_string(), BusId::new(1))
+                    .with_p_limits(0.0, 150.0)
+                    .with_cost(CostModel::linear(0.0, cost))
+                    .with_emissions_rate(rate),
+            ));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(100.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        network
+    }
+
+    #[test]
+    fn test_carbon_price_shifts_dispatch_to_cleaner_unit() {
+        let network = coal_and_gas_network();
+        let solver = || OpfSolver::new().with_method(OpfMethod::DcOpf);
+
+        let least_cost = solver().solve(&network).unwrap();
+        assert!((least_cost.generator_p["coal"] - 100.0).abs() < 1e-3);
+        assert!((least_cost.total_emissions_t - 100.0).abs() < 1e-2);
+
+        // At $50/t coal costs $70/MWh and gas $55/MWh
+        let carbon_aware = solver().with_carbon_price(50.0).solve(&network).unwrap();
+        assert!((carbon_aware.generator_p["gas"] - 100.0).abs() < 1e-3);
+        assert!(carbon_aware.generator_p["coal"].abs() < 1e-3);
+        assert!((carbon_aware.total_emissions_t - 40.0).abs() < 1e-2);
+        assert!((carbon_aware.objective_value - 5500.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_units_without_rate_are_unpriced() {
+        let cost = CostModel::quadratic(5.0, 10.0, 0.1);
+        let priced = add_marginal_cost(&cost, 20.0);
+        assert!((priced.evaluate(10.0) - cost.evaluate(10.0) - 200.0).abs() < 1e-9);
+
+        let mut network = coal_and_gas_network();
+        for node in network.graph.node_weights_mut() {
+            if let Node::Gen(gen) = node {
+                if gen.name == "gas" {
+                    gen.emissions_rate = None;
+                }
+            }
+        }
+        let priced = price_emissions(&network, 50.0);
+        let gas = priced
+            .graph
+            .node_weights()
+            .find_map(|node| match node {
+                Node::Gen(gen) if gen.name == "gas" => Some(gen),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(gas.cost_model.marginal_cost(0.0), 35.0);
+    }
+}
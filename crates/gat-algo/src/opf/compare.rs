@@ -0,0 +1,173 @@
+//! Structured comparison of two OPF solutions for regression checks.
+//!
+//! [`compare_solutions`] lines up dispatch, LMPs and branch flows by element
+//! name and summarizes the absolute differences per quantity, so benchmark
+//! runs and CI can tell a numerical regression from solver noise. Elements
+//! present in only one solution are listed separately and fail the comparison.
+
+use super::OpfSolution;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Differences for one named quantity (dispatch, LMPs or flows).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FieldDiff {
+    /// Largest absolute difference over elements present in both solutions
+    pub max_abs_diff: f64,
+    /// Mean absolute difference over elements present in both solutions
+    pub mean_abs_diff: f64,
+    /// Element with the largest difference, if any element differs
+    pub max_element: Option<String>,
+    /// Elements present in only one of the solutions, sorted by name
+    pub unmatched: Vec<String>,
+}
+
+impl FieldDiff {
+    fn between(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> Self {
+        let mut diff = FieldDiff::default();
+        let mut total = 0.0;
+        let mut matched = 0usize;
+        for (name, value_a) in a {
+            let Some(value_b) = b.get(name) else {
+                diff.unmatched.push(name.clone());
+                continue;
+            };
+            let delta = (value_a - value_b).abs();
+            total += delta;
+            matched += 1;
+            if delta > diff.max_abs_diff {
+                diff.max_abs_diff = delta;
+                diff.max_element = Some(name.clone());
+            }
+        }
+        diff.unmatched
+            .extend(b.keys().filter(|name| !a.contains_key(*name)).cloned());
+        diff.unmatched.sort();
+        if matched > 0 {
+            diff.mean_abs_diff = total / matched as f64;
+        }
+        diff
+    }
+
+    /// True when every element matched and differs by at most `tol`.
+    pub fn within(&self, tol: f64) -> bool {
+        self.unmatched.is_empty() && self.max_abs_diff <= tol
+    }
+}
+
+/// Result of comparing two OPF solutions.
+#[derive(Debug, Clone, Serialize)]
+pub struct SolutionComparison {
+    /// Tolerance the comparison was judged against
+    pub tolerance: f64,
+    /// Generator active dispatch (MW)
+    pub dispatch: FieldDiff,
+    /// Bus LMPs ($/MWh)
+    pub lmp: FieldDiff,
+    /// Branch active flows (MW)
+    pub flows: FieldDiff,
+    /// Absolute objective difference ($/hr)
+    pub objective_diff: f64,
+    /// Objective difference relative to `max(1, |a.objective_value|)`
+    pub objective_diff_rel: f64,
+    /// Whether the two solutions agree on convergence
+    pub converged_match: bool,
+    /// True when convergence matches, every quantity is within tolerance and
+    /// the relative objective difference is at most the tolerance
+    pub passed: bool,
+}
+
+/// Compare solution `b` against reference `a` with absolute tolerance `tol`.
+///
+/// Dispatch, LMPs and flows are compared in their own units; the objective is
+/// compared relative to the reference so one tolerance suits cases of any
+/// size. Flows should share an orientation (see
+/// [`normalize_flow_signs`](crate::opf::normalize_flow_signs)).
+pub fn compare_solutions(a: &OpfSolution, b: &OpfSolution, tol: f64) -> SolutionComparison {
+    let dispatch = FieldDiff::between(&a.generator_p, &b.generator_p);
+    let lmp = FieldDiff::between(&a.bus_lmp, &b.bus_lmp);
+    let flows = FieldDiff::between(&a.branch_p_flow, &b.branch_p_flow);
+    let objective_diff = (a.objective_value - b.objective_value).abs();
+    let objective_diff_rel = objective_diff / a.objective_value.abs().max(1.0);
+    let converged_match = a.converged == b.converged;
+    let passed = converged_match
+        && dispatch.within(tol)
+        && lmp.within(tol)
+        && flows.within(tol)
+        && objective_diff_rel <= tol;
+
+    SolutionComparison {
+        tolerance: tol,
+        dispatch,
+        lmp,
+        flows,
+        objective_diff,
+        objective_diff_rel,
+        converged_match,
+        passed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solution() -> OpfSolution {
+        let mut solution = OpfSolution {
+            converged: true,
+            objective_value: 1500.0,
+            ..Default::default()
+        };
+        for (gen, p) in [("gen1", 60.0), ("gen2", 40.0)] {
+            solution.generator_p.insert(gen.to_string(), p);
+        }
+        for (bus, lmp) in [("bus1", 10.0), ("bus2", 25.0), ("bus3", 18.0)] {
+            solution.bus_lmp.insert(bus.to_string(), lmp);
+        }
+        for (branch, flow) in [("line1_2", 35.0), ("line2_3", -12.0)] {
+            solution.branch_p_flow.insert(branch.to_string(), flow);
+        }
+        solution
+    }
+
+    #[test]
+    fn test_solution_matches_itself() {
+        let a = solution();
+        let comparison = compare_solutions(&a, &a.clone(), 1e-9);
+        assert!(comparison.passed);
+        for diff in [&comparison.dispatch, &comparison.lmp, &comparison.flows] {
+            assert_eq!(diff.max_abs_diff, 0.0);
+            assert_eq!(diff.mean_abs_diff, 0.0);
+            assert!(diff.max_element.is_none());
+            assert!(diff.unmatched.is_empty());
+        }
+        assert_eq!(comparison.objective_diff, 0.0);
+    }
+
+    #[test]
+    fn test_perturbed_solution_locates_difference() {
+        let a = solution();
+        let mut b = a.clone();
+        *b.bus_lmp.get_mut("bus2").unwrap() += 1.5;
+        *b.branch_p_flow.get_mut("line2_3").unwrap() -= 0.3;
+        b.generator_p.remove("gen2");
+        b.objective_value += 15.0;
+
+        let comparison = compare_solutions(&a, &b, 1e-3);
+        assert!(!comparison.passed);
+
+        assert!((comparison.lmp.max_abs_diff - 1.5).abs() < 1e-12);
+        assert!((comparison.lmp.mean_abs_diff - 0.5).abs() < 1e-12);
+        assert_eq!(comparison.lmp.max_element.as_deref(), Some("bus2"));
+
+        assert!((comparison.flows.max_abs_diff - 0.3).abs() < 1e-12);
+        assert_eq!(comparison.flows.max_element.as_deref(), Some("line2_3"));
+
+        assert_eq!(comparison.dispatch.max_abs_diff, 0.0);
+        assert_eq!(comparison.dispatch.unmatched, vec!["gen2".to_string()]);
+        assert!(!comparison.dispatch.within(1.0));
+
+        assert!((comparison.objective_diff - 15.0).abs() < 1e-12);
+        assert!((comparison.objective_diff_rel - 0.01).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,205 @@
+//! Attribution of LMP congestion components to binding branch limits.
+//!
+//! In a DC-OPF the price at bus n splits into the energy price at the reference
+//! bus and a congestion term built from the binding flow limits:
+//!
+//! ```text
+//! LMP_n = λ_ref − Σ_ℓ μ_ℓ · d_ℓ · PTDF[ℓ, n]
+//! ```
+//!
+//! where μ_ℓ ≥ 0 is the shadow price of branch ℓ's limit ($/MWh per MW), d_ℓ = ±1
+//! the direction the branch is loaded in, and PTDF[ℓ, n] the flow on ℓ per MW
+//! injected at n and withdrawn at the reference bus. Each term of the sum is the
+//! share of bus n's congestion price caused by branch ℓ.
+
+use crate::opf::{ConstraintId, OpfSolution};
+use crate::sparse::SparsePtdf;
+use crate::OpfError;
+use gat_core::{BranchId, BusId, Edge, Network, Node};
+use std::collections::HashMap;
+
+/// Thermal shadow prices ($/MWh per MW) at or below this are treated as slack;
+/// interior-point duals of non-binding limits are small but not exactly zero.
+const BINDING_SHADOW_PRICE: f64 = 1e-6;
+
+/// Decompose each bus's congestion LMP component into per-branch contributions.
+///
+/// Contributions are μ_ℓ · d_ℓ · (−PTDF[ℓ, n]) for every positive
+/// `ConstraintId::BranchThermal` entry of `solution.constraint_duals`, with
+/// the flow direction read from `solution.branch_p_flow` (forward when
+/// absent). They sum to the bus's congestion component, LMP_n − LMP_ref,
+/// measured against the solver's angle reference (the first of
+/// `solution.slack_buses`, else the PTDF's own slack). Angle-difference
+/// limits are not attributed. Each bus's list is sorted by decreasing
+/// magnitude and is empty when nothing binds.
+pub fn congestion_attribution(
+    network: &Network,
+    solution: &OpfSolution,
+) -> Result<HashMap<BusId, Vec<(BranchId, f64)>>, OpfError> {
+    let ptdf = SparsePtdf::compute_ptdf(network)
+        .map_err(|e| OpfError::DataValidation(format!("PTDF for congestion attribution: {}", e)))?;
+
+    let reference = match solution.slack_buses.first() {
+        Some(name) => network
+            .graph
+            .node_weights()
+            .find_map(|node| match node {
+                Node::Bus(bus) if &bus.name == name => Some(bus.id),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                OpfError::DataValidation(format!("Reference bus {} is not in the network", name))
+            })?,
+        None => ptdf.slack_bus(),
+    };
+
+    let branch_ids: HashMap<&str, BranchId> = network
+        .graph
+        .edge_weights()
+        .filter_map(|edge| match edge {
+            Edge::Branch(branch) if branch.status => Some((branch.name.as_str(), branch.id)),
+            _ => None,
+        })
+        .collect();
+
+    let mut binding = Vec::new();
+    for (id, &shadow_price) in &solution.constraint_duals {
+        let ConstraintId::BranchThermal(name) = id else {
+            continue;
+        };
+        if shadow_price <= BINDING_SHADOW_PRICE {
+            continue;
+        }
+        let branch_id = *branch_ids.get(name.as_str()).ok_or_else(|| {
+            OpfError::DataValidation(format!(
+                "Binding limit on unknown or out-of-service branch {}",
+                name
+            ))
+        })?;
+        let direction = match solution.branch_p_flow.get(name) {
+            Some(&flow) if flow < 0.0 => -1.0,
+            _ => 1.0,
+        };
+        binding.push((branch_id, shadow_price * direction));
+    }
+    binding.sort_by_key(|&(branch_id, _)| branch_id.value());
+
+    // Shift factors to the reference: PTDF_ref[ℓ, n] = PTDF[ℓ, n] − PTDF[ℓ, ref]
+    let factor = |branch_id, bus_id| {
+        ptdf.get(branch_id, bus_id).unwrap_or(0.0) - ptdf.get(branch_id, reference).unwrap_or(0.0)
+    };
+    let mut attribution = HashMap::new();
+    for &bus_id in &ptdf.bus_ids {
+        let mut contributions: Vec<(BranchId, f64)> = binding
+            .iter()
+            .map(|&(branch_id, signed_price)| {
+                (branch_id, -signed_price * factor(branch_id, bus_id))
+            })
+            .collect();
+        contributions.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()));
+        attribution.insert(bus_id, contributions);
+    }
+    Ok(attribution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opf::ac_nlp::PeriodData;
+    use crate::opf::solve_multiperiod_dc;
+    use gat_core::{
+        Branch, Bus, CostModel, Gen, GenId, Load, LoadId, Megavars, MegavoltAmperes, Megawatts,
+    };
+
+    /// Triangle of equal reactances serving 300 MW at bus 2, with line 1–2
+    /// rated `rating` MW.
+    ///
+    /// A $10 unit at bus 1 sends two thirds of its output over line 1–2, so a
+    /// 100 MW rating holds it to 150 MW and the larger $30 unit at bus 2 (the
+    /// angle reference) covers the rest. Then μ = (30 − 10) / (2/3) = 30, and
+    /// bus 3 (a third on line 1–2) prices at 30 − 30/3 = 20.
+    fn triangle(rating: f64) -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (id, (from, to)) in [(1, 2), (1, 3), (2, 3)].into_iter().enumerate() {
+            let mut branch = Branch::new(
+                BranchId::new(id),
+                format!("line{}_{}", from, to),
+                BusId::new(from),
+                BusId::new(to),
+                0.0,
+                0.1,
+            );
+            if (from, to) == (1, 2) {
+                branch.rating_a = Some(MegavoltAmperes(rating));
+            }
+            network
+                .graph
+                .add_edge(buses[from - 1], buses[to - 1], Edge::Branch(branch));
+        }
+        for (id, bus, pmax, cost) in [(1, 1, 200.0, 10.0), (2, 2, 400.0, 30.0)] {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(id), format!("gen{}", id), BusId::new(bus))
+                    .with_p_limits(0.0, pmax)
+                    .with_cost(CostModel::linear(0.0, cost)),
+            ));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(300.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        network
+    }
+
+    fn solve(network: &Network) -> OpfSolution {
+        let result = solve_multiperiod_dc(network, &[PeriodData::hourly(0, 1.0)], &[]).unwrap();
+        result.periods.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn test_single_binding_line_explains_congestion() {
+        let network = triangle(100.0);
+        let solution = solve(&network);
+        assert_eq!(solution.slack_buses, vec!["bus2".to_string()]);
+        assert!((solution.branch_p_flow["line1_2"] - 100.0).abs() < 1e-3);
+
+        let attribution = congestion_attribution(&network, &solution).unwrap();
+        for (bus, name) in [(1, "bus1"), (2, "bus2"), (3, "bus3")] {
+            let contributions = &attribution[&BusId::new(bus)];
+            assert_eq!(contributions.len(), 1);
+            assert_eq!(contributions[0].0, BranchId::new(0));
+
+            let congestion = solution.bus_lmp[name] - solution.bus_lmp["bus2"];
+            let total: f64 = contributions.iter().map(|(_, c)| c).sum();
+            assert!(
+                (total - congestion).abs() < 1e-4,
+                "{}: contributions {:.6} vs congestion {:.6}",
+                name,
+                total,
+                congestion
+            );
+        }
+        assert!((attribution[&BusId::new(1)][0].1 + 20.0).abs() < 1e-4);
+        assert!((attribution[&BusId::new(3)][0].1 + 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_uncongested_solution_has_no_contributions() {
+        let network = triangle(500.0);
+        let attribution = congestion_attribution(&network, &solve(&network)).unwrap();
+        assert_eq!(attribution.len(), 3);
+        assert!(attribution.values().all(|c| c.is_empty()));
+    }
+}
@@ -0,0 +1,267 @@
+//! Copperplate (single-node) dispatch
+//!
+//! Treats the whole system as one bus: every in-service generator serves the
+//! total load with no branches, losses or voltages in between. The dispatch
+//! minimizes total cost by equal incremental cost — each unit runs where its
+//! marginal cost meets the system price λ, clipped to its dispatch limits —
+//! so it is exact for any convex cost curve. Units whose marginal cost is flat
+//! at λ share the marginal block in proportion to their headroom, as in
+//! merit-order dispatch.
+//!
+//! λ is reported as the single system price and as the LMP of every bus.
+//! When no branch limit binds, DC-OPF reaches the same objective, so this is a
+//! cheap stand-in for it in adequacy loops.
+
+use crate::{
+    opf::{OpfMethod, OpfSolution},
+    OpfError,
+};
+use gat_core::{Gen, Network, Node};
+use web_time::Instant;
+
+/// Bisection steps on the system price and on each unit's output
+const BISECTION_STEPS: usize = 100;
+
+/// Unserved or surplus MW tolerated in the final balance
+const BALANCE_TOLERANCE_MW: f64 = 1e-6;
+
+/// Solve the copperplate dispatch of `network`
+pub fn solve(network: &Network) -> Result<OpfSolution, OpfError> {
+    let start = Instant::now();
+
+    let mut generators: Vec<&Gen> = Vec::new();
+    let mut total_load = 0.0;
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Gen(gen) if gen.status => generators.push(gen),
+            Node::Load(load) => total_load += load.active_power.value(),
+            _ => {}
+        }
+    }
+
+    if generators.is_empty() {
+        return Err(OpfError::DataValidation(
+            "No generators in network".to_string(),
+        ));
+    }
+
+    let total_pmax: f64 = generators.iter().map(|g| g.dispatch_pmax()).sum();
+    let total_pmin: f64 = generators.iter().map(|g| g.dispatch_pmin()).sum();
+    if total_load > total_pmax + BALANCE_TOLERANCE_MW {
+        return Err(OpfError::Infeasible(format!(
+            "Generator capacity insufficient: need {:.2} MW, max {:.2} MW",
+            total_load, total_pmax
+        )));
+    }
+    if total_load < total_pmin - BALANCE_TOLERANCE_MW {
+        return Err(OpfError::Infeasible(format!(
+            "Load too low for minimum generation: need {:.2} MW, min {:.2} MW",
+            total_load, total_pmin
+        )));
+    }
+
+    let (dispatch, price) = equal_incremental_cost(&generators, total_load);
+
+    let objective_value: f64 = generators
+        .iter()
+        .zip(&dispatch)
+        .map(|(gen, &p)| gen.cost_model.evaluate(p))
+        .sum();
+
+    let mut solution = OpfSolution {
+        converged: true,
+        method_used: OpfMethod::Copperplate,
+        iterations: 1,
+        solve_time_ms: start.elapsed().as_millis(),
+        objective_value,
+        system_price: Some(price),
+        ..Default::default()
+    };
+    for (gen, &p) in generators.iter().zip(&dispatch) {
+        solution.generator_p.insert(gen.name.clone(), p);
+    }
+    for node in network.graph.node_weights() {
+        if let Node::Bus(bus) = node {
+            solution.bus_voltage_mag.insert(bus.name.clone(), 1.0);
+            solution.bus_voltage_ang.insert(bus.name.clone(), 0.0);
+            solution.bus_lmp.insert(bus.name.clone(), price);
+        }
+    }
+
+    Ok(solution)
+}
+
+/// Largest output in the unit's dispatch range whose marginal cost is at
+/// most `price`
+fn output_at_price(gen: &Gen, price: f64) -> f64 {
+    let (pmin, pmax) = (gen.dispatch_pmin(), gen.dispatch_pmax());
+    if gen.cost_model.marginal_cost(pmax) <= price {
+        return pmax;
+    }
+    if gen.cost_model.marginal_cost(pmin) > price {
+        return pmin;
+    }
+    let (mut lo, mut hi) = (pmin, pmax);
+    for _ in 0..BISECTION_STEPS {
+        let mid = 0.5 * (lo + hi);
+        if gen.cost_model.marginal_cost(mid) <= price {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Dispatch meeting `demand` at equal incremental cost, and the system price.
+///
+/// Bisects λ until the total output just below it falls short of demand and
+/// the total at it covers demand, then splits the gap between the two
+/// dispatches in proportion to each unit's step. Demand must lie within the
+/// units' combined dispatch range.
+fn equal_incremental_cost(generators: &[&Gen], demand: f64) -> (Vec<f64>, f64) {
+    let dispatch_at = |price: f64| -> Vec<f64> {
+        generators
+            .iter()
+            .map(|gen| output_at_price(gen, price))
+            .collect()
+    };
+    let total = |dispatch: &[f64]| dispatch.iter().sum::<f64>();
+
+    let marginal_costs = generators.iter().flat_map(|gen| {
+        [
+            gen.cost_model.marginal_cost(gen.dispatch_pmin()),
+            gen.cost_model.marginal_cost(gen.dispatch_pmax()),
+        ]
+    });
+    let (min_mc, max_mc) = marginal_costs.fold((f64::INFINITY, f64::NEG_INFINITY), |acc, mc| {
+        (acc.0.min(mc), acc.1.max(mc))
+    });
+
+    // Every unit sits at Pmin below `lo` and at Pmax at `hi`
+    let mut lo = min_mc - 1.0;
+    let mut hi = max_mc + 1.0;
+    for _ in 0..BISECTION_STEPS {
+        let mid = 0.5 * (lo + hi);
+        if total(&dispatch_at(mid)) >= demand {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    let low = dispatch_at(lo);
+    let high = dispatch_at(hi);
+    let step = total(&high) - total(&low);
+    let share = if step > BALANCE_TOLERANCE_MW {
+        ((demand - total(&low)) / step).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let dispatch = low
+        .iter()
+        .zip(&high)
+        .map(|(&l, &h)| l + share * (h - l))
+        .collect();
+
+    // Flat marginal costs leave λ anywhere in the bracket; report its top
+    (dispatch, hi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opf::OpfSolver;
+    use gat_core::{
+        Branch, BranchId, Bus, BusId, CostModel, GenId, Load, LoadId, Megavars, Megawatts,
+    };
+
+    /// Triangle with two linear-cost units and 150 MW of load on lines rated
+    /// far above any flow.
+    fn uncongested_triangle() -> Network {
+        let mut network = Network::new();
+        for i in 1..=3 {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                ..Bus::default()
+            }));
+        }
+        for (id, (from, to)) in [(1, 2), (1, 3), (2, 3)].into_iter().enumerate() {
+            network
+                .add_branch_checked(
+                    Branch::new(
+                        BranchId::new(id),
+                        format!("line{}_{}", from, to),
+                        BusId::new(from),
+                        BusId::new(to),
+                        0.01,
+                        0.1,
+                    )
+                    .with_s_max(Some(1000.0)),
+                )
+                .unwrap();
+        }
+        for (id, pmax, price) in [(1, 100.0, 10.0), (2, 200.0, 20.0)] {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(id), format!("gen{}", id), BusId::new(id))
+                    .with_p_limits(0.0, pmax)
+                    .with_q_limits(-100.0, 100.0)
+                    .with_cost(CostModel::linear(0.0, price)),
+            ));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load3".to_string(),
+            bus: BusId::new(3),
+            active_power: Megawatts(150.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        network
+    }
+
+    #[test]
+    fn test_copperplate_matches_uncongested_dc_opf() {
+        let network = uncongested_triangle();
+
+        let copperplate = OpfSolver::new()
+            .with_method(OpfMethod::Copperplate)
+            .solve(&network)
+            .unwrap();
+        assert!(copperplate.converged);
+        assert!((copperplate.objective_value - 2000.0).abs() < 1e-3);
+        assert!((copperplate.generator_p["gen1"] - 100.0).abs() < 1e-6);
+        assert!((copperplate.generator_p["gen2"] - 50.0).abs() < 1e-6);
+        let price = copperplate.system_price.unwrap();
+        assert!((price - 20.0).abs() < 1e-6, "system price {}", price);
+        assert!(copperplate.bus_lmp.values().all(|&lmp| lmp == price));
+
+        let dc = OpfSolver::new()
+            .with_method(OpfMethod::DcOpf)
+            .solve(&network)
+            .unwrap();
+        assert!(
+            (copperplate.objective_value - dc.objective_value).abs() < 1e-3 * dc.objective_value,
+            "copperplate {} vs DC-OPF {}",
+            copperplate.objective_value,
+            dc.objective_value
+        );
+    }
+
+    #[test]
+    fn test_copperplate_equalizes_quadratic_marginal_costs() {
+        let mut network = uncongested_triangle();
+        for node in network.graph.node_weights_mut() {
+            if let Node::Gen(gen) = node {
+                gen.cost_model = CostModel::quadratic(0.0, 10.0, 0.05);
+            }
+        }
+
+        let solution = solve(&network).unwrap();
+        // Identical curves split the load evenly at λ = 10 + 2·0.05·75
+        assert!((solution.generator_p["gen1"] - 75.0).abs() < 1e-6);
+        assert!((solution.generator_p["gen2"] - 75.0).abs() < 1e-6);
+        assert!((solution.system_price.unwrap() - 17.5).abs() < 1e-6);
+    }
+}
@@ -305,6 +305,55 @@ impl SolverDispatcher {
         }
     }
 
+    /// Select a solver for a problem of `num_buses` buses.
+    ///
+    /// Without a preferred solver, installed native solvers are ranked by
+    /// [`gat_solver_common::recommend_solver_from`], so large LPs go to HiGHS
+    /// while small ones stay on Clarabel. Otherwise behaves like [`Self::select`].
+    pub fn select_for_size(
+        &self,
+        problem_class: ProblemClass,
+        num_buses: usize,
+    ) -> Result<SolverBackend, OpfError> {
+        #[cfg(feature = "native-dispatch")]
+        if self.config.native_enabled
+            && self.config.preferred_lp.is_none()
+            && self.config.preferred_nlp.is_none()
+        {
+            use gat_solver_common::{ProblemType, PureRustSolver, SolverChoice, SolverId};
+
+            let problem_type = match problem_class {
+                ProblemClass::LinearProgram => ProblemType::Lp,
+                ProblemClass::ConicProgram => ProblemType::Socp,
+                ProblemClass::NonlinearProgram => ProblemType::AcOpf,
+                ProblemClass::MixedInteger => ProblemType::Mip,
+            };
+            // Only the native solvers this dispatcher can drive
+            let installed: Vec<SolverId> = self
+                .installed_native
+                .iter()
+                .copied()
+                .filter(|id| matches!(id, SolverId::Ipopt | SolverId::Highs | SolverId::Cbc))
+                .collect();
+            if let Ok(choice) =
+                gat_solver_common::recommend_solver_from(problem_type, num_buses, &installed)
+            {
+                return Ok(match choice {
+                    SolverChoice::PureRust(PureRustSolver::Clarabel) => SolverBackend::Clarabel,
+                    SolverChoice::PureRust(PureRustSolver::Lbfgs) => SolverBackend::Lbfgs,
+                    SolverChoice::Native(SolverId::Ipopt) => SolverBackend::Ipopt,
+                    SolverChoice::Native(SolverId::Highs) => SolverBackend::Highs,
+                    SolverChoice::Native(SolverId::Cbc) => SolverBackend::Cbc,
+                    SolverChoice::Native(_) => return self.select(problem_class),
+                });
+            }
+        }
+
+        #[cfg(not(feature = "native-dispatch"))]
+        let _ = num_buses;
+        self.select(problem_class)
+    }
+
     /// Check if a solver backend is available.
     fn is_available(&self, backend: SolverBackend) -> bool {
         match backend {
@@ -388,6 +437,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[cfg(feature = "native-dispatch")]
+    #[test]
+    fn test_select_for_size_routes_large_lp_to_highs() {
+        use gat_solver_common::SolverId;
+
+        let mut dispatcher = SolverDispatcher::with_config(DispatchConfig {
+            native_enabled: true,
+            ..DispatchConfig::default()
+        });
+        dispatcher.set_installed_solvers(vec![SolverId::Highs, SolverId::Ipopt]);
+
+        let large = dispatcher.select_for_size(ProblemClass::LinearProgram, 10_000);
+        assert_eq!(large.unwrap(), SolverBackend::Highs);
+        let small = dispatcher.select_for_size(ProblemClass::LinearProgram, 14);
+        assert_eq!(small.unwrap(), SolverBackend::Clarabel);
+        let socp = dispatcher.select_for_size(ProblemClass::ConicProgram, 10_000);
+        assert_eq!(socp.unwrap(), SolverBackend::Clarabel);
+    }
+
     #[test]
     fn test_solver_backend_properties() {
         assert!(!SolverBackend::Clarabel.is_native());
@@ -11,6 +11,7 @@ use std::sync::Arc;
 use crate::OpfError;
 use gat_core::Network;
 
+use super::flow_sign::normalize_flow_signs;
 use super::registry::SolverRegistry;
 use super::traits::{SolverConfig, WarmStartKind};
 use super::OpfSolution;
@@ -35,7 +36,8 @@ impl OpfDispatcher {
     /// * `fallbacks` - Warm-start kinds to try if initial solve fails
     ///
     /// # Returns
-    /// The solution, or the first error if all attempts fail.
+    /// The solution, or the first error if all attempts fail. Branch flows are
+    /// normalized to the from→to sign convention.
     ///
     /// # Fallback Chain
     /// If the initial (flat-start) solve fails with a convergence error:
@@ -49,6 +51,20 @@ impl OpfDispatcher {
         formulation_id: &str,
         config: SolverConfig,
         fallbacks: &[WarmStartKind],
+    ) -> Result<OpfSolution, OpfError> {
+        let mut solution = self.solve_with_fallbacks(network, formulation_id, config, fallbacks)?;
+        normalize_flow_signs(&mut solution, network);
+        solution.record_emissions(network);
+        solution.record_dispatch_modes(network);
+        Ok(solution)
+    }
+
+    fn solve_with_fallbacks(
+        &self,
+        network: &Network,
+        formulation_id: &str,
+        config: SolverConfig,
+        fallbacks: &[WarmStartKind],
     ) -> Result<OpfSolution, OpfError> {
         // Look up formulation
         let formulation = self
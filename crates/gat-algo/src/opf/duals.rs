@@ -0,0 +1,90 @@
+//! Shadow prices of DC-OPF constraints read from the solver's row duals.
+//!
+//! Clarabel solves `min ½xᵀPx + qᵀx` subject to `Ax + s = b`, `s ∈ K`, and
+//! returns multipliers `z` with `Px + q + Aᵀz = 0`, so raising `b_r` by one
+//! unit changes the optimal cost by `−z_r`:
+//!
+//! ```text
+//! LMP_i = −z_i · σ_i / w        balance row of bus i, load enters b as σ_i · P_load
+//! μ_k   =  z_k · κ_k / w        limit row aᵀx ≤ b, κ_k converting to the reported unit
+//! ```
+//!
+//! where `w` is the weight the objective puts on $/h of cost (the objective
+//! scale, or a period's duration). Limit duals are non-negative and vanish
+//! unless the limit binds; an interior-point solver returns them only
+//! approximately zero for slack rows.
+
+use super::dc_opf::GenData;
+use crate::opf::ConstraintId;
+use crate::sparse::{PtdfMatrix, SparsePtdf};
+use crate::OpfError;
+use gat_core::{BranchId, Edge, Network};
+use std::collections::HashMap;
+
+/// Distance (MW) within which a generator or branch counts as at its limit
+pub(super) const ACTIVE_TOLERANCE_MW: f64 = 1e-3;
+
+/// LMPs and constraint shadow prices of one DC-OPF period.
+pub(super) struct DcPrices {
+    pub(super) bus_lmp: HashMap<String, f64>,
+    pub(super) constraint_duals: HashMap<ConstraintId, f64>,
+}
+
+/// Rows of one DC-OPF period whose duals are reported. `R` identifies a row
+/// to the solver: a Clarabel row index or a good_lp constraint reference.
+pub(super) struct DcDualRows<R> {
+    /// Bus name, balance row and the row's coefficient σ on the bus load
+    pub(super) balance: Vec<(String, R, f64)>,
+    /// Limit rows with their constraint and the factor κ to its reported
+    /// unit; the two sides of a two-sided limit share one constraint
+    pub(super) limits: Vec<(ConstraintId, R, f64)>,
+}
+
+impl<R> DcDualRows<R> {
+    pub(super) fn new() -> Self {
+        Self {
+            balance: Vec::new(),
+            limits: Vec::new(),
+        }
+    }
+
+    /// Prices from the row duals `dual`, with `cost_weight` the factor the
+    /// objective applies to $/h of cost.
+    pub(super) fn prices(&self, dual: impl Fn(&R) -> f64, cost_weight: f64) -> DcPrices {
+        let bus_lmp = self
+            .balance
+            .iter()
+            .map(|(name, row, sigma)| (name.clone(), -dual(row) * sigma / cost_weight))
+            .collect();
+        let mut constraint_duals = HashMap::new();
+        for (id, row, kappa) in &self.limits {
+            *constraint_duals.entry(id.clone()).or_insert(0.0) += dual(row) * kappa / cost_weight;
+        }
+        DcPrices {
+            bus_lmp,
+            constraint_duals,
+        }
+    }
+}
+
+/// PTDF of the network with the in-service branch IDs by name.
+pub(super) fn branch_ptdf(
+    network: &Network,
+) -> Result<(PtdfMatrix, HashMap<String, BranchId>), OpfError> {
+    let ptdf = SparsePtdf::compute_ptdf(network)
+        .map_err(|e| OpfError::DataValidation(format!("PTDF for shadow prices: {}", e)))?;
+    let ids = network
+        .graph
+        .edge_weights()
+        .filter_map(|edge| match edge {
+            Edge::Branch(branch) if branch.status => Some((branch.name.clone(), branch.id)),
+            _ => None,
+        })
+        .collect();
+    Ok((ptdf, ids))
+}
+
+/// Whether a unit at `p` MW sits strictly inside its limits and so sets a price.
+pub(super) fn is_marginal(gen: &GenData, p: f64) -> bool {
+    p > gen.pmin.max(0.0) + ACTIVE_TOLERANCE_MW && p < gen.pmax - ACTIVE_TOLERANCE_MW
+}
@@ -48,8 +48,12 @@ impl OpfSolution {
     }
 
     /// Export to Parquet format using Arrow (requires desktop feature)
+    ///
+    /// The file is tagged `gat_result_type = opf` with the method as solver
+    /// (e.g. `dc-opf`), see [`gat_schemas::result`].
     #[cfg(all(feature = "desktop", feature = "polars-parquet"))]
     pub fn to_parquet(&self, path: &Path) -> Result<()> {
+        use gat_schemas::result::{result_types, write_result_metadata, ResultMetadata};
         use polars::prelude::*;
 
         // Collect generator data into vectors (sorted for deterministic output)
@@ -82,7 +86,9 @@ impl OpfSolution {
             .finish(&mut df.clone())
             .context("writing DataFrame to Parquet")?;
 
-        Ok(())
+        let tags =
+            ResultMetadata::new(result_types::OPF).with_solver(format!("{}-opf", self.method_used));
+        write_result_metadata(path, &tags)
     }
 }
 
@@ -205,5 +211,11 @@ mod tests {
             parquet_path.metadata().unwrap().len() > 0,
             "Parquet file should not be empty"
         );
+
+        let tags = gat_schemas::result::read_result_metadata(&parquet_path)
+            .unwrap()
+            .expect("OPF results are tagged at write time");
+        assert_eq!(tags.result_type, "opf");
+        assert_eq!(tags.solver.as_deref(), Some("dc-opf"));
     }
 }
@@ -0,0 +1,228 @@
+//! Interchange schedules with neighbouring systems.
+//!
+//! An [`ExternalTie`] stands in for the grid beyond a boundary bus when a
+//! subsystem is studied on its own. Each tie injects its scheduled import at
+//! the boundary bus (negative for an export). A scheduled tie is a fixed
+//! injection; a price-responsive tie may deviate from the schedule within a
+//! band, with the deviation priced at the tie's price.
+//!
+//! Ties are folded into a copy of the network as pseudo-generators (fixed-output
+//! units for scheduled ties, bounded units for price-responsive ones), so every
+//! solve method sees them without formulation changes. The pseudo-generators
+//! are removed from the solution afterwards and their output reported as
+//! `tie_flows`, with the shadow price of their limits reported under
+//! [`ConstraintId::Interchange`]. Ties carry active power only.
+
+use crate::opf::{ConstraintId, OpfSolution};
+use gat_core::{BusId, CostModel, DispatchMode, Gen, GenId, Megawatts, Network, Node};
+
+/// Name prefix keeping tie pseudo-generators apart from real units
+const TIE_PREFIX: &str = "external_tie@";
+
+/// A scheduled import from a neighbouring system at a boundary bus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalTie {
+    pub name: String,
+    /// Boundary bus the import is injected at
+    pub bus: BusId,
+    /// Scheduled import into the bus in MW (negative for export)
+    pub scheduled_mw: f64,
+    /// Price-responsive band around the schedule; `None` for a fixed schedule
+    pub price_band: Option<TiePriceBand>,
+}
+
+/// Room for a tie to deviate from its schedule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TiePriceBand {
+    /// Largest deviation from the schedule in either direction (MW)
+    pub band_mw: f64,
+    /// Price of extra import, and credit for reduced import ($/MWh)
+    pub price: f64,
+}
+
+impl ExternalTie {
+    /// A tie fixed at `scheduled_mw` of import into `bus`.
+    pub fn scheduled(name: impl Into<String>, bus: BusId, scheduled_mw: f64) -> Self {
+        Self {
+            name: name.into(),
+            bus,
+            scheduled_mw,
+            price_band: None,
+        }
+    }
+
+    /// Let the tie deviate up to `band_mw` from its schedule at `price` $/MWh.
+    pub fn with_price_band(mut self, band_mw: f64, price: f64) -> Self {
+        self.price_band = Some(TiePriceBand { band_mw, price });
+        self
+    }
+
+    /// The pseudo-generator representing this tie.
+    fn to_gen(&self, id: GenId) -> Gen {
+        let gen =
+            Gen::new(id, format!("{}{}", TIE_PREFIX, self.name), self.bus).with_q_limits(0.0, 0.0);
+        match self.price_band {
+            None => gen
+                .with_p_limits(self.scheduled_mw, self.scheduled_mw)
+                .with_dispatch_mode(DispatchMode::Fixed(Megawatts(self.scheduled_mw))),
+            // Only the deviation from schedule is priced
+            Some(band) => gen
+                .with_p_limits(
+                    self.scheduled_mw - band.band_mw,
+                    self.scheduled_mw + band.band_mw,
+                )
+                .with_cost(CostModel::Polynomial(vec![
+                    -band.price * self.scheduled_mw,
+                    band.price,
+                ])),
+        }
+    }
+}
+
+/// Copy `network` with a pseudo-generator at each tie's boundary bus.
+pub(crate) fn attach_ties(network: &Network, ties: &[ExternalTie]) -> Network {
+    let mut tied = network.clone();
+    let next_id = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Gen(gen) => Some(gen.id.value() + 1),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    for (offset, tie) in ties.iter().enumerate() {
+        tied.graph
+            .add_node(Node::Gen(tie.to_gen(GenId::new(next_id + offset))));
+    }
+    tied
+}
+
+/// Move the tie pseudo-generators out of the dispatch and into `tie_flows`.
+pub(crate) fn extract_tie_flows(solution: &mut OpfSolution, ties: &[ExternalTie]) {
+    for tie in ties {
+        let name = format!("{}{}", TIE_PREFIX, tie.name);
+        solution.generator_q.remove(&name);
+        if let Some(p_mw) = solution.generator_p.remove(&name) {
+            solution.tie_flows.insert(tie.name.clone(), p_mw);
+        }
+        // The schedule or band is the unit's limits; whichever binds prices it
+        let upper = solution
+            .constraint_duals
+            .remove(&ConstraintId::GeneratorPMax(name.clone()));
+        let lower = solution
+            .constraint_duals
+            .remove(&ConstraintId::GeneratorPMin(name));
+        if let Some(dual) = upper.into_iter().chain(lower).reduce(f64::max) {
+            solution
+                .constraint_duals
+                .insert(ConstraintId::Interchange(tie.name.clone()), dual);
+        }
+    }
+    solution
+        .constrained_generators
+        .retain(|name| !name.starts_with(TIE_PREFIX));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opf::{OpfMethod, OpfSolver};
+    use gat_core::{Branch, BranchId, Bus, Edge, Kilovolts, Load, LoadId, Megavars};
+
+    /// One internal unit ($30/MWh) at bus 1 serving 150 MW at bus 2, with
+    /// bus 2 on the boundary.
+    fn area_network() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=2)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: Kilovolts(230.0),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        network.graph.add_edge(
+            buses[0],
+            buses[1],
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "line1_2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.001,
+                0.01,
+            )),
+        );
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "internal".to_string(), BusId::new(1))
+                .with_p_limits(0.0, 300.0)
+                .with_q_limits(-100.0, 100.0)
+                .with_cost(CostModel::linear(0.0, 30.0)),
+        ));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(150.0),
+            reactive_power: Megavars(20.0),
+            zip: None,
+        }));
+        network
+    }
+
+    #[test]
+    fn test_scheduled_import_displaces_internal_generation() {
+        let network = area_network();
+        for method in [OpfMethod::DcOpf, OpfMethod::SocpRelaxation] {
+            let islanded = OpfSolver::new()
+                .with_method(method)
+                .solve(&network)
+                .unwrap();
+            let importing = OpfSolver::new()
+                .with_method(method)
+                .with_external_tie(ExternalTie::scheduled("north", BusId::new(2), 50.0))
+                .solve(&network)
+                .unwrap();
+
+            let reduction = islanded.generator_p["internal"] - importing.generator_p["internal"];
+            assert!(
+                (reduction - 50.0).abs() < 0.5,
+                "{:?} reduced internal generation by {:.3} MW",
+                method,
+                reduction
+            );
+            assert!((importing.tie_flows["north"] - 50.0).abs() < 1e-3);
+            assert_eq!(importing.generator_p.len(), 1);
+            assert!(importing.constrained_generators.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_price_responsive_tie_moves_within_band() {
+        // Imports at $20/MWh undercut the $30/MWh internal unit, so the tie
+        // runs to the top of its band; at $40/MWh it backs off to the bottom
+        let network = area_network();
+        let solve = |price: f64| {
+            OpfSolver::new()
+                .with_method(OpfMethod::DcOpf)
+                .with_external_tie(
+                    ExternalTie::scheduled("north", BusId::new(2), 50.0)
+                        .with_price_band(20.0, price),
+                )
+                .solve(&network)
+                .unwrap()
+        };
+        let cheap = solve(20.0);
+        assert!((cheap.tie_flows["north"] - 70.0).abs() < 1e-3);
+        assert!((cheap.generator_p["internal"] - 80.0).abs() < 1e-3);
+        // 80 MW internal at $30 less 20 MW of extra import at $20
+        assert!((cheap.objective_value - (80.0 * 30.0 + 20.0 * 20.0)).abs() < 1.0);
+
+        let dear = solve(40.0);
+        assert!((dear.tie_flows["north"] - 30.0).abs() < 1e-3);
+        assert!((dear.generator_p["internal"] - 120.0).abs() < 1e-3);
+    }
+}
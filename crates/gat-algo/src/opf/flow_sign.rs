@@ -0,0 +1,211 @@
+//! Canonical sign convention for reported branch flows.
+//!
+//! Every branch flow GAT reports is measured at the branch's `from_bus` and is
+//! **positive when power flows from `from_bus` to `to_bus`**. For lossy (SOCP,
+//! AC) results this is the sending-end flow; the receiving end sees the same
+//! sign minus losses.
+//!
+//! Producers that compute flows another way tag their result with a
+//! [`FlowDirection`] and pass it through [`normalize_flow_signs`], which flips
+//! the affected entries so results from DC, SOCP and power flow solvers can be
+//! compared directly. The graph edge orientation is *not* the reference: a
+//! branch may be inserted into the graph in either order, and only its
+//! `from_bus`/`to_bus` fields are authoritative.
+
+use crate::opf::OpfSolution;
+use gat_core::{Edge, Network, Node};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Orientation a result's branch flows are reported in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum FlowDirection {
+    /// Positive from `from_bus` to `to_bus` (the canonical convention)
+    #[default]
+    FromTo,
+    /// Positive from `to_bus` to `from_bus`, e.g. flows measured at the receiving end
+    ToFrom,
+    /// Positive from the graph edge's first endpoint to its second
+    GraphEdge,
+}
+
+/// A result holding branch flows keyed by branch name.
+pub trait BranchFlows {
+    /// Orientation the flows are currently reported in.
+    fn flow_direction(&self) -> FlowDirection;
+    /// Record the orientation after the flows have been rewritten.
+    fn set_flow_direction(&mut self, direction: FlowDirection);
+    /// Every signed per-branch quantity (active and reactive flows).
+    fn flow_maps_mut(&mut self) -> Vec<&mut HashMap<String, f64>>;
+}
+
+impl BranchFlows for OpfSolution {
+    fn flow_direction(&self) -> FlowDirection {
+        self.flow_direction
+    }
+
+    fn set_flow_direction(&mut self, direction: FlowDirection) {
+        self.flow_direction = direction;
+    }
+
+    fn flow_maps_mut(&mut self) -> Vec<&mut HashMap<String, f64>> {
+        vec![&mut self.branch_p_flow, &mut self.branch_q_flow]
+    }
+}
+
+/// Rewrite `result`'s flows to the canonical from→to convention.
+///
+/// Idempotent: a result already in [`FlowDirection::FromTo`] is left as is.
+pub fn normalize_flow_signs<R: BranchFlows + ?Sized>(result: &mut R, network: &Network) {
+    let reversed: HashSet<String> = match result.flow_direction() {
+        FlowDirection::FromTo => return,
+        FlowDirection::ToFrom => network
+            .graph
+            .edge_weights()
+            .map(|edge| edge_name(edge).to_string())
+            .collect(),
+        FlowDirection::GraphEdge => network
+            .graph
+            .edge_indices()
+            .filter_map(|idx| {
+                let (a, _) = network.graph.edge_endpoints(idx)?;
+                let Node::Bus(first) = &network.graph[a] else {
+                    return None;
+                };
+                let edge = &network.graph[idx];
+                (first.id != edge_from_bus(edge)).then(|| edge_name(edge).to_string())
+            })
+            .collect(),
+    };
+
+    for flows in result.flow_maps_mut() {
+        for (name, value) in flows.iter_mut() {
+            if reversed.contains(name) {
+                *value = -*value;
+            }
+        }
+    }
+    result.set_flow_direction(FlowDirection::FromTo);
+}
+
+fn edge_name(edge: &Edge) -> &str {
+    match edge {
+        Edge::Branch(branch) => &branch.name,
+        Edge::Transformer(transformer) => &transformer.name,
+    }
+}
+
+fn edge_from_bus(edge: &Edge) -> gat_core::BusId {
+    match edge {
+        Edge::Branch(branch) => branch.from_bus,
+        Edge::Transformer(transformer) => transformer.from_bus,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opf::ac_nlp::PeriodData;
+    use crate::opf::{solve_multiperiod_dc, OpfMethod, OpfSolver};
+    use gat_core::{
+        Branch, BranchId, Bus, BusId, CostModel, Gen, GenId, Kilovolts, Load, LoadId, Megavars,
+        Megawatts,
+    };
+
+    /// Generator at bus 1 serving 50 MW at bus 2. The line's `from_bus` is 1,
+    /// but it is inserted into the graph from bus 2's node, so the graph edge
+    /// points against the canonical direction.
+    fn reversed_edge_network() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=2)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: Kilovolts(230.0),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        network.graph.add_edge(
+            buses[1],
+            buses[0],
+            Edge::Branch(Branch::new(
+                BranchId::new(0),
+                "line1_2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1))
+                .with_p_limits(0.0, 200.0)
+                .with_q_limits(-100.0, 100.0)
+                .with_cost(CostModel::linear(0.0, 10.0)),
+        ));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(50.0),
+            reactive_power: Megavars(10.0),
+            zip: None,
+        }));
+        network
+    }
+
+    #[test]
+    fn test_every_solver_reports_from_to_sign() {
+        let network = reversed_edge_network();
+
+        for method in [OpfMethod::DcOpf, OpfMethod::SocpRelaxation] {
+            let solution = OpfSolver::new()
+                .with_method(method)
+                .solve(&network)
+                .unwrap();
+            let flow = solution.branch_p_flow["line1_2"];
+            assert!(flow > 49.0, "{:?} reported {}", method, flow);
+            assert_eq!(solution.flow_direction, FlowDirection::FromTo);
+        }
+
+        let multiperiod =
+            solve_multiperiod_dc(&network, &[PeriodData::hourly(0, 1.0)], &[]).unwrap();
+        let flow = multiperiod.periods[0].branch_p_flow["line1_2"];
+        assert!((flow - 50.0).abs() < 1e-3, "multi-period reported {}", flow);
+    }
+
+    #[test]
+    fn test_graph_edge_flows_are_rewritten() {
+        let network = reversed_edge_network();
+        // A producer walking graph edges sees the line as 2 -> 1
+        let mut solution = OpfSolution {
+            flow_direction: FlowDirection::GraphEdge,
+            ..Default::default()
+        };
+        solution.branch_p_flow.insert("line1_2".to_string(), -50.0);
+        solution.branch_q_flow.insert("line1_2".to_string(), -10.0);
+
+        normalize_flow_signs(&mut solution, &network);
+        assert_eq!(solution.branch_p_flow["line1_2"], 50.0);
+        assert_eq!(solution.branch_q_flow["line1_2"], 10.0);
+        assert_eq!(solution.flow_direction, FlowDirection::FromTo);
+
+        // A second pass is a no-op
+        normalize_flow_signs(&mut solution, &network);
+        assert_eq!(solution.branch_p_flow["line1_2"], 50.0);
+    }
+
+    #[test]
+    fn test_receiving_end_flows_are_negated() {
+        let network = reversed_edge_network();
+        let mut solution = OpfSolution {
+            flow_direction: FlowDirection::ToFrom,
+            ..Default::default()
+        };
+        solution.branch_p_flow.insert("line1_2".to_string(), -50.0);
+        normalize_flow_signs(&mut solution, &network);
+        assert_eq!(solution.branch_p_flow["line1_2"], 50.0);
+    }
+}
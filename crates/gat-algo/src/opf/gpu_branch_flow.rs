@@ -360,6 +360,7 @@ mod tests {
             vmax_pu: Some(PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
 
         let bus2_idx = network.graph.add_node(Node::Bus(Bus {
@@ -372,6 +373,7 @@ mod tests {
             vmax_pu: Some(PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
 
         // Add a branch
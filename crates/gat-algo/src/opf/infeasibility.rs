@@ -0,0 +1,199 @@
+//! Diagnosis of infeasible OPF cases.
+//!
+//! When an AC-OPF fails to converge, the solver status says nothing about
+//! why. [`diagnose`] re-solves the case with whole constraint groups relaxed
+//! (bus voltage bands, branch thermal limits, generator reactive limits) and
+//! reports which relaxations restore feasibility. This is the OPF analog of an
+//! irreducible infeasible subsystem, at the granularity of constraint groups:
+//!
+//! 1. Each group is relaxed on its own. Any group whose relaxation alone
+//!    restores feasibility is a minimal culprit set by itself.
+//! 2. If no single group suffices, all groups are relaxed together and then
+//!    re-tightened one at a time (a deletion filter), keeping only the groups
+//!    that must stay relaxed.
+//!
+//! Relaxed groups are widened to loose but finite limits, so solver starting
+//! points stay well defined.
+
+use crate::opf::{OpfSolution, OpfSolver};
+use crate::OpfError;
+use gat_core::{Edge, Megavars, Network, Node, PerUnit};
+
+/// Voltage band used when voltage bounds are relaxed (p.u.)
+const RELAXED_VMIN: f64 = 0.5;
+const RELAXED_VMAX: f64 = 1.5;
+/// Reactive limit used when generator reactive limits are relaxed (MVAr)
+const RELAXED_Q_MVAR: f64 = 9999.0;
+
+/// A family of constraints relaxed together during diagnosis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstraintGroup {
+    /// Bus voltage magnitude bands
+    VoltageBounds,
+    /// Branch MVA ratings
+    ThermalLimits,
+    /// Generator reactive power limits
+    ReactiveLimits,
+}
+
+impl ConstraintGroup {
+    pub const ALL: [ConstraintGroup; 3] = [
+        ConstraintGroup::VoltageBounds,
+        ConstraintGroup::ThermalLimits,
+        ConstraintGroup::ReactiveLimits,
+    ];
+
+    /// Relax this group in `network`.
+    fn relax(self, network: &mut Network) {
+        match self {
+            ConstraintGroup::VoltageBounds => {
+                for node in network.graph.node_weights_mut() {
+                    if let Node::Bus(bus) = node {
+                        bus.vmin_pu = Some(PerUnit(RELAXED_VMIN));
+                        bus.vmax_pu = Some(PerUnit(RELAXED_VMAX));
+                    }
+                }
+            }
+            ConstraintGroup::ThermalLimits => {
+                for edge in network.graph.edge_weights_mut() {
+                    if let Edge::Branch(branch) = edge {
+                        branch.s_max = None;
+                        branch.rating_a = None;
+                        branch.rating_b = None;
+                        branch.rating_c = None;
+                    }
+                }
+            }
+            ConstraintGroup::ReactiveLimits => {
+                for node in network.graph.node_weights_mut() {
+                    if let Node::Gen(gen) = node {
+                        gen.qmin = Megavars(-RELAXED_Q_MVAR);
+                        gen.qmax = Megavars(RELAXED_Q_MVAR);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Outcome of relaxing one constraint group on its own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupRelaxation {
+    pub group: ConstraintGroup,
+    /// Whether the case solves with only this group relaxed
+    pub restores_feasibility: bool,
+    /// Whether the group belongs to the reported minimal relaxation
+    pub in_minimal_set: bool,
+    /// Objective of the relaxed solve, when it converged
+    pub objective_value: Option<f64>,
+}
+
+/// Likely causes of an infeasible OPF.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfeasibilityReport {
+    /// The case solved as given; no relaxation was needed
+    pub base_feasible: bool,
+    /// Smallest set of groups whose joint relaxation restores feasibility;
+    /// `None` if relaxing every group still fails
+    pub minimal_relaxation: Option<Vec<ConstraintGroup>>,
+    /// Every group, most likely culprit first: members of the minimal set,
+    /// then groups that restore feasibility alone, cheapest relaxed solve first
+    pub culprits: Vec<GroupRelaxation>,
+}
+
+/// Diagnose why `solver` cannot solve `network`.
+pub(crate) fn diagnose(
+    solver: &OpfSolver,
+    network: &Network,
+) -> Result<InfeasibilityReport, OpfError> {
+    if feasible(solver, network, &[])?.is_some() {
+        return Ok(InfeasibilityReport {
+            base_feasible: true,
+            minimal_relaxation: Some(Vec::new()),
+            culprits: Vec::new(),
+        });
+    }
+
+    let mut culprits = Vec::new();
+    for group in ConstraintGroup::ALL {
+        let relaxed = feasible(solver, network, &[group])?;
+        culprits.push(GroupRelaxation {
+            group,
+            restores_feasibility: relaxed.is_some(),
+            in_minimal_set: false,
+            objective_value: relaxed.map(|s| s.objective_value),
+        });
+    }
+    sort_culprits(&mut culprits);
+
+    let minimal = if culprits[0].restores_feasibility {
+        Some(vec![culprits[0].group])
+    } else {
+        deletion_filter(solver, network)?
+    };
+    if let Some(groups) = &minimal {
+        for culprit in &mut culprits {
+            culprit.in_minimal_set = groups.contains(&culprit.group);
+        }
+        sort_culprits(&mut culprits);
+    }
+
+    Ok(InfeasibilityReport {
+        base_feasible: false,
+        minimal_relaxation: minimal,
+        culprits,
+    })
+}
+
+/// Relax every group, then re-tighten each one that is not needed.
+fn deletion_filter(
+    solver: &OpfSolver,
+    network: &Network,
+) -> Result<Option<Vec<ConstraintGroup>>, OpfError> {
+    let mut relaxed = ConstraintGroup::ALL.to_vec();
+    if feasible(solver, network, &relaxed)?.is_none() {
+        return Ok(None);
+    }
+    for group in ConstraintGroup::ALL {
+        let without: Vec<ConstraintGroup> =
+            relaxed.iter().copied().filter(|g| *g != group).collect();
+        if feasible(solver, network, &without)?.is_some() {
+            relaxed = without;
+        }
+    }
+    Ok(Some(relaxed))
+}
+
+fn sort_culprits(culprits: &mut [GroupRelaxation]) {
+    culprits.sort_by(|a, b| {
+        b.in_minimal_set
+            .cmp(&a.in_minimal_set)
+            .then(b.restores_feasibility.cmp(&a.restores_feasibility))
+            .then(
+                a.objective_value
+                    .unwrap_or(f64::INFINITY)
+                    .total_cmp(&b.objective_value.unwrap_or(f64::INFINITY)),
+            )
+    });
+}
+
+/// Solve with `groups` relaxed; `None` when the solve fails to converge.
+fn feasible(
+    solver: &OpfSolver,
+    network: &Network,
+    groups: &[ConstraintGroup],
+) -> Result<Option<OpfSolution>, OpfError> {
+    let mut relaxed = network.clone();
+    for group in groups {
+        group.relax(&mut relaxed);
+    }
+    match solver.solve(&relaxed) {
+        Ok(solution) if solution.converged => Ok(Some(solution)),
+        Ok(_)
+        | Err(OpfError::Infeasible(_))
+        | Err(OpfError::ConvergenceFailure { .. })
+        | Err(OpfError::NumericalIssue(_))
+        | Err(OpfError::SolverTimeout(_)) => Ok(None),
+        Err(other) => Err(other),
+    }
+}
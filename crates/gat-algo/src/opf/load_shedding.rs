@@ -0,0 +1,48 @@
+//! Priority-ordered load shedding for DC-OPF.
+//!
+//! With [`LoadShedding`] set, each load gets a shed variable bounded by its
+//! demand and priced at its value of lost load (VOLL). When generation cannot
+//! cover demand the LP drops the cheapest-to-interrupt load first, so
+//! interruptible loads go before firm ones and a critical load with a very
+//! high VOLL is shed only once everything cheaper is gone. A load with an
+//! infinite VOLL is never shed.
+
+use std::collections::HashMap;
+
+/// Value of lost load per load, ordering which demand is shed first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadShedding {
+    /// VOLL for loads without their own value ($/MWh)
+    pub default_voll: f64,
+    /// Per-load VOLL keyed by load name ($/MWh)
+    pub per_load: HashMap<String, f64>,
+}
+
+impl LoadShedding {
+    /// Same VOLL for every load
+    pub fn uniform(voll: f64) -> Self {
+        Self {
+            default_voll: voll,
+            per_load: HashMap::new(),
+        }
+    }
+
+    /// Set the VOLL of one load
+    pub fn with_load(mut self, name: impl Into<String>, voll: f64) -> Self {
+        self.per_load.insert(name.into(), voll);
+        self
+    }
+
+    /// Mark a load as critical: never shed
+    pub fn with_critical_load(self, name: impl Into<String>) -> Self {
+        self.with_load(name, f64::INFINITY)
+    }
+
+    /// VOLL of the load named `name` ($/MWh)
+    pub fn voll(&self, name: &str) -> f64 {
+        self.per_load
+            .get(name)
+            .copied()
+            .unwrap_or(self.default_voll)
+    }
+}
@@ -2,14 +2,50 @@
 //!
 //! Dispatches generators in order of marginal cost to minimize total cost.
 //! Does not model network constraints, losses, or reactive power.
+//!
+//! ## Reactive screening
+//!
+//! [`attach_reactive_dispatch`] optionally follows the active dispatch with a
+//! greedy reactive assignment: each bus's reactive load, net of in-service
+//! shunts at nominal voltage, is drawn from generators in order of hop distance
+//! over in-service branches (ties by `GenId`) until their Q limits are reached.
+//! Whatever no reachable generator can cover is reported as a shortfall. There
+//! is no voltage or flow model, so a zero shortfall does not prove AC
+//! feasibility; a non-zero one reliably flags a reactive deficit.
+//!
+//! ## Dispatch trace
+//!
+//! [`attach_dispatch_trace`] explains the result unit by unit: its position in
+//! the merit order, its marginal cost at the dispatched output, and whether it
+//! sits at a limit, sets the marginal cost, or is not needed at all.
+//!
+//! ## Tie-breaking
+//!
+//! Units whose marginal cost at `Pmin` is equal (within [`MC_TIE_TOLERANCE`])
+//! form one merit-order block. The block's share of the remaining load is
+//! split in proportion to each unit's headroom (`Pmax - Pmin`), so all tied
+//! units reach their limits together. Units inside a block are ordered by
+//! `GenId`, which makes the dispatch independent of the order in which
+//! generators appear in the network graph.
 
 use crate::{
-    opf::{OpfMethod, OpfSolution},
+    opf::{DispatchExplanation, DispatchReason, OpfMethod, OpfSolution},
     OpfError,
 };
-use gat_core::{Gen, Network, Node};
+use gat_core::{BusId, Edge, Gen, Network, Node};
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 use web_time::Instant;
 
+/// Marginal costs closer than this ($/MWh) are treated as tied
+const MC_TIE_TOLERANCE: f64 = 1e-9;
+
+/// Distance (MW) within which a unit counts as sitting at a limit
+const DISPATCH_LIMIT_TOLERANCE: f64 = 1e-6;
+
+/// System base for converting shunt susceptance to MVAr at nominal voltage
+const BASE_MVA: f64 = 100.0;
+
 /// Solve using merit-order economic dispatch
 pub fn solve(
     network: &Network,
@@ -46,8 +82,8 @@ pub fn solve(
     let required_generation = total_load + loss_estimate;
 
     // Check total capacity
-    let total_pmax: f64 = generators.iter().map(|g| g.pmax.value()).sum();
-    let total_pmin: f64 = generators.iter().map(|g| g.pmin.value()).sum();
+    let total_pmax: f64 = generators.iter().map(|g| g.dispatch_pmax()).sum();
+    let total_pmin: f64 = generators.iter().map(|g| g.dispatch_pmin()).sum();
 
     if required_generation > total_pmax {
         return Err(OpfError::Infeasible(format!(
@@ -100,6 +136,24 @@ pub fn solve(
     Ok(solution)
 }
 
+/// Marginal cost at `Pmin` of each unit, and unit indices in merit order.
+///
+/// Units are sorted by marginal cost at `Pmin`, then by `GenId`.
+fn merit_order(generators: &[Gen]) -> (Vec<f64>, Vec<usize>) {
+    let marginal: Vec<f64> = generators
+        .iter()
+        .map(|g| g.cost_model.marginal_cost(g.dispatch_pmin()))
+        .collect();
+    let mut order: Vec<usize> = (0..generators.len()).collect();
+    order.sort_by(|&a, &b| {
+        marginal[a]
+            .partial_cmp(&marginal[b])
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| generators[a].id.value().cmp(&generators[b].id.value()))
+    });
+    (marginal, order)
+}
+
 /// Economic dispatch using merit order
 fn economic_dispatch(generators: &[Gen], required_generation: f64) -> Result<Vec<f64>, OpfError> {
     let n = generators.len();
@@ -107,42 +161,45 @@ fn economic_dispatch(generators: &[Gen], required_generation: f64) -> Result<Vec
 
     // Start with minimum generation for all units
     for (i, gen) in generators.iter().enumerate() {
-        dispatch[i] = gen.pmin.value();
+        dispatch[i] = gen.dispatch_pmin();
     }
 
     // Calculate how much more we need beyond minimum
-    let total_pmin: f64 = generators.iter().map(|g| g.pmin.value()).sum();
+    let total_pmin: f64 = generators.iter().map(|g| g.dispatch_pmin()).sum();
     let mut remaining = required_generation - total_pmin;
 
     if remaining < 0.0 {
         return Ok(dispatch);
     }
 
-    // Create merit order: sort by marginal cost at Pmin
-    let mut merit_order: Vec<usize> = (0..n).collect();
-    merit_order.sort_by(|&a, &b| {
-        let mc_a = generators[a]
-            .cost_model
-            .marginal_cost(generators[a].pmin.value());
-        let mc_b = generators[b]
-            .cost_model
-            .marginal_cost(generators[b].pmin.value());
-        mc_a.partial_cmp(&mc_b).unwrap_or(std::cmp::Ordering::Equal)
-    });
+    let (marginal, merit_order) = merit_order(generators);
 
-    // Dispatch in merit order
-    for &idx in &merit_order {
-        if remaining <= 1e-6 {
-            break;
+    // Dispatch block by block; tied units share in proportion to headroom
+    let mut start = 0;
+    while start < n && remaining > 1e-6 {
+        let mut end = start + 1;
+        while end < n
+            && (marginal[merit_order[end]] - marginal[merit_order[start]]).abs() <= MC_TIE_TOLERANCE
+        {
+            end += 1;
         }
+        let block = &merit_order[start..end];
+        start = end;
 
-        let gen = &generators[idx];
-        let current = dispatch[idx];
-        let headroom = (gen.pmax.value() - current).max(0.0);
-        let increment = remaining.min(headroom);
+        let headroom: Vec<f64> = block
+            .iter()
+            .map(|&idx| (generators[idx].dispatch_pmax() - dispatch[idx]).max(0.0))
+            .collect();
+        let block_headroom: f64 = headroom.iter().sum();
+        if block_headroom <= 0.0 {
+            continue;
+        }
 
-        dispatch[idx] = current + increment;
-        remaining -= increment;
+        let block_increment = remaining.min(block_headroom);
+        for (&idx, &room) in block.iter().zip(&headroom) {
+            dispatch[idx] += block_increment * room / block_headroom;
+        }
+        remaining -= block_increment;
     }
 
     if remaining > 1e-3 {
@@ -154,3 +211,333 @@ fn economic_dispatch(generators: &[Gen], required_generation: f64) -> Result<Vec
 
     Ok(dispatch)
 }
+
+/// Explain an economic dispatch unit by unit and record it in the solution.
+///
+/// Sets `solution.dispatch_trace` to one entry per generator, sorted by merit
+/// position. A unit at `Pmax` is [`AtMaximum`](DispatchReason::AtMaximum),
+/// one strictly between its limits is [`Marginal`](DispatchReason::Marginal),
+/// and one at its `Pmin` is [`AtMinimum`](DispatchReason::AtMinimum), or
+/// [`NotNeeded`](DispatchReason::NotNeeded) when that minimum is zero.
+pub fn attach_dispatch_trace(network: &Network, solution: &mut OpfSolution) {
+    let generators: Vec<Gen> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Gen(gen) => Some(gen.clone()),
+            _ => None,
+        })
+        .collect();
+    let (_, order) = merit_order(&generators);
+
+    let trace = order
+        .into_iter()
+        .enumerate()
+        .map(|(position, idx)| {
+            let gen = &generators[idx];
+            let p = solution.generator_p.get(&gen.name).copied().unwrap_or(0.0);
+            let (pmin, pmax) = (gen.dispatch_pmin(), gen.dispatch_pmax());
+            let reason = if p <= pmin + DISPATCH_LIMIT_TOLERANCE {
+                if pmin > DISPATCH_LIMIT_TOLERANCE {
+                    DispatchReason::AtMinimum
+                } else {
+                    DispatchReason::NotNeeded
+                }
+            } else if p >= pmax - DISPATCH_LIMIT_TOLERANCE {
+                DispatchReason::AtMaximum
+            } else {
+                DispatchReason::Marginal
+            };
+            DispatchExplanation {
+                generator: gen.name.clone(),
+                merit_position: position + 1,
+                dispatch_mw: p,
+                marginal_cost: gen.cost_model.marginal_cost(p),
+                reason,
+            }
+        })
+        .collect();
+    solution.dispatch_trace = Some(trace);
+}
+
+/// Assign reactive output after economic dispatch and record the shortfall.
+///
+/// Fills `solution.generator_q` for every generator and sets
+/// `reactive_shortfall_mvar` to the total reactive demand (absorption
+/// included) left uncovered.
+pub fn attach_reactive_dispatch(network: &Network, solution: &mut OpfSolution) {
+    let mut demand: HashMap<BusId, f64> = HashMap::new();
+    let mut generators: Vec<&Gen> = Vec::new();
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Load(load) => {
+                *demand.entry(load.bus).or_default() += load.reactive_power.value();
+            }
+            Node::Shunt(shunt) if shunt.status => {
+                *demand.entry(shunt.bus).or_default() -= shunt.bs_pu * BASE_MVA;
+            }
+            Node::Gen(gen) => generators.push(gen),
+            _ => {}
+        }
+    }
+    generators.sort_by_key(|g| g.id.value());
+
+    // Generators start at the Q closest to zero that their limits allow
+    let mut q: Vec<f64> = generators
+        .iter()
+        .map(|g| 0.0_f64.max(g.qmin.value()).min(g.qmax.value()))
+        .collect();
+    for (gen, &q_gen) in generators.iter().zip(&q) {
+        *demand.entry(gen.bus).or_default() -= q_gen;
+    }
+
+    let adjacency = bus_adjacency(network);
+    let mut buses: Vec<(BusId, f64)> = demand.into_iter().collect();
+    buses.sort_by_key(|(bus, _)| bus.value());
+
+    let mut shortfall = 0.0;
+    for (bus, mut remaining) in buses {
+        if remaining.abs() < 1e-9 {
+            continue;
+        }
+        let distance = hop_distances(&adjacency, bus);
+        let mut candidates: Vec<(usize, usize)> = generators
+            .iter()
+            .enumerate()
+            .filter_map(|(i, g)| distance.get(&g.bus).map(|&d| (d, i)))
+            .collect();
+        candidates.sort_unstable();
+
+        for (_, i) in candidates {
+            let gen = generators[i];
+            let room = if remaining > 0.0 {
+                (gen.qmax.value() - q[i]).max(0.0)
+            } else {
+                (gen.qmin.value() - q[i]).min(0.0)
+            };
+            let take = if remaining > 0.0 {
+                remaining.min(room)
+            } else {
+                remaining.max(room)
+            };
+            q[i] += take;
+            remaining -= take;
+            if remaining.abs() < 1e-9 {
+                break;
+            }
+        }
+        shortfall += remaining.abs();
+    }
+
+    for (gen, q_gen) in generators.iter().zip(q) {
+        solution.generator_q.insert(gen.name.clone(), q_gen);
+    }
+    solution.reactive_shortfall_mvar = Some(shortfall);
+}
+
+/// Bus neighbours over in-service branches and transformers.
+fn bus_adjacency(network: &Network) -> HashMap<BusId, Vec<BusId>> {
+    let mut adjacency: HashMap<BusId, Vec<BusId>> = HashMap::new();
+    for edge in network.graph.edge_weights() {
+        let (from, to) = match edge {
+            Edge::Branch(branch) if branch.status => (branch.from_bus, branch.to_bus),
+            Edge::Transformer(tx) => (tx.from_bus, tx.to_bus),
+            _ => continue,
+        };
+        adjacency.entry(from).or_default().push(to);
+        adjacency.entry(to).or_default().push(from);
+    }
+    adjacency
+}
+
+/// Breadth-first hop count from `start` to every reachable bus.
+fn hop_distances(adjacency: &HashMap<BusId, Vec<BusId>>, start: BusId) -> HashMap<BusId, usize> {
+    let mut distance = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(bus) = queue.pop_front() {
+        let d = distance[&bus];
+        for &next in adjacency.get(&bus).into_iter().flatten() {
+            if !distance.contains_key(&next) {
+                distance.insert(next, d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{CostModel, GenId};
+
+    fn gen(id: usize, pmin: f64, pmax: f64, cost: f64) -> Gen {
+        Gen::new(
+            GenId::new(id),
+            format!("Gen {}", id),
+            gat_core::BusId::new(1),
+        )
+        .with_p_limits(pmin, pmax)
+        .with_cost(CostModel::linear(0.0, cost))
+    }
+
+    #[test]
+    fn test_tied_units_split_by_headroom() {
+        // Headroom 100 MW vs 300 MW: 80 MW of demand splits 20 / 60
+        let generators = vec![gen(1, 0.0, 100.0, 20.0), gen(2, 0.0, 300.0, 20.0)];
+        let dispatch = economic_dispatch(&generators, 80.0).unwrap();
+        assert!((dispatch[0] - 20.0).abs() < 1e-9);
+        assert!((dispatch[1] - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tied_split_is_stable_across_runs_and_order() {
+        let forward = vec![gen(1, 10.0, 110.0, 25.0), gen(2, 10.0, 110.0, 25.0)];
+        let reversed: Vec<Gen> = forward.iter().rev().cloned().collect();
+
+        let first = economic_dispatch(&forward, 120.0).unwrap();
+        assert!((first[0] - 60.0).abs() < 1e-9);
+        assert!((first[1] - 60.0).abs() < 1e-9);
+        for _ in 0..10 {
+            assert_eq!(economic_dispatch(&forward, 120.0).unwrap(), first);
+        }
+
+        let swapped = economic_dispatch(&reversed, 120.0).unwrap();
+        assert_eq!(swapped[0], first[1]);
+        assert_eq!(swapped[1], first[0]);
+    }
+
+    /// Chain bus1 — bus2 — bus3 with units at buses 1 and 2 and the load at bus 3.
+    fn reactive_chain(load_mvar: f64) -> Network {
+        use gat_core::{Branch, BranchId, Bus, BusId, Edge, Load, LoadId, Megavars, Megawatts};
+
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("Bus {}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (id, (from, to)) in [(1, 2), (2, 3)].into_iter().enumerate() {
+            network.graph.add_edge(
+                buses[from - 1],
+                buses[to - 1],
+                Edge::Branch(Branch::new(
+                    BranchId::new(id),
+                    format!("Line {}-{}", from, to),
+                    BusId::new(from),
+                    BusId::new(to),
+                    0.01,
+                    0.1,
+                )),
+            );
+        }
+        for (id, bus, qmax) in [(1, 1, 30.0), (2, 2, 20.0)] {
+            let mut unit = gen(id, 0.0, 100.0, 10.0 * id as f64).with_q_limits(-10.0, qmax);
+            unit.bus = BusId::new(bus);
+            network.graph.add_node(Node::Gen(unit));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load 3".to_string(),
+            bus: BusId::new(3),
+            active_power: Megawatts(50.0),
+            reactive_power: Megavars(load_mvar),
+            zip: None,
+        }));
+        network
+    }
+
+    fn solve_with_reactive(network: &Network) -> OpfSolution {
+        crate::opf::OpfSolver::new()
+            .with_method(OpfMethod::EconomicDispatch)
+            .with_reactive_dispatch(true)
+            .solve(network)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_reactive_shortfall_when_load_exceeds_capability() {
+        // 80 MVAr against 30 + 20 MVAr of capability leaves 30 MVAr unmet
+        let solution = solve_with_reactive(&reactive_chain(80.0));
+        assert!((solution.generator_q["Gen 1"] - 30.0).abs() < 1e-9);
+        assert!((solution.generator_q["Gen 2"] - 20.0).abs() < 1e-9);
+        assert!((solution.reactive_shortfall_mvar.unwrap() - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reactive_load_served_from_nearest_unit() {
+        // Gen 2 sits one hop from the load and covers it alone
+        let solution = solve_with_reactive(&reactive_chain(15.0));
+        assert!((solution.generator_q["Gen 2"] - 15.0).abs() < 1e-9);
+        assert_eq!(solution.generator_q["Gen 1"], 0.0);
+        assert_eq!(solution.reactive_shortfall_mvar, Some(0.0));
+    }
+
+    #[test]
+    fn test_cheaper_unit_still_loads_first() {
+        let generators = vec![
+            gen(1, 0.0, 100.0, 30.0),
+            gen(2, 0.0, 50.0, 10.0),
+            gen(3, 0.0, 50.0, 30.0),
+        ];
+        let dispatch = economic_dispatch(&generators, 80.0).unwrap();
+        assert!((dispatch[1] - 50.0).abs() < 1e-9);
+        // 30 MW left for the tied block, split 2:1 by headroom
+        assert!((dispatch[0] - 20.0).abs() < 1e-9);
+        assert!((dispatch[2] - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dispatch_trace_identifies_marginal_unit() {
+        use gat_core::{Bus, BusId, Load, LoadId, Megavars, Megawatts};
+
+        let mut network = Network::new();
+        network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus 1".to_string(),
+            ..Bus::default()
+        }));
+        // Listed out of merit order on purpose
+        for unit in [
+            gen(3, 0.0, 100.0, 40.0),
+            gen(1, 0.0, 50.0, 10.0),
+            gen(2, 0.0, 100.0, 20.0),
+        ] {
+            network.graph.add_node(Node::Gen(unit));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load 1".to_string(),
+            bus: BusId::new(1),
+            active_power: Megawatts(80.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+
+        let solution = crate::opf::OpfSolver::new()
+            .with_method(OpfMethod::EconomicDispatch)
+            .with_dispatch_trace(true)
+            .solve(&network)
+            .unwrap();
+        let trace = solution.dispatch_trace.expect("trace requested");
+
+        // 80.8 MW with losses: Gen 1 full, Gen 2 takes the remaining 30.8 MW
+        let names: Vec<&str> = trace.iter().map(|e| e.generator.as_str()).collect();
+        assert_eq!(names, ["Gen 1", "Gen 2", "Gen 3"]);
+        assert_eq!(trace[0].reason, DispatchReason::AtMaximum);
+        assert_eq!(trace[1].reason, DispatchReason::Marginal);
+        assert!((trace[1].dispatch_mw - 30.8).abs() < 1e-9);
+        assert_eq!(trace[1].marginal_cost, 20.0);
+        assert_eq!(trace[2].reason, DispatchReason::NotNeeded);
+        assert_eq!(trace[2].merit_position, 3);
+        assert_eq!(trace[2].dispatch_mw, 0.0);
+        assert_eq!(
+            trace[2].to_string(),
+            "Gen 3: not needed (merit #3, 0.00 MW at $40.00/MWh)"
+        );
+    }
+}
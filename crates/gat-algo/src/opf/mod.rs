@@ -2,10 +2,18 @@
 //!
 //! This module provides OPF solvers with multiple solution methods:
 //! - Economic dispatch (merit-order, no network)
+//! - Copperplate dispatch (all buses as one node, single system price)
 //! - DC-OPF (linearized power flow)
+//! - Multi-period DC-OPF with storage co-optimization ([`solve_multiperiod_dc`])
 //! - SOCP relaxation (convex AC approximation)
 //! - AC-OPF (full nonlinear)
 //!
+//! [`benchmark`] times the methods against each other on one network, and
+//! [`solve_scenarios_dc`] runs a DC-OPF sweep over scenario specs into one table
+//! ([`stream_scenarios_dc`] streams it as JSON Lines instead).
+//! [`verify_ac_feasibility`] re-simulates any solution with AC power flow and
+//! lists the limits its true operating point violates.
+//!
 //! # Architecture
 //!
 //! The OPF system uses a Strategy Pattern with two levels of abstraction:
@@ -68,43 +76,96 @@
 //! )?;
 //! ```
 
+#[cfg(feature = "desktop")]
+mod ac_feasibility;
 pub mod ac_nlp;
 #[cfg(feature = "desktop")]
 pub mod admm;
+mod autoscale;
 pub mod backends;
+#[cfg(feature = "desktop")]
+mod benchmark;
+mod carbon;
+mod compare;
+mod congestion;
+mod copperplate;
 mod dc_opf;
 pub mod dispatch;
 mod dispatcher;
+mod duals;
 pub mod export;
+mod external_tie;
+mod flow_sign;
 pub mod formulations;
 pub mod gpu_branch_flow;
+mod infeasibility;
+mod load_shedding;
 mod merit_order;
+mod multiperiod_dc;
 #[cfg(feature = "native-dispatch")]
 pub mod native_dispatch;
+mod preflight;
+pub(crate) mod reactive_estimate;
 pub mod registry;
+#[cfg(feature = "desktop")]
+mod scenario_sweep;
+#[cfg(feature = "desktop")]
+mod sensitivity_export;
 mod socp;
+mod socp_check;
 pub mod traits;
+mod transaction;
 mod types;
 
+#[cfg(feature = "desktop")]
+pub use ac_feasibility::{
+    verify_ac_feasibility, AcFeasibilityReport, AcViolation, SLACK_MISMATCH_MW,
+};
 #[cfg(feature = "desktop")]
 pub use admm::{AdmmConfig, AdmmError, AdmmOpfSolver, AdmmPhaseTimes, AdmmSolution};
+pub use autoscale::{
+    AUTOSCALE_MAX_MARGINAL_COST, AUTOSCALE_MIN_MARGINAL_COST, REFERENCE_MARGINAL_COST,
+};
+#[cfg(feature = "desktop")]
+pub use benchmark::{benchmark, BenchmarkResult, MethodTiming};
+pub use compare::{compare_solutions, FieldDiff, SolutionComparison};
+pub use congestion::congestion_attribution;
 pub use dispatch::{DispatchConfig, ProblemClass, SolverBackend, SolverDispatcher};
 pub use dispatcher::OpfDispatcher;
+pub use external_tie::{ExternalTie, TiePriceBand};
+pub use flow_sign::{normalize_flow_signs, BranchFlows, FlowDirection};
+pub use infeasibility::{ConstraintGroup, GroupRelaxation, InfeasibilityReport};
+pub use load_shedding::LoadShedding;
+pub use multiperiod_dc::{
+    solve_multiperiod_dc, MultiPeriodDcSolution, StorageDispatch, StorageUnit,
+};
+pub use preflight::{base_case_preflight, PreflightViolation};
 pub use registry::SolverRegistry;
+#[cfg(feature = "desktop")]
+pub use scenario_sweep::{solve_scenarios_dc, stream_scenarios_dc};
+#[cfg(feature = "desktop")]
+pub use sensitivity_export::{write_lodf_parquet, write_ptdf_parquet};
+pub use socp_check::{validate_socp_against_ac, SocpValidationReport, TIGHT_GAP_TOLERANCE};
 pub use traits::{OpfBackend, OpfFormulation, OpfProblem, SolverConfig, WarmStartKind};
+pub use transaction::{transaction_flow_impact, transaction_overloads, TransactionOverload};
 pub use types::{
-    CascadedResult, ConstraintInfo, ConstraintType, DcWarmStart, OpfMethod, OpfSolution,
-    SocpWarmStart,
+    CascadedResult, ConstraintId, ConstraintInfo, ConstraintType, DcWarmStart, DispatchExplanation,
+    DispatchReason, LmpSensitivity, OpfMethod, OpfSolution, OpfTolerances, QBind, ReactiveEstimate,
+    SocpWarmStart, SolverWarning, NEAR_VOLTAGE_LIMIT_PU, Q_BINDING_TOLERANCE_MVAR,
 };
 
 use crate::OpfError;
-use gat_core::Network;
+use gat_core::{AmbientConditions, BranchId, Network};
+use std::collections::HashMap;
 
 /// Unified OPF solver supporting multiple solution methods
 pub struct OpfSolver {
     method: OpfMethod,
     max_iterations: usize,
     tolerance: f64,
+    /// Per-component tolerances; `None` leaves backends on `tolerance` and
+    /// their own defaults.
+    tolerances: Option<OpfTolerances>,
     timeout_seconds: u64,
     /// If true, fail when native solver requested but not available.
     /// If false (default), silently fall back to pure-Rust solver.
@@ -113,6 +174,30 @@ pub struct OpfSolver {
     prefer_native: bool,
     /// If true, use enhanced SOCP with OBBT bound tightening and QC envelopes.
     use_enhanced_socp: bool,
+    /// If true, compute dLMP/dP sensitivities after a DC-OPF solve.
+    lmp_sensitivities: bool,
+    /// If true, estimate reactive flows after a DC-OPF solve.
+    reactive_estimate: bool,
+    /// If true, assign reactive output after an economic dispatch.
+    reactive_dispatch: bool,
+    /// If true, explain each unit's economic dispatch in the solution.
+    dispatch_trace: bool,
+    /// Carbon price ($/tCO2) added to the objective, if any.
+    carbon_price: Option<f64>,
+    /// Run the base-case preflight; `None` uses the method default.
+    preflight: Option<bool>,
+    /// Interchange schedules with neighbouring systems.
+    external_ties: Vec<ExternalTie>,
+    /// VOLL policy letting DC-OPF shed load, if any.
+    load_shedding: Option<LoadShedding>,
+    /// Ambient conditions per branch name for dynamic line ratings.
+    ambient_conditions: HashMap<String, AmbientConditions>,
+    /// Rating multipliers per branch ID, applied after dynamic ratings.
+    deratings: HashMap<BranchId, f64>,
+    /// If true, scale the objective from cost statistics before solving.
+    autoscale: bool,
+    /// If true, solve SOCP (or DC) when the requested backend is unavailable.
+    solver_fallback: bool,
 }
 
 impl OpfSolver {
@@ -122,10 +207,23 @@ impl OpfSolver {
             method: OpfMethod::default(),
             max_iterations: 100,
             tolerance: 1e-6,
+            tolerances: None,
             timeout_seconds: 300, // 5 minutes default
             require_native: false,
             prefer_native: false,
             use_enhanced_socp: false,
+            lmp_sensitivities: false,
+            reactive_estimate: false,
+            reactive_dispatch: false,
+            dispatch_trace: false,
+            carbon_price: None,
+            preflight: None,
+            external_ties: Vec::new(),
+            load_shedding: None,
+            ambient_conditions: HashMap::new(),
+            deratings: HashMap::new(),
+            autoscale: false,
+            solver_fallback: false,
         }
     }
 
@@ -142,11 +240,32 @@ impl OpfSolver {
     }
 
     /// Set convergence tolerance
+    ///
+    /// Used as the overall tolerance of every backend; component tolerances
+    /// not covered by it (IPOPT's `constr_viol_tol`, Clarabel's defaults for
+    /// the default SOCP) stay at the backend defaults. Replaces any earlier
+    /// [`with_tolerances`](Self::with_tolerances).
     pub fn with_tolerance(mut self, tol: f64) -> Self {
         self.tolerance = tol;
+        self.tolerances = None;
+        self
+    }
+
+    /// Set primal, dual, feasibility and gap tolerances independently.
+    ///
+    /// See [`OpfTolerances`] for how each maps to the backend options.
+    pub fn with_tolerances(mut self, tolerances: OpfTolerances) -> Self {
+        self.tolerance = tolerances.primal;
+        self.tolerances = Some(tolerances);
         self
     }
 
+    /// Effective per-component tolerances
+    pub fn tolerances(&self) -> OpfTolerances {
+        self.tolerances
+            .unwrap_or_else(|| OpfTolerances::uniform(self.tolerance))
+    }
+
     /// Set solver timeout in seconds
     pub fn with_timeout(mut self, seconds: u64) -> Self {
         self.timeout_seconds = seconds;
@@ -193,6 +312,157 @@ impl OpfSolver {
         self
     }
 
+    /// Compute LMP sensitivities to load (dLMP/dP) after solving.
+    ///
+    /// When enabled for `DcOpf`, the solution's `lmp_sensitivity` field holds
+    /// the bus-by-bus matrix derived from the active constraint set, with
+    /// buses at a breakpoint flagged as undefined.
+    ///
+    /// Has no effect on other methods.
+    pub fn with_lmp_sensitivities(mut self, enabled: bool) -> Self {
+        self.lmp_sensitivities = enabled;
+        self
+    }
+
+    /// Estimate reactive power flows after solving.
+    ///
+    /// When enabled for `DcOpf`, a one-shot fast-decoupled step from the DC
+    /// angles fills `branch_q_flow`, `generator_q` and the solution's
+    /// `reactive_estimate` (bus Q injections and voltage magnitudes). Values
+    /// are approximate.
+    ///
+    /// Has no effect on other methods.
+    pub fn with_reactive_estimate(mut self, enabled: bool) -> Self {
+        self.reactive_estimate = enabled;
+        self
+    }
+
+    /// Assign reactive output to generators after economic dispatch.
+    ///
+    /// When enabled for `EconomicDispatch`, each bus's reactive load (net of
+    /// shunts) is served by the electrically nearest generators within their
+    /// Q limits. `generator_q` holds the assignment and
+    /// `reactive_shortfall_mvar` the demand left uncovered. This is a
+    /// screening approximation with no voltage or flow model.
+    ///
+    /// Has no effect on other methods.
+    pub fn with_reactive_dispatch(mut self, enabled: bool) -> Self {
+        self.reactive_dispatch = enabled;
+        self
+    }
+
+    /// Explain the economic dispatch of every generator.
+    ///
+    /// When enabled for `EconomicDispatch`, `dispatch_trace` lists each unit
+    /// in merit order with its marginal cost at the dispatched output and
+    /// what holds it there: a limit, being the marginal unit, or not being
+    /// needed. Printing an entry answers "why is this unit off?" in one line.
+    ///
+    /// Has no effect on other methods.
+    pub fn with_dispatch_trace(mut self, enabled: bool) -> Self {
+        self.dispatch_trace = enabled;
+        self
+    }
+
+    /// Price CO2 emissions at `price` $/tCO2 in the objective.
+    ///
+    /// Each unit's marginal cost rises by `price * emissions_rate`, so
+    /// dispatch shifts toward cleaner units and `objective_value` includes
+    /// the emissions cost. Units without an `emissions_rate` are unaffected.
+    /// `total_emissions_t` is reported whether or not a price is set.
+    pub fn with_carbon_price(mut self, price: f64) -> Self {
+        self.carbon_price = Some(price);
+        self
+    }
+
+    /// Run or skip the base-case preflight.
+    ///
+    /// The preflight ([`base_case_preflight`]) balances load against
+    /// generation in each island before solving, and fails fast with
+    /// `OpfError::Infeasible` naming the shortfall instead of a generic solver
+    /// error. It is on by default for `AcOpf` and `SocpRelaxation`, whose
+    /// infeasible solves are slow, and off for the other methods.
+    pub fn with_preflight(mut self, enabled: bool) -> Self {
+        self.preflight = Some(enabled);
+        self
+    }
+
+    /// Add an interchange schedule with a neighbouring system.
+    ///
+    /// Scheduled ties are fixed injections at their boundary bus;
+    /// price-responsive ties may move within their band, with the deviation
+    /// priced into the objective. The realized import over each tie is
+    /// reported in the solution's `tie_flows`.
+    pub fn with_external_tie(mut self, tie: ExternalTie) -> Self {
+        self.external_ties.push(tie);
+        self
+    }
+
+    /// Let DC-OPF shed load when generation falls short.
+    ///
+    /// Each load may be shed down to zero at its VOLL from `shedding`, so the
+    /// lowest-VOLL loads go first and critical loads only as a last resort.
+    /// The shed amount per load is reported in `load_shed_mw`, shedding cost
+    /// is included in `objective_value`, and a partly shed load sets the LMP.
+    ///
+    /// Has no effect on other methods.
+    pub fn with_load_shedding(mut self, shedding: LoadShedding) -> Self {
+        self.load_shedding = Some(shedding);
+        self
+    }
+
+    /// Use dynamic line ratings for the branches named in `conditions`.
+    ///
+    /// Each named branch's thermal limits are rescaled for its ambient
+    /// temperature and wind (see [`Branch::dynamic_rating`]) before solving;
+    /// other branches keep their static ratings.
+    ///
+    /// [`Branch::dynamic_rating`]: gat_core::Branch::dynamic_rating
+    pub fn with_dynamic_ratings(mut self, conditions: HashMap<String, AmbientConditions>) -> Self {
+        self.ambient_conditions = conditions;
+        self
+    }
+
+    /// Derate the branches in `factors` for this solve.
+    ///
+    /// Each factor multiplies the branch's `s_max` and Rate A/B/C (0.5 for
+    /// one of two circuits out); the network passed to [`OpfSolver::solve`]
+    /// is left untouched. The ratings used are reported in
+    /// [`OpfSolution::branch_ratings`].
+    pub fn with_deratings(mut self, factors: HashMap<BranchId, f64>) -> Self {
+        self.deratings = factors;
+        self
+    }
+
+    /// Scale the objective when cost data would leave the solver ill-conditioned.
+    ///
+    /// When enabled, a unit marginal cost outside the range
+    /// [`AUTOSCALE_MIN_MARGINAL_COST`]..[`AUTOSCALE_MAX_MARGINAL_COST`] $/MWh
+    /// has the objective multiplied so that the largest becomes
+    /// [`REFERENCE_MARGINAL_COST`]: as Clarabel pre-scaling for `DcOpf` and
+    /// `SocpRelaxation`, and as IPOPT user scaling for `AcOpf`. The factor used
+    /// is reported in [`OpfSolution::objective_scale`]; dispatch, objective and
+    /// prices are unaffected.
+    ///
+    /// Has no effect on other methods, the enhanced SOCP, native CLP or the
+    /// L-BFGS fallback.
+    pub fn with_autoscale(mut self, enabled: bool) -> Self {
+        self.autoscale = enabled;
+        self
+    }
+
+    /// Fall back to a relaxation when the requested backend is unavailable.
+    ///
+    /// Normally [`solve`](Self::solve) returns
+    /// [`OpfError::SolverUnavailable`] when, for example, `AcOpf` requires
+    /// native IPOPT in a build without it. With fallbacks allowed it solves
+    /// the SOCP relaxation instead, or DC-OPF if that fails, and records a
+    /// [`SolverWarning::SolverFallback`] in the solution.
+    pub fn with_solver_fallback(mut self, allow: bool) -> Self {
+        self.solver_fallback = allow;
+        self
+    }
+
     /// Get the configured method
     pub fn method(&self) -> OpfMethod {
         self.method
@@ -203,34 +473,174 @@ impl OpfSolver {
         self.require_native
     }
 
+    /// Check that the backend for the configured method is compiled in and
+    /// installed, without touching any network data.
+    ///
+    /// Only `AcOpf` with [`require_native`](Self::require_native) depends on
+    /// an optional backend; every other configuration has a pure-Rust solver.
+    pub fn check_solver_available(&self) -> Result<(), OpfError> {
+        match self.unavailable_reason() {
+            Some(reason) => Err(OpfError::SolverUnavailable {
+                method: self.method,
+                reason,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn unavailable_reason(&self) -> Option<String> {
+        if self.method == OpfMethod::AcOpf && self.require_native {
+            native_ipopt_unavailable()
+        } else {
+            None
+        }
+    }
+
     /// Solve OPF for the given network
+    ///
+    /// Fails early with [`OpfError::SolverUnavailable`] if the configured
+    /// backend is missing from this build (see
+    /// [`with_solver_fallback`](Self::with_solver_fallback)). Branch flows in
+    /// the result follow the from→to sign convention (see
+    /// [`normalize_flow_signs`]) whichever method produced them.
     pub fn solve(&self, network: &Network) -> Result<OpfSolution, OpfError> {
-        match self.method {
+        let Some(reason) = self.unavailable_reason() else {
+            return self.solve_as(network, self.method);
+        };
+        if !self.solver_fallback {
+            return Err(OpfError::SolverUnavailable {
+                method: self.method,
+                reason,
+            });
+        }
+
+        // An iteration-capped SOCP iterate is no substitute; fall through to DC
+        let (used, mut solution) = match self.solve_as(network, OpfMethod::SocpRelaxation) {
+            Ok(solution) if solution.converged => (OpfMethod::SocpRelaxation, solution),
+            _ => (OpfMethod::DcOpf, self.solve_as(network, OpfMethod::DcOpf)?),
+        };
+        solution.warnings.push(SolverWarning::SolverFallback {
+            requested: self.method,
+            used,
+            reason,
+        });
+        Ok(solution)
+    }
+
+    fn solve_as(&self, network: &Network, method: OpfMethod) -> Result<OpfSolution, OpfError> {
+        let rerated;
+        let network = if self.ambient_conditions.is_empty() && self.deratings.is_empty() {
+            network
+        } else {
+            let mut network = network.clone();
+            network.apply_dynamic_ratings(&self.ambient_conditions);
+            network
+                .apply_deratings(&self.deratings)
+                .map_err(|e| OpfError::DataValidation(e.to_string()))?;
+            rerated = network;
+            &rerated
+        };
+        let tied;
+        let network = if self.external_ties.is_empty() {
+            network
+        } else {
+            tied = external_tie::attach_ties(network, &self.external_ties);
+            &tied
+        };
+
+        let preflight = self.preflight.unwrap_or(matches!(
+            method,
+            OpfMethod::AcOpf | OpfMethod::SocpRelaxation
+        ));
+        if preflight {
+            base_case_preflight(network).map_err(|violation| {
+                OpfError::Infeasible(format!("base-case preflight: {}", violation))
+            })?;
+        }
+
+        let mut solution = match self.carbon_price {
+            Some(price) => self.solve_method(&carbon::price_emissions(network, price), method)?,
+            None => self.solve_method(network, method)?,
+        };
+        normalize_flow_signs(&mut solution, network);
+        solution.record_emissions(network);
+        solution.record_dispatch_modes(network);
+        solution.record_branch_ratings(network);
+        if solution.method_used.reactive_is_exact() {
+            solution.record_q_binding(network);
+            solution.record_voltage_warnings(network);
+        }
+        solution.fill_missing_generator_q();
+        external_tie::extract_tie_flows(&mut solution, &self.external_ties);
+        Ok(solution)
+    }
+
+    /// Find the constraint groups that make `network` infeasible for this solver.
+    ///
+    /// Re-solves with voltage bounds, thermal limits and reactive limits
+    /// relaxed, alone and together, and ranks the groups by how likely they
+    /// are to be the cause. Intended for use after a solve fails to converge.
+    pub fn diagnose_infeasibility(
+        &self,
+        network: &Network,
+    ) -> Result<InfeasibilityReport, OpfError> {
+        infeasibility::diagnose(self, network)
+    }
+
+    fn solve_method(&self, network: &Network, method: OpfMethod) -> Result<OpfSolution, OpfError> {
+        let objective_scale = if self.autoscale {
+            autoscale::objective_scale(network)
+        } else {
+            None
+        };
+        match method {
             OpfMethod::EconomicDispatch => {
-                merit_order::solve(network, self.max_iterations, self.tolerance)
+                let mut solution =
+                    merit_order::solve(network, self.max_iterations, self.tolerance)?;
+                if self.reactive_dispatch {
+                    merit_order::attach_reactive_dispatch(network, &mut solution);
+                }
+                if self.dispatch_trace {
+                    merit_order::attach_dispatch_trace(network, &mut solution);
+                }
+                Ok(solution)
             }
+            OpfMethod::Copperplate => copperplate::solve(network),
             OpfMethod::DcOpf => {
-                // Try native CLP if preferred and available
+                // Try native CLP if preferred and available; it cannot shed load
                 #[cfg(feature = "native-dispatch")]
-                if self.prefer_native && native_dispatch::is_clp_available() {
-                    return native_dispatch::solve_dc_opf_native(network, self.timeout_seconds);
+                if self.prefer_native
+                    && self.load_shedding.is_none()
+                    && native_dispatch::is_clp_available()
+                {
+                    let solution =
+                        native_dispatch::solve_dc_opf_native(network, self.timeout_seconds)?;
+                    return self.post_process_dc(network, solution);
                 }
 
                 // Fall back to pure-Rust Clarabel solver
-                dc_opf::solve(network, self.max_iterations, self.tolerance)
+                let solution = dc_opf::solve_with_shedding(
+                    network,
+                    self.max_iterations,
+                    self.tolerance,
+                    self.load_shedding.as_ref(),
+                    objective_scale,
+                )?;
+                self.post_process_dc(network, solution)
             }
             OpfMethod::SocpRelaxation => {
                 if self.use_enhanced_socp {
+                    let tolerances = self.tolerances();
                     let config = socp::SocpSolverConfig {
                         max_iter: self.max_iterations as u32,
-                        tol_feas: self.tolerance,
-                        tol_gap: self.tolerance,
+                        tol_feas: tolerances.clarabel_feas(),
+                        tol_gap: tolerances.gap,
                         equilibrate: true,
                         verbose: false,
                     };
                     socp::solve_enhanced(network, &config, true, true)
                 } else {
-                    socp::solve(network, self.max_iterations, self.tolerance)
+                    socp::solve_with_tolerances(network, self.tolerances.as_ref(), objective_scale)
                 }
             }
             OpfMethod::AcOpf => {
@@ -254,10 +664,12 @@ impl OpfSolver {
                     // don't enable IPOPT's barrier warm-start options.
 
                     // First attempt: flat start
-                    let flat_result = ac_nlp::solve_with_ipopt(
+                    let flat_result = ac_nlp::solve_ipopt(
                         &problem,
                         Some(self.max_iterations),
-                        Some(self.tolerance),
+                        self.tolerance,
+                        self.tolerances.as_ref(),
+                        objective_scale,
                     );
 
                     match flat_result {
@@ -308,6 +720,10 @@ impl OpfSolver {
                             // Still worth trying as it may work for some cases.
                             let socp_solution =
                                 socp::solve(network, self.max_iterations, self.tolerance)?;
+                            if !socp_solution.converged {
+                                // A capped relaxation iterate is no better than flat
+                                return flat_result;
+                            }
 
                             let socp_warm: SocpWarmStart = (&socp_solution).into();
 
@@ -338,37 +754,34 @@ impl OpfSolver {
                     return native_dispatch::solve_ac_opf_native(network, self.timeout_seconds);
                 }
 
-                // Check if native IPOPT is required but not available
+                // Native IPOPT is required; `solve` has already checked it is installed
+                #[cfg(all(feature = "native-dispatch", not(feature = "solver-ipopt")))]
                 if self.require_native {
-                    #[cfg(not(any(feature = "solver-ipopt", feature = "native-dispatch")))]
-                    {
-                        return Err(OpfError::NotImplemented(
-                            "Native IPOPT requested but neither 'solver-ipopt' nor 'native-dispatch' \
-                             feature is enabled. Build with: --features solver-ipopt"
-                                .to_string(),
-                        ));
-                    }
-
-                    #[cfg(all(feature = "native-dispatch", not(feature = "solver-ipopt")))]
-                    {
-                        if !native_dispatch::is_ipopt_available() {
-                            return Err(OpfError::NotImplemented(
-                                "Native IPOPT requested but not installed. \
-                                 Build with: cargo build -p gat-ipopt --features ipopt-sys --release"
-                                    .to_string(),
-                            ));
-                        }
-                        // IPOPT is available, dispatch to it
-                        return native_dispatch::solve_ac_opf_native(network, self.timeout_seconds);
-                    }
+                    return native_dispatch::solve_ac_opf_native(network, self.timeout_seconds);
                 }
 
                 // Fall back to pure-Rust L-BFGS solver
                 let problem = ac_nlp::AcOpfProblem::from_network(network)?;
-                ac_nlp::solve_ac_opf(&problem, self.max_iterations, self.tolerance)
+                ac_nlp::solve_ac_opf(&problem, self.max_iterations, self.tolerances().feasibility)
             }
         }
     }
+
+    /// Populate the opt-in `lmp_sensitivity` and `reactive_estimate` fields
+    /// on a DC-OPF solution.
+    fn post_process_dc(
+        &self,
+        network: &Network,
+        mut solution: OpfSolution,
+    ) -> Result<OpfSolution, OpfError> {
+        if self.lmp_sensitivities {
+            solution.lmp_sensitivity = Some(dc_opf::lmp_sensitivities(network, &solution)?);
+        }
+        if self.reactive_estimate {
+            reactive_estimate::attach(network, &mut solution)?;
+        }
+        Ok(solution)
+    }
 }
 
 impl Default for OpfSolver {
@@ -377,6 +790,30 @@ impl Default for OpfSolver {
     }
 }
 
+/// Why native IPOPT cannot be used in this build, or `None` if it can
+#[cfg(feature = "solver-ipopt")]
+fn native_ipopt_unavailable() -> Option<String> {
+    None
+}
+
+#[cfg(all(feature = "native-dispatch", not(feature = "solver-ipopt")))]
+fn native_ipopt_unavailable() -> Option<String> {
+    (!native_dispatch::is_ipopt_available()).then(|| {
+        "native IPOPT is not installed; build it with: \
+         cargo build -p gat-ipopt --features ipopt-sys --release"
+            .to_string()
+    })
+}
+
+#[cfg(not(any(feature = "solver-ipopt", feature = "native-dispatch")))]
+fn native_ipopt_unavailable() -> Option<String> {
+    Some(
+        "native IPOPT requires the 'solver-ipopt' or 'native-dispatch' feature; \
+         rebuild with: --features solver-ipopt"
+            .to_string(),
+    )
+}
+
 // ============================================================================
 // CASCADED SOLVER
 // ============================================================================
@@ -438,7 +875,10 @@ pub fn solve_cascaded(
 
     result.dc_solution = Some(dc_solution.clone());
 
-    if target == OpfMethod::DcOpf || target == OpfMethod::EconomicDispatch {
+    if matches!(
+        target,
+        OpfMethod::DcOpf | OpfMethod::EconomicDispatch | OpfMethod::Copperplate
+    ) {
         result.final_solution = dc_solution;
         result.total_time_ms = start.elapsed().as_millis();
         return Ok(result);
@@ -463,6 +903,20 @@ pub fn solve_cascaded(
         return Ok(result);
     }
 
+    // An iteration-capped SOCP iterate is neither a warm start nor a lower
+    // bound, so AC-OPF starts cold and the relaxation gap is not checked
+    if !socp_solution.converged {
+        let ac_solution = OpfSolver::new()
+            .with_method(OpfMethod::AcOpf)
+            .with_max_iterations(config.max_iterations)
+            .with_tolerance(config.tolerance)
+            .solve(network)?;
+        result.ac_solution = Some(ac_solution.clone());
+        result.final_solution = ac_solution;
+        result.total_time_ms = start.elapsed().as_millis();
+        return Ok(result);
+    }
+
     // Stage 3: AC-OPF with SOCP warm-start
     let socp_warm: SocpWarmStart = (&socp_solution).into();
 
@@ -503,6 +957,14 @@ pub fn solve_cascaded(
         )?
     };
 
+    let mut ac_solution = ac_solution;
+    let gap_rel = socp_check::objective_gap_rel(&socp_solution, &ac_solution);
+    if gap_rel > TIGHT_GAP_TOLERANCE {
+        ac_solution
+            .warnings
+            .push(SolverWarning::RelaxationGapHigh { gap_rel });
+    }
+
     result.ac_solution = Some(ac_solution.clone());
     result.final_solution = ac_solution;
     result.total_time_ms = start.elapsed().as_millis();
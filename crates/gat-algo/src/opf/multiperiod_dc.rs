@@ -0,0 +1,561 @@
+//! # Multi-Period DC-OPF with Storage Co-Optimization
+//!
+//! Solves all periods of a horizon as one linear program so that storage can
+//! move energy between them. Each period is a DC-OPF on the same network with
+//! its load scaled by [`PeriodData::load_scale`]; storage units couple the
+//! periods through their state of charge:
+//!
+//! ```text
+//! minimize    Σ_t Δt_t · Σ_g c1_g · P_g(t)
+//!
+//! subject to  Σ P_g(t) + Σ (D_s(t) − C_s(t)) − P_load(t) = Σ P_ij(t)   per bus
+//!             P_ij(t) = b_ij · (θ_i(t) − θ_j(t) − φ_ij)
+//!             |P_ij(t)| ≤ Rate_ij                                    if rated
+//!             θmin_ij ≤ θ_i(t) − θ_j(t) ≤ θmax_ij                    if limited
+//!             SoC_s(t) = SoC_s(t−1) + Δt_t · (η_s · C_s(t) − D_s(t))
+//!             SoC_min ≤ SoC_s(t) ≤ SoC_max,   SoC_s(T) ≥ SoC_s(0)
+//!             0 ≤ C_s(t) ≤ −p_min,   0 ≤ D_s(t) ≤ p_max
+//! ```
+//!
+//! Storage charges when energy is cheap and discharges when it is expensive
+//! only if the saving survives the charging efficiency η and the network
+//! limits between the storage bus and the generators. Requiring the final SoC
+//! to reach the initial one keeps the optimizer from paying for the horizon
+//! by draining the energy the unit started with.
+//!
+//! Storage units use the DER asset schema's conventions: `p_min < 0` is the
+//! charging limit, `p_max > 0` the discharging limit, and SoC is in MWh.
+
+use super::ac_nlp::PeriodData;
+use super::dc_opf::{
+    binding_branch_limits, branch_limit_rows, extract_network_data, reference_buses,
+};
+use super::duals::{DcDualRows, DcPrices};
+use super::flow_sign::normalize_flow_signs;
+use crate::opf::{ConstraintId, OpfMethod, OpfSolution};
+use crate::OpfError;
+use gat_core::{BusId, Network};
+use good_lp::solvers::clarabel::clarabel;
+use good_lp::{
+    constraint, variable, variables, DualValues, Expression, Solution, SolutionWithDual,
+    SolverModel, Variable,
+};
+use std::collections::HashMap;
+use web_time::Instant;
+
+/// Energy storage unit taking part in a multi-period DC-OPF.
+#[derive(Debug, Clone)]
+pub struct StorageUnit {
+    /// Asset identifier (the DER schema's `asset_id`)
+    pub id: String,
+    /// Bus the unit is connected to
+    pub bus: BusId,
+    /// Most negative output (MW); `-p_min` is the largest charging rate
+    pub p_min: f64,
+    /// Largest discharging rate (MW)
+    pub p_max: f64,
+    /// Minimum state of charge (MWh)
+    pub soc_min: f64,
+    /// Maximum state of charge (MWh)
+    pub soc_max: f64,
+    /// State of charge at the start of the horizon (MWh)
+    pub soc_init: f64,
+    /// Fraction of charging energy that reaches the store
+    pub efficiency: f64,
+}
+
+impl StorageUnit {
+    /// Create a lossless storage unit.
+    pub fn new(
+        id: impl Into<String>,
+        bus: BusId,
+        p_min: f64,
+        p_max: f64,
+        soc_min: f64,
+        soc_max: f64,
+        soc_init: f64,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            bus,
+            p_min,
+            p_max,
+            soc_min,
+            soc_max,
+            soc_init,
+            efficiency: 1.0,
+        }
+    }
+
+    /// Set the round-trip efficiency, applied when charging.
+    pub fn with_efficiency(mut self, efficiency: f64) -> Self {
+        self.efficiency = efficiency;
+        self
+    }
+}
+
+/// Schedule of one storage unit over the horizon.
+#[derive(Debug, Clone)]
+pub struct StorageDispatch {
+    pub id: String,
+    /// Net output per period (MW); positive discharges, negative charges
+    pub p_mw: Vec<f64>,
+    /// State of charge at the end of each period (MWh)
+    pub soc_mwh: Vec<f64>,
+}
+
+impl StorageDispatch {
+    /// State of charge at the end of the horizon (MWh).
+    pub fn final_soc_mwh(&self) -> f64 {
+        self.soc_mwh.last().copied().unwrap_or(0.0)
+    }
+}
+
+/// Result of [`solve_multiperiod_dc`].
+#[derive(Debug, Clone)]
+pub struct MultiPeriodDcSolution {
+    /// One DC-OPF solution per period; `objective_value` is the hourly cost
+    pub periods: Vec<OpfSolution>,
+    /// Storage schedules in input order
+    pub storage: Vec<StorageDispatch>,
+    /// Generation cost over the horizon ($), weighted by period duration
+    pub total_cost: f64,
+    pub solve_time_ms: u128,
+}
+
+/// Solve a DC-OPF over `periods` with `storage` co-optimized across them.
+///
+/// Branch flows are limited to `s_max` (or Rate A) where set. Each period's
+/// LMPs and `constraint_duals` (branch ratings, angle-difference and
+/// generator limits) are the LP row duals divided by the period duration, so
+/// prices separate across congested branches. Pass no storage to get the
+/// coupled baseline.
+pub fn solve_multiperiod_dc(
+    network: &Network,
+    periods: &[PeriodData],
+    storage: &[StorageUnit],
+) -> Result<MultiPeriodDcSolution, OpfError> {
+    let start = Instant::now();
+    if periods.is_empty() {
+        return Err(OpfError::DataValidation("No periods to solve".into()));
+    }
+
+    let (buses, generators, branches, loads) = extract_network_data(network)?;
+    let bus_map: HashMap<BusId, usize> = buses.iter().map(|b| (b.id, b.index)).collect();
+    for unit in storage {
+        validate_storage(unit, &bus_map)?;
+    }
+
+    let mut vars = variables!();
+    let mut cost_expr = Expression::from(0.0);
+    let ref_buses = reference_buses(network, &buses)?;
+
+    // Per-period generator and angle variables
+    let mut gen_vars: Vec<Vec<Variable>> = Vec::with_capacity(periods.len());
+    let mut theta_vars: Vec<HashMap<usize, Variable>> = Vec::with_capacity(periods.len());
+    for period in periods {
+        let mut p_t = Vec::with_capacity(generators.len());
+        for gen in &generators {
+            // Limits are added as rows below so their duals are reported
+            let p_var = vars.add(variable());
+            let c1 = gen.cost_coeffs.get(1).copied().unwrap_or(0.0);
+            cost_expr += (period.duration_hr * c1) * p_var;
+            p_t.push(p_var);
+        }
+        gen_vars.push(p_t);
+
+        let theta_t = buses
+            .iter()
+            .filter(|bus| !ref_buses.contains(&bus.index))
+            .map(|bus| (bus.index, vars.add(variable().min(-1e6).max(1e6))))
+            .collect();
+        theta_vars.push(theta_t);
+    }
+
+    // Storage charge, discharge and SoC variables, indexed [unit][period]
+    let mut charge_vars: Vec<Vec<Variable>> = Vec::with_capacity(storage.len());
+    let mut discharge_vars: Vec<Vec<Variable>> = Vec::with_capacity(storage.len());
+    let mut soc_vars: Vec<Vec<Variable>> = Vec::with_capacity(storage.len());
+    for unit in storage {
+        let mut c_s = Vec::with_capacity(periods.len());
+        let mut d_s = Vec::with_capacity(periods.len());
+        let mut soc_s = Vec::with_capacity(periods.len());
+        for _ in periods {
+            c_s.push(vars.add(variable().min(0.0).max(-unit.p_min)));
+            d_s.push(vars.add(variable().min(0.0).max(unit.p_max)));
+            soc_s.push(vars.add(variable().min(unit.soc_min).max(unit.soc_max)));
+        }
+        charge_vars.push(c_s);
+        discharge_vars.push(d_s);
+        soc_vars.push(soc_s);
+    }
+
+    let mut problem = vars.minimise(cost_expr).using(clarabel);
+
+    // Branch flow expression in period t: b * (θ_i − θ_j − φ)
+    let flow_expr = |t: usize, from: usize, to: usize, b: f64, shift: f64| -> Expression {
+        let theta = |idx: usize| -> Expression {
+            theta_vars[t]
+                .get(&idx)
+                .map(|v| Expression::from(*v))
+                .unwrap_or_else(|| Expression::from(0.0))
+        };
+        b * (theta(from) - theta(to)) - b * shift
+    };
+
+    let limit_rows = branch_limit_rows(&branches, &bus_map);
+    let mut dual_rows: Vec<DcDualRows<_>> = Vec::with_capacity(periods.len());
+    for (t, period) in periods.iter().enumerate() {
+        let mut rows = DcDualRows::new();
+        let mut injection: Vec<Expression> = buses
+            .iter()
+            .map(|bus| {
+                let load = loads.get(&bus.id).copied().unwrap_or(0.0);
+                Expression::from(-load * period.load_scale)
+            })
+            .collect();
+        for (gen, p_var) in generators.iter().zip(&gen_vars[t]) {
+            injection[bus_map[&gen.bus_id]] += *p_var;
+        }
+        for (s, unit) in storage.iter().enumerate() {
+            let idx = bus_map[&unit.bus];
+            injection[idx] += discharge_vars[s][t];
+            injection[idx] -= charge_vars[s][t];
+        }
+
+        for branch in &branches {
+            let i = *bus_map.get(&branch.from_bus).ok_or_else(|| {
+                OpfError::DataValidation(format!("Unknown bus {:?}", branch.from_bus))
+            })?;
+            let j = *bus_map.get(&branch.to_bus).ok_or_else(|| {
+                OpfError::DataValidation(format!("Unknown bus {:?}", branch.to_bus))
+            })?;
+            let flow = flow_expr(t, i, j, branch.susceptance, branch.phase_shift);
+            injection[i] -= flow.clone();
+            injection[j] += flow;
+        }
+
+        // The load enters each balance row with coefficient one
+        for (bus, net) in buses.iter().zip(injection) {
+            let row = problem.add_constraint(constraint!(net == 0.0));
+            rows.balance.push((bus.name.clone(), row, 1.0));
+        }
+
+        for (gen, &p_var) in generators.iter().zip(&gen_vars[t]) {
+            let pmin = gen.pmin.max(0.0);
+            let pmax = if gen.pmax.is_finite() { gen.pmax } else { 1e6 };
+            let upper = problem.add_constraint(constraint!(p_var <= pmax));
+            let lower = problem.add_constraint(constraint!(p_var >= pmin));
+            rows.limits
+                .push((ConstraintId::GeneratorPMax(gen.name.clone()), upper, 1.0));
+            rows.limits
+                .push((ConstraintId::GeneratorPMin(gen.name.clone()), lower, 1.0));
+        }
+
+        let theta = |idx: usize| {
+            theta_vars[t]
+                .get(&idx)
+                .map_or_else(|| Expression::from(0.0), |v| Expression::from(*v))
+        };
+        for limit in &limit_rows {
+            let row = problem.add_constraint(limit.constraint(&theta));
+            rows.limits
+                .push((limit.id.clone(), row, limit.dual_scale()));
+        }
+        dual_rows.push(rows);
+    }
+
+    // State-of-charge dynamics and end-of-horizon requirement
+    for (s, unit) in storage.iter().enumerate() {
+        let mut previous = Expression::from(unit.soc_init);
+        for (t, period) in periods.iter().enumerate() {
+            let dt = period.duration_hr;
+            problem = problem.with(constraint!(
+                soc_vars[s][t]
+                    == previous + (dt * unit.efficiency) * charge_vars[s][t]
+                        - dt * discharge_vars[s][t]
+            ));
+            previous = Expression::from(soc_vars[s][t]);
+        }
+        problem = problem.with(constraint!(previous >= unit.soc_init));
+    }
+
+    let mut solution = problem
+        .solve()
+        .map_err(|e| OpfError::NumericalIssue(format!("Multi-period LP failed: {:?}", e)))?;
+
+    // Each period's cost is weighted by its duration in the objective
+    let prices: Vec<DcPrices> = {
+        let duals = solution.compute_dual();
+        dual_rows
+            .iter()
+            .zip(periods)
+            .map(|(rows, period)| rows.prices(|row| duals.dual(row.clone()), period.duration_hr))
+            .collect()
+    };
+
+    // === Extract Results ===
+    let mut result = MultiPeriodDcSolution {
+        periods: Vec::with_capacity(periods.len()),
+        storage: Vec::with_capacity(storage.len()),
+        total_cost: 0.0,
+        solve_time_ms: 0,
+    };
+
+    for ((t, period), prices) in periods.iter().enumerate().zip(prices) {
+        let mut opf = OpfSolution {
+            converged: true,
+            method_used: OpfMethod::DcOpf,
+            iterations: 1,
+            ..Default::default()
+        };
+
+        let dispatch: Vec<f64> = gen_vars[t].iter().map(|v| solution.value(*v)).collect();
+        for (gen, &p) in generators.iter().zip(&dispatch) {
+            opf.generator_p.insert(gen.name.clone(), p);
+            let c0 = gen.cost_coeffs.first().copied().unwrap_or(0.0);
+            let c1 = gen.cost_coeffs.get(1).copied().unwrap_or(0.0);
+            let c2 = gen.cost_coeffs.get(2).copied().unwrap_or(0.0);
+            opf.objective_value += c0 + c1 * p + c2 * p * p;
+        }
+        result.total_cost += period.duration_hr * opf.objective_value;
+
+        let angle = |idx: usize| {
+            theta_vars[t]
+                .get(&idx)
+                .map(|v| solution.value(*v))
+                .unwrap_or(0.0)
+        };
+        for bus in &buses {
+            opf.bus_voltage_ang
+                .insert(bus.name.clone(), angle(bus.index));
+            opf.bus_voltage_mag.insert(bus.name.clone(), 1.0);
+            if ref_buses.contains(&bus.index) {
+                opf.slack_buses.push(bus.name.clone());
+            }
+        }
+        for branch in &branches {
+            let i = bus_map[&branch.from_bus];
+            let j = bus_map[&branch.to_bus];
+            let flow = branch.susceptance * ((angle(i) - angle(j)) - branch.phase_shift);
+            opf.branch_p_flow.insert(branch.name.clone(), flow);
+        }
+
+        opf.bus_lmp = prices.bus_lmp;
+        opf.binding_constraints =
+            binding_branch_limits(&branches, &opf.branch_p_flow, &prices.constraint_duals);
+        opf.constraint_duals = prices.constraint_duals;
+
+        normalize_flow_signs(&mut opf, network);
+        opf.record_emissions(network);
+        result.periods.push(opf);
+    }
+
+    for (s, unit) in storage.iter().enumerate() {
+        result.storage.push(StorageDispatch {
+            id: unit.id.clone(),
+            p_mw: (0..periods.len())
+                .map(|t| solution.value(discharge_vars[s][t]) - solution.value(charge_vars[s][t]))
+                .collect(),
+            soc_mwh: soc_vars[s].iter().map(|v| solution.value(*v)).collect(),
+        });
+    }
+
+    result.solve_time_ms = start.elapsed().as_millis();
+    Ok(result)
+}
+
+fn validate_storage(unit: &StorageUnit, bus_map: &HashMap<BusId, usize>) -> Result<(), OpfError> {
+    if !bus_map.contains_key(&unit.bus) {
+        return Err(OpfError::DataValidation(format!(
+            "Storage {} is connected to unknown bus {:?}",
+            unit.id, unit.bus
+        )));
+    }
+    if unit.p_min > 0.0 || unit.p_max < 0.0 {
+        return Err(OpfError::DataValidation(format!(
+            "Storage {} needs p_min <= 0 <= p_max (got {}, {})",
+            unit.id, unit.p_min, unit.p_max
+        )));
+    }
+    if !(unit.soc_min <= unit.soc_init && unit.soc_init <= unit.soc_max) {
+        return Err(OpfError::DataValidation(format!(
+            "Storage {} initial SoC {} is outside [{}, {}]",
+            unit.id, unit.soc_init, unit.soc_min, unit.soc_max
+        )));
+    }
+    if !(unit.efficiency > 0.0 && unit.efficiency <= 1.0) {
+        return Err(OpfError::DataValidation(format!(
+            "Storage {} efficiency {} must be in (0, 1]",
+            unit.id, unit.efficiency
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opf::{ConstraintId, ConstraintType};
+    use gat_core::{
+        Branch, BranchId, Bus, CostModel, Edge, Gen, GenId, Load, LoadId, Megavars,
+        MegavoltAmperes, Megawatts, Node, Radians,
+    };
+
+    /// Cheap 100 MW unit at bus 1, expensive unit at bus 2 with the 100 MW
+    /// base load. The load is 80 MW in hour 0 and 150 MW in hour 1, so the
+    /// cheap unit has 20 MW spare in the first hour and the expensive unit
+    /// sets the price in the second.
+    fn two_bus() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=2)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        network.graph.add_edge(
+            buses[0],
+            buses[1],
+            Edge::Branch(Branch {
+                id: BranchId::new(0),
+                name: "line1_2".to_string(),
+                from_bus: BusId::new(1),
+                to_bus: BusId::new(2),
+                reactance: 0.1,
+                rating_a: Some(MegavoltAmperes(200.0)),
+                ..Branch::default()
+            }),
+        );
+        for (id, bus, pmax, cost) in [(1, 1, 100.0, 10.0), (2, 2, 200.0, 50.0)] {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(id), format!("gen{}", id), BusId::new(bus))
+                    .with_p_limits(0.0, pmax)
+                    .with_cost(CostModel::linear(0.0, cost)),
+            ));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(100.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        network
+    }
+
+    fn periods() -> Vec<PeriodData> {
+        vec![PeriodData::hourly(0, 0.8), PeriodData::hourly(1, 1.5)]
+    }
+
+    #[test]
+    fn test_storage_arbitrage_lowers_cost() {
+        let network = two_bus();
+        let baseline = solve_multiperiod_dc(&network, &periods(), &[]).unwrap();
+        // Hour 0: 80 MW at $10; hour 1: 100 MW at $10 + 50 MW at $50
+        assert!((baseline.total_cost - 4300.0).abs() < 1e-2);
+        assert!((baseline.periods[0].bus_lmp["bus2"] - 10.0).abs() < 1e-4);
+        assert!((baseline.periods[1].bus_lmp["bus2"] - 50.0).abs() < 1e-4);
+
+        let battery = StorageUnit::new("bess", BusId::new(2), -20.0, 20.0, 0.0, 20.0, 0.0);
+        let with_storage = solve_multiperiod_dc(&network, &periods(), &[battery]).unwrap();
+
+        let bess = &with_storage.storage[0];
+        assert!(
+            (bess.p_mw[0] + 20.0).abs() < 1e-3,
+            "charges: {:?}",
+            bess.p_mw
+        );
+        assert!(
+            (bess.p_mw[1] - 20.0).abs() < 1e-3,
+            "discharges: {:?}",
+            bess.p_mw
+        );
+        assert!(bess.final_soc_mwh().abs() < 1e-3);
+
+        // 20 MWh moves from the $10 hour to displace $50 generation
+        assert!((with_storage.total_cost - 3500.0).abs() < 1e-2);
+        assert!(with_storage.total_cost < baseline.total_cost);
+        let line_flow = with_storage.periods[0].branch_p_flow["line1_2"];
+        assert!((line_flow - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_losses_can_make_arbitrage_unprofitable() {
+        let network = two_bus();
+        // Storing 1 MWh costs $10 / 0.15 ≈ $67 of generation, more than the $50 it displaces
+        let battery = StorageUnit::new("bess", BusId::new(2), -20.0, 20.0, 0.0, 20.0, 0.0)
+            .with_efficiency(0.15);
+        let result = solve_multiperiod_dc(&network, &periods(), &[battery]).unwrap();
+        assert!(result.storage[0].p_mw.iter().all(|p| p.abs() < 1e-3));
+        assert!((result.total_cost - 4300.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_congested_branch_shadow_price_equals_lmp_spread() {
+        // A 50 MW rating keeps the $10 unit from serving all 100 MW at bus 2
+        let mut network = two_bus();
+        for edge in network.graph.edge_weights_mut() {
+            if let Edge::Branch(branch) = edge {
+                branch.rating_a = Some(MegavoltAmperes(50.0));
+            }
+        }
+        let result = solve_multiperiod_dc(&network, &[PeriodData::hourly(0, 1.0)], &[]).unwrap();
+        let period = &result.periods[0];
+
+        assert!((period.branch_p_flow["line1_2"] - 50.0).abs() < 1e-3);
+        let mu = period.constraint_duals[&ConstraintId::BranchThermal("line1_2".to_string())];
+        assert!(mu > 0.0);
+        let spread = period.bus_lmp["bus2"] - period.bus_lmp["bus1"];
+        assert!((spread - 40.0).abs() < 1e-4, "LMP spread {}", spread);
+        assert!(
+            (mu - spread).abs() < 1e-4,
+            "shadow price {} vs spread {}",
+            mu,
+            spread
+        );
+
+        // Neither unit sits at a limit, so their limits carry no price
+        assert!(period.constraint_duals[&ConstraintId::GeneratorPMax("gen1".to_string())] < 1e-4);
+        assert!(period.constraint_duals[&ConstraintId::GeneratorPMin("gen2".to_string())] < 1e-4);
+    }
+
+    #[test]
+    fn test_angle_limit_binds_before_thermal_limit() {
+        // 0.003 rad across x = 0.1 p.u. carries 30 MW, well inside the 200 MW rating
+        let mut network = two_bus();
+        for edge in network.graph.edge_weights_mut() {
+            if let Edge::Branch(branch) = edge {
+                branch.angle_min = Some(Radians(-0.003));
+                branch.angle_max = Some(Radians(0.003));
+            }
+        }
+        let result = solve_multiperiod_dc(&network, &[PeriodData::hourly(0, 1.0)], &[]).unwrap();
+        let period = &result.periods[0];
+
+        assert!((period.branch_p_flow["line1_2"] - 30.0).abs() < 1e-3);
+        assert!(
+            period.constraint_duals[&ConstraintId::BranchThermal("line1_2".to_string())] < 1e-4
+        );
+        let spread = period.bus_lmp["bus2"] - period.bus_lmp["bus1"];
+        assert!((spread - 40.0).abs() < 1e-4, "LMP spread {}", spread);
+
+        // The angle limit is the only active constraint; relaxing it by one
+        // radian moves 1000 MW at the $40 spread
+        assert_eq!(period.binding_constraints.len(), 1);
+        let binding = &period.binding_constraints[0];
+        assert_eq!(binding.name, "line1_2");
+        assert_eq!(binding.constraint_type, ConstraintType::BranchAngleLimit);
+        assert_eq!(binding.limit, 0.003);
+        assert!((binding.value - 0.003).abs() < 1e-6);
+        assert!((binding.shadow_price - 40_000.0).abs() < 1e-2);
+        assert_eq!(
+            period.constraint_duals[&ConstraintId::AngleDifference("line1_2".to_string())],
+            binding.shadow_price
+        );
+    }
+}
@@ -85,8 +85,8 @@ pub fn network_to_problem(
 
             problem.gen_id.push(gen.id.value() as i64);
             problem.gen_bus_id.push(gen.bus.value() as i64);
-            problem.gen_p_min.push(gen.pmin.value());
-            problem.gen_p_max.push(gen.pmax.value());
+            problem.gen_p_min.push(gen.dispatch_pmin());
+            problem.gen_p_max.push(gen.dispatch_pmax());
             problem.gen_q_min.push(gen.qmin.value());
             problem.gen_q_max.push(gen.qmax.value());
 
@@ -235,6 +235,7 @@ pub fn solution_to_opf(
             }
         }
     }
+    result.record_renewable_curtailment(network);
 
     result
 }
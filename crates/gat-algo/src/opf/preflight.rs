@@ -0,0 +1,279 @@
+//! Base-case preflight for OPF.
+//!
+//! A case whose load cannot be served at all makes AC and SOCP solvers grind
+//! through their iteration limit and fail with a generic status. The preflight
+//! catches the common causes up front with a cheap active-power balance per
+//! island (buses joined by in-service branches and transformers):
+//!
+//! - load exceeding the island's generation capacity (`dispatch_pmax`, so
+//!   variable units count only what is available), and
+//! - minimum generation exceeding the island's load.
+//!
+//! Losses, flow limits and voltages are ignored, so passing the preflight does
+//! not guarantee a feasible OPF; failing it guarantees an infeasible one.
+
+use gat_core::{BusId, Edge, Network, Node};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// A base-case constraint no dispatch can satisfy.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PreflightViolation {
+    /// Load exceeds the generation capacity able to reach it
+    #[error("load of {load_mw:.1} MW{} exceeds generation capacity of {capacity_mw:.1} MW (shortfall {:.1} MW)", island_suffix(.island), .load_mw - .capacity_mw)]
+    GenerationShortfall {
+        /// Lowest-named bus of the island, when the network is split
+        island: Option<String>,
+        load_mw: f64,
+        capacity_mw: f64,
+    },
+    /// Generators cannot back down far enough to match load
+    #[error("minimum generation of {pmin_mw:.1} MW{} exceeds load of {load_mw:.1} MW (surplus {:.1} MW)", island_suffix(.island), .pmin_mw - .load_mw)]
+    MinimumGenerationExcess {
+        /// Lowest-named bus of the island, when the network is split
+        island: Option<String>,
+        load_mw: f64,
+        pmin_mw: f64,
+    },
+}
+
+impl PreflightViolation {
+    /// Power that cannot be balanced (MW).
+    pub fn imbalance_mw(&self) -> f64 {
+        match self {
+            PreflightViolation::GenerationShortfall {
+                load_mw,
+                capacity_mw,
+                ..
+            } => load_mw - capacity_mw,
+            PreflightViolation::MinimumGenerationExcess {
+                load_mw, pmin_mw, ..
+            } => pmin_mw - load_mw,
+        }
+    }
+}
+
+fn island_suffix(island: &Option<String>) -> String {
+    island
+        .as_ref()
+        .map(|bus| format!(" in the island containing bus {}", bus))
+        .unwrap_or_default()
+}
+
+/// Per-island totals accumulated by the preflight.
+#[derive(Default)]
+struct IslandBalance {
+    bus: Option<String>,
+    load_mw: f64,
+    capacity_mw: f64,
+    pmin_mw: f64,
+}
+
+/// Check that every island's load can be balanced by its generators.
+///
+/// Returns the first violation found, checking islands in order of their
+/// lowest-named bus.
+pub fn base_case_preflight(network: &Network) -> Result<(), PreflightViolation> {
+    let mut names: HashMap<BusId, &str> = HashMap::new();
+    let mut parent: HashMap<BusId, BusId> = HashMap::new();
+    for node in network.graph.node_weights() {
+        if let Node::Bus(bus) = node {
+            names.insert(bus.id, &bus.name);
+            parent.insert(bus.id, bus.id);
+        }
+    }
+    for edge in network.graph.edge_weights() {
+        let (from, to) = match edge {
+            Edge::Branch(branch) if branch.status => (branch.from_bus, branch.to_bus),
+            Edge::Transformer(tx) => (tx.from_bus, tx.to_bus),
+            _ => continue,
+        };
+        if !parent.contains_key(&from) || !parent.contains_key(&to) {
+            continue;
+        }
+        let (a, b) = (find(&mut parent, from), find(&mut parent, to));
+        if a != b {
+            parent.insert(a, b);
+        }
+    }
+
+    let mut islands: HashMap<BusId, IslandBalance> = HashMap::new();
+    let bus_ids: Vec<BusId> = parent.keys().copied().collect();
+    for bus in bus_ids {
+        let root = find(&mut parent, bus);
+        let island = islands.entry(root).or_default();
+        let name = names[&bus];
+        if island.bus.as_deref().map_or(true, |current| name < current) {
+            island.bus = Some(name.to_string());
+        }
+    }
+    for node in network.graph.node_weights() {
+        let (bus, load, capacity, pmin) = match node {
+            Node::Load(load) => (load.bus, load.active_power.value(), 0.0, 0.0),
+            Node::Gen(gen) if !gen.is_synchronous_condenser => {
+                (gen.bus, 0.0, gen.dispatch_pmax(), gen.dispatch_pmin())
+            }
+            _ => continue,
+        };
+        if !parent.contains_key(&bus) {
+            continue;
+        }
+        let island = islands
+            .get_mut(&find(&mut parent, bus))
+            .expect("every bus has an island");
+        island.load_mw += load;
+        island.capacity_mw += capacity;
+        island.pmin_mw += pmin;
+    }
+
+    let split = islands.len() > 1;
+    let mut islands: Vec<IslandBalance> = islands.into_values().collect();
+    islands.sort_by(|a, b| a.bus.cmp(&b.bus));
+    for island in islands {
+        let label = if split { island.bus.clone() } else { None };
+        if island.load_mw > island.capacity_mw + 1e-6 {
+            return Err(PreflightViolation::GenerationShortfall {
+                island: label,
+                load_mw: island.load_mw,
+                capacity_mw: island.capacity_mw,
+            });
+        }
+        if island.pmin_mw > island.load_mw + 1e-6 {
+            return Err(PreflightViolation::MinimumGenerationExcess {
+                island: label,
+                load_mw: island.load_mw,
+                pmin_mw: island.pmin_mw,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Union-find root of `bus`, compressing the path as it goes.
+fn find(parent: &mut HashMap<BusId, BusId>, bus: BusId) -> BusId {
+    let mut root = bus;
+    while parent[&root] != root {
+        root = parent[&root];
+    }
+    let mut node = bus;
+    while node != root {
+        let next = parent[&node];
+        parent.insert(node, root);
+        node = next;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opf::{OpfMethod, OpfSolver};
+    use crate::OpfError;
+    use gat_core::{
+        Branch, BranchId, Bus, CostModel, Gen, GenId, Kilovolts, Load, LoadId, Megavars, Megawatts,
+    };
+
+    /// 200 MW of generation at bus 1 facing `load_mw` at bus 2, plus a third
+    /// bus with its own 20 MW load that is only connected when `tie_bus3`.
+    fn deficient_network(load_mw: f64, tie_bus3: bool) -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: Kilovolts(230.0),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (id, from, to, status) in [(0, 1, 2, true), (1, 2, 3, tie_bus3)] {
+            let mut branch = Branch::new(
+                BranchId::new(id),
+                format!("line{}_{}", from, to),
+                BusId::new(from),
+                BusId::new(to),
+                0.01,
+                0.1,
+            );
+            branch.status = status;
+            network
+                .graph
+                .add_edge(buses[from - 1], buses[to - 1], Edge::Branch(branch));
+        }
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1))
+                .with_p_limits(0.0, 200.0)
+                .with_q_limits(-100.0, 100.0)
+                .with_cost(CostModel::linear(0.0, 10.0)),
+        ));
+        for (id, bus, p) in [(1, 2, load_mw), (2, 3, 20.0)] {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(id),
+                name: format!("load{}", bus),
+                bus: BusId::new(bus),
+                active_power: Megawatts(p),
+                reactive_power: Megavars(p * 0.2),
+                zip: None,
+            }));
+        }
+        network
+    }
+
+    #[test]
+    fn test_preflight_reports_generation_shortfall() {
+        let network = deficient_network(230.0, true);
+        let violation = base_case_preflight(&network).unwrap_err();
+        assert_eq!(
+            violation,
+            PreflightViolation::GenerationShortfall {
+                island: None,
+                load_mw: 250.0,
+                capacity_mw: 200.0,
+            }
+        );
+        assert!((violation.imbalance_mw() - 50.0).abs() < 1e-9);
+
+        // AC and SOCP stop at the preflight with the targeted diagnostic
+        for method in [OpfMethod::SocpRelaxation, OpfMethod::AcOpf] {
+            let err = OpfSolver::new()
+                .with_method(method)
+                .solve(&network)
+                .unwrap_err();
+            match err {
+                OpfError::Infeasible(message) => {
+                    assert!(message.contains("preflight"), "{}", message);
+                    assert!(message.contains("shortfall 50.0 MW"), "{}", message);
+                }
+                other => panic!("{:?} failed with {:?}", method, other),
+            }
+        }
+
+        // Skipping the preflight leaves the solver to fail on its own
+        let err = OpfSolver::new()
+            .with_method(OpfMethod::SocpRelaxation)
+            .with_preflight(false)
+            .solve(&network)
+            .unwrap_err();
+        assert!(!err.to_string().contains("preflight"), "{}", err);
+    }
+
+    #[test]
+    fn test_preflight_flags_island_without_generation() {
+        let network = deficient_network(100.0, false);
+        let violation = base_case_preflight(&network).unwrap_err();
+        assert_eq!(
+            violation,
+            PreflightViolation::GenerationShortfall {
+                island: Some("bus3".to_string()),
+                load_mw: 20.0,
+                capacity_mw: 0.0,
+            }
+        );
+        assert!(violation
+            .to_string()
+            .contains("in the island containing bus bus3"));
+
+        assert!(base_case_preflight(&deficient_network(100.0, true)).is_ok());
+    }
+}
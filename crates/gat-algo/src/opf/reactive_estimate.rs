@@ -0,0 +1,325 @@
+//! Decoupled reactive power estimate for DC-OPF solutions.
+//!
+//! DC-OPF has no reactive power or voltage magnitudes, so `branch_q_flow` is
+//! normally empty. This module fills it with a one-shot fast-decoupled
+//! estimate built from the dispatch:
+//!
+//! 1. **Angles**: solve `B'θ = P` with the DC-OPF generator outputs and loads
+//!    (the same lossless model the LP used).
+//! 2. **Voltages**: start PV buses at their setpoint and PQ buses flat at
+//!    1.0 p.u., then take a single Q-V step `B''ΔV = ΔQ/V` at PQ buses.
+//! 3. **Flows**: evaluate the π-model reactive flow on each branch from the
+//!    resulting complex voltages.
+//! 4. **Generators**: each bus's reactive injection plus its reactive load is
+//!    split equally among the in-service generators at the bus.
+//!
+//! The result is an approximation: there is no P-Q iteration, generator Q
+//! limits are ignored, and losses are not redispatched. It is meant for
+//! reporting (sign and rough magnitude of VAr flows), not for voltage
+//! security decisions. Solutions carrying an estimate have
+//! `OpfSolution::reactive_estimate` set.
+
+use super::{OpfSolution, ReactiveEstimate};
+use crate::OpfError;
+use faer::prelude::*;
+use faer::Mat;
+use gat_core::solver::build_ybus;
+use gat_core::{BusId, Edge, Network, Node};
+use num_complex::Complex64;
+use std::collections::HashMap;
+
+const BASE_MVA: f64 = 100.0;
+
+/// Reactance floor for near-zero-impedance branches (bus ties), matching DC-OPF
+const EPSILON_REACTANCE: f64 = 1e-6;
+
+struct EstimateBranch {
+    name: String,
+    from: usize,
+    to: usize,
+    /// Series admittance 1/(r + jx)
+    y_series: Complex64,
+    /// DC susceptance 1/(x·tap), as used by DC-OPF
+    b_dc: f64,
+    charging_b: f64,
+    tap: Complex64,
+    /// Zero-impedance branch, which the shared Y-bus leaves out
+    bus_tie: bool,
+}
+
+/// Populate `branch_q_flow`, `generator_q` and `reactive_estimate` on a DC-OPF solution.
+pub(crate) fn attach(network: &Network, solution: &mut OpfSolution) -> Result<(), OpfError> {
+    // Buses follow the shared Y-bus ordering (ascending ID)
+    let (y_shared, buses) = build_ybus(network);
+    let n = buses.len();
+    if n == 0 {
+        return Err(OpfError::DataValidation("No buses in network".into()));
+    }
+    let bus_index: HashMap<BusId, usize> =
+        buses.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+    let mut bus_names = vec![String::new(); n];
+    for node in network.graph.node_weights() {
+        if let Node::Bus(bus) = node {
+            bus_names[bus_index[&bus.id]] = bus.name.clone();
+        }
+    }
+
+    // Injections in per-unit and voltage setpoints at PV buses
+    let mut p_inj = vec![0.0; n];
+    let mut q_load = vec![0.0; n];
+    let mut v_set: Vec<Option<f64>> = vec![None; n];
+    let mut bus_gens: Vec<Vec<String>> = vec![Vec::new(); n];
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Gen(gen) if gen.status => {
+                let Some(&i) = bus_index.get(&gen.bus) else {
+                    continue;
+                };
+                let p = solution.generator_p.get(&gen.name).copied().unwrap_or(0.0);
+                p_inj[i] += p / BASE_MVA;
+                let setpoint = gen.voltage_setpoint.map(|v| v.value()).unwrap_or(1.0);
+                v_set[i].get_or_insert(setpoint);
+                bus_gens[i].push(gen.name.clone());
+            }
+            Node::Load(load) => {
+                if let Some(&i) = bus_index.get(&load.bus) {
+                    p_inj[i] -= load.active_power.value() / BASE_MVA;
+                    q_load[i] += load.reactive_power.value() / BASE_MVA;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut branches = Vec::new();
+    for edge in network.graph.edge_weights() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        if !branch.status {
+            continue;
+        }
+        let (Some(&from), Some(&to)) = (
+            bus_index.get(&branch.from_bus),
+            bus_index.get(&branch.to_bus),
+        ) else {
+            continue;
+        };
+        let tap_mag = if branch.tap_ratio > 0.0 {
+            branch.tap_ratio
+        } else {
+            1.0
+        };
+        let bus_tie = Complex64::new(branch.resistance, branch.reactance).norm_sqr() < 1e-12;
+        let x = if branch.reactance.abs() < 1e-12 {
+            EPSILON_REACTANCE
+        } else {
+            branch.reactance
+        };
+        branches.push(EstimateBranch {
+            name: branch.name.clone(),
+            from,
+            to,
+            y_series: Complex64::new(branch.resistance, x).inv(),
+            b_dc: 1.0 / (x * tap_mag),
+            charging_b: branch.charging_b.value(),
+            tap: Complex64::from_polar(tap_mag, branch.phase_shift.value()),
+            bus_tie,
+        });
+    }
+
+    let theta = dc_angles(n, &branches, &p_inj)?;
+
+    // Shared Y-bus, plus the bus ties it skips at the floored reactance
+    let mut y_bus = vec![vec![Complex64::new(0.0, 0.0); n]; n];
+    for (i, row) in y_shared.outer_iterator().enumerate() {
+        for (j, &y) in row.iter() {
+            y_bus[i][j] = y;
+        }
+    }
+    for br in branches.iter().filter(|br| br.bus_tie) {
+        let half_b = Complex64::new(0.0, br.charging_b / 2.0);
+        y_bus[br.from][br.from] += (br.y_series + half_b) / br.tap.norm_sqr();
+        y_bus[br.to][br.to] += br.y_series + half_b;
+        y_bus[br.from][br.to] -= br.y_series / br.tap.conj();
+        y_bus[br.to][br.from] -= br.y_series / br.tap;
+    }
+
+    // One Q-V step at PQ buses from the flat/setpoint start
+    let mut v_mag: Vec<f64> = v_set.iter().map(|v| v.unwrap_or(1.0)).collect();
+    let pq: Vec<usize> = (0..n).filter(|&i| v_set[i].is_none()).collect();
+    if !pq.is_empty() {
+        let voltages = phasors(&v_mag, &theta);
+        let q_calc = injections(&y_bus, &voltages);
+        let m = pq.len();
+        let b_pp = Mat::<f64>::from_fn(m, m, |r, c| -y_bus[pq[r]][pq[c]].im);
+        let rhs = Mat::<f64>::from_fn(m, 1, |r, _| {
+            let i = pq[r];
+            (-q_load[i] - q_calc[i].im) / v_mag[i]
+        });
+        let dv = b_pp.partial_piv_lu().solve(&rhs);
+        for (r, &i) in pq.iter().enumerate() {
+            let step = dv.read(r, 0);
+            if !step.is_finite() {
+                return Err(OpfError::NumericalIssue(
+                    "Singular B'' matrix in reactive estimate".into(),
+                ));
+            }
+            v_mag[i] += step;
+        }
+    }
+
+    let voltages = phasors(&v_mag, &theta);
+    for br in &branches {
+        let (vf, vt) = (voltages[br.from], voltages[br.to]);
+        let half_b = Complex64::new(0.0, br.charging_b / 2.0);
+        let i_from =
+            (br.y_series + half_b) / br.tap.norm_sqr() * vf - br.y_series / br.tap.conj() * vt;
+        let s_from = vf * i_from.conj();
+        solution
+            .branch_q_flow
+            .insert(br.name.clone(), s_from.im * BASE_MVA);
+    }
+
+    let s_bus = injections(&y_bus, &voltages);
+    let mut estimate = ReactiveEstimate::default();
+    for (i, name) in bus_names.iter().enumerate() {
+        estimate
+            .bus_q_injection
+            .insert(name.clone(), s_bus[i].im * BASE_MVA);
+        estimate.bus_voltage_mag.insert(name.clone(), v_mag[i]);
+
+        let gens = &bus_gens[i];
+        let q_share = (s_bus[i].im + q_load[i]) * BASE_MVA / gens.len().max(1) as f64;
+        for gen in gens {
+            solution.generator_q.insert(gen.clone(), q_share);
+        }
+    }
+    solution.reactive_estimate = Some(estimate);
+    Ok(())
+}
+
+/// Solve `B'θ = P - P_shift` with bus 0 as the angle reference (radians).
+fn dc_angles(n: usize, branches: &[EstimateBranch], p_inj: &[f64]) -> Result<Vec<f64>, OpfError> {
+    let mut theta = vec![0.0; n];
+    if n == 1 {
+        return Ok(theta);
+    }
+
+    let mut b_prime = Mat::<f64>::zeros(n - 1, n - 1);
+    let mut rhs = Mat::<f64>::from_fn(n - 1, 1, |r, _| p_inj[r + 1]);
+    for br in branches {
+        let (f, t, b) = (br.from, br.to, br.b_dc);
+        // Phase shifters inject ±bφ at their terminals
+        let shift = b * br.tap.arg();
+        if f > 0 {
+            b_prime.write(f - 1, f - 1, b_prime.read(f - 1, f - 1) + b);
+            rhs.write(f - 1, 0, rhs.read(f - 1, 0) + shift);
+        }
+        if t > 0 {
+            b_prime.write(t - 1, t - 1, b_prime.read(t - 1, t - 1) + b);
+            rhs.write(t - 1, 0, rhs.read(t - 1, 0) - shift);
+        }
+        if f > 0 && t > 0 {
+            b_prime.write(f - 1, t - 1, b_prime.read(f - 1, t - 1) - b);
+            b_prime.write(t - 1, f - 1, b_prime.read(t - 1, f - 1) - b);
+        }
+    }
+
+    let solved = b_prime.partial_piv_lu().solve(&rhs);
+    for (i, angle) in theta.iter_mut().enumerate().skip(1) {
+        *angle = solved.read(i - 1, 0);
+        if !angle.is_finite() {
+            return Err(OpfError::NumericalIssue(
+                "Singular B' matrix in reactive estimate (islanded network?)".into(),
+            ));
+        }
+    }
+    Ok(theta)
+}
+
+fn phasors(v_mag: &[f64], theta: &[f64]) -> Vec<Complex64> {
+    v_mag
+        .iter()
+        .zip(theta)
+        .map(|(&v, &a)| Complex64::from_polar(v, a))
+        .collect()
+}
+
+/// Complex power injection S = V·conj(Y·V) at every bus (per-unit).
+fn injections(y_bus: &[Vec<Complex64>], voltages: &[Complex64]) -> Vec<Complex64> {
+    y_bus
+        .iter()
+        .zip(voltages)
+        .map(|(row, &v)| {
+            let current: Complex64 = row.iter().zip(voltages).map(|(y, vj)| y * vj).sum();
+            v * current.conj()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{Branch, BranchId, Bus, Gen, GenId, Load, LoadId, Megavars, Megawatts};
+
+    /// Generator at bus 1 feeding a lagging load at bus 2 over one line.
+    fn radial() -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus 1".to_string(),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "Bus 2".to_string(),
+            ..Bus::default()
+        }));
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "Gen 1".to_string(), BusId::new(1)).with_p_limits(0.0, 200.0),
+        ));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load 2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(50.0),
+            reactive_power: Megavars(20.0),
+            zip: None,
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "Line 1-2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        network
+    }
+
+    #[test]
+    fn test_radial_line_carries_load_vars() {
+        let network = radial();
+        let mut solution = OpfSolution::default();
+        solution.generator_p.insert("Gen 1".to_string(), 50.0);
+
+        attach(&network, &mut solution).unwrap();
+
+        // Sending end supplies the 20 MVAr load plus I²X on the line
+        let q = solution.branch_q_flow["Line 1-2"];
+        assert!(q > 20.0 && q < 26.0, "q_from = {}", q);
+
+        let estimate = solution.reactive_estimate.as_ref().unwrap();
+        assert!((estimate.bus_q_injection["Bus 2"] + 20.0).abs() < 2.0);
+        assert!(estimate.bus_voltage_mag["Bus 2"] < 1.0);
+        assert_eq!(estimate.bus_voltage_mag["Bus 1"], 1.0);
+
+        // The only generator supplies the sending-end vars
+        assert!((solution.generator_q["Gen 1"] - q).abs() < 1e-9);
+    }
+}
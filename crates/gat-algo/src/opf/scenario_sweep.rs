@@ -0,0 +1,316 @@
+//! Multi-scenario DC-OPF sweeps.
+//!
+//! [`solve_scenarios_dc`] applies each [`ScenarioSpec`] to a copy of the base
+//! network, solves DC-OPF, and stacks every result into one long-format
+//! DataFrame, ready for pivoting in DuckDB or polars:
+//!
+//! | Column      | Type       | Meaning                                          |
+//! |-------------|------------|--------------------------------------------------|
+//! | `scenario`  | str        | `scenario_id` of the spec                        |
+//! | `converged` | bool       | Whether the scenario solved                      |
+//! | `error`     | str, opt   | Why the scenario failed (null when it solved)    |
+//! | `quantity`  | str        | `objective`, `dispatch_mw`, `flow_mw` or `lmp`   |
+//! | `element`   | str, opt   | Generator, branch or bus name (null for objective) |
+//! | `value`     | float, opt | Objective ($/hr), MW, or $/MWh                   |
+//!
+//! Each solved scenario contributes an `objective` row followed by its
+//! dispatch, flows and LMPs, each sorted by element name. A scenario that
+//! cannot be applied or solved is kept as a single `objective` row with
+//! `converged = false`, a null value and the error message, so one bad case
+//! does not abort the sweep.
+//!
+//! [`stream_scenarios_dc`] runs the same sweep but writes a JSON Lines summary
+//! of each scenario as soon as it is solved, for piping long runs into other
+//! tools:
+//!
+//! ```text
+//! {"scenario":"base","converged":true,"objective":500.0,"error":null}
+//! ```
+
+use crate::opf::{OpfMethod, OpfSolution, OpfSolver};
+use anyhow::{Context, Result};
+use gat_core::Network;
+use gat_scenarios::ScenarioSpec;
+use polars::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Columns of the sweep table, filled row by row
+#[derive(Default)]
+struct SweepRows {
+    scenario: Vec<String>,
+    converged: Vec<bool>,
+    error: Vec<Option<String>>,
+    quantity: Vec<&'static str>,
+    element: Vec<Option<String>>,
+    value: Vec<Option<f64>>,
+}
+
+impl SweepRows {
+    fn push(
+        &mut self,
+        scenario: &str,
+        result: &Result<OpfSolution, String>,
+        quantity: &'static str,
+        element: Option<&str>,
+        value: Option<f64>,
+    ) {
+        self.scenario.push(scenario.to_string());
+        self.converged
+            .push(result.as_ref().is_ok_and(|solution| solution.converged));
+        self.error.push(result.as_ref().err().cloned());
+        self.quantity.push(quantity);
+        self.element.push(element.map(str::to_string));
+        self.value.push(value);
+    }
+
+    fn push_sorted(
+        &mut self,
+        scenario: &str,
+        result: &Result<OpfSolution, String>,
+        quantity: &'static str,
+        values: &HashMap<String, f64>,
+    ) {
+        let mut names: Vec<_> = values.keys().collect();
+        names.sort();
+        for name in names {
+            self.push(scenario, result, quantity, Some(name), Some(values[name]));
+        }
+    }
+}
+
+/// One line of [`stream_scenarios_dc`] output
+#[derive(Serialize)]
+struct ScenarioLine<'a> {
+    scenario: &'a str,
+    converged: bool,
+    objective: Option<f64>,
+    error: Option<&'a str>,
+}
+
+/// Apply `spec` to a copy of `base` and solve it
+fn solve_scenario(
+    solver: &OpfSolver,
+    base: &Network,
+    spec: &ScenarioSpec,
+) -> Result<OpfSolution, String> {
+    gat_scenarios::apply(base, spec)
+        .map_err(|err| err.to_string())
+        .and_then(|network| solver.solve(&network).map_err(|err| err.to_string()))
+}
+
+/// Solve DC-OPF for every scenario in `specs` and stack the results.
+///
+/// Scenarios are applied with [`gat_scenarios::apply`], so outages must name
+/// existing elements. Failures are recorded in the table rather than
+/// returned; the `Err` case is reserved for building the DataFrame itself.
+pub fn solve_scenarios_dc(base: &Network, specs: &[ScenarioSpec]) -> Result<DataFrame> {
+    let solver = OpfSolver::new().with_method(OpfMethod::DcOpf);
+    let mut rows = SweepRows::default();
+
+    for spec in specs {
+        let scenario = spec.scenario_id.as_str();
+        let result = solve_scenario(&solver, base, spec);
+
+        match &result {
+            Ok(solution) => {
+                rows.push(
+                    scenario,
+                    &result,
+                    "objective",
+                    None,
+                    Some(solution.objective_value),
+                );
+                rows.push_sorted(scenario, &result, "dispatch_mw", &solution.generator_p);
+                rows.push_sorted(scenario, &result, "flow_mw", &solution.branch_p_flow);
+                rows.push_sorted(scenario, &result, "lmp", &solution.bus_lmp);
+            }
+            Err(_) => rows.push(scenario, &result, "objective", None, None),
+        }
+    }
+
+    DataFrame::new(vec![
+        Series::new("scenario", rows.scenario),
+        Series::new("converged", rows.converged),
+        Series::new("error", rows.error),
+        Series::new("quantity", rows.quantity),
+        Series::new("element", rows.element),
+        Series::new("value", rows.value),
+    ])
+    .context("building scenario sweep results")
+}
+
+/// Solve DC-OPF for every scenario in `specs`, writing one JSON object per
+/// line to `writer` as each solve finishes.
+///
+/// Lines appear in completion order and are flushed one at a time, so a long
+/// sweep can be consumed live. As in [`solve_scenarios_dc`], a scenario that
+/// fails is reported with a null objective and its error; the `Err` case is
+/// reserved for write failures.
+pub fn stream_scenarios_dc<W: Write>(
+    base: &Network,
+    specs: &[ScenarioSpec],
+    mut writer: W,
+) -> Result<()> {
+    let solver = OpfSolver::new().with_method(OpfMethod::DcOpf);
+
+    for spec in specs {
+        let result = solve_scenario(&solver, base, spec);
+        let line = ScenarioLine {
+            scenario: &spec.scenario_id,
+            converged: result.as_ref().is_ok_and(|solution| solution.converged),
+            objective: result
+                .as_ref()
+                .ok()
+                .map(|solution| solution.objective_value),
+            error: result.as_ref().err().map(String::as_str),
+        };
+        serde_json::to_writer(&mut writer, &line).context("writing scenario result")?;
+        writeln!(writer).context("writing scenario result")?;
+        writer.flush().context("flushing scenario result")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{
+        Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Megavars,
+        Megawatts, Node,
+    };
+
+    fn spec(id: &str, load_scale: f64) -> ScenarioSpec {
+        ScenarioSpec {
+            scenario_id: id.to_string(),
+            description: None,
+            tags: None,
+            outages: Vec::new(),
+            dispatch_overrides: None,
+            load_scale: Some(load_scale),
+            zone_load_scale: None,
+            renewable_scale: None,
+            branch_limit_scale: None,
+            time_slices: None,
+            weight: None,
+            metadata: None,
+        }
+    }
+
+    /// Gen at bus 1 (0-100 MW, $10/MWh) serving 50 MW at bus 2
+    fn two_bus() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..2)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i + 1),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        network.graph.add_edge(
+            buses[0],
+            buses[1],
+            Edge::Branch(Branch::new(
+                BranchId::new(0),
+                "line1_2".to_string(),
+                BusId::new(0),
+                BusId::new(1),
+                0.01,
+                0.1,
+            )),
+        );
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(0), "gen1".to_string(), BusId::new(0))
+                .with_p_limits(0.0, 100.0)
+                .with_cost(CostModel::linear(0.0, 10.0)),
+        ));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(0),
+            name: "load2".to_string(),
+            bus: BusId::new(1),
+            active_power: Megawatts(50.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        network
+    }
+
+    fn rows_for(df: &DataFrame, scenario: &str) -> usize {
+        df.column("scenario")
+            .unwrap()
+            .utf8()
+            .unwrap()
+            .into_iter()
+            .filter(|value| *value == Some(scenario))
+            .count()
+    }
+
+    #[test]
+    fn test_load_multiplier_scenarios_stack_into_one_table() {
+        let specs = [spec("base", 1.0), spec("load_up_20", 1.2)];
+        let df = solve_scenarios_dc(&two_bus(), &specs).unwrap();
+
+        // objective + 1 dispatch + 1 flow + 2 LMPs per scenario
+        assert_eq!(rows_for(&df, "base"), 5);
+        assert_eq!(rows_for(&df, "load_up_20"), 5);
+        assert!(df.column("converged").unwrap().bool().unwrap().all());
+
+        let quantity = df.column("quantity").unwrap().utf8().unwrap();
+        let value = df.column("value").unwrap().f64().unwrap();
+        let dispatch: Vec<f64> = quantity
+            .into_iter()
+            .zip(value)
+            .filter(|(q, _)| *q == Some("dispatch_mw"))
+            .map(|(_, v)| v.unwrap())
+            .collect();
+        assert_eq!(dispatch.len(), 2);
+        assert!((dispatch[0] - 50.0).abs() < 0.1);
+        assert!((dispatch[1] - 60.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_failed_scenario_is_flagged_not_fatal() {
+        let mut outage = spec("bad_outage", 1.0);
+        outage.outages = vec![gat_scenarios::spec::OutageSpec::Branch {
+            id: "no_such_line".to_string(),
+        }];
+        let specs = [outage, spec("base", 1.0)];
+        let df = solve_scenarios_dc(&two_bus(), &specs).unwrap();
+
+        assert_eq!(rows_for(&df, "bad_outage"), 1);
+        assert_eq!(rows_for(&df, "base"), 5);
+        let converged = df.column("converged").unwrap().bool().unwrap();
+        assert_eq!(converged.get(0), Some(false));
+        assert!(df.column("error").unwrap().utf8().unwrap().get(0).is_some());
+        assert_eq!(df.column("value").unwrap().null_count(), 1);
+    }
+
+    #[test]
+    fn test_stream_emits_one_json_line_per_scenario() {
+        let specs = [spec("base", 1.0), spec("load_up_20", 1.2)];
+        let mut output = Vec::new();
+        stream_scenarios_dc(&two_bus(), &specs, &mut output).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["scenario"], "base");
+        assert_eq!(lines[1]["scenario"], "load_up_20");
+        for (line, load) in lines.iter().zip([50.0, 60.0]) {
+            assert_eq!(line["converged"], true);
+            assert!(line["error"].is_null());
+            let objective = line["objective"].as_f64().unwrap();
+            assert!(
+                (objective - 10.0 * load).abs() < 1.0,
+                "objective {}",
+                objective
+            );
+        }
+    }
+}
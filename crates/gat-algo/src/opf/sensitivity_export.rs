@@ -0,0 +1,246 @@
+//! Parquet export of PTDF and LODF matrices.
+//!
+//! Factors are written in long format, one row per matrix entry, so they can be
+//! joined and filtered directly in DuckDB or Polars:
+//!
+//! | File | Columns                                       |
+//! |------|-----------------------------------------------|
+//! | PTDF | `branch_id`, `bus_id`, `factor`               |
+//! | LODF | `branch_id`, `outaged_branch_id`, `factor`    |
+//!
+//! The footer's key-value metadata records what the factors are relative to:
+//!
+//! | Key                  | Meaning                                            |
+//! |----------------------|----------------------------------------------------|
+//! | `gat_result_type`    | `ptdf` or `lodf`                                   |
+//! | `gat_schema_version` | Result schema version                              |
+//! | `solver`             | `dc-sensitivity`                                   |
+//! | `slack_bus`          | Bus absorbing each injection (PTDF only)           |
+//! | `base_case_buses`    | Buses in the base-case topology (PTDF only)        |
+//! | `base_case_branches` | In-service branches in the base-case topology      |
+//!
+//! LODF diagonals are −1 and radial outages are written as `inf`.
+
+use crate::sparse::{LodfMatrix, PtdfMatrix};
+use anyhow::{Context, Result};
+use gat_schemas::result::{key_value, result_types, writer_properties, ResultMetadata};
+use parquet::data_type::{DoubleType, Int64Type};
+use parquet::file::writer::SerializedFileWriter;
+use parquet::format::KeyValue;
+use parquet::schema::parser::parse_message_type;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Solver tag for factors derived from the DC power-flow linearization
+const SENSITIVITY_SOLVER: &str = "dc-sensitivity";
+
+/// Write `ptdf` to `path` as a long-format `{branch_id, bus_id, factor}` table.
+pub fn write_ptdf_parquet(ptdf: &PtdfMatrix, path: &Path) -> Result<()> {
+    let mut branch_ids = Vec::with_capacity(ptdf.num_branches() * ptdf.num_buses());
+    let mut bus_ids = Vec::with_capacity(branch_ids.capacity());
+    let mut factors = Vec::with_capacity(branch_ids.capacity());
+    for (row, branch) in ptdf.values.iter().zip(&ptdf.branch_ids) {
+        for (&factor, bus) in row.iter().zip(&ptdf.bus_ids) {
+            branch_ids.push(branch.value() as i64);
+            bus_ids.push(bus.value() as i64);
+            factors.push(factor);
+        }
+    }
+
+    let tags = ResultMetadata::new(result_types::PTDF).with_solver(SENSITIVITY_SOLVER);
+    let metadata = vec![
+        key_value("slack_bus", ptdf.slack_bus().value()),
+        key_value("base_case_buses", ptdf.num_buses()),
+        key_value("base_case_branches", ptdf.num_branches()),
+    ];
+    write_long_table(
+        path,
+        "bus_id",
+        &branch_ids,
+        &bus_ids,
+        &factors,
+        &tags,
+        metadata,
+    )
+}
+
+/// Write `lodf` to `path` as a long-format `{branch_id, outaged_branch_id, factor}` table.
+pub fn write_lodf_parquet(lodf: &LodfMatrix, path: &Path) -> Result<()> {
+    let n = lodf.num_branches();
+    let mut branch_ids = Vec::with_capacity(n * n);
+    let mut outaged_ids = Vec::with_capacity(n * n);
+    let mut factors = Vec::with_capacity(n * n);
+    for (row, branch) in lodf.values.iter().zip(&lodf.branch_ids) {
+        for (&factor, outaged) in row.iter().zip(&lodf.branch_ids) {
+            branch_ids.push(branch.value() as i64);
+            outaged_ids.push(outaged.value() as i64);
+            factors.push(factor);
+        }
+    }
+
+    let tags = ResultMetadata::new(result_types::LODF).with_solver(SENSITIVITY_SOLVER);
+    let metadata = vec![key_value("base_case_branches", n)];
+    write_long_table(
+        path,
+        "outaged_branch_id",
+        &branch_ids,
+        &outaged_ids,
+        &factors,
+        &tags,
+        metadata,
+    )
+}
+
+/// Write a `{branch_id, <column>, factor}` table in a single row group.
+fn write_long_table(
+    path: &Path,
+    column: &str,
+    branch_ids: &[i64],
+    column_ids: &[i64],
+    factors: &[f64],
+    tags: &ResultMetadata,
+    metadata: Vec<KeyValue>,
+) -> Result<()> {
+    let schema = parse_message_type(&format!(
+        "message factors {{ required int64 branch_id; required int64 {}; required double factor; }}",
+        column
+    ))
+    .context("building factor schema")?;
+    let props = writer_properties(tags, metadata);
+
+    let file = File::create(path)
+        .with_context(|| format!("creating Parquet file at {}", path.display()))?;
+    let mut writer = SerializedFileWriter::new(file, Arc::new(schema), Arc::new(props))
+        .context("creating Parquet writer")?;
+    let mut row_group = writer.next_row_group().context("starting row group")?;
+    for ids in [branch_ids, column_ids] {
+        let mut col = row_group
+            .next_column()?
+            .context("factor schema has too few columns")?;
+        col.typed::<Int64Type>()
+            .write_batch(ids, None, None)
+            .context("writing ID column")?;
+        col.close()?;
+    }
+    let mut col = row_group
+        .next_column()?
+        .context("factor schema has too few columns")?;
+    col.typed::<DoubleType>()
+        .write_batch(factors, None, None)
+        .context("writing factor column")?;
+    col.close()?;
+    row_group.close()?;
+    writer.close().context("finalizing Parquet file")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sparse::SparsePtdf;
+    use gat_core::{Branch, BranchId, Bus, BusId, Edge, Network, Node};
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::RowAccessor;
+    use tempfile::TempDir;
+
+    /// Triangle of equal reactances; bus 1 is the slack.
+    fn triangle() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (id, (from, to)) in [(1, 2), (1, 3), (2, 3)].into_iter().enumerate() {
+            network.graph.add_edge(
+                buses[from - 1],
+                buses[to - 1],
+                Edge::Branch(Branch::new(
+                    BranchId::new(id),
+                    format!("line{}_{}", from, to),
+                    BusId::new(from),
+                    BusId::new(to),
+                    0.0,
+                    0.1,
+                )),
+            );
+        }
+        network
+    }
+
+    fn metadata(reader: &SerializedFileReader<File>, key: &str) -> Option<String> {
+        reader
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()?
+            .iter()
+            .find(|kv| kv.key == key)
+            .and_then(|kv| kv.value.clone())
+    }
+
+    #[test]
+    fn test_ptdf_round_trip() {
+        let network = triangle();
+        let ptdf = SparsePtdf::compute_ptdf(&network).unwrap();
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("ptdf.parquet");
+        write_ptdf_parquet(&ptdf, &path).unwrap();
+
+        let reader = SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(
+            metadata(&reader, "gat_result_type").as_deref(),
+            Some("ptdf")
+        );
+        assert_eq!(
+            metadata(&reader, "gat_schema_version").as_deref(),
+            Some(gat_schemas::result::RESULT_SCHEMA_VERSION)
+        );
+        assert_eq!(
+            metadata(&reader, "solver").as_deref(),
+            Some(SENSITIVITY_SOLVER)
+        );
+        assert_eq!(metadata(&reader, "slack_bus").as_deref(), Some("1"));
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 9);
+
+        // Two thirds of an injection at bus 2 flows back over line 1-2
+        let expected = ptdf.get(BranchId::new(0), BusId::new(2)).unwrap();
+        assert!((expected + 2.0 / 3.0).abs() < 1e-9);
+        let factor = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.unwrap())
+            .find(|row| row.get_long(0).unwrap() == 0 && row.get_long(1).unwrap() == 2)
+            .map(|row| row.get_double(2).unwrap())
+            .expect("row for line 1-2, bus 2");
+        assert_eq!(factor, expected);
+    }
+
+    #[test]
+    fn test_lodf_columns() {
+        let network = triangle();
+        let ptdf = SparsePtdf::compute_ptdf(&network).unwrap();
+        let lodf = SparsePtdf::compute_lodf(&network, &ptdf).unwrap();
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("lodf.parquet");
+        write_lodf_parquet(&lodf, &path).unwrap();
+
+        let reader = SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(
+            metadata(&reader, "gat_result_type").as_deref(),
+            Some("lodf")
+        );
+        let fields = reader
+            .metadata()
+            .file_metadata()
+            .schema_descr()
+            .root_schema();
+        let names: Vec<&str> = fields.get_fields().iter().map(|f| f.name()).collect();
+        assert_eq!(names, ["branch_id", "outaged_branch_id", "factor"]);
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 9);
+    }
+}
@@ -107,6 +107,9 @@
 //! This solver supports:
 //! - **Quadratic cost curves**: `cost = c₀ + c₁·P + c₂·P²`
 //! - **Phase-shifting transformers**: Via angle-coupled formulation
+//! - **Cycle constraints on meshed networks**: Every branch carries an
+//!   angle-recovery row `θⱼ - θᵢ + (x·P - r·Q)/τ = -φ`, so the angle drops
+//!   around each independent loop sum to zero (Kirchhoff's voltage law)
 //! - **Tap-changing transformers**: Off-nominal tap ratios
 //! - **Line charging**: Shunt susceptance (π-model)
 //! - **Thermal limits**: MVA flow constraints
@@ -119,8 +122,8 @@
 //! interior-point solver for conic programs written in Rust. Clarabel implements a
 //! primal-dual interior point method with Nesterov-Todd scaling.
 
-use crate::opf::types::{ConstraintInfo, ConstraintType};
-use crate::opf::{OpfMethod, OpfSolution};
+use crate::opf::types::{ConstraintId, ConstraintInfo, ConstraintType};
+use crate::opf::{OpfMethod, OpfSolution, OpfTolerances, SolverWarning};
 use crate::OpfError;
 use clarabel::{
     algebra::CscMatrix,
@@ -456,8 +459,8 @@ fn extract_network_data(
                 generators.push(GenData {
                     name: gen.name.clone(),
                     bus_id: gen.bus,
-                    pmin: gen.pmin.value(),
-                    pmax: gen.pmax.value(),
+                    pmin: gen.dispatch_pmin(),
+                    pmax: gen.dispatch_pmax(),
                     qmin: gen.qmin.value(),
                     qmax: gen.qmax.value(),
                     cost_coeffs,
@@ -650,6 +653,18 @@ pub fn solve(
     network: &Network,
     _max_iterations: usize,
     _tolerance: f64,
+) -> Result<OpfSolution, OpfError> {
+    solve_with_tolerances(network, None, None)
+}
+
+/// Solve the SOCP relaxation with explicit Clarabel tolerances.
+///
+/// `None` keeps Clarabel's defaults, as [`solve`] does. An `objective_scale`
+/// multiplies the cost terms handed to Clarabel; duals are divided back out.
+pub(crate) fn solve_with_tolerances(
+    network: &Network,
+    tolerances: Option<&OpfTolerances>,
+    objective_scale: Option<f64>,
 ) -> Result<OpfSolution, OpfError> {
     let start = Instant::now();
 
@@ -1349,6 +1364,11 @@ pub fn solve(
     }
     col_ptr.push(nnz); // Final column pointer
 
+    // Pre-scale the objective; both cost terms scale alike
+    let scale = objective_scale.unwrap_or(1.0);
+    let p_values: Vec<f64> = p_values.into_iter().map(|v| v * scale).collect();
+    let obj: Vec<f64> = obj.into_iter().map(|c| c * scale).collect();
+
     // Construct sparse matrices
     let a_mat = CscMatrix::new(n_con_rows, n_var, col_ptr, row_idx, values);
     let p_mat = CscMatrix::new(n_var, n_var, p_col_ptr, p_row_idx, p_values);
@@ -1369,8 +1389,15 @@ pub fn solve(
     //
     // Typical convergence: 15-30 iterations for 1e-8 tolerance.
 
-    let settings = DefaultSettingsBuilder::default()
-        .verbose(false)
+    let mut settings = DefaultSettingsBuilder::default();
+    settings.verbose(false);
+    if let Some(tolerances) = tolerances {
+        settings
+            .tol_feas(tolerances.clarabel_feas())
+            .tol_gap_abs(tolerances.gap)
+            .tol_gap_rel(tolerances.gap);
+    }
+    let settings = settings
         .build()
         .map_err(|e| OpfError::NumericalIssue(format!("Clarabel settings error: {:?}", e)))?;
 
@@ -1382,16 +1409,19 @@ pub fn solve(
 
     solver.solve();
 
-    // Check solver status
+    // Check solver status; an iteration-capped iterate is returned unconverged
     let sol = solver.solution;
-    if !matches!(
-        sol.status,
-        clarabel::solver::SolverStatus::Solved
-            | clarabel::solver::SolverStatus::AlmostSolved
-            | clarabel::solver::SolverStatus::AlmostDualInfeasible
-            | clarabel::solver::SolverStatus::AlmostPrimalInfeasible
-            | clarabel::solver::SolverStatus::DualInfeasible
-    ) {
+    let capped = matches!(sol.status, clarabel::solver::SolverStatus::MaxIterations);
+    if !capped
+        && !matches!(
+            sol.status,
+            clarabel::solver::SolverStatus::Solved
+                | clarabel::solver::SolverStatus::AlmostSolved
+                | clarabel::solver::SolverStatus::AlmostDualInfeasible
+                | clarabel::solver::SolverStatus::AlmostPrimalInfeasible
+                | clarabel::solver::SolverStatus::DualInfeasible
+        )
+    {
         return Err(OpfError::NumericalIssue(format!(
             "Clarabel returned status {:?}. \
              This may indicate an infeasible problem (load exceeds capacity, \
@@ -1400,21 +1430,27 @@ pub fn solve(
         )));
     }
 
-    // Extract primal solution (x) and dual variables (z)
+    // Extract primal solution (x) and dual variables (z), undoing the scaling
     let x = &sol.x;
-    let z = &sol.z;
+    let z: Vec<f64> = sol.z.iter().map(|dual| dual / scale).collect();
 
     // ========================================================================
     // STEP 8: EXTRACT AND FORMAT SOLUTION
     // ========================================================================
 
     let mut result = OpfSolution {
-        converged: true,
+        converged: !capped,
         method_used: OpfMethod::SocpRelaxation,
         iterations: sol.iterations as usize,
         solve_time_ms: start.elapsed().as_millis(),
+        objective_scale,
         ..Default::default()
     };
+    if capped {
+        result.warnings.push(SolverWarning::IterationCapHit {
+            iterations: result.iterations,
+        });
+    }
 
     // ------------------------------------------------------------------------
     // 8a. Generator dispatch and total cost
@@ -1479,6 +1515,9 @@ pub fn solve(
         // Check if thermal limit is binding
         if let Some(row) = row_branch_thermal[idx] {
             if row < z.len() && br.s_max.is_some() {
+                result
+                    .constraint_duals
+                    .insert(ConstraintId::BranchThermal(br.name.clone()), z[row]);
                 let smax = br.s_max.unwrap();
                 let limit_pu_sq = (smax / BASE_MVA).powi(2);
                 let slack = limit_pu_sq - l;
@@ -1567,6 +1606,11 @@ pub fn solve(
         let v_mag = *result.bus_voltage_mag.get(&bus.name).unwrap_or(&1.0);
 
         if let Some(&row_min) = row_voltage_min.get(i) {
+            if row_min < z.len() {
+                result
+                    .constraint_duals
+                    .insert(ConstraintId::VoltageMin(bus.name.clone()), z[row_min]);
+            }
             if row_min < z.len() && (v_mag - bus.v_min).abs() < 1e-4 {
                 result.binding_constraints.push(ConstraintInfo {
                     name: bus.name.clone(),
@@ -1579,6 +1623,11 @@ pub fn solve(
         }
 
         if let Some(&row_max) = row_voltage_max.get(i) {
+            if row_max < z.len() {
+                result
+                    .constraint_duals
+                    .insert(ConstraintId::VoltageMax(bus.name.clone()), z[row_max]);
+            }
             if row_max < z.len() && (v_mag - bus.v_max).abs() < 1e-4 {
                 result.binding_constraints.push(ConstraintInfo {
                     name: bus.name.clone(),
@@ -1596,6 +1645,11 @@ pub fn solve(
         let p = *result.generator_p.get(&gen.name).unwrap_or(&0.0);
 
         if let Some(&row) = row_gen_pmax.get(i) {
+            if row < z.len() {
+                result
+                    .constraint_duals
+                    .insert(ConstraintId::GeneratorPMax(gen.name.clone()), z[row]);
+            }
             if row < z.len() && (p - gen.pmax).abs() < 1e-3 {
                 result.binding_constraints.push(ConstraintInfo {
                     name: gen.name.clone(),
@@ -1608,6 +1662,11 @@ pub fn solve(
         }
 
         if let Some(&row) = row_gen_pmin.get(i) {
+            if row < z.len() {
+                result
+                    .constraint_duals
+                    .insert(ConstraintId::GeneratorPMin(gen.name.clone()), z[row]);
+            }
             if row < z.len() && (p - gen.pmin).abs() < 1e-3 {
                 result.binding_constraints.push(ConstraintInfo {
                     name: gen.name.clone(),
@@ -1645,6 +1704,7 @@ pub fn solve(
             }
         }
     }
+    result.record_renewable_curtailment(network);
 
     Ok(result)
 }
@@ -2067,10 +2127,13 @@ pub fn solve_with_config(
     solver.solve();
 
     let sol = solver.solution;
-    if !matches!(
-        sol.status,
-        clarabel::solver::SolverStatus::Solved | clarabel::solver::SolverStatus::AlmostSolved
-    ) {
+    let capped = matches!(sol.status, clarabel::solver::SolverStatus::MaxIterations);
+    if !capped
+        && !matches!(
+            sol.status,
+            clarabel::solver::SolverStatus::Solved | clarabel::solver::SolverStatus::AlmostSolved
+        )
+    {
         return Err(OpfError::NumericalIssue(format!(
             "Clarabel returned status {:?}",
             sol.status
@@ -2081,12 +2144,17 @@ pub fn solve_with_config(
 
     // Extract results
     let mut result = OpfSolution {
-        converged: true,
+        converged: !capped,
         method_used: OpfMethod::SocpRelaxation,
         iterations: sol.iterations as usize,
         solve_time_ms: start.elapsed().as_millis(),
         ..Default::default()
     };
+    if capped {
+        result.warnings.push(SolverWarning::IterationCapHit {
+            iterations: result.iterations,
+        });
+    }
 
     // Generator outputs
     let mut total_cost = 0.0;
@@ -2125,6 +2193,7 @@ pub fn solve_with_config(
         total_losses += br.r * l * BASE_MVA;
     }
     result.total_losses_mw = total_losses;
+    result.record_renewable_curtailment(network);
 
     Ok(result)
 }
@@ -0,0 +1,123 @@
+//! Quality check of the SOCP relaxation against the full AC-OPF.
+//!
+//! The SOCP relaxation is a lower bound on the AC-OPF objective. It is exact
+//! on radial networks under mild conditions, but on meshed networks the
+//! relaxed solution can be physically unrealizable. [`validate_socp_against_ac`]
+//! solves both formulations and reports how far apart they are, so users can
+//! decide per case whether the faster SOCP answer is good enough.
+//!
+//! The relaxation is reported as tight when the relative objective gap
+//! `(AC - SOCP) / |AC|` is within [`TIGHT_GAP_TOLERANCE`] and the AC reference
+//! converged. The AC solver is a penalty method, so a small negative gap (AC
+//! slightly below the bound) is possible and counts as tight.
+
+use super::{OpfMethod, OpfSolution, OpfSolver};
+use crate::OpfError;
+use gat_core::Network;
+use std::collections::HashMap;
+
+/// Relative objective gap below which the relaxation is considered tight
+pub const TIGHT_GAP_TOLERANCE: f64 = 0.01;
+
+/// Iteration limit for the AC reference solve
+const AC_MAX_ITERATIONS: usize = 300;
+
+/// Convergence tolerance for the AC reference solve
+const AC_TOLERANCE: f64 = 1e-4;
+
+/// Comparison of an SOCP solution with the AC-OPF solution of the same case.
+#[derive(Debug, Clone, Default)]
+pub struct SocpValidationReport {
+    /// SOCP objective value ($/hr), a lower bound on the AC optimum
+    pub socp_objective: f64,
+    /// AC-OPF objective value ($/hr)
+    pub ac_objective: f64,
+    /// Relative gap `(AC - SOCP) / |AC|`
+    pub objective_gap_rel: f64,
+    /// `|V|` difference per bus, SOCP minus AC (p.u.)
+    pub bus_voltage_diff: HashMap<String, f64>,
+    /// Active dispatch difference per generator, SOCP minus AC (MW)
+    pub generator_p_diff: HashMap<String, f64>,
+    /// Reactive dispatch difference per generator, SOCP minus AC (MVAr)
+    pub generator_q_diff: HashMap<String, f64>,
+    /// Whether the AC reference met its constraint tolerance
+    pub ac_converged: bool,
+    /// True when the AC reference converged and
+    /// `objective_gap_rel <= TIGHT_GAP_TOLERANCE`
+    pub relaxation_tight: bool,
+}
+
+impl SocpValidationReport {
+    /// Largest absolute bus voltage magnitude difference (p.u.).
+    pub fn max_voltage_diff(&self) -> f64 {
+        max_abs(&self.bus_voltage_diff)
+    }
+
+    /// Largest absolute generator active power difference (MW).
+    pub fn max_dispatch_diff_mw(&self) -> f64 {
+        max_abs(&self.generator_p_diff)
+    }
+
+    /// True when the relaxation is tight and voltages and dispatch agree
+    /// within the given tolerances.
+    pub fn agrees_within(&self, voltage_tol_pu: f64, dispatch_tol_mw: f64) -> bool {
+        self.relaxation_tight
+            && self.max_voltage_diff() <= voltage_tol_pu
+            && self.max_dispatch_diff_mw() <= dispatch_tol_mw
+    }
+}
+
+/// Solve `network` with SOCP and AC-OPF and compare the solutions.
+///
+/// Fails if either solver errors or the SOCP relaxation stops at its
+/// iteration cap (a capped iterate is not a lower bound). A non-converged AC
+/// solve is still compared, with `ac_converged` false and the relaxation not
+/// marked tight.
+pub fn validate_socp_against_ac(network: &Network) -> Result<SocpValidationReport, OpfError> {
+    let socp = OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .solve(network)?;
+    if !socp.converged {
+        return Err(OpfError::NumericalIssue(format!(
+            "SOCP relaxation stopped at its iteration cap ({} iterations)",
+            socp.iterations
+        )));
+    }
+    let ac = OpfSolver::new()
+        .with_method(OpfMethod::AcOpf)
+        .with_max_iterations(AC_MAX_ITERATIONS)
+        .with_tolerance(AC_TOLERANCE)
+        .solve(network)?;
+    Ok(compare(&socp, &ac))
+}
+
+/// Relative objective gap `(AC - SOCP) / |AC|`.
+pub(crate) fn objective_gap_rel(socp: &OpfSolution, ac: &OpfSolution) -> f64 {
+    (ac.objective_value - socp.objective_value) / ac.objective_value.abs().max(1.0)
+}
+
+fn compare(socp: &OpfSolution, ac: &OpfSolution) -> SocpValidationReport {
+    let objective_gap_rel = objective_gap_rel(socp, ac);
+    SocpValidationReport {
+        socp_objective: socp.objective_value,
+        ac_objective: ac.objective_value,
+        objective_gap_rel,
+        bus_voltage_diff: diff(&socp.bus_voltage_mag, &ac.bus_voltage_mag),
+        generator_p_diff: diff(&socp.generator_p, &ac.generator_p),
+        generator_q_diff: diff(&socp.generator_q, &ac.generator_q),
+        ac_converged: ac.converged,
+        relaxation_tight: ac.converged && objective_gap_rel <= TIGHT_GAP_TOLERANCE,
+    }
+}
+
+/// `relaxed - exact` for every key present in both maps.
+fn diff(relaxed: &HashMap<String, f64>, exact: &HashMap<String, f64>) -> HashMap<String, f64> {
+    relaxed
+        .iter()
+        .filter_map(|(name, r)| exact.get(name).map(|e| (name.clone(), r - e)))
+        .collect()
+}
+
+fn max_abs(values: &HashMap<String, f64>) -> f64 {
+    values.values().fold(0.0, |acc, v| acc.max(v.abs()))
+}
@@ -0,0 +1,192 @@
+//! Flow impact of point-to-point transactions.
+//!
+//! A transaction of P MW injected at bus a and withdrawn at bus b changes the
+//! DC flow on branch ℓ by
+//!
+//! ```text
+//! ΔF_ℓ = P · (PTDF[ℓ, a] − PTDF[ℓ, b])
+//! ```
+//!
+//! The reference bus cancels out, so the result does not depend on which bus
+//! the PTDF was computed against. [`transaction_overloads`] adds the impact to
+//! a base case's flows and reports the branches pushed past their rating.
+
+use crate::sparse::PtdfMatrix;
+use gat_core::{BranchId, BusId, Edge, Network};
+use std::collections::HashMap;
+
+/// A branch loaded beyond its rating once a transaction is added.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionOverload {
+    pub branch: BranchId,
+    pub name: String,
+    /// Flow before the transaction (MW, from→to)
+    pub base_flow_mw: f64,
+    /// Flow with the transaction (MW, from→to)
+    pub flow_mw: f64,
+    /// Thermal rating, `s_max` or Rate A (MW)
+    pub rating_mw: f64,
+}
+
+/// MW change on every branch from moving `mw` from `from_bus` to `to_bus`.
+///
+/// Positive values increase from→to flow. A bus missing from the PTDF (for
+/// instance one in another island) is treated as the reference bus.
+pub fn transaction_flow_impact(
+    ptdf: &PtdfMatrix,
+    from_bus: BusId,
+    to_bus: BusId,
+    mw: f64,
+) -> HashMap<BranchId, f64> {
+    let from_idx = ptdf.bus_index(from_bus);
+    let to_idx = ptdf.bus_index(to_bus);
+    ptdf.branch_ids
+        .iter()
+        .enumerate()
+        .map(|(row, &branch)| {
+            let factor = |idx: Option<usize>| idx.map_or(0.0, |col| ptdf.get_by_idx(row, col));
+            (branch, mw * (factor(from_idx) - factor(to_idx)))
+        })
+        .collect()
+}
+
+/// Branches whose rating is exceeded once `impact` is added to `base_flows`.
+///
+/// `base_flows` holds from→to flows in MW keyed by branch name, as in
+/// [`OpfSolution::branch_p_flow`](crate::opf::OpfSolution). Branches without
+/// a rating are never flagged. The result is sorted by branch name.
+pub fn transaction_overloads(
+    network: &Network,
+    base_flows: &HashMap<String, f64>,
+    impact: &HashMap<BranchId, f64>,
+) -> Vec<TransactionOverload> {
+    let mut overloads: Vec<_> = network
+        .graph
+        .edge_weights()
+        .filter_map(|edge| {
+            let Edge::Branch(branch) = edge else {
+                return None;
+            };
+            let rating_mw = branch.s_max.or(branch.rating_a)?.value();
+            let delta = *impact.get(&branch.id)?;
+            let base_flow_mw = base_flows.get(&branch.name).copied().unwrap_or(0.0);
+            let flow_mw = base_flow_mw + delta;
+            (rating_mw > 0.0 && flow_mw.abs() > rating_mw).then(|| TransactionOverload {
+                branch: branch.id,
+                name: branch.name.clone(),
+                base_flow_mw,
+                flow_mw,
+                rating_mw,
+            })
+        })
+        .collect();
+    overloads.sort_by(|a, b| a.name.cmp(&b.name));
+    overloads
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opf::{OpfMethod, OpfSolver};
+    use crate::sparse::SparsePtdf;
+    use gat_core::{Branch, Bus, CostModel, Gen, GenId, Load, LoadId, Megavars, Megawatts, Node};
+
+    /// Triangle of lossless lines, 100 MW from a unit at bus 1 to a load at
+    /// bus 3. Line 2–3 has half the reactance of the others, so it is the
+    /// corridor a bus-2 → bus-3 transaction mostly uses.
+    fn triangle(transaction_mw: f64) -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (id, (from, to, x)) in [(1, 2, 0.1), (1, 3, 0.1), (2, 3, 0.05)]
+            .into_iter()
+            .enumerate()
+        {
+            network.graph.add_edge(
+                buses[from - 1],
+                buses[to - 1],
+                Edge::Branch(
+                    Branch::new(
+                        BranchId::new(id),
+                        format!("line{}_{}", from, to),
+                        BusId::new(from),
+                        BusId::new(to),
+                        0.0,
+                        x,
+                    )
+                    .with_s_max(Some(80.0)),
+                ),
+            );
+        }
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1))
+                .with_p_limits(0.0, 300.0)
+                .with_cost(CostModel::linear(0.0, 10.0)),
+        ));
+        if transaction_mw > 0.0 {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(2), "seller".to_string(), BusId::new(2))
+                    .with_p_limits(transaction_mw, transaction_mw)
+                    .with_cost(CostModel::linear(0.0, 10.0)),
+            ));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(3),
+            name: "load3".to_string(),
+            bus: BusId::new(3),
+            active_power: Megawatts(100.0 + transaction_mw),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        network
+    }
+
+    fn dc_flows(network: &Network) -> HashMap<String, f64> {
+        OpfSolver::new()
+            .with_method(OpfMethod::DcOpf)
+            .solve(network)
+            .expect("DC-OPF should solve")
+            .branch_p_flow
+    }
+
+    #[test]
+    fn test_transaction_impact_matches_resolve() {
+        let base = triangle(0.0);
+        let ptdf = SparsePtdf::compute_ptdf(&base).unwrap();
+        let impact = transaction_flow_impact(&ptdf, BusId::new(2), BusId::new(3), 30.0);
+
+        let before = dc_flows(&base);
+        let after = dc_flows(&triangle(30.0));
+        for (id, name) in [(0, "line1_2"), (1, "line1_3"), (2, "line2_3")] {
+            let expected = after[name] - before[name];
+            let got = impact[&BranchId::new(id)];
+            assert!(
+                (got - expected).abs() < 1e-3,
+                "{}: impact {:.4} vs re-solve {:.4}",
+                name,
+                got,
+                expected
+            );
+        }
+        // Line 2–3 carries 4/5 of the transaction
+        assert!((impact[&BranchId::new(2)] - 24.0).abs() < 1e-6);
+
+        // Base: 60 MW on line 1–3, 40 MW round 1–2–3. The transaction takes
+        // line 2–3 to 64 MW and line 1–3 to 66 MW, both within 80 MW; doubling
+        // it overloads line 2–3 only
+        assert!(transaction_overloads(&base, &before, &impact).is_empty());
+        let large = transaction_flow_impact(&ptdf, BusId::new(2), BusId::new(3), 60.0);
+        let overloads = transaction_overloads(&base, &before, &large);
+        assert_eq!(overloads.len(), 1);
+        assert_eq!(overloads[0].name, "line2_3");
+        assert!((overloads[0].flow_mw - 88.0).abs() < 1e-6);
+        assert_eq!(overloads[0].rating_mw, 80.0);
+    }
+}
@@ -1,13 +1,22 @@
 use std::collections::HashMap;
 use std::fmt;
 
-use serde::{Deserialize, Serialize};
+use gat_core::{DispatchMode, Edge, Network, Node};
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::flow_sign::FlowDirection;
 
 /// OPF solution method
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OpfMethod {
     /// Merit-order economic dispatch (no network constraints)
+    ///
+    /// Units tied on marginal cost share load in proportion to headroom,
+    /// ordered by `GenId`, so repeated runs give identical dispatch.
     EconomicDispatch,
+    /// All buses collapsed into one: lossless supply/demand balance at equal
+    /// incremental cost, reporting a single system price
+    Copperplate,
     /// DC optimal power flow (LP with B-matrix)
     DcOpf,
     /// Second-order cone relaxation of AC-OPF
@@ -21,6 +30,7 @@ impl fmt::Display for OpfMethod {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             OpfMethod::EconomicDispatch => write!(f, "economic"),
+            OpfMethod::Copperplate => write!(f, "copperplate"),
             OpfMethod::DcOpf => write!(f, "dc"),
             OpfMethod::SocpRelaxation => write!(f, "socp"),
             OpfMethod::AcOpf => write!(f, "ac"),
@@ -28,12 +38,21 @@ impl fmt::Display for OpfMethod {
     }
 }
 
+impl OpfMethod {
+    /// Whether the method optimizes reactive power. For economic dispatch,
+    /// copperplate and DC-OPF, `generator_q` is zero or a post-solve estimate.
+    pub fn reactive_is_exact(self) -> bool {
+        matches!(self, OpfMethod::SocpRelaxation | OpfMethod::AcOpf)
+    }
+}
+
 impl std::str::FromStr for OpfMethod {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "economic" | "fast" => Ok(OpfMethod::EconomicDispatch),
+            "copperplate" => Ok(OpfMethod::Copperplate),
             "dc" | "balanced" => Ok(OpfMethod::DcOpf),
             "socp" | "accurate" => Ok(OpfMethod::SocpRelaxation),
             "ac" => Ok(OpfMethod::AcOpf),
@@ -42,6 +61,51 @@ impl std::str::FromStr for OpfMethod {
     }
 }
 
+/// Convergence tolerances for the individual parts of an OPF solve.
+///
+/// Each backend maps these to its own options:
+///
+/// | Field         | IPOPT             | Clarabel                  | L-BFGS penalty    |
+/// |---------------|-------------------|---------------------------|-------------------|
+/// | `primal`      | `tol`             | `tol_feas` (tightest of `primal`, `dual`, `feasibility`) | - |
+/// | `dual`        | `dual_inf_tol`    | `tol_feas` (as above)     | -                 |
+/// | `feasibility` | `constr_viol_tol` | `tol_feas` (as above)     | stopping violation |
+/// | `gap`         | `compl_inf_tol`   | `tol_gap_abs`, `tol_gap_rel` | -              |
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OpfTolerances {
+    /// Overall optimality error
+    pub primal: f64,
+    /// Dual infeasibility
+    pub dual: f64,
+    /// Largest constraint violation in the returned solution
+    pub feasibility: f64,
+    /// Duality gap / complementarity
+    pub gap: f64,
+}
+
+impl OpfTolerances {
+    /// The same tolerance for every component
+    pub fn uniform(tol: f64) -> Self {
+        Self {
+            primal: tol,
+            dual: tol,
+            feasibility: tol,
+            gap: tol,
+        }
+    }
+
+    /// Clarabel checks primal and dual residuals against one tolerance
+    pub(crate) fn clarabel_feas(&self) -> f64 {
+        self.primal.min(self.dual).min(self.feasibility)
+    }
+}
+
+impl Default for OpfTolerances {
+    fn default() -> Self {
+        Self::uniform(1e-6)
+    }
+}
+
 /// Type of constraint for reporting
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum ConstraintType {
@@ -50,11 +114,70 @@ pub enum ConstraintType {
     GeneratorQMax,
     GeneratorQMin,
     BranchFlowLimit,
+    BranchAngleLimit,
     VoltageMax,
     VoltageMin,
     PowerBalance,
 }
 
+/// Identifies one constraint of an OPF formulation by kind and element name.
+///
+/// Serializes as `"<kind>:<name>"`, e.g. `"branch_thermal:line1_2"`, so it
+/// can key JSON maps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ConstraintId {
+    /// Thermal limit of a branch
+    BranchThermal(String),
+    /// Angle-difference limit of a branch (whichever side binds)
+    AngleDifference(String),
+    /// Upper voltage bound of a bus
+    VoltageMax(String),
+    /// Lower voltage bound of a bus
+    VoltageMin(String),
+    /// Upper active power limit of a generator
+    GeneratorPMax(String),
+    /// Lower active power limit of a generator
+    GeneratorPMin(String),
+    /// Schedule, or price band limit, of an external tie
+    Interchange(String),
+}
+
+impl ConstraintId {
+    /// Name of the constrained element.
+    pub fn element(&self) -> &str {
+        match self {
+            ConstraintId::BranchThermal(name)
+            | ConstraintId::AngleDifference(name)
+            | ConstraintId::VoltageMax(name)
+            | ConstraintId::VoltageMin(name)
+            | ConstraintId::GeneratorPMax(name)
+            | ConstraintId::GeneratorPMin(name)
+            | ConstraintId::Interchange(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for ConstraintId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            ConstraintId::BranchThermal(_) => "branch_thermal",
+            ConstraintId::AngleDifference(_) => "angle_difference",
+            ConstraintId::VoltageMax(_) => "voltage_max",
+            ConstraintId::VoltageMin(_) => "voltage_min",
+            ConstraintId::GeneratorPMax(_) => "generator_pmax",
+            ConstraintId::GeneratorPMin(_) => "generator_pmin",
+            ConstraintId::Interchange(_) => "interchange",
+        };
+        write!(f, "{}:{}", kind, self.element())
+    }
+}
+
+impl Serialize for ConstraintId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 /// Information about a binding or violated constraint
 #[derive(Debug, Clone, Serialize)]
 pub struct ConstraintInfo {
@@ -65,6 +188,103 @@ pub struct ConstraintInfo {
     pub shadow_price: f64,
 }
 
+/// Distance from a reactive limit (MVAr) within which a generator counts as
+/// held by it in [`OpfSolution::generator_q_binding`]
+pub const Q_BINDING_TOLERANCE_MVAR: f64 = 1e-2;
+
+/// Which reactive limit, if any, a generator's output sits at.
+///
+/// A unit at a limit has no reactive headroom left in that direction, so it
+/// can no longer support its bus voltage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QBind {
+    /// At `qmin`: cannot absorb more reactive power
+    Lower,
+    /// At `qmax`: cannot produce more reactive power
+    Upper,
+    /// Strictly inside `[qmin, qmax]`
+    Neither,
+}
+
+/// Distance from a voltage limit (p.u.) within which a bus is reported by
+/// [`SolverWarning::NearVoltageLimit`]
+pub const NEAR_VOLTAGE_LIMIT_PU: f64 = 1e-3;
+
+/// A condition a solve ran into without failing.
+///
+/// The solution is still returned, but callers may want to surface these
+/// before trusting it. Serializes with a `kind` tag, e.g.
+/// `{"kind": "iteration_cap_hit", "iterations": 200}`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SolverWarning {
+    /// The solver stopped at its iteration limit before meeting its
+    /// tolerances; the solution is its last iterate
+    IterationCapHit { iterations: usize },
+    /// Relative objective gap `(AC - SOCP) / |AC|` above
+    /// [`TIGHT_GAP_TOLERANCE`](crate::opf::TIGHT_GAP_TOLERANCE): the SOCP
+    /// relaxation is not exact for this case
+    RelaxationGapHigh { gap_rel: f64 },
+    /// Bus voltage magnitude within [`NEAR_VOLTAGE_LIMIT_PU`] of a limit
+    NearVoltageLimit {
+        bus: String,
+        vm_pu: f64,
+        limit_pu: f64,
+    },
+    /// The slack unit picked up more than
+    /// [`SLACK_MISMATCH_MW`](crate::opf::SLACK_MISMATCH_MW) beyond its
+    /// dispatch when the solution was re-simulated by
+    /// [`verify_ac_feasibility`](crate::opf::verify_ac_feasibility)
+    SlackAbsorbedLargeMismatch { generator: String, mismatch_mw: f64 },
+    /// The backend for `requested` was unavailable, so the solution comes
+    /// from `used`; see [`OpfSolver::with_solver_fallback`](crate::opf::OpfSolver::with_solver_fallback)
+    SolverFallback {
+        requested: OpfMethod,
+        used: OpfMethod,
+        reason: String,
+    },
+}
+
+impl fmt::Display for SolverWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverWarning::IterationCapHit { iterations } => {
+                write!(f, "iteration cap hit after {} iterations", iterations)
+            }
+            SolverWarning::RelaxationGapHigh { gap_rel } => {
+                write!(f, "SOCP relaxation gap {:.2}%", gap_rel * 100.0)
+            }
+            SolverWarning::NearVoltageLimit {
+                bus,
+                vm_pu,
+                limit_pu,
+            } => write!(
+                f,
+                "bus {} voltage {:.4} p.u. at limit {:.4} p.u.",
+                bus, vm_pu, limit_pu
+            ),
+            SolverWarning::SlackAbsorbedLargeMismatch {
+                generator,
+                mismatch_mw,
+            } => write!(
+                f,
+                "slack generator {} absorbed {:.2} MW mismatch",
+                generator, mismatch_mw
+            ),
+            SolverWarning::SolverFallback {
+                requested,
+                used,
+                reason,
+            } => write!(
+                f,
+                "{} OPF unavailable ({}); solved with {} instead",
+                requested, reason, used
+            ),
+        }
+    }
+}
+
 /// OPF solution output
 #[derive(Debug, Clone, Serialize)]
 pub struct OpfSolution {
@@ -80,17 +300,315 @@ pub struct OpfSolution {
     // === Primal Variables ===
     pub generator_p: HashMap<String, f64>,
     pub generator_q: HashMap<String, f64>,
+    /// Reactive limit each generator sits at, keyed by name. Only filled by
+    /// methods that optimize reactive power (SOCP, AC-OPF).
+    pub generator_q_binding: HashMap<String, QBind>,
     pub bus_voltage_mag: HashMap<String, f64>,
     pub bus_voltage_ang: HashMap<String, f64>,
+    /// Angle reference bus of each island, by name. Empty for methods
+    /// that do not select one.
+    pub slack_buses: Vec<String>,
     pub branch_p_flow: HashMap<String, f64>,
     pub branch_q_flow: HashMap<String, f64>,
+    /// Orientation of `branch_p_flow`/`branch_q_flow`; always `FromTo` once the
+    /// solution has passed through [`normalize_flow_signs`](crate::opf::normalize_flow_signs)
+    pub flow_direction: FlowDirection,
+    /// Spilled output of variable units in MW (`p_available` capped at `pmax`,
+    /// minus dispatch), keyed by generator name. Empty when the network has
+    /// no variable units.
+    pub renewable_curtailment: HashMap<String, f64>,
+    /// Optimized transformer tap ratios keyed by branch name. Empty unless the
+    /// AC-OPF was asked to control taps.
+    pub transformer_taps: HashMap<String, f64>,
 
     // === Dual Variables ===
     pub bus_lmp: HashMap<String, f64>,
+    /// Single marginal price of energy ($/MWh) when the network is ignored.
+    /// Only set by copperplate dispatch.
+    pub system_price: Option<f64>,
+    /// Shadow price of every limit the method models, zero (to solver
+    /// tolerance) when it does not bind: the objective decrease per unit the
+    /// limit is relaxed. Units follow the constraint: $/MWh per MW for DC
+    /// branch and generator limits, $/h per radian for DC angle-difference
+    /// limits; SOCP thermal and voltage duals are per p.u.² of squared
+    /// current or voltage. Empty for economic dispatch.
+    pub constraint_duals: HashMap<ConstraintId, f64>,
 
     // === Constraint Info ===
     pub binding_constraints: Vec<ConstraintInfo>,
     pub total_losses_mw: f64,
+    /// CO2 emitted by the dispatch (tCO2 over one hour), from each unit's
+    /// `emissions_rate`. Units without a rate contribute zero.
+    pub total_emissions_t: f64,
+    /// Generators held by their `dispatch_mode`: fixed units, and must-run
+    /// units dispatched at `pmin`. Sorted by name.
+    pub constrained_generators: Vec<String>,
+    /// Realized import over each external tie in MW (negative for export),
+    /// keyed by tie name. Empty unless the solver was given external ties.
+    pub tie_flows: HashMap<String, f64>,
+    /// Demand shed in MW, keyed by load name. Empty unless the solver was
+    /// given a [`LoadShedding`](crate::opf::LoadShedding) policy.
+    pub load_shed_mw: HashMap<String, f64>,
+    /// Thermal rating each branch was solved against in MVA (`s_max`, else
+    /// Rate A), after any dynamic rating or derating. Unrated branches are absent.
+    pub branch_ratings: HashMap<String, f64>,
+    /// Voltage outside the bus band in p.u. (positive above `v_max`, negative
+    /// below `v_min`), keyed by bus name. Only filled by AC-OPF with soft
+    /// voltage limits; hard limits never report violations.
+    pub voltage_violations: HashMap<String, f64>,
+    /// Conditions worth a second look in an otherwise usable solution, such
+    /// as an iteration cap or a voltage riding its limit. Empty when clean.
+    pub warnings: Vec<SolverWarning>,
+    /// Factor the objective was multiplied by inside the solver, when
+    /// `OpfSolver::with_autoscale` applied one. `objective_value`, prices and
+    /// duals are reported unscaled.
+    pub objective_scale: Option<f64>,
+
+    // === Sensitivities (opt-in) ===
+    /// dLMP/dP matrix, populated when requested via `OpfSolver::with_lmp_sensitivities`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lmp_sensitivity: Option<LmpSensitivity>,
+    /// Decoupled reactive estimate, populated when requested via
+    /// `OpfSolver::with_reactive_estimate`. When present, `branch_q_flow`
+    /// holds estimated (not optimized) values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactive_estimate: Option<ReactiveEstimate>,
+    /// Reactive demand (MVAr) no generator could cover, populated when
+    /// requested via `OpfSolver::with_reactive_dispatch`. `generator_q` then
+    /// holds the screening assignment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactive_shortfall_mvar: Option<f64>,
+    /// Per-generator explanation of an economic dispatch in merit order,
+    /// populated when requested via `OpfSolver::with_dispatch_trace`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_trace: Option<Vec<DispatchExplanation>>,
+}
+
+impl OpfSolution {
+    /// Total shed load in MW.
+    pub fn total_load_shed_mw(&self) -> f64 {
+        self.load_shed_mw.values().sum()
+    }
+
+    /// Total curtailed renewable output in MW.
+    pub fn total_curtailment_mw(&self) -> f64 {
+        self.renewable_curtailment.values().sum()
+    }
+
+    /// Fill `renewable_curtailment` from the dispatch of variable units.
+    pub(crate) fn record_renewable_curtailment(&mut self, network: &Network) {
+        for node in network.graph.node_weights() {
+            let Node::Gen(gen) = node else {
+                continue;
+            };
+            let (Some(available), Some(&dispatched)) =
+                (gen.p_available, self.generator_p.get(&gen.name))
+            else {
+                continue;
+            };
+            // A forecast above nameplate cannot be produced, so it is not spilled
+            let available = available.value().min(gen.pmax.value()).max(0.0);
+            let curtailed = (available - dispatched).max(0.0);
+            self.renewable_curtailment
+                .insert(gen.name.clone(), curtailed);
+        }
+    }
+
+    /// Warn about buses whose voltage magnitude lies within
+    /// [`NEAR_VOLTAGE_LIMIT_PU`] of their `vmin_pu` or `vmax_pu`.
+    pub(crate) fn record_voltage_warnings(&mut self, network: &Network) {
+        for node in network.graph.node_weights() {
+            let Node::Bus(bus) = node else {
+                continue;
+            };
+            let Some(&vm_pu) = self.bus_voltage_mag.get(&bus.name) else {
+                continue;
+            };
+            let near = [bus.vmax_pu, bus.vmin_pu]
+                .into_iter()
+                .flatten()
+                .map(|limit| limit.value())
+                .find(|limit| (vm_pu - limit).abs() <= NEAR_VOLTAGE_LIMIT_PU);
+            if let Some(limit_pu) = near {
+                self.warnings.push(SolverWarning::NearVoltageLimit {
+                    bus: bus.name.clone(),
+                    vm_pu,
+                    limit_pu,
+                });
+            }
+        }
+    }
+
+    /// Fill `branch_ratings` from the ratings of the network that was solved.
+    pub(crate) fn record_branch_ratings(&mut self, network: &Network) {
+        for edge in network.graph.edge_weights() {
+            if let Edge::Branch(branch) = edge {
+                if let Some(rating) = branch.s_max.or(branch.rating_a) {
+                    self.branch_ratings
+                        .insert(branch.name.clone(), rating.value());
+                }
+            }
+        }
+    }
+
+    /// Fill `total_emissions_t` from the dispatch and generator emissions rates.
+    pub(crate) fn record_emissions(&mut self, network: &Network) {
+        self.total_emissions_t = network
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Gen(gen) => Some((gen.emissions_rate?, self.generator_p.get(&gen.name)?)),
+                _ => None,
+            })
+            .map(|(rate, &p_mw)| rate * p_mw.max(0.0))
+            .sum();
+    }
+
+    /// Fill `generator_q_binding` by comparing `generator_q` with each unit's
+    /// reactive limits.
+    pub(crate) fn record_q_binding(&mut self, network: &Network) {
+        for node in network.graph.node_weights() {
+            let Node::Gen(gen) = node else {
+                continue;
+            };
+            let Some(&q_mvar) = self.generator_q.get(&gen.name) else {
+                continue;
+            };
+            let bind = if q_mvar >= gen.qmax.value() - Q_BINDING_TOLERANCE_MVAR {
+                QBind::Upper
+            } else if q_mvar <= gen.qmin.value() + Q_BINDING_TOLERANCE_MVAR {
+                QBind::Lower
+            } else {
+                QBind::Neither
+            };
+            self.generator_q_binding.insert(gen.name.clone(), bind);
+        }
+    }
+
+    /// Give every dispatched generator a `generator_q` entry, zero when the
+    /// method produced neither an optimized nor an estimated value.
+    pub(crate) fn fill_missing_generator_q(&mut self) {
+        for name in self.generator_p.keys() {
+            self.generator_q.entry(name.clone()).or_insert(0.0);
+        }
+    }
+
+    /// Fill `constrained_generators` from the dispatch and generator dispatch modes.
+    pub(crate) fn record_dispatch_modes(&mut self, network: &Network) {
+        let mut constrained: Vec<String> = network
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Gen(gen) => {
+                    let p_mw = *self.generator_p.get(&gen.name)?;
+                    let held = match gen.dispatch_mode {
+                        DispatchMode::Auto => false,
+                        DispatchMode::MustRun => (p_mw - gen.pmin.value()).abs() < 1e-3,
+                        DispatchMode::Fixed(_) => true,
+                    };
+                    held.then(|| gen.name.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        constrained.sort();
+        self.constrained_generators = constrained;
+    }
+}
+
+/// Marginal sensitivity of bus LMPs to bus load changes (dLMP/dP).
+///
+/// Derived from the active constraint set (basis) of a solved DC-OPF: with the
+/// basis fixed, a small load change at bus k is absorbed by the marginal units
+/// and the LMP at bus i moves by `sensitivity[i][k]` $/MWh per MW.
+///
+/// Near a breakpoint (a unit that sets the bus price about to hit a limit,
+/// or several linear-cost units marginal at the same cost) a load change
+/// alters the basis and the derivative is undefined. Such buses are listed
+/// in `breakpoint_buses` and their rows should not be trusted.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LmpSensitivity {
+    /// `sensitivity[lmp_bus][load_bus]` = ∂LMP(lmp_bus) / ∂P_load(load_bus) in $/MWh per MW
+    pub sensitivity: HashMap<String, HashMap<String, f64>>,
+    /// Buses whose LMP sits at a breakpoint where the sensitivity is undefined
+    pub breakpoint_buses: Vec<String>,
+}
+
+impl LmpSensitivity {
+    /// Sensitivity of the LMP at `lmp_bus` to load at `load_bus`.
+    pub fn get(&self, lmp_bus: &str, load_bus: &str) -> Option<f64> {
+        self.sensitivity
+            .get(lmp_bus)
+            .and_then(|row| row.get(load_bus))
+            .copied()
+    }
+
+    /// Whether the sensitivity at `bus` is well-defined (not at a breakpoint).
+    pub fn is_defined(&self, bus: &str) -> bool {
+        !self.breakpoint_buses.iter().any(|b| b == bus)
+    }
+}
+
+/// Approximate reactive quantities for a DC-OPF solution.
+///
+/// Computed by a one-shot fast-decoupled step from the DC angles with PV
+/// buses at setpoint and PQ buses starting flat. Expect the right sign and
+/// order of magnitude, not AC-OPF accuracy.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReactiveEstimate {
+    /// Net reactive injection per bus in MVAr (generation minus load)
+    pub bus_q_injection: HashMap<String, f64>,
+    /// Voltage magnitude per bus after the Q-V step (p.u.)
+    pub bus_voltage_mag: HashMap<String, f64>,
+}
+
+/// What holds a generator at its economic dispatch output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DispatchReason {
+    /// Fully loaded: cheaper than the marginal unit
+    AtMaximum,
+    /// Between its limits, setting the system marginal cost
+    Marginal,
+    /// Held at a non-zero `Pmin` although more expensive than needed
+    AtMinimum,
+    /// Off: load is met by cheaper units
+    NotNeeded,
+}
+
+impl fmt::Display for DispatchReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            DispatchReason::AtMaximum => "at maximum limit",
+            DispatchReason::Marginal => "marginal unit",
+            DispatchReason::AtMinimum => "at minimum limit",
+            DispatchReason::NotNeeded => "not needed",
+        };
+        f.write_str(reason)
+    }
+}
+
+/// One generator's line in an economic dispatch trace.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DispatchExplanation {
+    pub generator: String,
+    /// 1-based position in the merit order (marginal cost at `Pmin`, then `GenId`)
+    pub merit_position: usize,
+    /// Dispatched output (MW)
+    pub dispatch_mw: f64,
+    /// Marginal cost at the dispatched output ($/MWh)
+    pub marginal_cost: f64,
+    pub reason: DispatchReason,
+}
+
+impl fmt::Display for DispatchExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} (merit #{}, {:.2} MW at ${:.2}/MWh)",
+            self.generator, self.reason, self.merit_position, self.dispatch_mw, self.marginal_cost
+        )
+    }
 }
 
 impl Default for OpfSolution {
@@ -103,13 +621,32 @@ impl Default for OpfSolution {
             objective_value: 0.0,
             generator_p: HashMap::new(),
             generator_q: HashMap::new(),
+            generator_q_binding: HashMap::new(),
             bus_voltage_mag: HashMap::new(),
             bus_voltage_ang: HashMap::new(),
+            slack_buses: Vec::new(),
             branch_p_flow: HashMap::new(),
             branch_q_flow: HashMap::new(),
+            flow_direction: FlowDirection::FromTo,
+            renewable_curtailment: HashMap::new(),
+            transformer_taps: HashMap::new(),
             bus_lmp: HashMap::new(),
+            system_price: None,
+            constraint_duals: HashMap::new(),
             binding_constraints: Vec::new(),
             total_losses_mw: 0.0,
+            total_emissions_t: 0.0,
+            constrained_generators: Vec::new(),
+            tie_flows: HashMap::new(),
+            load_shed_mw: HashMap::new(),
+            branch_ratings: HashMap::new(),
+            voltage_violations: HashMap::new(),
+            warnings: Vec::new(),
+            objective_scale: None,
+            lmp_sensitivity: None,
+            reactive_estimate: None,
+            reactive_shortfall_mvar: None,
+            dispatch_trace: None,
         }
     }
 }
@@ -1,13 +1,24 @@
 pub mod ac_pf;
+pub mod agc;
 pub mod cpf;
 pub mod fast_decoupled;
+pub mod loss_allocation;
 #[cfg(test)]
 mod q_limits;
+pub mod short_circuit;
+pub mod voltage_estimate;
 
 // Export new power flow solvers for public use
 pub use ac_pf::AcPowerFlowSolution as AcPfSolution;
+pub use ac_pf::NewtonVariant;
+pub use agc::{apply_agc_response, AgcResponse};
 pub use cpf::{CpfPoint, CpfResult, CpfSolver};
 pub use fast_decoupled::FastDecoupledSolver;
+pub use loss_allocation::{allocate_losses, LossAllocation, LossAllocationMethod};
+pub use short_circuit::{short_circuit, BranchFaultCurrent, FaultType, ShortCircuitResult};
+pub use voltage_estimate::{
+    estimate_voltages_from_dc, VoltageEstimate, VOLTAGE_ESTIMATE_TOLERANCE_PU,
+};
 
 use std::{
     collections::{HashMap, HashSet},
@@ -81,6 +92,9 @@ struct MeasurementRecord {
     #[serde(default = "default_weight")]
     weight: f64,
     label: Option<String>,
+    /// Forecast-based pseudo-measurement rather than a real meter reading
+    #[serde(default)]
+    pseudo: bool,
 }
 
 fn default_weight() -> f64 {
@@ -97,12 +111,7 @@ pub enum LpSolverKind {
     Highs,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum BusType {
-    Slack,
-    PV,
-    PQ,
-}
+pub use gat_core::BusType;
 
 #[derive(Debug, Clone, Default)]
 pub struct AcPowerFlowSolution {
@@ -474,6 +483,28 @@ pub fn ac_power_flow(
     Ok(())
 }
 
+/// AC power flow by Newton-Raphson with Levenberg-Marquardt step damping.
+///
+/// Whenever a full Newton step would increase the mismatch, the step is
+/// damped until it does not. This takes more iterations than plain
+/// Newton-Raphson but converges on heavily loaded or ill-conditioned cases
+/// where full steps overshoot. Results are written like [`write_fdpf_solution`].
+pub fn ac_power_flow_damped(
+    network: &Network,
+    tol: f64,
+    max_iter: u32,
+    output_file: &Path,
+    partitions: &[String],
+) -> Result<AcPfSolution> {
+    let solution = ac_pf::AcPowerFlowSolver::new()
+        .with_tolerance(tol)
+        .with_max_iterations(max_iter as usize)
+        .with_newton_variant(NewtonVariant::LevenbergMarquardt)
+        .solve(network)?;
+    write_fdpf_solution(network, &solution, output_file, partitions)?;
+    Ok(solution)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn dc_optimal_power_flow(
     network: &Network,
@@ -907,6 +938,13 @@ pub fn ac_optimal_power_flow(
     Ok(())
 }
 
+/// DC weighted least squares state estimation from a measurements CSV.
+///
+/// Rows with `pseudo = true` are forecast pseudo-measurements (typically load
+/// injections with a small weight, i.e. a large σ) that make sparsely metered
+/// feeders observable. The state output reports, per bus, the share of the
+/// estimate's information that came from pseudo-measurements
+/// (`pseudo_share`) and whether it relied mainly on them (`pseudo_dependent`).
 pub fn state_estimation_wls(
     network: &Network,
     solver: &dyn LinearSystemBackend,
@@ -1003,6 +1041,7 @@ pub fn state_estimation_wls(
     let mut residuals = Vec::with_capacity(n_measurements);
     let mut normalized_residuals = Vec::with_capacity(n_measurements);
     let mut weights = Vec::with_capacity(n_measurements);
+    let mut pseudo = Vec::with_capacity(n_measurements);
     let mut chi2 = 0.0;
 
     for (idx, row) in measurement_rows.iter().enumerate() {
@@ -1024,6 +1063,7 @@ pub fn state_estimation_wls(
         residuals.push(residual);
         normalized_residuals.push(normalized);
         weights.push(row.weight);
+        pseudo.push(row.pseudo);
     }
 
     let mut measurement_df = DataFrame::new(vec![
@@ -1035,6 +1075,7 @@ pub fn state_estimation_wls(
         Series::new("residual", residuals),
         Series::new("normalized_residual", normalized_residuals),
         Series::new("weight", weights),
+        Series::new("pseudo", pseudo),
     ])?;
 
     persist_dataframe(
@@ -1045,16 +1086,47 @@ pub fn state_estimation_wls(
     )
     .context("writing state estimation measurements")?;
 
+    // Share of each state's information (diagonal of HᵗWH) that comes from
+    // pseudo-measurements; above one half the estimate is mostly forecast
+    let mut information = vec![0.0; n_vars];
+    let mut pseudo_information = vec![0.0; n_vars];
+    for row in &measurement_rows {
+        for (i, &h_i) in row.h.iter().enumerate() {
+            let contribution = row.weight * h_i * h_i;
+            information[i] += contribution;
+            if row.pseudo {
+                pseudo_information[i] += contribution;
+            }
+        }
+    }
+    let mut pseudo_share = HashMap::new();
+    for (idx, bus_id) in unknown_buses.iter().enumerate() {
+        let share = if information[idx] > 0.0 {
+            pseudo_information[idx] / information[idx]
+        } else {
+            0.0
+        };
+        pseudo_share.insert(*bus_id, share);
+    }
+    let pseudo_dependent = pseudo_share.values().filter(|&&share| share > 0.5).count();
+
     if let Some(state_path) = state_out {
         let mut bus_ids_vec = Vec::with_capacity(bus_ids.len());
         let mut angle_vec = Vec::with_capacity(bus_ids.len());
+        let mut share_vec = Vec::with_capacity(bus_ids.len());
+        let mut dependent_vec = Vec::with_capacity(bus_ids.len());
         for bus_id in &bus_ids {
             bus_ids_vec.push(*bus_id as i64);
             angle_vec.push(*angle_map.get(bus_id).unwrap_or(&0.0));
+            let share = *pseudo_share.get(bus_id).unwrap_or(&0.0);
+            share_vec.push(share);
+            dependent_vec.push(share > 0.5);
         }
         let mut state_df = DataFrame::new(vec![
             Series::new("bus_id", bus_ids_vec),
             Series::new("angle_rad", angle_vec),
+            Series::new("pseudo_share", share_vec),
+            Series::new("pseudo_dependent", dependent_vec),
         ])?;
         persist_dataframe(&mut state_df, state_path, &[], OutputStage::SeWls.as_str())
             .context("writing state estimation angles")?;
@@ -1072,9 +1144,197 @@ pub fn state_estimation_wls(
         chi2,
         output_file.display()
     );
+    if pseudo_dependent > 0 {
+        println!(
+            "  {} of {} state(s) rely mainly on pseudo-measurements",
+            pseudo_dependent, n_vars
+        );
+    }
     Ok(())
 }
 
+/// Observability of the DC state estimator for a set of measurements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservabilityReport {
+    /// Whether the gain matrix HᵗWH is full rank
+    pub observable: bool,
+    /// Rank of the gain matrix
+    pub gain_rank: usize,
+    /// State variables: one angle per bus other than the slack
+    pub state_count: usize,
+    pub measurement_count: usize,
+    /// Measurements per state variable; below 1.0 the system cannot be observable
+    pub redundancy_ratio: f64,
+    /// Buses whose angle cannot be estimated, sorted by id
+    pub unobservable_buses: Vec<usize>,
+    /// Groups of unobservable buses whose angles are fixed relative to each
+    /// other but not to the slack, each sorted by id
+    pub unobservable_islands: Vec<Vec<usize>>,
+}
+
+/// Check whether `measurements_csv` makes the DC state observable before running WLS.
+///
+/// Builds the same measurement Jacobian as [`state_estimation_wls`] and
+/// reduces the gain matrix G = HᵗWH to row echelon form. G is full rank
+/// exactly when every bus angle is determined relative to the slack.
+/// Otherwise the null space of G gives the unobservable buses (those with a
+/// nonzero null-space component) and the unobservable branches (whose end
+/// angles differ along some null-space direction); removing those branches
+/// splits the unobservable buses into islands (Monticelli & Wu,
+/// doi:10.1109/TPAS.1985.319200).
+pub fn check_observability(
+    network: &Network,
+    measurements_csv: &str,
+    slack_bus: Option<usize>,
+) -> Result<ObservabilityReport> {
+    let measurements = load_measurements(measurements_csv)?;
+    let (bus_ids, id_to_index, susceptance) = build_bus_susceptance(network, None);
+    let slack_bus = match slack_bus {
+        Some(bus) if !id_to_index.contains_key(&bus) => {
+            return Err(anyhow!("slack bus {} not found in network", bus));
+        }
+        Some(bus) => bus,
+        None => *bus_ids
+            .first()
+            .ok_or_else(|| anyhow!("network must contain at least one bus"))?,
+    };
+    let unknown_buses: Vec<usize> = bus_ids
+        .iter()
+        .copied()
+        .filter(|&bus| bus != slack_bus)
+        .collect();
+    let unknown_idx: HashMap<usize, usize> = unknown_buses
+        .iter()
+        .enumerate()
+        .map(|(idx, &bus)| (bus, idx))
+        .collect();
+    let rows = build_measurement_rows(
+        &measurements,
+        &susceptance,
+        &id_to_index,
+        &unknown_buses,
+        &unknown_idx,
+        slack_bus,
+        network,
+    )?;
+
+    let n_vars = unknown_buses.len();
+    let mut gain = vec![vec![0.0; n_vars]; n_vars];
+    for row in &rows {
+        for (i, &h_i) in row.h.iter().enumerate() {
+            if h_i == 0.0 {
+                continue;
+            }
+            for (j, &h_j) in row.h.iter().enumerate() {
+                gain[i][j] += h_i * row.weight * h_j;
+            }
+        }
+    }
+
+    // Gauss-Jordan elimination to reduced row echelon form; pivots give the rank
+    let scale = gain
+        .iter()
+        .flatten()
+        .fold(0.0_f64, |acc, value| acc.max(value.abs()))
+        .max(1.0);
+    let pivot_tol = 1e-9 * scale;
+    let mut pivot_cols = Vec::new();
+    let mut rank = 0;
+    for col in 0..n_vars {
+        let Some(pivot) = (rank..n_vars)
+            .max_by(|&a, &b| gain[a][col].abs().total_cmp(&gain[b][col].abs()))
+            .filter(|&row| gain[row][col].abs() > pivot_tol)
+        else {
+            // Free column: clear round-off so it reads cleanly in the null space
+            for row in gain.iter_mut().skip(rank) {
+                row[col] = 0.0;
+            }
+            continue;
+        };
+        gain.swap(rank, pivot);
+        let lead = gain[rank][col];
+        for value in gain[rank].iter_mut() {
+            *value /= lead;
+        }
+        for row in 0..n_vars {
+            if row != rank && gain[row][col] != 0.0 {
+                let factor = gain[row][col];
+                for k in 0..n_vars {
+                    gain[row][k] -= factor * gain[rank][k];
+                }
+            }
+        }
+        pivot_cols.push(col);
+        rank += 1;
+    }
+
+    // One null-space vector per free column, as bus angle offsets (slack fixed at zero)
+    let null_space: Vec<HashMap<usize, f64>> = (0..n_vars)
+        .filter(|col| !pivot_cols.contains(col))
+        .map(|free| {
+            let mut angles: HashMap<usize, f64> = HashMap::from([(slack_bus, 0.0)]);
+            angles.insert(unknown_buses[free], 1.0);
+            for (row, &col) in pivot_cols.iter().enumerate() {
+                angles.insert(unknown_buses[col], -gain[row][free]);
+            }
+            for &bus in &unknown_buses {
+                angles.entry(bus).or_insert(0.0);
+            }
+            angles
+        })
+        .collect();
+    const NULL_TOL: f64 = 1e-6;
+    let moves = |bus: usize| {
+        null_space
+            .iter()
+            .any(|angles| angles[&bus].abs() > NULL_TOL)
+    };
+    let unobservable_buses: Vec<usize> = unknown_buses
+        .iter()
+        .copied()
+        .filter(|&bus| moves(bus))
+        .collect();
+
+    let mut islands = petgraph::unionfind::UnionFind::<usize>::new(bus_ids.len());
+    for edge in network.graph.edge_references() {
+        if let Edge::Branch(branch) = edge.weight() {
+            let (from, to) = (branch.from_bus.value(), branch.to_bus.value());
+            let (Some(&i), Some(&j)) = (id_to_index.get(&from), id_to_index.get(&to)) else {
+                continue;
+            };
+            let observable_branch = null_space
+                .iter()
+                .all(|angles| (angles[&from] - angles[&to]).abs() <= NULL_TOL);
+            if branch.status && observable_branch {
+                islands.union(i, j);
+            }
+        }
+    }
+    let mut grouped: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &bus in &unobservable_buses {
+        grouped
+            .entry(islands.find(id_to_index[&bus]))
+            .or_default()
+            .push(bus);
+    }
+    let mut unobservable_islands: Vec<Vec<usize>> = grouped.into_values().collect();
+    unobservable_islands.sort();
+
+    Ok(ObservabilityReport {
+        observable: rank == n_vars,
+        gain_rank: rank,
+        state_count: n_vars,
+        measurement_count: rows.len(),
+        redundancy_ratio: if n_vars == 0 {
+            0.0
+        } else {
+            rows.len() as f64 / n_vars as f64
+        },
+        unobservable_buses,
+        unobservable_islands,
+    })
+}
+
 pub(crate) fn branch_flow_dataframe(
     network: &Network,
     injections: &HashMap<usize, f64>,
@@ -1097,31 +1357,30 @@ pub(crate) fn branch_flow_dataframe_with_angles(
     angles: &HashMap<usize, f64>,
     skip_branch: Option<i64>,
 ) -> PolarsResult<(DataFrame, f64, f64)> {
-    // Each branch flow is computed directly as the angle difference divided by reactance.
+    // Each branch flow is computed directly as the angle difference divided by reactance,
+    // positive from `from_bus` to `to_bus` (see `opf::normalize_flow_signs`).
     let edge_count = network.graph.edge_count();
     let mut ids = Vec::with_capacity(edge_count);
     let mut from_bus = Vec::with_capacity(edge_count);
     let mut to_bus = Vec::with_capacity(edge_count);
     let mut flows = Vec::with_capacity(edge_count);
 
-    for edge_idx in network.graph.edge_indices() {
-        if let Edge::Branch(branch) = &network.graph[edge_idx] {
-            if !branch.status {
-                continue;
-            }
-            let branch_id = branch.id.value() as i64;
-            if skip_branch == Some(branch_id) {
-                continue;
-            }
-            let reactance = (branch.reactance * branch.tap_ratio).abs().max(1e-6);
-            let theta_from = *angles.get(&branch.from_bus.value()).unwrap_or(&0.0);
-            let theta_to = *angles.get(&branch.to_bus.value()).unwrap_or(&0.0);
-            let flow = ((theta_from - theta_to) - branch.phase_shift.value()) / reactance;
-            ids.push(branch.id.value() as i64);
-            from_bus.push(branch.from_bus.value() as i64);
-            to_bus.push(branch.to_bus.value() as i64);
-            flows.push(flow);
+    for branch in network.branches_sorted() {
+        if !branch.status {
+            continue;
         }
+        let branch_id = branch.id.value() as i64;
+        if skip_branch == Some(branch_id) {
+            continue;
+        }
+        let reactance = (branch.reactance * branch.tap_ratio).abs().max(1e-6);
+        let theta_from = *angles.get(&branch.from_bus.value()).unwrap_or(&0.0);
+        let theta_to = *angles.get(&branch.to_bus.value()).unwrap_or(&0.0);
+        let flow = ((theta_from - theta_to) - branch.phase_shift.value()) / reactance;
+        ids.push(branch_id);
+        from_bus.push(branch.from_bus.value() as i64);
+        to_bus.push(branch.to_bus.value() as i64);
+        flows.push(flow);
     }
 
     let df = DataFrame::new(vec![
@@ -1150,13 +1409,11 @@ fn bus_result_dataframe(network: &Network) -> PolarsResult<DataFrame> {
     let mut voltages = Vec::with_capacity(node_count);
     let mut angles = Vec::with_capacity(node_count);
 
-    for node_idx in network.graph.node_indices() {
-        if let Node::Bus(bus) = &network.graph[node_idx] {
-            ids.push(bus.id.value() as i64);
-            names.push(bus.name.clone());
-            voltages.push(bus.base_kv.value());
-            angles.push((bus.id.value() % 360) as f64);
-        }
+    for bus in network.buses_sorted() {
+        ids.push(bus.id.value() as i64);
+        names.push(bus.name.clone());
+        voltages.push(bus.base_kv.value());
+        angles.push((bus.id.value() % 360) as f64);
     }
 
     DataFrame::new(vec![
@@ -1181,35 +1438,33 @@ pub fn write_ac_pf_solution(
     let mut va_values: Vec<f64> = Vec::with_capacity(node_count);
     let mut bus_type_values: Vec<String> = Vec::with_capacity(node_count);
 
-    for node in network.graph.node_weights() {
-        if let Node::Bus(bus) = node {
-            bus_ids.push(bus.id.value() as u32);
-            bus_names.push(bus.name.clone());
-
-            let vm = solution
-                .bus_voltage_magnitude
-                .get(&bus.id)
-                .copied()
-                .unwrap_or(1.0);
-            let va = solution
-                .bus_voltage_angle
-                .get(&bus.id)
-                .copied()
-                .unwrap_or(0.0);
-            let bus_type = solution
-                .bus_types
-                .get(&bus.id)
-                .map(|t| match t {
-                    BusType::Slack => "Slack",
-                    BusType::PV => "PV",
-                    BusType::PQ => "PQ",
-                })
-                .unwrap_or("PQ");
-
-            vm_values.push(vm);
-            va_values.push(va.to_degrees());
-            bus_type_values.push(bus_type.to_string());
-        }
+    for bus in network.buses_sorted() {
+        bus_ids.push(bus.id.value() as u32);
+        bus_names.push(bus.name.clone());
+
+        let vm = solution
+            .bus_voltage_magnitude
+            .get(&bus.id)
+            .copied()
+            .unwrap_or(1.0);
+        let va = solution
+            .bus_voltage_angle
+            .get(&bus.id)
+            .copied()
+            .unwrap_or(0.0);
+        let bus_type = solution
+            .bus_types
+            .get(&bus.id)
+            .map(|t| match t {
+                BusType::Slack => "Slack",
+                BusType::PV => "PV",
+                BusType::PQ => "PQ",
+            })
+            .unwrap_or("PQ");
+
+        vm_values.push(vm);
+        va_values.push(va.to_degrees());
+        bus_type_values.push(bus_type.to_string());
     }
 
     let mut df = DataFrame::new(vec![
@@ -1248,18 +1503,7 @@ pub fn write_fdpf_solution(
     let converted = AcPowerFlowSolution {
         bus_voltage_magnitude: solution.bus_voltage_magnitude.clone(),
         bus_voltage_angle: solution.bus_voltage_angle.clone(),
-        bus_types: solution
-            .bus_types
-            .iter()
-            .map(|(k, v)| {
-                let bt = match v {
-                    ac_pf::BusType::Slack => BusType::Slack,
-                    ac_pf::BusType::PV => BusType::PV,
-                    ac_pf::BusType::PQ => BusType::PQ,
-                };
-                (*k, bt)
-            })
-            .collect(),
+        bus_types: solution.bus_types.clone(),
         iterations: solution.iterations,
         max_mismatch: solution.max_mismatch,
         converged: solution.converged,
@@ -1435,7 +1679,7 @@ fn get_bus_ids(network: &Network) -> Vec<usize> {
 ///
 /// # Returns
 /// Map from bus ID to voltage angle (radians)
-fn compute_dc_angles_sparse(
+pub(crate) fn compute_dc_angles_sparse(
     network: &Network,
     injections: &HashMap<usize, f64>,
 ) -> Result<HashMap<usize, f64>> {
@@ -1586,6 +1830,7 @@ struct MeasurementRow {
     offset: f64,
     value: f64,
     weight: f64,
+    pseudo: bool,
 }
 
 struct BranchDescriptor {
@@ -1701,6 +1946,7 @@ fn build_measurement_rows(
             offset,
             value: record.value,
             weight: record.weight,
+            pseudo: record.pseudo,
         });
     }
 
@@ -1917,6 +2163,15 @@ mod tests {
         assert_eq!(df.height(), 1);
         let flow = df.column("flow_mw").unwrap().f64().unwrap().get(0).unwrap();
         assert!(!flow.is_nan());
+
+        // Both the staged copy and the final output carry the result tags
+        let staged = crate::io::staged_output_path(&out, OutputStage::PfDc.as_str());
+        for path in [staged.as_path(), out.as_path()] {
+            assert_eq!(
+                gat_schemas::result::read_result_metadata(path).unwrap(),
+                OutputStage::PfDc.result_tags()
+            );
+        }
     }
 
     #[test]
@@ -2247,6 +2502,150 @@ mod tests {
         assert_eq!(state_df.height(), 2);
     }
 
+    /// Chain 0-1-2-3-4 with unit reactances
+    fn build_chain_network() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..5)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("Bus {}", i),
+                    base_kv: gat_core::Kilovolts(138.0),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for i in 0..4 {
+            network.graph.add_edge(
+                buses[i],
+                buses[i + 1],
+                Edge::Branch(Branch {
+                    id: BranchId::new(i),
+                    name: format!("Line {}-{}", i, i + 1),
+                    from_bus: BusId::new(i),
+                    to_bus: BusId::new(i + 1),
+                    reactance: 1.0,
+                    ..Branch::default()
+                }),
+            );
+        }
+        network
+    }
+
+    #[test]
+    fn check_observability_names_unobservable_islands() {
+        let network = build_chain_network();
+        let temp_dir = tempdir().unwrap();
+        let meas_path = temp_dir.path().join("sparse.csv");
+        // Flows on 0-1 and 2-3 only: 2-3 hangs together but floats against the slack
+        fs::write(
+            &meas_path,
+            "measurement_type,branch_id,bus_id,value,weight,label\nflow,0,,0.5,1.0,\nflow,2,,0.2,1.0,\n",
+        )
+        .unwrap();
+
+        let report = check_observability(&network, meas_path.to_str().unwrap(), None).unwrap();
+        assert!(!report.observable);
+        assert_eq!(report.state_count, 4);
+        assert_eq!(report.gain_rank, 2);
+        assert!((report.redundancy_ratio - 0.5).abs() < 1e-12);
+        assert_eq!(report.unobservable_buses, vec![2, 3, 4]);
+        assert_eq!(report.unobservable_islands, vec![vec![2, 3], vec![4]]);
+
+        // An injection at bus 2 ties the 2-3 island to the slack; bus 4 stays unmeasured
+        fs::write(
+            &meas_path,
+            "measurement_type,branch_id,bus_id,value,weight,label\nflow,0,,0.5,1.0,\nflow,2,,0.2,1.0,\ninjection,,2,0.1,1.0,\n",
+        )
+        .unwrap();
+        let report = check_observability(&network, meas_path.to_str().unwrap(), None).unwrap();
+        assert!(!report.observable);
+        assert_eq!(report.unobservable_buses, vec![4]);
+        assert_eq!(report.unobservable_islands, vec![vec![4]]);
+
+        // A flow on 3-4 completes the chain
+        fs::write(
+            &meas_path,
+            "measurement_type,branch_id,bus_id,value,weight,label\nflow,0,,0.5,1.0,\nflow,2,,0.2,1.0,\ninjection,,2,0.1,1.0,\nflow,3,,0.1,1.0,\n",
+        )
+        .unwrap();
+        let report = check_observability(&network, meas_path.to_str().unwrap(), None).unwrap();
+        assert!(report.observable);
+        assert_eq!(report.gain_rank, 4);
+        assert!(report.unobservable_buses.is_empty());
+        assert!(report.unobservable_islands.is_empty());
+    }
+
+    #[test]
+    fn state_estimation_pseudo_measurements_restore_observability() {
+        // True angles 0, -0.4, -0.7, -0.9, -1.0: flows 0.4, 0.3, 0.2, 0.1 and
+        // injections -0.1 at buses 2-4. SCADA meters only the first two lines.
+        let network = build_chain_network();
+        let temp_dir = tempdir().unwrap();
+        let scada = "measurement_type,branch_id,bus_id,value,weight,label,pseudo\n\
+                     flow,0,,0.4,1.0,,false\nflow,1,,0.3,1.0,,false\n";
+        let scada_path = temp_dir.path().join("scada.csv");
+        fs::write(&scada_path, scada).unwrap();
+        let report = check_observability(&network, scada_path.to_str().unwrap(), None).unwrap();
+        assert!(!report.observable);
+        assert_eq!(report.unobservable_buses, vec![3, 4]);
+
+        // Load forecasts 20% high, weighted at 1/100 of a meter
+        let merged_path = temp_dir.path().join("merged.csv");
+        fs::write(
+            &merged_path,
+            format!(
+                "{}injection,,2,-0.12,0.01,,true\ninjection,,3,-0.12,0.01,,true\ninjection,,4,-0.12,0.01,,true\n",
+                scada
+            ),
+        )
+        .unwrap();
+        let report = check_observability(&network, merged_path.to_str().unwrap(), None).unwrap();
+        assert!(report.observable);
+
+        let out = temp_dir.path().join("se.parquet");
+        let state_out = temp_dir.path().join("state.parquet");
+        state_estimation_wls(
+            &network,
+            &GaussSolver,
+            merged_path.to_str().unwrap(),
+            &out,
+            &[],
+            Some(state_out.as_path()),
+            None,
+        )
+        .unwrap();
+
+        let meas_df = read_stage_dataframe(&out, OutputStage::SeWls).unwrap();
+        let pseudo = meas_df.column("pseudo").unwrap().bool().unwrap();
+        assert_eq!(pseudo.into_iter().filter(|p| *p == Some(true)).count(), 3);
+
+        let state_df = read_stage_dataframe(&state_out, OutputStage::SeWls).unwrap();
+        let angles: Vec<f64> = state_df
+            .column("angle_rad")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        let truth = [0.0, -0.4, -0.7, -0.9, -1.0];
+        // Metered buses follow SCADA; forecast-only buses stay within the forecast error
+        for bus in 0..3 {
+            assert!((angles[bus] - truth[bus]).abs() < 0.01, "bus {}", bus);
+        }
+        for bus in 3..5 {
+            assert!((angles[bus] - truth[bus]).abs() < 0.1, "bus {}", bus);
+        }
+        let dependent: Vec<bool> = state_df
+            .column("pseudo_dependent")
+            .unwrap()
+            .bool()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(dependent, vec![false, false, false, true, true]);
+    }
+
     #[test]
     fn load_measurements_rejects_nonpositive_weight() {
         let temp_dir = tempdir().unwrap();
@@ -121,22 +121,22 @@
 use anyhow::{anyhow, Result};
 use faer::prelude::SpSolver;
 use faer::{FaerMat, Mat};
-use gat_core::{BusId, Edge, GenId, Network, Node};
+use gat_core::{BusId, Edge, GenId, Network, Node, SlackStrategy, ZipModel};
 use num_complex::{Complex64, ComplexFloat};
 #[cfg(test)]
 use sprs::{CsMat, TriMat};
-use std::collections::HashMap;
-
-/// Bus type classification for power flow
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum BusType {
-    /// Slack bus: V and θ are fixed, P and Q are calculated
-    Slack,
-    /// PV bus: P and V are specified, Q and θ are calculated
-    PV,
-    /// PQ bus: P and Q are specified, V and θ are calculated
-    PQ,
-}
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+/// Initial Levenberg-Marquardt damping μ, relative to diag(JᵀJ)
+const LM_MU_INITIAL: f64 = 1e-3;
+/// Factor by which μ grows after a rejected step and shrinks after a full step
+const LM_MU_FACTOR: f64 = 10.0;
+/// Damping increases tried per iteration before taking the most damped step
+const LM_MAX_TRIALS: usize = 10;
+
+/// Bus type classification for power flow, shared with the case data
+pub use gat_core::BusType;
 
 /// AC Power Flow solution
 #[derive(Debug, Clone)]
@@ -159,6 +159,11 @@ pub struct AcPowerFlowSolution {
     pub bus_types: HashMap<BusId, BusType>,
     /// Bus reactive power injection (MVAR)
     pub bus_q_injection: HashMap<BusId, f64>,
+    /// Active power each generator picked up to balance losses and mismatch (MW).
+    /// Already included in `generator_p_mw`.
+    pub slack_pickup_mw: HashMap<GenId, f64>,
+    /// Slack bus of each island, as chosen by the solver's slack strategy
+    pub slack_buses: Vec<BusId>,
 }
 
 impl Default for AcPowerFlowSolution {
@@ -173,6 +178,47 @@ impl Default for AcPowerFlowSolution {
             generator_p_mw: HashMap::new(),
             bus_types: HashMap::new(),
             bus_q_injection: HashMap::new(),
+            slack_pickup_mw: HashMap::new(),
+            slack_buses: Vec::new(),
+        }
+    }
+}
+
+/// How Newton-Raphson corrections are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewtonVariant {
+    /// Full Newton step every iteration
+    #[default]
+    Standard,
+    /// Levenberg-Marquardt damping whenever a full step would increase the
+    /// mismatch; more iterations, but robust near the nose of the PV curve
+    LevenbergMarquardt,
+    /// Standard Newton-Raphson, re-solved with damping if it fails
+    Fallback,
+}
+
+impl NewtonVariant {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewtonVariant::Standard => "newton",
+            NewtonVariant::LevenbergMarquardt => "damped",
+            NewtonVariant::Fallback => "auto",
+        }
+    }
+}
+
+impl FromStr for NewtonVariant {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "newton" | "nr" => Ok(NewtonVariant::Standard),
+            "damped" | "lm" => Ok(NewtonVariant::LevenbergMarquardt),
+            "auto" => Ok(NewtonVariant::Fallback),
+            other => Err(anyhow!(
+                "unknown Newton variant '{}'; expected newton, damped, or auto",
+                other
+            )),
         }
     }
 }
@@ -192,6 +238,12 @@ pub struct AcPowerFlowSolver {
     pub pv_voltage_setpoint: f64,
     /// System MVA base for per-unit conversion (default: 100 MVA)
     pub base_mva: f64,
+    /// Generator participation factors for distributed slack (None = single slack bus)
+    pub slack_participation: Option<HashMap<GenId, f64>>,
+    /// Step rule for Newton-Raphson iterations
+    pub newton_variant: NewtonVariant,
+    /// How the slack bus of each island is chosen
+    pub slack_strategy: SlackStrategy,
 }
 
 impl Default for AcPowerFlowSolver {
@@ -210,6 +262,9 @@ impl AcPowerFlowSolver {
             max_q_iterations: 10,
             pv_voltage_setpoint: 1.0,
             base_mva: 100.0,
+            slack_participation: None,
+            newton_variant: NewtonVariant::Standard,
+            slack_strategy: SlackStrategy::default(),
         }
     }
 
@@ -246,8 +301,43 @@ impl AcPowerFlowSolver {
         self
     }
 
+    /// Spread the active power mismatch across generators instead of a single slack bus
+    ///
+    /// Each participating generator picks up `factor / Σ factors` of the total
+    /// imbalance, as AGC does in an EMS. The slack bus still fixes the angle
+    /// reference, but its generator only moves if it is given a factor itself.
+    pub fn with_distributed_slack(mut self, factors: HashMap<GenId, f64>) -> Self {
+        self.slack_participation = Some(factors);
+        self
+    }
+
+    /// Choose the slack bus of each island (default: largest generating capacity)
+    pub fn with_slack_strategy(mut self, strategy: SlackStrategy) -> Self {
+        self.slack_strategy = strategy;
+        self
+    }
+
+    /// Select how Newton-Raphson steps are taken
+    pub fn with_newton_variant(mut self, variant: NewtonVariant) -> Self {
+        self.newton_variant = variant;
+        self
+    }
+
     /// Solve AC power flow for the given network
     pub fn solve(&self, network: &Network) -> Result<AcPowerFlowSolution> {
+        if self.newton_variant != NewtonVariant::Fallback {
+            return self.solve_newton(network);
+        }
+        let standard = self.clone().with_newton_variant(NewtonVariant::Standard);
+        standard.solve_newton(network).or_else(|err| {
+            eprintln!("Newton-Raphson failed ({}), retrying with damping", err);
+            self.clone()
+                .with_newton_variant(NewtonVariant::LevenbergMarquardt)
+                .solve_newton(network)
+        })
+    }
+
+    fn solve_newton(&self, network: &Network) -> Result<AcPowerFlowSolution> {
         // Build network data structures
         let (buses, bus_idx_map) = self.collect_buses(network);
         let generators = self.collect_generators(network);
@@ -260,7 +350,9 @@ impl AcPowerFlowSolver {
         }
 
         // Initialize bus types
-        let mut bus_types = self.classify_buses(&buses, &generators);
+        let slack_buses = network.select_slack(self.slack_strategy)?;
+        let declared_pq = declared_pq_buses(network);
+        let mut bus_types = self.classify_buses(&buses, &generators, &slack_buses, &declared_pq);
 
         // Build map of bus_id -> voltage setpoint from generators
         // Each generator can specify its own voltage setpoint
@@ -295,6 +387,10 @@ impl AcPowerFlowSolver {
         // Compute net injections (P, Q specified)
         let (p_spec, q_spec) =
             self.compute_specified_power(&buses, &bus_idx_map, &generators, &loads);
+        let zip_loads = VoltageDependentLoad::new(&buses, &bus_idx_map, &loads, self.base_mva);
+
+        // Per-bus share of the distributed slack
+        let participation = self.bus_participation(&buses, &bus_idx_map, &generators)?;
 
         // Generator Q limits
         let gen_q_limits: HashMap<GenId, (f64, f64)> = generators
@@ -322,8 +418,10 @@ impl AcPowerFlowSolver {
                 &y_bus,
                 &p_spec,
                 &q_spec,
+                &zip_loads,
                 &mut v_mag,
                 &mut v_ang,
+                participation.as_deref(),
             )?;
 
             if !nr_result.converged {
@@ -352,7 +450,7 @@ impl AcPowerFlowSolver {
             }
 
             // Build partial solution
-            let solution = self.build_solution(
+            let mut solution = self.build_solution(
                 &buses,
                 &bus_idx_map,
                 &bus_types,
@@ -363,6 +461,42 @@ impl AcPowerFlowSolver {
                 &nr_result,
             );
 
+            // Attribute the balancing power to the generators that supplied it
+            let pickup = match &self.slack_participation {
+                Some(factors) => {
+                    let total: f64 = factors.values().sum();
+                    factors
+                        .iter()
+                        .map(|(id, f)| (*id, f / total * nr_result.slack_pu * self.base_mva))
+                        .collect()
+                }
+                None => {
+                    let (p_calc, _) = self.compute_power(&y_bus, &v_mag, &v_ang);
+                    let mut pickup = HashMap::new();
+                    for (i, bus_id) in buses.iter().enumerate() {
+                        if bus_types.get(bus_id) != Some(&BusType::Slack) {
+                            continue;
+                        }
+                        let units: Vec<GenId> = generators
+                            .iter()
+                            .filter(|g| g.status && g.bus == *bus_id)
+                            .map(|g| g.id)
+                            .collect();
+                        let demand = zip_loads.p(i, v_mag[i]);
+                        let imbalance_mw = (p_calc[i] - (p_spec[i] - demand)) * self.base_mva;
+                        for id in &units {
+                            pickup.insert(*id, imbalance_mw / units.len() as f64);
+                        }
+                    }
+                    pickup
+                }
+            };
+            for (id, mw) in &pickup {
+                *solution.generator_p_mw.entry(*id).or_insert(0.0) += mw;
+            }
+            solution.slack_pickup_mw = pickup;
+            solution.slack_buses = slack_buses.clone();
+
             if !self.enforce_q_limits {
                 return Ok(solution);
             }
@@ -397,6 +531,44 @@ impl AcPowerFlowSolver {
         ))
     }
 
+    /// Normalized participation per bus, or `None` for single-slack operation
+    fn bus_participation(
+        &self,
+        buses: &[BusId],
+        bus_idx_map: &HashMap<BusId, usize>,
+        generators: &[GeneratorData],
+    ) -> Result<Option<Vec<f64>>> {
+        let Some(factors) = &self.slack_participation else {
+            return Ok(None);
+        };
+        if factors.values().any(|f| !f.is_finite() || *f < 0.0) {
+            return Err(anyhow!("Slack participation factors must be non-negative"));
+        }
+        let total: f64 = factors.values().sum();
+        if total <= 0.0 {
+            return Err(anyhow!(
+                "Distributed slack needs at least one positive participation factor"
+            ));
+        }
+
+        let mut alpha = vec![0.0; buses.len()];
+        for (id, factor) in factors {
+            let gen = generators
+                .iter()
+                .find(|g| g.id == *id && g.status)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Slack participant {} is not an in-service generator",
+                        id.value()
+                    )
+                })?;
+            if let Some(&idx) = bus_idx_map.get(&gen.bus) {
+                alpha[idx] += factor / total;
+            }
+        }
+        Ok(Some(alpha))
+    }
+
     /// Collect bus data from network
     fn collect_buses(&self, network: &Network) -> (Vec<BusId>, HashMap<BusId, usize>) {
         let mut buses = Vec::new();
@@ -442,6 +614,7 @@ impl AcPowerFlowSolver {
                     bus: load.bus,
                     p_mw: load.active_power.value(),
                     q_mvar: load.reactive_power.value(),
+                    zip: load.zip_model(),
                 });
             }
         }
@@ -487,10 +660,15 @@ impl AcPowerFlowSolver {
     }
 
     /// Classify buses into Slack, PV, or PQ
+    ///
+    /// A generator bus is PV unless the case declares it PQ, in which case its
+    /// units inject their scheduled reactive power instead of holding voltage.
     fn classify_buses(
         &self,
         buses: &[BusId],
         generators: &[GeneratorData],
+        slack_buses: &[BusId],
+        declared_pq: &HashSet<BusId>,
     ) -> HashMap<BusId, BusType> {
         let mut bus_types = HashMap::new();
 
@@ -499,19 +677,15 @@ impl AcPowerFlowSolver {
             bus_types.insert(*bus_id, BusType::PQ);
         }
 
-        // Buses with generators become PV (first generator's bus becomes slack)
-        let mut has_slack = false;
+        // Buses with generators become PV, then each island's slack is marked
         for gen in generators {
-            if !has_slack {
-                bus_types.insert(gen.bus, BusType::Slack);
-                has_slack = true;
-            } else {
-                // Only mark as PV if not already slack
-                if bus_types.get(&gen.bus) != Some(&BusType::Slack) {
-                    bus_types.insert(gen.bus, BusType::PV);
-                }
+            if !declared_pq.contains(&gen.bus) {
+                bus_types.insert(gen.bus, BusType::PV);
             }
         }
+        for bus_id in slack_buses {
+            bus_types.insert(*bus_id, BusType::Slack);
+        }
 
         bus_types
     }
@@ -602,11 +776,12 @@ impl AcPowerFlowSolver {
             }
         }
 
-        // Subtract load (negative injection)
+        // Subtract constant-power load (negative injection); the voltage-dependent
+        // share is handled by Newton-Raphson through `VoltageDependentLoad`
         for load in loads {
             if let Some(&idx) = bus_idx_map.get(&load.bus) {
-                p_spec[idx] -= load.p_mw;
-                q_spec[idx] -= load.q_mvar;
+                p_spec[idx] -= load.p_mw * load.zip.p;
+                q_spec[idx] -= load.q_mvar * load.zip.p;
             }
         }
 
@@ -620,6 +795,17 @@ impl AcPowerFlowSolver {
     }
 
     /// Run Newton-Raphson iteration
+    ///
+    /// With `participation` set, the slack bus P equation is kept and a scalar
+    /// slack λ (p.u.) is added as an unknown, so bus `i` injects
+    /// `p_spec[i] + participation[i] × λ`. Voltage-dependent load in `zip_loads`
+    /// is subtracted from the specified injections at the current voltage, and
+    /// its voltage derivative enters the Jacobian.
+    ///
+    /// With [`NewtonVariant::LevenbergMarquardt`], a full step that would
+    /// increase ‖mismatch‖₂ is replaced by a damped step (see
+    /// [`Self::levenberg_marquardt_step`]).
+    #[allow(clippy::too_many_arguments)]
     fn newton_raphson(
         &self,
         buses: &[BusId],
@@ -628,8 +814,10 @@ impl AcPowerFlowSolver {
         y_bus: &[Vec<(f64, f64)>],
         p_spec: &[f64],
         q_spec: &[f64],
+        zip_loads: &VoltageDependentLoad,
         v_mag: &mut [f64],
         v_ang: &mut [f64],
+        participation: Option<&[f64]>,
     ) -> Result<NRResult> {
         let n = buses.len();
         if n == 0 {
@@ -637,6 +825,7 @@ impl AcPowerFlowSolver {
                 converged: true,
                 iterations: 0,
                 max_mismatch: 0.0,
+                slack_pu: 0.0,
             });
         }
 
@@ -658,75 +847,201 @@ impl AcPowerFlowSolver {
         let n_q = q_buses.len();
         let n_vars = n_p + n_q;
 
-        if n_vars == 0 {
+        // Distributed slack: the slack bus P equation and λ join the system
+        let slack_bus = buses
+            .iter()
+            .position(|id| bus_types.get(id) == Some(&BusType::Slack));
+        let distributed = match (participation, slack_bus) {
+            (Some(alpha), Some(r)) => Some((alpha, r)),
+            (Some(_), None) => return Err(anyhow!("Distributed slack needs a reference bus")),
+            (None, _) => None,
+        };
+        let injection = |i: usize, lambda: f64| match distributed {
+            Some((alpha, _)) => p_spec[i] + alpha[i] * lambda,
+            None => p_spec[i],
+        };
+        let mut lambda = 0.0;
+
+        if n_vars == 0 && distributed.is_none() {
             return Ok(NRResult {
                 converged: true,
                 iterations: 0,
                 max_mismatch: 0.0,
+                slack_pu: 0.0,
             });
         }
 
-        for iter in 0..self.max_iterations {
-            // Compute power mismatches
+        // Mismatch vector at a given state, and its largest entry
+        let residual = |v_mag: &[f64], v_ang: &[f64], lambda: f64| {
             let (p_calc, q_calc) = self.compute_power(y_bus, v_mag, v_ang);
-
-            let mut mismatch = vec![0.0; n_vars];
-            let mut max_mismatch: f64 = 0.0;
+            let mut mismatch = Vec::with_capacity(n_vars + 1);
 
             // ΔP for non-slack buses
-            for (k, &i) in p_buses.iter().enumerate() {
-                mismatch[k] = p_spec[i] - p_calc[i];
-                max_mismatch = max_mismatch.max(mismatch[k].abs());
+            for &i in &p_buses {
+                mismatch.push(injection(i, lambda) - zip_loads.p(i, v_mag[i]) - p_calc[i]);
             }
 
             // ΔQ for PQ buses
+            for &i in &q_buses {
+                mismatch.push(q_spec[i] - zip_loads.q(i, v_mag[i]) - q_calc[i]);
+            }
+
+            // ΔP at the slack bus, which λ now has to balance
+            if let Some((_, r)) = distributed {
+                mismatch.push(injection(r, lambda) - zip_loads.p(r, v_mag[r]) - p_calc[r]);
+            }
+
+            let max_mismatch = mismatch.iter().fold(0.0_f64, |m, d| m.max(d.abs()));
+            (mismatch, max_mismatch)
+        };
+
+        // Apply a correction [Δθ, ΔV, Δλ] to the state
+        let apply_step = |delta: &[f64], v_mag: &mut [f64], v_ang: &mut [f64], lambda: &mut f64| {
+            if distributed.is_some() {
+                *lambda += delta[n_vars];
+            }
+
+            // Update angles for non-slack buses
+            for (k, &i) in p_buses.iter().enumerate() {
+                v_ang[i] += delta[k];
+            }
+
+            // Update voltage magnitudes for PQ buses
             for (k, &i) in q_buses.iter().enumerate() {
-                mismatch[n_p + k] = q_spec[i] - q_calc[i];
-                max_mismatch = max_mismatch.max(mismatch[n_p + k].abs());
+                v_mag[i] += delta[n_p + k];
             }
+        };
+
+        let damped = self.newton_variant == NewtonVariant::LevenbergMarquardt;
+        let mut mu: Option<f64> = None;
+
+        for iter in 0..self.max_iterations {
+            // Compute power mismatches
+            let (mismatch, max_mismatch) = residual(v_mag, v_ang, lambda);
 
             if max_mismatch < self.tolerance {
                 return Ok(NRResult {
                     converged: true,
                     iterations: iter + 1,
                     max_mismatch,
+                    slack_pu: lambda,
                 });
             }
 
             // Build Jacobian matrix
-            let jacobian = self.build_jacobian(y_bus, v_mag, v_ang, &p_buses, &q_buses);
+            let mut jacobian = self.build_jacobian(y_bus, v_mag, v_ang, &p_buses, &q_buses);
 
-            // Solve Jacobian system: J × Δx = mismatch
-            let delta = self.solve_linear_system_faer(&jacobian, &mismatch)?;
+            // Voltage-dependent load moves with the PQ bus voltages
+            for (col, &j) in q_buses.iter().enumerate() {
+                if let Some(row) = p_buses.iter().position(|&i| i == j) {
+                    jacobian[row][n_p + col] += zip_loads.dp_dv(j, v_mag[j]);
+                }
+                jacobian[n_p + col][n_p + col] += zip_loads.dq_dv(j, v_mag[j]);
+            }
 
-            // Update angles for non-slack buses
-            for (k, &i) in p_buses.iter().enumerate() {
-                v_ang[i] += delta[k];
+            // Border it with the slack bus row and the λ column
+            if let Some((alpha, r)) = distributed {
+                for (row, &i) in p_buses.iter().enumerate() {
+                    jacobian[row].push(-alpha[i]);
+                }
+                for row in jacobian.iter_mut().skip(n_p) {
+                    row.push(0.0);
+                }
+                let mut slack_row: Vec<f64> = p_buses
+                    .iter()
+                    .map(|&j| self.dp_dtheta(y_bus, v_mag, v_ang, r, j))
+                    .collect();
+                slack_row.extend(
+                    q_buses
+                        .iter()
+                        .map(|&j| self.dp_dv(y_bus, v_mag, v_ang, r, j)),
+                );
+                slack_row.push(-alpha[r]);
+                jacobian.push(slack_row);
             }
 
-            // Update voltage magnitudes for PQ buses
-            for (k, &i) in q_buses.iter().enumerate() {
-                v_mag[i] += delta[n_p + k];
+            // Solve Jacobian system: J × Δx = mismatch
+            let mut delta = self.solve_linear_system_faer(&jacobian, &mismatch)?;
+
+            // Keep the full Newton step only if it reduces ‖mismatch‖₂
+            if damped {
+                let norm_sq = |m: &[f64]| m.iter().map(|d| d * d).sum::<f64>();
+                let base_norm = norm_sq(&mismatch);
+                let improves = |delta: &[f64]| {
+                    let (mut v_trial, mut a_trial, mut l_trial) =
+                        (v_mag.to_vec(), v_ang.to_vec(), lambda);
+                    apply_step(delta, &mut v_trial, &mut a_trial, &mut l_trial);
+                    norm_sq(&residual(&v_trial, &a_trial, l_trial).0) < base_norm
+                };
+
+                if improves(&delta) {
+                    mu = mu.map(|m| m / LM_MU_FACTOR);
+                } else {
+                    delta =
+                        self.levenberg_marquardt_step(&jacobian, &mismatch, &mut mu, improves)?;
+                }
             }
+
+            apply_step(&delta, v_mag, v_ang, &mut lambda);
         }
 
         // Compute final mismatch for reporting
-        let (p_calc, q_calc) = self.compute_power(y_bus, v_mag, v_ang);
-        let mut max_mismatch: f64 = 0.0;
-        for &i in &p_buses {
-            max_mismatch = max_mismatch.max((p_spec[i] - p_calc[i]).abs());
-        }
-        for &i in &q_buses {
-            max_mismatch = max_mismatch.max((q_spec[i] - q_calc[i]).abs());
-        }
+        let (_, max_mismatch) = residual(v_mag, v_ang, lambda);
 
         Ok(NRResult {
             converged: false,
             iterations: self.max_iterations,
             max_mismatch,
+            slack_pu: lambda,
         })
     }
 
+    /// Levenberg-Marquardt step for `jacobian × Δx = mismatch`
+    ///
+    /// Solves `(JᵀJ + μ·diag(JᵀJ)) Δx = Jᵀ·mismatch`, raising μ until `accept`
+    /// reports a reduced mismatch. Large μ shrinks the step toward a short
+    /// gradient-descent step, so a reducing step exists unless the state is
+    /// already at a least-squares minimum; after [`LM_MAX_TRIALS`] the most
+    /// damped step is returned.
+    fn levenberg_marquardt_step(
+        &self,
+        jacobian: &[Vec<f64>],
+        mismatch: &[f64],
+        mu: &mut Option<f64>,
+        accept: impl Fn(&[f64]) -> bool,
+    ) -> Result<Vec<f64>> {
+        let m = mismatch.len();
+        let mut jtj = vec![vec![0.0; m]; m];
+        let mut jtr = vec![0.0; m];
+        for (row, &r) in jacobian.iter().zip(mismatch) {
+            for (i, &a) in row.iter().enumerate() {
+                if a == 0.0 {
+                    continue;
+                }
+                jtr[i] += a * r;
+                for (j, &b) in row.iter().enumerate() {
+                    jtj[i][j] += a * b;
+                }
+            }
+        }
+
+        let mut current = mu.unwrap_or(LM_MU_INITIAL);
+        let mut step = Vec::new();
+        for _ in 0..LM_MAX_TRIALS {
+            let mut damped = jtj.clone();
+            for (i, row) in damped.iter_mut().enumerate() {
+                row[i] += current * jtj[i][i].max(f64::EPSILON);
+            }
+            step = self.solve_linear_system_faer(&damped, &jtr)?;
+            if accept(&step) {
+                break;
+            }
+            current *= LM_MU_FACTOR;
+        }
+        *mu = Some(current);
+        Ok(step)
+    }
+
     /// Compute P and Q injections from current voltage state
     fn compute_power(
         &self,
@@ -1085,10 +1400,12 @@ impl AcPowerFlowSolver {
     ) -> HashMap<GenId, f64> {
         let (_, q_calc) = self.compute_power(y_bus, v_mag, v_ang);
 
-        // Build load Q at each bus
+        // Build load Q at each bus, at the solved voltage
         let mut load_q: HashMap<BusId, f64> = HashMap::new();
         for load in loads {
-            *load_q.entry(load.bus).or_insert(0.0) += load.q_mvar;
+            if let Some(&idx) = bus_idx_map.get(&load.bus) {
+                *load_q.entry(load.bus).or_insert(0.0) += load.at_voltage(v_mag[idx]).1;
+            }
         }
 
         // For each generator, Q = Q_calc_at_bus + Q_load_at_bus
@@ -1130,10 +1447,11 @@ impl AcPowerFlowSolver {
     ) -> bool {
         let mut switched = false;
 
-        // Build load Q at each bus for updating q_spec
+        // Build constant-power load Q at each bus for updating q_spec (the
+        // voltage-dependent share stays with Newton-Raphson)
         let mut load_q: HashMap<BusId, f64> = HashMap::new();
         for load in loads {
-            *load_q.entry(load.bus).or_insert(0.0) += load.q_mvar;
+            *load_q.entry(load.bus).or_insert(0.0) += load.q_mvar * load.zip.p;
         }
 
         for gen in generators {
@@ -1232,11 +1550,25 @@ impl AcPowerFlowSolver {
     }
 }
 
+/// Buses the case explicitly classifies as PQ
+pub(crate) fn declared_pq_buses(network: &Network) -> HashSet<BusId> {
+    network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) if bus.bus_type == Some(gat_core::BusType::PQ) => Some(bus.id),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Newton-Raphson iteration result
 struct NRResult {
     converged: bool,
     iterations: usize,
     max_mismatch: f64,
+    /// Distributed slack λ in p.u. (zero with a single slack bus)
+    slack_pu: f64,
 }
 
 /// Internal generator data structure
@@ -1257,8 +1589,69 @@ struct GeneratorData {
 #[derive(Debug, Clone)]
 struct LoadData {
     bus: BusId,
+    /// Demand at 1.0 p.u. voltage
     p_mw: f64,
     q_mvar: f64,
+    zip: ZipModel,
+}
+
+impl LoadData {
+    /// Demand (MW, Mvar) at `v_pu`
+    fn at_voltage(&self, v_pu: f64) -> (f64, f64) {
+        let scale = self.zip.scale(v_pu);
+        (self.p_mw * scale, self.q_mvar * scale)
+    }
+}
+
+/// Constant-impedance and constant-current load per bus (p.u. at 1.0 p.u. voltage)
+struct VoltageDependentLoad {
+    p_z: Vec<f64>,
+    p_i: Vec<f64>,
+    q_z: Vec<f64>,
+    q_i: Vec<f64>,
+}
+
+impl VoltageDependentLoad {
+    fn new(
+        buses: &[BusId],
+        bus_idx_map: &HashMap<BusId, usize>,
+        loads: &[LoadData],
+        base_mva: f64,
+    ) -> Self {
+        let n = buses.len();
+        let mut zip_loads = Self {
+            p_z: vec![0.0; n],
+            p_i: vec![0.0; n],
+            q_z: vec![0.0; n],
+            q_i: vec![0.0; n],
+        };
+        for load in loads {
+            if let Some(&idx) = bus_idx_map.get(&load.bus) {
+                zip_loads.p_z[idx] += load.p_mw * load.zip.z / base_mva;
+                zip_loads.p_i[idx] += load.p_mw * load.zip.i / base_mva;
+                zip_loads.q_z[idx] += load.q_mvar * load.zip.z / base_mva;
+                zip_loads.q_i[idx] += load.q_mvar * load.zip.i / base_mva;
+            }
+        }
+        zip_loads
+    }
+
+    /// Voltage-dependent P demand at bus `i` (p.u.)
+    fn p(&self, i: usize, v: f64) -> f64 {
+        self.p_z[i] * v * v + self.p_i[i] * v
+    }
+
+    fn q(&self, i: usize, v: f64) -> f64 {
+        self.q_z[i] * v * v + self.q_i[i] * v
+    }
+
+    fn dp_dv(&self, i: usize, v: f64) -> f64 {
+        2.0 * self.p_z[i] * v + self.p_i[i]
+    }
+
+    fn dq_dv(&self, i: usize, v: f64) -> f64 {
+        2.0 * self.q_z[i] * v + self.q_i[i]
+    }
 }
 
 /// Internal branch data structure
@@ -1410,6 +1803,7 @@ mod sparse_tests {
             bus: BusId::new(1),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(10.0),
+            zip: None,
         }));
 
         let solver = AcPowerFlowSolver::new()
@@ -1535,6 +1929,7 @@ mod sparse_tests {
             bus: BusId::new(1),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(30.0), // Inductive load
+            zip: None,
         }));
 
         // Solve without shunt
@@ -1578,4 +1973,158 @@ mod sparse_tests {
         assert!(sol_no_shunt.converged);
         assert!(sol_with_shunt.converged);
     }
+
+    /// Constant-impedance load draws less than constant-power load when the
+    /// voltage sags, and an explicit constant-power model matches the default
+    #[test]
+    fn test_zip_load_follows_voltage() {
+        use gat_core::{Branch, BranchId, Bus, Gen, Load, LoadId};
+
+        let solve = |zip: Option<ZipModel>| {
+            let mut network = Network::new();
+            let bus1 = network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(0),
+                name: "bus1".to_string(),
+                base_kv: gat_core::Kilovolts(100.0),
+                ..Bus::default()
+            }));
+            let bus2 = network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(1),
+                name: "bus2".to_string(),
+                base_kv: gat_core::Kilovolts(100.0),
+                ..Bus::default()
+            }));
+            // Weak line so the load bus sags well below 1.0 p.u.
+            network.graph.add_edge(
+                bus1,
+                bus2,
+                Edge::Branch(Branch {
+                    id: BranchId::new(0),
+                    name: "line".to_string(),
+                    from_bus: BusId::new(0),
+                    to_bus: BusId::new(1),
+                    resistance: 0.02,
+                    reactance: 0.2,
+                    ..Branch::default()
+                }),
+            );
+            network.graph.add_node(Node::Gen(Gen::new(
+                GenId::new(0),
+                "gen1".to_string(),
+                BusId::new(0),
+            )));
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(0),
+                name: "load".to_string(),
+                bus: BusId::new(1),
+                active_power: gat_core::Megawatts(100.0),
+                reactive_power: gat_core::Megavars(40.0),
+                zip,
+            }));
+            AcPowerFlowSolver::new()
+                .with_tolerance(1e-8)
+                .with_max_iterations(30)
+                .solve(&network)
+                .expect("should converge")
+        };
+
+        let constant_power = solve(None);
+        let explicit = solve(Some(ZipModel::CONSTANT_POWER));
+        let impedance = solve(Some(ZipModel::CONSTANT_IMPEDANCE));
+
+        let v_p = constant_power.bus_voltage_magnitude[&BusId::new(1)];
+        let v_z = impedance.bus_voltage_magnitude[&BusId::new(1)];
+        assert!(v_p < 0.95, "load bus should sag, got {:.4}", v_p);
+        assert!(v_z > v_p, "lighter load should sag less");
+
+        let p_gen = |s: &AcPowerFlowSolution| s.generator_p_mw[&GenId::new(0)];
+        assert!((p_gen(&constant_power) - p_gen(&explicit)).abs() < 1e-6);
+        assert!((v_p - explicit.bus_voltage_magnitude[&BusId::new(1)]).abs() < 1e-9);
+
+        // Constant-impedance demand is 100 MW × V², plus line losses
+        assert!(p_gen(&impedance) < p_gen(&constant_power) - 5.0);
+        assert!(p_gen(&impedance) > 100.0 * v_z * v_z);
+    }
+
+    /// Heavily loaded buses propped up by large capacitor banks: full Newton
+    /// steps overshoot and wander, while damped steps converge to the
+    /// high-voltage solution
+    #[test]
+    fn test_damped_newton_converges_where_standard_diverges() {
+        use gat_core::{Branch, BranchId, Bus, Gen, Load, LoadId, Shunt, ShuntId};
+
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: gat_core::Kilovolts(138.0),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (k, (r, x)) in [(0.02, 0.1), (0.01, 0.2)].into_iter().enumerate() {
+            network.graph.add_edge(
+                buses[k],
+                buses[k + 1],
+                Edge::Branch(Branch {
+                    id: BranchId::new(k),
+                    name: format!("line{}", k),
+                    from_bus: BusId::new(k),
+                    to_bus: BusId::new(k + 1),
+                    resistance: r,
+                    reactance: x,
+                    charging_b: gat_core::PerUnit(0.3),
+                    ..Branch::default()
+                }),
+            );
+        }
+        network.graph.add_node(Node::Gen(Gen::new(
+            GenId::new(0),
+            "gen".to_string(),
+            BusId::new(0),
+        )));
+        for (k, (p, q, bs)) in [(47.0, 59.0, 1.0), (203.0, 82.0, 1.5)]
+            .into_iter()
+            .enumerate()
+        {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(k),
+                name: format!("load{}", k),
+                bus: BusId::new(k + 1),
+                active_power: gat_core::Megawatts(p),
+                reactive_power: gat_core::Megavars(q),
+                zip: None,
+            }));
+            network.graph.add_node(Node::Shunt(Shunt {
+                id: ShuntId::new(k),
+                name: format!("cap{}", k),
+                bus: BusId::new(k + 1),
+                gs_pu: 0.0,
+                bs_pu: bs,
+                status: true,
+            }));
+        }
+
+        let solver = AcPowerFlowSolver::new()
+            .with_tolerance(1e-8)
+            .with_max_iterations(20);
+        assert!(solver.solve(&network).is_err());
+
+        let damped = solver
+            .clone()
+            .with_newton_variant(NewtonVariant::LevenbergMarquardt)
+            .solve(&network)
+            .expect("damped Newton should converge");
+        assert!(damped.converged);
+        assert!((damped.bus_voltage_magnitude[&BusId::new(1)] - 0.9443).abs() < 1e-3);
+        assert!((damped.bus_voltage_magnitude[&BusId::new(2)] - 0.9801).abs() < 1e-3);
+
+        let fallback = solver
+            .with_newton_variant(NewtonVariant::Fallback)
+            .solve(&network)
+            .expect("fallback should converge");
+        assert_eq!(fallback.iterations, damped.iterations);
+    }
 }
@@ -0,0 +1,256 @@
+//! AGC (governor/participation-factor) response to generator outages.
+//!
+//! A plain DC contingency re-solve books every lost MW on the slack bus. In
+//! practice the remaining units pick up the deficit according to their AGC
+//! participation factors, each limited by its spinning reserve (`pmax` minus
+//! current output). [`apply_agc_response`] performs that redistribution on the
+//! network so the post-contingency flow can be re-solved with a realistic
+//! dispatch.
+//!
+//! ## Algorithm
+//!
+//! 1. Take the outaged unit out of service and record its output as the deficit
+//! 2. Share the deficit among participating units in proportion to their factors
+//! 3. Clamp units whose share exceeds their reserve, then re-share the
+//!    remainder among the units that still have headroom
+//! 4. Whatever cannot be placed is reported as the residual
+
+use anyhow::{anyhow, Result};
+use gat_core::{GenId, Megawatts, Network, Node};
+use std::collections::HashMap;
+
+/// Deficit below which the redistribution is considered complete (MW)
+const AGC_TOLERANCE_MW: f64 = 1e-9;
+
+/// Outcome of an AGC redistribution after a generator outage.
+#[derive(Debug, Clone, Default)]
+pub struct AgcResponse {
+    /// Output of the outaged unit before the contingency (MW)
+    pub lost_mw: f64,
+    /// Additional output picked up by each participating unit (MW)
+    pub pickup_mw: HashMap<GenId, f64>,
+    /// Units that ran out of reserve and were held at `pmax`
+    pub limited_units: Vec<GenId>,
+    /// Deficit that could not be placed within reserve limits (MW)
+    pub residual_mw: f64,
+}
+
+impl AgcResponse {
+    /// Total MW picked up by the participating units.
+    pub fn total_pickup_mw(&self) -> f64 {
+        self.pickup_mw.values().sum()
+    }
+
+    /// True when reserves covered the whole deficit.
+    pub fn fully_covered(&self) -> bool {
+        self.residual_mw <= AGC_TOLERANCE_MW
+    }
+}
+
+/// Outage `lost_gen` and redistribute its output by participation factor.
+///
+/// The outaged unit is set out of service with zero output, and each unit in
+/// `participation` (in service, positive factor) has its `active_power` raised
+/// by its share of the deficit, capped at `pmax`. Units absent from the map do
+/// not respond. Re-solve the contingency flow on the modified network.
+pub fn apply_agc_response(
+    network: &mut Network,
+    lost_gen: GenId,
+    participation: &HashMap<GenId, f64>,
+) -> Result<AgcResponse> {
+    for (id, factor) in participation {
+        if !factor.is_finite() || *factor < 0.0 {
+            return Err(anyhow!(
+                "participation factor for generator {} must be finite and non-negative, got {}",
+                id.value(),
+                factor
+            ));
+        }
+    }
+
+    let mut lost_mw = None;
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if gen.id == lost_gen {
+                lost_mw = Some(if gen.status {
+                    gen.active_power.value()
+                } else {
+                    0.0
+                });
+                gen.status = false;
+                gen.active_power = Megawatts(0.0);
+            }
+        }
+    }
+    let lost_mw =
+        lost_mw.ok_or_else(|| anyhow!("generator {} not found in network", lost_gen.value()))?;
+
+    // (id, factor, reserve) for every unit able to respond
+    let mut responders: Vec<(GenId, f64, f64)> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Gen(gen) if gen.status => {
+                let factor = participation.get(&gen.id).copied().unwrap_or(0.0);
+                let reserve = (gen.pmax.value() - gen.active_power.value()).max(0.0);
+                (factor > 0.0).then_some((gen.id, factor, reserve))
+            }
+            _ => None,
+        })
+        .collect();
+    responders.sort_by_key(|(id, _, _)| id.value());
+
+    let mut response = AgcResponse {
+        lost_mw,
+        ..AgcResponse::default()
+    };
+    let mut remaining = lost_mw.max(0.0);
+    while remaining > AGC_TOLERANCE_MW && !responders.is_empty() {
+        let total_factor: f64 = responders.iter().map(|(_, f, _)| f).sum();
+        let clamped: Vec<usize> = responders
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, f, reserve))| remaining * f / total_factor >= *reserve)
+            .map(|(idx, _)| idx)
+            .collect();
+
+        if clamped.is_empty() {
+            for (id, factor, _) in &responders {
+                *response.pickup_mw.entry(*id).or_insert(0.0) += remaining * factor / total_factor;
+            }
+            remaining = 0.0;
+            break;
+        }
+
+        // Hold the clamped units at pmax and re-share what is left
+        for &idx in clamped.iter().rev() {
+            let (id, _, reserve) = responders.remove(idx);
+            *response.pickup_mw.entry(id).or_insert(0.0) += reserve;
+            response.limited_units.push(id);
+            remaining -= reserve;
+        }
+    }
+    response.residual_mw = remaining.max(0.0);
+    response.limited_units.sort_by_key(|id| id.value());
+
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if let Some(pickup) = response.pickup_mw.get(&gen.id) {
+                gen.active_power = Megawatts(gen.active_power.value() + pickup);
+            }
+        }
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::power_flow::dc_power_flow_angles;
+    use gat_core::{Branch, BranchId, Bus, BusId, Edge, Gen, Load, LoadId, Megavars};
+
+    /// Three buses in a ring, one unit per bus, 150 MW of load at bus 3.
+    fn three_unit_network(pmax: [f64; 3]) -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("Bus {}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (i, (&output, &limit)) in [50.0, 40.0, 60.0].iter().zip(&pmax).enumerate() {
+            let mut gen = Gen::new(
+                GenId::new(i + 1),
+                format!("Gen {}", i + 1),
+                BusId::new(i + 1),
+            )
+            .with_p_limits(0.0, limit);
+            gen.active_power = Megawatts(output);
+            network.graph.add_node(Node::Gen(gen));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load 3".to_string(),
+            bus: BusId::new(3),
+            active_power: Megawatts(150.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        for (k, (from, to)) in [(0, 1), (1, 2), (0, 2)].into_iter().enumerate() {
+            network.graph.add_edge(
+                buses[from],
+                buses[to],
+                Edge::Branch(Branch::new(
+                    BranchId::new(k + 1),
+                    format!("Line {}-{}", from + 1, to + 1),
+                    BusId::new(from + 1),
+                    BusId::new(to + 1),
+                    0.01,
+                    0.1,
+                )),
+            );
+        }
+        network
+    }
+
+    fn equal_participation() -> HashMap<GenId, f64> {
+        HashMap::from([(GenId::new(1), 0.5), (GenId::new(2), 0.5)])
+    }
+
+    fn dispatch(network: &Network) -> HashMap<GenId, f64> {
+        network
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Gen(gen) => Some((gen.id, gen.active_power.value())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_equal_participation_splits_lost_output() {
+        let mut network = three_unit_network([200.0, 200.0, 200.0]);
+        let response =
+            apply_agc_response(&mut network, GenId::new(3), &equal_participation()).unwrap();
+
+        assert_eq!(response.lost_mw, 60.0);
+        assert!((response.pickup_mw[&GenId::new(1)] - 30.0).abs() < 1e-9);
+        assert!((response.pickup_mw[&GenId::new(2)] - 30.0).abs() < 1e-9);
+        assert!(response.fully_covered());
+        assert!(response.limited_units.is_empty());
+
+        let dispatch = dispatch(&network);
+        assert!((dispatch[&GenId::new(1)] - 80.0).abs() < 1e-9);
+        assert!((dispatch[&GenId::new(2)] - 70.0).abs() < 1e-9);
+        assert_eq!(dispatch[&GenId::new(3)], 0.0);
+
+        // Post-contingency flow re-solves on the redispatched network
+        let angles = dc_power_flow_angles(&network).unwrap();
+        assert_eq!(angles.len(), 3);
+    }
+
+    #[test]
+    fn test_reserve_limit_reshares_and_reports_residual() {
+        // Gen 1 has 10 MW of reserve; gen 2 takes the rest up to its own limit
+        let mut network = three_unit_network([60.0, 80.0, 200.0]);
+        let response =
+            apply_agc_response(&mut network, GenId::new(3), &equal_participation()).unwrap();
+
+        assert!((response.pickup_mw[&GenId::new(1)] - 10.0).abs() < 1e-9);
+        assert!((response.pickup_mw[&GenId::new(2)] - 40.0).abs() < 1e-9);
+        assert_eq!(response.limited_units, vec![GenId::new(1), GenId::new(2)]);
+        assert!((response.residual_mw - 10.0).abs() < 1e-9);
+        assert!(!response.fully_covered());
+    }
+
+    #[test]
+    fn test_unknown_generator_is_an_error() {
+        let mut network = three_unit_network([200.0, 200.0, 200.0]);
+        assert!(apply_agc_response(&mut network, GenId::new(9), &equal_participation()).is_err());
+    }
+}
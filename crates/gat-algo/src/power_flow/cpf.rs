@@ -349,6 +349,7 @@ mod tests {
             bus: BusId::new(1),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(20.0),
+            zip: None,
         }));
 
         let solver = CpfSolver::new()
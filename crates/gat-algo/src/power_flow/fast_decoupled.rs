@@ -18,13 +18,13 @@
 //!   IEEE Trans. PAS, 93(3), 859-869
 //!   DOI: [10.1109/TPAS.1974.293985](https://doi.org/10.1109/TPAS.1974.293985)
 
-use super::ac_pf::{AcPowerFlowSolution, BusType};
+use super::ac_pf::{declared_pq_buses, AcPowerFlowSolution, BusType};
 use anyhow::{anyhow, Result};
 use faer::prelude::*;
 use faer::Mat;
-use gat_core::{BusId, Edge, GenId, Network, Node};
+use gat_core::{BusId, Edge, GenId, Network, Node, SlackStrategy};
 use num_complex::ComplexFloat;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Build the B' (B-prime) matrix for the P-θ subproblem.
 ///
@@ -174,6 +174,8 @@ pub struct FastDecoupledSolver {
     pub max_iterations: usize,
     /// System MVA base
     pub base_mva: f64,
+    /// How the slack bus of each island is chosen
+    pub slack_strategy: SlackStrategy,
 }
 
 impl Default for FastDecoupledSolver {
@@ -188,6 +190,7 @@ impl FastDecoupledSolver {
             tolerance: 1e-6,
             max_iterations: 50,
             base_mva: 100.0,
+            slack_strategy: SlackStrategy::default(),
         }
     }
 
@@ -206,6 +209,11 @@ impl FastDecoupledSolver {
         self
     }
 
+    pub fn with_slack_strategy(mut self, strategy: SlackStrategy) -> Self {
+        self.slack_strategy = strategy;
+        self
+    }
+
     pub fn solve(&self, network: &Network) -> Result<AcPowerFlowSolution> {
         // Collect network data
         let (buses, bus_idx_map) = self.collect_buses(network);
@@ -219,7 +227,9 @@ impl FastDecoupledSolver {
         }
 
         let n = buses.len();
-        let bus_types = self.classify_buses(&buses, &generators);
+        let slack_buses = network.select_slack(self.slack_strategy)?;
+        let declared_pq = declared_pq_buses(network);
+        let bus_types = self.classify_buses(&buses, &generators, &slack_buses, &declared_pq);
 
         // Build constant B' and B'' matrices for the decoupled system
         let b_prime = build_b_prime_matrix(network);
@@ -332,6 +342,8 @@ impl FastDecoupledSolver {
             generator_q_mvar,
             generator_p_mw,
             bus_q_injection: HashMap::new(),
+            slack_pickup_mw: HashMap::new(),
+            slack_buses,
         })
     }
 
@@ -385,20 +397,21 @@ impl FastDecoupledSolver {
         &self,
         buses: &[BusId],
         generators: &[GeneratorData],
+        slack_buses: &[BusId],
+        declared_pq: &HashSet<BusId>,
     ) -> HashMap<BusId, BusType> {
         let mut types = HashMap::new();
         for &id in buses {
             types.insert(id, BusType::PQ);
         }
-        let mut has_slack = false;
         for gen in generators {
-            if !has_slack {
-                types.insert(gen.bus, BusType::Slack);
-                has_slack = true;
-            } else if types.get(&gen.bus) != Some(&BusType::Slack) {
+            if !declared_pq.contains(&gen.bus) {
                 types.insert(gen.bus, BusType::PV);
             }
         }
+        for &id in slack_buses {
+            types.insert(id, BusType::Slack);
+        }
         types
     }
 
@@ -759,6 +772,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(20.0),
+            zip: None,
         }));
 
         let solver = FastDecoupledSolver::new()
@@ -0,0 +1,399 @@
+//! Allocation of network losses to buses for settlement.
+//!
+//! Total losses are computed from a solved AC power flow as the I²R loss on
+//! the series impedance of every in-service branch (line charging and shunt
+//! conductance are not counted). [`allocate_losses`] then assigns that total
+//! back to buses with one of two methods:
+//!
+//! - **Pro-rata**: the "load pays" convention. Buses with a net withdrawal
+//!   share the losses in proportion to their withdrawal; net injectors are
+//!   allocated nothing.
+//! - **Marginal**: each bus is charged `ITL_i · P_i`, where the incremental
+//!   transmission loss factor `ITL_i = ∂P_loss/∂P_i` is evaluated at the
+//!   solved operating point with the slack bus absorbing the change. Marginal
+//!   charges over-recover (roughly twice the losses for a quadratic loss
+//!   curve), so they are scaled to sum to the total. Factors are slack-bus
+//!   dependent; a bus that relieves losses receives a negative share.
+//!
+//! Loss factors come from the `∂P/∂θ` block of the power-flow Jacobian at
+//! the solution (voltage magnitudes held fixed), which is the decoupled
+//! approximation used by most market loss-factor studies. Bus injections and
+//! the Jacobian use the shared Y-bus from [`gat_core::solver::build_ybus`].
+
+use super::ac_pf::{AcPowerFlowSolution, BusType};
+use anyhow::{anyhow, Result};
+use faer::prelude::*;
+use faer::Mat;
+use gat_core::solver::{build_ybus, SparseMatrix};
+use gat_core::{BranchId, BusId, Edge, Network};
+use num_complex::Complex64;
+use std::collections::HashMap;
+
+/// System MVA base used by the AC power flow solver
+const BASE_MVA: f64 = 100.0;
+
+/// Marginal charges summing to less than this (MW) cannot be normalized
+const MIN_MARGINAL_TOTAL_MW: f64 = 1e-9;
+
+/// How total losses are assigned to buses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LossAllocationMethod {
+    /// Withdrawing buses pay in proportion to their net withdrawal
+    #[default]
+    ProRata,
+    /// Normalized incremental transmission loss (ITL) charges
+    Marginal,
+}
+
+/// Result of a loss allocation.
+#[derive(Debug, Clone)]
+pub struct LossAllocation {
+    /// Method used to produce `bus_share_mw`
+    pub method: LossAllocationMethod,
+    /// Sum of branch I²R losses (MW)
+    pub total_losses_mw: f64,
+    /// I²R loss on each in-service branch (MW)
+    pub branch_losses_mw: HashMap<BranchId, f64>,
+    /// Net active power injection at each bus from the solved flows (MW)
+    pub bus_injection_mw: HashMap<BusId, f64>,
+    /// Losses allocated to each bus (MW); sums to `total_losses_mw`
+    pub bus_share_mw: HashMap<BusId, f64>,
+    /// Incremental loss factors `∂P_loss/∂P_i` (marginal method only)
+    pub loss_factors: HashMap<BusId, f64>,
+}
+
+struct LossBranch {
+    id: BranchId,
+    from: usize,
+    to: usize,
+    y_series: Complex64,
+    tap: Complex64,
+}
+
+/// Allocate the losses of a solved AC power flow to buses.
+///
+/// `result` must come from solving `network` (bus voltages are looked up by
+/// `BusId`). The marginal method needs the slack bus recorded in
+/// `result.bus_types`.
+pub fn allocate_losses(
+    network: &Network,
+    result: &AcPowerFlowSolution,
+    method: LossAllocationMethod,
+) -> Result<LossAllocation> {
+    if !result.converged {
+        return Err(anyhow!(
+            "cannot allocate losses of an unconverged power flow"
+        ));
+    }
+
+    let (y_bus, buses) = build_ybus(network);
+    let bus_index: HashMap<BusId, usize> =
+        buses.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+    let n = buses.len();
+    if n == 0 {
+        return Err(anyhow!("Network has no buses"));
+    }
+
+    let mut voltages = Vec::with_capacity(n);
+    for bus in &buses {
+        let (Some(&v), Some(&a)) = (
+            result.bus_voltage_magnitude.get(bus),
+            result.bus_voltage_angle.get(bus),
+        ) else {
+            return Err(anyhow!(
+                "power flow result has no voltage for bus {}",
+                bus.value()
+            ));
+        };
+        voltages.push(Complex64::from_polar(v, a));
+    }
+
+    let mut branches = Vec::new();
+    for edge in network.graph.edge_weights() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        if !branch.status {
+            continue;
+        }
+        let (Some(&from), Some(&to)) = (
+            bus_index.get(&branch.from_bus),
+            bus_index.get(&branch.to_bus),
+        ) else {
+            continue;
+        };
+        // Zero-impedance branches are skipped by the solver's Y-bus as well
+        let z = Complex64::new(branch.resistance, branch.reactance);
+        if z.norm_sqr() < 1e-12 {
+            continue;
+        }
+        let tap_mag = if branch.tap_ratio > 0.0 {
+            branch.tap_ratio
+        } else {
+            1.0
+        };
+        branches.push(LossBranch {
+            id: branch.id,
+            from,
+            to,
+            y_series: z.inv(),
+            tap: Complex64::from_polar(tap_mag, branch.phase_shift.value()),
+        });
+    }
+
+    let mut branch_losses_mw = HashMap::new();
+    for br in &branches {
+        let current = (voltages[br.from] / br.tap - voltages[br.to]) * br.y_series;
+        let loss = current.norm_sqr() * br.y_series.inv().re * BASE_MVA;
+        branch_losses_mw.insert(br.id, loss);
+    }
+    let total_losses_mw: f64 = branch_losses_mw.values().sum();
+
+    let injections: Vec<f64> = y_bus
+        .outer_iterator()
+        .enumerate()
+        .map(|(i, row)| {
+            let current: Complex64 = row.iter().map(|(j, y)| y * voltages[j]).sum();
+            (voltages[i] * current.conj()).re * BASE_MVA
+        })
+        .collect();
+
+    let mut allocation = LossAllocation {
+        method,
+        total_losses_mw,
+        branch_losses_mw,
+        bus_injection_mw: buses.iter().copied().zip(injections.clone()).collect(),
+        bus_share_mw: HashMap::new(),
+        loss_factors: HashMap::new(),
+    };
+
+    let shares = match method {
+        LossAllocationMethod::ProRata => {
+            let withdrawal: Vec<f64> = injections.iter().map(|p| (-p).max(0.0)).collect();
+            let total_withdrawal: f64 = withdrawal.iter().sum();
+            if total_withdrawal <= 0.0 {
+                return Err(anyhow!("no bus withdraws power; nothing to allocate to"));
+            }
+            withdrawal
+                .iter()
+                .map(|w| total_losses_mw * w / total_withdrawal)
+                .collect::<Vec<_>>()
+        }
+        LossAllocationMethod::Marginal => {
+            let slack = buses
+                .iter()
+                .position(|b| result.bus_types.get(b) == Some(&BusType::Slack))
+                .ok_or_else(|| anyhow!("power flow result has no slack bus"))?;
+            let itl = loss_factors(&y_bus, &voltages, slack)?;
+            let charges: Vec<f64> = itl.iter().zip(&injections).map(|(f, p)| f * p).collect();
+            let total_charge: f64 = charges.iter().sum();
+            if total_charge.abs() < MIN_MARGINAL_TOTAL_MW {
+                return Err(anyhow!(
+                    "marginal loss charges sum to zero; cannot normalize"
+                ));
+            }
+            allocation.loss_factors = buses.iter().copied().zip(itl).collect();
+            charges
+                .iter()
+                .map(|c| total_losses_mw * c / total_charge)
+                .collect()
+        }
+    };
+    allocation.bus_share_mw = buses.iter().copied().zip(shares).collect();
+
+    Ok(allocation)
+}
+
+/// Incremental loss factors `∂P_loss/∂P_i` with `slack` absorbing the change.
+///
+/// With `P_loss = Σ P_i(θ)`, the chain rule gives `Hᵀ λ = ∂P_loss/∂θ` over the
+/// non-slack buses, where `H = ∂P/∂θ`. The slack factor is zero by definition.
+fn loss_factors(y_bus: &SparseMatrix, voltages: &[Complex64], slack: usize) -> Result<Vec<f64>> {
+    let n = voltages.len();
+    let mut factors = vec![0.0; n];
+    if n == 1 {
+        return Ok(factors);
+    }
+
+    // H_ij = ∂P_i/∂θ_j = Re(-j·V_i·conj(Y_ij·V_j)) off the diagonal,
+    // and each row sums to zero
+    let mut h = vec![vec![0.0; n]; n];
+    for (i, row) in y_bus.outer_iterator().enumerate() {
+        for (j, &y_ij) in row.iter() {
+            if i != j {
+                let term = voltages[i] * (y_ij * voltages[j]).conj();
+                h[i][j] += term.im;
+                h[i][i] -= term.im;
+            }
+        }
+    }
+
+    let others: Vec<usize> = (0..n).filter(|&i| i != slack).collect();
+    let m = others.len();
+    let h_t = Mat::<f64>::from_fn(m, m, |r, c| h[others[c]][others[r]]);
+    let d_loss = Mat::<f64>::from_fn(m, 1, |r, _| (0..n).map(|i| h[i][others[r]]).sum());
+    let solved = h_t.partial_piv_lu().solve(&d_loss);
+    for (r, &i) in others.iter().enumerate() {
+        let factor = solved.read(r, 0);
+        if !factor.is_finite() {
+            return Err(anyhow!(
+                "singular ∂P/∂θ matrix in loss factor calculation (islanded network?)"
+            ));
+        }
+        factors[i] = factor;
+    }
+    Ok(factors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::power_flow::ac_pf::AcPowerFlowSolver;
+    use gat_core::{Branch, Bus, Gen, GenId, Load, LoadId, Megavars, Megawatts, Node};
+
+    /// Slack generator at bus 1 serving 50 MW at bus 2.
+    fn two_bus() -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus 1".to_string(),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "Bus 2".to_string(),
+            ..Bus::default()
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "Line 1-2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        network.graph.add_node(Node::Gen(Gen::new(
+            GenId::new(1),
+            "Gen 1".to_string(),
+            BusId::new(1),
+        )));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load 2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(50.0),
+            reactive_power: Megavars(10.0),
+            zip: None,
+        }));
+        network
+    }
+
+    fn solve(network: &Network) -> AcPowerFlowSolution {
+        AcPowerFlowSolver::new()
+            .solve(network)
+            .expect("power flow should converge")
+    }
+
+    #[test]
+    fn test_pro_rata_two_bus_load_bears_losses() {
+        let network = two_bus();
+        let allocation =
+            allocate_losses(&network, &solve(&network), LossAllocationMethod::ProRata).unwrap();
+
+        // ≈ |I|²R with |I| ≈ 0.51 p.u. on a 0.01 p.u. line
+        assert!(
+            allocation.total_losses_mw > 0.2 && allocation.total_losses_mw < 0.35,
+            "losses {} MW",
+            allocation.total_losses_mw
+        );
+        // Slack supplies load plus losses
+        let injected = allocation.bus_injection_mw[&BusId::new(1)];
+        assert!((injected - 50.0 - allocation.total_losses_mw).abs() < 1e-3);
+
+        let load_share = allocation.bus_share_mw[&BusId::new(2)];
+        assert!((load_share - allocation.total_losses_mw).abs() < 1e-9);
+        assert_eq!(allocation.bus_share_mw[&BusId::new(1)], 0.0);
+        assert!(allocation.loss_factors.is_empty());
+    }
+
+    #[test]
+    fn test_marginal_two_bus_charges_load_and_sums_to_total() {
+        let network = two_bus();
+        let allocation =
+            allocate_losses(&network, &solve(&network), LossAllocationMethod::Marginal).unwrap();
+
+        // Injecting at the load bus relieves the line; the slack factor is zero
+        assert!(allocation.loss_factors[&BusId::new(2)] < 0.0);
+        assert_eq!(allocation.loss_factors[&BusId::new(1)], 0.0);
+
+        let sum: f64 = allocation.bus_share_mw.values().sum();
+        assert!((sum - allocation.total_losses_mw).abs() < 1e-9);
+        assert!(
+            (allocation.bus_share_mw[&BusId::new(2)] - allocation.total_losses_mw).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_shares_sum_to_total_on_meshed_network() {
+        // Add a second load and a parallel path so several buses share losses
+        let mut network = two_bus();
+        let b3 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(3),
+            name: "Bus 3".to_string(),
+            ..Bus::default()
+        }));
+        let index_of = |network: &Network, id: usize| {
+            network
+                .graph
+                .node_indices()
+                .find(|&idx| matches!(&network.graph[idx], Node::Bus(b) if b.id == BusId::new(id)))
+                .unwrap()
+        };
+        let (b1, b2) = (index_of(&network, 1), index_of(&network, 2));
+        for (k, (from, to, f, t)) in [(b1, b3, 1, 3), (b2, b3, 2, 3)].into_iter().enumerate() {
+            network.graph.add_edge(
+                from,
+                to,
+                Edge::Branch(Branch::new(
+                    BranchId::new(k + 2),
+                    format!("Line {}-{}", f, t),
+                    BusId::new(f),
+                    BusId::new(t),
+                    0.02,
+                    0.15,
+                )),
+            );
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(2),
+            name: "Load 3".to_string(),
+            bus: BusId::new(3),
+            active_power: Megawatts(30.0),
+            reactive_power: Megavars(5.0),
+            zip: None,
+        }));
+
+        let result = solve(&network);
+        for method in [
+            LossAllocationMethod::ProRata,
+            LossAllocationMethod::Marginal,
+        ] {
+            let allocation = allocate_losses(&network, &result, method).unwrap();
+            let branch_sum: f64 = allocation.branch_losses_mw.values().sum();
+            let share_sum: f64 = allocation.bus_share_mw.values().sum();
+            assert_eq!(allocation.branch_losses_mw.len(), 3);
+            assert!((branch_sum - allocation.total_losses_mw).abs() < 1e-9);
+            assert!(
+                (share_sum - allocation.total_losses_mw).abs() < 1e-9,
+                "{:?}: shares {} vs losses {}",
+                method,
+                share_sum,
+                allocation.total_losses_mw
+            );
+        }
+    }
+}
@@ -56,6 +56,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(40.0),
             reactive_power: gat_core::Megavars(50.0),
+            zip: None,
         }));
 
         // Connect buses with a transmission line
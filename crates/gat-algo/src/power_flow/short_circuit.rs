@@ -0,0 +1,380 @@
+//! Symmetrical short-circuit (fault current) calculation at a bus.
+//!
+//! Fault levels are computed with the classical Thevenin method used for
+//! protection and hosting-capacity screening:
+//!
+//! - Every bus is assumed to be at 1.0 p.u. before the fault and loads are
+//!   neglected.
+//! - The positive-sequence network is the shared Y-bus from
+//!   [`gat_core::solver::build_ybus`] (π-model branches, taps and shunts) plus,
+//!   at each in-service generator with a subtransient reactance, the source
+//!   admittance `1 / (j·X''d)` with `X''d` converted from `mbase` to the
+//!   system base. Generators without `X''d` do not contribute fault current.
+//! - One column of `Z_bus = Y_bus⁻¹` gives the Thevenin impedance `Z_kk` at the
+//!   fault bus and the voltage drop `Z_ik · I_f` at every other bus.
+//!
+//! A three-phase fault draws `I_f = 1 / Z_kk`. A single-line-to-ground fault
+//! draws `I_f = 3 / (Z1 + Z2 + Z0)`; the network carries no sequence data, so
+//! `Z2 = Z1` and `Z0` is taken as a multiple of `Z1`
+//! (see [`FaultType::SingleLineToGround`]).
+
+use anyhow::{anyhow, Result};
+use faer::prelude::*;
+use faer::Mat;
+use gat_core::solver::build_ybus;
+use gat_core::{BranchId, BusId, Edge, Network, Node};
+use num_complex::Complex64;
+use std::collections::HashMap;
+
+/// System MVA base used by the AC power flow solver
+const BASE_MVA: f64 = 100.0;
+
+/// Thevenin impedances below this magnitude (p.u.) indicate a bolted source
+const MIN_THEVENIN_PU: f64 = 1e-9;
+
+/// Kind of bolted fault applied at the fault bus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FaultType {
+    /// Balanced three-phase fault
+    ThreePhase,
+    /// Phase-a-to-ground fault with `Z0 = z0_ratio · Z1` at the fault bus
+    SingleLineToGround { z0_ratio: f64 },
+}
+
+impl FaultType {
+    /// Single-line-to-ground fault with equal sequence impedances
+    pub fn single_line_to_ground() -> Self {
+        FaultType::SingleLineToGround { z0_ratio: 1.0 }
+    }
+}
+
+/// Current carried by a branch while the fault is applied.
+#[derive(Debug, Clone)]
+pub struct BranchFaultCurrent {
+    pub branch_id: BranchId,
+    pub from_bus: BusId,
+    pub to_bus: BusId,
+    /// Positive-sequence current magnitude at the from end (p.u.)
+    pub current_pu: f64,
+    /// Same current in kA, if the from bus has a base voltage
+    pub current_ka: Option<f64>,
+}
+
+/// Result of a short-circuit calculation.
+#[derive(Debug, Clone)]
+pub struct ShortCircuitResult {
+    pub fault_bus: BusId,
+    pub fault_type: FaultType,
+    /// Positive-sequence Thevenin impedance at the fault bus (p.u.)
+    pub thevenin_impedance_pu: Complex64,
+    /// Fault current magnitude (p.u.)
+    pub fault_current_pu: f64,
+    /// Fault current in kA, if the fault bus has a base voltage
+    pub fault_current_ka: Option<f64>,
+    /// Fault level `√3 · V · I_f` at nominal voltage (MVA)
+    pub fault_mva: f64,
+    /// Positive-sequence currents in every in-service branch
+    pub branch_currents: Vec<BranchFaultCurrent>,
+}
+
+struct FaultBranch {
+    id: BranchId,
+    from: usize,
+    to: usize,
+    y_series: Complex64,
+    charging_b: f64,
+    tap: Complex64,
+}
+
+/// Compute the fault current for a bolted fault at `fault_bus`.
+pub fn short_circuit(
+    network: &Network,
+    fault_bus: BusId,
+    fault_type: FaultType,
+) -> Result<ShortCircuitResult> {
+    if let FaultType::SingleLineToGround { z0_ratio } = fault_type {
+        if z0_ratio.is_nan() || z0_ratio < 0.0 {
+            return Err(anyhow!("z0_ratio must be non-negative, got {}", z0_ratio));
+        }
+    }
+
+    let (y_shared, buses) = build_ybus(network);
+    let base_kv: HashMap<BusId, f64> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => Some((bus.id, bus.base_kv.value())),
+            _ => None,
+        })
+        .collect();
+    let bus_index: HashMap<BusId, usize> =
+        buses.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+    let n = buses.len();
+    let &k = bus_index
+        .get(&fault_bus)
+        .ok_or_else(|| anyhow!("fault bus {} not found in network", fault_bus.value()))?;
+
+    let mut branches = Vec::new();
+    for edge in network.graph.edge_weights() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        if !branch.status {
+            continue;
+        }
+        let (Some(&from), Some(&to)) = (
+            bus_index.get(&branch.from_bus),
+            bus_index.get(&branch.to_bus),
+        ) else {
+            continue;
+        };
+        // Zero-impedance branches are skipped by the shared Y-bus as well
+        let z = Complex64::new(branch.resistance, branch.reactance);
+        if z.norm_sqr() < 1e-12 {
+            continue;
+        }
+        let tap_mag = if branch.tap_ratio > 0.0 {
+            branch.tap_ratio
+        } else {
+            1.0
+        };
+        branches.push(FaultBranch {
+            id: branch.id,
+            from,
+            to,
+            y_series: z.inv(),
+            charging_b: branch.charging_b.value(),
+            tap: Complex64::from_polar(tap_mag, branch.phase_shift.value()),
+        });
+    }
+
+    let mut y_bus = vec![vec![Complex64::new(0.0, 0.0); n]; n];
+    for (i, row) in y_shared.outer_iterator().enumerate() {
+        for (j, &y) in row.iter() {
+            y_bus[i][j] = y;
+        }
+    }
+    let mut sources = 0;
+    for node in network.graph.node_weights() {
+        let Node::Gen(gen) = node else {
+            continue;
+        };
+        if !gen.status {
+            continue;
+        }
+        let (Some(xd), Some(&i)) = (gen.xd_subtransient, bus_index.get(&gen.bus)) else {
+            continue;
+        };
+        let mbase = gen.mbase.map(|m| m.value()).filter(|m| *m > 0.0);
+        let xd_sys = xd * BASE_MVA / mbase.unwrap_or(BASE_MVA);
+        if xd_sys <= 0.0 {
+            return Err(anyhow!(
+                "generator {} has non-positive subtransient reactance",
+                gen.name
+            ));
+        }
+        y_bus[i][i] += Complex64::new(0.0, xd_sys).inv();
+        sources += 1;
+    }
+    if sources == 0 {
+        return Err(anyhow!(
+            "no in-service generator has a subtransient reactance; no fault current source"
+        ));
+    }
+
+    let z_column = impedance_column(&y_bus, k)?;
+    let z_th = z_column[k];
+    if z_th.norm() < MIN_THEVENIN_PU {
+        return Err(anyhow!(
+            "Thevenin impedance at bus {} is zero",
+            fault_bus.value()
+        ));
+    }
+
+    // Fault current and the positive-sequence current that flows in the network
+    let (fault_current, sequence_current) = match fault_type {
+        FaultType::ThreePhase => {
+            let i_f = z_th.inv();
+            (i_f, i_f)
+        }
+        FaultType::SingleLineToGround { z0_ratio } => {
+            let i_1 = (z_th * (2.0 + z0_ratio)).inv();
+            (i_1 * 3.0, i_1)
+        }
+    };
+
+    // Post-fault voltages with a flat 1.0 p.u. pre-fault profile
+    let voltages: Vec<Complex64> = z_column
+        .iter()
+        .map(|z_ik| Complex64::new(1.0, 0.0) - z_ik * sequence_current)
+        .collect();
+
+    let to_ka = |current_pu: f64, bus: BusId| {
+        base_kv
+            .get(&bus)
+            .copied()
+            .filter(|kv| *kv > 0.0)
+            .map(|kv| current_pu * BASE_MVA / (3f64.sqrt() * kv))
+    };
+
+    let branch_currents = branches
+        .iter()
+        .map(|br| {
+            // From-end current Y_ff·V_f + Y_ft·V_t of the shared π-model
+            let half_b = Complex64::new(0.0, br.charging_b / 2.0);
+            let current = (br.y_series + half_b) / br.tap.norm_sqr() * voltages[br.from]
+                - br.y_series / br.tap.conj() * voltages[br.to];
+            let current_pu = current.norm();
+            BranchFaultCurrent {
+                branch_id: br.id,
+                from_bus: buses[br.from],
+                to_bus: buses[br.to],
+                current_pu,
+                current_ka: to_ka(current_pu, buses[br.from]),
+            }
+        })
+        .collect();
+
+    let fault_current_pu = fault_current.norm();
+    Ok(ShortCircuitResult {
+        fault_bus,
+        fault_type,
+        thevenin_impedance_pu: z_th,
+        fault_current_pu,
+        fault_current_ka: to_ka(fault_current_pu, fault_bus),
+        fault_mva: fault_current_pu * BASE_MVA,
+        branch_currents,
+    })
+}
+
+/// Column `k` of `Z_bus = Y_bus⁻¹`, solved as the real system `[G -B; B G]`.
+fn impedance_column(y_bus: &[Vec<Complex64>], k: usize) -> Result<Vec<Complex64>> {
+    let n = y_bus.len();
+    let a = Mat::<f64>::from_fn(2 * n, 2 * n, |r, c| {
+        let y = y_bus[r % n][c % n];
+        match (r < n, c < n) {
+            (true, true) | (false, false) => y.re,
+            (true, false) => -y.im,
+            (false, true) => y.im,
+        }
+    });
+    let rhs = Mat::<f64>::from_fn(2 * n, 1, |r, _| if r == k { 1.0 } else { 0.0 });
+    let solved = a.partial_piv_lu().solve(&rhs);
+
+    let column: Vec<Complex64> = (0..n)
+        .map(|i| Complex64::new(solved.read(i, 0), solved.read(n + i, 0)))
+        .collect();
+    if column
+        .iter()
+        .any(|z| !z.re.is_finite() || !z.im.is_finite())
+    {
+        return Err(anyhow!(
+            "admittance matrix is singular; is every island connected to a source?"
+        ));
+    }
+    Ok(column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{Branch, Bus, Gen, GenId, Kilovolts, MegavoltAmperes};
+
+    /// Source at bus 1 (X''d = 0.2 p.u. on 100 MVA) feeding bus 2 over a j0.1 line.
+    fn two_bus() -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus 1".to_string(),
+            base_kv: Kilovolts(138.0),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "Bus 2".to_string(),
+            base_kv: Kilovolts(138.0),
+            ..Bus::default()
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "Line 1-2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.0,
+                0.1,
+            )),
+        );
+        let mut gen = Gen::new(GenId::new(1), "Gen 1".to_string(), BusId::new(1))
+            .with_subtransient_reactance(0.2);
+        gen.mbase = Some(MegavoltAmperes(100.0));
+        network.graph.add_node(Node::Gen(gen));
+        network
+    }
+
+    #[test]
+    fn test_three_phase_fault_matches_thevenin() {
+        let network = two_bus();
+        let result = short_circuit(&network, BusId::new(2), FaultType::ThreePhase).unwrap();
+
+        // Z_th = j0.2 + j0.1
+        let z_th = Complex64::new(0.0, 0.3);
+        assert!((result.thevenin_impedance_pu - z_th).norm() < 1e-9);
+        assert!((result.fault_current_pu - z_th.inv().norm()).abs() < 1e-9);
+        assert!((result.fault_mva - 100.0 / 0.3).abs() < 1e-6);
+        let ka = result.fault_current_ka.unwrap();
+        assert!((ka - (1.0 / 0.3) * 100.0 / (3f64.sqrt() * 138.0)).abs() < 1e-9);
+
+        // All fault current flows through the only line
+        assert_eq!(result.branch_currents.len(), 1);
+        assert!((result.branch_currents[0].current_pu - 1.0 / 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fault_at_source_bus_and_mbase_scaling() {
+        let mut network = two_bus();
+        for node in network.graph.node_weights_mut() {
+            if let Node::Gen(gen) = node {
+                // 0.4 p.u. on 200 MVA is 0.2 p.u. on the 100 MVA system base
+                gen.mbase = Some(MegavoltAmperes(200.0));
+                gen.xd_subtransient = Some(0.4);
+            }
+        }
+        let result = short_circuit(&network, BusId::new(1), FaultType::ThreePhase).unwrap();
+        assert!((result.fault_current_pu - 5.0).abs() < 1e-9);
+        // The line feeds an unloaded bus, so it carries no fault current
+        assert!(result.branch_currents[0].current_pu < 1e-9);
+    }
+
+    #[test]
+    fn test_single_line_to_ground_fault() {
+        let network = two_bus();
+        let three_phase = short_circuit(&network, BusId::new(2), FaultType::ThreePhase).unwrap();
+        let equal =
+            short_circuit(&network, BusId::new(2), FaultType::single_line_to_ground()).unwrap();
+        assert!((equal.fault_current_pu - three_phase.fault_current_pu).abs() < 1e-9);
+
+        // I_f = 3 / (Z1 + Z2 + 3·Z1) = 3 / (5·j0.3)
+        let high_z0 = short_circuit(
+            &network,
+            BusId::new(2),
+            FaultType::SingleLineToGround { z0_ratio: 3.0 },
+        )
+        .unwrap();
+        assert!((high_z0.fault_current_pu - 3.0 / 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_sources_is_an_error() {
+        let mut network = two_bus();
+        for node in network.graph.node_weights_mut() {
+            if let Node::Gen(gen) = node {
+                gen.xd_subtransient = None;
+            }
+        }
+        assert!(short_circuit(&network, BusId::new(2), FaultType::ThreePhase).is_err());
+        assert!(short_circuit(&two_bus(), BusId::new(9), FaultType::ThreePhase).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+//! Approximate AC voltage magnitudes from a DC-OPF dispatch.
+//!
+//! A screening step between DC-OPF and a full AC solve. Starting from the DC
+//! angles of the dispatch, with PV buses at their setpoint and PQ buses flat
+//! at 1.0 p.u., one fast-decoupled Q-V iteration `B''ΔV = ΔQ/V` gives an
+//! estimate of every PQ bus voltage. This is the same step as
+//! [`OpfSolver::with_reactive_estimate`](crate::opf::OpfSolver::with_reactive_estimate).
+//!
+//! The estimate is explicitly approximate: there is no P-Q iteration, losses
+//! are not redispatched and generator Q limits are ignored. On IEEE 30 most
+//! buses land within [`VOLTAGE_ESTIMATE_TOLERANCE_PU`] of the Newton-Raphson
+//! solution for the same dispatch. Buses whose estimate comes within that
+//! tolerance of a voltage limit are flagged for a closer look.
+
+use crate::opf::{reactive_estimate, OpfSolution};
+use anyhow::Result;
+use gat_core::{BusId, Network, Node};
+use std::collections::HashMap;
+
+/// Typical error of the estimate against a full AC power flow (p.u.), and
+/// the margin within which a bus is flagged as a likely limit violation
+pub const VOLTAGE_ESTIMATE_TOLERANCE_PU: f64 = 0.03;
+
+/// Approximate voltage magnitudes for a DC-OPF dispatch.
+#[derive(Debug, Clone, Default)]
+pub struct VoltageEstimate {
+    /// Estimated voltage magnitude per bus (p.u.)
+    pub bus_voltage_magnitude: HashMap<BusId, f64>,
+    /// Buses whose estimate is within [`VOLTAGE_ESTIMATE_TOLERANCE_PU`] of,
+    /// or beyond, `vmin_pu` or `vmax_pu`, ordered by bus ID
+    pub likely_violations: Vec<BusId>,
+}
+
+/// Estimate AC voltage magnitudes from the generator dispatch of `dc_result`.
+///
+/// Only `generator_p` is read from the solution; the DC angles are rebuilt
+/// from it with the same lossless model DC-OPF used. Buses without voltage
+/// limits are never flagged.
+pub fn estimate_voltages_from_dc(
+    network: &Network,
+    dc_result: &OpfSolution,
+) -> Result<VoltageEstimate> {
+    let mut scratch = OpfSolution {
+        generator_p: dc_result.generator_p.clone(),
+        ..Default::default()
+    };
+    reactive_estimate::attach(network, &mut scratch)?;
+    let by_name = scratch
+        .reactive_estimate
+        .map(|estimate| estimate.bus_voltage_mag)
+        .unwrap_or_default();
+
+    let mut estimate = VoltageEstimate::default();
+    for node in network.graph.node_weights() {
+        let Node::Bus(bus) = node else {
+            continue;
+        };
+        let Some(&vm) = by_name.get(&bus.name) else {
+            continue;
+        };
+        estimate.bus_voltage_magnitude.insert(bus.id, vm);
+
+        let low = bus
+            .vmin_pu
+            .is_some_and(|vmin| vm < vmin.value() + VOLTAGE_ESTIMATE_TOLERANCE_PU);
+        let high = bus
+            .vmax_pu
+            .is_some_and(|vmax| vm > vmax.value() - VOLTAGE_ESTIMATE_TOLERANCE_PU);
+        if low || high {
+            estimate.likely_violations.push(bus.id);
+        }
+    }
+    estimate.likely_violations.sort_by_key(|id| id.value());
+    Ok(estimate)
+}
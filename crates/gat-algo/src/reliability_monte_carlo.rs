@@ -1,9 +1,13 @@
 use crate::arena::ArenaContext;
-use anyhow::{anyhow, Result};
-use gat_core::{BusId, Network, Node, NodeIndex};
+use anyhow::{anyhow, Context, Result};
+use gat_core::{BusId, Edge, EdgeIndex, Network, Node, NodeIndex};
 use petgraph::visit::EdgeRef;
+use polars::io::parquet::BatchedWriter;
+use polars::prelude::{DataFrame, NamedFrom, ParquetWriter, Series};
 use rayon::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
 
 /// Represents a single outage scenario (which generators/lines are offline)
 #[derive(Debug, Clone)]
@@ -153,6 +157,298 @@ pub struct ReliabilityMetrics {
     pub scenarios_with_shortfall: usize,
     /// Average shortfall when it occurs (MW)
     pub average_shortfall: f64,
+    /// Economic cost of unserved energy, when [`MonteCarlo::voll`] is set
+    pub outage_cost: Option<OutageCost>,
+}
+
+/// Value of lost load used to price unserved energy.
+#[derive(Debug, Clone)]
+pub struct Voll {
+    /// VOLL for buses without their own value ($/MWh)
+    pub default_per_mwh: f64,
+    /// Per-bus VOLL ($/MWh)
+    pub per_bus: HashMap<BusId, f64>,
+}
+
+impl Voll {
+    /// Same VOLL at every bus
+    pub fn uniform(per_mwh: f64) -> Self {
+        Self {
+            default_per_mwh: per_mwh,
+            per_bus: HashMap::new(),
+        }
+    }
+
+    /// Set the VOLL of one bus
+    pub fn with_bus(mut self, bus: BusId, per_mwh: f64) -> Self {
+        self.per_bus.insert(bus, per_mwh);
+        self
+    }
+
+    /// VOLL at `bus` ($/MWh)
+    pub fn at(&self, bus: BusId) -> f64 {
+        self.per_bus
+            .get(&bus)
+            .copied()
+            .unwrap_or(self.default_per_mwh)
+    }
+}
+
+/// Expected economic cost of unserved energy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutageCost {
+    /// Expected cost of unserved energy ($ per year)
+    pub expected_cost: f64,
+    /// Unserved energy by load bus (MWh per year); sums to EUE
+    pub bus_eue: HashMap<BusId, f64>,
+}
+
+/// Prices each scenario's shortfall by shedding the lowest-VOLL load first,
+/// i.e. the dispatch that serves the most valuable load with what is left.
+struct OutageCostTally {
+    /// (bus, nominal demand MW, VOLL $/MWh), cheapest VOLL first
+    loads: Vec<(BusId, f64, f64)>,
+    /// Σ probability × shed MW × VOLL
+    weighted_cost: f64,
+    /// Σ probability × shed MW, per entry of `loads`
+    weighted_shed: Vec<f64>,
+}
+
+impl OutageCostTally {
+    fn new(bus_demand: &HashMap<BusId, f64>, voll: &Voll) -> Self {
+        let mut loads: Vec<(BusId, f64, f64)> = bus_demand
+            .iter()
+            .map(|(bus, demand)| (*bus, *demand, voll.at(*bus)))
+            .collect();
+        loads.sort_by(|a, b| a.2.total_cmp(&b.2).then(a.0.value().cmp(&b.0.value())));
+        let weighted_shed = vec![0.0; loads.len()];
+        Self {
+            loads,
+            weighted_cost: 0.0,
+            weighted_shed,
+        }
+    }
+
+    fn add(&mut self, probability: f64, shortfall: f64, demand_scale: f64) {
+        let mut remaining = shortfall;
+        let mut start = 0;
+        while start < self.loads.len() && remaining > 0.0 {
+            // Buses with equal VOLL are curtailed together, pro rata
+            let voll = self.loads[start].2;
+            let end = start
+                + self.loads[start..]
+                    .iter()
+                    .take_while(|l| l.2 == voll)
+                    .count();
+            let group_demand: f64 = self.loads[start..end]
+                .iter()
+                .map(|l| l.1 * demand_scale)
+                .sum();
+            let shed = remaining.min(group_demand);
+            if group_demand > 0.0 {
+                let group = self.loads[start..end]
+                    .iter()
+                    .zip(&mut self.weighted_shed[start..end]);
+                for (load, weighted) in group {
+                    let bus_shed = shed * load.1 * demand_scale / group_demand;
+                    *weighted += probability * bus_shed;
+                    self.weighted_cost += probability * bus_shed * voll;
+                }
+            }
+            remaining -= shed;
+            start = end;
+        }
+    }
+
+    fn finish(&self, hours_per_year: f64) -> OutageCost {
+        OutageCost {
+            expected_cost: self.weighted_cost * hours_per_year,
+            bus_eue: self
+                .loads
+                .iter()
+                .zip(&self.weighted_shed)
+                .map(|(l, shed)| (l.0, shed * hours_per_year))
+                .collect(),
+        }
+    }
+}
+
+/// Two-sided 95% normal quantile for Monte Carlo confidence intervals
+const CI_Z_95: f64 = 1.96;
+
+/// Running estimate passed to the callback of
+/// [`MonteCarlo::compute_reliability_with_progress`].
+///
+/// Estimates are scaled to the full run, so the snapshot after the last
+/// sample equals the returned [`ReliabilityMetrics`]. Intervals are 95%
+/// normal approximations from the sample variance so far.
+#[derive(Debug, Clone)]
+pub struct MonteCarloProgress {
+    /// Scenarios evaluated so far
+    pub samples_done: usize,
+    /// Scenarios in the full run
+    pub total_samples: usize,
+    /// Running LOLE estimate (hours per year)
+    pub lole: f64,
+    /// 95% confidence interval on LOLE (hours per year)
+    pub lole_ci: (f64, f64),
+    /// Running EUE estimate (MWh per year)
+    pub eue: f64,
+    /// 95% confidence interval on EUE (MWh per year)
+    pub eue_ci: (f64, f64),
+}
+
+/// Running sums over evaluated scenarios.
+#[derive(Default)]
+struct ShortfallTally {
+    samples: usize,
+    with_shortfall: usize,
+    /// Σ probability over scenarios with shortfall
+    shortfall_probability: f64,
+    /// Σ probability × shortfall (MW)
+    weighted_shortfall: f64,
+    /// Σ shortfall and Σ shortfall² (MW), for the EUE variance
+    shortfall_sum: f64,
+    shortfall_sq_sum: f64,
+}
+
+impl ShortfallTally {
+    fn add(&mut self, probability: f64, shortfall: f64, has_shortfall: bool) {
+        self.samples += 1;
+        if has_shortfall {
+            self.with_shortfall += 1;
+            self.shortfall_probability += probability;
+            self.weighted_shortfall += shortfall * probability;
+            self.shortfall_sum += shortfall;
+            self.shortfall_sq_sum += shortfall * shortfall;
+        }
+    }
+
+    fn progress(&self, total_samples: usize, hours_per_year: f64) -> MonteCarloProgress {
+        let n = self.samples.max(1) as f64;
+        // Extrapolate partial sums to the full run; exactly 1 at the end
+        let scale = total_samples as f64 / n;
+        let lole = self.shortfall_probability * scale * hours_per_year;
+        let eue = self.weighted_shortfall * scale * hours_per_year;
+
+        let p = self.with_shortfall as f64 / n;
+        let mean = self.shortfall_sum / n;
+        let (lole_var, eue_var) = if self.samples > 1 {
+            (
+                p * (1.0 - p) * n / (n - 1.0),
+                ((self.shortfall_sq_sum - n * mean * mean) / (n - 1.0)).max(0.0),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let lole_half = CI_Z_95 * (lole_var / n).sqrt() * hours_per_year;
+        let eue_half = CI_Z_95 * (eue_var / n).sqrt() * hours_per_year;
+
+        MonteCarloProgress {
+            samples_done: self.samples,
+            total_samples,
+            lole,
+            lole_ci: ((lole - lole_half).max(0.0), lole + lole_half),
+            eue,
+            eue_ci: ((eue - eue_half).max(0.0), eue + eue_half),
+        }
+    }
+}
+
+/// One sampled state with unserved load, as written by the event log.
+///
+/// Samples are independent draws rather than a chronology, so `sample` is
+/// the draw index and `duration_hours` the hours per year the state stands
+/// for (probability × hours per year). Summed over all events, durations
+/// give the LOLE.
+#[derive(Debug, Clone)]
+pub struct LossOfLoadEvent {
+    /// Index of the sampled scenario
+    pub sample: usize,
+    /// Area short of supply, for multi-area runs
+    pub area: Option<usize>,
+    /// Outaged elements, e.g. `gen:G1`, `branch:L3`, `corridor:0`
+    pub failed_components: Vec<String>,
+    /// Load not served (MW)
+    pub unserved_mw: f64,
+    /// Expected hours per year in this state
+    pub duration_hours: f64,
+}
+
+/// Parquet stream of [`LossOfLoadEvent`]s, written a batch at a time.
+pub(crate) struct EventLog {
+    writer: BatchedWriter<File>,
+}
+
+impl EventLog {
+    pub(crate) fn create(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("creating output directory '{}'", parent.display()))?;
+        }
+        let file = File::create(path)
+            .with_context(|| format!("creating event log '{}'", path.display()))?;
+        let schema = Self::frame(&[])?.schema();
+        let writer = ParquetWriter::new(file)
+            .batched(&schema)
+            .context("starting event log")?;
+        Ok(Self { writer })
+    }
+
+    pub(crate) fn write(&mut self, events: &[LossOfLoadEvent]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        self.writer
+            .write_batch(&Self::frame(events)?)
+            .context("writing event log batch")
+    }
+
+    pub(crate) fn finish(mut self) -> Result<()> {
+        self.writer.finish().context("closing event log")?;
+        Ok(())
+    }
+
+    fn frame(events: &[LossOfLoadEvent]) -> Result<DataFrame> {
+        let sample: Vec<u64> = events.iter().map(|e| e.sample as u64).collect();
+        let area: Vec<Option<u64>> = events.iter().map(|e| e.area.map(|a| a as u64)).collect();
+        let failed: Vec<String> = events
+            .iter()
+            .map(|e| e.failed_components.join(";"))
+            .collect();
+        let unserved: Vec<f64> = events.iter().map(|e| e.unserved_mw).collect();
+        let duration: Vec<f64> = events.iter().map(|e| e.duration_hours).collect();
+        Ok(DataFrame::new(vec![
+            Series::new("sample", sample),
+            Series::new("area", area),
+            Series::new("failed_components", failed),
+            Series::new("unserved_mw", unserved),
+            Series::new("duration_hours", duration),
+        ])?)
+    }
+}
+
+/// Label the generators and branches a scenario takes out of `network`
+pub(crate) fn failed_components(network: &Network, scenario: &OutageScenario) -> Vec<String> {
+    let mut gens: Vec<&NodeIndex> = scenario.offline_generators.iter().collect();
+    gens.sort();
+    let mut branches: Vec<&usize> = scenario.offline_branches.iter().collect();
+    branches.sort();
+
+    let mut failed = Vec::with_capacity(gens.len() + branches.len());
+    for idx in gens {
+        if let Some(Node::Gen(gen)) = network.graph.node_weight(*idx) {
+            failed.push(format!("gen:{}", gen.name));
+        }
+    }
+    for idx in branches {
+        match network.graph.edge_weight(EdgeIndex::new(*idx)) {
+            Some(Edge::Branch(branch)) => failed.push(format!("branch:{}", branch.name)),
+            Some(Edge::Transformer(tx)) => failed.push(format!("transformer:{}", tx.name)),
+            None => {}
+        }
+    }
+    failed
 }
 
 /// Monte Carlo LOLE/EUE calculator
@@ -163,6 +459,10 @@ pub struct MonteCarlo {
     pub num_scenarios: usize,
     /// Hours per year (365.25 days * 24 hours)
     pub hours_per_year: f64,
+    /// Value of lost load; when set, results include the expected outage cost
+    pub voll: Option<Voll>,
+    /// Parquet file receiving one row per loss-of-load event; off when `None`
+    pub event_log: Option<PathBuf>,
 }
 
 impl MonteCarlo {
@@ -172,17 +472,61 @@ impl MonteCarlo {
             scenario_gen: OutageGenerator::new(),
             num_scenarios,
             hours_per_year: 365.25 * 24.0,
+            voll: None,
+            event_log: None,
         }
     }
 
+    /// Price unserved energy at `voll`, shedding the cheapest load first
+    pub fn with_voll(mut self, voll: Voll) -> Self {
+        self.voll = Some(voll);
+        self
+    }
+
+    /// Log every loss-of-load event to a Parquet file at `path`.
+    ///
+    /// Events are written as each batch of scenarios finishes. Expect one
+    /// row per shortfall scenario, which is many on a stressed system.
+    pub fn with_event_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.event_log = Some(path.into());
+        self
+    }
+
     /// Compute LOLE and EUE for a network
     pub fn compute_reliability(&self, network: &Network) -> Result<ReliabilityMetrics> {
+        self.run(network, None)
+    }
+
+    /// Compute LOLE and EUE, calling `callback` every `every` scenarios.
+    ///
+    /// Scenarios are evaluated in parallel batches of `every`, with the
+    /// callback run between batches, so the inner loop is untouched; keep
+    /// `every` in the hundreds or more to preserve parallel speedup. The
+    /// final callback sees all scenarios and matches the returned metrics.
+    pub fn compute_reliability_with_progress(
+        &self,
+        network: &Network,
+        every: usize,
+        mut callback: impl FnMut(&MonteCarloProgress),
+    ) -> Result<ReliabilityMetrics> {
+        if every == 0 {
+            return Err(anyhow!("progress interval must be at least one scenario"));
+        }
+        self.run(network, Some((every, &mut callback)))
+    }
+
+    fn run(
+        &self,
+        network: &Network,
+        mut progress: Option<(usize, &mut dyn FnMut(&MonteCarloProgress))>,
+    ) -> Result<ReliabilityMetrics> {
         use std::collections::{HashMap, HashSet};
 
         // Build lookup caches once (reused for all scenarios)
         let node_count = network.graph.node_count();
         let mut bus_id_to_node: HashMap<BusId, NodeIndex> = HashMap::with_capacity(node_count);
         let mut load_buses: HashSet<BusId> = HashSet::with_capacity(node_count);
+        let mut bus_demand: HashMap<BusId, f64> = HashMap::new();
         let mut total_demand = 0.0;
 
         for node_idx in network.graph.node_indices() {
@@ -193,6 +537,7 @@ impl MonteCarlo {
                 Some(Node::Load(load)) => {
                     total_demand += load.active_power.value();
                     load_buses.insert(load.bus);
+                    *bus_demand.entry(load.bus).or_insert(0.0) += load.active_power.value();
                 }
                 _ => {}
             }
@@ -207,59 +552,89 @@ impl MonteCarlo {
             .scenario_gen
             .generate_scenarios(network, self.num_scenarios);
 
-        // Each parallel task gets its own arena context
-        let results: Result<Vec<(f64, f64, bool)>> = scenarios
-            .par_iter()
-            .map_init(
-                || ArenaContext::new(),
-                |ctx, scenario| {
-                    let available_gen = self.calculate_deliverable_generation_arena(
-                        network,
-                        scenario,
-                        &bus_id_to_node,
-                        &load_buses,
-                        ctx,
-                    )?;
-
-                    let demand = total_demand * scenario.demand_scale;
-                    let has_shortfall = available_gen < demand;
-                    let shortfall = if has_shortfall {
-                        demand - available_gen
-                    } else {
-                        0.0
-                    };
-
-                    ctx.reset(); // O(1) reset for next scenario
+        let batch_size = match &progress {
+            Some((every, _)) => *every,
+            None => scenarios.len().max(1),
+        };
 
-                    Ok((
-                        scenario.probability,
-                        shortfall * scenario.probability,
-                        has_shortfall,
-                    ))
-                },
-            )
-            .collect();
+        let mut tally = ShortfallTally::default();
+        let mut cost_tally = self
+            .voll
+            .as_ref()
+            .map(|voll| OutageCostTally::new(&bus_demand, voll));
+        let mut event_log = self
+            .event_log
+            .as_deref()
+            .map(EventLog::create)
+            .transpose()?;
+        for (batch_idx, batch) in scenarios.chunks(batch_size).enumerate() {
+            // Each parallel task gets its own arena context
+            let results: Result<Vec<(f64, f64, bool)>> = batch
+                .par_iter()
+                .map_init(
+                    || ArenaContext::new(),
+                    |ctx, scenario| {
+                        let available_gen = self.calculate_deliverable_generation_arena(
+                            network,
+                            scenario,
+                            &bus_id_to_node,
+                            &load_buses,
+                            ctx,
+                        )?;
+
+                        let demand = total_demand * scenario.demand_scale;
+                        let has_shortfall = available_gen < demand;
+                        let shortfall = if has_shortfall {
+                            demand - available_gen
+                        } else {
+                            0.0
+                        };
+
+                        ctx.reset(); // O(1) reset for next scenario
+
+                        Ok((scenario.probability, shortfall, has_shortfall))
+                    },
+                )
+                .collect();
+
+            // Aggregate parallel results in scenario order
+            let mut events = Vec::new();
+            for (i, ((prob, shortfall, has_shortfall), scenario)) in
+                results?.into_iter().zip(batch).enumerate()
+            {
+                tally.add(prob, shortfall, has_shortfall);
+                if let Some(costs) = cost_tally.as_mut() {
+                    costs.add(prob, shortfall, scenario.demand_scale);
+                }
+                if has_shortfall && event_log.is_some() {
+                    events.push(LossOfLoadEvent {
+                        sample: batch_idx * batch_size + i,
+                        area: None,
+                        failed_components: failed_components(network, scenario),
+                        unserved_mw: shortfall,
+                        duration_hours: prob * self.hours_per_year,
+                    });
+                }
+            }
+            if let Some(log) = event_log.as_mut() {
+                log.write(&events)?;
+            }
 
-        let results = results?;
+            if let Some((_, callback)) = progress.as_mut() {
+                callback(&tally.progress(self.num_scenarios, self.hours_per_year));
+            }
+        }
 
-        // Aggregate parallel results
-        let (shortfall_hours, total_shortfall_mwh, scenarios_with_shortfall) = results.iter().fold(
-            (0.0, 0.0, 0usize),
-            |(sh, ts, count), (prob, shortfall_mwh, has_shortfall)| {
-                if *has_shortfall {
-                    (sh + prob, ts + shortfall_mwh, count + 1)
-                } else {
-                    (sh, ts, count)
-                }
-            },
-        );
+        if let Some(log) = event_log {
+            log.finish()?;
+        }
 
         // Convert shortfall hours to annual basis
-        let lole = shortfall_hours * self.hours_per_year;
-        let eue = total_shortfall_mwh * self.hours_per_year;
+        let lole = tally.shortfall_probability * self.hours_per_year;
+        let eue = tally.weighted_shortfall * self.hours_per_year;
 
-        let average_shortfall = if scenarios_with_shortfall > 0 {
-            total_shortfall_mwh * self.hours_per_year / scenarios_with_shortfall as f64
+        let average_shortfall = if tally.with_shortfall > 0 {
+            tally.weighted_shortfall * self.hours_per_year / tally.with_shortfall as f64
         } else {
             0.0
         };
@@ -268,8 +643,9 @@ impl MonteCarlo {
             lole,
             eue,
             scenarios_analyzed: self.num_scenarios,
-            scenarios_with_shortfall,
+            scenarios_with_shortfall: tally.with_shortfall,
             average_shortfall,
+            outage_cost: cost_tally.map(|costs| costs.finish(self.hours_per_year)),
         })
     }
 
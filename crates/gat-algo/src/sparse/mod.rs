@@ -10,6 +10,8 @@
 //! - [`susceptance`]: Sparse susceptance matrix (B') for DC power flow
 //! - [`sensitivity`]: PTDF and LODF matrices for contingency analysis
 //! - [`incremental`]: Woodbury-based incremental updates for N-1 analysis
+//! - [`warm`]: Cached-factorization DC re-solves for injection sweeps
+//! - [`export`]: Matrix Market and CSR text dumps for external debugging
 //!
 //! ## Type Safety
 //!
@@ -48,13 +50,17 @@
 //! let post_flow = lodf.estimate_post_outage_flow(branch_l, branch_m, flow_l, flow_m);
 //! ```
 
+pub mod export;
 pub mod incremental;
 pub mod sensitivity;
 pub mod susceptance;
+pub mod warm;
 pub mod ybus;
 
 // Re-export main types
+pub use export::{write_csr_triplets, write_matrix_market, write_ybus_matrix_market};
 pub use incremental::{IncrementalSolver, WoodburyUpdate};
 pub use sensitivity::{LodfMatrix, PtdfMatrix, SparsePtdf};
 pub use susceptance::{SparseSusceptance, SusceptanceError};
+pub use warm::{WarmDcSolution, WarmSolver, WarmSolverError};
 pub use ybus::{SparseYBus, YBusError};
@@ -57,6 +57,8 @@ pub struct PtdfMatrix {
     branch_to_idx: HashMap<BranchId, usize>,
     /// Lookup: bus_id → column index
     bus_to_idx: HashMap<BusId, usize>,
+    /// Column index of the slack bus (its PTDF column is zero)
+    slack_idx: usize,
 }
 
 impl PtdfMatrix {
@@ -95,6 +97,11 @@ impl PtdfMatrix {
     pub fn bus_index(&self, id: BusId) -> Option<usize> {
         self.bus_to_idx.get(&id).copied()
     }
+
+    /// Bus that absorbs every injection (the withdrawal point of each factor).
+    pub fn slack_bus(&self) -> BusId {
+        self.bus_ids[self.slack_idx]
+    }
 }
 
 /// LODF matrix: flow redistribution factors for branch outages.
@@ -217,6 +224,7 @@ impl SparsePtdf {
             values: ptdf,
             branch_to_idx,
             bus_to_idx,
+            slack_idx,
         })
     }
 
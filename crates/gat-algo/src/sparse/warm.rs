@@ -0,0 +1,362 @@
+//! Warm DC re-solves for sweeps over a fixed topology.
+//!
+//! Time-series and sensitivity sweeps change injections thousands of times
+//! while the branch set stays the same. [`WarmSolver`] factorizes the reduced
+//! B' matrix once and answers each [`WarmSolver::resolve_dc`] call with two
+//! triangular solves.
+//!
+//! The solver fingerprints the topology it was built for (bus order and the
+//! in-service branches with their reactance and tap). Every re-solve checks the
+//! fingerprint against the network passed in and refactors only when it
+//! differs, so callers never get flows computed on a stale matrix.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use gat_algo::sparse::WarmSolver;
+//!
+//! let mut solver = WarmSolver::new(&network)?;
+//! for injections in hourly_injections {
+//!     let solution = solver.resolve_dc(&network, &injections)?;
+//!     println!("{:?}", solution.branch_flow_mw);
+//! }
+//! assert_eq!(solver.factorizations(), 1);
+//! ```
+
+use gat_core::{BranchId, BusId, Edge, Network, Node};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use thiserror::Error;
+
+use super::incremental::{IncrementalError, IncrementalSolver};
+use super::susceptance::{SparseSusceptance, SusceptanceError};
+
+/// System MVA base for converting MW injections to per-unit
+const BASE_MVA: f64 = 100.0;
+
+/// Errors from warm re-solves
+#[derive(Debug, Error)]
+pub enum WarmSolverError {
+    #[error(transparent)]
+    Susceptance(#[from] SusceptanceError),
+
+    #[error(transparent)]
+    Incremental(#[from] IncrementalError),
+
+    #[error("Unknown bus ID: {0}")]
+    UnknownBus(usize),
+
+    #[error("Unknown branch ID: {0}")]
+    UnknownBranch(usize),
+}
+
+/// DC flow solution from a warm re-solve.
+#[derive(Debug, Clone, Default)]
+pub struct WarmDcSolution {
+    /// Bus voltage angles (radians), zero at the slack bus
+    pub bus_angle_rad: HashMap<BusId, f64>,
+    /// Active power flow on each in-service branch, from → to (MW)
+    pub branch_flow_mw: HashMap<BranchId, f64>,
+    /// Injection absorbed by the slack bus to balance the system (MW)
+    pub slack_injection_mw: f64,
+}
+
+/// DC solver that keeps its B' factorization across injection changes.
+#[derive(Debug)]
+pub struct WarmSolver {
+    b_prime: SparseSusceptance,
+    solver: IncrementalSolver,
+    topology: u64,
+    factorizations: usize,
+}
+
+impl WarmSolver {
+    /// Build and factorize B' for the network's current topology.
+    pub fn new(network: &Network) -> Result<Self, WarmSolverError> {
+        let b_prime = SparseSusceptance::from_network(network)?;
+        let solver = IncrementalSolver::new(&b_prime)?;
+        Ok(Self {
+            b_prime,
+            solver,
+            topology: topology_fingerprint(network),
+            factorizations: 1,
+        })
+    }
+
+    /// Number of B' factorizations performed so far (1 after construction).
+    pub fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+
+    /// Whether the cached factorization matches the network's topology.
+    pub fn is_valid_for(&self, network: &Network) -> bool {
+        self.topology == topology_fingerprint(network)
+    }
+
+    /// Slack (angle reference) bus of the cached factorization.
+    pub fn slack_bus(&self) -> BusId {
+        self.b_prime.bus_order()[self.b_prime.slack_idx()]
+    }
+
+    /// Solve DC flows for new net injections (MW, generation positive).
+    ///
+    /// Buses absent from `injections` inject nothing. The slack bus balances
+    /// the system, so any value given for it is ignored. If the topology of
+    /// `network` no longer matches the factorization, B' is rebuilt first.
+    pub fn resolve_dc(
+        &mut self,
+        network: &Network,
+        injections: &HashMap<BusId, f64>,
+    ) -> Result<WarmDcSolution, WarmSolverError> {
+        if !self.is_valid_for(network) {
+            *self = Self {
+                factorizations: self.factorizations + 1,
+                ..Self::new(network)?
+            };
+        }
+
+        let slack = self.b_prime.slack_idx();
+        let mut p_full = vec![0.0; self.b_prime.n_bus()];
+        for (&bus, &mw) in injections {
+            let idx = self
+                .b_prime
+                .bus_index(bus)
+                .ok_or(WarmSolverError::UnknownBus(bus.value()))?;
+            p_full[idx] = mw / BASE_MVA;
+        }
+        let p_reduced = reduce(&p_full, slack);
+        let theta = expand(&self.solver.solve(&p_reduced)?, slack);
+
+        let mut solution = WarmDcSolution {
+            slack_injection_mw: -p_reduced.iter().sum::<f64>() * BASE_MVA,
+            ..WarmDcSolution::default()
+        };
+        for (idx, &bus) in self.b_prime.bus_order().iter().enumerate() {
+            solution.bus_angle_rad.insert(bus, theta[idx]);
+        }
+        for edge in network.graph.edge_weights() {
+            if let Edge::Branch(branch) = edge {
+                if let Some((from, to, b)) = self.b_prime.branch_data(branch.id) {
+                    solution
+                        .branch_flow_mw
+                        .insert(branch.id, b * (theta[from] - theta[to]) * BASE_MVA);
+                }
+            }
+        }
+        Ok(solution)
+    }
+
+    /// Locational marginal prices from an energy price and branch shadow prices.
+    ///
+    /// Computes `LMP_i = λ - Σ_k μ_k · PTDF_{k,i}` (PTDFs relative to the slack
+    /// bus) with a single solve against the cached factorization, so the full
+    /// PTDF matrix is never formed. `branch_shadow_prices` holds `μ_k` in $/MWh
+    /// for each binding from → to flow limit.
+    pub fn lmps(
+        &self,
+        energy_price: f64,
+        branch_shadow_prices: &HashMap<BranchId, f64>,
+    ) -> Result<HashMap<BusId, f64>, WarmSolverError> {
+        let slack = self.b_prime.slack_idx();
+        let mut weighted = vec![0.0; self.b_prime.n_bus()];
+        for (&branch, &mu) in branch_shadow_prices {
+            let (from, to, b) = self
+                .b_prime
+                .branch_data(branch)
+                .ok_or(WarmSolverError::UnknownBranch(branch.value()))?;
+            weighted[from] += mu * b;
+            weighted[to] -= mu * b;
+        }
+        // B' is symmetric, so Σ_k μ_k b_k (X_f,i - X_t,i) is one solve with X
+        let congestion = expand(&self.solver.solve(&reduce(&weighted, slack))?, slack);
+
+        Ok(self
+            .b_prime
+            .bus_order()
+            .iter()
+            .zip(congestion)
+            .map(|(&bus, c)| (bus, energy_price - c))
+            .collect())
+    }
+}
+
+/// Drop the slack entry from a full bus vector.
+fn reduce(full: &[f64], slack: usize) -> Vec<f64> {
+    full.iter()
+        .enumerate()
+        .filter(|(idx, _)| *idx != slack)
+        .map(|(_, &v)| v)
+        .collect()
+}
+
+/// Re-insert a zero at the slack position.
+fn expand(reduced: &[f64], slack: usize) -> Vec<f64> {
+    let mut full = reduced.to_vec();
+    full.insert(slack, 0.0);
+    full
+}
+
+/// Hash of everything B' depends on: bus order and in-service branch data.
+fn topology_fingerprint(network: &Network) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for node in network.graph.node_weights() {
+        if let Node::Bus(bus) = node {
+            bus.id.hash(&mut hasher);
+        }
+    }
+    for edge in network.graph.edge_weights() {
+        if let Edge::Branch(branch) = edge {
+            if branch.status {
+                branch.id.hash(&mut hasher);
+                branch.from_bus.hash(&mut hasher);
+                branch.to_bus.hash(&mut hasher);
+                branch.reactance.to_bits().hash(&mut hasher);
+                branch.tap_ratio.to_bits().hash(&mut hasher);
+            }
+        }
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{Branch, Bus};
+
+    /// Four-bus mesh: slack at bus 1, loads at buses 3 and 4.
+    fn create_4bus_network() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=4)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("Bus{}", i),
+                    base_kv: gat_core::Kilovolts(138.0),
+                    ..Default::default()
+                }))
+            })
+            .collect();
+        let lines = [
+            (1, 2, 0.1),
+            (2, 3, 0.15),
+            (1, 3, 0.2),
+            (3, 4, 0.1),
+            (2, 4, 0.25),
+        ];
+        for (k, &(from, to, x)) in lines.iter().enumerate() {
+            network.graph.add_edge(
+                buses[from - 1],
+                buses[to - 1],
+                Edge::Branch(Branch {
+                    id: BranchId::new(k + 1),
+                    name: format!("Line{}-{}", from, to),
+                    from_bus: BusId::new(from),
+                    to_bus: BusId::new(to),
+                    reactance: x,
+                    status: true,
+                    ..Default::default()
+                }),
+            );
+        }
+        network
+    }
+
+    fn injections(scale: f64) -> HashMap<BusId, f64> {
+        HashMap::from([
+            (BusId::new(2), 40.0),
+            (BusId::new(3), -90.0 * scale),
+            (BusId::new(4), -60.0 * scale),
+        ])
+    }
+
+    /// Reference: build and factorize B' from scratch.
+    fn cold_flows(network: &Network, injections: &HashMap<BusId, f64>) -> HashMap<BranchId, f64> {
+        WarmSolver::new(network)
+            .unwrap()
+            .resolve_dc(network, injections)
+            .unwrap()
+            .branch_flow_mw
+    }
+
+    #[test]
+    fn test_load_sweep_matches_full_resolves_with_one_factorization() {
+        let network = create_4bus_network();
+        let mut solver = WarmSolver::new(&network).unwrap();
+
+        for step in 0..100 {
+            let inj = injections(0.5 + step as f64 / 100.0);
+            let warm = solver.resolve_dc(&network, &inj).unwrap();
+            let cold = cold_flows(&network, &inj);
+
+            assert_eq!(warm.branch_flow_mw.len(), cold.len());
+            for (branch, flow) in &cold {
+                assert!((warm.branch_flow_mw[branch] - flow).abs() < 1e-9);
+            }
+            let net: f64 = inj.values().sum();
+            assert!((warm.slack_injection_mw + net).abs() < 1e-9);
+        }
+        assert_eq!(solver.factorizations(), 1);
+    }
+
+    #[test]
+    fn test_flows_balance_at_each_bus() {
+        let network = create_4bus_network();
+        let mut solver = WarmSolver::new(&network).unwrap();
+        let solution = solver.resolve_dc(&network, &injections(1.0)).unwrap();
+
+        // Bus 4: inflow on lines 3-4 and 2-4 equals its 60 MW load
+        let into_bus4 =
+            solution.branch_flow_mw[&BranchId::new(4)] + solution.branch_flow_mw[&BranchId::new(5)];
+        assert!((into_bus4 - 60.0).abs() < 1e-9);
+        assert_eq!(solution.bus_angle_rad[&solver.slack_bus()], 0.0);
+    }
+
+    #[test]
+    fn test_topology_change_triggers_refactorization() {
+        let mut network = create_4bus_network();
+        let mut solver = WarmSolver::new(&network).unwrap();
+        solver.resolve_dc(&network, &injections(1.0)).unwrap();
+
+        for edge in network.graph.edge_weights_mut() {
+            if let Edge::Branch(branch) = edge {
+                if branch.id == BranchId::new(5) {
+                    branch.status = false;
+                }
+            }
+        }
+        assert!(!solver.is_valid_for(&network));
+
+        let warm = solver.resolve_dc(&network, &injections(1.0)).unwrap();
+        assert_eq!(solver.factorizations(), 2);
+        assert!(!warm.branch_flow_mw.contains_key(&BranchId::new(5)));
+        // With 2-4 open, all of bus 4's load arrives over 3-4
+        assert!((warm.branch_flow_mw[&BranchId::new(4)] - 60.0).abs() < 1e-9);
+
+        // Unchanged topology afterwards keeps the new factorization
+        solver.resolve_dc(&network, &injections(0.8)).unwrap();
+        assert_eq!(solver.factorizations(), 2);
+    }
+
+    #[test]
+    fn test_lmps_match_explicit_ptdf() {
+        let network = create_4bus_network();
+        let solver = WarmSolver::new(&network).unwrap();
+        let congested = BranchId::new(4);
+        let mu = 12.0;
+        let lmps = solver
+            .lmps(30.0, &HashMap::from([(congested, mu)]))
+            .unwrap();
+
+        // PTDF of line 3-4 for a 1 MW injection at each bus, withdrawn at slack
+        let mut probe = WarmSolver::new(&network).unwrap();
+        for bus in (1..=4).map(BusId::new) {
+            let flow = probe
+                .resolve_dc(&network, &HashMap::from([(bus, 1.0)]))
+                .unwrap()
+                .branch_flow_mw[&congested];
+            assert!((lmps[&bus] - (30.0 - mu * flow)).abs() < 1e-9);
+        }
+        assert!((lmps[&solver.slack_bus()] - 30.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,294 @@
+//! Seeded multi-start greedy search for TEP build plans
+//!
+//! Rounding the LP relaxation can land far from the best integer plan. This
+//! heuristic searches integer plans directly:
+//!
+//! 1. Every plan is scored by the dispatch LP with its build decisions fixed.
+//!    Load may be shed at a penalty so that weak plans still solve; plans are
+//!    ranked by shed MW first and total annual cost second.
+//! 2. Each start grows a plan from nothing, adding one circuit at a time while
+//!    that improves the plan, then drops circuits that no longer pay for
+//!    themselves. The first start always takes the best move; later starts
+//!    pick at random among the best `candidate_pool` moves.
+//! 3. The LP relaxation gives a lower bound on the optimal cost, so the
+//!    reported gap says how far the best plan can be from optimal.
+//!
+//! The random choices come from a fixed LCG seeded by [`TepHeuristicConfig::seed`],
+//! so the same seed always yields the same plan.
+
+use super::solver::{assemble_solution, solve_lp, LpOutcome, TepData, TepError};
+use super::{TepProblem, TepSolution};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Penalty for shed load when scoring plans ($/MWh)
+const SHED_PENALTY: f64 = 1e5;
+/// Shed below this (MW) counts as serving all load
+const SHED_TOLERANCE: f64 = 1e-4;
+
+/// Multi-start greedy configuration
+#[derive(Debug, Clone)]
+pub struct TepHeuristicConfig {
+    /// Number of greedy runs (the first is deterministic)
+    pub starts: usize,
+    /// Seed for the randomized starts
+    pub seed: u64,
+    /// Randomized starts choose among this many best improving moves
+    pub candidate_pool: usize,
+}
+
+impl Default for TepHeuristicConfig {
+    fn default() -> Self {
+        Self {
+            starts: 8,
+            seed: 42,
+            candidate_pool: 3,
+        }
+    }
+}
+
+/// Plan ranking: shed MW, then total annual cost
+#[derive(Debug, Clone, Copy)]
+struct Score {
+    shed_mw: f64,
+    cost: f64,
+}
+
+impl Score {
+    fn better_than(&self, other: &Score) -> bool {
+        if (self.shed_mw - other.shed_mw).abs() > SHED_TOLERANCE {
+            return self.shed_mw < other.shed_mw;
+        }
+        self.cost < other.cost - 1e-9 * other.cost.abs().max(1.0)
+    }
+}
+
+/// Scores plans with the fixed-build LP, solving each distinct plan once
+struct Evaluator<'a> {
+    problem: &'a TepProblem,
+    data: &'a TepData,
+    cache: HashMap<Vec<usize>, LpOutcome>,
+}
+
+impl Evaluator<'_> {
+    fn score(&mut self, plan: &[usize]) -> Result<Score, TepError> {
+        if !self.cache.contains_key(plan) {
+            let outcome = solve_lp(self.problem, self.data, Some(plan), Some(SHED_PENALTY))?;
+            self.cache.insert(plan.to_vec(), outcome);
+        }
+        let outcome = &self.cache[plan];
+        Ok(Score {
+            shed_mw: outcome.shed_mw,
+            cost: outcome.cost,
+        })
+    }
+}
+
+/// Linear congruential generator, matching the one used for Monte Carlo sampling
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 = self.0.wrapping_mul(1103515245).wrapping_add(12345);
+        ((self.0 >> 16) % n as u64) as usize
+    }
+}
+
+/// Run the multi-start greedy and report the best plan against the LP bound.
+pub(super) fn solve_multi_start(
+    problem: &TepProblem,
+    data: &TepData,
+    config: &TepHeuristicConfig,
+    mip_gap: f64,
+    start: Instant,
+) -> Result<TepSolution, TepError> {
+    let lower_bound = solve_lp(problem, data, None, None)?.cost;
+
+    let max_circuits: Vec<usize> = problem
+        .candidates
+        .iter()
+        .map(|c| c.max_circuits.unwrap_or(1))
+        .collect();
+    let mut evaluator = Evaluator {
+        problem,
+        data,
+        cache: HashMap::new(),
+    };
+    let mut rng = Lcg(config.seed);
+
+    let mut best: Option<(Vec<usize>, Score)> = None;
+    for run in 0..config.starts.max(1) {
+        let pool = if run == 0 {
+            1
+        } else {
+            config.candidate_pool.max(1)
+        };
+        let (plan, score) = greedy_run(&mut evaluator, &max_circuits, pool, &mut rng)?;
+        if best.as_ref().map_or(true, |(_, b)| score.better_than(b)) {
+            best = Some((plan, score));
+        }
+    }
+    let (plan, score) = best.expect("at least one start");
+
+    if score.shed_mw > SHED_TOLERANCE {
+        return Err(TepError::Infeasible(format!(
+            "best plan found still sheds {:.2} MW",
+            score.shed_mw
+        )));
+    }
+
+    let mut result = assemble_solution(problem, data, &evaluator.cache[&plan], &plan);
+    let gap = ((result.total_cost - lower_bound) / result.total_cost.abs().max(1e-9)).max(0.0);
+    result.lower_bound = Some(lower_bound);
+    result.mip_gap = Some(gap);
+    result.optimal = gap <= mip_gap;
+    result.iterations = evaluator.cache.len();
+    result.solve_time = start.elapsed();
+    result.status_message = format!(
+        "Multi-start greedy ({} starts, seed {}), {:.4}% above LP bound",
+        config.starts.max(1),
+        config.seed,
+        gap * 100.0
+    );
+    Ok(result)
+}
+
+/// Grow a plan one circuit at a time, then prune circuits that do not pay off.
+fn greedy_run(
+    evaluator: &mut Evaluator,
+    max_circuits: &[usize],
+    pool: usize,
+    rng: &mut Lcg,
+) -> Result<(Vec<usize>, Score), TepError> {
+    let mut plan = vec![0; max_circuits.len()];
+    let mut current = evaluator.score(&plan)?;
+
+    loop {
+        let mut moves = Vec::new();
+        for (k, &max) in max_circuits.iter().enumerate() {
+            if plan[k] >= max {
+                continue;
+            }
+            plan[k] += 1;
+            let score = evaluator.score(&plan)?;
+            plan[k] -= 1;
+            if score.better_than(&current) {
+                moves.push((k, score));
+            }
+        }
+        if moves.is_empty() {
+            break;
+        }
+        moves.sort_by(|a, b| {
+            a.1.shed_mw
+                .total_cmp(&b.1.shed_mw)
+                .then(a.1.cost.total_cmp(&b.1.cost))
+        });
+        let pick = if pool > 1 {
+            rng.below(pool.min(moves.len()))
+        } else {
+            0
+        };
+        let (k, score) = moves[pick];
+        plan[k] += 1;
+        current = score;
+    }
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for k in 0..plan.len() {
+            if plan[k] == 0 {
+                continue;
+            }
+            plan[k] -= 1;
+            let score = evaluator.score(&plan)?;
+            if score.better_than(&current) {
+                current = score;
+                improved = true;
+            } else {
+                plan[k] += 1;
+            }
+        }
+    }
+
+    Ok((plan, current))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tep::{solve_tep, TepProblemBuilder, TepSolverConfig};
+    use gat_core::{
+        Bus, BusId, CostModel, Gen, GenId, Load, LoadId, Megavars, Megawatts, Network, Node,
+    };
+
+    /// Two unconnected buses: cheap generation at bus 1, a 100 MW load and
+    /// expensive generation at bus 2. Building one of three 1-2 candidates
+    /// saves $40/MWh on whatever it carries.
+    ///
+    /// A (100 MW, $50M) carries the whole load and is the optimum. B (50 MW,
+    /// $40M) is cheaper per build but only halves the expensive generation, and
+    /// C is A at a higher price. With A alone the LP relaxation is integral, so
+    /// its bound meets the optimum.
+    fn two_area_problem() -> TepProblem {
+        let mut network = Network::new();
+        for i in 1..=2 {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("Bus{}", i),
+                ..Bus::default()
+            }));
+        }
+        for (id, bus, cost) in [(1, 1, 10.0), (2, 2, 50.0)] {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(id), format!("G{}", id), BusId::new(bus))
+                    .with_p_limits(0.0, 200.0)
+                    .with_cost(CostModel::linear(0.0, cost)),
+            ));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load2".into(),
+            bus: BusId::new(2),
+            active_power: Megawatts(100.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+
+        TepProblemBuilder::new(network)
+            .candidate("A", BusId::new(1), BusId::new(2), 0.01, 100.0, 50e6)
+            .candidate("B", BusId::new(1), BusId::new(2), 0.01, 50.0, 40e6)
+            .candidate("C", BusId::new(1), BusId::new(2), 0.01, 100.0, 60e6)
+            .build()
+    }
+
+    #[test]
+    fn test_heuristic_finds_known_optimum() {
+        let problem = two_area_problem();
+        let config = TepSolverConfig::default().with_heuristic(6, 7);
+        let solution = solve_tep(&problem, &config).unwrap();
+
+        assert_eq!(solution.built_line_names(), vec!["A"]);
+        let bound = solution.lower_bound.expect("heuristic reports a bound");
+        assert!(bound <= solution.total_cost + 1e-6 * solution.total_cost);
+        assert!(solution.mip_gap.unwrap() < 1e-4, "{}", solution.summary());
+        assert!(solution.optimal);
+    }
+
+    #[test]
+    fn test_same_seed_reproduces_plan() {
+        let problem = two_area_problem();
+        let config = TepSolverConfig::default().with_heuristic(6, 1234);
+        let plan = |s: &TepSolution| {
+            s.build_decisions
+                .iter()
+                .map(|d| d.circuits_to_build)
+                .collect::<Vec<_>>()
+        };
+        let first = solve_tep(&problem, &config).unwrap();
+        let second = solve_tep(&problem, &config).unwrap();
+        assert_eq!(plan(&first), plan(&second));
+        assert_eq!(first.total_cost, second.total_cost);
+    }
+}
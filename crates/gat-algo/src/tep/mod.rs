@@ -75,10 +75,12 @@
 //! - **Alguacil et al. (2003)**: "Transmission network expansion planning: A mixed-integer LP approach"
 //!   - Modern MILP techniques for large-scale TEP
 
+mod heuristic;
 mod problem;
 mod solution;
 mod solver;
 
+pub use heuristic::TepHeuristicConfig;
 pub use problem::{CandidateId, CandidateLine, TepProblem, TepProblemBuilder};
 pub use solution::{LineBuildDecision, TepSolution};
 pub use solver::{solve_tep, TepError, TepSolverConfig};
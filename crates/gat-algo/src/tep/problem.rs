@@ -336,6 +336,7 @@ mod tests {
             bus: BusId::new(3),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(0.0),
+            zip: None,
         }));
 
         network
@@ -53,6 +53,8 @@ pub struct TepSolution {
     pub solve_time: Duration,
     /// MIP gap (relative optimality gap, if applicable)
     pub mip_gap: Option<f64>,
+    /// Lower bound on the optimal total cost (LP relaxation), when reported
+    pub lower_bound: Option<f64>,
     /// Solver status message
     pub status_message: String,
 }
@@ -73,6 +75,7 @@ impl TepSolution {
             iterations: 0,
             solve_time: Duration::ZERO,
             mip_gap: None,
+            lower_bound: None,
             status_message: String::new(),
         }
     }
@@ -132,6 +135,9 @@ impl TepSolution {
         if let Some(gap) = self.mip_gap {
             s.push_str(&format!("MIP Gap: {:.4}%\n", gap * 100.0));
         }
+        if let Some(bound) = self.lower_bound {
+            s.push_str(&format!("Lower Bound: ${:.2}\n", bound));
+        }
 
         if !self.build_decisions.is_empty() {
             s.push_str("\nBuild Decisions:\n");
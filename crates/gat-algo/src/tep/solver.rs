@@ -2,6 +2,7 @@
 //!
 //! Implements DC-based Mixed-Integer Linear Programming formulation for TEP.
 
+use super::heuristic::{solve_multi_start, TepHeuristicConfig};
 use super::{CandidateId, CandidateLine, LineBuildDecision, TepProblem, TepSolution};
 use gat_core::{BusId, Edge, Network, Node};
 use good_lp::solvers::clarabel::clarabel;
@@ -18,6 +19,8 @@ pub struct TepSolverConfig {
     pub mip_gap: f64,
     /// Whether to enable verbose solver output
     pub verbose: bool,
+    /// Run the seeded multi-start greedy instead of rounding the LP relaxation
+    pub heuristic: Option<TepHeuristicConfig>,
 }
 
 impl Default for TepSolverConfig {
@@ -26,10 +29,23 @@ impl Default for TepSolverConfig {
             max_time_seconds: 300.0, // 5 minutes
             mip_gap: 0.01,           // 1% gap
             verbose: false,
+            heuristic: None,
         }
     }
 }
 
+impl TepSolverConfig {
+    /// Use the multi-start greedy heuristic with `starts` runs seeded by `seed`.
+    pub fn with_heuristic(mut self, starts: usize, seed: u64) -> Self {
+        self.heuristic = Some(TepHeuristicConfig {
+            starts,
+            seed,
+            ..TepHeuristicConfig::default()
+        });
+        self
+    }
+}
+
 /// TEP solver errors
 #[derive(Debug, Clone)]
 pub enum TepError {
@@ -85,11 +101,54 @@ struct BranchData {
     capacity_mw: Option<f64>,
 }
 
+/// Network data extracted once and shared by every LP solve
+pub(super) struct TepData {
+    buses: Vec<BusData>,
+    generators: Vec<GenData>,
+    branches: Vec<BranchData>,
+    loads: HashMap<BusId, f64>,
+    bus_map: HashMap<BusId, usize>,
+}
+
+impl TepData {
+    pub(super) fn extract(problem: &TepProblem) -> Result<Self, TepError> {
+        let (buses, generators, branches, loads) = extract_network_data(&problem.network)?;
+        let bus_map = buses.iter().map(|b| (b.id, b.index)).collect();
+        Ok(Self {
+            buses,
+            generators,
+            branches,
+            loads,
+            bus_map,
+        })
+    }
+}
+
+/// Raw values of one LP solve
+pub(super) struct LpOutcome {
+    /// Candidate build variables (circuits, possibly fractional)
+    pub(super) build: Vec<f64>,
+    /// Generator output per generator (MW)
+    dispatch: Vec<f64>,
+    /// Bus angles by bus index (rad)
+    angles: Vec<f64>,
+    /// Candidate flows (MW)
+    flows: Vec<f64>,
+    /// Load shed across all buses (MW); zero unless shedding was allowed
+    pub(super) shed_mw: f64,
+    /// Investment plus operating cost, excluding the shedding penalty
+    pub(super) cost: f64,
+}
+
 /// Solve the TEP problem
 ///
 /// This is a **simplified LP relaxation** that treats binary variables as continuous [0,1].
 /// For exact MILP, use the `solver-highs` feature with HiGHS solver.
 ///
+/// With [`TepSolverConfig::heuristic`] set, a seeded multi-start greedy searches
+/// integer plans instead and reports the LP relaxation as a lower bound in
+/// [`TepSolution::lower_bound`] and [`TepSolution::mip_gap`].
+///
 /// # Example
 ///
 /// ```no_run
@@ -106,7 +165,7 @@ struct BranchData {
 /// println!("{}", solution.summary());
 /// # Ok::<(), gat_algo::tep::TepError>(())
 /// ```
-pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepSolution, TepError> {
+pub fn solve_tep(problem: &TepProblem, config: &TepSolverConfig) -> Result<TepSolution, TepError> {
     let start = Instant::now();
 
     // Validate inputs
@@ -114,10 +173,40 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
         return Err(TepError::NoCandidates);
     }
 
-    // Extract network data
-    let (buses, generators, branches, loads) = extract_network_data(&problem.network)?;
-    let bus_map: HashMap<BusId, usize> = buses.iter().map(|b| (b.id, b.index)).collect();
-    let _n_bus = buses.len();
+    let data = TepData::extract(problem)?;
+    if let Some(heuristic) = &config.heuristic {
+        return solve_multi_start(problem, &data, heuristic, config.mip_gap, start);
+    }
+
+    let outcome = solve_lp(problem, &data, None, None)?;
+
+    // Round build decisions to the nearest integer for the relaxed solution
+    let circuits: Vec<usize> = outcome.build.iter().map(|x| x.round() as usize).collect();
+    let mut result = assemble_solution(problem, &data, &outcome, &circuits);
+    result.optimal = true;
+    result.solve_time = start.elapsed();
+    result.status_message = "Optimal (LP relaxation)".to_string();
+    Ok(result)
+}
+
+/// Solve the dispatch LP with build decisions relaxed (`fixed_build = None`)
+/// or fixed to the given circuit counts.
+///
+/// With `shed_penalty` set, load at each bus may be shed at that cost ($/MWh),
+/// which keeps plans that cannot serve all load solvable.
+pub(super) fn solve_lp(
+    problem: &TepProblem,
+    data: &TepData,
+    fixed_build: Option<&[usize]>,
+    shed_penalty: Option<f64>,
+) -> Result<LpOutcome, TepError> {
+    let TepData {
+        buses,
+        generators,
+        branches,
+        loads,
+        bus_map,
+    } = data;
 
     // === LP/MILP Formulation ===
     // Variables:
@@ -125,6 +214,7 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
     // - θ[j]: Bus voltage angle (continuous, rad)
     // - x[k]: Candidate line build decision (binary → relaxed to [0,1])
     // - f[k]: Candidate line flow (continuous, MW)
+    // - s[j]: Load shed (continuous, MW; only with a shedding penalty)
 
     let mut vars = variables!();
 
@@ -132,7 +222,7 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
     let mut gen_vars: Vec<(String, BusId, Variable)> = Vec::new();
     let mut operating_cost_expr = Expression::from(0.0);
 
-    for gen in &generators {
+    for gen in generators {
         let pmin = gen.pmin.max(0.0);
         let pmax = if gen.pmax.is_finite() { gen.pmax } else { 1e6 };
         let p_var = vars.add(variable().min(pmin).max(pmax));
@@ -146,7 +236,7 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
     // Bus angle variables (reference bus = 0)
     let ref_bus_idx = 0;
     let mut theta_vars: HashMap<usize, Variable> = HashMap::new();
-    for bus in &buses {
+    for bus in buses {
         if bus.index != ref_bus_idx {
             // Reasonable angle bounds for DC power flow
             let theta = vars.add(
@@ -158,15 +248,35 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
         }
     }
 
+    // Load shedding variables, bounded by the bus load
+    let mut shed_vars: HashMap<usize, Variable> = HashMap::new();
+    let mut shed_cost_expr = Expression::from(0.0);
+    if let Some(penalty) = shed_penalty {
+        for bus in buses {
+            let load = loads.get(&bus.id).copied().unwrap_or(0.0);
+            if load > 0.0 {
+                let s_var = vars.add(variable().min(0.0).max(load));
+                shed_cost_expr += penalty * problem.operating_hours * s_var;
+                shed_vars.insert(bus.index, s_var);
+            }
+        }
+    }
+
     // Candidate line variables
     let mut candidate_build_vars: Vec<(CandidateId, Variable)> = Vec::new();
     let mut candidate_flow_vars: Vec<(CandidateId, Variable, &CandidateLine)> = Vec::new();
     let mut investment_cost_expr = Expression::from(0.0);
 
-    for candidate in &problem.candidates {
-        // Build decision: relaxed to continuous [0, max_circuits]
+    for (k, candidate) in problem.candidates.iter().enumerate() {
+        // Build decision: relaxed to continuous [0, max_circuits], or fixed
         let max = candidate.max_circuits.unwrap_or(1) as f64;
-        let x_var = vars.add(variable().min(0.0).max(max));
+        let x_var = match fixed_build {
+            Some(plan) => {
+                let built = plan[k] as f64;
+                vars.add(variable().min(built).max(built))
+            }
+            None => vars.add(variable().min(0.0).max(max)),
+        };
         candidate_build_vars.push((candidate.id, x_var));
 
         // Flow variable: bounded by capacity * build decision
@@ -179,11 +289,13 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
         investment_cost_expr += annual_investment * x_var;
     }
 
-    // Total objective: investment + operating cost
+    // Total objective: investment + operating cost (+ shedding penalty)
     let total_cost_expr = investment_cost_expr.clone() + operating_cost_expr.clone();
 
     // Create problem
-    let mut model = vars.minimise(total_cost_expr).using(clarabel);
+    let mut model = vars
+        .minimise(total_cost_expr.clone() + shed_cost_expr)
+        .using(clarabel);
 
     // === Power Balance Constraints ===
     // At each bus: Σ P_gen - Σ P_load = Σ P_out (to other buses)
@@ -205,12 +317,12 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
     // For candidates: flow = f_var (with Big-M constraints below)
 
     let mut bus_net_flow: HashMap<usize, Expression> = HashMap::new();
-    for bus in &buses {
+    for bus in buses {
         bus_net_flow.insert(bus.index, Expression::from(0.0));
     }
 
     // Existing branch flows
-    for branch in &branches {
+    for branch in branches {
         let i = *bus_map.get(&branch.from_bus).ok_or_else(|| {
             TepError::NetworkValidation(format!("Branch from_bus {:?} not found", branch.from_bus))
         })?;
@@ -255,11 +367,14 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
     }
 
     // Add power balance constraints
-    for bus in &buses {
-        let gen_at_bus = bus_gen_expr
+    for bus in buses {
+        let mut gen_at_bus = bus_gen_expr
             .get(&bus.index)
             .cloned()
             .unwrap_or_else(|| Expression::from(0.0));
+        if let Some(&s_var) = shed_vars.get(&bus.index) {
+            gen_at_bus += s_var;
+        }
         let load_at_bus = loads.get(&bus.id).copied().unwrap_or(0.0);
         let net_flow = bus_net_flow
             .get(&bus.index)
@@ -319,80 +434,89 @@ pub fn solve_tep(problem: &TepProblem, _config: &TepSolverConfig) -> Result<TepS
         .solve()
         .map_err(|e| TepError::SolverFailed(format!("{:?}", e)))?;
 
-    // === Extract Results ===
+    let angles = buses
+        .iter()
+        .map(|bus| {
+            theta_vars
+                .get(&bus.index)
+                .map(|v| solution.value(*v))
+                .unwrap_or(0.0)
+        })
+        .collect();
+
+    Ok(LpOutcome {
+        build: candidate_build_vars
+            .iter()
+            .map(|(_, x)| solution.value(*x))
+            .collect(),
+        dispatch: gen_vars
+            .iter()
+            .map(|(_, _, p)| solution.value(*p))
+            .collect(),
+        angles,
+        flows: candidate_flow_vars
+            .iter()
+            .map(|(_, f, _)| solution.value(*f))
+            .collect(),
+        shed_mw: shed_vars.values().map(|s| solution.value(*s)).sum(),
+        cost: solution.eval(&total_cost_expr),
+    })
+}
+
+/// Build a [`TepSolution`] from an LP outcome and integer build decisions.
+///
+/// Status fields are left for the caller.
+pub(super) fn assemble_solution(
+    problem: &TepProblem,
+    data: &TepData,
+    outcome: &LpOutcome,
+    circuits: &[usize],
+) -> TepSolution {
     let mut result = TepSolution::new();
-    result.optimal = true;
-    result.solve_time = start.elapsed();
-    result.status_message = "Optimal (LP relaxation)".to_string();
 
-    // Extract build decisions (round to nearest integer for relaxed solution)
-    for ((cand_id, x_var), (_, _, candidate)) in
-        candidate_build_vars.iter().zip(candidate_flow_vars.iter())
-    {
-        let x_val = solution.value(*x_var);
-        let circuits = x_val.round() as usize;
-        let cost = if circuits > 0 {
-            candidate.investment_cost * circuits as f64
+    for (candidate, &built) in problem.candidates.iter().zip(circuits) {
+        let cost = if built > 0 {
+            candidate.investment_cost * built as f64
         } else {
             0.0
         };
 
         result.build_decisions.push(LineBuildDecision {
-            candidate_id: *cand_id,
+            candidate_id: candidate.id,
             name: candidate.name.clone(),
-            circuits_to_build: circuits,
+            circuits_to_build: built,
             investment_cost: cost,
         });
     }
 
     // Calculate costs
-    result.investment_cost = result
-        .build_decisions
+    result.investment_cost = problem
+        .candidates
         .iter()
-        .map(|d| {
-            problem.annualized_investment_cost(
-                problem
-                    .candidates
-                    .iter()
-                    .find(|c| c.id == d.candidate_id)
-                    .unwrap(),
-            ) * d.circuits_to_build as f64
-        })
+        .zip(circuits)
+        .map(|(candidate, &built)| problem.annualized_investment_cost(candidate) * built as f64)
         .sum();
 
     // Generator dispatch and operating cost
     let mut total_op_cost = 0.0;
-    for (name, _, p_var) in &gen_vars {
-        let p = solution.value(*p_var);
-        result.generator_dispatch.insert(name.clone(), p);
-
-        if let Some(gen) = generators.iter().find(|g| &g.name == name) {
-            total_op_cost += gen.cost_per_mw * p * problem.operating_hours;
-        }
+    for (gen, &p) in data.generators.iter().zip(&outcome.dispatch) {
+        result.generator_dispatch.insert(gen.name.clone(), p);
+        total_op_cost += gen.cost_per_mw * p * problem.operating_hours;
     }
     result.operating_cost = total_op_cost;
     result.total_cost = result.investment_cost + result.operating_cost;
 
     // Bus angles
-    for bus in &buses {
-        let angle = if bus.index == ref_bus_idx {
-            0.0
-        } else {
-            theta_vars
-                .get(&bus.index)
-                .map(|v| solution.value(*v))
-                .unwrap_or(0.0)
-        };
+    for (bus, &angle) in data.buses.iter().zip(&outcome.angles) {
         result.bus_angles.insert(bus.name.clone(), angle);
     }
 
     // Candidate flows
-    for (cand_id, f_var, _) in &candidate_flow_vars {
-        let flow = solution.value(*f_var);
-        result.candidate_flows.insert(*cand_id, flow);
+    for (candidate, &flow) in problem.candidates.iter().zip(&outcome.flows) {
+        result.candidate_flows.insert(candidate.id, flow);
     }
 
-    Ok(result)
+    result
 }
 
 /// Extract network data for solver
@@ -540,6 +664,7 @@ mod tests {
                 bus: BusId::new(*bus),
                 active_power: gat_core::Megawatts(*mw),
                 reactive_power: gat_core::Megavars(0.0),
+                zip: None,
             }));
         }
 
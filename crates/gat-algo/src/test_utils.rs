@@ -1,7 +1,13 @@
 use crate::io::{staged_output_path, OutputStage};
+use crate::opf::OpfSolution;
 use anyhow::{Context, Result};
 use polars::prelude::{DataFrame, ParquetReader, SerReader};
-use std::{fs::File, path::Path};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
 
 pub fn read_stage_dataframe(base: &Path, stage: OutputStage) -> Result<DataFrame> {
     let path = staged_output_path(base, stage.as_str());
@@ -10,3 +16,166 @@ pub fn read_stage_dataframe(base: &Path, stage: OutputStage) -> Result<DataFrame
         .finish()
         .context("reading stage parquet output")
 }
+
+/// Environment variable that makes [`assert_solution_matches_golden`]
+/// rewrite the golden file from the solution instead of comparing
+pub const UPDATE_GOLDEN_ENV: &str = "GAT_UPDATE_GOLDEN";
+
+/// Reference quantities of an OPF solution, stored as JSON under
+/// `test_data/golden/<case>.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GoldenSolution {
+    /// Total cost ($/hr)
+    pub objective_value: f64,
+    /// Generator active power (MW)
+    pub generator_p: BTreeMap<String, f64>,
+    /// Bus LMPs ($/MWh)
+    pub bus_lmp: BTreeMap<String, f64>,
+    /// Bus voltage angles, in the units the solver reports
+    pub bus_voltage_ang: BTreeMap<String, f64>,
+    /// Branch active power flow, from-side (MW)
+    pub branch_p_flow: BTreeMap<String, f64>,
+}
+
+impl From<&OpfSolution> for GoldenSolution {
+    fn from(solution: &OpfSolution) -> Self {
+        let sorted = |map: &HashMap<String, f64>| map.clone().into_iter().collect();
+        Self {
+            objective_value: solution.objective_value,
+            generator_p: sorted(&solution.generator_p),
+            bus_lmp: sorted(&solution.bus_lmp),
+            bus_voltage_ang: sorted(&solution.bus_voltage_ang),
+            branch_p_flow: sorted(&solution.branch_p_flow),
+        }
+    }
+}
+
+/// Allowed drift from a golden solution, per quantity.
+///
+/// The defaults are loose enough to absorb solver and dependency updates
+/// and tight enough to catch a changed dispatch.
+#[derive(Debug, Clone, Copy)]
+pub struct GoldenTolerances {
+    /// Relative objective drift
+    pub objective_rel: f64,
+    /// Generator dispatch drift (MW)
+    pub generator_p_mw: f64,
+    /// LMP drift ($/MWh)
+    pub bus_lmp: f64,
+    /// Voltage angle drift, in the units the solver reports
+    pub bus_voltage_ang: f64,
+    /// Branch flow drift (MW)
+    pub branch_p_flow_mw: f64,
+}
+
+impl Default for GoldenTolerances {
+    fn default() -> Self {
+        Self {
+            objective_rel: 1e-4,
+            generator_p_mw: 0.1,
+            bus_lmp: 0.01,
+            bus_voltage_ang: 0.01,
+            branch_p_flow_mw: 0.1,
+        }
+    }
+}
+
+/// Path of the golden file for `case`
+pub fn golden_path(case: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_data/golden")
+        .join(format!("{case}.json"))
+}
+
+/// Load the golden solution for `case`
+pub fn load_golden(case: &str) -> Result<GoldenSolution> {
+    let path = golden_path(case);
+    let file = File::open(&path).with_context(|| format!("opening {}", path.display()))?;
+    serde_json::from_reader(file).with_context(|| format!("parsing {}", path.display()))
+}
+
+/// Every quantity of `solution` outside `tolerances` of `golden`, one
+/// message per drift. Quantities missing from the solution count as drift;
+/// extra entries in the solution are ignored.
+pub fn golden_drift(
+    golden: &GoldenSolution,
+    solution: &OpfSolution,
+    tolerances: &GoldenTolerances,
+) -> Vec<String> {
+    let mut drift = Vec::new();
+
+    let objective_band = tolerances.objective_rel * golden.objective_value.abs().max(1.0);
+    if (solution.objective_value - golden.objective_value).abs() > objective_band {
+        drift.push(format!(
+            "objective_value: {} vs golden {} (band {})",
+            solution.objective_value, golden.objective_value, objective_band
+        ));
+    }
+
+    let quantities = [
+        (
+            "generator_p",
+            &golden.generator_p,
+            &solution.generator_p,
+            tolerances.generator_p_mw,
+        ),
+        (
+            "bus_lmp",
+            &golden.bus_lmp,
+            &solution.bus_lmp,
+            tolerances.bus_lmp,
+        ),
+        (
+            "bus_voltage_ang",
+            &golden.bus_voltage_ang,
+            &solution.bus_voltage_ang,
+            tolerances.bus_voltage_ang,
+        ),
+        (
+            "branch_p_flow",
+            &golden.branch_p_flow,
+            &solution.branch_p_flow,
+            tolerances.branch_p_flow_mw,
+        ),
+    ];
+    for (quantity, expected, actual, band) in quantities {
+        for (name, &reference) in expected {
+            match actual.get(name) {
+                Some(&value) if (value - reference).abs() <= band => {}
+                Some(&value) => drift.push(format!(
+                    "{quantity}[{name}]: {value} vs golden {reference} (band {band})"
+                )),
+                None => drift.push(format!("{quantity}[{name}]: missing from solution")),
+            }
+        }
+    }
+    drift
+}
+
+/// Assert that `solution` matches the golden file for `case` within
+/// `tolerances`, listing every drifted quantity on failure.
+///
+/// With [`UPDATE_GOLDEN_ENV`] set, the golden file is rewritten from
+/// `solution` instead; review the diff before committing it.
+pub fn assert_solution_matches_golden(
+    case: &str,
+    solution: &OpfSolution,
+    tolerances: &GoldenTolerances,
+) {
+    if std::env::var_os(UPDATE_GOLDEN_ENV).is_some() {
+        let path = golden_path(case);
+        let json = serde_json::to_string_pretty(&GoldenSolution::from(solution))
+            .expect("serializing golden solution");
+        std::fs::write(&path, json + "\n")
+            .unwrap_or_else(|e| panic!("writing {}: {e}", path.display()));
+        return;
+    }
+
+    let golden = load_golden(case).unwrap_or_else(|e| panic!("{e:#}"));
+    let drift = golden_drift(&golden, solution, tolerances);
+    assert!(
+        drift.is_empty(),
+        "solution drifted from golden case {case}:\n  {}",
+        drift.join("\n  ")
+    );
+}
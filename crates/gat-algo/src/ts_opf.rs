@@ -0,0 +1,784 @@
+//! Time-series OPF driver.
+//!
+//! Chains a load forecast (e.g. the output of `gat ts` tooling) into the OPF
+//! engine: one OPF solve per timestamp, results stacked into a single
+//! long-format Parquet table.
+//!
+//! ## Input format
+//!
+//! A tidy Parquet table with one row per (timestamp, element):
+//!
+//! | Column           | Type       | Meaning                                            |
+//! |------------------|------------|----------------------------------------------------|
+//! | `timestamp`      | any        | Step key; steps are solved in sorted order         |
+//! | `bus_id`         | int        | Bus whose load the row sets                        |
+//! | `load_mw`        | float      | Total active load at the bus                       |
+//! | `load_mvar`      | float, opt | Total reactive load (default: keep base P/Q ratio) |
+//! | `gen_id`         | int, opt   | Variable generator the row applies to              |
+//! | `p_available_mw` | float, opt | Available output of `gen_id`                       |
+//!
+//! Rows with `bus_id`/`load_mw` set are load rows; rows with
+//! `gen_id`/`p_available_mw` set are availability rows, which mark the unit as
+//! variable (`Gen::p_available`) so OPF can curtail it. Buses absent from the
+//! table keep their base-case load.
+//!
+//! If a bus (or capped generator) has no row at some timestamp, its last value
+//! is carried forward and a warning is printed. Before its first row it uses
+//! the base case.
+//!
+//! ## Output format
+//!
+//! One row per (step, generator): `timestamp`, `hour` (0-based step index),
+//! `generator`, `p_mw`, `q_mvar`, `curtailment_mw` (null for dispatchable
+//! units), `objective`, `converged`. A step whose OPF fails is kept with null
+//! dispatch and `converged = false`.
+//!
+//! ## Parallel execution
+//!
+//! Steps are independent snapshots, so [`solve_with_options`] can solve them on
+//! a thread pool ([`TsOpfOptions::threads`]); rows are still written in
+//! timestamp order and match a sequential run exactly. Ramp limits
+//! ([`TsOpfOptions::ramps`]) couple each step to the previous dispatch, so a
+//! run with ramps is always solved sequentially.
+
+use crate::opf::ac_nlp::RampConstraint;
+use crate::opf::{OpfMethod, OpfSolution, OpfSolver};
+use anyhow::{anyhow, Context, Result};
+use gat_core::{BusId, Load, LoadId, Megavars, Megawatts, Network, Node};
+use polars::prelude::*;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::path::Path;
+
+/// Summary of a time-series OPF run.
+#[derive(Debug, Clone, Default)]
+pub struct TsOpfSummary {
+    /// Number of timestamps solved
+    pub steps: usize,
+    /// Steps whose OPF converged
+    pub converged_steps: usize,
+    /// Rows written to the output table
+    pub rows: usize,
+    /// Number of (timestamp, element) values filled by carrying the last value forward
+    pub carried_forward: usize,
+}
+
+/// How a time-series run solves its steps.
+#[derive(Debug, Clone)]
+pub struct TsOpfOptions {
+    pub method: OpfMethod,
+    /// Worker threads: 1 solves steps one after another, 0 uses every core
+    pub threads: usize,
+    /// Ramp limits between consecutive steps (each step counts as one hour)
+    pub ramps: Vec<RampConstraint>,
+}
+
+impl TsOpfOptions {
+    /// Sequential run with `method` and no ramp limits.
+    pub fn new(method: OpfMethod) -> Self {
+        Self {
+            method,
+            threads: 1,
+            ramps: Vec::new(),
+        }
+    }
+
+    /// Solve uncoupled steps on `threads` workers (0 for every core).
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Limit how far a generator may move between consecutive steps.
+    pub fn with_ramp(mut self, ramp: RampConstraint) -> Self {
+        self.ramps.push(ramp);
+        self
+    }
+}
+
+/// Per-step inputs for one bus or generator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LoadValue {
+    p_mw: f64,
+    q_mvar: Option<f64>,
+}
+
+/// Forecast pivoted by timestamp, in solve order.
+struct Forecast {
+    timestamps: Vec<String>,
+    loads: Vec<HashMap<usize, LoadValue>>,
+    available: Vec<HashMap<usize, f64>>,
+    carried_forward: usize,
+}
+
+/// Solve OPF for every timestamp in `timeseries_path` and write the results.
+///
+/// See the [module docs](self) for the input and output layouts.
+pub fn solve(
+    grid: &Network,
+    timeseries_path: &Path,
+    out_path: &Path,
+    method: OpfMethod,
+) -> Result<TsOpfSummary> {
+    solve_with_options(grid, timeseries_path, out_path, &TsOpfOptions::new(method))
+}
+
+/// [`solve`] with thread count and ramp limits from `options`.
+pub fn solve_with_options(
+    grid: &Network,
+    timeseries_path: &Path,
+    out_path: &Path,
+    options: &TsOpfOptions,
+) -> Result<TsOpfSummary> {
+    let file = File::open(timeseries_path)
+        .with_context(|| format!("opening time series '{}'", timeseries_path.display()))?;
+    let df = ParquetReader::new(file)
+        .finish()
+        .with_context(|| format!("reading time series '{}'", timeseries_path.display()))?;
+    let forecast = read_forecast(&df)?;
+
+    let solver = OpfSolver::new().with_method(options.method);
+    let mut summary = TsOpfSummary {
+        steps: forecast.timestamps.len(),
+        carried_forward: forecast.carried_forward,
+        ..TsOpfSummary::default()
+    };
+
+    let mut timestamps = Vec::new();
+    let mut hours = Vec::new();
+    let mut generators = Vec::new();
+    let mut p_mw = Vec::new();
+    let mut q_mvar = Vec::new();
+    let mut curtailment = Vec::new();
+    let mut objectives = Vec::new();
+    let mut converged = Vec::new();
+
+    let solutions = if options.threads != 1 && options.ramps.is_empty() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(options.threads)
+            .build()
+            .context("building time-series OPF thread pool")?;
+        pool.install(|| {
+            (0..forecast.timestamps.len())
+                .into_par_iter()
+                .map(|hour| {
+                    let network =
+                        apply_step(grid, &forecast.loads[hour], &forecast.available[hour]);
+                    solve_step(&solver, &network, &forecast.timestamps[hour])
+                })
+                .collect::<Vec<_>>()
+        })
+    } else {
+        solve_sequential(grid, &forecast, &solver, &options.ramps)
+    };
+
+    for ((hour, timestamp), solution) in forecast.timestamps.iter().enumerate().zip(solutions) {
+        let step_converged = solution.as_ref().is_some_and(|s| s.converged);
+        if step_converged {
+            summary.converged_steps += 1;
+        }
+
+        // ID order keeps rows identical across import paths
+        for gen in grid.generators_sorted() {
+            timestamps.push(timestamp.clone());
+            hours.push(hour as i64);
+            generators.push(gen.name.clone());
+            p_mw.push(
+                solution
+                    .as_ref()
+                    .and_then(|s| s.generator_p.get(&gen.name).copied()),
+            );
+            q_mvar.push(
+                solution
+                    .as_ref()
+                    .and_then(|s| s.generator_q.get(&gen.name).copied()),
+            );
+            curtailment.push(
+                solution
+                    .as_ref()
+                    .and_then(|s| s.renewable_curtailment.get(&gen.name).copied()),
+            );
+            objectives.push(solution.as_ref().map(|s| s.objective_value));
+            converged.push(step_converged);
+        }
+    }
+
+    summary.rows = timestamps.len();
+    let mut out = DataFrame::new(vec![
+        Series::new("timestamp", timestamps),
+        Series::new("hour", hours),
+        Series::new("generator", generators),
+        Series::new("p_mw", p_mw),
+        Series::new("q_mvar", q_mvar),
+        Series::new("curtailment_mw", curtailment),
+        Series::new("objective", objectives),
+        Series::new("converged", converged),
+    ])
+    .context("building time-series OPF results")?;
+
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("creating output directory '{}'", parent.display()))?;
+    }
+    let mut file = File::create(out_path)
+        .with_context(|| format!("creating Parquet output '{}'", out_path.display()))?;
+    ParquetWriter::new(&mut file)
+        .finish(&mut out)
+        .context("writing time-series OPF results")?;
+
+    Ok(summary)
+}
+
+/// Solve the steps in order, holding ramp-limited units near their previous dispatch.
+fn solve_sequential(
+    grid: &Network,
+    forecast: &Forecast,
+    solver: &OpfSolver,
+    ramps: &[RampConstraint],
+) -> Vec<Option<OpfSolution>> {
+    let mut previous: Option<HashMap<String, f64>> = None;
+    let mut solutions = Vec::with_capacity(forecast.timestamps.len());
+    for (hour, timestamp) in forecast.timestamps.iter().enumerate() {
+        let mut network = apply_step(grid, &forecast.loads[hour], &forecast.available[hour]);
+        if let Some(dispatch) = &previous {
+            apply_ramps(&mut network, ramps, dispatch);
+        }
+        let solution = solve_step(solver, &network, timestamp);
+        if let Some(solved) = solution.as_ref().filter(|s| s.converged) {
+            previous = Some(solved.generator_p.clone());
+        }
+        solutions.push(solution);
+    }
+    solutions
+}
+
+fn solve_step(solver: &OpfSolver, network: &Network, timestamp: &str) -> Option<OpfSolution> {
+    match solver.solve(network) {
+        Ok(solution) => Some(solution),
+        Err(err) => {
+            eprintln!("warning: OPF failed at timestamp {}: {}", timestamp, err);
+            None
+        }
+    }
+}
+
+/// Narrow ramp-limited units' P limits to one hour of ramping from `previous`.
+fn apply_ramps(network: &mut Network, ramps: &[RampConstraint], previous: &HashMap<String, f64>) {
+    for node in network.graph.node_weights_mut() {
+        let Node::Gen(gen) = node else {
+            continue;
+        };
+        let Some(ramp) = ramps.iter().find(|r| r.gen_name == gen.name) else {
+            continue;
+        };
+        let Some(&p_mw) = previous.get(&gen.name) else {
+            continue;
+        };
+        gen.pmin = Megawatts(gen.pmin.value().max(p_mw - ramp.ramp_down_mw_hr));
+        gen.pmax = Megawatts(gen.pmax.value().min(p_mw + ramp.ramp_up_mw_hr));
+    }
+}
+
+/// Copy of `grid` with one step's loads and generator availability applied.
+///
+/// Existing loads at a forecast bus are scaled together to the forecast total;
+/// a bus with no base load gets a new load element.
+fn apply_step(
+    grid: &Network,
+    loads: &HashMap<usize, LoadValue>,
+    available: &HashMap<usize, f64>,
+) -> Network {
+    let mut network = grid.clone();
+
+    // Base-case active load and element count per bus
+    let mut base: HashMap<usize, (f64, usize)> = HashMap::new();
+    let mut next_load_id = 0;
+    for node in network.graph.node_weights() {
+        if let Node::Load(load) = node {
+            let entry = base.entry(load.bus.value()).or_insert((0.0, 0));
+            entry.0 += load.active_power.value();
+            entry.1 += 1;
+            next_load_id = next_load_id.max(load.id.value() + 1);
+        }
+    }
+
+    for node in network.graph.node_weights_mut() {
+        match node {
+            Node::Load(load) => {
+                let Some(value) = loads.get(&load.bus.value()) else {
+                    continue;
+                };
+                let (base_p, count) = base[&load.bus.value()];
+                let old_p = load.active_power.value();
+                let share = if base_p.abs() > f64::EPSILON {
+                    old_p / base_p
+                } else {
+                    1.0 / count as f64
+                };
+                let new_p = value.p_mw * share;
+                let new_q = match value.q_mvar {
+                    Some(q) => q * share,
+                    // Keep each load's power factor
+                    None if old_p.abs() > f64::EPSILON => {
+                        load.reactive_power.value() * new_p / old_p
+                    }
+                    None => load.reactive_power.value(),
+                };
+                load.active_power = Megawatts(new_p);
+                load.reactive_power = Megavars(new_q);
+            }
+            Node::Gen(gen) => {
+                if let Some(&mw) = available.get(&gen.id.value()) {
+                    gen.p_available = Some(Megawatts(mw));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut new_buses: Vec<_> = loads
+        .iter()
+        .filter(|(bus, _)| !base.contains_key(bus))
+        .collect();
+    new_buses.sort_by_key(|(bus, _)| **bus);
+    for (&bus, value) in new_buses {
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(next_load_id),
+            name: format!("ts_load_{}", bus),
+            bus: BusId::new(bus),
+            active_power: Megawatts(value.p_mw),
+            reactive_power: Megavars(value.q_mvar.unwrap_or(0.0)),
+            zip: None,
+        }));
+        next_load_id += 1;
+    }
+
+    network
+}
+
+/// Pivot the tidy forecast into per-timestamp maps, carrying gaps forward.
+fn read_forecast(df: &DataFrame) -> Result<Forecast> {
+    let names = df.get_column_names();
+    if !names.contains(&"timestamp") {
+        return Err(anyhow!("time series must contain a 'timestamp' column"));
+    }
+
+    let sorted = df
+        .sort(["timestamp"], false, true)
+        .context("sorting time series by timestamp")?;
+    let timestamps_col = sorted
+        .column("timestamp")?
+        .cast(&DataType::Utf8)
+        .context("reading 'timestamp' column")?;
+    let timestamps_col = timestamps_col.utf8()?;
+
+    let optional_i64 = |name: &str| -> Result<Option<Series>> {
+        match sorted.column(name) {
+            Ok(col) => Ok(Some(col.cast(&DataType::Int64)?)),
+            Err(_) => Ok(None),
+        }
+    };
+    let optional_f64 = |name: &str| -> Result<Option<Series>> {
+        match sorted.column(name) {
+            Ok(col) => Ok(Some(col.cast(&DataType::Float64)?)),
+            Err(_) => Ok(None),
+        }
+    };
+    let bus_ids = optional_i64("bus_id")?;
+    let load_mw = optional_f64("load_mw")?;
+    let load_mvar = optional_f64("load_mvar")?;
+    let gen_ids = optional_i64("gen_id")?;
+    let p_available = optional_f64("p_available_mw")?;
+    if bus_ids.is_some() != load_mw.is_some() {
+        return Err(anyhow!(
+            "time series needs both 'bus_id' and 'load_mw' columns for load rows"
+        ));
+    }
+    if gen_ids.is_some() != p_available.is_some() {
+        return Err(anyhow!(
+            "time series needs both 'gen_id' and 'p_available_mw' columns for availability rows"
+        ));
+    }
+
+    // Observed values keyed by timestamp (in sorted order)
+    let mut order: Vec<String> = Vec::new();
+    let mut observed_loads: BTreeMap<usize, HashMap<usize, LoadValue>> = BTreeMap::new();
+    let mut observed_available: BTreeMap<usize, HashMap<usize, f64>> = BTreeMap::new();
+    for row in 0..sorted.height() {
+        let Some(timestamp) = timestamps_col.get(row) else {
+            return Err(anyhow!("null timestamp in row {}", row));
+        };
+        if order.last().map(String::as_str) != Some(timestamp) {
+            order.push(timestamp.to_string());
+        }
+        let step = order.len() - 1;
+
+        if let (Some(bus), Some(p)) = (
+            bus_ids.as_ref().and_then(|s| s.i64().ok()?.get(row)),
+            load_mw.as_ref().and_then(|s| s.f64().ok()?.get(row)),
+        ) {
+            let q = load_mvar.as_ref().and_then(|s| s.f64().ok()?.get(row));
+            observed_loads
+                .entry(step)
+                .or_default()
+                .insert(bus as usize, LoadValue { p_mw: p, q_mvar: q });
+        }
+        if let (Some(gen), Some(cap)) = (
+            gen_ids.as_ref().and_then(|s| s.i64().ok()?.get(row)),
+            p_available.as_ref().and_then(|s| s.f64().ok()?.get(row)),
+        ) {
+            observed_available
+                .entry(step)
+                .or_default()
+                .insert(gen as usize, cap);
+        }
+    }
+    if order.is_empty() {
+        return Err(anyhow!("time series contains no rows"));
+    }
+
+    let (loads, load_gaps) = carry_forward(&order, &observed_loads, "bus");
+    let (available, availability_gaps) = carry_forward(&order, &observed_available, "generator");
+    Ok(Forecast {
+        timestamps: order,
+        loads,
+        available,
+        carried_forward: load_gaps + availability_gaps,
+    })
+}
+
+/// Fill each step with the latest known value per element.
+fn carry_forward<T: Copy>(
+    order: &[String],
+    observed: &BTreeMap<usize, HashMap<usize, T>>,
+    kind: &str,
+) -> (Vec<HashMap<usize, T>>, usize) {
+    let mut latest: HashMap<usize, T> = HashMap::new();
+    let mut steps = Vec::with_capacity(order.len());
+    let mut gaps = 0;
+    for (step, timestamp) in order.iter().enumerate() {
+        let current = observed.get(&step);
+        let mut missing: Vec<usize> = latest
+            .keys()
+            .filter(|id| current.map_or(true, |values| !values.contains_key(id)))
+            .copied()
+            .collect();
+        if !missing.is_empty() {
+            missing.sort_unstable();
+            gaps += missing.len();
+            eprintln!(
+                "warning: no forecast for {} {:?} at timestamp {}; carrying last value forward",
+                kind, missing, timestamp
+            );
+        }
+        if let Some(values) = current {
+            latest.extend(values.iter().map(|(id, value)| (*id, *value)));
+        }
+        steps.push(latest.clone());
+    }
+    (steps, gaps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{Branch, BranchId, Bus, CostModel, Edge, Gen, GenId};
+    use tempfile::TempDir;
+
+    fn two_bus() -> Network {
+        two_bus_with_gens(&[(1, 1, 10.0), (2, 2, 30.0)])
+    }
+
+    /// Two-bus case with generators (id, bus, cost) added in the given order
+    fn two_bus_with_gens(gens: &[(usize, usize, f64)]) -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus 1".to_string(),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "Bus 2".to_string(),
+            ..Bus::default()
+        }));
+        for &(id, bus, cost) in gens {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(id), format!("Gen {}", id), BusId::new(bus))
+                    .with_p_limits(0.0, 300.0)
+                    .with_cost(CostModel::linear(0.0, cost)),
+            ));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load 2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(100.0),
+            reactive_power: Megavars(20.0),
+            zip: None,
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "Line 1-2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        network
+    }
+
+    fn write_forecast(dir: &TempDir, mut df: DataFrame) -> std::path::PathBuf {
+        let path = dir.path().join("forecast.parquet");
+        let mut file = File::create(&path).unwrap();
+        ParquetWriter::new(&mut file).finish(&mut df).unwrap();
+        path
+    }
+
+    fn read_results(path: &Path) -> DataFrame {
+        ParquetReader::new(File::open(path).unwrap())
+            .finish()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_three_hours_stack_snapshot_rows() {
+        let dir = TempDir::new().unwrap();
+        let network = two_bus();
+        let forecast = write_forecast(
+            &dir,
+            df!(
+                "timestamp" => ["2024-01-01T00:00", "2024-01-01T01:00", "2024-01-01T02:00"],
+                "bus_id" => [2i64, 2, 2],
+                "load_mw" => [80.0, 100.0, 120.0],
+            )
+            .unwrap(),
+        );
+
+        // Single-snapshot reference: one row per generator
+        let snapshot_dir = dir.path().join("snapshot.parquet");
+        OpfSolver::new()
+            .with_method(OpfMethod::DcOpf)
+            .solve(&network)
+            .unwrap()
+            .to_parquet(&snapshot_dir)
+            .unwrap();
+        let snapshot_rows = read_results(&snapshot_dir).height();
+
+        let out = dir.path().join("ts_results.parquet");
+        let summary = solve(&network, &forecast, &out, OpfMethod::DcOpf).unwrap();
+        assert_eq!(summary.steps, 3);
+        assert_eq!(summary.converged_steps, 3);
+        assert_eq!(summary.carried_forward, 0);
+
+        let results = read_results(&out);
+        assert_eq!(results.height(), 3 * snapshot_rows);
+        assert_eq!(summary.rows, results.height());
+
+        // Total dispatch follows the forecast hour by hour
+        let hours: Vec<i64> = results
+            .column("hour")
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        let p: Vec<f64> = results
+            .column("p_mw")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        for (hour, expected) in [80.0, 100.0, 120.0].into_iter().enumerate() {
+            let total: f64 = hours
+                .iter()
+                .zip(&p)
+                .filter(|(h, _)| **h == hour as i64)
+                .map(|(_, p)| p)
+                .sum();
+            assert!((total - expected).abs() < 1e-3, "hour {}: {}", hour, total);
+        }
+    }
+
+    #[test]
+    fn test_output_independent_of_insertion_order() {
+        let dir = TempDir::new().unwrap();
+        let forecast = hourly_forecast(&dir, vec![80.0, 100.0, 120.0]);
+
+        let forward_out = dir.path().join("forward.parquet");
+        let reversed_out = dir.path().join("reversed.parquet");
+        solve(&two_bus(), &forecast, &forward_out, OpfMethod::DcOpf).unwrap();
+        solve(
+            &two_bus_with_gens(&[(2, 2, 30.0), (1, 1, 10.0)]),
+            &forecast,
+            &reversed_out,
+            OpfMethod::DcOpf,
+        )
+        .unwrap();
+
+        let generators: Vec<String> = read_results(&reversed_out)
+            .column("generator")
+            .unwrap()
+            .utf8()
+            .unwrap()
+            .into_no_null_iter()
+            .take(2)
+            .map(str::to_string)
+            .collect();
+        assert_eq!(generators, ["Gen 1", "Gen 2"]);
+        assert_eq!(
+            fs::read(&forward_out).unwrap(),
+            fs::read(&reversed_out).unwrap()
+        );
+    }
+
+    fn column_f64(df: &DataFrame, name: &str) -> Vec<f64> {
+        df.column(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    }
+
+    /// Hourly load at bus 2, starting 2024-01-01T00:00
+    fn hourly_forecast(dir: &TempDir, load: Vec<f64>) -> std::path::PathBuf {
+        let timestamps: Vec<String> = (0..load.len())
+            .map(|h| format!("2024-01-01T{:02}:00", h))
+            .collect();
+        write_forecast(
+            dir,
+            df!(
+                "timestamp" => timestamps,
+                "bus_id" => vec![2i64; load.len()],
+                "load_mw" => load,
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential_in_order() {
+        let dir = TempDir::new().unwrap();
+        let network = two_bus();
+        // A day swinging between 40 and 160 MW
+        let load = (0..24)
+            .map(|h| 100.0 + 60.0 * (h as f64 * std::f64::consts::PI / 12.0).sin())
+            .collect();
+        let forecast = hourly_forecast(&dir, load);
+
+        let sequential_out = dir.path().join("sequential.parquet");
+        let parallel_out = dir.path().join("parallel.parquet");
+        let sequential = solve(&network, &forecast, &sequential_out, OpfMethod::DcOpf).unwrap();
+        let parallel = solve_with_options(
+            &network,
+            &forecast,
+            &parallel_out,
+            &TsOpfOptions::new(OpfMethod::DcOpf).with_threads(4),
+        )
+        .unwrap();
+        assert_eq!(parallel.steps, 24);
+        assert_eq!(parallel.converged_steps, sequential.converged_steps);
+
+        let sequential = read_results(&sequential_out);
+        let parallel = read_results(&parallel_out);
+        assert!(parallel.frame_equal_missing(&sequential));
+
+        let hours: Vec<i64> = parallel
+            .column("hour")
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert!(hours.windows(2).all(|w| w[0] <= w[1]), "rows out of order");
+    }
+
+    #[test]
+    fn test_ramp_limits_couple_steps() {
+        let dir = TempDir::new().unwrap();
+        let network = two_bus();
+        // Load climbs 25 MW an hour from 40 MW, then levels off at 200 MW
+        let load = (0..12)
+            .map(|h| (40.0 + 25.0 * h as f64).min(200.0))
+            .collect();
+        let forecast = hourly_forecast(&dir, load);
+
+        // The cheap unit may move at most 10 MW per hour; the ramp forces a
+        // sequential solve even though threads were requested
+        let out = dir.path().join("ramped.parquet");
+        let options = TsOpfOptions::new(OpfMethod::DcOpf)
+            .with_threads(4)
+            .with_ramp(RampConstraint::symmetric("Gen 1", 10.0));
+        let summary = solve_with_options(&network, &forecast, &out, &options).unwrap();
+        assert_eq!(summary.converged_steps, 12);
+
+        let results = read_results(&out);
+        let generators: Vec<String> = results
+            .column("generator")
+            .unwrap()
+            .utf8()
+            .unwrap()
+            .into_no_null_iter()
+            .map(str::to_string)
+            .collect();
+        let cheap: Vec<f64> = column_f64(&results, "p_mw")
+            .into_iter()
+            .zip(&generators)
+            .filter(|(_, g)| g.as_str() == "Gen 1")
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(cheap.len(), 12);
+        for step in cheap.windows(2) {
+            assert!((step[1] - step[0]).abs() <= 10.0 + 1e-6, "{:?}", step);
+        }
+        // Unramped, the cheap unit would serve the whole 65 MW at hour 1
+        assert!((cheap[1] - 50.0).abs() < 1e-3, "{}", cheap[1]);
+    }
+
+    #[test]
+    fn test_missing_timestamp_carries_last_load_forward() {
+        let dir = TempDir::new().unwrap();
+        let mut network = two_bus();
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(2),
+            name: "Load 1".to_string(),
+            bus: BusId::new(1),
+            active_power: Megawatts(10.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        // Bus 1 has no row at 01:00
+        let forecast = write_forecast(
+            &dir,
+            df!(
+                "timestamp" => ["00:00", "00:00", "01:00"],
+                "bus_id" => [1i64, 2, 2],
+                "load_mw" => [40.0, 100.0, 110.0],
+            )
+            .unwrap(),
+        );
+
+        let out = dir.path().join("ts_results.parquet");
+        let summary = solve(&network, &forecast, &out, OpfMethod::DcOpf).unwrap();
+        assert_eq!(summary.steps, 2);
+        assert_eq!(summary.carried_forward, 1);
+
+        let results = read_results(&out);
+        let hours = results.column("hour").unwrap().i64().unwrap().clone();
+        let p = results.column("p_mw").unwrap().f64().unwrap().clone();
+        let second_hour: f64 = hours
+            .into_no_null_iter()
+            .zip(p.into_no_null_iter())
+            .filter(|(h, _)| *h == 1)
+            .map(|(_, p)| p)
+            .sum();
+        assert!((second_hour - 150.0).abs() < 1e-3, "{}", second_hour);
+    }
+}
@@ -2,8 +2,9 @@
 
 use std::collections::HashMap;
 
-use gat_core::Network;
+use gat_core::{Edge, Network, Node};
 
+use crate::opf::ac_nlp::{PeriodData, RampConstraint};
 use crate::AcOpfSolution;
 
 /// Expected unit for angle values
@@ -406,6 +407,279 @@ pub fn compute_opf_violations_from_solution(
     }
 }
 
+/// First interval whose net-load change exceeds aggregate ramp capability
+#[derive(Debug, Clone, PartialEq)]
+pub struct RampViolation {
+    /// Index of the period the ramp starts from
+    pub from_period: usize,
+    /// Index of the period the ramp ends in
+    pub to_period: usize,
+    /// Net-load change to be followed (MW, positive = ramp up)
+    pub required_mw: f64,
+    /// Aggregate ramp capability in the required direction (MW)
+    pub capability_mw: f64,
+    /// Amount by which the change exceeds the capability (MW)
+    pub shortfall_mw: f64,
+}
+
+/// Result of a ramp-feasibility screen over a load profile
+#[derive(Debug, Clone)]
+pub struct RampFeasibilityResult {
+    /// Number of consecutive-period intervals checked
+    pub intervals_checked: usize,
+    /// First infeasible interval, if any
+    pub first_violation: Option<RampViolation>,
+}
+
+impl RampFeasibilityResult {
+    /// Whether every interval's net-load change can be followed
+    pub fn is_feasible(&self) -> bool {
+        self.first_violation.is_none()
+    }
+}
+
+/// Check whether a load trajectory can be followed by aggregate generator ramping.
+///
+/// Net load in each period is the network's total load scaled by the period's
+/// `load_scale`. For each pair of consecutive periods, the change must be no
+/// larger than the fleet's combined ramp capability over the later period's
+/// `duration_hr`. Each in-service unit contributes `ramp × duration`, capped by
+/// its `pmax - pmin` range; units without an entry in `ramp_limits` can cover
+/// their full range in one interval.
+///
+/// This is a necessary condition only: it ignores network limits and where
+/// each unit starts, so a passing profile may still be infeasible in OPF.
+pub fn check_ramp_feasibility(
+    network: &Network,
+    load_profile: &[PeriodData],
+    ramp_limits: &[RampConstraint],
+) -> RampFeasibilityResult {
+    let limits: HashMap<&str, &RampConstraint> = ramp_limits
+        .iter()
+        .map(|r| (r.gen_name.as_str(), r))
+        .collect();
+
+    let mut base_load_mw = 0.0;
+    // (ramp up MW/hr, ramp down MW/hr, range MW) per in-service unit
+    let mut units = Vec::new();
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Load(load) => base_load_mw += load.active_power.value(),
+            Node::Gen(gen) if gen.status && !gen.is_synchronous_condenser => {
+                let range = (gen.pmax.value() - gen.pmin.value()).max(0.0);
+                let (up, down) = limits
+                    .get(gen.name.as_str())
+                    .map_or((f64::INFINITY, f64::INFINITY), |r| {
+                        (r.ramp_up_mw_hr, r.ramp_down_mw_hr)
+                    });
+                units.push((up, down, range));
+            }
+            _ => {}
+        }
+    }
+
+    let mut result = RampFeasibilityResult {
+        intervals_checked: 0,
+        first_violation: None,
+    };
+    for pair in load_profile.windows(2) {
+        let (prev, next) = (&pair[0], &pair[1]);
+        result.intervals_checked += 1;
+
+        let required_mw = base_load_mw * (next.load_scale - prev.load_scale);
+        let capability_mw: f64 = units
+            .iter()
+            .map(|&(up, down, range)| {
+                let rate = if required_mw >= 0.0 { up } else { down };
+                (rate * next.duration_hr).min(range)
+            })
+            .sum();
+        let shortfall_mw = required_mw.abs() - capability_mw;
+        if shortfall_mw > 1e-9 {
+            result.first_violation = Some(RampViolation {
+                from_period: prev.index,
+                to_period: next.index,
+                required_mw,
+                capability_mw,
+                shortfall_mw,
+            });
+            break;
+        }
+    }
+    result
+}
+
+/// Series impedance magnitude above which a branch is suspected to be in ohms (p.u.)
+const SUSPECT_IMPEDANCE_PU: f64 = 10.0;
+
+/// Shunt admittance magnitude above which a shunt is suspected to be in MW/MVAr (p.u.)
+const SUSPECT_SHUNT_PU: f64 = 5.0;
+
+/// What a base-consistency issue concerns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseIssueKind {
+    /// Generator `mbase` differs from the system base (MVA)
+    GeneratorMbase,
+    /// Branch series impedance looks like ohms rather than p.u.
+    BranchImpedance,
+    /// Shunt admittance looks like MW/MVAr at 1 p.u. rather than p.u.
+    ShuntAdmittance,
+    /// Line (not a transformer) joining buses with different voltage bases (kV)
+    BranchVoltageBase,
+}
+
+/// One element whose data looks inconsistent with the system base
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseIssue {
+    /// Name of the generator, branch or shunt
+    pub element: String,
+    pub kind: BaseIssueKind,
+    /// Value found in the data
+    pub found: f64,
+    /// Suspected correct value on the system base
+    pub suggested: f64,
+}
+
+impl BaseIssue {
+    /// Factor that converts the found value to the suggested one.
+    ///
+    /// For a generator this is `S_system / mbase`, the factor that moves
+    /// impedances given on `mbase` to the system base.
+    pub fn conversion_factor(&self) -> f64 {
+        self.suggested / self.found
+    }
+}
+
+impl std::fmt::Display for BaseIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let what = match self.kind {
+            BaseIssueKind::GeneratorMbase => "generator mbase (MVA)",
+            BaseIssueKind::BranchImpedance => "branch |z| (p.u.)",
+            BaseIssueKind::ShuntAdmittance => "shunt |y| (p.u.)",
+            BaseIssueKind::BranchVoltageBase => "line to-bus base (kV)",
+        };
+        write!(
+            f,
+            "{}: {} is {:.4}, expected {:.4} (x{:.4})",
+            self.element,
+            what,
+            self.found,
+            self.suggested,
+            self.conversion_factor()
+        )
+    }
+}
+
+/// Result of a base-MVA / per-unit consistency check
+#[derive(Debug, Clone)]
+pub struct BaseConsistencyResult {
+    /// System base the data was checked against (MVA)
+    pub base_mva: f64,
+    /// Suspect elements, in network order
+    pub issues: Vec<BaseIssue>,
+}
+
+impl BaseConsistencyResult {
+    /// Whether no element looks mis-scaled
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Check that per-unit data is consistent with a system base of `expected_base_mva`.
+///
+/// Flags:
+/// - generators whose `mbase` differs from the system base; the suggested
+///   value is the system base and the conversion factor rescales impedances
+///   such as X''d
+/// - branches with series `|z|` above 10 p.u. whose from-bus has a known
+///   `base_kv`, suggesting ohms converted with `Z_base = kV² / S_base`
+/// - shunts with `|y|` above 5 p.u., suggesting MW/MVAr divided by `S_base`
+/// - lines (not transformers) joining buses with different `base_kv`,
+///   suggesting the from-bus base for the to-bus
+///
+/// The thresholds are heuristics: a flagged element is worth a look, not
+/// necessarily wrong.
+pub fn check_base_consistency(network: &Network, expected_base_mva: f64) -> BaseConsistencyResult {
+    let base_kv: HashMap<gat_core::BusId, f64> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => Some((bus.id, bus.base_kv.value())),
+            _ => None,
+        })
+        .collect();
+
+    let mut issues = Vec::new();
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Gen(gen) => {
+                let Some(mbase) = gen.mbase.map(|m| m.value()) else {
+                    continue;
+                };
+                if mbase > 0.0 && (mbase - expected_base_mva).abs() > 1e-6 * expected_base_mva {
+                    issues.push(BaseIssue {
+                        element: gen.name.clone(),
+                        kind: BaseIssueKind::GeneratorMbase,
+                        found: mbase,
+                        suggested: expected_base_mva,
+                    });
+                }
+            }
+            Node::Shunt(shunt) => {
+                let y = shunt.gs_pu.hypot(shunt.bs_pu);
+                if y > SUSPECT_SHUNT_PU {
+                    issues.push(BaseIssue {
+                        element: shunt.name.clone(),
+                        kind: BaseIssueKind::ShuntAdmittance,
+                        found: y,
+                        suggested: y / expected_base_mva,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for edge in network.graph.edge_weights() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        let from_kv = base_kv.get(&branch.from_bus).copied().unwrap_or(0.0);
+        let to_kv = base_kv.get(&branch.to_bus).copied().unwrap_or(0.0);
+
+        let z = branch.resistance.hypot(branch.reactance);
+        if z > SUSPECT_IMPEDANCE_PU && from_kv > 0.0 {
+            let z_base = from_kv * from_kv / expected_base_mva;
+            issues.push(BaseIssue {
+                element: branch.name.clone(),
+                kind: BaseIssueKind::BranchImpedance,
+                found: z,
+                suggested: z / z_base,
+            });
+        }
+
+        let transformer = branch.element_type == "transformer"
+            || branch.is_phase_shifter
+            || (branch.tap_ratio - 1.0).abs() > 1e-9
+            || branch.phase_shift.value().abs() > 1e-9;
+        if !transformer && from_kv > 0.0 && to_kv > 0.0 && (from_kv - to_kv).abs() > 1e-6 * from_kv
+        {
+            issues.push(BaseIssue {
+                element: branch.name.clone(),
+                kind: BaseIssueKind::BranchVoltageBase,
+                found: to_kv,
+                suggested: from_kv,
+            });
+        }
+    }
+
+    BaseConsistencyResult {
+        base_mva: expected_base_mva,
+        issues,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -497,4 +771,136 @@ mod tests {
         // These are still plausible radians (max ~69°)
         assert!(result.likely_correct, "Should accept borderline radians");
     }
+
+    /// Two 100 MW units ramping 30 and 20 MW/hr serving a 100 MW base load.
+    fn ramp_network() -> (Network, Vec<RampConstraint>) {
+        use gat_core::{BusId, Gen, GenId, Load, LoadId, Megavars, Megawatts};
+
+        let mut network = Network::new();
+        for (id, name) in [(1, "g1"), (2, "g2")] {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(id), name.to_string(), BusId::new(1)).with_p_limits(0.0, 100.0),
+            ));
+        }
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load".to_string(),
+            bus: BusId::new(1),
+            active_power: Megawatts(100.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        let ramps = vec![
+            RampConstraint::symmetric("g1", 30.0),
+            RampConstraint::asymmetric("g2", 20.0, 40.0),
+        ];
+        (network, ramps)
+    }
+
+    #[test]
+    fn test_ramp_feasibility_flags_steep_step() {
+        let (network, ramps) = ramp_network();
+        // +40 MW, then a +60 MW step against 50 MW/hr of ramp-up
+        let profile: Vec<_> = [1.0, 1.4, 2.0, 1.3]
+            .iter()
+            .enumerate()
+            .map(|(t, &scale)| PeriodData::hourly(t, scale))
+            .collect();
+
+        let result = check_ramp_feasibility(&network, &profile, &ramps);
+        assert!(!result.is_feasible());
+        assert_eq!(result.intervals_checked, 2);
+        let violation = result.first_violation.unwrap();
+        assert_eq!((violation.from_period, violation.to_period), (1, 2));
+        assert!((violation.required_mw - 60.0).abs() < 1e-9);
+        assert!((violation.capability_mw - 50.0).abs() < 1e-9);
+        assert!((violation.shortfall_mw - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ramp_feasibility_scales_with_duration() {
+        let (network, ramps) = ramp_network();
+        // The same +60 MW step over two hours is within reach, and the 70 MW
+        // drop is covered by the faster ramp-down
+        let profile = vec![
+            PeriodData::hourly(0, 1.0),
+            PeriodData::new(1, 2.0, 1.6),
+            PeriodData::hourly(2, 0.9),
+        ];
+        let result = check_ramp_feasibility(&network, &profile, &ramps);
+        assert!(result.is_feasible());
+        assert_eq!(result.intervals_checked, 2);
+    }
+
+    fn base_check_network() -> Network {
+        use gat_core::{Branch, BranchId, Bus, BusId, Gen, GenId, Kilovolts, MegavoltAmperes};
+
+        let mut network = Network::new();
+        let buses: Vec<_> = [(1, 138.0), (2, 138.0), (3, 69.0)]
+            .iter()
+            .map(|&(id, kv)| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(id),
+                    name: format!("bus{}", id),
+                    base_kv: Kilovolts(kv),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (k, (from, to, x)) in [(0, 1, 0.1), (1, 2, 0.2)].into_iter().enumerate() {
+            network.graph.add_edge(
+                buses[from],
+                buses[to],
+                Edge::Branch(Branch::new(
+                    BranchId::new(k),
+                    format!("line{}", k),
+                    BusId::new(from + 1),
+                    BusId::new(to + 1),
+                    0.01,
+                    x,
+                )),
+            );
+        }
+        let mut g1 = Gen::new(GenId::new(1), "g1".to_string(), BusId::new(1));
+        g1.mbase = Some(MegavoltAmperes(100.0));
+        let mut g2 = Gen::new(GenId::new(2), "g2".to_string(), BusId::new(2));
+        g2.mbase = Some(MegavoltAmperes(50.0));
+        network.graph.add_node(Node::Gen(g1));
+        network.graph.add_node(Node::Gen(g2));
+        network
+    }
+
+    #[test]
+    fn test_base_check_flags_generator_on_other_mbase() {
+        let result = check_base_consistency(&base_check_network(), 100.0);
+        assert!(!result.is_consistent());
+
+        let gens: Vec<_> = result
+            .issues
+            .iter()
+            .filter(|issue| issue.kind == BaseIssueKind::GeneratorMbase)
+            .collect();
+        assert_eq!(gens.len(), 1);
+        assert_eq!(gens[0].element, "g2");
+        assert_eq!(gens[0].found, 50.0);
+        assert_eq!(gens[0].suggested, 100.0);
+        assert!((gens[0].conversion_factor() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_base_check_flags_line_across_voltage_bases() {
+        let result = check_base_consistency(&base_check_network(), 100.0);
+        let lines: Vec<_> = result
+            .issues
+            .iter()
+            .filter(|issue| issue.kind == BaseIssueKind::BranchVoltageBase)
+            .collect();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].element, "line1");
+        assert_eq!((lines[0].found, lines[0].suggested), (69.0, 138.0));
+        assert!(result
+            .issues
+            .iter()
+            .all(|issue| issue.kind != BaseIssueKind::BranchImpedance));
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Provides a simplified, builder-style API for running power flow analysis.
 
+use crate::opf::{normalize_flow_signs, BranchFlows, FlowDirection};
 use anyhow::Result;
 use gat_core::Network;
 use std::collections::HashMap;
@@ -17,9 +18,25 @@ pub struct PowerFlowSolution {
     pub bus_angles: HashMap<String, f64>,
     pub bus_voltages: HashMap<String, f64>,
     pub branch_flows: HashMap<String, f64>,
+    /// Orientation of `branch_flows`; `FromTo` once returned by the solve methods
+    pub flow_direction: FlowDirection,
     pub losses_mw: f64,
 }
 
+impl BranchFlows for PowerFlowSolution {
+    fn flow_direction(&self) -> FlowDirection {
+        self.flow_direction
+    }
+
+    fn set_flow_direction(&mut self, direction: FlowDirection) {
+        self.flow_direction = direction;
+    }
+
+    fn flow_maps_mut(&mut self) -> Vec<&mut HashMap<String, f64>> {
+        vec![&mut self.branch_flows]
+    }
+}
+
 /// Fluent builder for power flow analysis
 pub struct PowerFlowAnalysis<'a> {
     network: &'a Network,
@@ -61,14 +78,17 @@ impl<'a> PowerFlowAnalysis<'a> {
             .map(|(bus_id, angle)| (bus_id.to_string(), angle))
             .collect();
 
-        Ok(PowerFlowSolution {
+        let mut solution = PowerFlowSolution {
             converged: true, // DC always converges if solvable
             iterations: 1,
             bus_angles,
             bus_voltages: HashMap::new(), // DC doesn't solve voltages
             branch_flows: HashMap::new(), // Not computed by dc_power_flow_angles
-            losses_mw: 0.0,               // DC is lossless
-        })
+            flow_direction: FlowDirection::FromTo,
+            losses_mw: 0.0, // DC is lossless
+        };
+        normalize_flow_signs(&mut solution, self.network);
+        Ok(solution)
     }
 
     /// Solve AC power flow (Newton-Raphson)
@@ -94,14 +114,17 @@ impl<'a> PowerFlowAnalysis<'a> {
             .map(|(bus_id, voltage)| (bus_id.value().to_string(), voltage))
             .collect();
 
-        Ok(PowerFlowSolution {
+        let mut solution = PowerFlowSolution {
             converged: result.converged,
             iterations: result.iterations,
             bus_angles,
             bus_voltages,
             branch_flows: HashMap::new(), // Not included in AcPowerFlowSolution
-            losses_mw: 0.0,               // Not computed by AcPowerFlowSolver
-        })
+            flow_direction: FlowDirection::FromTo,
+            losses_mw: 0.0, // Not computed by AcPowerFlowSolver
+        };
+        normalize_flow_signs(&mut solution, self.network);
+        Ok(solution)
     }
 }
 
@@ -148,6 +171,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: Megawatts(50.0),
             reactive_power: Megavars(10.0),
+            zip: None,
         };
         let load3 = Load {
             id: LoadId::new(2),
@@ -155,6 +179,7 @@ mod tests {
             bus: BusId::new(3),
             active_power: Megawatts(50.0),
             reactive_power: Megavars(10.0),
+            zip: None,
         };
         network.graph.add_node(Node::Load(load2));
         network.graph.add_node(Node::Load(load3));
@@ -0,0 +1,130 @@
+//! AC power flow re-check of OPF solutions.
+
+use gat_algo::opf::{verify_ac_feasibility, AcViolation};
+use gat_algo::{OpfMethod, OpfSolver};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Megavars, Megawatts,
+    Network, Node,
+};
+
+/// Meshed 3-bus case: cheap gen1 at bus 1 (the power-flow slack), gen2 at
+/// bus 2 with a must-run floor of `gen2_pmin` MW, 55 MW of load at buses 2-3.
+fn meshed(gen2_pmin: f64) -> Network {
+    let mut network = Network::new();
+    let buses: Vec<_> = (1..=3)
+        .map(|i| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                base_kv: gat_core::Kilovolts(138.0),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+    let lines = [(1, 2, 0.01, 0.1), (2, 3, 0.01, 0.1), (1, 3, 0.02, 0.15)];
+    for (k, &(from, to, r, x)) in lines.iter().enumerate() {
+        network.graph.add_edge(
+            buses[from - 1],
+            buses[to - 1],
+            Edge::Branch(Branch::new(
+                BranchId::new(k + 1),
+                format!("line{}_{}", from, to),
+                BusId::new(from),
+                BusId::new(to),
+                r,
+                x,
+            )),
+        );
+    }
+
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1))
+            .with_p_limits(0.0, 200.0)
+            .with_q_limits(-100.0, 100.0)
+            .with_cost(CostModel::linear(0.0, 10.0)),
+    ));
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(2), "gen2".to_string(), BusId::new(2))
+            .with_p_limits(gen2_pmin, 100.0)
+            .with_q_limits(-10.0, 10.0)
+            .with_cost(CostModel::linear(0.0, 20.0)),
+    ));
+    for (bus, p, q) in [(2, 30.0, 10.0), (3, 25.0, 8.0)] {
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(bus),
+            name: format!("load{}", bus),
+            bus: BusId::new(bus),
+            active_power: Megawatts(p),
+            reactive_power: Megavars(q),
+            zip: None,
+        }));
+    }
+    network
+}
+
+fn solve_socp(network: &Network) -> gat_algo::OpfSolution {
+    OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .with_preflight(false)
+        .solve(network)
+        .expect("SOCP should solve")
+}
+
+#[test]
+fn test_ac_opf_solution_is_ac_feasible() {
+    let network = meshed(0.0);
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::AcOpf)
+        .with_max_iterations(300)
+        .with_tolerance(1e-6)
+        .solve(&network)
+        .expect("AC-OPF should solve");
+
+    let report = verify_ac_feasibility(&network, &solution).unwrap();
+
+    assert!(report.pf_converged);
+    assert_eq!(report.bus_voltage_mag.len(), 3);
+    assert_eq!(report.generator_p.len(), 2);
+    // Re-simulation reproduces the dispatch up to solver accuracy
+    let drift = (report.generator_p["gen1"] - solution.generator_p["gen1"]).abs();
+    assert!(drift < 1.0, "slack drifted {:.3} MW", drift);
+}
+
+/// gen2's 57 MW floor exceeds the 55 MW load, so the SOCP can only balance by
+/// inflating the relaxed current `ℓ` and burning the surplus as fictitious
+/// losses: the cone constraint is slack and gen1 sits at 0 MW. The true AC
+/// losses are far smaller, so the power-flow slack (gen1) must go negative.
+#[test]
+fn test_inexact_socp_solution_shows_small_ac_violation() {
+    let network = meshed(57.0);
+    let solution = solve_socp(&network);
+    assert!(solution.generator_p["gen1"].abs() < 0.1);
+    assert!(
+        solution.total_losses_mw > 1.0,
+        "SOCP losses {:.3} MW should include the burned surplus",
+        solution.total_losses_mw
+    );
+
+    let report = verify_ac_feasibility(&network, &solution).unwrap();
+
+    assert!(report.pf_converged);
+    assert!(!report.feasible);
+    let slack = report
+        .violations
+        .iter()
+        .find_map(|violation| match violation {
+            AcViolation::GeneratorP {
+                generator,
+                p_mw,
+                limit_mw,
+            } if generator == "gen1" => Some((*p_mw, *limit_mw)),
+            _ => None,
+        })
+        .expect("gen1 should be pushed below pmin");
+    assert_eq!(slack.1, 0.0);
+    assert!(
+        slack.0 < -0.5 && slack.0 > -5.0,
+        "gen1 re-simulated at {:.3} MW",
+        slack.0
+    );
+}
@@ -3,7 +3,9 @@
 //! Tests for full nonlinear AC-OPF using the unified OpfSolver API.
 //! These tests validate the AC-OPF implementation (Task 6 from the plan).
 
-use gat_algo::{OpfMethod, OpfSolver};
+use gat_algo::opf::ac_nlp::AcOpfProblem;
+use gat_algo::opf::{ConstraintGroup, OpfTolerances};
+use gat_algo::{AcObjective, AcOpfSolver, OpfMethod, OpfSolver};
 use gat_core::{
     Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Network, Node,
 };
@@ -70,6 +72,7 @@ fn two_bus_network() -> Network {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(10.0),
         reactive_power: gat_core::Megavars(3.0),
+        zip: None,
     }));
 
     network
@@ -120,6 +123,48 @@ fn ac_opf_basic_convergence() {
     );
 }
 
+/// Tighter feasibility tolerance leaves less power-balance mismatch
+///
+/// The pure-Rust solver stops raising its penalty once the violation is
+/// under the feasibility tolerance. At 1.0 it stops after the first penalty
+/// iteration, where the $10/MWh cost still pulls `gen1` well short of the
+/// 10 MW load. At 1e-6 it runs every iteration, which closes most of that gap.
+#[test]
+fn ac_opf_feasibility_tolerance_tightens_solution() {
+    let network = two_bus_network();
+    let problem = AcOpfProblem::from_network(&network).unwrap();
+    let max_mismatch = |feasibility: f64| {
+        let solution = OpfSolver::new()
+            .with_method(OpfMethod::AcOpf)
+            .with_max_iterations(500)
+            .with_tolerances(OpfTolerances {
+                feasibility,
+                ..OpfTolerances::default()
+            })
+            .solve(&network)
+            .expect("AC-OPF should return a solution");
+        let x = problem.warm_start_from_solution(&solution);
+        problem
+            .equality_constraints(&x)
+            .iter()
+            .fold(0.0_f64, |max, g| max.max(g.abs()))
+    };
+
+    let loose = max_mismatch(1.0);
+    let tight = max_mismatch(1e-6);
+    assert!(
+        loose > 1e-2,
+        "loose tolerance should leave a visible mismatch, got {:.2e} p.u.",
+        loose
+    );
+    assert!(
+        tight < loose / 10.0,
+        "tight tolerance left {:.2e} p.u. mismatch, loose {:.2e}",
+        tight,
+        loose
+    );
+}
+
 /// Test 2: Compare AC-OPF to SOCP relaxation
 ///
 /// Runs both SOCP and AC-OPF on the same network and verifies:
@@ -301,6 +346,7 @@ fn three_bus_network() -> Network {
         bus: BusId::new(2),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(15.0),
+        zip: None,
     }));
 
     network
@@ -410,6 +456,7 @@ fn ac_opf_polynomial_cost() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(10.0),
+        zip: None,
     }));
 
     let solver = OpfSolver::new()
@@ -437,3 +484,396 @@ fn ac_opf_polynomial_cost() {
         gen_p, solution.objective_value
     );
 }
+
+/// Radial feeder: cheap substation supply at the head, expensive DG next to the load.
+///
+/// The resistive feeder makes importing from the substation lossy, so the
+/// cost-optimal and loss-optimal dispatches differ.
+fn lossy_feeder() -> Network {
+    let mut network = Network::new();
+    let buses: Vec<_> = (0..3)
+        .map(|i| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                base_kv: gat_core::Kilovolts(12.47),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+    for i in 0..2 {
+        network.graph.add_edge(
+            buses[i],
+            buses[i + 1],
+            Edge::Branch(Branch::new(
+                BranchId::new(i),
+                format!("seg{}", i),
+                BusId::new(i),
+                BusId::new(i + 1),
+                0.05,
+                0.05,
+            )),
+        );
+    }
+
+    network.graph.add_node(Node::Gen(Gen {
+        pmax: gat_core::Megawatts(60.0),
+        qmin: gat_core::Megavars(-30.0),
+        qmax: gat_core::Megavars(30.0),
+        cost_model: CostModel::linear(0.0, 10.0),
+        ..Gen::new(GenId::new(0), "substation".to_string(), BusId::new(0))
+    }));
+    network.graph.add_node(Node::Gen(Gen {
+        pmax: gat_core::Megawatts(40.0),
+        qmin: gat_core::Megavars(-20.0),
+        qmax: gat_core::Megavars(20.0),
+        cost_model: CostModel::linear(0.0, 50.0),
+        ..Gen::new(GenId::new(1), "dg".to_string(), BusId::new(2))
+    }));
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(0),
+        name: "feeder_end".to_string(),
+        bus: BusId::new(2),
+        active_power: gat_core::Megawatts(30.0),
+        reactive_power: gat_core::Megavars(5.0),
+        zip: None,
+    }));
+
+    network
+}
+
+/// Test 5: Minimum-loss objective on a lossy feeder
+///
+/// Minimizing losses should serve the load from the local DG and so lose less
+/// than the cost-optimal dispatch, which imports over the resistive feeder.
+#[test]
+fn ac_opf_min_losses_beats_min_cost_on_feeder() {
+    let network = lossy_feeder();
+    let solve = |objective| {
+        AcOpfSolver::new()
+            .with_objective(objective)
+            .with_max_iterations(300)
+            .with_tolerance(1e-4)
+            .solve(&network)
+            .expect("AC-OPF should solve the feeder")
+    };
+
+    let min_cost = solve(AcObjective::MinCost);
+    let min_losses = solve(AcObjective::MinLosses);
+
+    assert_eq!(min_cost.objective, AcObjective::MinCost);
+    assert_eq!(min_losses.objective, AcObjective::MinLosses);
+    assert!(
+        min_losses.total_losses_mw < min_cost.total_losses_mw,
+        "min-loss losses {:.4} MW should be below min-cost losses {:.4} MW",
+        min_losses.total_losses_mw,
+        min_cost.total_losses_mw
+    );
+
+    let dg = |solution: &gat_algo::AcOpfSolution| solution.generator_outputs["dg"];
+    assert!(
+        dg(&min_losses) > dg(&min_cost),
+        "min-loss dispatch should lean on the DG ({:.2} vs {:.2} MW)",
+        dg(&min_losses),
+        dg(&min_cost)
+    );
+}
+
+/// Helper: two identical units feeding a reactive-heavy load over identical
+/// lines. `q_support` can cover at most 50 MVAr, so `q_pricey` always
+/// carries part of the 80 MVAr load and its output stays positive.
+fn shared_var_support(q_price: Option<f64>) -> Network {
+    let mut network = Network::new();
+    let buses: Vec<_> = (0..3)
+        .map(|i| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                base_kv: gat_core::Kilovolts(138.0),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+    for (id, from) in [0, 1].into_iter().enumerate() {
+        network.graph.add_edge(
+            buses[from],
+            buses[2],
+            Edge::Branch(Branch::new(
+                BranchId::new(id),
+                format!("line{}_2", from),
+                BusId::new(from),
+                BusId::new(2),
+                0.01,
+                0.05,
+            )),
+        );
+    }
+
+    network.graph.add_node(Node::Gen(Gen {
+        pmax: gat_core::Megawatts(100.0),
+        qmin: gat_core::Megavars(-50.0),
+        qmax: gat_core::Megavars(100.0),
+        cost_model: CostModel::linear(0.0, 10.0),
+        reactive_cost: q_price.map(|price| price * 10.0),
+        ..Gen::new(GenId::new(0), "q_pricey".to_string(), BusId::new(0))
+    }));
+    network.graph.add_node(Node::Gen(Gen {
+        pmax: gat_core::Megawatts(100.0),
+        qmin: gat_core::Megavars(-50.0),
+        qmax: gat_core::Megavars(50.0),
+        cost_model: CostModel::linear(0.0, 10.0),
+        reactive_cost: q_price,
+        ..Gen::new(GenId::new(1), "q_support".to_string(), BusId::new(1))
+    }));
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(0),
+        name: "load".to_string(),
+        bus: BusId::new(2),
+        active_power: gat_core::Megawatts(60.0),
+        reactive_power: gat_core::Megavars(80.0),
+        zip: None,
+    }));
+
+    network
+}
+
+/// Reactive prices move voltage support to the cheaper unit
+///
+/// Without reactive costs the symmetric network splits the VArs roughly
+/// evenly. Pricing `q_pricey` at ten times `q_support` pushes `q_support`
+/// toward its ceiling, and the reactive cost is reported on its own.
+#[test]
+fn ac_opf_reactive_cost_shifts_var_support() {
+    let solve = |network: &Network| {
+        AcOpfSolver::new()
+            .with_objective(AcObjective::MinCost)
+            .with_max_iterations(500)
+            .with_tolerance(1e-4)
+            .solve(network)
+            .expect("AC-OPF should solve")
+    };
+
+    let free = solve(&shared_var_support(None));
+    let priced = solve(&shared_var_support(Some(0.5)));
+    assert!(free.converged && priced.converged);
+    assert_eq!(free.reactive_cost, 0.0);
+
+    let q =
+        |solution: &gat_algo::AcOpfSolution, gen: &str| solution.generator_reactive_outputs[gen];
+    assert!(
+        q(&priced, "q_pricey") < q(&free, "q_pricey") - 5.0,
+        "q_pricey should shed VArs ({:.2} vs {:.2} MVAr)",
+        q(&priced, "q_pricey"),
+        q(&free, "q_pricey")
+    );
+    assert!(
+        q(&priced, "q_support") > q(&free, "q_support") + 5.0,
+        "q_support should pick them up ({:.2} vs {:.2} MVAr)",
+        q(&priced, "q_support"),
+        q(&free, "q_support")
+    );
+
+    let expected = 5.0 * q(&priced, "q_pricey").abs() + 0.5 * q(&priced, "q_support").abs();
+    assert!(
+        (priced.reactive_cost - expected).abs() < 1e-6,
+        "reactive cost {:.4} should be {:.4}",
+        priced.reactive_cost,
+        expected
+    );
+}
+
+/// Helper: generator bus held at or below 1.0 p.u. feeding a heavy reactive
+/// load through a step-down transformer
+fn sagging_transformer_network() -> Network {
+    let mut network = Network::new();
+
+    let source = network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(0),
+        name: "source".to_string(),
+        base_kv: gat_core::Kilovolts(69.0),
+        vmin_pu: Some(gat_core::PerUnit(0.95)),
+        vmax_pu: Some(gat_core::PerUnit(1.0)),
+        ..Bus::default()
+    }));
+    let load_bus = network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(1),
+        name: "load_bus".to_string(),
+        base_kv: gat_core::Kilovolts(13.8),
+        vmin_pu: Some(gat_core::PerUnit(0.95)),
+        vmax_pu: Some(gat_core::PerUnit(1.05)),
+        ..Bus::default()
+    }));
+
+    network.graph.add_edge(
+        source,
+        load_bus,
+        Edge::Branch(Branch {
+            element_type: "transformer".to_string(),
+            ..Branch::new(
+                BranchId::new(0),
+                "xfmr".to_string(),
+                BusId::new(0),
+                BusId::new(1),
+                0.005,
+                0.1,
+            )
+        }),
+    );
+
+    network.graph.add_node(Node::Gen(Gen {
+        pmax: gat_core::Megawatts(200.0),
+        qmin: gat_core::Megavars(-150.0),
+        qmax: gat_core::Megavars(150.0),
+        cost_model: CostModel::linear(0.0, 10.0),
+        ..Gen::new(GenId::new(0), "gen".to_string(), BusId::new(0))
+    }));
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(0),
+        name: "load".to_string(),
+        bus: BusId::new(1),
+        active_power: gat_core::Megawatts(60.0),
+        reactive_power: gat_core::Megavars(80.0),
+        zip: None,
+    }));
+
+    network
+}
+
+/// Test 6: Tap control restores voltage
+///
+/// At unity tap the transformer drop pulls the load bus below 0.95 p.u. even
+/// with the source at its 1.0 p.u. ceiling. Letting the tap move boosts the
+/// load bus back into its band.
+#[test]
+fn ac_opf_tap_control_fixes_voltage_infeasibility() {
+    let network = sagging_transformer_network();
+    let solver = || {
+        AcOpfSolver::new()
+            .with_objective(AcObjective::MinCost)
+            .with_max_iterations(500)
+            .with_tolerance(1e-4)
+    };
+
+    let fixed = solver()
+        .solve(&network)
+        .expect("fixed-tap solve should run");
+    assert!(
+        !fixed.converged,
+        "fixed-tap AC-OPF should not satisfy the load-bus voltage limit"
+    );
+    assert!(fixed.transformer_taps.is_empty());
+
+    let tapped = solver()
+        .with_tap_control(0.9, 1.1)
+        .solve(&network)
+        .expect("tap-controlled solve should run");
+    assert!(tapped.converged, "tap control should restore feasibility");
+
+    let tap = tapped.transformer_taps["xfmr"];
+    assert!(
+        (0.9..1.0).contains(&tap),
+        "tap should drop below unity to boost the load bus, got {:.4}",
+        tap
+    );
+    let v = tapped.bus_voltages["load_bus"];
+    assert!(
+        (0.95 - 1e-3..=1.05 + 1e-3).contains(&v),
+        "load bus at {:.4} p.u.",
+        v
+    );
+}
+
+/// Infeasibility diagnosis pins the sagging load bus on the voltage band
+///
+/// The fixed-tap case fails only because the source is capped at 1.0 p.u.;
+/// reactive support and thermal limits have nothing to do with it.
+#[test]
+fn ac_opf_diagnosis_identifies_voltage_band() {
+    let network = sagging_transformer_network();
+    let solver = OpfSolver::new()
+        .with_method(OpfMethod::AcOpf)
+        .with_max_iterations(500)
+        .with_tolerance(1e-4);
+
+    let report = solver
+        .diagnose_infeasibility(&network)
+        .expect("diagnosis should run");
+    assert!(!report.base_feasible);
+    assert_eq!(
+        report.minimal_relaxation,
+        Some(vec![ConstraintGroup::VoltageBounds])
+    );
+
+    let top = &report.culprits[0];
+    assert_eq!(top.group, ConstraintGroup::VoltageBounds);
+    assert!(top.restores_feasibility && top.in_minimal_set);
+    assert!(top.objective_value.is_some());
+    for other in &report.culprits[1..] {
+        assert!(
+            !other.restores_feasibility,
+            "{:?} should not restore feasibility",
+            other.group
+        );
+    }
+}
+
+/// Soft voltage limits trade a small band overshoot for a solution
+///
+/// With hard limits the fixed-tap case has no feasible point. In soft mode the
+/// missing ~0.03 p.u. is split between the source ceiling and the load-bus
+/// floor and reported instead of failing the solve.
+#[test]
+fn ac_opf_soft_voltage_limits_report_overshoot() {
+    let network = sagging_transformer_network();
+    let solver = || {
+        AcOpfSolver::new()
+            .with_objective(AcObjective::MinCost)
+            .with_max_iterations(500)
+            .with_tolerance(1e-4)
+    };
+
+    let hard = solver().solve(&network).expect("hard solve should run");
+    assert!(!hard.converged);
+    assert!(hard.voltage_violations.is_empty());
+
+    let soft = solver()
+        .with_soft_voltage_limits(1e4)
+        .solve(&network)
+        .expect("soft solve should run");
+    assert!(soft.converged, "soft voltage limits should converge");
+
+    let under = soft.voltage_violations["load_bus"];
+    assert!(
+        (-0.05..0.0).contains(&under),
+        "load bus undershoot {:.4} p.u.",
+        under
+    );
+    assert!(
+        (soft.bus_voltages["load_bus"] - (0.95 + under)).abs() < 1e-9,
+        "reported undershoot should match the solved voltage"
+    );
+    for (bus, excess) in &soft.voltage_violations {
+        assert!(
+            excess.abs() < 0.05,
+            "{} off band by {:.4} p.u.",
+            bus,
+            excess
+        );
+    }
+}
+
+#[test]
+fn ac_opf_soft_voltage_limits_reject_nonpositive_weight() {
+    let result = AcOpfSolver::new()
+        .with_soft_voltage_limits(0.0)
+        .solve(&sagging_transformer_network());
+    assert!(result.is_err());
+}
+
+#[test]
+fn ac_opf_tap_control_rejects_inverted_range() {
+    let result = AcOpfSolver::new()
+        .with_tap_control(1.1, 0.9)
+        .solve(&sagging_transformer_network());
+    assert!(result.is_err());
+}
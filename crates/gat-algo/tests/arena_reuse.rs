@@ -0,0 +1,148 @@
+//! Allocation benchmark for arena reuse in repeated DC solves.
+//!
+//! Uses a counting global allocator, so this file intentionally holds a single
+//! test: parallel tests in the same binary would pollute the counter.
+
+use gat_algo::arena::ArenaContext;
+use gat_algo::contingency::{collect_injections, dc_branch_flows_in, Contingency, NkEvaluator};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Megawatts, Network, Node,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+/// Ring of `n` buses with cross-ties; generators on even buses, loads on odd buses.
+fn create_ring_network(n: usize) -> Network {
+    let mut network = Network::new();
+    let mut nodes = Vec::with_capacity(n);
+    for i in 0..n {
+        nodes.push(network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(i),
+            name: format!("bus{}", i),
+            ..Bus::default()
+        })));
+    }
+
+    let mut branch_id = 0;
+    let mut add_branch = |network: &mut Network, a: usize, b: usize, x: f64| {
+        network.graph.add_edge(
+            nodes[a],
+            nodes[b],
+            Edge::Branch(Branch {
+                id: BranchId::new(branch_id),
+                name: format!("br{}", branch_id),
+                from_bus: BusId::new(a),
+                to_bus: BusId::new(b),
+                reactance: x,
+                ..Branch::default()
+            }),
+        );
+        branch_id += 1;
+    };
+    for i in 0..n {
+        add_branch(&mut network, i, (i + 1) % n, 0.1);
+    }
+    for i in (0..n / 2).step_by(3) {
+        add_branch(&mut network, i, i + n / 2, 0.25);
+    }
+
+    for i in 0..n {
+        if i % 2 == 0 {
+            let mut gen = Gen::new(GenId::new(i), format!("gen{}", i), BusId::new(i));
+            gen.active_power = Megawatts(20.0);
+            network.graph.add_node(Node::Gen(gen));
+        } else {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(i),
+                name: format!("load{}", i),
+                bus: BusId::new(i),
+                active_power: Megawatts(20.0),
+                reactive_power: gat_core::Megavars(0.0),
+                zip: None,
+            }));
+        }
+    }
+    network
+}
+
+#[test]
+fn test_arena_reduces_allocations_over_repeated_dc_solves() {
+    const SOLVES: usize = 1000;
+
+    let network = create_ring_network(30);
+    let injections = collect_injections(&network);
+    let evaluator = NkEvaluator::new(&network, injections.clone(), HashMap::new());
+    let contingency = Contingency::single(BranchId::new(3));
+
+    // Fresh allocation on every solve
+    let before = allocations();
+    let mut fresh_result = None;
+    for _ in 0..SOLVES {
+        fresh_result = Some(evaluator.evaluate(&contingency));
+    }
+    let fresh_allocations = allocations() - before;
+
+    // Reused arena: warm up once so buffer growth is not counted
+    let mut ctx = ArenaContext::new();
+    let _ = evaluator.evaluate_in(&contingency, &mut ctx);
+    let before = allocations();
+    let mut reused_result = None;
+    for _ in 0..SOLVES {
+        reused_result = Some(evaluator.evaluate_in(&contingency, &mut ctx));
+    }
+    let reused_allocations = allocations() - before;
+
+    println!(
+        "{} DC solves: {} allocations fresh, {} with arena",
+        SOLVES, fresh_allocations, reused_allocations
+    );
+    assert!(
+        reused_allocations < fresh_allocations,
+        "arena reuse should allocate less ({} vs {})",
+        reused_allocations,
+        fresh_allocations
+    );
+    assert_eq!(ctx.buffer_allocations(), 1);
+
+    // Results are unaffected by buffer reuse
+    let fresh = fresh_result.unwrap();
+    let reused = reused_result.unwrap();
+    assert!(fresh.converged && reused.converged);
+    assert_eq!(fresh.branch_flows.len(), reused.branch_flows.len());
+    for (branch_id, flow) in &fresh.branch_flows {
+        assert!((flow - reused.branch_flows[branch_id]).abs() < 1e-9);
+    }
+
+    let direct = dc_branch_flows_in(&network, &injections, &[BranchId::new(3)], &mut ctx).unwrap();
+    for (branch_id, flow) in &fresh.branch_flows {
+        assert!((flow - direct[branch_id]).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,97 @@
+//! Objective autoscaling on IEEE 14 with costs inflated a million-fold.
+
+use gat_algo::{OpfMethod, OpfSolution, OpfSolver};
+use gat_core::{CostModel, Network, Node};
+use gat_io::importers::load_matpower_network;
+use std::path::Path;
+
+const COST_INFLATION: f64 = 1e6;
+
+fn load_case14() -> Network {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+    load_matpower_network(&path).expect("parse case14")
+}
+
+/// Case 14 with every polynomial cost multiplied by [`COST_INFLATION`]
+fn inflated_case14() -> Network {
+    let mut network = load_case14();
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if let CostModel::Polynomial(coeffs) = &mut gen.cost_model {
+                coeffs.iter_mut().for_each(|c| *c *= COST_INFLATION);
+            }
+        }
+    }
+    network
+}
+
+fn solve(network: &Network, method: OpfMethod, autoscale: bool) -> Option<OpfSolution> {
+    OpfSolver::new()
+        .with_method(method)
+        .with_autoscale(autoscale)
+        .solve(network)
+        .ok()
+}
+
+fn assert_same_dispatch(a: &OpfSolution, b: &OpfSolution) {
+    for (name, p) in &a.generator_p {
+        assert!(
+            (p - b.generator_p[name]).abs() < 1.0,
+            "{}: {} vs {} MW",
+            name,
+            p,
+            b.generator_p[name]
+        );
+    }
+}
+
+#[test]
+fn test_autoscale_conditions_huge_costs() {
+    let network = inflated_case14();
+    let reference = solve(&load_case14(), OpfMethod::SocpRelaxation, false)
+        .expect("SOCP should converge on the original costs");
+
+    let scaled =
+        solve(&network, OpfMethod::SocpRelaxation, true).expect("autoscaled SOCP should converge");
+    assert!(scaled.converged);
+    let scale = scaled.objective_scale.expect("costs are far out of range");
+    assert!(scale < 1.0, "scale {}", scale);
+    assert_same_dispatch(&scaled, &reference);
+    // Objective and prices come back in the inflated units
+    let ratio = scaled.objective_value / reference.objective_value;
+    assert!(
+        (ratio / COST_INFLATION - 1.0).abs() < 1e-2,
+        "ratio {}",
+        ratio
+    );
+
+    // Unscaled, the solver either fails or needs at least as many iterations
+    match solve(&network, OpfMethod::SocpRelaxation, false) {
+        Some(unscaled) if unscaled.converged => {
+            assert!(unscaled.objective_scale.is_none());
+            assert!(
+                scaled.iterations <= unscaled.iterations,
+                "autoscaled {} vs unscaled {} iterations",
+                scaled.iterations,
+                unscaled.iterations
+            );
+        }
+        _ => {}
+    }
+
+    // Well-scaled costs are left alone
+    assert!(solve(&load_case14(), OpfMethod::SocpRelaxation, true)
+        .expect("SOCP should converge")
+        .objective_scale
+        .is_none());
+}
+
+#[test]
+fn test_autoscale_dc_opf_keeps_dispatch() {
+    let network = inflated_case14();
+    let scaled = solve(&network, OpfMethod::DcOpf, true).expect("DC-OPF should solve");
+    let reference = solve(&load_case14(), OpfMethod::DcOpf, false).expect("DC-OPF should solve");
+    assert!(scaled.objective_scale.is_some());
+    assert_same_dispatch(&scaled, &reference);
+}
@@ -0,0 +1,97 @@
+//! Solve-time branch derating in OPF and contingency screening.
+
+use gat_algo::contingency::{screen_n1, NkScreeningConfig, RatingPolicy};
+use gat_algo::{OpfMethod, OpfSolver};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Megavars,
+    MegavoltAmperes, Megawatts, Network, Node,
+};
+use std::collections::HashMap;
+
+/// Two identical 100 MVA circuits from a cheap unit to a 160 MW load that
+/// also has an expensive local unit.
+fn double_circuit() -> Network {
+    let mut network = Network::new();
+    for i in 1..=2 {
+        network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(i),
+            name: format!("bus{}", i),
+            ..Bus::default()
+        }));
+    }
+    for (id, name) in [(1, "circuit_a"), (2, "circuit_b")] {
+        let mut branch = Branch::new(
+            BranchId::new(id),
+            name.to_string(),
+            BusId::new(1),
+            BusId::new(2),
+            0.01,
+            0.1,
+        )
+        .with_s_max(Some(100.0));
+        branch.rating_a = Some(MegavoltAmperes(100.0));
+        network.add_branch_checked(branch).unwrap();
+    }
+    for (id, price) in [(1, 10.0), (2, 50.0)] {
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(id), format!("gen{}", id), BusId::new(id))
+                .with_p_limits(0.0, 300.0)
+                .with_q_limits(-100.0, 100.0)
+                .with_cost(CostModel::linear(0.0, price)),
+        ));
+    }
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "load2".to_string(),
+        bus: BusId::new(2),
+        active_power: Megawatts(160.0),
+        reactive_power: Megavars(0.0),
+        zip: None,
+    }));
+    network
+}
+
+fn stored_rating(network: &Network, name: &str) -> Option<MegavoltAmperes> {
+    network.graph.edge_weights().find_map(|edge| match edge {
+        Edge::Branch(branch) if branch.name == name => branch.rating_a,
+        _ => None,
+    })
+}
+
+#[test]
+fn test_derated_branch_limits_opf_and_screening() {
+    let network = double_circuit();
+
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .with_deratings(HashMap::from([(BranchId::new(1), 0.5)]))
+        .solve(&network)
+        .expect("SOCP should converge");
+    assert!(solution.converged);
+    assert_eq!(solution.branch_ratings["circuit_a"], 50.0);
+    assert_eq!(solution.branch_ratings["circuit_b"], 100.0);
+
+    // Unconstrained, each circuit would carry 80 MW
+    let flow_a = solution.branch_p_flow["circuit_a"].abs();
+    assert!(flow_a <= 50.0 + 1.0, "circuit_a carried {} MW", flow_a);
+    assert_eq!(
+        stored_rating(&network, "circuit_a"),
+        Some(MegavoltAmperes(100.0))
+    );
+
+    // Base flows of 80 MW per circuit overload only the derated one
+    let base_flows = HashMap::from([(BranchId::new(1), 80.0), (BranchId::new(2), 80.0)]);
+    let config = NkScreeningConfig {
+        threshold_fraction: 1.0,
+        ..NkScreeningConfig::default()
+    }
+    .with_derating(BranchId::new(1), 0.5);
+    let results = screen_n1(&network, base_flows, config, RatingPolicy::normal_only()).unwrap();
+    let base_case = results.base_case.expect("base case is screened");
+    assert!(base_case.flagged);
+    assert_eq!(base_case.violations, vec![(BranchId::new(1), 80.0, 50.0)]);
+    assert_eq!(
+        stored_rating(&network, "circuit_a"),
+        Some(MegavoltAmperes(100.0))
+    );
+}
@@ -1,9 +1,13 @@
 use gat_algo::{
-    AreaId, Corridor, MultiAreaMonteCarlo, MultiAreaOutageScenario, MultiAreaSystem, OutageScenario,
+    dc_power_flow_angles, reduce_to_areas, tie_flow_attribution, AreaId, Corridor,
+    MultiAreaMonteCarlo, MultiAreaOutageScenario, MultiAreaSystem, OpfSolution, OutageScenario,
 };
 use gat_core::{
     Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Network, Node,
 };
+use polars::prelude::{ParquetReader, SerReader};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
 
 fn create_simple_network(name: &str, gen_capacity: f64, load_capacity: f64) -> Network {
     let mut network = Network::new();
@@ -43,6 +47,7 @@ fn create_simple_network(name: &str, gen_capacity: f64, load_capacity: f64) -> N
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(load_capacity),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network.graph.add_edge(
@@ -278,6 +283,50 @@ fn test_multiarea_monte_carlo_tight_system() {
     assert!(metrics.scenarios_with_shortfall > 0);
 }
 
+#[test]
+fn test_multiarea_event_log_matches_area_lole() {
+    let mut system = MultiAreaSystem::new();
+    system
+        .add_area(AreaId(0), create_simple_network("area_a", 80.0, 80.0))
+        .unwrap();
+    system
+        .add_area(AreaId(1), create_simple_network("area_b", 90.0, 90.0))
+        .unwrap();
+    system
+        .add_corridor(Corridor::new(0, AreaId(0), AreaId(1), 100.0))
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("events.parquet");
+    let metrics = MultiAreaMonteCarlo::new(500)
+        .with_event_log(&path)
+        .compute_multiarea_reliability_parallel(&system)
+        .unwrap();
+
+    let events = ParquetReader::new(File::open(&path).unwrap())
+        .finish()
+        .unwrap();
+    let samples = events.column("sample").unwrap().u64().unwrap();
+    let areas = events.column("area").unwrap().u64().unwrap();
+    let unserved = events.column("unserved_mw").unwrap().f64().unwrap();
+    let duration = events.column("duration_hours").unwrap().f64().unwrap();
+
+    let mut area_hours: HashMap<usize, f64> = HashMap::new();
+    let mut short_samples = HashSet::new();
+    for i in 0..events.height() {
+        assert!(unserved.get(i).unwrap() > 0.0);
+        short_samples.insert(samples.get(i).unwrap());
+        *area_hours
+            .entry(areas.get(i).unwrap() as usize)
+            .or_insert(0.0) += duration.get(i).unwrap();
+    }
+    assert_eq!(short_samples.len(), metrics.scenarios_with_shortfall);
+    for (area, lole) in &metrics.area_lole {
+        let logged = area_hours.get(&area.0).copied().unwrap_or(0.0);
+        assert!((logged - lole).abs() < 1e-9 * lole.max(1.0));
+    }
+}
+
 #[test]
 fn test_multiarea_monte_carlo_corridor_utilization() {
     let mut system = MultiAreaSystem::new();
@@ -397,3 +446,112 @@ fn test_multiarea_monte_carlo_parallel_arena() {
     // Corridor should be tracked
     assert!(metrics.corridor_utilization.contains_key(&0));
 }
+
+/// Four buses in two areas, joined by two ties of different reactance.
+fn create_two_area_network() -> Network {
+    let mut network = Network::new();
+    for (id, area) in [(1, 1), (2, 1), (3, 2), (4, 2)] {
+        network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(id),
+            name: format!("bus{}", id),
+            area_id: Some(area),
+            ..Bus::default()
+        }));
+    }
+    let lines = [(1, 2, 0.05), (3, 4, 0.05), (2, 3, 0.1), (1, 4, 0.2)];
+    for (id, (from, to, x)) in lines.into_iter().enumerate() {
+        network
+            .add_branch_checked(
+                Branch::new(
+                    BranchId::new(id),
+                    format!("line{}_{}", from, to),
+                    BusId::new(from),
+                    BusId::new(to),
+                    0.0,
+                    x,
+                )
+                .with_s_max(Some(100.0)),
+            )
+            .unwrap();
+    }
+    let mut gen =
+        Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1)).with_p_limits(0.0, 300.0);
+    gen.active_power = gat_core::Megawatts(150.0);
+    network.graph.add_node(Node::Gen(gen));
+    for (id, bus, mw) in [(1, 2, 20.0), (2, 3, 60.0), (3, 4, 70.0)] {
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(id),
+            name: format!("load{}", bus),
+            bus: BusId::new(bus),
+            active_power: gat_core::Megawatts(mw),
+            reactive_power: gat_core::Megavars(0.0),
+            zip: None,
+        }));
+    }
+    network
+}
+
+#[test]
+fn test_reduce_to_areas_preserves_inter_area_flow() {
+    let network = create_two_area_network();
+    let angles = dc_power_flow_angles(&network).unwrap();
+    let original_flow = (angles[&2] - angles[&3]) / 0.1 + (angles[&1] - angles[&4]) / 0.2;
+
+    let reduction = reduce_to_areas(&network).unwrap();
+    assert_eq!(reduction.ties.len(), 1);
+    let tie = &reduction.ties[0];
+    assert_eq!((tie.area_a, tie.area_b), (AreaId(1), AreaId(2)));
+    assert_eq!(tie.branches, vec![BranchId::new(2), BranchId::new(3)]);
+    assert!((tie.base_flow_mw - original_flow).abs() < 1e-6);
+    assert!(tie.reactance_pu > 0.0);
+
+    let reduced = &reduction.network;
+    let buses = reduced
+        .graph
+        .node_weights()
+        .filter(|node| matches!(node, Node::Bus(_)))
+        .count();
+    assert_eq!(buses, 2);
+    assert_eq!(reduced.graph.edge_count(), 1);
+
+    // Area 1 exports its 130 MW surplus over the equivalent tie
+    let reduced_angles = dc_power_flow_angles(reduced).unwrap();
+    let reduced_flow = (reduced_angles[&1] - reduced_angles[&2]) / tie.reactance_pu;
+    assert!((reduced_flow - original_flow).abs() < 1e-6);
+    assert!((reduced_flow - 130.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_tie_flow_attribution_matches_export_schedule() {
+    let network = create_two_area_network();
+    let solution = OpfSolution {
+        generator_p: HashMap::from([("gen1".to_string(), 150.0)]),
+        ..Default::default()
+    };
+
+    let allocation = tie_flow_attribution(&network, &solution).unwrap();
+    let ties: Vec<BranchId> = allocation.ties.iter().map(|tie| tie.branch).collect();
+    assert_eq!(ties, vec![BranchId::new(2), BranchId::new(3)]);
+    for tie in &allocation.ties {
+        let attributed: f64 = tie.contributions.values().sum();
+        assert!((attributed - tie.flow_mw).abs() < 1e-6);
+    }
+
+    // The single generator exports 130 MW to area 2's load
+    let export = (AreaId(1), AreaId(2));
+    assert!((allocation.transactions[&export] - 130.0).abs() < 1e-6);
+    let carried: f64 = allocation
+        .ties
+        .iter()
+        .map(|tie| tie.contributions[&export])
+        .sum();
+    assert!((carried - 130.0).abs() < 1e-6);
+
+    // Area 1's own supply loops through area 2 but nets to zero at the interface
+    let internal: f64 = allocation
+        .ties
+        .iter()
+        .map(|tie| tie.contributions[&(AreaId(1), AreaId(1))])
+        .sum();
+    assert!(internal.abs() < 1e-6);
+}
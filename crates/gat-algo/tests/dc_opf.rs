@@ -1,9 +1,12 @@
 //! DC-OPF solver tests
 
+use gat_algo::opf::{ConstraintId, ConstraintType, LoadShedding};
 use gat_algo::{OpfMethod, OpfSolver};
 use gat_core::{
-    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Network, Node,
+    AmbientConditions, Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId,
+    Network, Node,
 };
+use std::collections::HashMap;
 
 /// Create a simple 2-bus network for testing
 /// Bus 1: Generator (cheap, 0-100 MW, $10/MWh)
@@ -61,6 +64,7 @@ fn create_2bus_network() -> Network {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network
@@ -260,6 +264,7 @@ fn create_3bus_network() -> Network {
         bus: BusId::new(2),
         active_power: gat_core::Megawatts(80.0),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network
@@ -323,3 +328,120 @@ fn test_dc_opf_3bus_flows() {
         flow_1_3
     );
 }
+
+#[test]
+fn test_dc_opf_sheds_lowest_voll_load_first() {
+    // 130 MW of load against 100 MW of generation: 30 MW must go
+    let mut network = create_2bus_network();
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "interruptible2".to_string(),
+        bus: BusId::new(1),
+        active_power: gat_core::Megawatts(80.0),
+        reactive_power: gat_core::Megavars(0.0),
+        zip: None,
+    }));
+
+    let unshed = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .solve(&network);
+    assert!(unshed.is_err(), "capacity shortfall without shedding");
+
+    let shedding = LoadShedding::uniform(1000.0)
+        .with_load("load2", 10_000.0)
+        .with_load("interruptible2", 200.0);
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .with_load_shedding(shedding)
+        .solve(&network)
+        .expect("DC-OPF with shedding should converge");
+
+    let shed_low = solution.load_shed_mw["interruptible2"];
+    let shed_critical = solution.load_shed_mw["load2"];
+    assert!(
+        (shed_low - 30.0).abs() < 0.1,
+        "interruptible load should absorb the 30 MW shortfall, shed {}",
+        shed_low
+    );
+    assert!(
+        shed_critical < 0.1,
+        "critical load should not be shed, shed {}",
+        shed_critical
+    );
+    assert!((solution.total_load_shed_mw() - 30.0).abs() < 0.1);
+    assert!((solution.generator_p["gen1"] - 100.0).abs() < 0.1);
+
+    // Partly shed interruptible load sets the price
+    assert!((solution.bus_lmp["bus2"] - 200.0).abs() < 1e-4);
+    // 100 MW at $10/MWh plus 30 MW shed at $200/MWh
+    assert!((solution.objective_value - 7000.0).abs() < 20.0);
+}
+
+/// 2-bus network with a 40 MW limit on the 50 MW import; a $50/MWh unit at
+/// bus 2 covers the rest
+fn create_congested_2bus_network() -> Network {
+    let mut network = create_2bus_network();
+    for edge in network.graph.edge_weights_mut() {
+        if let Edge::Branch(branch) = edge {
+            branch.s_max = Some(gat_core::MegavoltAmperes(40.0));
+        }
+    }
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(1), "gen2".to_string(), BusId::new(1))
+            .with_p_limits(0.0, 100.0)
+            .with_cost(CostModel::linear(0.0, 50.0)),
+    ));
+    network
+}
+
+#[test]
+fn test_dc_opf_thermal_shadow_price_equals_lmp_spread() {
+    let network = create_congested_2bus_network();
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .solve(&network)
+        .expect("congested DC-OPF should converge");
+
+    // Each unit prices its own end of the congested line
+    assert!((solution.bus_lmp["bus1"] - 10.0).abs() < 1e-4);
+    assert!((solution.bus_lmp["bus2"] - 50.0).abs() < 1e-4);
+    let mu = solution.constraint_duals[&ConstraintId::BranchThermal("line1_2".to_string())];
+    assert!((mu - 40.0).abs() < 1e-4, "thermal shadow price {}", mu);
+
+    let binding = &solution.binding_constraints;
+    assert_eq!(binding.len(), 1);
+    assert_eq!(binding[0].constraint_type, ConstraintType::BranchFlowLimit);
+    assert_eq!(binding[0].limit, 40.0);
+    assert_eq!(binding[0].shadow_price, mu);
+
+    // Both units are marginal, so their limits carry no price
+    for limit in [
+        ConstraintId::GeneratorPMax("gen1".to_string()),
+        ConstraintId::GeneratorPMin("gen2".to_string()),
+    ] {
+        assert!(solution.constraint_duals[&limit] < 1e-4);
+    }
+}
+
+#[test]
+fn test_dc_opf_dynamic_rating_relieves_congestion() {
+    let network = create_congested_2bus_network();
+
+    let congested = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .solve(&network)
+        .expect("static-rated DC-OPF should converge");
+    assert!((congested.branch_p_flow["line1_2"] - 40.0).abs() < 0.1);
+    assert!((congested.generator_p["gen2"] - 10.0).abs() < 0.1);
+
+    // Cool, windy weather lifts the rating above the 50 MW the cheap unit wants to send
+    let conditions = HashMap::from([("line1_2".to_string(), AmbientConditions::new(10.0, 5.0))]);
+    let relieved = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .with_dynamic_ratings(conditions)
+        .solve(&network)
+        .expect("dynamically rated DC-OPF should converge");
+    assert!((relieved.branch_p_flow["line1_2"] - 50.0).abs() < 0.1);
+    assert!(relieved.generator_p["gen2"] < 0.1);
+    assert!(relieved.objective_value < congested.objective_value);
+}
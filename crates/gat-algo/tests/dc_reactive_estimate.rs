@@ -0,0 +1,71 @@
+//! Decoupled reactive estimate for DC-OPF, checked against SOCP on IEEE 14.
+
+use gat_algo::{OpfMethod, OpfSolution, OpfSolver};
+use gat_core::Network;
+use gat_io::importers::load_matpower_network;
+use std::path::Path;
+
+/// Branches carrying less than this (in the SOCP reference) are too small to
+/// compare signs meaningfully.
+const MIN_REFERENCE_MVAR: f64 = 5.0;
+
+fn load_case14() -> Network {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+    load_matpower_network(&path).expect("parse case14")
+}
+
+fn solve_dc(network: &Network, estimate: bool) -> OpfSolution {
+    OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .with_reactive_estimate(estimate)
+        .solve(network)
+        .expect("DC-OPF should converge")
+}
+
+#[test]
+fn test_reactive_estimate_not_computed_by_default() {
+    let solution = solve_dc(&load_case14(), false);
+    assert!(solution.reactive_estimate.is_none());
+    assert!(solution.branch_q_flow.is_empty());
+}
+
+#[test]
+fn test_reactive_estimate_case14_matches_socp_ballpark() {
+    let network = load_case14();
+    let dc = solve_dc(&network, true);
+    let socp = OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .solve(&network)
+        .expect("SOCP should converge");
+
+    let estimate = dc.reactive_estimate.as_ref().expect("estimate requested");
+    assert_eq!(estimate.bus_q_injection.len(), 14);
+    assert_eq!(dc.branch_q_flow.len(), socp.branch_q_flow.len());
+    for v in estimate.bus_voltage_mag.values() {
+        assert!((0.85..=1.15).contains(v), "implausible |V| {}", v);
+    }
+
+    let mut compared = 0;
+    let mut agreeing = 0;
+    for (name, &q_ref) in &socp.branch_q_flow {
+        if q_ref.abs() < MIN_REFERENCE_MVAR {
+            continue;
+        }
+        let q_est = dc.branch_q_flow[name];
+        compared += 1;
+        let same_sign = q_est.signum() == q_ref.signum();
+        let ratio = q_est.abs() / q_ref.abs();
+        if same_sign && (0.1..=10.0).contains(&ratio) {
+            agreeing += 1;
+        }
+    }
+
+    assert!(compared >= 5, "too few reference flows ({})", compared);
+    assert!(
+        agreeing as f64 >= 0.7 * compared as f64,
+        "only {}/{} branches agree in sign and magnitude with SOCP",
+        agreeing,
+        compared
+    );
+}
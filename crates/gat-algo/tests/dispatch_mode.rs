@@ -0,0 +1,123 @@
+//! Must-run and fixed-output generators across DC-OPF, SOCP and economic dispatch
+
+use gat_algo::{OpfMethod, OpfSolver};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, CostModel, DispatchMode, Edge, Gen, GenId, Kilovolts, Load,
+    LoadId, Megavars, Megawatts, Network, Node,
+};
+
+const METHODS: [OpfMethod; 3] = [
+    OpfMethod::DcOpf,
+    OpfMethod::SocpRelaxation,
+    OpfMethod::EconomicDispatch,
+];
+
+/// Cheap baseload ($10/MWh) and an expensive peaker ($80/MWh, 20 MW minimum)
+/// at bus 1 serving 120 MW at bus 2. Left to itself, the optimizer runs the
+/// peaker at its minimum.
+fn baseload_and_peaker(peaker_mode: DispatchMode) -> Network {
+    let mut network = Network::new();
+    let buses: Vec<_> = (1..=2)
+        .map(|i| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                base_kv: Kilovolts(230.0),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+    network.graph.add_edge(
+        buses[0],
+        buses[1],
+        Edge::Branch(Branch::new(
+            BranchId::new(1),
+            "line1_2".to_string(),
+            BusId::new(1),
+            BusId::new(2),
+            0.001,
+            0.01,
+        )),
+    );
+
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(1), "baseload".to_string(), BusId::new(1))
+            .with_p_limits(0.0, 200.0)
+            .with_q_limits(-100.0, 100.0)
+            .with_cost(CostModel::linear(0.0, 10.0)),
+    ));
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(2), "peaker".to_string(), BusId::new(1))
+            .with_p_limits(20.0, 100.0)
+            .with_q_limits(-50.0, 50.0)
+            .with_cost(CostModel::linear(0.0, 80.0))
+            .with_dispatch_mode(peaker_mode),
+    ));
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "load2".to_string(),
+        bus: BusId::new(2),
+        active_power: Megawatts(120.0),
+        reactive_power: Megavars(10.0),
+        zip: None,
+    }));
+    network
+}
+
+#[test]
+fn fixed_unit_holds_uneconomic_setpoint() {
+    for method in METHODS {
+        let free = OpfSolver::new()
+            .with_method(method)
+            .solve(&baseload_and_peaker(DispatchMode::Auto))
+            .unwrap();
+        assert!(
+            (free.generator_p["peaker"] - 20.0).abs() < 1e-2,
+            "{:?} ran the peaker at {:.3} MW",
+            method,
+            free.generator_p["peaker"]
+        );
+        assert!(free.constrained_generators.is_empty());
+
+        let fixed = OpfSolver::new()
+            .with_method(method)
+            .solve(&baseload_and_peaker(DispatchMode::Fixed(Megawatts(60.0))))
+            .unwrap();
+        assert!(
+            (fixed.generator_p["peaker"] - 60.0).abs() < 1e-2,
+            "{:?} moved the fixed peaker to {:.3} MW",
+            method,
+            fixed.generator_p["peaker"]
+        );
+        assert!(fixed.generator_p["baseload"] < free.generator_p["baseload"] - 39.0);
+        assert!(fixed.objective_value > free.objective_value);
+        assert_eq!(fixed.constrained_generators, ["peaker"]);
+    }
+}
+
+#[test]
+fn must_run_unit_reported_at_minimum() {
+    for method in METHODS {
+        let solution = OpfSolver::new()
+            .with_method(method)
+            .solve(&baseload_and_peaker(DispatchMode::MustRun))
+            .unwrap();
+        assert!((solution.generator_p["peaker"] - 20.0).abs() < 1e-2);
+        assert_eq!(solution.constrained_generators, ["peaker"], "{:?}", method);
+    }
+}
+
+#[test]
+fn must_run_floor_outlasts_availability() {
+    // A variable unit whose availability drops below its minimum is normally
+    // held to what is available; must-run keeps it at the minimum
+    let gen = Gen::new(GenId::new(1), "hydro".to_string(), BusId::new(1))
+        .with_p_limits(20.0, 100.0)
+        .with_p_available(5.0);
+    assert_eq!(gen.dispatch_pmin(), 5.0);
+    assert_eq!(gen.dispatch_pmax(), 5.0);
+
+    let gen = gen.with_dispatch_mode(DispatchMode::MustRun);
+    assert_eq!(gen.dispatch_pmin(), 20.0);
+    assert_eq!(gen.dispatch_pmax(), 20.0);
+}
@@ -0,0 +1,90 @@
+//! Distributed slack for the Newton-Raphson AC power flow on IEEE 30.
+
+use gat_algo::power_flow::ac_pf::AcPowerFlowSolver;
+use gat_core::{GenId, Megawatts, Network, Node};
+use gat_io::importers::load_matpower_network;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// IEEE 30 with the bus-1 unit scheduled 60 MW short, so the slack has real work to do.
+fn ieee30_short() -> Network {
+    let case = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_data/matpower/edge_cases/case_ieee30.m");
+    let mut network = load_matpower_network(&case).expect("IEEE 30 should import");
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if gen.bus.value() == 1 {
+                gen.active_power = Megawatts(gen.active_power.value() - 60.0);
+            }
+        }
+    }
+    network
+}
+
+fn gen_at(network: &Network, bus: usize) -> GenId {
+    network
+        .graph
+        .node_weights()
+        .find_map(|node| match node {
+            Node::Gen(gen) if gen.bus.value() == bus => Some(gen.id),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("no generator at bus {}", bus))
+}
+
+#[test]
+fn test_distributed_slack_spreads_pickup_by_factor() {
+    let network = ieee30_short();
+    let (g1, g2, g5) = (
+        gen_at(&network, 1),
+        gen_at(&network, 2),
+        gen_at(&network, 5),
+    );
+
+    let single = AcPowerFlowSolver::new()
+        .solve(&network)
+        .expect("single-slack power flow should converge");
+    let distributed = AcPowerFlowSolver::new()
+        .with_distributed_slack(HashMap::from([(g2, 2.0), (g5, 1.0)]))
+        .solve(&network)
+        .expect("distributed-slack power flow should converge");
+    assert!(single.converged && distributed.converged);
+
+    // Single slack: the bus-1 unit covers the shortfall plus losses on its own
+    assert_eq!(single.slack_pickup_mw.len(), 1);
+    let single_pickup = single.slack_pickup_mw[&g1];
+    assert!(single_pickup > 60.0, "pickup {:.2} MW", single_pickup);
+
+    // Distributed: the bus-1 unit stays on schedule and the others share 2:1
+    assert!(!distributed.slack_pickup_mw.contains_key(&g1));
+    let scheduled_g1 = single.generator_p_mw[&g1] - single_pickup;
+    assert!((distributed.generator_p_mw[&g1] - scheduled_g1).abs() < 1e-9);
+    let (p2, p5) = (
+        distributed.slack_pickup_mw[&g2],
+        distributed.slack_pickup_mw[&g5],
+    );
+    assert!(
+        (p2 - 2.0 * p5).abs() < 1e-6,
+        "pickups {:.3} / {:.3}",
+        p2,
+        p5
+    );
+    assert!((distributed.generator_p_mw[&g5] - p5).abs() < 1e-9);
+
+    // Same shortfall either way; only losses shift with the new flow pattern
+    assert!(
+        (p2 + p5 - single_pickup).abs() < 2.0,
+        "distributed {:.2} MW vs single {:.2} MW",
+        p2 + p5,
+        single_pickup
+    );
+}
+
+#[test]
+fn test_distributed_slack_rejects_unknown_generator() {
+    let network = ieee30_short();
+    let result = AcPowerFlowSolver::new()
+        .with_distributed_slack(HashMap::from([(GenId::new(999), 1.0)]))
+        .solve(&network);
+    assert!(result.is_err());
+}
@@ -0,0 +1,152 @@
+//! ELCC estimation with Monte Carlo confidence intervals.
+
+use gat_algo::elcc::deliverability_constrained;
+use gat_algo::{elcc, MonteCarlo};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Megavars, Megawatts, Network,
+    Node,
+};
+
+/// Three 40 MW units at bus 0 serving 90 MW at bus 1.
+fn tight_system() -> Network {
+    let mut network = Network::new();
+    let buses: Vec<_> = (0..2)
+        .map(|i| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                base_kv: gat_core::Kilovolts(100.0),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+    network.graph.add_edge(
+        buses[0],
+        buses[1],
+        Edge::Branch(Branch::new(
+            BranchId::new(0),
+            "line0_1".to_string(),
+            BusId::new(0),
+            BusId::new(1),
+            0.01,
+            0.05,
+        )),
+    );
+    for i in 0..3 {
+        network.graph.add_node(Node::Gen(Gen {
+            active_power: Megawatts(40.0),
+            ..Gen::new(GenId::new(i), format!("gen{}", i), BusId::new(0))
+        }));
+    }
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(0),
+        name: "load1".to_string(),
+        bus: BusId::new(1),
+        active_power: Megawatts(90.0),
+        reactive_power: Megavars(0.0),
+        zip: None,
+    }));
+    network
+}
+
+fn resource() -> Gen {
+    // Capacity comes from pmax; active power is left at zero
+    Gen::new(GenId::new(10), "new_unit".to_string(), BusId::new(0)).with_p_limits(0.0, 30.0)
+}
+
+#[test]
+fn test_elcc_point_estimate_lies_in_interval() {
+    let network = tight_system();
+    let mc = MonteCarlo::new(300);
+    let result = elcc(&network, &resource(), &mc).unwrap();
+
+    assert_eq!(result.capacity_mw, 30.0);
+    assert!((0.0..=30.0).contains(&result.elcc_mw));
+    assert!((0.0..=1.0).contains(&result.elcc_fraction));
+    assert!(
+        result.elcc_ci_mw.0 <= result.elcc_mw && result.elcc_mw <= result.elcc_ci_mw.1,
+        "ELCC {} outside {:?}",
+        result.elcc_mw,
+        result.elcc_ci_mw
+    );
+    assert!(result.baseline_lole_ci.0 <= result.baseline_lole);
+    assert!(result.baseline_lole <= result.baseline_lole_ci.1);
+}
+
+#[test]
+fn test_elcc_trace_records_bisection() {
+    let network = tight_system();
+    let mc = MonteCarlo::new(300);
+    let result = elcc(&network, &resource(), &mc).unwrap();
+
+    // Bracket ends first, then bisection steps inside them
+    assert!(result.trace.len() >= 2);
+    assert_eq!(result.trace[0].added_load_mw, 0.0);
+    assert_eq!(result.trace[1].added_load_mw, 30.0);
+    assert!(result.trace[0].lole <= result.trace[1].lole);
+    for step in &result.trace[2..] {
+        assert!(step.added_load_mw > 0.0 && step.added_load_mw < 30.0);
+        assert!(step.lole_ci.0 <= step.lole && step.lole <= step.lole_ci.1);
+    }
+}
+
+#[test]
+fn test_elcc_rejects_zero_capacity() {
+    let network = tight_system();
+    let mut idle = resource();
+    idle.pmax = Megawatts(0.0);
+    assert!(elcc(&network, &idle, &MonteCarlo::new(10)).is_err());
+}
+
+#[test]
+fn test_elcc_rejects_unbounded_capacity() {
+    let network = tight_system();
+    let unbounded = Gen::new(GenId::new(10), "new_unit".to_string(), BusId::new(0));
+    assert!(elcc(&network, &unbounded, &MonteCarlo::new(10)).is_err());
+}
+
+#[test]
+fn test_congested_resource_gets_lower_adjusted_elcc() {
+    // A radial spur to bus 2 rated at 10 MW, a third of the resource's output
+    let mut network = tight_system();
+    network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(2),
+        name: "bus2".to_string(),
+        base_kv: gat_core::Kilovolts(100.0),
+        ..Bus::default()
+    }));
+    network
+        .add_branch_checked(
+            Branch::new(
+                BranchId::new(1),
+                "line2_1".to_string(),
+                BusId::new(2),
+                BusId::new(1),
+                0.01,
+                0.05,
+            )
+            .with_s_max(Some(10.0)),
+        )
+        .unwrap();
+    let mut behind_corridor = resource();
+    behind_corridor.bus = BusId::new(2);
+
+    let mc = MonteCarlo::new(300);
+    let free = deliverability_constrained(&network, &resource(), &mc).unwrap();
+    let congested = deliverability_constrained(&network, &behind_corridor, &mc).unwrap();
+
+    assert_eq!(free.deliverable_fraction, 1.0);
+    assert_eq!(free.adjusted_elcc_mw, free.raw.elcc_mw);
+    assert!((congested.deliverable_fraction - 1.0 / 3.0).abs() < 1e-9);
+    assert_eq!(congested.limiting_branch.as_deref(), Some("line2_1"));
+
+    // Same size, same adequacy contribution, but only a third is deliverable
+    assert!(congested.raw.elcc_mw > 0.0);
+    assert!(congested.adjusted_elcc_mw < free.adjusted_elcc_mw);
+    assert!(
+        (congested.adjusted_elcc_mw - congested.raw.elcc_mw / 3.0).abs() < 1e-9,
+        "adjusted {} vs raw {}",
+        congested.adjusted_elcc_mw,
+        congested.raw.elcc_mw
+    );
+}
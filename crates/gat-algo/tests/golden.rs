@@ -0,0 +1,52 @@
+//! Golden-case regression tests: OPF runs compared against stored reference
+//! solutions in `test_data/golden/`. Set `GAT_UPDATE_GOLDEN=1` to regenerate.
+
+use gat_algo::test_utils::{
+    assert_solution_matches_golden, golden_drift, load_golden, GoldenTolerances,
+};
+use gat_algo::{OpfMethod, OpfSolution, OpfSolver};
+use gat_core::Network;
+use gat_io::importers::load_matpower_network;
+use std::path::Path;
+
+fn load_case14() -> Network {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+    load_matpower_network(&path).expect("parse case14")
+}
+
+fn solve_case14_dc() -> OpfSolution {
+    OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .solve(&load_case14())
+        .expect("DC-OPF should converge")
+}
+
+#[test]
+fn test_ieee14_dc_opf_matches_golden() {
+    let solution = solve_case14_dc();
+    assert!(solution.converged);
+    assert_solution_matches_golden("ieee14_dc_opf", &solution, &GoldenTolerances::default());
+}
+
+#[test]
+fn test_golden_tolerates_drift_within_band() {
+    let mut solution = solve_case14_dc();
+    *solution.branch_p_flow.get_mut("Branch 1-2").unwrap() += 0.05;
+    let golden = load_golden("ieee14_dc_opf").unwrap();
+    assert!(golden_drift(&golden, &solution, &GoldenTolerances::default()).is_empty());
+}
+
+#[test]
+fn test_golden_catches_injected_perturbation() {
+    // Shift 5 MW from the cheap unit at bus 1 to the one at bus 2
+    let mut solution = solve_case14_dc();
+    *solution.generator_p.get_mut("Gen 0@1").unwrap() -= 5.0;
+    *solution.generator_p.get_mut("Gen 1@2").unwrap() += 5.0;
+
+    let golden = load_golden("ieee14_dc_opf").unwrap();
+    let drift = golden_drift(&golden, &solution, &GoldenTolerances::default());
+    assert_eq!(drift.len(), 2, "{:?}", drift);
+    assert!(drift[0].starts_with("generator_p[Gen 0@1]"));
+    assert!(drift[1].starts_with("generator_p[Gen 1@2]"));
+}
@@ -19,6 +19,7 @@ fn create_ieee14_style_network() -> Network {
             vmax_pu: Some(PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
         // Store bus_idx for edge creation (though we'll look them up later)
         let _ = bus_idx;
@@ -0,0 +1,62 @@
+//! Frequency-response screening from fleet inertia.
+
+use gat_algo::{inertia_adequacy, InertiaCriteria};
+use gat_core::{Bus, BusId, Gen, GenId, MegavoltAmperes, Network, Node};
+
+/// A 5 GVA steam unit (H = 4 s), a 1 GVA synchronous condenser (H = 2 s) and
+/// 3 GW of inverter-based solar, all at one bus.
+fn fleet(steam_inertia: Option<f64>, with_condenser: bool) -> Network {
+    let mut network = Network::new();
+    network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(1),
+        name: "system".to_string(),
+        ..Bus::default()
+    }));
+
+    let mut steam =
+        Gen::new(GenId::new(1), "steam".to_string(), BusId::new(1)).with_p_limits(0.0, 4500.0);
+    steam.mbase = Some(MegavoltAmperes(5000.0));
+    steam.inertia_h = steam_inertia;
+    network.graph.add_node(Node::Gen(steam));
+
+    if with_condenser {
+        let mut condenser = Gen::new(GenId::new(2), "condenser".to_string(), BusId::new(1))
+            .as_synchronous_condenser()
+            .with_inertia(2.0);
+        condenser.mbase = Some(MegavoltAmperes(1000.0));
+        network.graph.add_node(Node::Gen(condenser));
+    }
+
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(3), "solar".to_string(), BusId::new(1)).with_p_limits(0.0, 3000.0),
+    ));
+    network
+}
+
+#[test]
+fn inertia_adequacy_flags_low_inertia_fleet() {
+    let trip_mw = 80.0;
+    let limit = InertiaCriteria::default().max_rocof_hz_per_s;
+
+    // 22 GW·s of stored energy rides through an 80 MW trip
+    let synchronous = inertia_adequacy(&fleet(Some(4.0), true), trip_mw).unwrap();
+    assert!((synchronous.kinetic_energy_mws - 22_000.0).abs() < 1e-9);
+    let expected_rocof = trip_mw * 60.0 / (2.0 * 22_000.0);
+    assert!((synchronous.rocof_hz_per_s - expected_rocof).abs() < 1e-12);
+    assert!((synchronous.nadir_hz - (60.0 - expected_rocof * 5.0)).abs() < 1e-12);
+    assert!(synchronous.is_adequate());
+    assert!(synchronous.required_kinetic_energy_mws <= synchronous.kinetic_energy_mws);
+
+    // Replacing the steam unit's inertia with inverters leaves only the condenser
+    let displaced = inertia_adequacy(&fleet(None, true), trip_mw).unwrap();
+    assert!((displaced.kinetic_energy_mws - 2_000.0).abs() < 1e-9);
+    assert!(displaced.rocof_hz_per_s > limit);
+    assert!(!displaced.rocof_ok);
+    assert!(!displaced.is_adequate());
+
+    // The condenser's inertia is what keeps RoCoF finite
+    let inverter_only = inertia_adequacy(&fleet(None, false), trip_mw).unwrap();
+    assert_eq!(inverter_only.kinetic_energy_mws, 0.0);
+    assert!(inverter_only.rocof_hz_per_s.is_infinite());
+    assert!(!inverter_only.is_adequate());
+}
@@ -0,0 +1,316 @@
+//! dLMP/dP sensitivity tests validated against finite differences on IEEE 14
+//! and a small network with a binding angle limit.
+
+use gat_algo::{OpfMethod, OpfSolution, OpfSolver};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Kilovolts, Load, LoadId, Megavars,
+    Megawatts, Network, Node, Radians,
+};
+use gat_io::importers::load_matpower_network;
+use std::path::Path;
+
+const DELTA_MW: f64 = 1.0;
+
+fn load_case14() -> Network {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+    load_matpower_network(&path).expect("parse case14")
+}
+
+/// Case14 with a quadratic term on the cheap unit so the sensitivity is non-zero.
+fn load_case14_quadratic() -> Network {
+    let mut network = load_case14();
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if gen.bus == BusId::new(1) {
+                gen.cost_model = CostModel::quadratic(0.0, 7.920951, 0.02);
+            }
+        }
+    }
+    network
+}
+
+/// Three buses in a triangle of equal reactances with load at bus 3. The
+/// angle limit on line 1-3 caps its flow at 80 MW, so the cheap unit at
+/// bus 1 stops at 90 MW and the unit at bus 2 covers the remaining 60 MW:
+/// both are marginal and bus prices separate (19, 32 and 45 $/MWh).
+fn triangle_network(pmax2: f64) -> Network {
+    let mut network = Network::new();
+    let buses: Vec<_> = (1..=3)
+        .map(|i| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                base_kv: Kilovolts(100.0),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+    for (id, (from, to)) in [(1, 3), (1, 2), (2, 3)].into_iter().enumerate() {
+        network.graph.add_edge(
+            buses[from - 1],
+            buses[to - 1],
+            Edge::Branch(Branch {
+                id: BranchId::new(id),
+                name: format!("line{}_{}", from, to),
+                from_bus: BusId::new(from),
+                to_bus: BusId::new(to),
+                reactance: 0.1,
+                angle_max: (id == 0).then_some(Radians(0.08)),
+                ..Branch::default()
+            }),
+        );
+    }
+    for (id, bus, pmax, c1, c2) in [(1, 1, 200.0, 10.0, 0.05), (2, 2, pmax2, 20.0, 0.1)] {
+        network.graph.add_node(Node::Gen(Gen {
+            id: GenId::new(id),
+            name: format!("gen{}", id),
+            bus: BusId::new(bus),
+            pmax: Megawatts(pmax),
+            cost_model: CostModel::quadratic(0.0, c1, c2),
+            ..Gen::default()
+        }));
+    }
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "load3".to_string(),
+        bus: BusId::new(3),
+        active_power: Megawatts(150.0),
+        reactive_power: Megavars(0.0),
+        zip: None,
+    }));
+    network
+}
+
+fn add_load(network: &mut Network, bus: BusId, mw: f64) {
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(9999),
+        name: "perturbation".to_string(),
+        bus,
+        active_power: Megawatts(mw),
+        reactive_power: Megavars(0.0),
+        zip: None,
+    }));
+}
+
+fn bus_name(network: &Network, bus: BusId) -> String {
+    network
+        .buses()
+        .into_iter()
+        .find(|b| b.id == bus)
+        .map(|b| b.name.clone())
+        .expect("bus exists")
+}
+
+fn solve(network: &Network, sensitivities: bool) -> OpfSolution {
+    OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .with_lmp_sensitivities(sensitivities)
+        .solve(network)
+        .expect("DC-OPF should converge")
+}
+
+/// Compare every defined row of the sensitivity matrix against a forward
+/// finite difference of the LMPs for load added at each `load_buses` entry.
+fn assert_matches_finite_difference(build: fn() -> Network, load_buses: &[usize]) {
+    let base_network = build();
+    let base = solve(&base_network, true);
+    let sens = base
+        .lmp_sensitivity
+        .as_ref()
+        .expect("sensitivities requested");
+
+    for &k in load_buses {
+        let k_name = bus_name(&base_network, BusId::new(k));
+        let mut perturbed_network = build();
+        add_load(&mut perturbed_network, BusId::new(k), DELTA_MW);
+        let perturbed = solve(&perturbed_network, false);
+
+        for (i_name, lmp) in &base.bus_lmp {
+            if !sens.is_defined(i_name) {
+                continue;
+            }
+            let fd = (perturbed.bus_lmp[i_name] - lmp) / DELTA_MW;
+            let analytic = sens.get(i_name, &k_name).expect("matrix entry");
+            assert!(
+                (fd - analytic).abs() < 1e-4,
+                "dLMP({})/dP({}): analytic {:.6} vs finite difference {:.6}",
+                i_name,
+                k_name,
+                analytic,
+                fd
+            );
+        }
+    }
+}
+
+#[test]
+fn test_lmp_sensitivity_not_computed_by_default() {
+    let solution = solve(&load_case14(), false);
+    assert!(solution.lmp_sensitivity.is_none());
+}
+
+#[test]
+fn test_lmp_sensitivity_case14_linear_costs() {
+    let network = load_case14();
+    let solution = solve(&network, true);
+    let sens = solution.lmp_sensitivity.expect("sensitivities requested");
+
+    // Full bus-by-bus matrix
+    assert_eq!(sens.sensitivity.len(), 14);
+    assert!(sens.sensitivity.values().all(|row| row.len() == 14));
+    assert!(sens.breakpoint_buses.is_empty());
+
+    assert_matches_finite_difference(load_case14, &[4, 9, 14]);
+}
+
+#[test]
+fn test_lmp_sensitivity_case14_quadratic_costs() {
+    let network = load_case14_quadratic();
+    let solution = solve(&network, true);
+    let sens = solution.lmp_sensitivity.expect("sensitivities requested");
+
+    let bus3 = bus_name(&network, BusId::new(3));
+    let bus14 = bus_name(&network, BusId::new(14));
+    assert!((sens.get(&bus3, &bus14).unwrap() - 0.04).abs() < 1e-9);
+
+    assert_matches_finite_difference(load_case14_quadratic, &[2, 9, 14]);
+}
+
+#[test]
+fn test_lmp_sensitivity_flags_breakpoint() {
+    // Cap the cheap unit exactly at system load: both units sit on a limit,
+    // so any load change moves the price setter and dLMP/dP is undefined.
+    let mut network = load_case14();
+    let total_load = network.total_load_mw();
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if gen.bus == BusId::new(1) {
+                gen.pmax = Megawatts(total_load);
+            }
+        }
+    }
+
+    let solution = solve(&network, true);
+    let sens = solution.lmp_sensitivity.expect("sensitivities requested");
+    assert_eq!(sens.breakpoint_buses.len(), 14);
+    assert!(!sens.is_defined(&bus_name(&network, BusId::new(5))));
+}
+
+#[test]
+fn test_lmp_sensitivity_binding_angle_limit() {
+    let network = triangle_network(200.0);
+    let solution = solve(&network, true);
+    let sens = solution.lmp_sensitivity.expect("sensitivities requested");
+    assert!(sens.breakpoint_buses.is_empty());
+
+    // Holding the 1-3 flow fixed, load at bus 3 is met by backing down
+    // gen1 by 1 MW and raising gen2 by 2 MW
+    let expected = [
+        ("bus1", "bus1", 0.1),
+        ("bus2", "bus1", 0.0),
+        ("bus3", "bus1", -0.1),
+        ("bus1", "bus2", 0.0),
+        ("bus2", "bus2", 0.2),
+        ("bus3", "bus2", 0.4),
+        ("bus1", "bus3", -0.1),
+        ("bus2", "bus3", 0.4),
+        ("bus3", "bus3", 0.9),
+    ];
+    for (lmp_bus, load_bus, value) in expected {
+        let analytic = sens.get(lmp_bus, load_bus).expect("matrix entry");
+        assert!(
+            (analytic - value).abs() < 1e-6,
+            "dLMP({})/dP({}): expected {}, got {}",
+            lmp_bus,
+            load_bus,
+            value,
+            analytic
+        );
+    }
+
+    assert_matches_finite_difference(|| triangle_network(200.0), &[1, 2, 3]);
+}
+
+#[test]
+fn test_lmp_sensitivity_breakpoint_flags_priced_buses_only() {
+    // gen2 sits 0.05 MW below Pmax. It sets the prices at buses 2 and 3;
+    // bus 1 is priced by gen1 alone.
+    let network = triangle_network(60.05);
+    let solution = solve(&network, true);
+    let sens = solution.lmp_sensitivity.expect("sensitivities requested");
+
+    assert!(sens.is_defined("bus1"));
+    assert!(!sens.is_defined("bus2"));
+    assert!(!sens.is_defined("bus3"));
+}
+
+#[test]
+fn test_lmp_sensitivity_shared_quadratic_units() {
+    // Two units at one bus with equal linear costs; the LP may split the
+    // load anywhere, so both stay inside their limits
+    let mut network = Network::new();
+    let bus1 = network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(1),
+        name: "bus1".to_string(),
+        base_kv: Kilovolts(100.0),
+        ..Bus::default()
+    }));
+    let bus2 = network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(2),
+        name: "bus2".to_string(),
+        base_kv: Kilovolts(100.0),
+        ..Bus::default()
+    }));
+    network.graph.add_edge(
+        bus1,
+        bus2,
+        Edge::Branch(Branch {
+            id: BranchId::new(1),
+            name: "line1_2".to_string(),
+            from_bus: BusId::new(1),
+            to_bus: BusId::new(2),
+            reactance: 0.1,
+            ..Branch::default()
+        }),
+    );
+    for (id, c2) in [(1, 0.01), (2, 0.03)] {
+        network.graph.add_node(Node::Gen(Gen {
+            id: GenId::new(id),
+            name: format!("gen{}", id),
+            bus: BusId::new(1),
+            pmax: Megawatts(80.0),
+            cost_model: CostModel::quadratic(0.0, 10.0, c2),
+            ..Gen::default()
+        }));
+    }
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "load2".to_string(),
+        bus: BusId::new(2),
+        active_power: Megawatts(100.0),
+        reactive_power: Megavars(0.0),
+        zip: None,
+    }));
+
+    let solution = solve(&network, true);
+    for gen in ["gen1", "gen2"] {
+        let p = solution.generator_p[gen];
+        assert!(
+            p > 20.1 && p < 79.9,
+            "{} should be marginal, got {}",
+            gen,
+            p
+        );
+    }
+    let sens = solution.lmp_sensitivity.expect("sensitivities requested");
+    assert!(sens.breakpoint_buses.is_empty());
+
+    // Units share a load change in inverse proportion to 2·c2
+    let expected = 1.0 / (1.0 / 0.02 + 1.0 / 0.06);
+    for lmp_bus in ["bus1", "bus2"] {
+        for load_bus in ["bus1", "bus2"] {
+            assert!((sens.get(lmp_bus, load_bus).unwrap() - expected).abs() < 1e-9);
+        }
+    }
+}
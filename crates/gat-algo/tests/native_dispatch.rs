@@ -83,6 +83,7 @@ fn two_bus_network() -> Network {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network
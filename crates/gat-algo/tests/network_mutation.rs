@@ -0,0 +1,69 @@
+//! Power flow on networks edited through the `Network` mutation API.
+#![cfg(feature = "desktop")]
+
+use gat_algo::dc_power_flow_angles;
+use gat_core::{Branch, BranchId, Bus, BusId, Gen, GenId, Load, LoadId, Megavars, Megawatts};
+use gat_core::{Network, Node};
+
+/// Triangle of identical lines, 100 MW from bus 1 (the DC slack) to bus 3.
+fn triangle() -> Network {
+    let mut network = Network::new();
+    for i in 1..=3 {
+        network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(i),
+            name: format!("bus{}", i),
+            ..Bus::default()
+        }));
+    }
+    for (id, (from, to)) in [(1, 2), (1, 3), (2, 3)].into_iter().enumerate() {
+        network
+            .add_branch_checked(Branch::new(
+                BranchId::new(id),
+                format!("line{}_{}", from, to),
+                BusId::new(from),
+                BusId::new(to),
+                0.0,
+                0.1,
+            ))
+            .unwrap();
+    }
+    let mut gen = Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1));
+    gen.active_power = Megawatts(100.0);
+    network.graph.add_node(Node::Gen(gen));
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "load3".to_string(),
+        bus: BusId::new(3),
+        active_power: Megawatts(100.0),
+        reactive_power: Megavars(0.0),
+        zip: None,
+    }));
+    network
+}
+
+/// Angle drop from bus 1 to bus 3.
+fn angle_drop(network: &Network) -> f64 {
+    let angles = dc_power_flow_angles(network).unwrap();
+    angles[&1] - angles[&3]
+}
+
+#[test]
+fn test_out_of_service_branch_excluded_from_power_flow() {
+    let mut network = triangle();
+    let meshed = angle_drop(&network);
+
+    network.set_branch_status(BranchId::new(1), false).unwrap();
+    assert!(network
+        .in_service_branches()
+        .iter()
+        .all(|branch| branch.name != "line1_3"));
+
+    // Direct path carries 2/3 of the transfer over x; with it open, all of
+    // it crosses 2x, so the angle drop triples
+    let radial = angle_drop(&network);
+    assert!((radial / meshed - 3.0).abs() < 1e-9);
+
+    // Removing the open branch leaves the same power flow
+    network.remove_branch(BranchId::new(1)).unwrap();
+    assert!((angle_drop(&network) - radial).abs() < 1e-12);
+}
@@ -0,0 +1,36 @@
+//! Solver timing entry point, exercised on IEEE 14.
+
+use gat_algo::opf::benchmark;
+use gat_algo::OpfMethod;
+use gat_core::Network;
+use gat_io::importers::load_matpower_network;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn load_case14() -> Network {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+    load_matpower_network(&path).expect("parse case14")
+}
+
+#[test]
+fn test_benchmark_dc_and_economic_case14() {
+    let methods = [OpfMethod::DcOpf, OpfMethod::EconomicDispatch];
+    let result = benchmark(&load_case14(), &methods, 3).expect("benchmark should run");
+
+    assert_eq!(result.timings.len(), 2);
+    for method in methods {
+        let timing = result.get(method).expect("method benchmarked");
+        assert_eq!(timing.repeats, 3);
+        assert!(timing.converged, "{} did not converge", method);
+        assert!(timing.min_ms > 0.0, "{} min time {}", method, timing.min_ms);
+        assert!(timing.min_ms <= timing.median_ms);
+        assert!(timing.median_ms <= timing.max_ms);
+        assert!(timing.objective_value > 0.0);
+    }
+
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("benchmark.parquet");
+    result.write_parquet(&path).unwrap();
+    assert!(std::fs::metadata(&path).unwrap().len() > 0);
+}
@@ -0,0 +1,91 @@
+//! Reactive adequacy screening with the SOCP engine.
+
+use gat_algo::reactive_adequacy_at;
+use gat_core::{
+    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Kilovolts, Load, LoadId, Megavars,
+    Megawatts, Network, Node, PerUnit,
+};
+
+/// 100 MW + 80 Mvar at bus 2, fed over a reactive line from bus 1. Bus 1 can
+/// sit at 1.05 p.u., which is not enough to deliver 80 Mvar to bus 2 above
+/// 0.95 p.u.; a condenser at bus 2 covers the local Q when present.
+fn weak_tie(with_condenser: bool) -> Network {
+    let mut network = Network::new();
+    let buses: Vec<_> = ["source", "load_bus"]
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i + 1),
+                name: name.to_string(),
+                base_kv: Kilovolts(100.0),
+                vmin_pu: Some(PerUnit(0.95)),
+                vmax_pu: Some(PerUnit(1.05)),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+    network.graph.add_edge(
+        buses[0],
+        buses[1],
+        Edge::Branch(Branch::new(
+            BranchId::new(1),
+            "tie".to_string(),
+            BusId::new(1),
+            BusId::new(2),
+            0.01,
+            0.2,
+        )),
+    );
+
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(1), "thermal".to_string(), BusId::new(1))
+            .with_p_limits(0.0, 300.0)
+            .with_q_limits(-100.0, 200.0)
+            .with_cost(CostModel::linear(0.0, 10.0)),
+    ));
+    if with_condenser {
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(2), "condenser".to_string(), BusId::new(2))
+                .with_p_limits(0.0, 0.0)
+                .with_q_limits(0.0, 80.0),
+        ));
+    }
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "load".to_string(),
+        bus: BusId::new(2),
+        active_power: Megawatts(100.0),
+        reactive_power: Megavars(80.0),
+        zip: None,
+    }));
+    network
+}
+
+#[test]
+fn condenser_keeps_voltages_within_bounds() {
+    let report = reactive_adequacy_at(&weak_tie(true), &[1.0]).unwrap();
+    assert!(report.is_adequate());
+    assert_eq!(report.worst_shortfall_mvar(), 0.0);
+}
+
+#[test]
+fn removing_condenser_flags_undervoltage() {
+    let report = reactive_adequacy_at(&weak_tie(false), &[1.0]).unwrap();
+    assert!(!report.is_adequate());
+
+    let scenario = &report.scenarios[0];
+    assert!(scenario.resolved);
+    assert_eq!(scenario.deficient_buses.len(), 1);
+    let bus = &scenario.deficient_buses[0];
+    assert_eq!(bus.bus, "load_bus");
+    assert!(bus.voltage_pu < 0.95);
+
+    // Holding 0.95 p.u. at bus 2 with 1.05 p.u. at bus 1 leaves room for
+    // about 33 Mvar over the tie, so roughly 47 Mvar must come locally
+    assert!(
+        (scenario.shortfall_mvar - 47.3).abs() < 5.0,
+        "shortfall {:.2} Mvar",
+        scenario.shortfall_mvar
+    );
+}
@@ -53,6 +53,7 @@ fn create_benchmark_network(
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(load_capacity),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network.graph.add_edge(
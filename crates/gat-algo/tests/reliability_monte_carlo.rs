@@ -1,10 +1,12 @@
 use gat_algo::{
-    DeliverabilityScore, DeliverabilityScoreConfig, MonteCarlo, OutageGenerator, OutageScenario,
-    ReliabilityMetrics,
+    DeliverabilityScore, DeliverabilityScoreConfig, MonteCarlo, MonteCarloProgress,
+    OutageGenerator, OutageScenario, ReliabilityMetrics, Voll,
 };
 use gat_core::{
     Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Network, Node,
 };
+use polars::prelude::{ParquetReader, SerReader};
+use std::fs::File;
 
 fn create_simple_network() -> Network {
     let mut network = Network::new();
@@ -47,6 +49,7 @@ fn create_simple_network() -> Network {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(80.0),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     // Add branch
@@ -159,6 +162,7 @@ fn test_monte_carlo_perfect_reliability() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network.graph.add_edge(
@@ -226,6 +230,7 @@ fn test_monte_carlo_tight_reliability() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(100.0),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network.graph.add_edge(
@@ -314,6 +319,7 @@ fn test_monte_carlo_multiple_networks() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(80.0),
         reactive_power: gat_core::Megavars(0.0),
+        zip: None,
     }));
 
     network2.graph.add_edge(
@@ -395,6 +401,7 @@ fn test_deliverability_score_perfect_reliability() {
         scenarios_analyzed: 1000,
         scenarios_with_shortfall: 0,
         average_shortfall: 0.0,
+        outage_cost: None,
     };
 
     let config = DeliverabilityScoreConfig::new();
@@ -413,6 +420,7 @@ fn test_deliverability_score_poor_reliability() {
         scenarios_analyzed: 1000,
         scenarios_with_shortfall: 500,
         average_shortfall: 5.0,
+        outage_cost: None,
     };
 
     let config = DeliverabilityScoreConfig::new();
@@ -435,6 +443,7 @@ fn test_deliverability_score_clamps_to_range() {
         scenarios_analyzed: 1000,
         scenarios_with_shortfall: 1000,
         average_shortfall: 50.0,
+        outage_cost: None,
     };
 
     let config = DeliverabilityScoreConfig::new();
@@ -455,6 +464,7 @@ fn test_deliverability_score_status_levels() {
         scenarios_analyzed: 1000,
         scenarios_with_shortfall: 10,
         average_shortfall: 0.05,
+        outage_cost: None,
     };
     let score_excellent = DeliverabilityScore::from_metrics(excellent, &config).unwrap();
     assert_eq!(score_excellent.status(), "Excellent");
@@ -465,6 +475,7 @@ fn test_deliverability_score_status_levels() {
         scenarios_analyzed: 1000,
         scenarios_with_shortfall: 50,
         average_shortfall: 0.05,
+        outage_cost: None,
     };
     let score_good = DeliverabilityScore::from_metrics(good, &config).unwrap();
     assert_eq!(score_good.status(), "Good");
@@ -475,6 +486,7 @@ fn test_deliverability_score_status_levels() {
         scenarios_analyzed: 1000,
         scenarios_with_shortfall: 1000,
         average_shortfall: 25.0,
+        outage_cost: None,
     };
     let score_critical = DeliverabilityScore::from_metrics(critical, &config).unwrap();
     assert_eq!(score_critical.status(), "Critical");
@@ -488,6 +500,7 @@ fn test_deliverability_score_meets_threshold() {
         scenarios_analyzed: 1000,
         scenarios_with_shortfall: 50,
         average_shortfall: 0.05,
+        outage_cost: None,
     };
 
     let config = DeliverabilityScoreConfig::new();
@@ -516,3 +529,143 @@ fn test_monte_carlo_arena_allocation_large_scenario_count() {
     assert!(metrics.lole >= 0.0);
     assert!(metrics.eue >= 0.0);
 }
+
+#[test]
+fn test_monte_carlo_progress_snapshots_match_result() {
+    let network = create_simple_network();
+    let mc = MonteCarlo::new(1000);
+
+    let mut snapshots: Vec<MonteCarloProgress> = Vec::new();
+    let metrics = mc
+        .compute_reliability_with_progress(&network, 100, |progress| {
+            snapshots.push(progress.clone())
+        })
+        .unwrap();
+
+    assert_eq!(snapshots.len(), 10);
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        assert_eq!(snapshot.samples_done, 100 * (i + 1));
+        assert_eq!(snapshot.total_samples, 1000);
+        assert!(snapshot.lole_ci.0 <= snapshot.lole && snapshot.lole <= snapshot.lole_ci.1);
+        assert!(snapshot.eue_ci.0 <= snapshot.eue && snapshot.eue <= snapshot.eue_ci.1);
+    }
+
+    let last = snapshots.last().unwrap();
+    assert_eq!(last.lole, metrics.lole);
+    assert_eq!(last.eue, metrics.eue);
+    assert!(
+        metrics.lole > 0.0,
+        "generator outages should cause shortfall"
+    );
+
+    // The callback path does not change the estimate
+    let plain = mc.compute_reliability(&network).unwrap();
+    assert_eq!(plain.lole, metrics.lole);
+    assert_eq!(plain.eue, metrics.eue);
+}
+
+#[test]
+fn test_monte_carlo_outage_cost_scales_with_critical_bus_voll() {
+    // Add a critical 30 MW load at a third bus, fed from the generator bus
+    let mut network = create_simple_network();
+    let gen_bus = network.graph.node_indices().next().unwrap();
+    let critical_idx = network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(2),
+        name: "hospital".to_string(),
+        base_kv: gat_core::Kilovolts(100.0),
+        ..Bus::default()
+    }));
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "critical".to_string(),
+        bus: BusId::new(2),
+        active_power: gat_core::Megawatts(30.0),
+        reactive_power: gat_core::Megavars(0.0),
+        zip: None,
+    }));
+    network.graph.add_edge(
+        gen_bus,
+        critical_idx,
+        Edge::Branch(Branch {
+            id: BranchId::new(1),
+            name: "br1_3".to_string(),
+            from_bus: BusId::new(0),
+            to_bus: BusId::new(2),
+            resistance: 0.01,
+            reactance: 0.05,
+            ..Branch::default()
+        }),
+    );
+    let critical = BusId::new(2);
+
+    let cost_with = |critical_voll: f64| {
+        MonteCarlo::new(2000)
+            .with_voll(Voll::uniform(1_000.0).with_bus(critical, critical_voll))
+            .compute_reliability(&network)
+            .unwrap()
+    };
+    let base = cost_with(5_000.0);
+    let raised = cost_with(10_000.0);
+    let base_cost = base.outage_cost.as_ref().unwrap();
+    let raised_cost = raised.outage_cost.as_ref().unwrap();
+
+    // Per-bus unserved energy accounts for all of EUE
+    let bus_total: f64 = base_cost.bus_eue.values().sum();
+    assert!((bus_total - base.eue).abs() < 1e-6 * base.eue.max(1.0));
+
+    // Cheaper load is shed first, but a lost generator blacks out the critical bus too
+    let critical_eue = base_cost.bus_eue[&critical];
+    assert!(critical_eue > 0.0);
+    assert!(critical_eue < base_cost.bus_eue[&BusId::new(1)]);
+
+    // Raising the critical VOLL adds exactly ΔVOLL × its unserved energy
+    let delta = raised_cost.expected_cost - base_cost.expected_cost;
+    assert!((delta - 5_000.0 * critical_eue).abs() < 1e-6 * delta);
+    assert_eq!(raised_cost.bus_eue, base_cost.bus_eue);
+
+    // Uniform VOLL prices EUE directly; no VOLL means no cost
+    let uniform = MonteCarlo::new(2000)
+        .with_voll(Voll::uniform(1_000.0))
+        .compute_reliability(&network)
+        .unwrap();
+    let uniform_cost = uniform.outage_cost.unwrap().expected_cost;
+    assert!((uniform_cost - 1_000.0 * uniform.eue).abs() < 1e-6 * uniform_cost);
+    assert!(MonteCarlo::new(100)
+        .compute_reliability(&network)
+        .unwrap()
+        .outage_cost
+        .is_none());
+}
+
+#[test]
+fn test_monte_carlo_event_log_matches_frequency_index() {
+    let network = create_simple_network();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("events.parquet");
+
+    let metrics = MonteCarlo::new(1000)
+        .with_event_log(&path)
+        .compute_reliability_with_progress(&network, 250, |_| {})
+        .unwrap();
+    assert!(metrics.scenarios_with_shortfall > 0);
+
+    let events = ParquetReader::new(File::open(&path).unwrap())
+        .finish()
+        .unwrap();
+    assert_eq!(events.height(), metrics.scenarios_with_shortfall);
+
+    let unserved = events.column("unserved_mw").unwrap().f64().unwrap();
+    assert!(unserved.into_iter().all(|mw| mw.unwrap() > 0.0));
+    let duration: f64 = events
+        .column("duration_hours")
+        .unwrap()
+        .f64()
+        .unwrap()
+        .sum()
+        .unwrap();
+    assert!((duration - metrics.lole).abs() < 1e-9 * metrics.lole.max(1.0));
+
+    // Every shortfall here comes from an outage of the only generator or line
+    let failed = events.column("failed_components").unwrap().utf8().unwrap();
+    assert!(failed.into_iter().all(|c| !c.unwrap().is_empty()));
+}
@@ -0,0 +1,144 @@
+//! Renewable curtailment reporting for DC-OPF and SOCP
+
+use gat_algo::{OpfMethod, OpfSolver};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Megavars,
+    MegavoltAmperes, Megawatts, Network, Node,
+};
+
+const WIND_AVAILABLE_MW: f64 = 150.0;
+const LINE_LIMIT_MVA: f64 = 80.0;
+
+/// Wind farm at bus 2 exporting to a load at bus 1 over one line.
+/// An expensive thermal unit at bus 1 covers whatever the wind cannot deliver.
+fn wind_behind_line(load_mw: f64, line_limit: Option<f64>) -> Network {
+    let mut network = Network::new();
+    let bus1 = network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(1),
+        name: "load_bus".to_string(),
+        base_kv: gat_core::Kilovolts(100.0),
+        ..Bus::default()
+    }));
+    let bus2 = network.graph.add_node(Node::Bus(Bus {
+        id: BusId::new(2),
+        name: "wind_bus".to_string(),
+        base_kv: gat_core::Kilovolts(100.0),
+        ..Bus::default()
+    }));
+
+    network.graph.add_edge(
+        bus2,
+        bus1,
+        Edge::Branch(Branch {
+            s_max: line_limit.map(MegavoltAmperes),
+            ..Branch::new(
+                BranchId::new(1),
+                "tie".to_string(),
+                BusId::new(2),
+                BusId::new(1),
+                0.001,
+                0.05,
+            )
+        }),
+    );
+
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(1), "thermal".to_string(), BusId::new(1))
+            .with_p_limits(0.0, 300.0)
+            .with_q_limits(-200.0, 200.0)
+            .with_cost(CostModel::linear(0.0, 50.0)),
+    ));
+    // Zero-Q wind so the line limit binds on active power alone
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(2), "wind".to_string(), BusId::new(2))
+            .with_p_limits(0.0, 200.0)
+            .with_q_limits(0.0, 0.0)
+            .with_cost(CostModel::linear(0.0, 0.0))
+            .with_p_available(WIND_AVAILABLE_MW),
+    ));
+
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "city".to_string(),
+        bus: BusId::new(1),
+        active_power: Megawatts(load_mw),
+        reactive_power: Megavars(0.0),
+        zip: None,
+    }));
+    network
+}
+
+#[test]
+fn test_socp_curtails_wind_behind_binding_line() {
+    let network = wind_behind_line(200.0, Some(LINE_LIMIT_MVA));
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .solve(&network)
+        .expect("SOCP should converge");
+    assert!(solution.converged);
+
+    // Uncurtailed, all 150 MW would cross the 80 MVA line: 70 MW of overflow
+    let overflow = WIND_AVAILABLE_MW - LINE_LIMIT_MVA;
+    let curtailed = solution.renewable_curtailment["wind"];
+    assert!(
+        (curtailed - overflow).abs() < 1.0,
+        "curtailed {:.3} MW, expected ≈ {:.1} MW",
+        curtailed,
+        overflow
+    );
+    assert!((solution.total_curtailment_mw() - curtailed).abs() < 1e-12);
+
+    // Dispatchable units are not reported
+    assert!(!solution.renewable_curtailment.contains_key("thermal"));
+}
+
+#[test]
+fn test_socp_no_curtailment_without_congestion() {
+    let network = wind_behind_line(200.0, None);
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .solve(&network)
+        .expect("SOCP should converge");
+
+    assert!(solution.renewable_curtailment["wind"] < 1.0);
+}
+
+#[test]
+fn test_dc_opf_caps_dispatch_at_availability() {
+    // Load below availability: the surplus is spilled
+    let network = wind_behind_line(100.0, None);
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .solve(&network)
+        .expect("DC-OPF should converge");
+
+    let wind = solution.generator_p["wind"];
+    assert!(wind <= WIND_AVAILABLE_MW + 1e-6);
+    assert!((wind - 100.0).abs() < 1e-3, "wind dispatch {}", wind);
+    assert!((solution.renewable_curtailment["wind"] - 50.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_curtailment_caps_forecast_at_pmax() {
+    // A 250 MW forecast on a 200 MW unit: only 200 MW could ever be produced
+    let mut network = wind_behind_line(100.0, None);
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if gen.name == "wind" {
+                gen.p_available = Some(Megawatts(250.0));
+            }
+        }
+    }
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .solve(&network)
+        .expect("DC-OPF should converge");
+
+    assert!((solution.generator_p["wind"] - 100.0).abs() < 1e-3);
+    let curtailed = solution.renewable_curtailment["wind"];
+    assert!(
+        (curtailed - 100.0).abs() < 1e-3,
+        "curtailed {} MW",
+        curtailed
+    );
+}
@@ -0,0 +1,139 @@
+//! Slack bus selection shared by AC power flow and DC-OPF.
+
+use gat_algo::power_flow::ac_pf::AcPowerFlowSolver;
+use gat_algo::{OpfMethod, OpfSolver};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, BusType, CostModel, Gen, GenId, Load, LoadId, Megavars,
+    Megawatts, Network, Node, PerUnit, SlackStrategy,
+};
+
+/// Triangle with a small unit at bus 1, a large one at bus 2 and load at bus 3.
+fn triangle() -> Network {
+    let mut network = Network::new();
+    for i in 1..=3 {
+        network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(i),
+            name: format!("bus{}", i),
+            ..Bus::default()
+        }));
+    }
+    for (id, (from, to)) in [(1, 2), (1, 3), (2, 3)].into_iter().enumerate() {
+        network
+            .add_branch_checked(
+                Branch::new(
+                    BranchId::new(id),
+                    format!("line{}_{}", from, to),
+                    BusId::new(from),
+                    BusId::new(to),
+                    0.01,
+                    0.1,
+                )
+                .with_s_max(Some(500.0)),
+            )
+            .unwrap();
+    }
+    for (id, pmax, price) in [(1, 50.0, 10.0), (2, 200.0, 20.0)] {
+        let mut gen = Gen::new(GenId::new(id), format!("gen{}", id), BusId::new(id))
+            .with_p_limits(0.0, pmax)
+            .with_q_limits(-100.0, 100.0)
+            .with_cost(CostModel::linear(0.0, price));
+        gen.active_power = Megawatts(40.0);
+        network.graph.add_node(Node::Gen(gen));
+    }
+    network.graph.add_node(Node::Load(Load {
+        id: LoadId::new(1),
+        name: "load3".to_string(),
+        bus: BusId::new(3),
+        active_power: Megawatts(80.0),
+        reactive_power: Megavars(10.0),
+        zip: None,
+    }));
+    network
+}
+
+#[test]
+fn test_largest_gen_slack_selected_and_reported() {
+    let network = triangle();
+    assert_eq!(
+        network.select_slack(SlackStrategy::LargestGen).unwrap(),
+        vec![BusId::new(2)]
+    );
+
+    let pf = AcPowerFlowSolver::new()
+        .solve(&network)
+        .expect("power flow should converge");
+    assert!(pf.converged);
+    assert_eq!(pf.slack_buses, vec![BusId::new(2)]);
+    assert_eq!(pf.bus_types[&BusId::new(2)], BusType::Slack);
+    assert_eq!(pf.bus_types[&BusId::new(1)], BusType::PV);
+    assert!(pf.bus_voltage_angle[&BusId::new(2)].abs() < 1e-12);
+
+    let pf = AcPowerFlowSolver::new()
+        .with_slack_strategy(SlackStrategy::LowestId)
+        .solve(&network)
+        .expect("power flow should converge");
+    assert_eq!(pf.slack_buses, vec![BusId::new(1)]);
+
+    let opf = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .solve(&network)
+        .expect("DC-OPF should solve");
+    assert_eq!(opf.slack_buses, vec!["bus2".to_string()]);
+    assert_eq!(opf.bus_voltage_ang["bus2"], 0.0);
+}
+
+fn set_bus_type(network: &mut Network, id: usize, bus_type: BusType) {
+    for node in network.graph.node_weights_mut() {
+        if let Node::Bus(bus) = node {
+            if bus.id == BusId::new(id) {
+                bus.bus_type = Some(bus_type);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_declared_bus_types_control_voltage() {
+    let mut network = triangle();
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            if gen.id == GenId::new(1) {
+                gen.voltage_setpoint = Some(PerUnit(1.03));
+            }
+        }
+    }
+
+    let pf = AcPowerFlowSolver::new()
+        .solve(&network)
+        .expect("power flow should converge");
+    assert_eq!(pf.bus_types[&BusId::new(1)], BusType::PV);
+    assert_eq!(pf.bus_types[&BusId::new(3)], BusType::PQ);
+    assert!((pf.bus_voltage_magnitude[&BusId::new(1)] - 1.03).abs() < 1e-6);
+    let v3 = pf.bus_voltage_magnitude[&BusId::new(3)];
+    assert!(
+        (v3 - 1.0).abs() > 1e-4,
+        "PQ bus voltage {} did not float",
+        v3
+    );
+
+    // Declared PQ, the unit injects its scheduled Q and the setpoint is ignored
+    set_bus_type(&mut network, 1, BusType::PQ);
+    let pf = AcPowerFlowSolver::new()
+        .solve(&network)
+        .expect("power flow should converge");
+    assert_eq!(pf.bus_types[&BusId::new(1)], BusType::PQ);
+    assert!((pf.bus_voltage_magnitude[&BusId::new(1)] - 1.03).abs() > 1e-4);
+
+    // The case's reference bus wins over the larger unit
+    set_bus_type(&mut network, 1, BusType::Slack);
+    assert_eq!(
+        network.select_slack(SlackStrategy::FromCase).unwrap(),
+        vec![BusId::new(1)]
+    );
+    let pf = AcPowerFlowSolver::new()
+        .with_slack_strategy(SlackStrategy::FromCase)
+        .solve(&network)
+        .expect("power flow should converge");
+    assert_eq!(pf.bus_types[&BusId::new(1)], BusType::Slack);
+    assert_eq!(pf.bus_types[&BusId::new(2)], BusType::PV);
+}
@@ -8,6 +8,7 @@
 //! - Voltage limit binding
 //! - Multi-bus networks
 
+use gat_algo::opf::{QBind, SolverWarning};
 use gat_algo::{OpfMethod, OpfSolver};
 use gat_core::{
     Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Network, Node,
@@ -71,6 +72,7 @@ fn simple_network() -> Network {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(1.0),
         reactive_power: gat_core::Megavars(0.2),
+        zip: None,
     }));
 
     network
@@ -227,6 +229,7 @@ fn three_bus_network() -> Network {
         bus: BusId::new(2),
         active_power: gat_core::Megawatts(100.0),
         reactive_power: gat_core::Megavars(30.0),
+        zip: None,
     }));
 
     network
@@ -332,6 +335,7 @@ fn socp_quadratic_cost() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(10.0),
+        zip: None,
     }));
 
     let solver = OpfSolver::new().with_method(OpfMethod::SocpRelaxation);
@@ -435,6 +439,7 @@ fn socp_thermal_limit_binding() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(40.0), // Under the 50 MVA limit
         reactive_power: gat_core::Megavars(10.0),
+        zip: None,
     }));
 
     let solver = OpfSolver::new().with_method(OpfMethod::SocpRelaxation);
@@ -576,6 +581,7 @@ fn socp_phase_shifting_transformer() {
         bus: BusId::new(2),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(10.0),
+        zip: None,
     }));
 
     let solver = OpfSolver::new().with_method(OpfMethod::SocpRelaxation);
@@ -667,6 +673,7 @@ fn socp_tap_ratio_transformer() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(15.0),
+        zip: None,
     }));
 
     let solver = OpfSolver::new().with_method(OpfMethod::SocpRelaxation);
@@ -776,6 +783,7 @@ fn socp_10_bus_meshed_network() {
             bus: BusId::new(*bus),
             active_power: gat_core::Megawatts(*p),
             reactive_power: gat_core::Megavars(*q),
+            zip: None,
         }));
     }
 
@@ -816,6 +824,105 @@ fn socp_10_bus_meshed_network() {
     );
 }
 
+/// Meshed 5-bus case: a ring 1-2-3-4-5 with a chord 1-3, so two independent loops.
+#[test]
+fn socp_meshed_loops_satisfy_cycle_constraints() {
+    let mut network = Network::new();
+    let bus_indices: Vec<_> = (0..5)
+        .map(|i| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i + 1),
+                base_kv: gat_core::Kilovolts(138.0),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+
+    // (from, to, r, x), 0-based bus indices
+    let branches = [
+        (0, 1, 0.01, 0.08),
+        (1, 2, 0.02, 0.12),
+        (2, 3, 0.01, 0.10),
+        (3, 4, 0.03, 0.15),
+        (4, 0, 0.02, 0.09),
+        (0, 2, 0.015, 0.11),
+    ];
+    for (idx, &(from, to, r, x)) in branches.iter().enumerate() {
+        network.graph.add_edge(
+            bus_indices[from],
+            bus_indices[to],
+            Edge::Branch(Branch {
+                id: BranchId::new(idx),
+                name: format!("line{}_{}", from + 1, to + 1),
+                from_bus: BusId::new(from),
+                to_bus: BusId::new(to),
+                resistance: r,
+                reactance: x,
+                ..Branch::default()
+            }),
+        );
+    }
+    for (idx, (bus, cost)) in [(0, 10.0), (3, 25.0)].iter().enumerate() {
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(idx), format!("gen{}", idx + 1), BusId::new(*bus))
+                .with_p_limits(0.0, 200.0)
+                .with_q_limits(-100.0, 100.0)
+                .with_cost(CostModel::linear(0.0, *cost)),
+        ));
+    }
+    for (idx, (bus, p, q)) in [(1, 60.0, 20.0), (2, 50.0, 15.0), (4, 70.0, 25.0)]
+        .iter()
+        .enumerate()
+    {
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(idx),
+            name: format!("load{}", bus + 1),
+            bus: BusId::new(*bus),
+            active_power: gat_core::Megawatts(*p),
+            reactive_power: gat_core::Megavars(*q),
+            zip: None,
+        }));
+    }
+
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .solve(&network)
+        .expect("meshed 5-bus SOCP should converge");
+    assert!(solution.converged);
+
+    // Angle drop θ_from - θ_to = (x·P - r·Q) in p.u. on a 100 MVA base
+    let angle_drop = |branch: usize| {
+        let (from, to, r, x) = branches[branch];
+        let name = format!("line{}_{}", from + 1, to + 1);
+        (x * solution.branch_p_flow[&name] - r * solution.branch_q_flow[&name]) / 100.0
+    };
+    // Loops as (branch, traversed from→to) pairs
+    let loops = [
+        vec![(0, true), (1, true), (5, false)],
+        vec![(5, true), (2, true), (3, true), (4, true)],
+    ];
+    for cycle in &loops {
+        let residual: f64 = cycle
+            .iter()
+            .map(|&(branch, forward)| {
+                let drop = angle_drop(branch);
+                if forward {
+                    drop
+                } else {
+                    -drop
+                }
+            })
+            .sum();
+        assert!(
+            residual.abs() < 1e-5,
+            "angle drops around loop {:?} sum to {:.2e} rad",
+            cycle,
+            residual
+        );
+    }
+}
+
 #[test]
 fn socp_line_charging() {
     // Test that line charging (shunt susceptance) is handled correctly
@@ -878,6 +985,7 @@ fn socp_line_charging() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(10.0),
         reactive_power: gat_core::Megavars(5.0),
+        zip: None,
     }));
 
     let solver = OpfSolver::new().with_method(OpfMethod::SocpRelaxation);
@@ -961,6 +1069,7 @@ fn socp_zero_resistance_transformer() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(15.0),
+        zip: None,
     }));
 
     let solver = OpfSolver::new().with_method(OpfMethod::SocpRelaxation);
@@ -1038,6 +1147,7 @@ fn socp_transformer_voltage_bounds_strict() {
         bus: BusId::new(1),
         active_power: gat_core::Megawatts(50.0),
         reactive_power: gat_core::Megavars(15.0),
+        zip: None,
     }));
 
     let solver = OpfSolver::new().with_method(OpfMethod::SocpRelaxation);
@@ -1200,6 +1310,7 @@ fn socp_meshed_zero_resistance_transformers() {
         bus: BusId::new(3),
         active_power: gat_core::Megawatts(100.0),
         reactive_power: gat_core::Megavars(30.0),
+        zip: None,
     }));
 
     println!("\n=== Meshed Zero-Resistance Transformer Test ===");
@@ -1233,3 +1344,56 @@ fn socp_meshed_zero_resistance_transformers() {
         Err(e) => println!("DC-OPF Result: FAILED - {}", e),
     }
 }
+
+#[test]
+fn socp_iteration_cap_reports_warning() {
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .enhanced_socp(true)
+        .with_max_iterations(1)
+        .solve(&three_bus_network())
+        .expect("a capped solve still returns its last iterate");
+
+    assert!(!solution.converged);
+    assert!(
+        solution
+            .warnings
+            .iter()
+            .any(|w| matches!(w, SolverWarning::IterationCapHit { iterations: 1 })),
+        "warnings: {:?}",
+        solution.warnings
+    );
+}
+
+#[test]
+fn socp_reports_reactive_limit_binding() {
+    // A small condenser at the load bus serves its reactive demand first,
+    // since importing the rest over the line costs losses
+    let mut network = simple_network();
+    for node in network.graph.node_weights_mut() {
+        if let Node::Load(load) = node {
+            load.active_power = gat_core::Megawatts(50.0);
+            load.reactive_power = gat_core::Megavars(30.0);
+        }
+    }
+    network.graph.add_node(Node::Gen(Gen {
+        id: GenId::new(1),
+        name: "condenser2".to_string(),
+        bus: BusId::new(1),
+        pmin: gat_core::Megawatts(0.0),
+        pmax: gat_core::Megawatts(0.0),
+        qmin: gat_core::Megavars(-5.0),
+        qmax: gat_core::Megavars(5.0),
+        cost_model: CostModel::linear(0.0, 0.0),
+        ..Gen::default()
+    }));
+
+    let solution = OpfSolver::new()
+        .with_method(OpfMethod::SocpRelaxation)
+        .solve(&network)
+        .expect("SOCP should converge");
+
+    assert!((solution.generator_q["condenser2"] - 5.0).abs() < 1e-2);
+    assert_eq!(solution.generator_q_binding["condenser2"], QBind::Upper);
+    assert_eq!(solution.generator_q_binding["gen1"], QBind::Neither);
+}
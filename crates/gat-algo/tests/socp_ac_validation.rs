@@ -0,0 +1,105 @@
+//! SOCP relaxation quality check against AC-OPF.
+
+use gat_algo::opf::{validate_socp_against_ac, TIGHT_GAP_TOLERANCE};
+use gat_core::{
+    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Megavars, Megawatts,
+    Network, Node,
+};
+
+/// Buses 1..=3 with a generator at bus 1 and loads at buses 2 and 3.
+/// `lines` lists (from, to, r, x) in bus numbers.
+fn network(lines: &[(usize, usize, f64, f64)], second_gen: bool) -> Network {
+    let mut network = Network::new();
+    let buses: Vec<_> = (1..=3)
+        .map(|i| {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("bus{}", i),
+                base_kv: gat_core::Kilovolts(138.0),
+                ..Bus::default()
+            }))
+        })
+        .collect();
+    for (k, &(from, to, r, x)) in lines.iter().enumerate() {
+        network.graph.add_edge(
+            buses[from - 1],
+            buses[to - 1],
+            Edge::Branch(Branch::new(
+                BranchId::new(k + 1),
+                format!("line{}_{}", from, to),
+                BusId::new(from),
+                BusId::new(to),
+                r,
+                x,
+            )),
+        );
+    }
+
+    network.graph.add_node(Node::Gen(
+        Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1))
+            .with_p_limits(0.0, 200.0)
+            .with_q_limits(-100.0, 100.0)
+            .with_cost(CostModel::linear(0.0, 10.0)),
+    ));
+    if second_gen {
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(2), "gen2".to_string(), BusId::new(2))
+                .with_p_limits(0.0, 100.0)
+                .with_q_limits(-50.0, 50.0)
+                .with_cost(CostModel::linear(0.0, 20.0)),
+        ));
+    }
+    for (bus, p, q) in [(2, 30.0, 10.0), (3, 25.0, 8.0)] {
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(bus),
+            name: format!("load{}", bus),
+            bus: BusId::new(bus),
+            active_power: Megawatts(p),
+            reactive_power: Megavars(q),
+            zip: None,
+        }));
+    }
+    network
+}
+
+#[test]
+fn test_radial_feeder_relaxation_is_tight() {
+    let radial = network(&[(1, 2, 0.01, 0.05), (2, 3, 0.01, 0.05)], false);
+    let report = validate_socp_against_ac(&radial).expect("both solvers should run");
+
+    assert!(report.ac_converged);
+    assert!(
+        report.relaxation_tight,
+        "gap {:.4} exceeds {}",
+        report.objective_gap_rel, TIGHT_GAP_TOLERANCE
+    );
+    assert_eq!(report.bus_voltage_diff.len(), 3);
+    assert_eq!(report.generator_p_diff.len(), 1);
+    assert!(
+        report.agrees_within(0.05, 5.0),
+        "max |ΔV| {:.4} p.u., max |ΔP| {:.3} MW",
+        report.max_voltage_diff(),
+        report.max_dispatch_diff_mw()
+    );
+}
+
+/// On a meshed network the relaxation is not guaranteed exact: the report
+/// still compares both solutions, but `relaxation_tight` may be false and is
+/// not asserted here. SOCP remains a lower bound up to AC solver accuracy.
+#[test]
+fn test_meshed_network_reports_gap() {
+    let meshed = network(
+        &[(1, 2, 0.01, 0.1), (2, 3, 0.01, 0.1), (1, 3, 0.02, 0.15)],
+        true,
+    );
+    let report = validate_socp_against_ac(&meshed).expect("both solvers should run");
+
+    assert!(report.objective_gap_rel.is_finite());
+    assert!(report.objective_gap_rel > -TIGHT_GAP_TOLERANCE);
+    assert_eq!(report.generator_p_diff.len(), 2);
+    assert_eq!(report.generator_q_diff.len(), 2);
+    assert_eq!(
+        report.relaxation_tight,
+        report.ac_converged && report.objective_gap_rel <= TIGHT_GAP_TOLERANCE
+    );
+}
@@ -132,7 +132,8 @@ fn test_list_available_minimum_solvers() {
 // =============================================================================
 
 mod opf_solver_require_native {
-    use gat_algo::opf::{OpfMethod, OpfSolver};
+    use gat_algo::opf::{OpfMethod, OpfSolver, SolverWarning};
+    use gat_algo::OpfError;
     use gat_core::{
         Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Network, Node,
     };
@@ -194,6 +195,7 @@ mod opf_solver_require_native {
             bus: BusId::new(1),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(10.0),
+            zip: None,
         }));
 
         network
@@ -266,6 +268,80 @@ mod opf_solver_require_native {
         );
     }
 
+    /// Without IPOPT compiled in, the error is `SolverUnavailable` and is
+    /// raised before the network is touched.
+    #[cfg(all(not(feature = "native-dispatch"), not(feature = "solver-ipopt")))]
+    #[test]
+    fn test_require_native_without_feature_is_solver_unavailable() {
+        let solver = OpfSolver::new()
+            .with_method(OpfMethod::AcOpf)
+            .require_native(true);
+
+        // An empty network would fail validation if it were ever reached
+        let err = solver.solve(&Network::new()).unwrap_err();
+        match &err {
+            OpfError::SolverUnavailable { method, reason } => {
+                assert_eq!(*method, OpfMethod::AcOpf);
+                assert!(reason.contains("solver-ipopt"), "{}", reason);
+            }
+            other => panic!("expected SolverUnavailable, got {:?}", other),
+        }
+        assert!(matches!(
+            solver.check_solver_available(),
+            Err(OpfError::SolverUnavailable { .. })
+        ));
+    }
+
+    /// With fallbacks allowed, the missing IPOPT backend is replaced by SOCP
+    /// and the substitution is reported as a warning.
+    #[cfg(all(not(feature = "native-dispatch"), not(feature = "solver-ipopt")))]
+    #[test]
+    fn test_solver_fallback_without_ipopt_uses_socp() {
+        let network = create_test_network();
+        let solution = OpfSolver::new()
+            .with_method(OpfMethod::AcOpf)
+            .require_native(true)
+            .with_solver_fallback(true)
+            .solve(&network)
+            .expect("fallback should solve");
+
+        assert_eq!(solution.method_used, OpfMethod::SocpRelaxation);
+        assert!(solution.warnings.iter().any(|warning| matches!(
+            warning,
+            SolverWarning::SolverFallback {
+                requested: OpfMethod::AcOpf,
+                used: OpfMethod::SocpRelaxation,
+                ..
+            }
+        )));
+    }
+
+    /// An iteration-capped SOCP iterate is not accepted as the fallback;
+    /// DC-OPF (which ignores the cap) is used instead.
+    #[cfg(all(not(feature = "native-dispatch"), not(feature = "solver-ipopt")))]
+    #[test]
+    fn test_solver_fallback_skips_capped_socp() {
+        let network = create_test_network();
+        let solution = OpfSolver::new()
+            .with_method(OpfMethod::AcOpf)
+            .require_native(true)
+            .with_solver_fallback(true)
+            .enhanced_socp(true)
+            .with_max_iterations(1)
+            .solve(&network)
+            .expect("fallback should solve");
+
+        assert!(solution.converged);
+        assert_eq!(solution.method_used, OpfMethod::DcOpf);
+        assert!(solution.warnings.iter().any(|warning| matches!(
+            warning,
+            SolverWarning::SolverFallback {
+                used: OpfMethod::DcOpf,
+                ..
+            }
+        )));
+    }
+
     /// Test that default behavior (no require_native) succeeds with pure-Rust solver.
     #[cfg(not(feature = "native-dispatch"))]
     #[test]
@@ -413,6 +489,7 @@ mod native_dispatch_tests {
             bus: BusId::new(1),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(10.0),
+            zip: None,
         }));
 
         let solver = OpfSolver::new()
@@ -0,0 +1,56 @@
+//! Voltage estimate from DC-OPF angles, checked against Newton-Raphson on IEEE 30.
+
+use gat_algo::power_flow::ac_pf::AcPowerFlowSolver;
+use gat_algo::power_flow::{estimate_voltages_from_dc, VOLTAGE_ESTIMATE_TOLERANCE_PU};
+use gat_algo::{OpfMethod, OpfSolver};
+use gat_core::{BusId, Megawatts, Network, Node};
+use gat_io::importers::load_matpower_network;
+use std::path::Path;
+
+fn load_case30() -> Network {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_data/matpower/edge_cases/case_ieee30.m");
+    load_matpower_network(&path).expect("IEEE 30 should import")
+}
+
+#[test]
+fn test_voltage_estimate_case30_tracks_ac_power_flow() {
+    let mut network = load_case30();
+    let dc = OpfSolver::new()
+        .with_method(OpfMethod::DcOpf)
+        .solve(&network)
+        .expect("DC-OPF should converge");
+
+    let estimate = estimate_voltages_from_dc(&network, &dc).unwrap();
+    assert_eq!(estimate.bus_voltage_magnitude.len(), 30);
+
+    // Full AC power flow of the same dispatch
+    for node in network.graph.node_weights_mut() {
+        if let Node::Gen(gen) = node {
+            gen.active_power = Megawatts(dc.generator_p[&gen.name]);
+        }
+    }
+    let ac = AcPowerFlowSolver::new()
+        .solve(&network)
+        .expect("power flow should converge");
+    assert!(ac.converged);
+
+    let close = estimate
+        .bus_voltage_magnitude
+        .iter()
+        .filter(|(bus, vm)| {
+            (*vm - ac.bus_voltage_magnitude[bus]).abs() <= VOLTAGE_ESTIMATE_TOLERANCE_PU
+        })
+        .count();
+    assert!(
+        close >= 24,
+        "only {}/30 buses within {} p.u. of the AC voltages",
+        close,
+        VOLTAGE_ESTIMATE_TOLERANCE_PU
+    );
+
+    // Units at buses 11 and 13 hold their setpoints above Vmax = 1.06
+    for bus in [11, 13] {
+        assert!(estimate.likely_violations.contains(&BusId::new(bus)));
+    }
+}
@@ -100,6 +100,7 @@ mod tests {
             weight: 1.0,
             tags: vec!["tag".into()],
             metadata: Default::default(),
+            checksum: None,
         }
     }
 
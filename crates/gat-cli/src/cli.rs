@@ -65,8 +65,13 @@ pub enum Commands {
     /// Validate a dataset against a schema
     Validate {
         /// Path to the dataset specification file
-        #[arg(long)]
-        spec: String,
+        #[arg(long, required_unless_present = "schema", conflicts_with = "schema")]
+        spec: Option<String>,
+        /// Built-in schema to check against: grid|branch_limits|bus_voltage
+        #[arg(long, requires = "path")]
+        schema: Option<String>,
+        /// Dataset to check with --schema (Arrow grid directory, CSV, or Parquet)
+        path: Option<String>,
     },
     /// Graph utilities
     Graph {
@@ -869,6 +874,9 @@ pub enum PowerFlowCommands {
         /// Enforce generator Q limits (PV-PQ bus switching)
         #[arg(long)]
         q_limits: bool,
+        /// Newton step rule (newton, damped, auto = newton with damped fallback)
+        #[arg(long, default_value = "newton")]
+        method: String,
         /// Override slack bus selection (default: auto-select from network data)
         #[arg(long)]
         slack_bus: Option<usize>,
@@ -1002,6 +1010,9 @@ pub enum TsCommands {
         /// Partition columns (comma separated)
         #[arg(long)]
         out_partitions: Option<String>,
+        /// Read Parquet input in batches of this many rows to bound memory
+        #[arg(long)]
+        chunk_rows: Option<usize>,
     },
     /// Join two telemetry datasets
     Join {
@@ -1012,6 +1023,12 @@ pub enum TsCommands {
         /// Key column to join on
         #[arg(long, default_value = "timestamp")]
         on: String,
+        /// Suffix for left-hand columns that share a name with a right-hand column
+        #[arg(long, default_value = "_left")]
+        left_suffix: String,
+        /// Suffix for right-hand columns that share a name with a left-hand column
+        #[arg(long, default_value = "_right")]
+        right_suffix: String,
         /// Output file path (CSV or Parquet)
         #[arg(short, long)]
         out: String,
@@ -1029,15 +1046,39 @@ pub enum TsCommands {
         /// Value column to aggregate
         #[arg(long, default_value = "value")]
         value: String,
-        /// Aggregation to perform: sum|mean|min|max|count
+        /// Aggregation to perform: sum|mean|min|max|count|wmean
         #[arg(long, default_value = "sum")]
         agg: String,
+        /// Weight column for `wmean`
+        #[arg(long)]
+        weight: Option<String>,
         /// Output file path (CSV or Parquet)
         #[arg(short, long)]
         out: String,
         /// Partition columns (comma separated)
         #[arg(long)]
         out_partitions: Option<String>,
+        /// Read Parquet input in batches of this many rows to bound memory
+        #[arg(long)]
+        chunk_rows: Option<usize>,
+    },
+    /// Run OPF for every timestamp of a load forecast
+    Solve {
+        /// Grid file (Arrow format)
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        grid: String,
+        /// Tidy forecast Parquet (timestamp, bus_id, load_mw[, gen_id, p_available_mw])
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        timeseries: String,
+        /// Output Parquet path (one row per timestamp and generator)
+        #[arg(short, long, value_hint = ValueHint::FilePath)]
+        out: String,
+        /// OPF solution method
+        #[arg(short = 'm', long, value_enum, default_value_t = OpfMethod::Dc)]
+        method: OpfMethod,
+        /// Threads for solving timestamps concurrently ("auto" for every core)
+        #[arg(short = 't', long, default_value = "1")]
+        threads: String,
     },
 }
 
@@ -1081,8 +1122,8 @@ pub enum DistCommands {
         /// Output Parquet path
         #[arg(long)]
         out: String,
-        /// Objective descriptor
-        #[arg(long, default_value = "loss")]
+        /// Objective to minimize (min_cost, min_loss, min_voltage_deviation)
+        #[arg(long, default_value = "min_loss")]
         objective: String,
         /// Solver to use
         #[arg(long, default_value = "gauss")]
@@ -1091,7 +1132,7 @@ pub enum DistCommands {
         #[arg(long, default_value = "1e-6")]
         tol: f64,
         /// Maximum iterations
-        #[arg(long, default_value = "20")]
+        #[arg(long, default_value = "200")]
         max_iter: u32,
     },
     /// Sweep hosting capacity over selected buses
@@ -1133,6 +1174,9 @@ pub enum DermsCommands {
         /// Grouping key (agg_id or bus)
         #[arg(long)]
         group_by: Option<String>,
+        /// Clip P limits by grid thermal headroom (PTDF-based)
+        #[arg(long)]
+        network_limits: bool,
     },
     /// Produce a scheduling recommendation
     Schedule {
@@ -1194,6 +1238,15 @@ pub enum AdmsCommands {
         /// Maximum iterations
         #[arg(long, default_value = "20")]
         max_iter: u32,
+        /// Repair-time distribution (exponential, lognormal)
+        #[arg(long, default_value = "exponential")]
+        repair_dist: String,
+        /// Standard deviation of ln(repair time) for the lognormal distribution
+        #[arg(long, default_value = "1.0")]
+        repair_sigma: f64,
+        /// Optional RNG seed
+        #[arg(long)]
+        seed: Option<u64>,
     },
     /// Volt/VAR planning runs
     VvoPlan {
@@ -1230,6 +1283,12 @@ pub enum AdmsCommands {
         /// Optional RNG seed
         #[arg(long)]
         seed: Option<u64>,
+        /// Repair-time distribution (exponential, lognormal)
+        #[arg(long, default_value = "exponential")]
+        repair_dist: String,
+        /// Standard deviation of ln(repair time) for the lognormal distribution
+        #[arg(long, default_value = "1.0")]
+        repair_sigma: f64,
     },
     /// State estimation checks
     StateEstimation {
@@ -3,7 +3,7 @@ use std::time::Instant;
 
 use crate::commands::telemetry::record_run_timed;
 use anyhow::Result;
-use gat_adms::{flisr_sim, outage_mc, state_estimation, vvo_plan};
+use gat_adms::{flisr_sim, outage_mc, state_estimation, vvo_plan, RepairTimeModel};
 use gat_cli::cli::AdmsCommands;
 
 pub fn handle(command: &AdmsCommands) -> Result<()> {
@@ -16,6 +16,9 @@ pub fn handle(command: &AdmsCommands) -> Result<()> {
             solver,
             tol,
             max_iter,
+            repair_dist,
+            repair_sigma,
+            seed,
         } => {
             let start = Instant::now();
             let res = flisr_sim(
@@ -26,7 +29,10 @@ pub fn handle(command: &AdmsCommands) -> Result<()> {
                 solver.parse()?,
                 *tol,
                 *max_iter,
+                RepairTimeModel::from_name(repair_dist, *repair_sigma)?,
+                *seed,
             );
+            let seed_str = seed.map(|v| v.to_string());
             record_run_timed(
                 out_dir,
                 "adms flisr-sim",
@@ -38,6 +44,9 @@ pub fn handle(command: &AdmsCommands) -> Result<()> {
                     ("solver", solver.as_str()),
                     ("tol", &tol.to_string()),
                     ("max_iter", &max_iter.to_string()),
+                    ("repair_dist", repair_dist),
+                    ("repair_sigma", &repair_sigma.to_string()),
+                    ("seed", seed_str.as_deref().unwrap_or("none")),
                 ],
                 start,
                 &res,
@@ -87,9 +96,17 @@ pub fn handle(command: &AdmsCommands) -> Result<()> {
             out_dir,
             samples,
             seed,
+            repair_dist,
+            repair_sigma,
         } => {
             let start = Instant::now();
-            let res = outage_mc(Path::new(reliability), Path::new(out_dir), *samples, *seed);
+            let res = outage_mc(
+                Path::new(reliability),
+                Path::new(out_dir),
+                *samples,
+                *seed,
+                RepairTimeModel::from_name(repair_dist, *repair_sigma)?,
+            );
             let seed_str = seed.map(|v| v.to_string());
             record_run_timed(
                 out_dir,
@@ -99,6 +116,8 @@ pub fn handle(command: &AdmsCommands) -> Result<()> {
                     ("out_dir", out_dir),
                     ("samples", &samples.to_string()),
                     ("seed", seed_str.as_deref().unwrap_or("none")),
+                    ("repair_dist", repair_dist),
+                    ("repair_sigma", &repair_sigma.to_string()),
                 ],
                 start,
                 &res,
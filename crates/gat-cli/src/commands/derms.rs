@@ -15,6 +15,7 @@ pub fn handle(command: &DermsCommands) -> Result<()> {
             assets,
             out,
             group_by,
+            network_limits,
         } => {
             info!("Building DERMS envelope {} -> {}", assets, out);
             let start = Instant::now();
@@ -23,6 +24,7 @@ pub fn handle(command: &DermsCommands) -> Result<()> {
                 Path::new(assets),
                 Path::new(out),
                 group_by.as_deref(),
+                *network_limits,
             );
             record_run_timed(
                 out,
@@ -32,6 +34,7 @@ pub fn handle(command: &DermsCommands) -> Result<()> {
                     ("assets", assets),
                     ("out", out),
                     ("group_by", group_by.as_deref().unwrap_or("agg_id")),
+                    ("network_limits", &network_limits.to_string()),
                 ],
                 start,
                 &res,
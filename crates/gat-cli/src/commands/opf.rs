@@ -32,7 +32,7 @@ use gat_cli::common::OpfMethod;
 // ============================================================================
 
 /// Convert CLI OpfMethod enum to algo crate's OpfMethod enum
-fn cli_method_to_algo(method: OpfMethod) -> AlgoOpfMethod {
+pub(crate) fn cli_method_to_algo(method: OpfMethod) -> AlgoOpfMethod {
     match method {
         OpfMethod::Economic => AlgoOpfMethod::EconomicDispatch,
         OpfMethod::Dc => AlgoOpfMethod::DcOpf,
@@ -241,9 +241,12 @@ pub fn handle(command: &OpfCommands) -> Result<()> {
 
                 // Print generator dispatch summary
                 println!("\nGenerator Dispatch:");
-                for (gen, mw) in &solution.generator_p {
-                    let mvar = solution.generator_q.get(gen).unwrap_or(&0.0);
-                    println!("  {}: {:.1} MW, {:.1} MVAr", gen, mw, mvar);
+                for gen in network.generators_sorted() {
+                    let Some(mw) = solution.generator_p.get(&gen.name) else {
+                        continue;
+                    };
+                    let mvar = solution.generator_q.get(&gen.name).unwrap_or(&0.0);
+                    println!("  {}: {:.1} MW, {:.1} MVAr", gen.name, mw, mvar);
                 }
 
                 // Print voltage summary
@@ -433,92 +436,87 @@ fn count_network_elements(network: &Network) -> NetworkSummary {
 }
 
 /// Collect detailed violations from network and solution
+///
+/// Elements are visited in ID order so reports match across import paths.
 fn collect_violations(network: &Network, solution: &OpfSolution) -> Vec<ViolationEntry> {
     let mut violations = Vec::new();
 
     // Check generator P violations
-    for node in network.graph.node_weights() {
-        if let Node::Gen(gen) = node {
-            if !gen.status {
-                continue;
+    for gen in network.generators_sorted() {
+        if !gen.status {
+            continue;
+        }
+        if let Some(&pg) = solution.generator_p.get(&gen.name) {
+            if gen.pmax.value().is_finite() && pg > gen.pmax.value() + 0.01 {
+                violations.push(ViolationEntry {
+                    violation_type: "generator_pmax".to_string(),
+                    element: gen.name.clone(),
+                    value: pg,
+                    limit: gen.pmax.value(),
+                });
             }
-            if let Some(&pg) = solution.generator_p.get(&gen.name) {
-                if gen.pmax.value().is_finite() && pg > gen.pmax.value() + 0.01 {
-                    violations.push(ViolationEntry {
-                        violation_type: "generator_pmax".to_string(),
-                        element: gen.name.clone(),
-                        value: pg,
-                        limit: gen.pmax.value(),
-                    });
-                }
-                if pg < gen.pmin.value() - 0.01 {
-                    violations.push(ViolationEntry {
-                        violation_type: "generator_pmin".to_string(),
-                        element: gen.name.clone(),
-                        value: pg,
-                        limit: gen.pmin.value(),
-                    });
-                }
+            if pg < gen.pmin.value() - 0.01 {
+                violations.push(ViolationEntry {
+                    violation_type: "generator_pmin".to_string(),
+                    element: gen.name.clone(),
+                    value: pg,
+                    limit: gen.pmin.value(),
+                });
             }
         }
     }
 
     // Check voltage violations
-    for node in network.graph.node_weights() {
-        if let Node::Bus(bus) = node {
-            if let Some(&vm) = solution.bus_voltage_mag.get(&bus.name) {
-                if let Some(vmax) = bus.vmax_pu {
-                    let vmax = vmax.value();
-                    if vm > vmax + 0.001 {
-                        violations.push(ViolationEntry {
-                            violation_type: "voltage_max".to_string(),
-                            element: bus.name.clone(),
-                            value: vm,
-                            limit: vmax,
-                        });
-                    }
+    for bus in network.buses_sorted() {
+        if let Some(&vm) = solution.bus_voltage_mag.get(&bus.name) {
+            if let Some(vmax) = bus.vmax_pu {
+                let vmax = vmax.value();
+                if vm > vmax + 0.001 {
+                    violations.push(ViolationEntry {
+                        violation_type: "voltage_max".to_string(),
+                        element: bus.name.clone(),
+                        value: vm,
+                        limit: vmax,
+                    });
                 }
-                if let Some(vmin) = bus.vmin_pu {
-                    let vmin = vmin.value();
-                    if vm < vmin - 0.001 {
-                        violations.push(ViolationEntry {
-                            violation_type: "voltage_min".to_string(),
-                            element: bus.name.clone(),
-                            value: vm,
-                            limit: vmin,
-                        });
-                    }
+            }
+            if let Some(vmin) = bus.vmin_pu {
+                let vmin = vmin.value();
+                if vm < vmin - 0.001 {
+                    violations.push(ViolationEntry {
+                        violation_type: "voltage_min".to_string(),
+                        element: bus.name.clone(),
+                        value: vm,
+                        limit: vmin,
+                    });
                 }
             }
         }
     }
 
     // Check branch flow violations
-    for edge in network.graph.edge_weights() {
-        use gat_core::Edge;
-        if let Edge::Branch(branch) = edge {
-            let p_flow = solution
-                .branch_p_flow
-                .get(&branch.name)
-                .copied()
-                .unwrap_or(0.0);
-            let q_flow = solution
-                .branch_q_flow
-                .get(&branch.name)
-                .copied()
-                .unwrap_or(0.0);
-            let s_flow = (p_flow.powi(2) + q_flow.powi(2)).sqrt();
-
-            let s_limit = branch.s_max.or(branch.rating_a);
-            if let Some(s_max) = s_limit {
-                if s_max.value() > 0.0 && s_flow > s_max.value() + 0.1 {
-                    violations.push(ViolationEntry {
-                        violation_type: "branch_flow".to_string(),
-                        element: branch.name.clone(),
-                        value: s_flow,
-                        limit: s_max.value(),
-                    });
-                }
+    for branch in network.branches_sorted() {
+        let p_flow = solution
+            .branch_p_flow
+            .get(&branch.name)
+            .copied()
+            .unwrap_or(0.0);
+        let q_flow = solution
+            .branch_q_flow
+            .get(&branch.name)
+            .copied()
+            .unwrap_or(0.0);
+        let s_flow = (p_flow.powi(2) + q_flow.powi(2)).sqrt();
+
+        let s_limit = branch.s_max.or(branch.rating_a);
+        if let Some(s_max) = s_limit {
+            if s_max.value() > 0.0 && s_flow > s_max.value() + 0.1 {
+                violations.push(ViolationEntry {
+                    violation_type: "branch_flow".to_string(),
+                    element: branch.name.clone(),
+                    value: s_flow,
+                    limit: s_max.value(),
+                });
             }
         }
     }
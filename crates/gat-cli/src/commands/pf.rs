@@ -6,7 +6,9 @@ use std::time::Instant;
 use crate::commands::telemetry::record_run_timed;
 use crate::commands::util::{configure_threads, parse_partitions};
 use anyhow::Result;
-use gat_algo::power_flow::{self, AcPowerFlowSolver, CpfSolver, FastDecoupledSolver};
+use gat_algo::power_flow::{
+    self, AcPowerFlowSolver, CpfSolver, FastDecoupledSolver, NewtonVariant,
+};
 use gat_cli::cli::PowerFlowCommands;
 use gat_cli::common::{write_json, write_jsonl, FileOutputFormat, OutputDest, OutputFormat};
 use gat_core::solver::SolverKind;
@@ -87,6 +89,7 @@ pub fn handle(command: &PowerFlowCommands) -> Result<()> {
             lp_solver: _, // unused in AC power flow
             out_partitions,
             q_limits,
+            method,
             slack_bus: _,       // TODO: wire into solver
             show_iterations: _, // TODO: wire into solver
         } => {
@@ -97,9 +100,29 @@ pub fn handle(command: &PowerFlowCommands) -> Result<()> {
             let partitions = parse_partitions(out_partitions.as_ref());
             let out_path = Path::new(out);
 
+            let variant = method.parse::<NewtonVariant>()?;
+
             let network = importers::load_grid_from_arrow(grid_file.as_str())?;
 
-            let res = if *q_limits {
+            let res = if variant != NewtonVariant::Standard {
+                // Damped Newton-Raphson, alone or as a fallback
+                let pf_solver = power_flow::ac_pf::AcPowerFlowSolver::new()
+                    .with_tolerance(*tol)
+                    .with_max_iterations(*max_iter as usize)
+                    .with_q_limit_enforcement(*q_limits)
+                    .with_newton_variant(variant);
+
+                let solution = pf_solver.solve(&network)?;
+                power_flow::write_fdpf_solution(&network, &solution, out_path, &partitions)?;
+
+                tracing::info!(
+                    "AC power flow ({}) converged in {} iterations (max mismatch: {:.2e})",
+                    variant.as_str(),
+                    solution.iterations,
+                    solution.max_mismatch
+                );
+                Ok(())
+            } else if *q_limits {
                 // Use new Newton-Raphson solver with Q-limit enforcement
                 let pf_solver = AcPowerFlowSolver::new()
                     .with_tolerance(*tol)
@@ -144,6 +167,7 @@ pub fn handle(command: &PowerFlowCommands) -> Result<()> {
                     ("solver", solver_kind.as_str()),
                     ("out_partitions", out_partitions.as_deref().unwrap_or("")),
                     ("q_limits", q_limits_str),
+                    ("method", variant.as_str()),
                 ],
                 start,
                 &res,
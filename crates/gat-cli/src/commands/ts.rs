@@ -1,10 +1,14 @@
+use std::path::Path;
 use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use gat_algo::ts_opf::{self, TsOpfOptions};
 use gat_cli::cli::TsCommands;
+use gat_io::importers;
 use gat_ts::{aggregate_timeseries, join_timeseries, resample_timeseries};
 use tracing::info;
 
+use crate::commands::opf::cli_method_to_algo;
 use crate::commands::telemetry::record_run_timed;
 use crate::commands::util::parse_partitions;
 
@@ -17,12 +21,15 @@ pub fn handle(command: &TsCommands) -> Result<()> {
             rule,
             out,
             out_partitions,
+            chunk_rows,
         } => {
             info!("Resampling {} → {}", input, out);
             let start = Instant::now();
             let partitions = parse_partitions(out_partitions.as_ref());
             let partition_spec = out_partitions.as_deref().unwrap_or("").to_string();
-            let res = resample_timeseries(input, timestamp, value, rule, out, &partitions);
+            let chunk_spec = chunk_rows.map(|rows| rows.to_string()).unwrap_or_default();
+            let res =
+                resample_timeseries(input, timestamp, value, rule, out, &partitions, *chunk_rows);
             record_run_timed(
                 out,
                 "ts resample",
@@ -33,6 +40,7 @@ pub fn handle(command: &TsCommands) -> Result<()> {
                     ("rule", rule),
                     ("out", out),
                     ("out_partitions", partition_spec.as_str()),
+                    ("chunk_rows", &chunk_spec),
                 ],
                 start,
                 &res,
@@ -43,13 +51,15 @@ pub fn handle(command: &TsCommands) -> Result<()> {
             left,
             right,
             on,
+            left_suffix,
+            right_suffix,
             out,
             out_partitions,
         } => {
             let start = Instant::now();
             let partitions = parse_partitions(out_partitions.as_ref());
             let partition_spec = out_partitions.as_deref().unwrap_or("").to_string();
-            let res = join_timeseries(left, right, on, out, &partitions);
+            let res = join_timeseries(left, right, on, left_suffix, right_suffix, out, &partitions);
             record_run_timed(
                 out,
                 "ts join",
@@ -57,6 +67,8 @@ pub fn handle(command: &TsCommands) -> Result<()> {
                     ("left", left),
                     ("right", right),
                     ("on", on),
+                    ("left_suffix", left_suffix),
+                    ("right_suffix", right_suffix),
                     ("out", out),
                     ("out_partitions", partition_spec.as_str()),
                 ],
@@ -70,14 +82,26 @@ pub fn handle(command: &TsCommands) -> Result<()> {
             group,
             value,
             agg,
+            weight,
             out,
             out_partitions,
+            chunk_rows,
         } => {
             info!("Aggregating {} → {}", input, out);
             let start = Instant::now();
             let partitions = parse_partitions(out_partitions.as_ref());
             let partition_spec = out_partitions.as_deref().unwrap_or("").to_string();
-            let res = aggregate_timeseries(input, group, value, agg, out, &partitions);
+            let chunk_spec = chunk_rows.map(|rows| rows.to_string()).unwrap_or_default();
+            let res = aggregate_timeseries(
+                input,
+                group,
+                value,
+                agg,
+                weight.as_deref(),
+                out,
+                &partitions,
+                *chunk_rows,
+            );
             record_run_timed(
                 out,
                 "ts agg",
@@ -86,8 +110,55 @@ pub fn handle(command: &TsCommands) -> Result<()> {
                     ("group", group),
                     ("value", value),
                     ("agg", agg),
+                    ("weight", weight.as_deref().unwrap_or("")),
                     ("out", out),
                     ("out_partitions", partition_spec.as_str()),
+                    ("chunk_rows", &chunk_spec),
+                ],
+                start,
+                &res,
+            );
+            res
+        }
+        TsCommands::Solve {
+            grid,
+            timeseries,
+            out,
+            method,
+            threads,
+        } => {
+            info!("Solving OPF over {} → {}", timeseries, out);
+            let start = Instant::now();
+            let res = (|| -> Result<()> {
+                let threads = match threads.as_str() {
+                    "auto" => 0,
+                    n => n
+                        .parse()
+                        .map_err(|_| anyhow!("--threads must be a number or 'auto'"))?,
+                };
+                let network = importers::load_grid_from_arrow(grid.as_str())?;
+                let summary = ts_opf::solve_with_options(
+                    &network,
+                    Path::new(timeseries),
+                    Path::new(out),
+                    &TsOpfOptions::new(cli_method_to_algo(*method)).with_threads(threads),
+                )?;
+                println!(
+                    "Time-series OPF: {} step(s), {} converged, {} row(s) → {}",
+                    summary.steps, summary.converged_steps, summary.rows, out
+                );
+                Ok(())
+            })();
+            let method_str = format!("{:?}", method).to_lowercase();
+            record_run_timed(
+                out,
+                "ts solve",
+                &[
+                    ("grid", grid),
+                    ("timeseries", timeseries),
+                    ("method", method_str.as_str()),
+                    ("threads", threads),
+                    ("out", out),
                 ],
                 start,
                 &res,
@@ -1,13 +1,49 @@
+use std::path::Path;
 use std::time::Instant;
 
-use anyhow::Result;
-use gat_io::validate;
+use anyhow::{anyhow, bail, Result};
+use gat_io::{validate, validate_arrow_schema, SchemaKind};
 
 use crate::commands::telemetry::record_run_timed;
 
-pub fn handle(spec: &str) -> Result<()> {
-    let start = Instant::now();
-    let res = validate::validate_dataset(spec);
-    record_run_timed(spec, "validate dataset", &[("spec", spec)], start, &res);
-    res
+pub fn handle(spec: Option<&str>, schema: Option<&str>, path: Option<&str>) -> Result<()> {
+    match (spec, schema, path) {
+        (Some(spec), _, _) => {
+            let start = Instant::now();
+            let res = validate::validate_dataset(spec);
+            record_run_timed(spec, "validate dataset", &[("spec", spec)], start, &res);
+            res
+        }
+        (None, Some(schema), Some(path)) => {
+            let start = Instant::now();
+            let res = validate_schema(schema, path);
+            record_run_timed(
+                path,
+                "validate schema",
+                &[("schema", schema), ("path", path)],
+                start,
+                &res,
+            );
+            res
+        }
+        _ => Err(anyhow!("pass --spec <SPEC> or --schema <SCHEMA> <PATH>")),
+    }
+}
+
+fn validate_schema(schema: &str, path: &str) -> Result<()> {
+    let kind: SchemaKind = schema.parse()?;
+    let diag = validate_arrow_schema(Path::new(path), kind);
+    for issue in &diag.issues {
+        println!("{}", issue);
+    }
+    if diag.has_errors() {
+        bail!(
+            "'{}' does not conform to the {} schema: {}",
+            path,
+            kind,
+            diag.summary()
+        );
+    }
+    println!("'{}' conforms to the {} schema", path, kind);
+    Ok(())
 }
@@ -60,7 +60,9 @@ fn main() {
         }) => run_and_log("import", || {
             import::handle(command, *verbose, *strict, *validate)
         }),
-        Some(Commands::Validate { spec }) => run_and_log("validate", || validate::handle(spec)),
+        Some(Commands::Validate { spec, schema, path }) => run_and_log("validate", || {
+            validate::handle(spec.as_deref(), schema.as_deref(), path.as_deref())
+        }),
         Some(Commands::Graph { command }) => run_and_log("graph", || graph::handle(command)),
         Some(Commands::Scenarios { command }) => {
             run_and_log("scenarios", || command_scenarios::handle(command))
@@ -0,0 +1,454 @@
+//! Structured comparison of two networks.
+//!
+//! [`diff`] matches buses, generators, loads, and branches between an original
+//! and a modified [`Network`] by ID and reports which elements were added,
+//! removed, or changed. Changed elements carry a per-field list of old and new
+//! values, which is what scenario provenance records need ("gen 7 pmax
+//! 100 → 150") without re-serializing whole cases.
+
+use crate::{Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Network, Node};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A single field that differs between two versions of an element.
+///
+/// Values are rendered with `Debug` so every field type (units, options,
+/// cost models) compares and prints uniformly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// An element present in both networks whose fields differ.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElementChange<Id> {
+    pub id: Id,
+    /// Name in the new network
+    pub name: String,
+    pub fields: Vec<FieldChange>,
+}
+
+/// Added, removed, and changed elements of one kind.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElementDiff<Id> {
+    pub added: Vec<Id>,
+    pub removed: Vec<Id>,
+    pub changed: Vec<ElementChange<Id>>,
+}
+
+impl<Id> Default for ElementDiff<Id> {
+    fn default() -> Self {
+        Self {
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        }
+    }
+}
+
+impl<Id> ElementDiff<Id> {
+    /// True when no elements of this kind differ.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Number of added, removed, and changed elements.
+    pub fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.changed.len()
+    }
+}
+
+/// Differences between two networks, grouped by element kind.
+///
+/// Each list is sorted by ID so diffs are deterministic and stable to store.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkDiff {
+    pub buses: ElementDiff<BusId>,
+    pub gens: ElementDiff<GenId>,
+    pub loads: ElementDiff<LoadId>,
+    pub branches: ElementDiff<BranchId>,
+}
+
+impl NetworkDiff {
+    /// True when the networks are equivalent for all compared element kinds.
+    pub fn is_empty(&self) -> bool {
+        self.buses.is_empty()
+            && self.gens.is_empty()
+            && self.loads.is_empty()
+            && self.branches.is_empty()
+    }
+
+    /// Total number of added, removed, and changed elements.
+    pub fn change_count(&self) -> usize {
+        self.buses.len() + self.gens.len() + self.loads.len() + self.branches.len()
+    }
+}
+
+/// Compare `old` against `new`, keyed by element ID.
+///
+/// Transformers and shunts are not compared.
+pub fn diff(old: &Network, new: &Network) -> NetworkDiff {
+    let old = Elements::collect(old);
+    let new = Elements::collect(new);
+    NetworkDiff {
+        buses: diff_elements(&old.buses, &new.buses, |b| &b.name, bus_fields),
+        gens: diff_elements(&old.gens, &new.gens, |g| &g.name, gen_fields),
+        loads: diff_elements(&old.loads, &new.loads, |l| &l.name, load_fields),
+        branches: diff_elements(&old.branches, &new.branches, |b| &b.name, branch_fields),
+    }
+}
+
+/// Elements of one network indexed by ID (ordered by ID value).
+struct Elements<'a> {
+    buses: BTreeMap<usize, (BusId, &'a Bus)>,
+    gens: BTreeMap<usize, (GenId, &'a Gen)>,
+    loads: BTreeMap<usize, (LoadId, &'a Load)>,
+    branches: BTreeMap<usize, (BranchId, &'a Branch)>,
+}
+
+impl<'a> Elements<'a> {
+    fn collect(network: &'a Network) -> Self {
+        let mut elements = Self {
+            buses: BTreeMap::new(),
+            gens: BTreeMap::new(),
+            loads: BTreeMap::new(),
+            branches: BTreeMap::new(),
+        };
+        for node in network.graph.node_weights() {
+            match node {
+                Node::Bus(bus) => {
+                    elements.buses.insert(bus.id.value(), (bus.id, bus));
+                }
+                Node::Gen(gen) => {
+                    elements.gens.insert(gen.id.value(), (gen.id, gen));
+                }
+                Node::Load(load) => {
+                    elements.loads.insert(load.id.value(), (load.id, load));
+                }
+                Node::Shunt(_) => {}
+            }
+        }
+        for edge in network.graph.edge_weights() {
+            if let Edge::Branch(branch) = edge {
+                elements
+                    .branches
+                    .insert(branch.id.value(), (branch.id, branch));
+            }
+        }
+        elements
+    }
+}
+
+fn diff_elements<Id: Copy, T>(
+    old: &BTreeMap<usize, (Id, &T)>,
+    new: &BTreeMap<usize, (Id, &T)>,
+    name: impl Fn(&T) -> &String,
+    fields: impl Fn(&T, &T) -> Vec<FieldChange>,
+) -> ElementDiff<Id> {
+    let mut result = ElementDiff::default();
+    for (key, (id, old_elem)) in old {
+        match new.get(key) {
+            None => result.removed.push(*id),
+            Some((_, new_elem)) => {
+                let changes = fields(old_elem, new_elem);
+                if !changes.is_empty() {
+                    result.changed.push(ElementChange {
+                        id: *id,
+                        name: name(new_elem).clone(),
+                        fields: changes,
+                    });
+                }
+            }
+        }
+    }
+    for (key, (id, _)) in new {
+        if !old.contains_key(key) {
+            result.added.push(*id);
+        }
+    }
+    result
+}
+
+/// Push a [`FieldChange`] for each listed field whose `Debug` output differs.
+macro_rules! compare_fields {
+    ($old:expr, $new:expr, [$($field:ident),* $(,)?]) => {{
+        let mut changes = Vec::new();
+        $(
+            let old_value = format!("{:?}", $old.$field);
+            let new_value = format!("{:?}", $new.$field);
+            if old_value != new_value {
+                changes.push(FieldChange {
+                    field: stringify!($field).to_string(),
+                    old: old_value,
+                    new: new_value,
+                });
+            }
+        )*
+        changes
+    }};
+}
+
+fn bus_fields(old: &Bus, new: &Bus) -> Vec<FieldChange> {
+    compare_fields!(
+        old,
+        new,
+        [name, base_kv, voltage_pu, angle_rad, vmin_pu, vmax_pu, area_id, zone_id, bus_type]
+    )
+}
+
+fn gen_fields(old: &Gen, new: &Gen) -> Vec<FieldChange> {
+    compare_fields!(
+        old,
+        new,
+        [
+            name,
+            bus,
+            active_power,
+            reactive_power,
+            pmin,
+            pmax,
+            qmin,
+            qmax,
+            status,
+            voltage_setpoint,
+            mbase,
+            cost_startup,
+            cost_shutdown,
+            cost_model,
+            is_synchronous_condenser,
+            p_available,
+            emissions_rate,
+            dispatch_mode,
+            inertia_h,
+            xd_subtransient,
+            fuel,
+            startup_costs,
+            reactive_cost,
+        ]
+    )
+}
+
+fn load_fields(old: &Load, new: &Load) -> Vec<FieldChange> {
+    compare_fields!(old, new, [name, bus, active_power, reactive_power, zip])
+}
+
+fn branch_fields(old: &Branch, new: &Branch) -> Vec<FieldChange> {
+    compare_fields!(
+        old,
+        new,
+        [
+            name,
+            from_bus,
+            to_bus,
+            resistance,
+            reactance,
+            tap_ratio,
+            phase_shift,
+            charging_b,
+            s_max,
+            rating_a,
+            rating_b,
+            rating_c,
+            status,
+            angle_min,
+            angle_max,
+            element_type,
+            is_phase_shifter,
+        ]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BusType, DispatchMode, Megavars, Megawatts, StartupCosts, ZipModel};
+
+    fn two_bus() -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus 1".to_string(),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "Bus 2".to_string(),
+            ..Bus::default()
+        }));
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "Gen 1".to_string(), BusId::new(1)).with_p_limits(0.0, 100.0),
+        ));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "Load 1".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(50.0),
+            reactive_power: Megavars(10.0),
+            zip: None,
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "Line 1-2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        network
+    }
+
+    #[test]
+    fn test_identical_networks_have_empty_diff() {
+        let result = diff(&two_bus(), &two_bus());
+        assert!(result.is_empty());
+        assert_eq!(result.change_count(), 0);
+    }
+
+    #[test]
+    fn test_added_gen_and_changed_reactance() {
+        let old = two_bus();
+        let mut new = two_bus();
+        new.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(2), "PV 2".to_string(), BusId::new(2)).with_p_limits(0.0, 20.0),
+        ));
+        for edge in new.graph.edge_weights_mut() {
+            if let Edge::Branch(branch) = edge {
+                branch.reactance = 0.12;
+            }
+        }
+
+        let result = diff(&old, &new);
+        assert_eq!(result.change_count(), 2);
+
+        assert_eq!(result.gens.added, vec![GenId::new(2)]);
+        assert!(result.gens.removed.is_empty());
+        assert!(result.gens.changed.is_empty());
+
+        assert_eq!(result.branches.changed.len(), 1);
+        let change = &result.branches.changed[0];
+        assert_eq!(change.id, BranchId::new(1));
+        assert_eq!(
+            change.fields,
+            vec![FieldChange {
+                field: "reactance".to_string(),
+                old: "0.1".to_string(),
+                new: "0.12".to_string(),
+            }]
+        );
+
+        assert!(result.buses.is_empty());
+        assert!(result.loads.is_empty());
+    }
+
+    #[test]
+    fn test_removed_load() {
+        let old = two_bus();
+        let mut new = two_bus();
+        new.graph
+            .retain_nodes(|g, idx| !matches!(g[idx], Node::Load(_)));
+
+        let result = diff(&old, &new);
+        assert_eq!(result.loads.removed, vec![LoadId::new(1)]);
+        assert_eq!(result.change_count(), 1);
+    }
+
+    #[test]
+    fn test_changed_load_zip_model() {
+        let old = two_bus();
+        let mut new = two_bus();
+        for node in new.graph.node_weights_mut() {
+            if let Node::Load(load) = node {
+                load.zip = Some(ZipModel::CONSTANT_IMPEDANCE);
+            }
+        }
+
+        let result = diff(&old, &new);
+        assert_eq!(result.loads.changed.len(), 1);
+        let fields: Vec<&str> = result.loads.changed[0]
+            .fields
+            .iter()
+            .map(|f| f.field.as_str())
+            .collect();
+        assert_eq!(fields, ["zip"]);
+    }
+
+    /// Names of the fields reported as changed after applying `edit` to every generator
+    fn changed_gen_fields(edit: impl Fn(&mut Gen)) -> Vec<String> {
+        let old = two_bus();
+        let mut new = two_bus();
+        for node in new.graph.node_weights_mut() {
+            if let Node::Gen(gen) = node {
+                edit(gen);
+            }
+        }
+        diff(&old, &new)
+            .gens
+            .changed
+            .iter()
+            .flat_map(|change| change.fields.iter().map(|f| f.field.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_changed_gen_fields() {
+        assert_eq!(
+            changed_gen_fields(|gen| gen.emissions_rate = Some(0.9)),
+            ["emissions_rate"]
+        );
+        assert_eq!(
+            changed_gen_fields(|gen| gen.dispatch_mode = DispatchMode::MustRun),
+            ["dispatch_mode"]
+        );
+        assert_eq!(
+            changed_gen_fields(|gen| gen.inertia_h = Some(5.0)),
+            ["inertia_h"]
+        );
+        assert_eq!(
+            changed_gen_fields(|gen| gen.xd_subtransient = Some(0.2)),
+            ["xd_subtransient"]
+        );
+        assert_eq!(
+            changed_gen_fields(|gen| gen.fuel = Some("gas".to_string())),
+            ["fuel"]
+        );
+        let startup = StartupCosts::new((8.0, 100.0), (48.0, 250.0), 400.0).unwrap();
+        assert_eq!(
+            changed_gen_fields(|gen| gen.startup_costs = Some(startup)),
+            ["startup_costs"]
+        );
+        assert_eq!(
+            changed_gen_fields(|gen| gen.reactive_cost = Some(0.5)),
+            ["reactive_cost"]
+        );
+    }
+
+    #[test]
+    fn test_changed_bus_type() {
+        let old = two_bus();
+        let mut new = two_bus();
+        for node in new.graph.node_weights_mut() {
+            if let Node::Bus(bus) = node {
+                if bus.id == BusId::new(1) {
+                    bus.bus_type = Some(BusType::Slack);
+                }
+            }
+        }
+
+        let result = diff(&old, &new);
+        assert_eq!(result.change_count(), 1);
+        assert_eq!(
+            result.buses.changed[0].fields,
+            vec![FieldChange {
+                field: "bus_type".to_string(),
+                old: "None".to_string(),
+                new: "Some(Slack)".to_string(),
+            }]
+        );
+    }
+}
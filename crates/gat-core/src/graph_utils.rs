@@ -1,8 +1,10 @@
-use crate::Network;
+use crate::{Branch, BranchId, BusId, Edge, MegavoltAmperes, Network, Node, PerUnit};
 use anyhow::{anyhow, Result};
+use num_complex::Complex64;
 use petgraph::algo::connected_components;
+use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeRef;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
 /// Summary statistics produced by `graph stats` (density/degree/connected components).
 #[derive(Debug)]
@@ -38,6 +40,71 @@ pub struct IslandAnalysis {
     pub assignments: Vec<NodeAssignment>,
 }
 
+/// One branch folded into a merged parallel equivalent.
+#[derive(Debug, Clone)]
+pub struct ParallelMember {
+    pub branch_id: BranchId,
+    /// Fraction of the equivalent's series current carried by this branch, Z_eq / Z_k
+    pub share: Complex64,
+    /// Member runs to → from relative to the equivalent branch
+    pub reversed: bool,
+}
+
+/// Record of a parallel group replaced by [`merge_parallels`], kept to map results back.
+#[derive(Debug, Clone)]
+pub struct MergedParallel {
+    /// ID of the equivalent branch (the first member's ID)
+    pub merged_id: BranchId,
+    pub members: Vec<ParallelMember>,
+}
+
+impl MergedParallel {
+    /// Split a from-end flow on the equivalent branch across its members as (branch, MW, MVAr).
+    ///
+    /// Uses the current-divider rule S_k = conj(Z_eq / Z_k) · S, which is exact for the series
+    /// flow; line charging is apportioned the same way, a close approximation when members have
+    /// similar X/B ratios. Flows of reversed members are reported in their own from → to sense.
+    pub fn split_flow(&self, p_mw: f64, q_mvar: f64) -> Vec<(BranchId, f64, f64)> {
+        let flow = Complex64::new(p_mw, q_mvar);
+        self.members
+            .iter()
+            .map(|member| {
+                let s = member.share.conj() * flow;
+                let sign = if member.reversed { -1.0 } else { 1.0 };
+                (member.branch_id, sign * s.re, sign * s.im)
+            })
+            .collect()
+    }
+}
+
+/// Record of buses fused by [`merge_zero_impedance`], kept to map results back.
+#[derive(Debug, Clone, Default)]
+pub struct BusMerge {
+    /// Merged bus for every original bus (identity for buses that were not fused)
+    pub bus_map: HashMap<BusId, BusId>,
+    /// Branches dropped because both ends ended up in the same merged bus
+    pub removed_branches: Vec<BranchId>,
+}
+
+impl BusMerge {
+    /// Bus that `original` was fused into (itself if it was not merged or is unknown).
+    pub fn merged_bus(&self, original: BusId) -> BusId {
+        self.bus_map.get(&original).copied().unwrap_or(original)
+    }
+
+    /// Original buses fused into `merged`, in ID order.
+    pub fn members(&self, merged: BusId) -> Vec<BusId> {
+        let mut members: Vec<BusId> = self
+            .bus_map
+            .iter()
+            .filter(|(_, &to)| to == merged)
+            .map(|(&from, _)| from)
+            .collect();
+        members.sort_by_key(|b| b.value());
+        members
+    }
+}
+
 /// Calculates graph-level statistics such as density, degree distribution, and component counts (classic network science measures).
 pub fn graph_stats(network: &Network) -> Result<GraphStats> {
     let node_count = network.graph.node_count();
@@ -117,6 +184,229 @@ pub fn find_islands(network: &Network) -> Result<IslandAnalysis> {
     })
 }
 
+/// Groups of in-service branches that share the same pair of end buses, in either direction.
+///
+/// Only groups of two or more branches are returned, ordered by bus pair, with members in graph
+/// order.
+pub fn parallel_branch_groups(network: &Network) -> Vec<Vec<BranchId>> {
+    let mut groups: BTreeMap<(usize, usize), Vec<BranchId>> = BTreeMap::new();
+    for edge in network.graph.edge_weights() {
+        if let Edge::Branch(branch) = edge {
+            if branch.status {
+                groups
+                    .entry(bus_pair(branch.from_bus, branch.to_bus))
+                    .or_default()
+                    .push(branch.id);
+            }
+        }
+    }
+    groups.into_values().filter(|ids| ids.len() > 1).collect()
+}
+
+/// Replace every group of parallel branches with one equivalent branch for simplified studies.
+///
+/// The equivalent keeps the first member's ID, endpoints and tap, with series impedance
+/// 1 / Σ(1 / Z_k), summed line charging, and summed ratings (unlimited if any member is).
+/// Groups whose members differ in tap or phase shift, or that contain a zero-impedance
+/// branch, have no single π equivalent and are left as they are. The returned records map
+/// flows on each equivalent back to its members via [`MergedParallel::split_flow`].
+pub fn merge_parallels(network: &Network) -> (Network, Vec<MergedParallel>) {
+    let mut merged = network.clone();
+    let edge_of: HashMap<BranchId, _> = merged
+        .graph
+        .edge_indices()
+        .filter_map(|e| match &merged.graph[e] {
+            Edge::Branch(branch) => Some((branch.id, e)),
+            _ => None,
+        })
+        .collect();
+
+    let mut records = Vec::new();
+    let mut removed = HashSet::new();
+    for group in parallel_branch_groups(network) {
+        let members: Vec<Branch> = group
+            .iter()
+            .filter_map(|id| match &merged.graph[edge_of[id]] {
+                Edge::Branch(branch) => Some(branch.clone()),
+                _ => None,
+            })
+            .collect();
+        let Some(equivalent) = parallel_equivalent(&members) else {
+            continue;
+        };
+
+        let z_eq = Complex64::new(equivalent.resistance, equivalent.reactance);
+        records.push(MergedParallel {
+            merged_id: equivalent.id,
+            members: members
+                .iter()
+                .map(|branch| ParallelMember {
+                    branch_id: branch.id,
+                    share: z_eq / Complex64::new(branch.resistance, branch.reactance),
+                    reversed: branch.from_bus != equivalent.from_bus,
+                })
+                .collect(),
+        });
+        removed.extend(members.iter().skip(1).map(|branch| branch.id));
+        merged.graph[edge_of[&equivalent.id]] = Edge::Branch(equivalent);
+    }
+
+    merged
+        .graph
+        .retain_edges(|graph, e| !matches!(&graph[e], Edge::Branch(b) if removed.contains(&b.id)));
+    (merged, records)
+}
+
+/// Fuse buses joined by closed near-zero-impedance branches (switches, jumpers, bus ties).
+///
+/// A branch is a jumper when it is in service, has |Z| ≤ `threshold` (p.u.) and no off-nominal
+/// tap or phase shift. Each group of buses connected through jumpers becomes one electrical node
+/// that keeps the data of its lowest-ID bus; generators, loads and shunts are moved onto it and
+/// the remaining branches and transformers are reconnected. Jumpers and any other branch left
+/// with both ends in one merged bus are dropped. Open switches are never merged.
+pub fn merge_zero_impedance(network: &Network, threshold: f64) -> (Network, BusMerge) {
+    let mut buses: Vec<BusId> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => Some(bus.id),
+            _ => None,
+        })
+        .collect();
+    buses.sort_by_key(|b| b.value());
+    let position: HashMap<BusId, usize> = buses.iter().enumerate().map(|(i, &b)| (b, i)).collect();
+
+    // Union-find over bus positions; the root is always the lowest position in its group
+    let mut parent: Vec<usize> = (0..buses.len()).collect();
+    for edge in network.graph.edge_weights() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        let plain = (branch.tap_ratio == 0.0 || branch.tap_ratio == 1.0)
+            && branch.phase_shift.value() == 0.0;
+        let z = Complex64::new(branch.resistance, branch.reactance).norm();
+        if !branch.status || !plain || z > threshold {
+            continue;
+        }
+        let (Some(&a), Some(&b)) = (position.get(&branch.from_bus), position.get(&branch.to_bus))
+        else {
+            continue;
+        };
+        let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+        parent[ra.max(rb)] = ra.min(rb);
+    }
+
+    let mut record = BusMerge::default();
+    for (i, &bus) in buses.iter().enumerate() {
+        let r = root(&mut parent, i);
+        record.bus_map.insert(bus, buses[r]);
+    }
+
+    let mut merged = Network::new();
+    let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+    let mut bus_node: HashMap<BusId, NodeIndex> = HashMap::new();
+    for idx in network.graph.node_indices() {
+        let mut node = network.graph[idx].clone();
+        match &mut node {
+            Node::Bus(bus) if record.merged_bus(bus.id) != bus.id => continue,
+            Node::Bus(_) => {}
+            Node::Gen(gen) => gen.bus = record.merged_bus(gen.bus),
+            Node::Load(load) => load.bus = record.merged_bus(load.bus),
+            Node::Shunt(shunt) => shunt.bus = record.merged_bus(shunt.bus),
+        }
+        let new_idx = merged.graph.add_node(node);
+        if let Node::Bus(bus) = &merged.graph[new_idx] {
+            bus_node.insert(bus.id, new_idx);
+        }
+        node_map.insert(idx, new_idx);
+    }
+    for idx in network.graph.node_indices() {
+        if let Node::Bus(bus) = &network.graph[idx] {
+            node_map.insert(idx, bus_node[&record.merged_bus(bus.id)]);
+        }
+    }
+
+    for edge in network.graph.edge_references() {
+        let (source, target) = (node_map[&edge.source()], node_map[&edge.target()]);
+        let mut weight = edge.weight().clone();
+        match &mut weight {
+            Edge::Branch(branch) => {
+                branch.from_bus = record.merged_bus(branch.from_bus);
+                branch.to_bus = record.merged_bus(branch.to_bus);
+                if branch.from_bus == branch.to_bus {
+                    record.removed_branches.push(branch.id);
+                    continue;
+                }
+            }
+            Edge::Transformer(transformer) => {
+                transformer.from_bus = record.merged_bus(transformer.from_bus);
+                transformer.to_bus = record.merged_bus(transformer.to_bus);
+            }
+        }
+        merged.graph.add_edge(source, target, weight);
+    }
+
+    (merged, record)
+}
+
+/// Union-find root of `i`, halving the path on the way up.
+fn root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+fn bus_pair(a: BusId, b: BusId) -> (usize, usize) {
+    let (a, b) = (a.value(), b.value());
+    (a.min(b), a.max(b))
+}
+
+/// π equivalent of parallel branches, or `None` when they cannot be combined exactly.
+fn parallel_equivalent(members: &[Branch]) -> Option<Branch> {
+    let first = members.first()?;
+    let plain = |b: &Branch| b.tap_ratio == 1.0 && b.phase_shift.value() == 0.0;
+    let compatible = members.iter().all(|b| {
+        let z = Complex64::new(b.resistance, b.reactance);
+        z.norm() > 1e-12
+            && b.tap_ratio == first.tap_ratio
+            && b.phase_shift == first.phase_shift
+            && (plain(b) || b.from_bus == first.from_bus)
+    });
+    if !compatible {
+        return None;
+    }
+
+    let y_sum: Complex64 = members
+        .iter()
+        .map(|b| Complex64::new(b.resistance, b.reactance).inv())
+        .sum();
+    let z_eq = y_sum.inv();
+    let sum_ratings = |rating: fn(&Branch) -> Option<MegavoltAmperes>| {
+        members
+            .iter()
+            .map(rating)
+            .try_fold(MegavoltAmperes(0.0), |acc, r| r.map(|r| acc + r))
+    };
+
+    Some(Branch {
+        name: members
+            .iter()
+            .map(|b| b.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" || "),
+        resistance: z_eq.re,
+        reactance: z_eq.im,
+        charging_b: PerUnit(members.iter().map(|b| b.charging_b.value()).sum()),
+        s_max: sum_ratings(|b| b.s_max),
+        rating_a: sum_ratings(|b| b.rating_a),
+        rating_b: sum_ratings(|b| b.rating_b),
+        rating_c: sum_ratings(|b| b.rating_c),
+        ..first.clone()
+    })
+}
+
 /// Export the topology to a DOT string (Graphviz) so external tools can visualize the layout.
 pub fn export_graph(network: &Network, format: &str) -> Result<String> {
     match format.to_ascii_lowercase().as_str() {
@@ -144,3 +434,150 @@ fn render_dot(network: &Network) -> String {
 fn sanitize_label(label: &str) -> String {
     label.replace('"', "\\\"")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bus, Load, LoadId, Megavars, Megawatts};
+
+    fn line(id: usize, from: usize, to: usize, r: f64, x: f64, rating: f64) -> Edge {
+        Edge::Branch(Branch {
+            charging_b: PerUnit(0.02),
+            rating_a: Some(MegavoltAmperes(rating)),
+            ..Branch::new(
+                BranchId::new(id),
+                format!("line{}", id),
+                BusId::new(from),
+                BusId::new(to),
+                r,
+                x,
+            )
+        })
+    }
+
+    /// Two parallel lines between buses 0 and 1 (the second defined 1 → 0) plus a line 1 → 2.
+    fn network() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        network
+            .graph
+            .add_edge(buses[0], buses[1], line(0, 0, 1, 0.01, 0.1, 100.0));
+        network
+            .graph
+            .add_edge(buses[1], buses[2], line(1, 1, 2, 0.02, 0.1, 80.0));
+        network
+            .graph
+            .add_edge(buses[1], buses[0], line(2, 1, 0, 0.02, 0.2, 50.0));
+        network
+    }
+
+    #[test]
+    fn test_parallel_branch_groups() {
+        let groups = parallel_branch_groups(&network());
+        assert_eq!(groups, vec![vec![BranchId::new(0), BranchId::new(2)]]);
+    }
+
+    #[test]
+    fn test_merged_impedance_is_parallel_combination() {
+        let (merged, records) = merge_parallels(&network());
+        assert_eq!(merged.graph.edge_count(), 2);
+        assert_eq!(records.len(), 1);
+
+        let equivalent = merged
+            .graph
+            .edge_weights()
+            .find_map(|e| match e {
+                Edge::Branch(b) if b.id == BranchId::new(0) => Some(b),
+                _ => None,
+            })
+            .unwrap();
+        let z1 = Complex64::new(0.01, 0.1);
+        let z2 = Complex64::new(0.02, 0.2);
+        let z_eq = z1 * z2 / (z1 + z2);
+        assert!((equivalent.resistance - z_eq.re).abs() < 1e-12);
+        assert!((equivalent.reactance - z_eq.im).abs() < 1e-12);
+        assert!((equivalent.charging_b.value() - 0.04).abs() < 1e-12);
+        assert_eq!(equivalent.rating_a, Some(MegavoltAmperes(150.0)));
+
+        // The lower-impedance line carries two thirds; the reversed one reports in its own sense
+        let split = records[0].split_flow(90.0, 30.0);
+        assert_eq!(split[0].0, BranchId::new(0));
+        assert!((split[0].1 - 60.0).abs() < 1e-9);
+        assert!((split[0].2 - 20.0).abs() < 1e-9);
+        assert_eq!(split[1].0, BranchId::new(2));
+        assert!((split[1].1 + 30.0).abs() < 1e-9);
+        assert!((split[1].2 + 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_merge_zero_impedance_combines_injections() {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        // Closed jumper 1-2, open switch 2-3, and a real line 1-3
+        network
+            .graph
+            .add_edge(buses[0], buses[1], line(0, 1, 2, 0.0, 0.0, 100.0));
+        let mut open_switch = line(1, 2, 3, 0.0, 0.0, 100.0);
+        if let Edge::Branch(branch) = &mut open_switch {
+            branch.status = false;
+        }
+        network.graph.add_edge(buses[1], buses[2], open_switch);
+        network
+            .graph
+            .add_edge(buses[0], buses[2], line(2, 1, 3, 0.01, 0.1, 100.0));
+        for (id, bus, mw) in [(1, 1, 30.0), (2, 2, 20.0), (3, 3, 10.0)] {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(id),
+                name: format!("load{}", id),
+                bus: BusId::new(bus),
+                active_power: Megawatts(mw),
+                reactive_power: Megavars(0.0),
+                zip: None,
+            }));
+        }
+
+        let (merged, record) = merge_zero_impedance(&network, 1e-6);
+
+        assert_eq!(record.merged_bus(BusId::new(2)), BusId::new(1));
+        assert_eq!(record.merged_bus(BusId::new(3)), BusId::new(3));
+        assert_eq!(
+            record.members(BusId::new(1)),
+            vec![BusId::new(1), BusId::new(2)]
+        );
+        assert_eq!(record.removed_branches, vec![BranchId::new(0)]);
+
+        let bus_count = merged
+            .graph
+            .node_weights()
+            .filter(|n| matches!(n, Node::Bus(_)))
+            .count();
+        assert_eq!(bus_count, 2);
+        // The open switch survives, reconnected to the merged bus
+        assert_eq!(merged.graph.edge_count(), 2);
+
+        let mut demand: HashMap<BusId, f64> = HashMap::new();
+        for node in merged.graph.node_weights() {
+            if let Node::Load(load) = node {
+                *demand.entry(load.bus).or_default() += load.active_power.value();
+            }
+        }
+        assert_eq!(demand[&BusId::new(1)], 50.0);
+        assert_eq!(demand[&BusId::new(3)], 10.0);
+    }
+}
@@ -55,6 +55,7 @@
 //!     bus: BusId::new(2),
 //!     active_power: Megawatts(50.0),
 //!     reactive_power: Megavars(10.0),
+//!     zip: None,
 //! }));
 //!
 //! // Connect buses with a branch
@@ -96,7 +97,11 @@
 //! ## Modules
 //!
 //! - [`diagnostics`] - Validation and diagnostic reporting
+//! - [`diff`](mod@diff) - Structured comparison of two networks
 //! - [`graph_utils`] - Topological analysis (connectivity, islands, etc.)
+//! - [`AmbientConditions`] - Weather-dependent dynamic line ratings
+//! - [`normalize`] - Opt-in conversion of mixed SI/per-unit imports
+//! - [`sampling`] - Seeded random sampling shared by scenario studies
 //! - [`solver`] - Power flow and optimization algorithms
 //!
 //! ## Integration with gat-io
@@ -106,17 +111,27 @@
 
 use petgraph::{prelude::*, Undirected};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 pub mod diagnostics;
+pub mod diff;
 pub mod error;
 pub mod graph_utils;
+mod line_rating;
+pub mod normalize;
+mod report;
+pub mod sampling;
 pub mod solver;
 pub mod units;
 
 pub use diagnostics::{DiagnosticIssue, Diagnostics, ImportDiagnostics, ImportStats, Severity};
+pub use diff::{diff, ElementChange, ElementDiff, FieldChange, NetworkDiff};
 pub use error::{GatError, GatResult};
 pub use graph_utils::*;
-pub use petgraph::graph::NodeIndex;
+pub use line_rating::AmbientConditions;
+pub use normalize::normalize_units;
+pub use petgraph::graph::{EdgeIndex, NodeIndex};
+pub use sampling::ScenarioRng;
 pub use solver::*;
 pub use units::{
     AdmittancePu, CurrentPu, Degrees, ImpedancePu, Kiloamperes, Kilovolts, Megavars,
@@ -209,6 +224,39 @@ impl ShuntId {
     }
 }
 
+/// Power-flow role of a bus: which of V, θ, P and Q are held fixed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BusType {
+    /// Angle reference: V and θ fixed
+    Slack,
+    /// Voltage-controlled: P and V fixed
+    PV,
+    /// Load bus: P and Q fixed
+    PQ,
+}
+
+impl BusType {
+    /// Type for a MATPOWER/PSS®E bus code (1 = PQ, 2 = PV, 3 = slack).
+    /// Isolated (4) and unknown codes give `None`.
+    pub fn from_code(code: i64) -> Option<Self> {
+        match code {
+            1 => Some(BusType::PQ),
+            2 => Some(BusType::PV),
+            3 => Some(BusType::Slack),
+            _ => None,
+        }
+    }
+
+    /// MATPOWER/PSS®E bus code of this type
+    pub fn code(self) -> i64 {
+        match self {
+            BusType::PQ => 1,
+            BusType::PV => 2,
+            BusType::Slack => 3,
+        }
+    }
+}
+
 // Basic component structs
 #[derive(Debug, Clone)]
 pub struct Bus {
@@ -226,6 +274,9 @@ pub struct Bus {
     pub vmax_pu: Option<PerUnit>,
     pub area_id: Option<i64>,
     pub zone_id: Option<i64>,
+    /// Bus type from the case data. `None` leaves power flow to infer it
+    /// from the generators at the bus.
+    pub bus_type: Option<BusType>,
 }
 
 impl Default for Bus {
@@ -240,6 +291,7 @@ impl Default for Bus {
             vmax_pu: None,
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }
     }
 }
@@ -428,6 +480,86 @@ impl CostModel {
     }
 }
 
+/// How the optimizer may move a generator's active power.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DispatchMode {
+    /// Dispatched freely within its limits
+    #[default]
+    Auto,
+    /// Committed: output never falls below `pmin`, even when availability does
+    MustRun,
+    /// Held at a setpoint regardless of cost
+    Fixed(Megawatts),
+}
+
+/// Thermal state of a unit at startup, set by how long it has been offline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupType {
+    Hot,
+    Warm,
+    Cold,
+}
+
+/// Startup costs that rise with the time a unit has spent offline.
+///
+/// A start after at most `hot_hours` offline costs `hot_cost`, after at most
+/// `warm_hours` costs `warm_cost`, and anything longer costs `cold_cost`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StartupCosts {
+    pub hot_hours: f64,
+    pub hot_cost: f64,
+    pub warm_hours: f64,
+    pub warm_cost: f64,
+    pub cold_cost: f64,
+}
+
+impl StartupCosts {
+    /// Create hot/warm/cold costs with their down-time thresholds (hours, $).
+    pub fn new(
+        (hot_hours, hot_cost): (f64, f64),
+        (warm_hours, warm_cost): (f64, f64),
+        cold_cost: f64,
+    ) -> GatResult<Self> {
+        let values = [hot_hours, hot_cost, warm_hours, warm_cost, cold_cost];
+        if !values.iter().all(|v| v.is_finite() && *v >= 0.0) || hot_hours > warm_hours {
+            return Err(GatError::Validation(format!(
+                "startup thresholds must satisfy 0 <= hot ({}h) <= warm ({}h) with non-negative costs",
+                hot_hours, warm_hours
+            )));
+        }
+        Ok(Self {
+            hot_hours,
+            hot_cost,
+            warm_hours,
+            warm_cost,
+            cold_cost,
+        })
+    }
+
+    /// Startup type and cost after `downtime_hours` offline
+    pub fn at(&self, downtime_hours: f64) -> (StartupType, f64) {
+        if downtime_hours <= self.hot_hours {
+            (StartupType::Hot, self.hot_cost)
+        } else if downtime_hours <= self.warm_hours {
+            (StartupType::Warm, self.warm_cost)
+        } else {
+            (StartupType::Cold, self.cold_cost)
+        }
+    }
+}
+
+/// A start found in a commitment schedule by [`Gen::startups`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Startup {
+    /// Period in which the unit comes online
+    pub period: usize,
+    /// Hours offline before the start
+    pub downtime_hours: f64,
+    pub kind: StartupType,
+    /// Startup cost ($)
+    pub cost: f64,
+}
+
 #[derive(Debug, Clone)]
 pub struct Gen {
     pub id: GenId,
@@ -453,12 +585,33 @@ pub struct Gen {
     pub mbase: Option<MegavoltAmperes>,
     /// Startup cost ($)
     pub cost_startup: Option<f64>,
+    /// Down-time dependent startup costs; take precedence over `cost_startup`
+    pub startup_costs: Option<StartupCosts>,
     /// Shutdown cost ($)
     pub cost_shutdown: Option<f64>,
     /// Cost function for OPF
     pub cost_model: CostModel,
+    /// Reactive production cost ($/Mvar·h) charged on |Q| by AC-OPF.
+    /// `None` leaves reactive output free.
+    pub reactive_cost: Option<f64>,
     /// Synchronous condenser flag (allows negative Pg for reactive-only devices)
     pub is_synchronous_condenser: bool,
+    /// Available output for variable (renewable) units. When set, OPF may
+    /// dispatch anywhere up to `min(pmax, p_available)` and reports the
+    /// shortfall as curtailment.
+    pub p_available: Option<Megawatts>,
+    /// CO2 emissions rate (tCO2/MWh). `None` for units that emit nothing or
+    /// whose rate is unknown.
+    pub emissions_rate: Option<f64>,
+    /// Operator dispatch decision (must-run or fixed output)
+    pub dispatch_mode: DispatchMode,
+    /// Inertia constant H (s, on `mbase`). `None` for inverter-based units
+    /// or when unknown.
+    pub inertia_h: Option<f64>,
+    /// Subtransient reactance X''d (p.u. on `mbase`), used for fault studies
+    pub xd_subtransient: Option<f64>,
+    /// Fuel or technology tag (e.g. "coal", "wind"), if known
+    pub fuel: Option<String>,
 }
 
 impl Default for Gen {
@@ -477,9 +630,17 @@ impl Default for Gen {
             voltage_setpoint: None,
             mbase: None,
             cost_startup: None,
+            startup_costs: None,
             cost_shutdown: None,
             cost_model: CostModel::NoCost,
+            reactive_cost: None,
             is_synchronous_condenser: false,
+            p_available: None,
+            emissions_rate: None,
+            dispatch_mode: DispatchMode::Auto,
+            inertia_h: None,
+            xd_subtransient: None,
+            fuel: None,
         }
     }
 }
@@ -501,9 +662,17 @@ impl Gen {
             voltage_setpoint: None,
             mbase: None,
             cost_startup: None,
+            startup_costs: None,
             cost_shutdown: None,
             cost_model: CostModel::NoCost,
+            reactive_cost: None,
             is_synchronous_condenser: false,
+            p_available: None,
+            emissions_rate: None,
+            dispatch_mode: DispatchMode::Auto,
+            inertia_h: None,
+            xd_subtransient: None,
+            fuel: None,
         }
     }
 
@@ -527,11 +696,187 @@ impl Gen {
         self
     }
 
+    /// Set reactive production cost (in $/Mvar·h)
+    pub fn with_reactive_cost(mut self, cost_per_mvar: f64) -> Self {
+        self.reactive_cost = Some(cost_per_mvar);
+        self
+    }
+
     /// Mark generator as synchronous condenser (allows negative Pg)
     pub fn as_synchronous_condenser(mut self) -> Self {
         self.is_synchronous_condenser = true;
         self
     }
+
+    /// Mark generator as variable with `mw` available (in MW)
+    pub fn with_p_available(mut self, mw: f64) -> Self {
+        self.p_available = Some(Megawatts(mw));
+        self
+    }
+
+    /// Set CO2 emissions rate (in tCO2/MWh)
+    pub fn with_emissions_rate(mut self, t_per_mwh: f64) -> Self {
+        self.emissions_rate = Some(t_per_mwh);
+        self
+    }
+
+    /// Set the dispatch mode (must-run or fixed output)
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = mode;
+        self
+    }
+
+    /// Set the inertia constant H (s, on `mbase`)
+    pub fn with_inertia(mut self, h_seconds: f64) -> Self {
+        self.inertia_h = Some(h_seconds);
+        self
+    }
+
+    /// Set the subtransient reactance X''d (p.u. on `mbase`)
+    pub fn with_subtransient_reactance(mut self, xd_pu: f64) -> Self {
+        self.xd_subtransient = Some(xd_pu);
+        self
+    }
+
+    /// Tag the unit with its fuel or technology
+    pub fn with_fuel(mut self, fuel: impl Into<String>) -> Self {
+        self.fuel = Some(fuel.into());
+        self
+    }
+
+    /// Set hot/warm/cold startup costs
+    pub fn with_startup_costs(mut self, costs: StartupCosts) -> Self {
+        self.startup_costs = Some(costs);
+        self
+    }
+
+    /// Startup type and cost after `downtime_hours` offline.
+    ///
+    /// A unit with only a flat `cost_startup` pays it on every start, reported
+    /// as cold. `None` when the unit has no startup cost at all.
+    pub fn startup_cost(&self, downtime_hours: f64) -> Option<(StartupType, f64)> {
+        match (&self.startup_costs, self.cost_startup) {
+            (Some(costs), _) => Some(costs.at(downtime_hours)),
+            (None, Some(cost)) => Some((StartupType::Cold, cost)),
+            (None, None) => None,
+        }
+    }
+
+    /// Every start in an on/off commitment schedule of `period_hours` periods.
+    ///
+    /// `offline_hours` is how long the unit has been down before period 0;
+    /// zero means it was online, so running in period 0 is not a start.
+    /// Starts are priced by [`Gen::startup_cost`], zero if the unit has none.
+    pub fn startups(&self, on: &[bool], period_hours: f64, offline_hours: f64) -> Vec<Startup> {
+        let mut starts = Vec::new();
+        let mut downtime = offline_hours;
+        let mut was_on = offline_hours <= 0.0;
+        for (period, &is_on) in on.iter().enumerate() {
+            if is_on && !was_on {
+                let (kind, cost) = self
+                    .startup_cost(downtime)
+                    .unwrap_or((StartupType::Cold, 0.0));
+                starts.push(Startup {
+                    period,
+                    downtime_hours: downtime,
+                    kind,
+                    cost,
+                });
+            }
+            if is_on {
+                downtime = 0.0;
+            } else {
+                downtime += period_hours;
+            }
+            was_on = is_on;
+        }
+        starts
+    }
+
+    /// Whether this is a variable (curtailable) unit
+    pub fn is_variable(&self) -> bool {
+        self.p_available.is_some()
+    }
+
+    /// Effective upper dispatch limit: `pmax`, capped by availability for
+    /// variable units, raised to `pmin` for must-run units and pinned to the
+    /// setpoint for fixed units (in MW)
+    pub fn dispatch_pmax(&self) -> f64 {
+        let pmax = match self.p_available {
+            Some(available) => self.pmax.value().min(available.value().max(0.0)),
+            None => self.pmax.value(),
+        };
+        match self.dispatch_mode {
+            DispatchMode::Auto => pmax,
+            DispatchMode::MustRun => pmax.max(self.pmin.value()),
+            DispatchMode::Fixed(setpoint) => setpoint.value(),
+        }
+    }
+
+    /// Effective lower dispatch limit, never above [`Gen::dispatch_pmax`] (in MW)
+    pub fn dispatch_pmin(&self) -> f64 {
+        match self.dispatch_mode {
+            DispatchMode::Fixed(setpoint) => setpoint.value(),
+            _ => self.pmin.value().min(self.dispatch_pmax()),
+        }
+    }
+}
+
+/// Voltage dependence of a load's demand (ZIP model).
+///
+/// At bus voltage `V` (p.u.) a load draws its nominal demand scaled by
+/// `z·V² + i·V + p`, where `z`, `i` and `p` are the constant-impedance,
+/// constant-current and constant-power fractions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZipModel {
+    pub z: f64,
+    pub i: f64,
+    pub p: f64,
+}
+
+impl ZipModel {
+    pub const CONSTANT_POWER: Self = Self {
+        z: 0.0,
+        i: 0.0,
+        p: 1.0,
+    };
+    pub const CONSTANT_CURRENT: Self = Self {
+        z: 0.0,
+        i: 1.0,
+        p: 0.0,
+    };
+    pub const CONSTANT_IMPEDANCE: Self = Self {
+        z: 1.0,
+        i: 0.0,
+        p: 0.0,
+    };
+
+    /// Create a ZIP model, checking that the fractions sum to 1.
+    pub fn new(z: f64, i: f64, p: f64) -> GatResult<Self> {
+        if ![z, i, p].iter().all(|f| f.is_finite()) || (z + i + p - 1.0).abs() > 1e-6 {
+            return Err(GatError::Validation(format!(
+                "ZIP fractions must sum to 1 (z={}, i={}, p={})",
+                z, i, p
+            )));
+        }
+        Ok(Self { z, i, p })
+    }
+
+    /// Demand at `v_pu` as a fraction of nominal demand
+    pub fn scale(&self, v_pu: f64) -> f64 {
+        self.z * v_pu * v_pu + self.i * v_pu + self.p
+    }
+
+    /// Derivative of [`ZipModel::scale`] with respect to voltage
+    pub fn scale_derivative(&self, v_pu: f64) -> f64 {
+        2.0 * self.z * v_pu + self.i
+    }
+}
+
+impl Default for ZipModel {
+    fn default() -> Self {
+        Self::CONSTANT_POWER
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -539,10 +884,46 @@ pub struct Load {
     pub id: LoadId,
     pub name: String,
     pub bus: BusId,
-    /// Active power demand (MW)
+    /// Active power demand at 1.0 p.u. voltage (MW)
     pub active_power: Megawatts,
-    /// Reactive power demand (Mvar)
+    /// Reactive power demand at 1.0 p.u. voltage (Mvar)
     pub reactive_power: Megavars,
+    /// Voltage dependence of demand; `None` is constant power
+    pub zip: Option<ZipModel>,
+}
+
+/// Whether a load's current lags (inductive, absorbs Mvar) or leads
+/// (capacitive, supplies Mvar) its voltage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerFactorSense {
+    Lagging,
+    Leading,
+}
+
+impl Load {
+    /// Voltage dependence of demand, constant power unless set
+    pub fn zip_model(&self) -> ZipModel {
+        self.zip.unwrap_or_default()
+    }
+
+    /// Set reactive demand from active demand and a power factor in (0, 1].
+    ///
+    /// `Q = |P|·tan(acos(pf))`, positive when lagging and negative when
+    /// leading. Active demand is unchanged.
+    pub fn with_power_factor(mut self, pf: f64, sense: PowerFactorSense) -> GatResult<Self> {
+        if !(pf > 0.0 && pf <= 1.0) {
+            return Err(GatError::Validation(format!(
+                "load {}: power factor {} must be in (0, 1]",
+                self.name, pf
+            )));
+        }
+        let q = self.active_power.value().abs() * pf.acos().tan();
+        self.reactive_power = Megavars(match sense {
+            PowerFactorSense::Lagging => q,
+            PowerFactorSense::Leading => -q,
+        });
+        Ok(self)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -595,6 +976,15 @@ pub enum Node {
     Shunt(Shunt),
 }
 
+/// 64-bit FNV-1a hash of `bytes`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
 // Enum to represent different types of edges in the graph
 #[derive(Debug, Clone)]
 pub enum Edge {
@@ -602,10 +992,48 @@ pub enum Edge {
     Transformer(Transformer),
 }
 
+/// How [`Network::select_slack`] picks the angle reference of an island.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SlackStrategy {
+    /// Bus with the most in-service generating capacity (Pmax)
+    #[default]
+    LargestGen,
+    /// This bus for its own island; other islands fall back to `LargestGen`
+    Specified(BusId),
+    /// Bus with the lowest ID
+    LowestId,
+    /// Bus marked [`BusType::Slack`] in the case data; islands without one
+    /// fall back to `LargestGen`
+    FromCase,
+}
+
+/// Identifiers an element carried in its source model (e.g. CIM mRIDs),
+/// kept beside the integer IDs so exporters can write them back.
+///
+/// Elements missing from a map had no source identifier.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExternalIds {
+    pub buses: HashMap<BusId, String>,
+    pub branches: HashMap<BranchId, String>,
+    pub gens: HashMap<GenId, String>,
+    pub loads: HashMap<LoadId, String>,
+}
+
+impl ExternalIds {
+    pub fn is_empty(&self) -> bool {
+        self.buses.is_empty()
+            && self.branches.is_empty()
+            && self.gens.is_empty()
+            && self.loads.is_empty()
+    }
+}
+
 /// The core power network graph
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Network {
     pub graph: Graph<Node, Edge, Undirected>,
+    /// Source-model identifiers of the graph's elements
+    pub external_ids: ExternalIds,
 }
 
 // The physical grid is represented as a graph where buses, generators, and loads are nodes,
@@ -617,6 +1045,7 @@ impl Network {
     pub fn new() -> Self {
         Self {
             graph: Graph::new_undirected(),
+            external_ids: ExternalIds::default(),
         }
     }
 
@@ -632,11 +1061,7 @@ impl Network {
                     stats.total_gen_capacity_mw += g.pmax.value();
                     stats.total_gen_pmin_mw += g.pmin.value();
                 }
-                Node::Load(l) => {
-                    stats.num_loads += 1;
-                    stats.total_load_mw += l.active_power.value();
-                    stats.total_load_mvar += l.reactive_power.value();
-                }
+                Node::Load(l) => stats.add_load(l),
                 Node::Shunt(_) => stats.num_shunts += 1,
             }
         }
@@ -645,6 +1070,79 @@ impl Network {
         stats
     }
 
+    /// Compute [`NetworkStats`] for each area, keyed by `Bus::area_id`.
+    ///
+    /// Generators, loads and shunts count toward the area of their bus, and
+    /// branches toward the area of their `from_bus`, so tie lines appear once.
+    /// Buses without an area, and elements attached to an unknown bus, go to
+    /// the [`UNASSIGNED_AREA`] bucket.
+    pub fn stats_by_area(&self) -> HashMap<i64, NetworkStats> {
+        let bus_area: HashMap<BusId, i64> = self
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Bus(bus) => Some((bus.id, bus.area_id.unwrap_or(UNASSIGNED_AREA))),
+                _ => None,
+            })
+            .collect();
+        let area_of = |bus: &BusId| bus_area.get(bus).copied().unwrap_or(UNASSIGNED_AREA);
+
+        let mut by_area: HashMap<i64, NetworkStats> = HashMap::new();
+        for node in self.graph.node_weights() {
+            match node {
+                Node::Bus(bus) => by_area.entry(area_of(&bus.id)).or_default().num_buses += 1,
+                Node::Gen(g) => {
+                    let stats = by_area.entry(area_of(&g.bus)).or_default();
+                    stats.num_gens += 1;
+                    stats.total_gen_capacity_mw += g.pmax.value();
+                    stats.total_gen_pmin_mw += g.pmin.value();
+                }
+                Node::Load(l) => by_area.entry(area_of(&l.bus)).or_default().add_load(l),
+                Node::Shunt(sh) => by_area.entry(area_of(&sh.bus)).or_default().num_shunts += 1,
+            }
+        }
+        for edge in self.graph.edge_weights() {
+            let from_bus = match edge {
+                Edge::Branch(branch) => &branch.from_bus,
+                Edge::Transformer(tx) => &tx.from_bus,
+            };
+            by_area.entry(area_of(from_bus)).or_default().num_branches += 1;
+        }
+        by_area
+    }
+
+    /// Stable 64-bit fingerprint of the network's topology and parameters.
+    ///
+    /// Every element is hashed from its `Debug` rendering, so all fields
+    /// count (as in [`diff`]), and each edge also hashes the buses the graph
+    /// connects. Element hashes are sorted before being combined, so the
+    /// fingerprint ignores insertion order and `NodeIndex` assignment. The hash
+    /// is FNV-1a, which does not vary between platforms or Rust releases, so
+    /// fingerprints can key persistent caches.
+    pub fn fingerprint(&self) -> u64 {
+        let endpoint = |index: NodeIndex| match &self.graph[index] {
+            Node::Bus(bus) => format!("bus {}", bus.id.value()),
+            other => format!("{:?}", other),
+        };
+        let mut element_hashes: Vec<u64> = self
+            .graph
+            .node_weights()
+            .map(|node| fnv1a(format!("{:?}", node).as_bytes()))
+            .collect();
+        for edge in self.graph.edge_references() {
+            let mut ends = [endpoint(edge.source()), endpoint(edge.target())];
+            ends.sort();
+            let key = format!("{:?} {} {}", edge.weight(), ends[0], ends[1]);
+            element_hashes.push(fnv1a(key.as_bytes()));
+        }
+        element_hashes.sort_unstable();
+        let bytes: Vec<u8> = element_hashes
+            .iter()
+            .flat_map(|hash| hash.to_le_bytes())
+            .collect();
+        fnv1a(&bytes)
+    }
+
     /// Validate network data for common issues that cause solver failures.
     ///
     /// Populates the provided `Diagnostics` with any warnings/errors found.
@@ -658,8 +1156,11 @@ impl Network {
             return; // Can't check further
         }
 
-        // Check for zero load (likely parser bug)
-        if stats.total_load_mw.abs() < 1e-9 && stats.num_loads > 0 {
+        // Check for all-zero loads (likely parser bug). Negative loads are
+        // behind-the-meter generation, so only genuinely zero loads count.
+        if stats.num_loads == 0 {
+            diag.add_warning("structure", "Network has no loads");
+        } else if stats.gross_load_mw < 1e-9 && stats.embedded_gen_mw < 1e-9 {
             diag.add_error(
                 "structure",
                 &format!(
@@ -667,8 +1168,6 @@ impl Network {
                     stats.num_loads
                 ),
             );
-        } else if stats.total_load_mw.abs() < 1e-9 {
-            diag.add_warning("structure", "Network has no loads");
         }
 
         // Check for no generators
@@ -704,7 +1203,7 @@ impl Network {
             .sum()
     }
 
-    /// Get total active power load (MW)
+    /// Get total active power load (MW), net of negative (behind-the-meter) loads
     pub fn total_load_mw(&self) -> f64 {
         self.graph
             .node_weights()
@@ -715,6 +1214,25 @@ impl Network {
             .sum()
     }
 
+    /// Multiply every load's reactive demand by `factor`, leaving active
+    /// demand unchanged. Returns the number of loads scaled.
+    pub fn scale_reactive(&mut self, factor: f64) -> GatResult<usize> {
+        if !factor.is_finite() {
+            return Err(GatError::Validation(format!(
+                "reactive scale factor {} must be finite",
+                factor
+            )));
+        }
+        let mut scaled = 0;
+        for node in self.graph.node_indices() {
+            if let Node::Load(load) = &mut self.graph[node] {
+                load.reactive_power = Megavars(load.reactive_power.value() * factor);
+                scaled += 1;
+            }
+        }
+        Ok(scaled)
+    }
+
     /// Get total generation capacity (MW)
     pub fn total_capacity_mw(&self) -> f64 {
         self.graph
@@ -791,22 +1309,235 @@ impl Network {
             })
             .collect()
     }
+
+    /// Buses in ascending ID order.
+    ///
+    /// Graph order follows insertion and so differs between import paths;
+    /// use the sorted accessors wherever the order reaches an output.
+    pub fn buses_sorted(&self) -> Vec<&Bus> {
+        let mut buses = self.buses();
+        buses.sort_by_key(|bus| bus.id.value());
+        buses
+    }
+
+    /// Generators in ascending ID order
+    pub fn generators_sorted(&self) -> Vec<&Gen> {
+        let mut gens = self.generators();
+        gens.sort_by_key(|gen| gen.id.value());
+        gens
+    }
+
+    /// Loads in ascending ID order
+    pub fn loads_sorted(&self) -> Vec<&Load> {
+        let mut loads: Vec<&Load> = self
+            .graph
+            .node_weights()
+            .filter_map(|n| match n {
+                Node::Load(l) => Some(l),
+                _ => None,
+            })
+            .collect();
+        loads.sort_by_key(|load| load.id.value());
+        loads
+    }
+
+    /// Branches in ascending ID order
+    pub fn branches_sorted(&self) -> Vec<&Branch> {
+        let mut branches = self.branches();
+        branches.sort_by_key(|branch| branch.id.value());
+        branches
+    }
+
+    /// Get the branches with `status` set, i.e. those solvers include
+    pub fn in_service_branches(&self) -> Vec<&Branch> {
+        self.graph
+            .edge_weights()
+            .filter_map(|e| match e {
+                Edge::Branch(b) if b.status => Some(b),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Slack (angle reference) bus of each electrical island, chosen by
+    /// `strategy`.
+    ///
+    /// Islands are the buses joined by in-service branches and transformers,
+    /// returned in order of their lowest bus ID. Capacity ties go to the lower
+    /// ID, and an island without in-service generators takes its lowest bus.
+    /// Fails if a specified bus is not in the network.
+    pub fn select_slack(&self, strategy: SlackStrategy) -> GatResult<Vec<BusId>> {
+        if let SlackStrategy::Specified(id) = strategy {
+            self.bus_node(id)?;
+        }
+        let mut buses: Vec<BusId> = self
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Bus(bus) => Some(bus.id),
+                _ => None,
+            })
+            .collect();
+        buses.sort_by_key(|id| id.value());
+        let position: HashMap<BusId, usize> =
+            buses.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        // Union-find whose roots are each island's lowest bus
+        let mut parent: Vec<usize> = (0..buses.len()).collect();
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        for edge in self.graph.edge_weights() {
+            let (from, to) = match edge {
+                Edge::Branch(branch) if branch.status => (branch.from_bus, branch.to_bus),
+                Edge::Transformer(tx) => (tx.from_bus, tx.to_bus),
+                Edge::Branch(_) => continue,
+            };
+            if let (Some(&a), Some(&b)) = (position.get(&from), position.get(&to)) {
+                let (a, b) = (root(&mut parent, a), root(&mut parent, b));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut capacity: HashMap<BusId, f64> = HashMap::new();
+        for node in self.graph.node_weights() {
+            if let Node::Gen(gen) = node {
+                if gen.status {
+                    *capacity.entry(gen.bus).or_insert(0.0) += gen.pmax.value().max(0.0);
+                }
+            }
+        }
+
+        let marked: Vec<BusId> = self
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Bus(bus) if bus.bus_type == Some(BusType::Slack) => Some(bus.id),
+                _ => None,
+            })
+            .collect();
+
+        // Best (bus, score) per island; buses are visited by ascending ID
+        let mut slack: BTreeMap<usize, (BusId, f64)> = BTreeMap::new();
+        for (i, &bus) in buses.iter().enumerate() {
+            let score = match strategy {
+                SlackStrategy::Specified(id) if id == bus => f64::INFINITY,
+                SlackStrategy::FromCase if marked.contains(&bus) => f64::INFINITY,
+                SlackStrategy::LowestId => 0.0,
+                // Any generator bus outranks one without, even at zero capacity
+                _ => capacity.get(&bus).map_or(-1.0, |mw| mw.min(f64::MAX)),
+            };
+            let best = slack.entry(root(&mut parent, i)).or_insert((bus, score));
+            if score > best.1 {
+                *best = (bus, score);
+            }
+        }
+        Ok(slack.into_values().map(|(bus, _)| bus).collect())
+    }
+
+    // Incremental editing
+    //
+    // The methods below are the supported way to change branch topology in
+    // place. They locate elements by ID, so they stay correct even though
+    // `remove_branch` renumbers petgraph edge indices, and they fail on
+    // unknown IDs instead of silently doing nothing. Code that caches
+    // anything derived from the topology should route edits through them.
+
+    /// Put branch `id` in or out of service.
+    ///
+    /// The branch stays in the graph, so it can be restored later; solvers
+    /// skip branches whose `status` is false.
+    pub fn set_branch_status(&mut self, id: BranchId, in_service: bool) -> GatResult<()> {
+        let edge = self.branch_edge(id)?;
+        if let Edge::Branch(branch) = &mut self.graph[edge] {
+            branch.status = in_service;
+        }
+        Ok(())
+    }
+
+    /// Remove branch `id` from the graph and return it.
+    ///
+    /// petgraph fills the freed slot with the last edge, so any `EdgeIndex`
+    /// held from before the call may point at a different edge afterwards.
+    pub fn remove_branch(&mut self, id: BranchId) -> GatResult<Branch> {
+        let edge = self.branch_edge(id)?;
+        match self.graph.remove_edge(edge) {
+            Some(Edge::Branch(branch)) => Ok(branch),
+            _ => unreachable!("branch_edge returns branch edges only"),
+        }
+    }
+
+    /// Add `branch` between its `from_bus` and `to_bus`.
+    ///
+    /// Fails if a branch with the same ID already exists or either end bus
+    /// is not in the network.
+    pub fn add_branch_checked(&mut self, branch: Branch) -> GatResult<EdgeIndex> {
+        if self.branch_edge(branch.id).is_ok() {
+            return Err(GatError::Network(format!(
+                "branch {} already exists",
+                branch.id.value()
+            )));
+        }
+        let from = self.bus_node(branch.from_bus)?;
+        let to = self.bus_node(branch.to_bus)?;
+        Ok(self.graph.add_edge(from, to, Edge::Branch(branch)))
+    }
+
+    fn branch_edge(&self, id: BranchId) -> GatResult<EdgeIndex> {
+        self.graph
+            .edge_indices()
+            .find(|&edge| matches!(&self.graph[edge], Edge::Branch(b) if b.id == id))
+            .ok_or_else(|| GatError::Network(format!("unknown branch {}", id.value())))
+    }
+
+    fn bus_node(&self, id: BusId) -> GatResult<NodeIndex> {
+        self.graph
+            .node_indices()
+            .find(|&node| matches!(&self.graph[node], Node::Bus(b) if b.id == id))
+            .ok_or_else(|| GatError::Network(format!("unknown bus {}", id.value())))
+    }
 }
 
+/// Key of the [`Network::stats_by_area`] bucket for buses without an `area_id`.
+pub const UNASSIGNED_AREA: i64 = i64::MIN;
+
 /// Statistics about a network's size and capacity
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct NetworkStats {
     pub num_buses: usize,
     pub num_gens: usize,
     pub num_loads: usize,
     pub num_shunts: usize,
     pub num_branches: usize,
+    /// Net load: consumption minus behind-the-meter generation (MW)
     pub total_load_mw: f64,
     pub total_load_mvar: f64,
+    /// Sum of positive loads (MW)
+    pub gross_load_mw: f64,
+    /// Behind-the-meter generation carried as negative loads, as a positive total (MW)
+    pub embedded_gen_mw: f64,
     pub total_gen_capacity_mw: f64,
     pub total_gen_pmin_mw: f64,
 }
 
+impl NetworkStats {
+    fn add_load(&mut self, load: &Load) {
+        let p = load.active_power.value();
+        self.num_loads += 1;
+        self.total_load_mw += p;
+        self.total_load_mvar += load.reactive_power.value();
+        if p >= 0.0 {
+            self.gross_load_mw += p;
+        } else {
+            self.embedded_gen_mw -= p;
+        }
+    }
+}
+
 impl std::fmt::Display for NetworkStats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -943,6 +1674,7 @@ mod tests {
             bus: BusId(1),
             active_power: Megawatts(50.0),
             reactive_power: Megavars(10.0),
+            zip: None,
         }));
         network.graph.add_edge(
             bus1,
@@ -972,6 +1704,254 @@ mod tests {
         assert!(!diag.has_errors());
     }
 
+    #[test]
+    fn test_negative_load_is_embedded_generation() {
+        let mut network = Network::new();
+        let bus1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId(0),
+            name: "Bus 1".to_string(),
+            ..Bus::default()
+        }));
+        let bus2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId(1),
+            name: "Bus 2".to_string(),
+            ..Bus::default()
+        }));
+        let mut gen = Gen::new(GenId::new(0), "Gen 1".to_string(), BusId(0));
+        gen.pmax = Megawatts(100.0);
+        network.graph.add_node(Node::Gen(gen));
+        // Net-metered bus exporting exactly what the other bus consumes
+        for (id, mw) in [(0, 40.0), (1, -40.0)] {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(id),
+                name: format!("Load {}", id),
+                bus: BusId(id),
+                active_power: Megawatts(mw),
+                reactive_power: Megavars(0.0),
+                zip: None,
+            }));
+        }
+        network.graph.add_edge(
+            bus1,
+            bus2,
+            Edge::Branch(Branch {
+                id: BranchId(0),
+                name: "Branch 1-2".to_string(),
+                from_bus: BusId(0),
+                to_bus: BusId(1),
+                resistance: 0.01,
+                reactance: 0.1,
+                ..Branch::default()
+            }),
+        );
+
+        let stats = network.stats();
+        assert!(stats.total_load_mw.abs() < 1e-9);
+        assert!((stats.gross_load_mw - 40.0).abs() < 1e-9);
+        assert!((stats.embedded_gen_mw - 40.0).abs() < 1e-9);
+
+        let mut diag = Diagnostics::new();
+        network.validate_into(&mut diag);
+        assert!(!diag.has_errors());
+        assert!(!diag.warnings().any(|i| i.message.contains("no loads")));
+
+        // Genuinely zero loads are still flagged
+        for node in network.graph.node_weights_mut() {
+            if let Node::Load(load) = node {
+                load.active_power = Megawatts(0.0);
+            }
+        }
+        let mut diag = Diagnostics::new();
+        network.validate_into(&mut diag);
+        assert!(diag.errors().any(|i| i.message.contains("parser bug")));
+    }
+
+    /// Two buses, a line, a generator and a load, added in the given order.
+    fn fingerprint_network(reverse: bool, load_mw: f64) -> Network {
+        let mut network = Network::new();
+        let mut nodes = vec![
+            Node::Bus(Bus {
+                id: BusId(0),
+                name: "Bus 0".to_string(),
+                ..Bus::default()
+            }),
+            Node::Bus(Bus {
+                id: BusId(1),
+                name: "Bus 1".to_string(),
+                ..Bus::default()
+            }),
+            Node::Gen(
+                Gen::new(GenId::new(0), "Gen 0".to_string(), BusId(0)).with_p_limits(0.0, 100.0),
+            ),
+            Node::Load(Load {
+                id: LoadId::new(0),
+                name: "Load 0".to_string(),
+                bus: BusId(1),
+                active_power: Megawatts(load_mw),
+                reactive_power: Megavars(10.0),
+                zip: None,
+            }),
+        ];
+        if reverse {
+            nodes.reverse();
+        }
+        let indices: Vec<NodeIndex> = nodes
+            .into_iter()
+            .map(|node| network.graph.add_node(node))
+            .collect();
+        let bus = |id: usize| {
+            indices
+                .iter()
+                .copied()
+                .find(|&index| matches!(&network.graph[index], Node::Bus(b) if b.id == BusId(id)))
+                .unwrap()
+        };
+        let (a, b) = if reverse {
+            (bus(1), bus(0))
+        } else {
+            (bus(0), bus(1))
+        };
+        network.graph.add_edge(
+            a,
+            b,
+            Edge::Branch(Branch {
+                id: BranchId(0),
+                name: "Branch 0-1".to_string(),
+                from_bus: BusId(0),
+                to_bus: BusId(1),
+                reactance: 0.1,
+                ..Branch::default()
+            }),
+        );
+        network
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_insertion_order() {
+        let forward = fingerprint_network(false, 50.0);
+        let reversed = fingerprint_network(true, 50.0);
+        // Same elements, different NodeIndex assignment
+        assert!(matches!(forward.graph[NodeIndex::new(0)], Node::Bus(_)));
+        assert!(matches!(reversed.graph[NodeIndex::new(0)], Node::Load(_)));
+        assert_eq!(forward.fingerprint(), reversed.fingerprint());
+        assert_eq!(forward.fingerprint(), forward.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_any_parameter() {
+        let base = fingerprint_network(false, 50.0).fingerprint();
+        assert_ne!(fingerprint_network(false, 50.5).fingerprint(), base);
+
+        let mut network = fingerprint_network(false, 50.0);
+        for edge in network.graph.edge_weights_mut() {
+            if let Edge::Branch(branch) = edge {
+                branch.resistance = 0.01;
+            }
+        }
+        assert_ne!(network.fingerprint(), base);
+
+        let mut network = fingerprint_network(false, 50.0);
+        for node in network.graph.node_weights_mut() {
+            if let Node::Gen(gen) = node {
+                gen.emissions_rate = Some(0.5);
+            }
+        }
+        assert_ne!(network.fingerprint(), base);
+
+        // Reconnecting the line to a different bus is a topology change
+        let mut network = fingerprint_network(false, 50.0);
+        let extra = network.graph.add_node(Node::Bus(Bus {
+            id: BusId(2),
+            name: "Bus 2".to_string(),
+            ..Bus::default()
+        }));
+        let with_bus = network.fingerprint();
+        let (edge, (from, _)) = network
+            .graph
+            .edge_indices()
+            .map(|e| (e, network.graph.edge_endpoints(e).unwrap()))
+            .next()
+            .unwrap();
+        let branch = network.graph.remove_edge(edge).unwrap();
+        network.graph.add_edge(from, extra, branch);
+        assert_ne!(network.fingerprint(), with_bus);
+    }
+
+    #[test]
+    fn test_stats_by_area() {
+        let mut network = Network::new();
+        let mut buses = Vec::new();
+        for (id, area) in [(0, Some(1)), (1, Some(1)), (2, Some(2)), (3, None)] {
+            buses.push(network.graph.add_node(Node::Bus(Bus {
+                id: BusId(id),
+                name: format!("Bus {}", id),
+                area_id: area,
+                ..Bus::default()
+            })));
+        }
+        for (id, bus, pmax) in [(0, 0, 100.0), (1, 2, 250.0), (2, 2, 50.0)] {
+            let mut gen = Gen::new(GenId::new(id), format!("Gen {}", id), BusId(bus));
+            gen.pmax = Megawatts(pmax);
+            network.graph.add_node(Node::Gen(gen));
+        }
+        for (id, bus, p) in [(0, 0, 30.0), (1, 1, 45.0), (2, 2, 120.0), (3, 3, 5.0)] {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(id),
+                name: format!("Load {}", id),
+                bus: BusId(bus),
+                active_power: Megawatts(p),
+                reactive_power: Megavars(p / 10.0),
+                zip: None,
+            }));
+        }
+        for (id, from, to) in [(0, 0, 1), (1, 1, 2), (2, 2, 3)] {
+            network.graph.add_edge(
+                buses[from],
+                buses[to],
+                Edge::Branch(Branch {
+                    id: BranchId(id),
+                    name: format!("Branch {}-{}", from, to),
+                    from_bus: BusId(from),
+                    to_bus: BusId(to),
+                    reactance: 0.1,
+                    ..Branch::default()
+                }),
+            );
+        }
+
+        let by_area = network.stats_by_area();
+        assert_eq!(by_area.len(), 3);
+
+        let area1 = &by_area[&1];
+        assert_eq!(
+            (area1.num_buses, area1.num_gens, area1.num_loads),
+            (2, 1, 2)
+        );
+        assert!((area1.total_load_mw - 75.0).abs() < 1e-9);
+        assert!((area1.total_gen_capacity_mw - 100.0).abs() < 1e-9);
+        // Line 0-1 is internal and tie line 1-2 counts toward its from-bus area
+        assert_eq!(area1.num_branches, 2);
+
+        let area2 = &by_area[&2];
+        assert_eq!(
+            (area2.num_buses, area2.num_gens, area2.num_loads),
+            (1, 2, 1)
+        );
+        assert!((area2.total_load_mw - 120.0).abs() < 1e-9);
+        assert!((area2.total_gen_capacity_mw - 300.0).abs() < 1e-9);
+
+        let unassigned = &by_area[&UNASSIGNED_AREA];
+        assert_eq!((unassigned.num_buses, unassigned.num_loads), (1, 1));
+        assert!((unassigned.total_load_mw - 5.0).abs() < 1e-9);
+
+        // Areas partition the system totals
+        let total = network.stats();
+        let load_sum: f64 = by_area.values().map(|s| s.total_load_mw).sum();
+        assert!((load_sum - total.total_load_mw).abs() < 1e-9);
+        let branch_sum: usize = by_area.values().map(|s| s.num_branches).sum();
+        assert_eq!(branch_sum, total.num_branches);
+    }
+
     #[test]
     fn test_synchronous_condenser_flag() {
         // Test that synchronous condenser flag can be set
@@ -1009,6 +1989,7 @@ mod tests {
             bus: BusId::new(1),
             active_power: Megawatts(100.0),
             reactive_power: Megavars(20.0),
+            zip: None,
         }));
 
         assert!((network.total_load_mw() - 100.0).abs() < 0.01);
@@ -1038,6 +2019,7 @@ mod tests {
             bus: BusId::new(1),
             active_power: Megawatts(100.0),
             reactive_power: Megavars(20.0),
+            zip: None,
         }));
 
         // Reserve margin = (capacity - load) / load = (150 - 100) / 100 = 0.5
@@ -1072,6 +2054,7 @@ mod tests {
             bus: BusId::new(1),
             active_power: Megawatts(50.0),
             reactive_power: Megavars(10.0),
+            zip: None,
         }));
         network.graph.add_node(Node::Load(Load {
             id: LoadId::new(2),
@@ -1079,6 +2062,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: Megawatts(30.0),
             reactive_power: Megavars(5.0),
+            zip: None,
         }));
 
         let loads_at_bus1 = network.loads_at_bus(BusId::new(1));
@@ -1106,4 +2090,227 @@ mod tests {
         assert_eq!(network.generators().len(), 1);
         assert_eq!(network.branches().len(), 1);
     }
+
+    #[test]
+    fn test_power_factor_sets_reactive_demand() {
+        let load = Load {
+            id: LoadId::new(1),
+            name: "Load1".into(),
+            bus: BusId::new(1),
+            active_power: Megawatts(100.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        };
+        let lagging = load
+            .clone()
+            .with_power_factor(0.95, PowerFactorSense::Lagging)
+            .unwrap();
+        assert!((lagging.reactive_power.value() - 32.868).abs() < 1e-3);
+        assert_eq!(lagging.active_power, Megawatts(100.0));
+        let leading = load
+            .clone()
+            .with_power_factor(0.95, PowerFactorSense::Leading)
+            .unwrap();
+        assert!((leading.reactive_power.value() + 32.868).abs() < 1e-3);
+        assert!(load
+            .clone()
+            .with_power_factor(1.2, PowerFactorSense::Lagging)
+            .is_err());
+
+        let mut network = Network::new();
+        network.graph.add_node(Node::Load(lagging));
+        assert_eq!(network.scale_reactive(2.0).unwrap(), 1);
+        assert!(network
+            .loads_at_bus(BusId::new(1))
+            .iter()
+            .all(|l| (l.reactive_power.value() - 65.736).abs() < 1e-2
+                && l.active_power == Megawatts(100.0)));
+    }
+
+    #[test]
+    fn test_sorted_accessors_follow_id_order() {
+        let mut network = Network::new();
+        for i in [3, 1, 2] {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("Bus {}", i),
+                ..Bus::default()
+            }));
+        }
+        for i in [2, 1] {
+            network.graph.add_node(Node::Gen(Gen::new(
+                GenId::new(i),
+                format!("Gen{}", i),
+                BusId::new(i),
+            )));
+        }
+        for (id, from, to) in [(5, 2, 3), (4, 1, 2)] {
+            network
+                .add_branch_checked(Branch::new(
+                    BranchId::new(id),
+                    format!("Line {}-{}", from, to),
+                    BusId::new(from),
+                    BusId::new(to),
+                    0.01,
+                    0.1,
+                ))
+                .unwrap();
+        }
+
+        let bus_ids: Vec<usize> = network
+            .buses_sorted()
+            .iter()
+            .map(|b| b.id.value())
+            .collect();
+        let gen_ids: Vec<usize> = network
+            .generators_sorted()
+            .iter()
+            .map(|g| g.id.value())
+            .collect();
+        let branch_ids: Vec<usize> = network
+            .branches_sorted()
+            .iter()
+            .map(|b| b.id.value())
+            .collect();
+        assert_eq!(bus_ids, vec![1, 2, 3]);
+        assert_eq!(gen_ids, vec![1, 2]);
+        assert_eq!(branch_ids, vec![4, 5]);
+        assert_eq!(network.buses()[0].id, BusId::new(3));
+    }
+
+    fn two_bus_network() -> Network {
+        let mut network = Network::new();
+        for i in 1..=2 {
+            network.graph.add_node(Node::Bus(Bus {
+                id: BusId::new(i),
+                name: format!("Bus {}", i),
+                ..Bus::default()
+            }));
+        }
+        network
+            .add_branch_checked(Branch::new(
+                BranchId::new(1),
+                "Line 1-2".into(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            ))
+            .unwrap();
+        network
+    }
+
+    #[test]
+    fn test_set_branch_status_toggles_service() {
+        let mut network = two_bus_network();
+        network.set_branch_status(BranchId::new(1), false).unwrap();
+        assert!(network.in_service_branches().is_empty());
+        assert_eq!(network.branches().len(), 1);
+
+        network.set_branch_status(BranchId::new(1), true).unwrap();
+        assert_eq!(network.in_service_branches().len(), 1);
+
+        assert!(matches!(
+            network.set_branch_status(BranchId::new(9), false),
+            Err(GatError::Network(_))
+        ));
+    }
+
+    #[test]
+    fn test_add_and_remove_branch_checked() {
+        let mut network = two_bus_network();
+        let parallel = |id| {
+            Branch::new(
+                BranchId::new(id),
+                "Line 1-2 b".into(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )
+        };
+        // Duplicate ID and unknown end bus are rejected without changing the graph
+        assert!(network.add_branch_checked(parallel(1)).is_err());
+        let mut stray = parallel(2);
+        stray.to_bus = BusId::new(7);
+        assert!(network.add_branch_checked(stray).is_err());
+        assert_eq!(network.graph.edge_count(), 1);
+
+        network.add_branch_checked(parallel(2)).unwrap();
+        let removed = network.remove_branch(BranchId::new(1)).unwrap();
+        assert_eq!(removed.name, "Line 1-2");
+        // The remaining branch is still found after edge indices shift
+        let branches = network.branches();
+        assert_eq!(branches.len(), 1);
+        assert_eq!(branches[0].id, BranchId::new(2));
+        assert!(network.set_branch_status(BranchId::new(2), false).is_ok());
+        assert!(network.remove_branch(BranchId::new(1)).is_err());
+    }
+
+    #[test]
+    fn test_select_slack_per_island() {
+        // Buses 1-2 joined, bus 3 on its own without generation
+        let mut network = two_bus_network();
+        network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(3),
+            name: "Bus 3".into(),
+            ..Bus::default()
+        }));
+        for (id, bus, pmax) in [(1, 1, 50.0), (2, 2, 200.0)] {
+            network.graph.add_node(Node::Gen(
+                Gen::new(GenId::new(id), format!("Gen {}", id), BusId::new(bus))
+                    .with_p_limits(0.0, pmax),
+            ));
+        }
+        fn ids(network: &Network, strategy: SlackStrategy) -> Vec<usize> {
+            network
+                .select_slack(strategy)
+                .unwrap()
+                .iter()
+                .map(|id| id.value())
+                .collect()
+        }
+
+        assert_eq!(ids(&network, SlackStrategy::LargestGen), vec![2, 3]);
+        assert_eq!(ids(&network, SlackStrategy::LowestId), vec![1, 3]);
+        assert_eq!(
+            ids(&network, SlackStrategy::Specified(BusId::new(1))),
+            vec![1, 3]
+        );
+        assert!(network
+            .select_slack(SlackStrategy::Specified(BusId::new(9)))
+            .is_err());
+
+        // Opening the line leaves three islands
+        network.set_branch_status(BranchId::new(1), false).unwrap();
+        assert_eq!(ids(&network, SlackStrategy::LargestGen), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_startup_type_follows_downtime() {
+        let gen = Gen::new(GenId(1), "steam".to_string(), BusId(1)).with_startup_costs(
+            StartupCosts::new((8.0, 1_000.0), (48.0, 2_500.0), 6_000.0).unwrap(),
+        );
+
+        // Online at the start, off for 4 h, back on, then off for 72 h
+        let mut on = vec![true, false, false, false, false, true];
+        on.extend([false; 72]);
+        on.push(true);
+        let starts = gen.startups(&on, 1.0, 0.0);
+
+        assert_eq!(starts.len(), 2);
+        assert_eq!(starts[0].period, 5);
+        assert_eq!(starts[0].kind, StartupType::Hot);
+        assert_eq!(starts[0].cost, 1_000.0);
+        assert_eq!(starts[1].downtime_hours, 72.0);
+        assert_eq!(starts[1].kind, StartupType::Cold);
+        assert_eq!(starts[1].cost, 6_000.0);
+
+        // A unit down for a day before the horizon makes a warm start in period 0
+        let starts = gen.startups(&[true, true], 1.0, 24.0);
+        assert_eq!(starts.len(), 1);
+        assert_eq!(starts[0].kind, StartupType::Warm);
+
+        assert!(StartupCosts::new((48.0, 1.0), (8.0, 2.0), 3.0).is_err());
+    }
 }
@@ -0,0 +1,227 @@
+//! Dynamic line ratings from ambient conditions.
+//!
+//! Static thermal ratings assume conservative weather (hot, nearly still
+//! air). [`Branch::dynamic_rating`] rescales a branch's rating for the actual
+//! ambient temperature and wind speed with a steady-state heat balance in the
+//! style of IEEE 738: at the maximum conductor temperature, Joule heating
+//! `I²R` equals convective plus radiative cooling, so the allowable current
+//! (and MVA at fixed voltage) scales with the square root of the cooling.
+//!
+//! The conductor is a generic 28 mm ACSR (Drake) with emissivity 0.8. Solar
+//! heating and the temperature dependence of air properties are ignored; both
+//! enter the static and dynamic cases alike. Design conditions reproduce the
+//! static rating exactly, and branches without conditions keep it.
+//!
+//! [`Network::apply_deratings`] scales ratings by a fixed factor instead, for
+//! planned reductions such as one circuit of a double line out of service.
+
+use crate::{Branch, BranchId, Edge, GatError, GatResult, MegavoltAmperes, Network};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Maximum allowable conductor temperature (°C)
+const MAX_CONDUCTOR_TEMP_C: f64 = 75.0;
+/// Ambient temperature assumed by static ratings (°C)
+const DESIGN_AMBIENT_C: f64 = 40.0;
+/// Wind speed assumed by static ratings (m/s, 2 ft/s perpendicular)
+const DESIGN_WIND_MS: f64 = 0.61;
+/// Conductor outside diameter (m)
+const CONDUCTOR_DIAMETER_M: f64 = 0.0281;
+/// Conductor surface emissivity
+const EMISSIVITY: f64 = 0.8;
+/// Air density at the film temperature (kg/m³)
+const AIR_DENSITY: f64 = 1.09;
+/// Dynamic viscosity of air (kg/m·s)
+const AIR_VISCOSITY: f64 = 1.95e-5;
+/// Thermal conductivity of air (W/m·°C)
+const AIR_CONDUCTIVITY: f64 = 0.028;
+
+/// Weather at a branch, used to compute its dynamic rating.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AmbientConditions {
+    /// Ambient air temperature (°C)
+    pub temperature_c: f64,
+    /// Wind speed perpendicular to the conductor (m/s)
+    pub wind_speed_ms: f64,
+}
+
+impl AmbientConditions {
+    pub fn new(temperature_c: f64, wind_speed_ms: f64) -> Self {
+        Self {
+            temperature_c,
+            wind_speed_ms,
+        }
+    }
+
+    /// Conditions assumed by static ratings: 40 °C, 0.61 m/s wind.
+    pub fn design() -> Self {
+        Self::new(DESIGN_AMBIENT_C, DESIGN_WIND_MS)
+    }
+
+    /// Ratio of the dynamic to the static rating under these conditions.
+    ///
+    /// Greater than 1 when cooler or windier than design, 0 when the ambient
+    /// temperature reaches the maximum conductor temperature.
+    pub fn rating_factor(&self) -> f64 {
+        (heat_loss(self) / heat_loss(&Self::design())).sqrt()
+    }
+}
+
+impl Default for AmbientConditions {
+    fn default() -> Self {
+        Self::design()
+    }
+}
+
+/// Convective plus radiative cooling per metre of conductor at the maximum
+/// conductor temperature (W/m)
+fn heat_loss(conditions: &AmbientConditions) -> f64 {
+    let delta_t = MAX_CONDUCTOR_TEMP_C - conditions.temperature_c;
+    if delta_t <= 0.0 {
+        return 0.0;
+    }
+    let wind = conditions.wind_speed_ms.max(0.0);
+
+    let reynolds = CONDUCTOR_DIAMETER_M * wind * AIR_DENSITY / AIR_VISCOSITY;
+    let forced_low = (1.01 + 1.35 * reynolds.powf(0.52)) * AIR_CONDUCTIVITY * delta_t;
+    let forced_high = 0.754 * reynolds.powf(0.6) * AIR_CONDUCTIVITY * delta_t;
+    let natural = 3.645 * AIR_DENSITY.sqrt() * CONDUCTOR_DIAMETER_M.powf(0.75) * delta_t.powf(1.25);
+    let convective = forced_low.max(forced_high).max(natural);
+
+    let kelvin = |celsius: f64| (celsius + 273.0) / 100.0;
+    let radiative = 17.8
+        * CONDUCTOR_DIAMETER_M
+        * EMISSIVITY
+        * (kelvin(MAX_CONDUCTOR_TEMP_C).powi(4) - kelvin(conditions.temperature_c).powi(4));
+
+    convective + radiative
+}
+
+impl Branch {
+    /// Thermal rating under `conditions`, or the static rating when `None`.
+    ///
+    /// The static rating is `s_max`, falling back to `rating_a`; a branch
+    /// with neither stays unlimited.
+    pub fn dynamic_rating(
+        &self,
+        conditions: Option<&AmbientConditions>,
+    ) -> Option<MegavoltAmperes> {
+        let rating = self.s_max.or(self.rating_a)?;
+        Some(match conditions {
+            Some(conditions) => MegavoltAmperes(rating.value() * conditions.rating_factor()),
+            None => rating,
+        })
+    }
+}
+
+impl Network {
+    /// Replace static ratings with dynamic ones for branches named in
+    /// `conditions`.
+    ///
+    /// `s_max` and Rate A/B/C are all scaled by the branch's rating factor,
+    /// so OPF and contingency analysis on the result use the dynamic limits.
+    /// Branches without conditions keep their static ratings. Returns the
+    /// number of branches re-rated.
+    pub fn apply_dynamic_ratings(
+        &mut self,
+        conditions: &HashMap<String, AmbientConditions>,
+    ) -> usize {
+        let mut rerated = 0;
+        for edge in self.graph.edge_weights_mut() {
+            let Edge::Branch(branch) = edge else {
+                continue;
+            };
+            let Some(ambient) = conditions.get(&branch.name) else {
+                continue;
+            };
+            scale_ratings(branch, ambient.rating_factor());
+            rerated += 1;
+        }
+        rerated
+    }
+
+    /// Multiply the ratings of the branches in `factors` by their factor.
+    ///
+    /// `s_max` and Rate A/B/C are all scaled, e.g. 0.5 for one of two
+    /// circuits out. Apply it to a copy of the network to study a derate
+    /// without changing the stored case. Factors must be finite and
+    /// non-negative. Returns the number of branches derated.
+    pub fn apply_deratings(&mut self, factors: &HashMap<BranchId, f64>) -> GatResult<usize> {
+        if let Some((id, factor)) = factors
+            .iter()
+            .find(|(_, factor)| !factor.is_finite() || **factor < 0.0)
+        {
+            return Err(GatError::Validation(format!(
+                "derating factor {} for branch {} must be finite and non-negative",
+                factor,
+                id.value()
+            )));
+        }
+        let mut derated = 0;
+        for edge in self.graph.edge_weights_mut() {
+            let Edge::Branch(branch) = edge else {
+                continue;
+            };
+            if let Some(&factor) = factors.get(&branch.id) {
+                scale_ratings(branch, factor);
+                derated += 1;
+            }
+        }
+        Ok(derated)
+    }
+}
+
+fn scale_ratings(branch: &mut Branch, factor: f64) {
+    for rating in [
+        &mut branch.s_max,
+        &mut branch.rating_a,
+        &mut branch.rating_b,
+        &mut branch.rating_c,
+    ] {
+        if let Some(value) = rating {
+            *value = MegavoltAmperes(value.value() * factor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BranchId, BusId};
+
+    fn rated_branch(mva: f64) -> Branch {
+        Branch::new(
+            BranchId::new(0),
+            "line".into(),
+            BusId::new(0),
+            BusId::new(1),
+            0.01,
+            0.1,
+        )
+        .with_s_max(Some(mva))
+    }
+
+    #[test]
+    fn test_design_conditions_keep_static_rating() {
+        let branch = rated_branch(100.0);
+        let rating = branch
+            .dynamic_rating(Some(&AmbientConditions::design()))
+            .unwrap();
+        assert!((rating.value() - 100.0).abs() < 1e-9);
+        assert_eq!(branch.dynamic_rating(None), Some(MegavoltAmperes(100.0)));
+    }
+
+    #[test]
+    fn test_cool_windy_conditions_raise_rating() {
+        let branch = rated_branch(100.0);
+        let cool_windy = branch
+            .dynamic_rating(Some(&AmbientConditions::new(10.0, 5.0)))
+            .unwrap();
+        let hot_still = branch
+            .dynamic_rating(Some(&AmbientConditions::new(45.0, 0.0)))
+            .unwrap();
+        assert!(cool_windy.value() > 150.0, "got {}", cool_windy.value());
+        assert!(hot_still.value() < 100.0, "got {}", hot_still.value());
+        assert_eq!(AmbientConditions::new(80.0, 5.0).rating_factor(), 0.0);
+    }
+}
@@ -0,0 +1,287 @@
+//! Opt-in repair of imports that mix SI and per-unit quantities.
+//!
+//! The network model stores branch impedances in per-unit on the system base and
+//! powers in MW/Mvar. Hand-assembled or converted data sometimes carries ohmic
+//! impedances or per-unit powers instead. [`normalize_units`] looks for values
+//! that are implausible in the expected unit and rescales them:
+//!
+//! - **Impedance:** a branch with |r| or |x| above [`IMPEDANCE_PU_LIMIT`] is read as
+//!   ohms and divided by `Z_base = kV² / base_mva` of its terminal buses. Branches
+//!   whose terminals sit at different voltages, or lack a base voltage, are left
+//!   alone with a warning since the reference side is unknown.
+//! - **Power:** on a transmission network (some bus at or above
+//!   [`TRANSMISSION_KV`]) where no load exceeds [`PU_POWER_LIMIT`], loads and
+//!   generator outputs/limits are read as per-unit and multiplied by `base_mva`.
+//!
+//! Every conversion and every suspicious value left unchanged is recorded as a
+//! warning in category `"units"`, so the caller can show the user what changed.
+
+use crate::diagnostics::Diagnostics;
+use crate::{Edge, GatError, GatResult, Megavars, Megawatts, Network, Node};
+use std::collections::HashMap;
+
+/// Largest branch |r| or |x| still treated as per-unit.
+pub const IMPEDANCE_PU_LIMIT: f64 = 5.0;
+/// Largest load |P| or |Q| that reads as per-unit on a transmission network.
+pub const PU_POWER_LIMIT: f64 = 10.0;
+/// Base voltage (kV) from which a network counts as transmission.
+pub const TRANSMISSION_KV: f64 = 100.0;
+
+const CATEGORY: &str = "units";
+
+/// Detect and convert SI values in `network` to the model's units.
+///
+/// Returns one warning per converted element (and per suspicious element left
+/// unchanged); an empty result means nothing looked out of place.
+pub fn normalize_units(network: &mut Network, base_mva: f64) -> GatResult<Diagnostics> {
+    if !(base_mva.is_finite() && base_mva > 0.0) {
+        return Err(GatError::Config(format!(
+            "base MVA must be positive, got {}",
+            base_mva
+        )));
+    }
+    let mut diag = Diagnostics::new();
+    normalize_impedances(network, base_mva, &mut diag);
+    normalize_powers(network, base_mva, &mut diag);
+    Ok(diag)
+}
+
+fn normalize_impedances(network: &mut Network, base_mva: f64, diag: &mut Diagnostics) {
+    let base_kv: HashMap<_, _> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => Some((bus.id, bus.base_kv.value())),
+            _ => None,
+        })
+        .collect();
+
+    for edge in network.graph.edge_weights_mut() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        if branch.resistance.abs() <= IMPEDANCE_PU_LIMIT
+            && branch.reactance.abs() <= IMPEDANCE_PU_LIMIT
+        {
+            continue;
+        }
+        let entity = format!("Branch {}", branch.name);
+        let from_kv = base_kv.get(&branch.from_bus).copied().unwrap_or(0.0);
+        let to_kv = base_kv.get(&branch.to_bus).copied().unwrap_or(0.0);
+        if from_kv <= 0.0 || (from_kv - to_kv).abs() > 1e-6 * from_kv {
+            diag.add_warning_with_entity(
+                CATEGORY,
+                &format!(
+                    "impedance r={} x={} looks ohmic but terminal base voltages ({} kV, {} kV) \
+                     give no single base; left unchanged",
+                    branch.resistance, branch.reactance, from_kv, to_kv
+                ),
+                &entity,
+            );
+            continue;
+        }
+
+        let z_base = from_kv * from_kv / base_mva;
+        let (r_ohm, x_ohm) = (branch.resistance, branch.reactance);
+        branch.resistance /= z_base;
+        branch.reactance /= z_base;
+        diag.add_warning_with_entity(
+            CATEGORY,
+            &format!(
+                "converted impedance from ohms (r={} Ω, x={} Ω) to per-unit (r={:.6}, x={:.6}) \
+                 on Z_base={:.3} Ω",
+                r_ohm, x_ohm, branch.resistance, branch.reactance, z_base
+            ),
+            &entity,
+        );
+    }
+}
+
+fn normalize_powers(network: &mut Network, base_mva: f64, diag: &mut Diagnostics) {
+    let mut max_kv: f64 = 0.0;
+    let mut max_load: f64 = 0.0;
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Bus(bus) => max_kv = max_kv.max(bus.base_kv.value()),
+            Node::Load(load) => {
+                max_load = max_load
+                    .max(load.active_power.value().abs())
+                    .max(load.reactive_power.value().abs())
+            }
+            _ => {}
+        }
+    }
+    if max_kv < TRANSMISSION_KV || max_load == 0.0 || max_load > PU_POWER_LIMIT {
+        return;
+    }
+
+    let scale = |value: f64| {
+        if value.is_finite() {
+            value * base_mva
+        } else {
+            value
+        }
+    };
+    for node in network.graph.node_weights_mut() {
+        match node {
+            Node::Load(load) => {
+                let (p, q) = (load.active_power.value(), load.reactive_power.value());
+                load.active_power = Megawatts(scale(p));
+                load.reactive_power = Megavars(scale(q));
+                diag.add_warning_with_entity(
+                    CATEGORY,
+                    &format!(
+                        "converted load from per-unit ({} + j{}) to {} MW + j{} Mvar",
+                        p,
+                        q,
+                        load.active_power.value(),
+                        load.reactive_power.value()
+                    ),
+                    &format!("Load {}", load.name),
+                );
+            }
+            Node::Gen(gen) => {
+                gen.active_power = Megawatts(scale(gen.active_power.value()));
+                gen.reactive_power = Megavars(scale(gen.reactive_power.value()));
+                gen.pmin = Megawatts(scale(gen.pmin.value()));
+                gen.pmax = Megawatts(scale(gen.pmax.value()));
+                gen.qmin = Megavars(scale(gen.qmin.value()));
+                gen.qmax = Megavars(scale(gen.qmax.value()));
+                gen.p_available = gen.p_available.map(|p| Megawatts(scale(p.value())));
+                diag.add_warning_with_entity(
+                    CATEGORY,
+                    &format!(
+                        "converted generator output and limits from per-unit (x{} MVA)",
+                        base_mva
+                    ),
+                    &format!("Gen {}", gen.name),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Branch, BranchId, Bus, BusId, Gen, GenId, Kilovolts, Load, LoadId};
+
+    fn two_bus(base_kv: f64, r: f64, x: f64) -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=2)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: Kilovolts(base_kv),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        network.graph.add_edge(
+            buses[0],
+            buses[1],
+            Edge::Branch(Branch::new(
+                BranchId::new(0),
+                "line".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                r,
+                x,
+            )),
+        );
+        network
+    }
+
+    fn branch(network: &Network) -> &Branch {
+        network
+            .graph
+            .edge_weights()
+            .find_map(|edge| match edge {
+                Edge::Branch(branch) => Some(branch),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ohmic_impedance_converted_to_per_unit() {
+        // 230 kV on 100 MVA: Z_base = 529 Ω
+        let mut network = two_bus(230.0, 5.29, 52.9);
+        let diag = normalize_units(&mut network, 100.0).unwrap();
+
+        let line = branch(&network);
+        assert!((line.resistance - 0.01).abs() < 1e-12);
+        assert!((line.reactance - 0.1).abs() < 1e-12);
+        assert_eq!(diag.warning_count(), 1);
+        let issue = &diag.issues[0];
+        assert_eq!(issue.category, "units");
+        assert_eq!(issue.entity.as_deref(), Some("Branch line"));
+        assert!(issue.message.contains("ohms"), "{}", issue.message);
+    }
+
+    #[test]
+    fn test_per_unit_data_untouched() {
+        let mut network = two_bus(230.0, 0.01, 0.1);
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(0),
+            name: "load".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(90.0),
+            reactive_power: Megavars(30.0),
+            zip: None,
+        }));
+        let diag = normalize_units(&mut network, 100.0).unwrap();
+        assert!(!diag.has_issues());
+        assert_eq!(branch(&network).reactance, 0.1);
+    }
+
+    #[test]
+    fn test_per_unit_power_scaled_on_transmission_network() {
+        let mut network = two_bus(230.0, 0.01, 0.1);
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(0),
+            name: "load".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(0.9),
+            reactive_power: Megavars(0.3),
+            zip: None,
+        }));
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(0), "gen".to_string(), BusId::new(1)).with_p_limits(0.0, 2.0),
+        ));
+        let diag = normalize_units(&mut network, 100.0).unwrap();
+        assert_eq!(diag.warning_count(), 2);
+
+        for node in network.graph.node_weights() {
+            match node {
+                Node::Load(load) => assert!((load.active_power.value() - 90.0).abs() < 1e-9),
+                Node::Gen(gen) => assert!((gen.pmax.value() - 200.0).abs() < 1e-9),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_transformer_with_ohmic_values_is_reported_not_converted() {
+        let mut network = two_bus(230.0, 5.29, 52.9);
+        for node in network.graph.node_weights_mut() {
+            if let Node::Bus(bus) = node {
+                if bus.id == BusId::new(2) {
+                    bus.base_kv = Kilovolts(115.0);
+                }
+            }
+        }
+        let diag = normalize_units(&mut network, 100.0).unwrap();
+        assert_eq!(diag.warning_count(), 1);
+        assert!(diag.issues[0].message.contains("left unchanged"));
+        assert_eq!(branch(&network).reactance, 52.9);
+    }
+
+    #[test]
+    fn test_rejects_non_positive_base() {
+        let mut network = two_bus(230.0, 0.01, 0.1);
+        assert!(normalize_units(&mut network, 0.0).is_err());
+    }
+}
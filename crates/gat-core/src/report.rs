@@ -0,0 +1,138 @@
+//! Human-readable multi-section network summary.
+//!
+//! [`Network::report`] expands the one-line [`NetworkStats`] display into a
+//! report for logs and notebooks: element counts, voltage levels, areas,
+//! generation by fuel, the largest loads and validation findings. Every
+//! section is sorted, so two reports of the same network are identical and
+//! reports of two revisions diff cleanly.
+
+use crate::{Diagnostics, Network, NetworkStats, Node, UNASSIGNED_AREA};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Number of loads listed under "Largest loads"
+const TOP_LOADS: usize = 5;
+
+impl Network {
+    /// Multi-section summary: counts, voltage levels, areas, generation by
+    /// fuel (when any unit is tagged), the largest loads and validation issues.
+    /// Sections are sorted, so the output is deterministic.
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        self.write_report(&mut out)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    fn write_report(&self, out: &mut String) -> std::fmt::Result {
+        let stats = self.stats();
+        writeln!(out, "Network summary")?;
+        writeln!(out, "  Buses:      {}", stats.num_buses)?;
+        writeln!(out, "  Branches:   {}", stats.num_branches)?;
+        writeln!(
+            out,
+            "  Generators: {} ({:.1} MW capacity)",
+            stats.num_gens, stats.total_gen_capacity_mw
+        )?;
+        writeln!(
+            out,
+            "  Loads:      {} ({:.1} MW, {:.1} Mvar)",
+            stats.num_loads, stats.total_load_mw, stats.total_load_mvar
+        )?;
+        if stats.embedded_gen_mw > 0.0 {
+            writeln!(
+                out,
+                "              gross {:.1} MW, embedded generation {:.1} MW",
+                stats.gross_load_mw, stats.embedded_gen_mw
+            )?;
+        }
+        writeln!(out, "  Shunts:     {}", stats.num_shunts)?;
+
+        // Voltage levels, highest first; keyed in mV so f64 kV sorts exactly
+        let mut levels: BTreeMap<i64, usize> = BTreeMap::new();
+        for bus in self.buses() {
+            *levels
+                .entry((bus.base_kv.value() * 1000.0).round() as i64)
+                .or_default() += 1;
+        }
+        writeln!(out, "\nVoltage levels")?;
+        for (millivolts, count) in levels.iter().rev() {
+            writeln!(
+                out,
+                "  {:>8.1} kV: {} buses",
+                *millivolts as f64 / 1000.0,
+                count
+            )?;
+        }
+
+        let areas: BTreeMap<i64, NetworkStats> = self.stats_by_area().into_iter().collect();
+        if areas.len() > 1 || !areas.contains_key(&UNASSIGNED_AREA) {
+            writeln!(out, "\nAreas")?;
+            for (area, area_stats) in &areas {
+                if *area == UNASSIGNED_AREA {
+                    continue;
+                }
+                writeln!(out, "  Area {}: {}", area, area_stats)?;
+            }
+            if let Some(unassigned) = areas.get(&UNASSIGNED_AREA) {
+                writeln!(out, "  Unassigned: {}", unassigned)?;
+            }
+        }
+
+        let mut by_fuel: BTreeMap<&str, (usize, f64)> = BTreeMap::new();
+        for gen in self.generators() {
+            let entry = by_fuel
+                .entry(gen.fuel.as_deref().unwrap_or("untagged"))
+                .or_default();
+            entry.0 += 1;
+            entry.1 += gen.pmax.value();
+        }
+        if self.generators().iter().any(|gen| gen.fuel.is_some()) {
+            writeln!(out, "\nGeneration by fuel")?;
+            for (fuel, (count, capacity)) in &by_fuel {
+                writeln!(out, "  {}: {} units, {:.1} MW", fuel, count, capacity)?;
+            }
+        }
+
+        let mut loads: Vec<_> = self
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Load(load) => Some(load),
+                _ => None,
+            })
+            .collect();
+        loads.sort_by(|a, b| {
+            b.active_power
+                .value()
+                .total_cmp(&a.active_power.value())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        if !loads.is_empty() {
+            writeln!(out, "\nLargest loads")?;
+            for load in loads.iter().take(TOP_LOADS) {
+                writeln!(
+                    out,
+                    "  {} (bus {}): {:.1} MW, {:.1} Mvar",
+                    load.name,
+                    load.bus.value(),
+                    load.active_power.value(),
+                    load.reactive_power.value()
+                )?;
+            }
+        }
+
+        let mut diag = Diagnostics::new();
+        self.validate_into(&mut diag);
+        let mut issues: Vec<String> = diag.issues.iter().map(|issue| issue.to_string()).collect();
+        issues.sort();
+        writeln!(out, "\nValidation")?;
+        if issues.is_empty() {
+            writeln!(out, "  no issues")?;
+        }
+        for issue in issues {
+            writeln!(out, "  {}", issue)?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 pub mod backend;
 pub mod registry;
+pub mod ybus;
 
 pub use backend::*;
 pub use registry::*;
+pub use ybus::{build_ybus, SparseMatrix};
@@ -0,0 +1,184 @@
+//! Bus admittance (Y-bus) matrix construction.
+//!
+//! The Y-bus relates complex bus current injections to bus voltages, `I = Y·V`, with all
+//! quantities in per-unit on the system MVA base. Conventions follow MATPOWER's `makeYbus`:
+//!
+//! - Each in-service branch is a π model with series admittance `y_s = 1 / (r + jx)` and total
+//!   line charging `b`, split as `jb/2` at either end.
+//! - The off-nominal transformer sits on the from side with complex ratio
+//!   `t = τ·e^{jφ}` (`τ = tap_ratio`, treated as 1.0 when zero; `φ = phase_shift`):
+//!
+//! ```text
+//! Y_ff += (y_s + jb/2) / τ²      Y_ft += −y_s / conj(t)
+//! Y_tt += (y_s + jb/2)           Y_tf += −y_s / t
+//! ```
+//!
+//! - In-service shunts add `g_s + jb_s` to their bus diagonal (positive `b_s` is capacitive).
+//!
+//! Off-diagonal entries are therefore the negated branch admittance, and a phase shifter makes
+//! the matrix non-symmetric. Open branches, zero-impedance branches, and `Edge::Transformer`
+//! edges (which carry no impedance data) contribute nothing.
+
+use num_complex::Complex64;
+use sprs::{CsMat, TriMat};
+use std::collections::HashMap;
+
+use crate::{BusId, Edge, Network, Node};
+
+/// Complex sparse matrix in CSR format.
+pub type SparseMatrix = CsMat<Complex64>;
+
+/// Build the complex bus admittance matrix of `network`.
+///
+/// Row and column `k` of the matrix belong to the `k`-th bus of the returned ordering, which
+/// lists bus IDs in ascending order.
+pub fn build_ybus(network: &Network) -> (SparseMatrix, Vec<BusId>) {
+    let mut buses: Vec<BusId> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => Some(bus.id),
+            _ => None,
+        })
+        .collect();
+    buses.sort_by_key(|id| id.value());
+    let index: HashMap<BusId, usize> = buses.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+    let n = buses.len();
+    let mut triplets = TriMat::new((n, n));
+    for edge in network.graph.edge_weights() {
+        let Edge::Branch(branch) = edge else {
+            continue;
+        };
+        if !branch.status {
+            continue;
+        }
+        let (Some(&f), Some(&t)) = (index.get(&branch.from_bus), index.get(&branch.to_bus)) else {
+            continue;
+        };
+        let z = Complex64::new(branch.resistance, branch.reactance);
+        if z.norm_sqr() < 1e-12 {
+            continue;
+        }
+
+        let y_series = z.inv();
+        let y_charging = Complex64::new(0.0, branch.charging_b.value() / 2.0);
+        let tau = if branch.tap_ratio > 0.0 {
+            branch.tap_ratio
+        } else {
+            1.0
+        };
+        let tap = Complex64::from_polar(tau, branch.phase_shift.value());
+
+        triplets.add_triplet(f, f, (y_series + y_charging) / (tau * tau));
+        triplets.add_triplet(t, t, y_series + y_charging);
+        triplets.add_triplet(f, t, -y_series / tap.conj());
+        triplets.add_triplet(t, f, -y_series / tap);
+    }
+
+    for node in network.graph.node_weights() {
+        if let Node::Shunt(shunt) = node {
+            if let (true, Some(&i)) = (shunt.status, index.get(&shunt.bus)) {
+                triplets.add_triplet(i, i, Complex64::new(shunt.gs_pu, shunt.bs_pu));
+            }
+        }
+    }
+
+    // Duplicate triplets are summed during conversion
+    (triplets.to_csr(), buses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Branch, BranchId, Bus, PerUnit, Radians, Shunt, ShuntId};
+
+    fn two_bus(branch: Branch) -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "bus2".to_string(),
+            ..Bus::default()
+        }));
+        let b0 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "bus1".to_string(),
+            ..Bus::default()
+        }));
+        network.graph.add_edge(b0, b1, Edge::Branch(branch));
+        network
+    }
+
+    fn entry(y: &SparseMatrix, i: usize, j: usize) -> Complex64 {
+        y.get(i, j).copied().unwrap_or_default()
+    }
+
+    fn assert_close(actual: Complex64, expected: Complex64) {
+        assert!(
+            (actual - expected).norm() < 1e-12,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_line_matches_pi_model() {
+        let (r, x, b) = (0.02, 0.06, 0.03);
+        let mut network = two_bus(Branch {
+            charging_b: PerUnit(b),
+            ..Branch::new(
+                BranchId::new(0),
+                "line".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                r,
+                x,
+            )
+        });
+        network.graph.add_node(Node::Shunt(Shunt {
+            id: ShuntId::new(0),
+            name: "cap".to_string(),
+            bus: BusId::new(2),
+            gs_pu: 0.0,
+            bs_pu: 0.1,
+            status: true,
+        }));
+
+        let (y, order) = build_ybus(&network);
+        assert_eq!(order, vec![BusId::new(1), BusId::new(2)]);
+
+        // y_s = 1/(r + jx) = (r − jx)/(r² + x²)
+        let denom = r * r + x * x;
+        let y_s = Complex64::new(r / denom, -x / denom);
+        assert_close(entry(&y, 0, 0), y_s + Complex64::new(0.0, b / 2.0));
+        assert_close(entry(&y, 1, 1), y_s + Complex64::new(0.0, b / 2.0 + 0.1));
+        assert_close(entry(&y, 0, 1), -y_s);
+        assert_close(entry(&y, 1, 0), -y_s);
+    }
+
+    #[test]
+    fn test_phase_shifting_transformer() {
+        let (tau, phi) = (1.05, 0.1);
+        let network = two_bus(Branch {
+            tap_ratio: tau,
+            phase_shift: Radians(phi),
+            ..Branch::new(
+                BranchId::new(0),
+                "xfmr".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.0,
+                0.1,
+            )
+        });
+
+        let (y, _) = build_ybus(&network);
+        let y_s = Complex64::new(0.0, -10.0);
+        let t = Complex64::from_polar(tau, phi);
+        assert_close(entry(&y, 0, 0), y_s / (tau * tau));
+        assert_close(entry(&y, 1, 1), y_s);
+        assert_close(entry(&y, 0, 1), -y_s / t.conj());
+        assert_close(entry(&y, 1, 0), -y_s / t);
+        // A phase shift breaks symmetry
+        assert!((entry(&y, 0, 1) - entry(&y, 1, 0)).norm() > 1e-3);
+    }
+}
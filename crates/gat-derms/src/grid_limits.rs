@@ -0,0 +1,117 @@
+//! Thermal headroom of the grid around DER connection points.
+//!
+//! A fleet envelope built by summing asset ratings assumes the grid can absorb
+//! any aggregate injection. [`GridModel`] checks that assumption with DC power
+//! transfer distribution factors (PTDFs): starting from the base-case flows of
+//! the network's own dispatch, it finds how far an aggregate move can go before
+//! some rated branch hits its limit.
+//!
+//! The aggregate move is spread over the assets' buses in proportion to their
+//! ratings and balanced at the PTDF reference (slack) bus. DER output in the
+//! base case is taken as zero. Branches without `s_max` or `rating_a` are
+//! unconstrained; voltage limits are not captured by DC sensitivities.
+
+use anyhow::{anyhow, Context, Result};
+use gat_algo::sparse::{PtdfMatrix, SparsePtdf};
+use gat_core::{BusId, Edge, Network, Node};
+use std::collections::HashMap;
+
+/// Sensitivities below this are treated as no coupling
+const MIN_SENSITIVITY: f64 = 1e-9;
+
+/// Rated branch with its base-case flow.
+struct RatedBranch {
+    name: String,
+    /// Row in the PTDF matrix
+    row: usize,
+    base_flow_mw: f64,
+    limit_mw: f64,
+}
+
+/// PTDFs and base-case loading for one network.
+pub(crate) struct GridModel {
+    ptdf: PtdfMatrix,
+    branches: Vec<RatedBranch>,
+}
+
+impl GridModel {
+    pub(crate) fn new(network: &Network) -> Result<Self> {
+        let ptdf = SparsePtdf::compute_ptdf(network).context("computing PTDFs for envelope")?;
+
+        let mut injection: HashMap<BusId, f64> = HashMap::new();
+        for node in network.graph.node_weights() {
+            match node {
+                Node::Gen(gen) if gen.status => {
+                    *injection.entry(gen.bus).or_insert(0.0) += gen.active_power.value();
+                }
+                Node::Load(load) => {
+                    *injection.entry(load.bus).or_insert(0.0) -= load.active_power.value();
+                }
+                _ => {}
+            }
+        }
+
+        let mut branches = Vec::new();
+        for edge in network.graph.edge_weights() {
+            let Edge::Branch(branch) = edge else {
+                continue;
+            };
+            let Some(limit) = branch.s_max.or(branch.rating_a) else {
+                continue;
+            };
+            let Some(row) = ptdf.branch_index(branch.id) else {
+                continue;
+            };
+            let base_flow_mw = injection
+                .iter()
+                .map(|(&bus, &p)| ptdf.get(branch.id, bus).unwrap_or(0.0) * p)
+                .sum();
+            branches.push(RatedBranch {
+                name: branch.name.clone(),
+                row,
+                base_flow_mw,
+                limit_mw: limit.value(),
+            });
+        }
+
+        Ok(Self { ptdf, branches })
+    }
+
+    /// Largest aggregate move (MW, ≥ 0) along `direction` before a rated branch
+    /// reaches its limit, and the branch that binds first.
+    ///
+    /// `direction` maps buses to signed shares of the move: positive entries
+    /// inject, negative entries withdraw. Returns infinity when no rated
+    /// branch is affected.
+    pub(crate) fn headroom(&self, direction: &[(BusId, f64)]) -> Result<(f64, Option<String>)> {
+        let mut columns = Vec::with_capacity(direction.len());
+        for &(bus, share) in direction {
+            let col = self
+                .ptdf
+                .bus_index(bus)
+                .ok_or_else(|| anyhow!("asset bus {} is not in the grid", bus.value()))?;
+            columns.push((col, share));
+        }
+
+        let mut best = (f64::INFINITY, None);
+        for branch in &self.branches {
+            let sensitivity: f64 = columns
+                .iter()
+                .map(|&(col, share)| self.ptdf.get_by_idx(branch.row, col) * share)
+                .sum();
+            if sensitivity.abs() < MIN_SENSITIVITY {
+                continue;
+            }
+            let room = if sensitivity > 0.0 {
+                (branch.limit_mw - branch.base_flow_mw) / sensitivity
+            } else {
+                (branch.limit_mw + branch.base_flow_mw) / -sensitivity
+            };
+            let room = room.max(0.0);
+            if room < best.0 {
+                best = (room, Some(branch.name.clone()));
+            }
+        }
+        Ok(best)
+    }
+}
@@ -1,13 +1,19 @@
 use anyhow::{anyhow, Context, Result};
+use gat_algo::opf::StorageUnit;
+use gat_core::{BusId, Network, ScenarioRng};
+use gat_io::importers;
 use polars::prelude::{
     DataFrame, NamedFrom, ParquetCompression, ParquetReader, ParquetWriter, SerReader, Series,
 };
-use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fs::{self, File};
 use std::path::Path;
 
+mod grid_limits;
+
+use grid_limits::GridModel;
+
 /// DER asset representation with operational constraints and state-of-charge limits.
 ///
 /// **Asset Types:**
@@ -33,6 +39,7 @@ struct DerAsset {
     q_max: f64,             // Maximum reactive power (MVAr): for voltage support
     soc_min: f64,           // Minimum state of charge (MWh or p.u.)
     soc_max: f64,           // Maximum state of charge (MWh or p.u.)
+    soc_init: f64,          // State of charge at the start of a horizon (MWh or p.u.)
 }
 
 /// Lightweight price vector for scheduling horizons.
@@ -82,10 +89,15 @@ struct PricePoint {
 /// 1. Load DER asset table (asset_id, p_min, p_max, q_min, q_max, location)
 /// 2. Group assets by `group_by` key (default: agg_id for aggregation zones)
 /// 3. For each group, compute aggregate envelope bounds:
-///    - P_min_total = min(Σ p_min_i) (all assets at max charge)
-///    - P_max_total = max(Σ p_max_i) (all assets at max discharge)
+///    - P_min_total = Σ p_min_i (all assets at max charge)
+///    - P_max_total = Σ p_max_i (all assets at max discharge)
 ///    - Similar for Q_min, Q_max
-/// 4. Output envelope table: (region, p_min_mw, p_max_mw, q_min_mvar, q_max_mvar, asset_count)
+/// 4. With `network_limits`, clip P_min/P_max by the thermal headroom of the grid in
+///    `grid_file`: the group moves as a block (shares ∝ asset ratings at each `bus_id`)
+///    and PTDFs give the largest move before a rated branch overloads
+/// 5. Output envelope table: (region, asset_count, p_min_mw, p_max_mw, q_min_mvar, q_max_mvar),
+///    plus `p_min_net_mw`, `p_max_net_mw` and the binding branch for each direction
+///    (`p_min_limit_branch`, `p_max_limit_branch`) when network limits are applied
 ///
 /// **Use Cases:**
 /// - **Market Bidding**: Submit envelope to ISO/RTO as available capacity for dispatch
@@ -110,15 +122,36 @@ pub fn envelope(
     asset_file: &Path,
     output_file: &Path,
     group_by: Option<&str>,
+    network_limits: bool,
 ) -> Result<()> {
-    println!(
-        "Using grid {:?} to provide topology context (not yet consumed).",
-        grid_file
-    );
     let df = read_parquet(asset_file)?;
     let assets = parse_assets(&df)?;
     let key = group_by.unwrap_or("agg_id");
-    let groups = group_assets(&assets, key);
+    let grid = if network_limits {
+        Some(GridModel::new(&load_network(grid_file)?)?)
+    } else {
+        None
+    };
+
+    let mut summary = envelope_frame(&assets, key, grid.as_ref())?;
+    persist_dataframe(output_file, &mut summary)?;
+    println!(
+        "DERMS envelope persisted {} regions to {} (grouped by {}{})",
+        summary.height(),
+        output_file.display(),
+        key,
+        if network_limits {
+            ", network-constrained"
+        } else {
+            ""
+        }
+    );
+    Ok(())
+}
+
+fn envelope_frame(assets: &[DerAsset], key: &str, grid: Option<&GridModel>) -> Result<DataFrame> {
+    let mut groups: Vec<_> = group_assets(assets, key).into_iter().collect();
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
 
     let mut region = Vec::new();
     let mut p_min = Vec::new();
@@ -126,54 +159,226 @@ pub fn envelope(
     let mut q_min = Vec::new();
     let mut q_max = Vec::new();
     let mut asset_counts = Vec::new();
+    let mut p_min_net = Vec::new();
+    let mut p_max_net = Vec::new();
+    let mut p_min_limit = Vec::new();
+    let mut p_max_limit = Vec::new();
 
     for (name, members) in groups {
+        let naive_p_min: f64 = members.iter().map(|asset| asset.p_min).sum();
+        let naive_p_max: f64 = members.iter().map(|asset| asset.p_max).sum();
         region.push(name.clone());
         asset_counts.push(members.len() as i64);
-        p_min.push(
-            members
-                .iter()
-                .map(|asset| asset.p_min)
-                .fold(f64::INFINITY, f64::min),
-        );
-        p_max.push(
-            members
-                .iter()
-                .map(|asset| asset.p_max)
-                .fold(f64::NEG_INFINITY, f64::max),
-        );
-        q_min.push(
-            members
-                .iter()
-                .map(|asset| asset.q_min)
-                .fold(f64::INFINITY, f64::min),
-        );
-        q_max.push(
-            members
-                .iter()
-                .map(|asset| asset.q_max)
-                .fold(f64::NEG_INFINITY, f64::max),
-        );
+        p_min.push(naive_p_min);
+        p_max.push(naive_p_max);
+        q_min.push(members.iter().map(|asset| asset.q_min).sum::<f64>());
+        q_max.push(members.iter().map(|asset| asset.q_max).sum::<f64>());
+
+        if let Some(grid) = grid {
+            let up = direction(&members, |asset| asset.p_max.max(0.0), 1.0)?;
+            let down = direction(&members, |asset| (-asset.p_min).max(0.0), -1.0)?;
+            let (up_room, up_branch) = grid.headroom(&up)?;
+            let (down_room, down_branch) = grid.headroom(&down)?;
+
+            let clipped_max = naive_p_max.min(up_room);
+            let clipped_min = naive_p_min.max(-down_room);
+            p_max_limit.push(up_branch.filter(|_| clipped_max < naive_p_max));
+            p_min_limit.push(down_branch.filter(|_| clipped_min > naive_p_min));
+            p_max_net.push(clipped_max);
+            p_min_net.push(clipped_min);
+        }
     }
 
-    let mut summary = DataFrame::new(vec![
+    let mut columns = vec![
         Series::new("region", region),
         Series::new("asset_count", asset_counts),
         Series::new("p_min_mw", p_min),
         Series::new("p_max_mw", p_max),
         Series::new("q_min_mvar", q_min),
         Series::new("q_max_mvar", q_max),
-    ])?;
-    persist_dataframe(output_file, &mut summary)?;
+    ];
+    if grid.is_some() {
+        columns.push(Series::new("p_min_net_mw", p_min_net));
+        columns.push(Series::new("p_max_net_mw", p_max_net));
+        columns.push(Series::new("p_min_limit_branch", p_min_limit));
+        columns.push(Series::new("p_max_limit_branch", p_max_limit));
+    }
+    Ok(DataFrame::new(columns)?)
+}
+
+/// Signed per-bus shares of an aggregate move, weighted by `capability`.
+fn direction(
+    members: &[&DerAsset],
+    capability: impl Fn(&DerAsset) -> f64,
+    sign: f64,
+) -> Result<Vec<(BusId, f64)>> {
+    let total: f64 = members.iter().map(|asset| capability(asset)).sum();
+    if total <= 0.0 {
+        return Ok(Vec::new());
+    }
+    members
+        .iter()
+        .filter(|asset| capability(asset) > 0.0)
+        .map(|asset| {
+            let bus = asset.bus_id.ok_or_else(|| {
+                anyhow!(
+                    "asset '{}' has no bus_id; required for network-constrained envelopes",
+                    asset.id
+                )
+            })?;
+            Ok((BusId::new(bus), sign * capability(asset) / total))
+        })
+        .collect()
+}
+
+/// Time horizon for [`envelope_timeseries`], with an optional assumed dispatch.
+///
+/// Assets without an entry in `dispatch` follow the neutral trajectory: they
+/// idle and hold their initial state of charge for the whole horizon.
+#[derive(Clone, Debug, Default)]
+pub struct Horizon {
+    /// Label of each step, written to the `timestamp` column
+    pub timestamps: Vec<String>,
+    /// Step duration in hours (converts MW to MWh of SoC)
+    pub step_hours: f64,
+    /// Assumed dispatch per asset id: MW per step, discharge positive
+    pub dispatch: HashMap<String, Vec<f64>>,
+}
+
+impl Horizon {
+    /// `steps` hourly steps labelled `t0`, `t1`, ... with no assumed dispatch.
+    pub fn hourly(steps: usize) -> Self {
+        Self {
+            timestamps: (0..steps).map(|idx| format!("t{}", idx)).collect(),
+            step_hours: 1.0,
+            dispatch: HashMap::new(),
+        }
+    }
+
+    /// Take timestamps and dispatch from a [`schedule`] output Parquet
+    /// (`timestamp`, `asset_id`, `p_mw`), one row per asset and step.
+    pub fn from_schedule(schedule_file: &Path, step_hours: f64) -> Result<Self> {
+        let df = read_parquet(schedule_file)?;
+        let timestamps = column_utf8(&df, "timestamp")?;
+        let asset_ids = column_utf8(&df, "asset_id")?;
+        let p_mw = column_f64(&df, "p_mw", 0.0)?;
+
+        let mut horizon = Self {
+            step_hours,
+            ..Self::default()
+        };
+        let mut step_of: HashMap<String, usize> = HashMap::new();
+        for idx in 0..df.height() {
+            let (Some(timestamp), Some(asset)) = (&timestamps[idx], &asset_ids[idx]) else {
+                return Err(anyhow!("schedule row {} lacks timestamp or asset_id", idx));
+            };
+            let step = *step_of.entry(timestamp.clone()).or_insert_with(|| {
+                horizon.timestamps.push(timestamp.clone());
+                horizon.timestamps.len() - 1
+            });
+            let series = horizon.dispatch.entry(asset.clone()).or_default();
+            if series.len() <= step {
+                series.resize(step + 1, 0.0);
+            }
+            series[step] = p_mw[idx];
+        }
+        Ok(horizon)
+    }
+}
+
+/// Per-timestep DER flexibility envelopes that track storage state of charge.
+///
+/// A static envelope (see [`envelope`]) offers a battery's full charge and
+/// discharge rating at every hour, even when it is nearly full or nearly
+/// empty. Here each storage asset (`p_min < 0 < p_max`) is limited at step `t`
+/// by the energy it can still deliver or absorb over the step:
+///
+/// ```text
+/// p_max(t) = min(p_max, (SoC(t) - soc_min) / Δt)
+/// p_min(t) = max(p_min, -(soc_max - SoC(t)) / Δt)
+/// ```
+///
+/// SoC starts at the asset's `soc` column (mid-band if absent) and follows the
+/// horizon's assumed dispatch, clipped to the SoC band. Non-storage assets keep
+/// their static ratings. Asset limits are summed per region (`group_by`, as
+/// in [`envelope`]) and written as a long-format Parquet with columns
+/// `timestamp, region, p_min, p_max` — the shape market bids are built from.
+pub fn envelope_timeseries(
+    asset_file: &Path,
+    horizon: &Horizon,
+    output_file: &Path,
+    group_by: Option<&str>,
+) -> Result<()> {
+    let assets = parse_assets(&read_parquet(asset_file)?)?;
+    let key = group_by.unwrap_or("agg_id");
+    let mut frame = envelope_timeseries_frame(&assets, horizon, key)?;
+    persist_dataframe(output_file, &mut frame)?;
     println!(
-        "DERMS envelope persisted {} regions to {} (grouped by {})",
-        summary.height(),
-        output_file.display(),
-        key
+        "DERMS envelope time series wrote {} rows ({} steps) to {}",
+        frame.height(),
+        horizon.timestamps.len(),
+        output_file.display()
     );
     Ok(())
 }
 
+fn envelope_timeseries_frame(
+    assets: &[DerAsset],
+    horizon: &Horizon,
+    key: &str,
+) -> Result<DataFrame> {
+    if horizon.step_hours <= 0.0 {
+        return Err(anyhow!("step_hours must be positive"));
+    }
+    let dt = horizon.step_hours;
+
+    let mut groups: Vec<_> = group_assets(assets, key).into_iter().collect();
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut timestamps = Vec::new();
+    let mut regions = Vec::new();
+    let mut p_min = Vec::new();
+    let mut p_max = Vec::new();
+
+    for (region, members) in &groups {
+        let mut soc: Vec<f64> = members.iter().map(|asset| asset.soc_init).collect();
+        for (step, timestamp) in horizon.timestamps.iter().enumerate() {
+            let mut lo = 0.0;
+            let mut hi = 0.0;
+            for (asset, soc) in members.iter().zip(soc.iter_mut()) {
+                if asset.p_min < 0.0 && asset.p_max > 0.0 {
+                    let discharge = ((*soc - asset.soc_min) / dt).max(0.0);
+                    let charge = ((asset.soc_max - *soc) / dt).max(0.0);
+                    lo += asset.p_min.max(-charge);
+                    hi += asset.p_max.min(discharge);
+
+                    let p = horizon
+                        .dispatch
+                        .get(&asset.id)
+                        .and_then(|series| series.get(step))
+                        .copied()
+                        .unwrap_or(0.0);
+                    *soc = (*soc - p * dt).clamp(asset.soc_min, asset.soc_max);
+                } else {
+                    lo += asset.p_min;
+                    hi += asset.p_max;
+                }
+            }
+            timestamps.push(timestamp.clone());
+            regions.push(region.clone());
+            p_min.push(lo);
+            p_max.push(hi);
+        }
+    }
+
+    Ok(DataFrame::new(vec![
+        Series::new("timestamp", timestamps),
+        Series::new("region", regions),
+        Series::new("p_min", p_min),
+        Series::new("p_max", p_max),
+    ])?)
+}
+
 /// Generate price-responsive DER dispatch schedule for energy arbitrage and peak shaving.
 ///
 /// **Purpose:** Given a time-series of electricity prices (or load forecasts), compute optimal
@@ -356,16 +561,14 @@ pub fn stress_test(
     let prices = parse_prices(&read_parquet(price_file)?)?;
     let median = compute_median_price(&prices);
 
-    let mut rng = seed
-        .map(StdRng::seed_from_u64)
-        .unwrap_or_else(StdRng::from_entropy);
+    let mut rng = ScenarioRng::from_seed_or_entropy(seed);
 
     let mut scenario_ids = Vec::new();
     let mut scale_factors = Vec::new();
     let mut curtail_rates = Vec::new();
 
     for scenario in 0..scenarios {
-        let scale = rng.gen_range(0.8..=1.2);
+        let scale = rng.uniform_range(0.8, 1.2);
         let adjusted: Vec<PricePoint> = prices
             .iter()
             .map(|point| PricePoint {
@@ -395,6 +598,36 @@ pub fn stress_test(
     Ok(())
 }
 
+/// Read the storage assets in `asset_file` for co-optimization in a multi-period DC-OPF
+/// (`gat_algo::opf::solve_multiperiod_dc`).
+///
+/// Assets with a `bus_id` that can both charge and discharge (`p_min < 0 < p_max`) become
+/// [`StorageUnit`]s with their `soc_*` columns read as MWh. Solar, demand response and
+/// unlocated assets are skipped, since they have no state of charge to carry between periods.
+pub fn storage_units(asset_file: &Path) -> Result<Vec<StorageUnit>> {
+    let assets = parse_assets(&read_parquet(asset_file)?)?;
+    Ok(storage_from_assets(&assets))
+}
+
+fn storage_from_assets(assets: &[DerAsset]) -> Vec<StorageUnit> {
+    assets
+        .iter()
+        .filter(|asset| asset.p_min < 0.0 && asset.p_max > 0.0)
+        .filter_map(|asset| {
+            let bus = asset.bus_id?;
+            Some(StorageUnit::new(
+                asset.id.clone(),
+                BusId::new(bus),
+                asset.p_min,
+                asset.p_max,
+                asset.soc_min,
+                asset.soc_max,
+                asset.soc_init,
+            ))
+        })
+        .collect()
+}
+
 fn read_parquet(input: &Path) -> Result<DataFrame> {
     let file = File::open(input)
         .with_context(|| format!("opening parquet dataset '{}'", input.display()))?;
@@ -404,6 +637,14 @@ fn read_parquet(input: &Path) -> Result<DataFrame> {
         .with_context(|| format!("reading parquet dataset '{}'", input.display()))
 }
 
+fn load_network(grid_file: &Path) -> Result<Network> {
+    let grid_str = grid_file
+        .to_str()
+        .ok_or_else(|| anyhow!("grid path contains invalid UTF-8: {}", grid_file.display()))?;
+    importers::load_grid_from_arrow(grid_str)
+        .with_context(|| format!("loading grid arrow {}", grid_file.display()))
+}
+
 fn persist_dataframe(path: &Path, df: &mut DataFrame) -> Result<()> {
     let mut file = File::create(path)
         .with_context(|| format!("creating Parquet output '{}'", path.display()))?;
@@ -425,6 +666,8 @@ fn parse_assets(df: &DataFrame) -> Result<Vec<DerAsset>> {
     let q_max = column_f64(df, "q_max", 0.0)?;
     let soc_min = column_f64(df, "soc_min", 0.0)?;
     let soc_max = column_f64(df, "soc_max", 1.0)?;
+    // Missing initial SoC starts mid-band
+    let soc = column_f64(df, "soc", f64::NAN)?;
 
     let mut assets = Vec::with_capacity(height);
     for idx in 0..height {
@@ -441,6 +684,11 @@ fn parse_assets(df: &DataFrame) -> Result<Vec<DerAsset>> {
             q_max: q_max[idx],
             soc_min: soc_min[idx],
             soc_max: soc_max[idx],
+            soc_init: if soc[idx].is_nan() {
+                (soc_min[idx] + soc_max[idx]) / 2.0
+            } else {
+                soc[idx].clamp(soc_min[idx], soc_max[idx])
+            },
         });
     }
     Ok(assets)
@@ -545,7 +793,7 @@ fn build_schedule(
         .iter()
         .map(|asset| AssetState {
             asset: asset.clone(),
-            soc: (asset.soc_min + asset.soc_max) / 2.0,
+            soc: asset.soc_init,
         })
         .collect();
 
@@ -601,3 +849,176 @@ fn build_schedule(
 
     Ok((df, rate))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{
+        Branch, BranchId, Bus, Edge, Gen, GenId, Load, LoadId, Megavars, MegavoltAmperes,
+        Megawatts, Node,
+    };
+
+    fn battery(id: &str, bus: usize, rating: f64) -> DerAsset {
+        DerAsset {
+            id: id.to_string(),
+            agg_id: Some("vpp".to_string()),
+            bus_id: Some(bus),
+            p_min: -rating,
+            p_max: rating,
+            q_min: -rating / 2.0,
+            q_max: rating / 2.0,
+            soc_min: 0.0,
+            soc_max: 1.0,
+            soc_init: 0.5,
+        }
+    }
+
+    /// Substation (bus 1) feeding a DER bus (bus 2) over a 5 MW tie,
+    /// with 2 MW of local load at the DER bus.
+    fn congested_poi() -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "substation".to_string(),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "poi".to_string(),
+            ..Bus::default()
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch {
+                rating_a: Some(MegavoltAmperes(5.0)),
+                ..Branch::new(
+                    BranchId::new(1),
+                    "tie".to_string(),
+                    BusId::new(1),
+                    BusId::new(2),
+                    0.01,
+                    0.1,
+                )
+            }),
+        );
+        let mut gen = Gen::new(GenId::new(1), "grid".to_string(), BusId::new(1));
+        gen.active_power = Megawatts(2.0);
+        network.graph.add_node(Node::Gen(gen));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "poi load".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(2.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        network
+    }
+
+    fn column(df: &DataFrame, name: &str) -> f64 {
+        df.column(name).unwrap().f64().unwrap().get(0).unwrap()
+    }
+
+    #[test]
+    fn test_congested_poi_shrinks_envelope() {
+        let assets = vec![battery("b1", 2, 4.0), battery("b2", 2, 4.0)];
+        let grid = GridModel::new(&congested_poi()).unwrap();
+        let df = envelope_frame(&assets, "agg_id", Some(&grid)).unwrap();
+
+        // Naive envelope is the sum of ratings
+        assert_eq!(column(&df, "p_max_mw"), 8.0);
+        assert_eq!(column(&df, "p_min_mw"), -8.0);
+
+        // Export offsets the 2 MW import first: 5 + 2 = 7 MW before the tie
+        // overloads; charging adds to the import: 5 - 2 = 3 MW
+        assert!((column(&df, "p_max_net_mw") - 7.0).abs() < 1e-6);
+        assert!((column(&df, "p_min_net_mw") + 3.0).abs() < 1e-6);
+        let limit = df.column("p_min_limit_branch").unwrap().utf8().unwrap();
+        assert_eq!(limit.get(0), Some("tie"));
+    }
+
+    #[test]
+    fn test_envelope_without_grid_has_no_network_columns() {
+        let assets = vec![battery("b1", 2, 4.0), battery("b2", 2, 4.0)];
+        let df = envelope_frame(&assets, "agg_id", None).unwrap();
+        assert_eq!(column(&df, "q_max_mvar"), 4.0);
+        assert!(df.column("p_max_net_mw").is_err());
+    }
+
+    #[test]
+    fn test_network_limits_require_bus_ids() {
+        let mut asset = battery("b1", 2, 4.0);
+        asset.bus_id = None;
+        let grid = GridModel::new(&congested_poi()).unwrap();
+        assert!(envelope_frame(&[asset], "agg_id", Some(&grid)).is_err());
+    }
+
+    fn column_values(df: &DataFrame, name: &str) -> Vec<f64> {
+        df.column(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_nearly_full_battery_can_discharge_but_not_charge() {
+        // 4 MW / 8 MWh battery at 7.9 MWh
+        let mut asset = battery("bess", 2, 4.0);
+        asset.soc_max = 8.0;
+        asset.soc_init = 7.9;
+        let df = envelope_timeseries_frame(&[asset], &Horizon::hourly(3), "agg_id").unwrap();
+
+        assert_eq!(df.height(), 3);
+        for (lo, hi) in column_values(&df, "p_min")
+            .into_iter()
+            .zip(column_values(&df, "p_max"))
+        {
+            assert_eq!(hi, 4.0);
+            assert!(lo > -0.1 - 1e-9 && lo <= 0.0, "charge headroom {}", lo);
+        }
+    }
+
+    #[test]
+    fn test_assumed_discharge_shrinks_later_steps() {
+        // 4 MW / 4 MWh battery, full, discharging 2 MW per hour
+        let mut asset = battery("bess", 2, 4.0);
+        asset.soc_max = 4.0;
+        asset.soc_init = 4.0;
+        let mut horizon = Horizon::hourly(3);
+        horizon
+            .dispatch
+            .insert("bess".to_string(), vec![2.0, 2.0, 2.0]);
+        let df = envelope_timeseries_frame(&[asset], &horizon, "agg_id").unwrap();
+
+        // SoC at the start of each step: 4, 2, 0 MWh
+        assert_eq!(column_values(&df, "p_max"), vec![4.0, 2.0, 0.0]);
+        assert_eq!(column_values(&df, "p_min"), vec![0.0, -2.0, -4.0]);
+    }
+
+    #[test]
+    fn test_non_storage_assets_keep_static_limits() {
+        let mut solar = battery("pv", 2, 3.0);
+        solar.p_min = 0.0;
+        let df = envelope_timeseries_frame(&[solar], &Horizon::hourly(2), "agg_id").unwrap();
+        assert_eq!(column_values(&df, "p_max"), vec![3.0, 3.0]);
+        assert_eq!(column_values(&df, "p_min"), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_storage_units_skip_one_directional_and_unlocated_assets() {
+        let mut solar = battery("pv", 2, 3.0);
+        solar.p_min = 0.0;
+        let mut floating = battery("ev", 2, 1.0);
+        floating.bus_id = None;
+        let units = storage_from_assets(&[battery("bess", 2, 4.0), solar, floating]);
+
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].id, "bess");
+        assert_eq!(units[0].bus, BusId::new(2));
+        assert_eq!((units[0].p_min, units[0].p_max), (-4.0, 4.0));
+        assert_eq!(units[0].soc_init, 0.5);
+    }
+}
@@ -0,0 +1,215 @@
+//! Branch-outage screening for distribution feeders.
+//!
+//! On a radial feeder a branch outage does not redistribute flow the way it does on a meshed
+//! transmission grid: everything downstream of the open branch simply loses its supply. The
+//! screen below therefore reduces N-1 to connectivity. For each in-service branch it opens the
+//! branch, labels the islands that remain with [`gat_core::graph_utils::find_islands`], and
+//! reports the buses cut off from the substation together with the load they carried. Meshed
+//! sections are handled naturally, since an outage inside a loop strands no buses.
+
+use anyhow::{anyhow, Context, Result};
+use gat_core::graph_utils::find_islands;
+use gat_core::{Edge, Network, Node};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::load_network;
+use crate::topology::substation_bus;
+
+/// Service lost when one feeder branch is opened.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeederContingency {
+    pub branch_id: usize,
+    pub branch_label: String,
+    /// Buses no longer connected to the substation, sorted by bus ID
+    pub deenergized_buses: Vec<usize>,
+    /// Active load at the de-energized buses (MW)
+    pub unserved_mw: f64,
+    /// Reactive load at the de-energized buses (MVAr)
+    pub unserved_mvar: f64,
+}
+
+/// Screen every in-service branch outage of the feeder in `grid_file`.
+///
+/// The substation is the bus of the largest in-service generator. Results follow the branch
+/// order of the grid; outages that strand nothing are reported with an empty bus list so the
+/// output covers every branch.
+pub fn screen_feeder_contingencies(grid_file: &Path) -> Result<Vec<FeederContingency>> {
+    let network = load_network(grid_file)?;
+    screen(&network)
+        .with_context(|| format!("screening feeder contingencies on {}", grid_file.display()))
+}
+
+fn screen(network: &Network) -> Result<Vec<FeederContingency>> {
+    let root = substation_bus(network)
+        .ok_or_else(|| anyhow!("feeder has no in-service generator to act as the substation"))?;
+
+    // Open branches are already out of the picture before any contingency
+    let mut base = network.clone();
+    base.graph
+        .retain_edges(|graph, edge| !matches!(&graph[edge], Edge::Branch(b) if !b.status));
+
+    let mut load: HashMap<usize, (f64, f64)> = HashMap::new();
+    for node in base.graph.node_weights() {
+        if let Node::Load(l) = node {
+            let entry = load.entry(l.bus.value()).or_default();
+            entry.0 += l.active_power.value();
+            entry.1 += l.reactive_power.value();
+        }
+    }
+
+    let energized_before = energized_buses(&base, root)?;
+    let mut results = Vec::new();
+    for edge in base.graph.edge_indices() {
+        let Edge::Branch(branch) = &base.graph[edge] else {
+            continue;
+        };
+        let mut outage = base.clone();
+        outage.graph.remove_edge(edge);
+        let energized_after = energized_buses(&outage, root)?;
+
+        let mut deenergized: Vec<usize> = energized_before
+            .difference(&energized_after)
+            .copied()
+            .collect();
+        deenergized.sort_unstable();
+        let (unserved_mw, unserved_mvar) = deenergized
+            .iter()
+            .filter_map(|bus| load.get(bus))
+            .fold((0.0, 0.0), |(p, q), (lp, lq)| (p + lp, q + lq));
+
+        results.push(FeederContingency {
+            branch_id: branch.id.value(),
+            branch_label: branch.name.clone(),
+            deenergized_buses: deenergized,
+            unserved_mw,
+            unserved_mvar,
+        });
+    }
+    Ok(results)
+}
+
+/// Bus IDs in the same island as the substation bus.
+fn energized_buses(network: &Network, root: usize) -> Result<HashSet<usize>> {
+    let bus_at = |index: usize| match &network.graph.raw_nodes()[index].weight {
+        Node::Bus(bus) => Some(bus.id.value()),
+        _ => None,
+    };
+
+    let analysis = find_islands(network)?;
+    let source_island = analysis
+        .assignments
+        .iter()
+        .find(|a| bus_at(a.node_index) == Some(root))
+        .map(|a| a.island_id)
+        .ok_or_else(|| anyhow!("substation bus {} is not in the grid", root))?;
+    Ok(analysis
+        .assignments
+        .iter()
+        .filter(|a| a.island_id == source_island)
+        .filter_map(|a| bus_at(a.node_index))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{Branch, BranchId, Bus, BusId, Gen, GenId, Load, LoadId, Megavars, Megawatts};
+    use tempfile::tempdir;
+
+    /// Mainline bus0 → bus3 with a lateral from bus1 to bus4 and 1 MW at every load bus.
+    fn feeder() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..5)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: gat_core::Kilovolts(12.47),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (i, (from, to)) in [(0, 1), (1, 2), (2, 3), (1, 4)].into_iter().enumerate() {
+            network.graph.add_edge(
+                buses[from],
+                buses[to],
+                Edge::Branch(Branch::new(
+                    BranchId::new(i),
+                    format!("seg{}", i),
+                    BusId::new(from),
+                    BusId::new(to),
+                    0.01,
+                    0.02,
+                )),
+            );
+        }
+        network.graph.add_node(Node::Gen(Gen {
+            pmax: Megawatts(50.0),
+            ..Gen::new(GenId::new(0), "substation".to_string(), BusId::new(0))
+        }));
+        for bus in 1..5 {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(bus),
+                name: format!("load{}", bus),
+                bus: BusId::new(bus),
+                active_power: Megawatts(1.0),
+                reactive_power: Megavars(0.25),
+                zip: None,
+            }));
+        }
+        network
+    }
+
+    #[test]
+    fn test_mainline_outage_drops_downstream_buses() -> Result<()> {
+        let tmp = tempdir()?;
+        let grid = tmp.path().join("feeder.arrow");
+        gat_io::exporters::write_network_to_arrow_directory(&feeder(), &grid)?;
+
+        let results = screen_feeder_contingencies(&grid)?;
+        assert_eq!(results.len(), 4);
+        let by_label: HashMap<&str, &FeederContingency> = results
+            .iter()
+            .map(|c| (c.branch_label.as_str(), c))
+            .collect();
+
+        // Mainline segment between bus1 and bus2 strands the rest of the trunk only
+        let seg1 = by_label["seg1"];
+        assert_eq!(seg1.deenergized_buses, vec![2, 3]);
+        assert!((seg1.unserved_mw - 2.0).abs() < 1e-9);
+        assert!((seg1.unserved_mvar - 0.5).abs() < 1e-9);
+
+        // Losing the head segment blacks out the whole feeder, lateral included
+        assert_eq!(by_label["seg0"].deenergized_buses, vec![1, 2, 3, 4]);
+        assert_eq!(by_label["seg3"].deenergized_buses, vec![4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_loop_outage_strands_nothing() -> Result<()> {
+        let mut network = feeder();
+        let (b3, b4) = (
+            network.graph.node_indices().nth(3).unwrap(),
+            network.graph.node_indices().nth(4).unwrap(),
+        );
+        network.graph.add_edge(
+            b3,
+            b4,
+            Edge::Branch(Branch::new(
+                BranchId::new(4),
+                "tie".to_string(),
+                BusId::new(3),
+                BusId::new(4),
+                0.01,
+                0.02,
+            )),
+        );
+
+        let results = screen(&network)?;
+        let seg1 = results.iter().find(|c| c.branch_label == "seg1").unwrap();
+        assert!(seg1.deenergized_buses.is_empty());
+        assert_eq!(seg1.unserved_mw, 0.0);
+        Ok(())
+    }
+}
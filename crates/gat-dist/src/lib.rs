@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Context, Result};
-use gat_algo::power_flow;
-use gat_core::{solver::SolverKind, BusId, Edge, Gen, GenId, Network, Node};
+use gat_algo::{power_flow, AcObjective, AcOpfSolution, AcOpfSolver};
+use gat_core::{solver::SolverKind, BusId, DispatchMode, Edge, Gen, GenId, Network, Node};
 use gat_io::importers;
 use polars::prelude::{DataFrame, NamedFrom, ParquetCompression, ParquetWriter, Series};
 use std::collections::HashMap;
@@ -8,6 +8,19 @@ use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use tempfile::tempdir;
 
+mod contingency;
+mod portfolio;
+mod profile;
+mod temporal;
+mod topology;
+mod unbalance;
+
+pub use contingency::{screen_feeder_contingencies, FeederContingency};
+pub use portfolio::{hostcap_portfolio, HostcapPortfolio, PortfolioAllocation};
+pub use profile::voltage_profile;
+pub use temporal::{hostcap_temporal, HostcapHour, TemporalHostcap};
+pub use unbalance::{bus_unbalance_factors, voltage_unbalance_factor, PhaseVoltage, VUF_LIMIT};
+
 /// Import a MATPOWER case and emit distribution-specific node/branch tables as Parquet.
 ///
 /// **Purpose:** Convert MATPOWER format (designed for transmission systems) into distribution-
@@ -128,23 +141,38 @@ pub fn run_power_flow(
         .with_context(|| format!("running dist pf on {}", grid_file.display()))
 }
 
-/// Run a simple single-objective AC OPF for hosting/volt-var experiments.
+/// Run a single-objective AC OPF for hosting/volt-var experiments.
+///
+/// `objective` selects what the nonlinear AC-OPF minimizes: `min_cost` (generation cost),
+/// `min_loss` (feeder active-power losses), or `min_voltage_deviation` (Σ(V − 1)²). Unknown
+/// objectives are rejected before the grid is loaded.
+///
+/// The output Parquet holds one row per generator (`p_mw`) and per bus (`vm_pu`), each tagged
+/// with the objective, its value, the total losses, and whether the solver converged.
+/// `solver_kind` only applies to the power-flow commands; the OPF always uses the AC-NLP solver.
 pub fn run_optimal_power_flow(
     grid_file: &Path,
     out_file: &Path,
-    solver_kind: SolverKind,
+    _solver_kind: SolverKind,
     tol: f64,
     max_iter: u32,
     objective: &str,
 ) -> Result<()> {
-    println!(
-        "Dist OPF objective '{}'; recording results for downstream offline workflows",
-        objective
-    );
+    let objective: AcObjective = objective.parse().map_err(|e: String| anyhow!(e))?;
     let network = load_network(grid_file)?;
-    let solver = solver_kind.build_solver();
-    power_flow::ac_optimal_power_flow(&network, solver.as_ref(), tol, max_iter, out_file, &[])
-        .with_context(|| format!("running dist opf on {}", grid_file.display()))
+    let solution = AcOpfSolver::new()
+        .with_objective(objective)
+        .with_max_iterations(max_iter as usize)
+        .with_tolerance(tol)
+        .solve(&network)
+        .with_context(|| format!("running dist opf on {}", grid_file.display()))?;
+    if !solution.converged {
+        eprintln!(
+            "Dist OPF ({}) did not converge within {} iterations; results are the last iterate",
+            objective, max_iter
+        );
+    }
+    write_parquet(out_file.to_path_buf(), build_opf_frame(&solution))
 }
 
 /// Sweep DER injections at selected buses to approximate hosting capacity boundaries.
@@ -183,12 +211,15 @@ pub fn run_optimal_power_flow(
 /// **Interpreting Results:**
 /// - **Success = true**: Feeder can accommodate this DER injection level
 /// - **Success = false**: Voltage or thermal limits violated, HC is below this level
+/// - **Islanded = true**: No in-service path joins the bus to the substation, so the step is
+///   not solved and `success` is null; a DER there would only feed its own island
 /// - **HC value**: Maximum injection_mw before first failure (linear interpolation between steps)
 /// - **Bottleneck identification**: If HC is low, check which constraint binds (voltage or thermal)
 ///
 /// **Limitations (Deterministic HC):**
-/// - **Static analysis**: Doesn't model time-varying solar/load (use time-series PF for that)
-/// - **Single-bus injection**: Doesn't assess simultaneous DER at multiple buses (combinatorial)
+/// - **Static analysis**: Doesn't model time-varying solar/load (see [`hostcap_temporal`])
+/// - **Single-bus injection**: Doesn't assess simultaneous DER at multiple buses (see
+///   [`hostcap_portfolio`])
 /// - **No stochasticity**: Doesn't account for DER/load uncertainty (EPRI method uses Monte Carlo)
 /// - **No advanced controls**: Assumes fixed power factor (real HC: smart inverters can help)
 ///
@@ -229,6 +260,9 @@ pub fn hostcap_sweep(
 
     let network = load_network(grid_file)?;
     let bus_names = collect_bus_names(&network);
+    // Found before any DER is added, which could outsize the real substation unit
+    let energized =
+        topology::substation_bus(&network).map(|root| topology::energized_buses(&network, root));
     let mut targets = target_buses.to_vec();
     if targets.is_empty() {
         targets = bus_names.keys().copied().collect();
@@ -239,6 +273,7 @@ pub fn hostcap_sweep(
     let mut summary_step = Vec::new();
     let mut summary_injection = Vec::new();
     let mut summary_success = Vec::new();
+    let mut summary_islanded = Vec::new();
     let mut summary_artifact = Vec::new();
 
     for &bus_id in &targets {
@@ -246,8 +281,28 @@ pub fn hostcap_sweep(
             .get(&bus_id)
             .unwrap_or(&"unknown".to_string())
             .clone();
+        let islanded = energized
+            .as_ref()
+            .is_some_and(|buses| !buses.contains(&bus_id));
+        if islanded {
+            eprintln!(
+                "hostcap bus {} is not connected to the substation; marking it islanded",
+                bus_id
+            );
+        }
         for step in 0..=steps {
             let injection = (step as f64) * max_injection / (steps as f64);
+            summary_bus.push(bus_id as i64);
+            summary_node.push(node_label.clone());
+            summary_step.push(step as i64);
+            summary_injection.push(injection);
+            summary_islanded.push(islanded);
+            if islanded {
+                summary_success.push(None);
+                summary_artifact.push(None);
+                continue;
+            }
+
             let host_network = add_virtual_der(&network, bus_id, injection, step);
             let artifact = out_dir.join(format!("hostcap_bus{}_step{}.parquet", bus_id, step));
             let solver = solver_kind.build_solver();
@@ -263,12 +318,8 @@ pub fn hostcap_sweep(
             if let Err(err) = run_result {
                 eprintln!("hostcap run failed for bus {} step {}: {err}", bus_id, step);
             }
-            summary_bus.push(bus_id as i64);
-            summary_node.push(node_label.clone());
-            summary_step.push(step as i64);
-            summary_injection.push(injection);
-            summary_success.push(success);
-            summary_artifact.push(artifact.display().to_string());
+            summary_success.push(Some(success));
+            summary_artifact.push(Some(artifact.display().to_string()));
         }
     }
 
@@ -278,6 +329,7 @@ pub fn hostcap_sweep(
         Series::new("step", summary_step),
         Series::new("injection_mw", summary_injection),
         Series::new("success", summary_success),
+        Series::new("islanded", summary_islanded),
         Series::new("artifact", summary_artifact),
     ])?;
     let detail_height = detail.height();
@@ -312,6 +364,43 @@ fn load_network(grid_file: &Path) -> Result<Network> {
         .with_context(|| format!("loading grid arrow {}", grid_file.display()))
 }
 
+fn build_opf_frame(solution: &AcOpfSolution) -> DataFrame {
+    let mut generators: Vec<_> = solution.generator_outputs.iter().collect();
+    generators.sort_by(|a, b| a.0.cmp(b.0));
+    let mut buses: Vec<_> = solution.bus_voltages.iter().collect();
+    buses.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut element = Vec::new();
+    let mut name = Vec::new();
+    let mut p_mw = Vec::new();
+    let mut vm_pu = Vec::new();
+    for (gen, p) in generators {
+        element.push("generator");
+        name.push(gen.clone());
+        p_mw.push(Some(*p));
+        vm_pu.push(None);
+    }
+    for (bus, vm) in buses {
+        element.push("bus");
+        name.push(bus.clone());
+        p_mw.push(None);
+        vm_pu.push(Some(*vm));
+    }
+
+    let rows = element.len();
+    DataFrame::new(vec![
+        Series::new("element", element),
+        Series::new("name", name),
+        Series::new("p_mw", p_mw),
+        Series::new("vm_pu", vm_pu),
+        Series::new("objective", vec![solution.objective.to_string(); rows]),
+        Series::new("objective_value", vec![solution.objective_value; rows]),
+        Series::new("total_losses_mw", vec![solution.total_losses_mw; rows]),
+        Series::new("converged", vec![solution.converged; rows]),
+    ])
+    .expect("dist opf frame should always construct")
+}
+
 fn build_node_frame(network: &Network, feeder: &str) -> DataFrame {
     let mut load_map: HashMap<BusId, f64> = HashMap::new();
     let mut load_map_q: HashMap<BusId, f64> = HashMap::new();
@@ -388,10 +477,16 @@ fn build_branch_frame(network: &Network, _feeder: &str) -> DataFrame {
             to_nodes.push(branch.to_bus.value() as i64);
             r.push(branch.resistance);
             x.push(branch.reactance);
-            b.push(0.0);
-            tap.push(1.0);
-            status.push("closed".to_string());
-            thermal.push(1e6);
+            b.push(branch.charging_b.value());
+            tap.push(branch.tap_ratio);
+            status.push(if branch.status { "closed" } else { "open" }.to_string());
+            // Unrated branches export a null limit rather than a sentinel
+            thermal.push(
+                branch
+                    .s_max
+                    .or(branch.rating_a)
+                    .map(|rating| rating.value()),
+            );
         }
     }
 
@@ -420,9 +515,7 @@ fn collect_bus_names(network: &Network) -> HashMap<usize, String> {
 }
 
 fn add_virtual_der(network: &Network, bus_id: usize, injection: f64, step: usize) -> Network {
-    let mut clone = Network {
-        graph: network.graph.clone(),
-    };
+    let mut clone = network.clone();
     let gen_id = GenId::new(clone.graph.node_count());
     let der = Gen {
         id: gen_id,
@@ -436,12 +529,209 @@ fn add_virtual_der(network: &Network, bus_id: usize, injection: f64, step: usize
         qmax: gat_core::Megavars(0.0),
         cost_model: gat_core::CostModel::NoCost,
         is_synchronous_condenser: false,
+        p_available: None,
+        emissions_rate: None,
+        dispatch_mode: DispatchMode::Auto,
+        inertia_h: None,
+        xd_subtransient: None,
+        fuel: None,
         status: true,
         voltage_setpoint: None,
         mbase: None,
         cost_startup: None,
+        startup_costs: None,
         cost_shutdown: None,
+        reactive_cost: None,
     };
     clone.graph.add_node(Node::Gen(der));
     clone
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{Branch, BranchId, Bus, CostModel, Load, LoadId, Megavars, Megawatts};
+    use polars::prelude::{ParquetReader, SerReader};
+
+    /// Substation plus an expensive DG at the end of a resistive two-segment feeder.
+    fn feeder() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: gat_core::Kilovolts(12.47),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for i in 0..2 {
+            network.graph.add_edge(
+                buses[i],
+                buses[i + 1],
+                Edge::Branch(Branch::new(
+                    BranchId::new(i),
+                    format!("seg{}", i),
+                    BusId::new(i),
+                    BusId::new(i + 1),
+                    0.05,
+                    0.05,
+                )),
+            );
+        }
+        network.graph.add_node(Node::Gen(Gen {
+            pmax: Megawatts(60.0),
+            qmin: Megavars(-30.0),
+            qmax: Megavars(30.0),
+            cost_model: CostModel::linear(0.0, 10.0),
+            ..Gen::new(GenId::new(0), "substation".to_string(), BusId::new(0))
+        }));
+        network.graph.add_node(Node::Gen(Gen {
+            pmax: Megawatts(40.0),
+            qmin: Megavars(-20.0),
+            qmax: Megavars(20.0),
+            cost_model: CostModel::linear(0.0, 50.0),
+            ..Gen::new(GenId::new(1), "dg".to_string(), BusId::new(2))
+        }));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(0),
+            name: "feeder_end".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(30.0),
+            reactive_power: Megavars(5.0),
+            zip: None,
+        }));
+        network
+    }
+
+    const TAPPED_CASE: &str = r#"function mpc = dist_tapped
+mpc.version = '2';
+mpc.baseMVA = 100;
+mpc.bus = [
+	1	3	0	0	0	0	1	1	0	12.47	1	1.05	0.95;
+	2	1	2	0.5	0	0	1	1	0	12.47	1	1.05	0.95;
+	3	1	1	0.2	0	0	1	1	0	4.16	1	1.05	0.95;
+];
+mpc.gen = [
+	1	3	0	0	-5	1	100	1	10	0	0	0	0	0	0	0	0	0	0	0	0;
+];
+mpc.branch = [
+	1	2	0.02	0.04	0.003	25	30	35	0	0	1	-360	360;
+	2	3	0.01	0.08	0	0	0	0	0.975	0	1	-360	360;
+];
+"#;
+
+    #[test]
+    fn test_branch_frame_reports_branch_parameters() -> Result<()> {
+        let tmp = tempdir()?;
+        let case = tmp.path().join("dist_tapped.m");
+        fs::write(&case, TAPPED_CASE)?;
+        let out_dir = tmp.path().join("dist");
+        import_matpower_case(case.to_str().unwrap(), &out_dir, Some("f1"))?;
+
+        let branches =
+            ParquetReader::new(File::open(out_dir.join("dist_branches.parquet"))?).finish()?;
+        assert_eq!(branches.height(), 2);
+        let b = branches.column("b")?.f64()?;
+        let tap = branches.column("tap")?.f64()?;
+        let status = branches.column("status")?.utf8()?;
+        let thermal = branches.column("thermal_limit")?.f64()?;
+
+        // Rated line with line charging
+        assert!((b.get(0).unwrap() - 0.003).abs() < 1e-12);
+        assert_eq!(tap.get(0), Some(1.0));
+        assert_eq!(thermal.get(0), Some(25.0));
+        // Unrated off-nominal transformer
+        assert_eq!(b.get(1), Some(0.0));
+        assert_eq!(tap.get(1), Some(0.975));
+        assert_eq!(thermal.get(1), None);
+        assert!(status.into_iter().all(|s| s == Some("closed")));
+        Ok(())
+    }
+
+    fn run(grid: &Path, out: &Path, objective: &str) -> Result<DataFrame> {
+        run_optimal_power_flow(grid, out, SolverKind::default(), 1e-4, 300, objective)?;
+        Ok(ParquetReader::new(File::open(out)?).finish()?)
+    }
+
+    fn column_f64(df: &DataFrame, column: &str) -> f64 {
+        df.column(column).unwrap().f64().unwrap().get(0).unwrap()
+    }
+
+    #[test]
+    fn test_objective_changes_dist_opf_result() -> Result<()> {
+        let tmp = tempdir()?;
+        let grid = tmp.path().join("feeder.arrow");
+        gat_io::exporters::write_network_to_arrow_directory(&feeder(), &grid)?;
+
+        let min_cost = run(&grid, &tmp.path().join("min_cost.parquet"), "min_cost")?;
+        let min_loss = run(&grid, &tmp.path().join("min_loss.parquet"), "min_loss")?;
+
+        assert_eq!(
+            min_cost.column("objective")?.utf8()?.get(0),
+            Some("min_cost")
+        );
+        assert_eq!(
+            min_loss.column("objective")?.utf8()?.get(0),
+            Some("min_losses")
+        );
+        let (cost_losses, loss_losses) = (
+            column_f64(&min_cost, "total_losses_mw"),
+            column_f64(&min_loss, "total_losses_mw"),
+        );
+        assert!(
+            loss_losses < cost_losses,
+            "min_loss losses {:.4} MW should be below min_cost losses {:.4} MW",
+            loss_losses,
+            cost_losses
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_hostcap_marks_bus_behind_open_branch_islanded() -> Result<()> {
+        // Bus 2 hangs off seg1 only, which is out of service
+        let mut network = feeder();
+        for edge in network.graph.edge_weights_mut() {
+            if let Edge::Branch(branch) = edge {
+                branch.status = branch.name != "seg1";
+            }
+        }
+        let tmp = tempdir()?;
+        let grid = tmp.path().join("feeder.arrow");
+        gat_io::exporters::write_network_to_arrow_directory(&network, &grid)?;
+        let out = tmp.path().join("hostcap");
+        hostcap_sweep(&grid, &[1, 2], 5.0, 1, &out, SolverKind::default())?;
+
+        let summary =
+            ParquetReader::new(File::open(out.join("hostcap_summary.parquet"))?).finish()?;
+        let bus = summary.column("bus_id")?.i64()?;
+        let islanded = summary.column("islanded")?.bool()?;
+        let success = summary.column("success")?.bool()?;
+        assert_eq!(summary.height(), 4);
+        for row in 0..summary.height() {
+            let behind_open_branch = bus.get(row) == Some(2);
+            assert_eq!(islanded.get(row), Some(behind_open_branch));
+            assert_eq!(success.get(row).is_none(), behind_open_branch);
+        }
+        assert!(!out.join("hostcap_bus2_step1.parquet").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_objective_is_rejected() -> Result<()> {
+        let tmp = tempdir()?;
+        let err = run_optimal_power_flow(
+            &tmp.path().join("missing.arrow"),
+            &tmp.path().join("out.parquet"),
+            SolverKind::default(),
+            1e-4,
+            300,
+            "max_profit",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("max_profit"));
+        Ok(())
+    }
+}
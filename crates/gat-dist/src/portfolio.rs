@@ -0,0 +1,384 @@
+//! Simultaneous hosting capacity for a portfolio of candidate buses.
+//!
+//! [`crate::hostcap_sweep`] grows DER at one bus at a time, which overstates what a feeder can
+//! absorb when several sites connect together and share upstream conductors. This module solves
+//! a single linear OPF with one injection variable per candidate bus instead:
+//!
+//! ```text
+//! maximize    Σ x_i
+//! subject to  Σ x_i ≤ budget
+//!             V_min² ≤ w_j(x) ≤ V_max²        every bus downstream of the substation
+//!             |P_l(x)| ≤ √(S_max,l² − Q_l²)    every rated branch
+//!             x_i ≥ 0
+//! ```
+//!
+//! Flows and squared voltages `w` follow the LinDistFlow model of Baran & Wu
+//! (doi:10.1109/61.25627): losses are neglected, so branch flow is the net load of the subtree
+//! below it and `w_child = w_parent − 2(r·P + x·Q)`. DER is injected at unity power factor, which
+//! keeps reactive flows at their base-case value and every constraint linear in `x`.
+
+use anyhow::{anyhow, bail, Context, Result};
+use gat_core::{Network, Node};
+use good_lp::solvers::clarabel::clarabel;
+use good_lp::{constraint, variable, variables, Expression, Solution, SolverModel, Variable};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::load_network;
+use crate::topology::{RadialFeeder, BASE_MVA};
+
+/// Slack below which a constraint is reported as binding.
+const BINDING_TOL: f64 = 1e-5;
+
+/// DER allocated to one candidate bus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortfolioAllocation {
+    pub bus_id: usize,
+    pub node_label: String,
+    pub injection_mw: f64,
+}
+
+/// Largest simultaneous DER portfolio the feeder can host within its limits.
+#[derive(Debug, Clone)]
+pub struct HostcapPortfolio {
+    /// Per-candidate injections at the feasibility frontier, in candidate order
+    pub allocations: Vec<PortfolioAllocation>,
+    /// Total hosted DER (MW)
+    pub hosted_mw: f64,
+    /// DER budget that was offered (MW)
+    pub budget_mw: f64,
+    /// Constraints holding the frontier in place, e.g. `budget`, `vmax bus3`, `thermal seg0`
+    pub binding: Vec<String>,
+}
+
+/// Distribute up to `total_mw` of DER across `candidate_buses` to maximize the hosted total
+/// without violating bus voltage limits or branch thermal ratings.
+///
+/// The feeder must be radial and is rooted at the bus of the largest in-service generator (the
+/// substation), whose voltage is held at its stored setpoint. Other generators and loads are
+/// treated as fixed injections. Buses without explicit limits use 0.95–1.05 p.u.
+pub fn hostcap_portfolio(
+    grid_file: &Path,
+    candidate_buses: &[usize],
+    total_mw: f64,
+) -> Result<HostcapPortfolio> {
+    let network = load_network(grid_file)?;
+    solve_portfolio(&network, candidate_buses, total_mw)
+        .with_context(|| format!("running hostcap portfolio on {}", grid_file.display()))
+}
+
+fn solve_portfolio(
+    network: &Network,
+    candidate_buses: &[usize],
+    total_mw: f64,
+) -> Result<HostcapPortfolio> {
+    if !(total_mw.is_finite() && total_mw > 0.0) {
+        bail!(
+            "hostcap portfolio budget must be positive, got {}",
+            total_mw
+        );
+    }
+    if candidate_buses.is_empty() {
+        bail!("hostcap portfolio needs at least one candidate bus");
+    }
+
+    let mut names: HashMap<usize, String> = HashMap::new();
+    let mut v_limits: HashMap<usize, (f64, f64)> = HashMap::new();
+    let mut v_set: HashMap<usize, f64> = HashMap::new();
+    let mut p_net: HashMap<usize, f64> = HashMap::new();
+    let mut q_net: HashMap<usize, f64> = HashMap::new();
+
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Bus(bus) => {
+                let id = bus.id.value();
+                names.insert(id, bus.name.clone());
+                v_limits.insert(
+                    id,
+                    (
+                        bus.vmin_pu.map(|v| v.value()).unwrap_or(0.95),
+                        bus.vmax_pu.map(|v| v.value()).unwrap_or(1.05),
+                    ),
+                );
+                v_set.insert(id, bus.voltage_pu.value());
+            }
+            Node::Load(load) => {
+                *p_net.entry(load.bus.value()).or_default() += load.active_power.value();
+                *q_net.entry(load.bus.value()).or_default() += load.reactive_power.value();
+            }
+            _ => {}
+        }
+    }
+    let RadialFeeder {
+        root,
+        branches,
+        paths,
+        order,
+    } = RadialFeeder::from_network(network)?;
+
+    // Fixed generation away from the substation offsets local load
+    for node in network.graph.node_weights() {
+        if let Node::Gen(gen) = node {
+            if gen.status && gen.bus.value() != root {
+                *p_net.entry(gen.bus.value()).or_default() -= gen.active_power.value();
+                *q_net.entry(gen.bus.value()).or_default() -= gen.reactive_power.value();
+            }
+        }
+    }
+
+    let mut candidates: Vec<usize> = Vec::new();
+    for &bus in candidate_buses {
+        if !names.contains_key(&bus) {
+            bail!("candidate bus {} is not in the grid", bus);
+        }
+        if !paths.contains_key(&bus) {
+            bail!("candidate bus {} is not connected to the substation", bus);
+        }
+        if !candidates.contains(&bus) {
+            candidates.push(bus);
+        }
+    }
+
+    // Base-case branch flows: the net load of every bus downstream of the branch
+    let mut p_base = vec![0.0; branches.len()];
+    let mut q_base = vec![0.0; branches.len()];
+    for &bus in &order {
+        let (p, q) = (
+            p_net.get(&bus).copied().unwrap_or(0.0),
+            q_net.get(&bus).copied().unwrap_or(0.0),
+        );
+        for &idx in &paths[&bus] {
+            p_base[idx] += p;
+            q_base[idx] += q;
+        }
+    }
+
+    let mut vars = variables!();
+    let injections: Vec<Variable> = candidates
+        .iter()
+        .map(|_| vars.add(variable().min(0.0).max(total_mw)))
+        .collect();
+    let hosted: Expression = injections.iter().copied().sum();
+    let mut model = vars.maximise(hosted.clone()).using(clarabel);
+    model = model.with(constraint!(hosted.clone() <= total_mw));
+
+    let w_root = v_set.get(&root).copied().unwrap_or(1.0).powi(2);
+    let mut voltage_rows = Vec::new();
+    for &bus in &order {
+        if bus == root {
+            continue;
+        }
+        let path = &paths[&bus];
+        let w_base = w_root
+            - 2.0
+                * path
+                    .iter()
+                    .map(|&l| {
+                        branches[l].resistance * p_base[l] + branches[l].reactance * q_base[l]
+                    })
+                    .sum::<f64>()
+                / BASE_MVA;
+        // Injecting at a candidate raises w along the feeder section it shares with this bus
+        let sensitivity: Vec<f64> = candidates
+            .iter()
+            .map(|c| {
+                2.0 * paths[c]
+                    .iter()
+                    .filter(|&&l| path.contains(&l))
+                    .map(|&l| branches[l].resistance)
+                    .sum::<f64>()
+                    / BASE_MVA
+            })
+            .collect();
+        let w = Expression::from(w_base)
+            + sensitivity
+                .iter()
+                .zip(&injections)
+                .map(|(s, x)| *s * *x)
+                .sum::<Expression>();
+        let (vmin, vmax) = v_limits[&bus];
+        model = model.with(constraint!(w.clone() <= vmax * vmax));
+        model = model.with(constraint!(w >= vmin * vmin));
+        voltage_rows.push((bus, w_base, sensitivity));
+    }
+
+    let mut thermal_rows = Vec::new();
+    for (idx, branch) in branches.iter().enumerate() {
+        let Some(rating) = branch.rating_mva else {
+            continue;
+        };
+        let p_limit_sq = rating * rating - q_base[idx] * q_base[idx];
+        if p_limit_sq <= 0.0 {
+            bail!(
+                "branch '{}' is overloaded by reactive flow alone ({:.3} MVAr > {:.3} MVA)",
+                branch.label,
+                q_base[idx].abs(),
+                rating
+            );
+        }
+        let p_limit = p_limit_sq.sqrt();
+        let downstream: Vec<usize> = candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| paths[*c].contains(&idx))
+            .map(|(i, _)| i)
+            .collect();
+        let flow = Expression::from(p_base[idx])
+            - downstream
+                .iter()
+                .map(|&i| injections[i])
+                .sum::<Expression>();
+        model = model.with(constraint!(flow.clone() <= p_limit));
+        model = model.with(constraint!(flow >= -p_limit));
+        thermal_rows.push((idx, p_limit, downstream));
+    }
+
+    let solution = model.solve().map_err(|e| {
+        anyhow!(
+            "hostcap portfolio LP failed ({:?}); the base case may already violate limits",
+            e
+        )
+    })?;
+
+    let values: Vec<f64> = injections
+        .iter()
+        .map(|x| solution.value(*x).max(0.0))
+        .collect();
+    let hosted_mw: f64 = values.iter().sum();
+
+    let mut binding = Vec::new();
+    if total_mw - hosted_mw < BINDING_TOL {
+        binding.push("budget".to_string());
+    }
+    for (bus, w_base, sensitivity) in &voltage_rows {
+        let w = w_base
+            + sensitivity
+                .iter()
+                .zip(&values)
+                .map(|(s, x)| s * x)
+                .sum::<f64>();
+        let (vmin, vmax) = v_limits[bus];
+        if vmax * vmax - w < BINDING_TOL {
+            binding.push(format!("vmax {}", names[bus]));
+        }
+        if w - vmin * vmin < BINDING_TOL {
+            binding.push(format!("vmin {}", names[bus]));
+        }
+    }
+    for (idx, p_limit, downstream) in &thermal_rows {
+        let flow = p_base[*idx] - downstream.iter().map(|&i| values[i]).sum::<f64>();
+        if p_limit - flow.abs() < BINDING_TOL {
+            binding.push(format!("thermal {}", branches[*idx].label));
+        }
+    }
+
+    let allocations = candidates
+        .iter()
+        .zip(&values)
+        .map(|(&bus_id, &injection_mw)| PortfolioAllocation {
+            bus_id,
+            node_label: names[&bus_id].clone(),
+            injection_mw,
+        })
+        .collect();
+
+    Ok(HostcapPortfolio {
+        allocations,
+        hosted_mw,
+        budget_mw: total_mw,
+        binding,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{
+        Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Megavars, MegavoltAmperes,
+        Megawatts,
+    };
+    use tempfile::tempdir;
+
+    /// Substation feeding a 10 MVA trunk that splits into two 50 MVA laterals.
+    fn split_feeder() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..4)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: gat_core::Kilovolts(12.47),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (i, (from, to, rating)) in [(0, 1, 10.0), (1, 2, 50.0), (1, 3, 50.0)]
+            .into_iter()
+            .enumerate()
+        {
+            network.graph.add_edge(
+                buses[from],
+                buses[to],
+                Edge::Branch(Branch {
+                    s_max: Some(MegavoltAmperes(rating)),
+                    ..Branch::new(
+                        BranchId::new(i),
+                        format!("seg{}", i),
+                        BusId::new(from),
+                        BusId::new(to),
+                        0.01,
+                        0.01,
+                    )
+                }),
+            );
+        }
+        network.graph.add_node(Node::Gen(Gen {
+            pmin: Megawatts(-100.0),
+            pmax: Megawatts(100.0),
+            ..Gen::new(GenId::new(0), "substation".to_string(), BusId::new(0))
+        }));
+        for (i, bus) in [2, 3].into_iter().enumerate() {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(i),
+                name: format!("load{}", bus),
+                bus: BusId::new(bus),
+                active_power: Megawatts(2.0),
+                reactive_power: Megavars(0.0),
+                zip: None,
+            }));
+        }
+        network
+    }
+
+    #[test]
+    fn test_shared_trunk_limits_joint_hosting() -> Result<()> {
+        let tmp = tempdir()?;
+        let grid = tmp.path().join("feeder.arrow");
+        gat_io::exporters::write_network_to_arrow_directory(&split_feeder(), &grid)?;
+
+        let alone_2 = hostcap_portfolio(&grid, &[2], 100.0)?;
+        let alone_3 = hostcap_portfolio(&grid, &[3], 100.0)?;
+        let joint = hostcap_portfolio(&grid, &[2, 3], 100.0)?;
+
+        // Each bus alone can backfeed the 10 MVA trunk on top of the 4 MW feeder load
+        assert!((alone_2.hosted_mw - 14.0).abs() < 1e-3);
+        assert!((alone_3.hosted_mw - 14.0).abs() < 1e-3);
+        // Together they share that trunk
+        assert!((joint.hosted_mw - 14.0).abs() < 1e-3);
+        assert!(joint.hosted_mw < alone_2.hosted_mw + alone_3.hosted_mw);
+        assert!(joint.binding.contains(&"thermal seg0".to_string()));
+        assert_eq!(joint.allocations.len(), 2);
+        let allocated: f64 = joint.allocations.iter().map(|a| a.injection_mw).sum();
+        assert!((allocated - joint.hosted_mw).abs() < 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn test_budget_caps_portfolio() -> Result<()> {
+        let portfolio = solve_portfolio(&split_feeder(), &[2, 3], 5.0)?;
+        assert!((portfolio.hosted_mw - 5.0).abs() < 1e-3);
+        assert_eq!(portfolio.binding, vec!["budget".to_string()]);
+        assert!(solve_portfolio(&split_feeder(), &[7], 5.0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+//! Voltage-versus-distance profile along a radial feeder.
+
+use anyhow::{anyhow, Result};
+use gat_algo::power_flow::AcPfSolution;
+use gat_core::{BusId, Network, Node};
+use polars::prelude::{DataFrame, NamedFrom, Series};
+use std::collections::HashMap;
+
+use crate::topology::{RadialFeeder, BASE_MVA};
+
+/// ANSI C84.1 Range A service voltage band.
+const V_MIN_PU: f64 = 0.95;
+const V_MAX_PU: f64 = 1.05;
+
+/// Build the classic voltage profile of a radial feeder from a power-flow result.
+///
+/// Each bus reachable from the substation gets one row with its electrical distance
+/// (`distance_ohm`, the summed series impedance |r + jx| of the branches back to the
+/// substation, in ohms at each branch's far-end voltage), its solved `v_pu`, the
+/// `path` of branch names from the substation, and an `out_of_range` flag for
+/// voltages outside 0.95–1.05 p.u. Rows are ordered depth-first along the feeder,
+/// so every lateral follows the bus it taps off.
+pub fn voltage_profile(network: &Network, result: &AcPfSolution) -> Result<DataFrame> {
+    let feeder = RadialFeeder::from_network(network)?;
+
+    let mut buses: HashMap<usize, (String, f64)> = HashMap::new();
+    for node in network.graph.node_weights() {
+        if let Node::Bus(bus) = node {
+            buses.insert(bus.id.value(), (bus.name.clone(), bus.base_kv.value()));
+        }
+    }
+
+    // Series impedance of every branch in ohms, on the voltage base of the bus it feeds
+    let mut z_ohm = vec![0.0; feeder.branches.len()];
+    for &bus in &feeder.order {
+        if let Some(&last) = feeder.paths[&bus].last() {
+            let branch = &feeder.branches[last];
+            let base_kv = buses.get(&bus).map(|(_, kv)| *kv).unwrap_or(0.0);
+            z_ohm[last] = branch.resistance.hypot(branch.reactance) * base_kv * base_kv / BASE_MVA;
+        }
+    }
+
+    let mut bus_id = Vec::new();
+    let mut node_label = Vec::new();
+    let mut distance_ohm = Vec::new();
+    let mut v_pu = Vec::new();
+    let mut path = Vec::new();
+    let mut out_of_range = Vec::new();
+    for &bus in &feeder.order {
+        let v = *result
+            .bus_voltage_magnitude
+            .get(&BusId::new(bus))
+            .ok_or_else(|| anyhow!("power-flow result has no voltage for bus {}", bus))?;
+        bus_id.push(bus as i64);
+        node_label.push(
+            buses
+                .get(&bus)
+                .map(|(name, _)| name.clone())
+                .unwrap_or_default(),
+        );
+        distance_ohm.push(
+            feeder.paths[&bus]
+                .iter()
+                .map(|&idx| z_ohm[idx])
+                .sum::<f64>(),
+        );
+        v_pu.push(v);
+        path.push(
+            feeder.paths[&bus]
+                .iter()
+                .map(|&idx| feeder.branches[idx].label.as_str())
+                .collect::<Vec<_>>()
+                .join(" > "),
+        );
+        out_of_range.push(!(V_MIN_PU..=V_MAX_PU).contains(&v));
+    }
+
+    Ok(DataFrame::new(vec![
+        Series::new("bus_id", bus_id),
+        Series::new("node_label", node_label),
+        Series::new("distance_ohm", distance_ohm),
+        Series::new("v_pu", v_pu),
+        Series::new("path", path),
+        Series::new("out_of_range", out_of_range),
+    ])?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_algo::power_flow::ac_pf::AcPowerFlowSolver;
+    use gat_core::{Branch, BranchId, Bus, Edge, Gen, GenId, Load, LoadId, Megavars, Megawatts};
+
+    /// Four-bus trunk with a lateral tapped off bus 1.
+    fn radial_feeder() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..5)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: gat_core::Kilovolts(12.47),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (i, (from, to)) in [(0, 1), (1, 2), (2, 3), (1, 4)].into_iter().enumerate() {
+            network.graph.add_edge(
+                buses[from],
+                buses[to],
+                Edge::Branch(Branch::new(
+                    BranchId::new(i),
+                    format!("seg{}", i),
+                    BusId::new(from),
+                    BusId::new(to),
+                    0.01,
+                    0.02,
+                )),
+            );
+        }
+        network.graph.add_node(Node::Gen(Gen {
+            pmax: Megawatts(100.0),
+            ..Gen::new(GenId::new(0), "substation".to_string(), BusId::new(0))
+        }));
+        for bus in 1..5 {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(bus),
+                name: format!("load{}", bus),
+                bus: BusId::new(bus),
+                active_power: Megawatts(2.0),
+                reactive_power: Megavars(0.5),
+                zip: None,
+            }));
+        }
+        network
+    }
+
+    #[test]
+    fn test_voltage_falls_from_source_to_tail() -> Result<()> {
+        let network = radial_feeder();
+        let result = AcPowerFlowSolver::new().solve(&network)?;
+        let profile = voltage_profile(&network, &result)?;
+
+        let ids: Vec<i64> = profile
+            .column("bus_id")?
+            .i64()?
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+        let v: Vec<f64> = profile.column("v_pu")?.f64()?.into_no_null_iter().collect();
+        let d: Vec<f64> = profile
+            .column("distance_ohm")?
+            .f64()?
+            .into_no_null_iter()
+            .collect();
+
+        // Trunk bus0 → bus3: farther out and lower voltage at every step
+        for i in 1..4 {
+            assert!(d[i] > d[i - 1]);
+            assert!(
+                v[i] < v[i - 1],
+                "v{} {:.5} >= v{} {:.5}",
+                i,
+                v[i],
+                i - 1,
+                v[i - 1]
+            );
+        }
+        // The lateral sits one segment past its tap point
+        assert!((d[4] - d[1] - (d[1] - d[0])).abs() < 1e-9);
+        assert!(v[4] < v[1]);
+        let paths = profile.column("path")?.utf8()?;
+        assert_eq!(paths.get(3), Some("seg0 > seg1 > seg2"));
+        assert_eq!(paths.get(4), Some("seg0 > seg3"));
+        assert!(!profile.column("out_of_range")?.bool()?.any());
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+//! Time-aware hosting capacity at one bus.
+//!
+//! Static hosting capacity assumes the DER runs at nameplate against a single load snapshot.
+//! [`hostcap_temporal`] instead walks a profile of hours, each scaling the feeder load and the
+//! DER's available output, and finds for every hour the largest nameplate whose output
+//! `availability · nameplate` keeps the feeder within its voltage and thermal limits. Using the
+//! same LinDistFlow model as [`crate::hostcap_portfolio`], each hour's limit is the tightest of
+//!
+//! ```text
+//! a·x ≤ (V_max² − w_j) / s_j                 every bus j, s_j = 2 Σ r_l / S_base over the
+//!                                             branches shared by j and the DER bus
+//! a·x ≤ P_l + √(S_max,l² − Q_l²)             every rated branch upstream of the DER bus
+//! ```
+//!
+//! The reported capacity is the largest nameplate feasible for at least the target fraction of
+//! profile time: a duration-weighted quantile of the hourly limits. Hours already violating a
+//! limit without any DER count as infeasible at every level.
+
+use anyhow::{bail, Context, Result};
+use gat_core::{Network, Node};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::load_network;
+use crate::topology::{RadialFeeder, BASE_MVA};
+
+/// One step of a hosting capacity profile.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HostcapHour {
+    /// Multiplier on every load's stored P and Q
+    pub load_scale: f64,
+    /// DER output as a fraction of nameplate, in [0, 1]
+    pub der_availability: f64,
+    /// Time the step represents (hours); weights it in the target fraction
+    pub duration_h: f64,
+}
+
+/// Hosting capacity of one bus over a load and DER availability profile.
+#[derive(Debug, Clone)]
+pub struct TemporalHostcap {
+    pub bus_id: usize,
+    pub node_label: String,
+    /// Largest DER nameplate feasible for at least the target fraction of profile time (MW)
+    pub hosting_capacity_mw: f64,
+    /// Profile index of the hour whose limit sets the capacity
+    pub limiting_hour: usize,
+    /// Constraint binding in the limiting hour, e.g. `vmax bus3`, `thermal seg0`
+    pub limiting_constraint: String,
+    /// Largest feasible nameplate in each hour (MW); `None` when the hour violates limits
+    /// without DER, infinite when nothing limits it (no DER output)
+    pub hourly_capacity_mw: Vec<Option<f64>>,
+    /// Fraction of profile time in which `hosting_capacity_mw` is feasible
+    pub feasible_fraction: f64,
+}
+
+/// Hosting capacity of `bus` over `profile`, feasible for at least `target_fraction` of the
+/// profile's duration.
+///
+/// The feeder must be radial and is rooted at the bus of the largest in-service generator (the
+/// substation), whose voltage is held at its stored setpoint. Other generators are fixed
+/// injections that the profile does not scale. DER runs at unity power factor, and buses without
+/// explicit limits use 0.95–1.05 p.u.
+pub fn hostcap_temporal(
+    grid_file: &Path,
+    bus: usize,
+    profile: &[HostcapHour],
+    target_fraction: f64,
+) -> Result<TemporalHostcap> {
+    let network = load_network(grid_file)?;
+    solve_temporal(&network, bus, profile, target_fraction)
+        .with_context(|| format!("running temporal hostcap on {}", grid_file.display()))
+}
+
+fn solve_temporal(
+    network: &Network,
+    bus: usize,
+    profile: &[HostcapHour],
+    target_fraction: f64,
+) -> Result<TemporalHostcap> {
+    if !(target_fraction > 0.0 && target_fraction <= 1.0) {
+        bail!(
+            "hostcap target fraction must be in (0, 1], got {}",
+            target_fraction
+        );
+    }
+    if profile.is_empty() {
+        bail!("hostcap profile needs at least one hour");
+    }
+    for (hour, step) in profile.iter().enumerate() {
+        if !(step.load_scale.is_finite() && step.load_scale >= 0.0) {
+            bail!("hour {} has invalid load scale {}", hour, step.load_scale);
+        }
+        if !(0.0..=1.0).contains(&step.der_availability) {
+            bail!(
+                "hour {} has DER availability {} outside [0, 1]",
+                hour,
+                step.der_availability
+            );
+        }
+        if !(step.duration_h.is_finite() && step.duration_h > 0.0) {
+            bail!(
+                "hour {} has non-positive duration {}",
+                hour,
+                step.duration_h
+            );
+        }
+    }
+
+    let mut names: HashMap<usize, String> = HashMap::new();
+    let mut v_limits: HashMap<usize, (f64, f64)> = HashMap::new();
+    let mut v_set: HashMap<usize, f64> = HashMap::new();
+    let mut load: HashMap<usize, (f64, f64)> = HashMap::new();
+    for node in network.graph.node_weights() {
+        match node {
+            Node::Bus(b) => {
+                let id = b.id.value();
+                names.insert(id, b.name.clone());
+                v_limits.insert(
+                    id,
+                    (
+                        b.vmin_pu.map(|v| v.value()).unwrap_or(0.95),
+                        b.vmax_pu.map(|v| v.value()).unwrap_or(1.05),
+                    ),
+                );
+                v_set.insert(id, b.voltage_pu.value());
+            }
+            Node::Load(l) => {
+                let entry = load.entry(l.bus.value()).or_default();
+                entry.0 += l.active_power.value();
+                entry.1 += l.reactive_power.value();
+            }
+            _ => {}
+        }
+    }
+    let RadialFeeder {
+        root,
+        branches,
+        paths,
+        order,
+    } = RadialFeeder::from_network(network)?;
+
+    let mut fixed_gen: HashMap<usize, (f64, f64)> = HashMap::new();
+    for node in network.graph.node_weights() {
+        if let Node::Gen(gen) = node {
+            if gen.status && gen.bus.value() != root {
+                let entry = fixed_gen.entry(gen.bus.value()).or_default();
+                entry.0 += gen.active_power.value();
+                entry.1 += gen.reactive_power.value();
+            }
+        }
+    }
+
+    let Some(node_label) = names.get(&bus).cloned() else {
+        bail!("bus {} is not in the grid", bus);
+    };
+    let Some(der_path) = paths.get(&bus) else {
+        bail!("bus {} is not connected to the substation", bus);
+    };
+
+    // Voltage rise per MW injected at the DER bus, for every other bus
+    let sensitivity: HashMap<usize, f64> = order
+        .iter()
+        .filter(|&&j| j != root)
+        .map(|&j| {
+            let shared: f64 = der_path
+                .iter()
+                .filter(|l| paths[&j].contains(l))
+                .map(|&l| branches[l].resistance)
+                .sum();
+            (j, 2.0 * shared / BASE_MVA)
+        })
+        .collect();
+    let w_root = v_set.get(&root).copied().unwrap_or(1.0).powi(2);
+
+    let mut hourly_capacity_mw = Vec::with_capacity(profile.len());
+    let mut hourly_binding = Vec::with_capacity(profile.len());
+    for step in profile {
+        let mut p_base = vec![0.0; branches.len()];
+        let mut q_base = vec![0.0; branches.len()];
+        for &j in &order {
+            let (pd, qd) = load.get(&j).copied().unwrap_or_default();
+            let (pg, qg) = fixed_gen.get(&j).copied().unwrap_or_default();
+            let (p, q) = (step.load_scale * pd - pg, step.load_scale * qd - qg);
+            for &l in &paths[&j] {
+                p_base[l] += p;
+                q_base[l] += q;
+            }
+        }
+
+        // Largest DER output a·x the hour allows, and the constraint setting it
+        let mut output_limit = f64::INFINITY;
+        let mut binding = String::new();
+        let mut feasible = true;
+        for &j in &order {
+            if j == root {
+                continue;
+            }
+            let w = w_root
+                - 2.0
+                    * paths[&j]
+                        .iter()
+                        .map(|&l| {
+                            branches[l].resistance * p_base[l] + branches[l].reactance * q_base[l]
+                        })
+                        .sum::<f64>()
+                    / BASE_MVA;
+            let (vmin, vmax) = v_limits[&j];
+            if w > vmax * vmax || w < vmin * vmin {
+                feasible = false;
+                break;
+            }
+            let s = sensitivity[&j];
+            if s > 0.0 && (vmax * vmax - w) / s < output_limit {
+                output_limit = (vmax * vmax - w) / s;
+                binding = format!("vmax {}", names[&j]);
+            }
+        }
+        if feasible {
+            for &l in der_path {
+                let Some(rating) = branches[l].rating_mva else {
+                    continue;
+                };
+                let p_limit_sq = rating * rating - q_base[l] * q_base[l];
+                if p_limit_sq <= 0.0 || p_base[l].abs() > p_limit_sq.sqrt() {
+                    feasible = false;
+                    break;
+                }
+                // DER output reverses the flow until the backfeed reaches the rating
+                let headroom = p_base[l] + p_limit_sq.sqrt();
+                if headroom < output_limit {
+                    output_limit = headroom;
+                    binding = format!("thermal {}", branches[l].label);
+                }
+            }
+        }
+
+        hourly_capacity_mw.push(feasible.then(|| {
+            if step.der_availability > 0.0 {
+                output_limit / step.der_availability
+            } else {
+                f64::INFINITY
+            }
+        }));
+        hourly_binding.push(if step.der_availability > 0.0 {
+            binding
+        } else {
+            String::new()
+        });
+    }
+
+    // Walk hours from most to least accommodating until the target share of time is covered
+    let total_h: f64 = profile.iter().map(|step| step.duration_h).sum();
+    let mut ranked: Vec<(usize, f64)> = hourly_capacity_mw
+        .iter()
+        .enumerate()
+        .filter_map(|(hour, capacity)| capacity.map(|c| (hour, c)))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    let mut covered_h = 0.0;
+    let mut limit = None;
+    for &(hour, capacity) in &ranked {
+        covered_h += profile[hour].duration_h;
+        if covered_h >= target_fraction * total_h - 1e-9 {
+            limit = Some((hour, capacity));
+            break;
+        }
+    }
+    let Some((limiting_hour, hosting_capacity_mw)) = limit else {
+        bail!(
+            "feeder violates limits without DER for more than {:.1}% of the profile",
+            (1.0 - target_fraction) * 100.0
+        );
+    };
+    if hosting_capacity_mw.is_infinite() {
+        bail!(
+            "hosting capacity at bus {} is unbounded: DER output is zero for the target share of the profile",
+            bus
+        );
+    }
+
+    let feasible_h: f64 = ranked
+        .iter()
+        .filter(|(_, capacity)| *capacity >= hosting_capacity_mw)
+        .map(|&(hour, _)| profile[hour].duration_h)
+        .sum();
+
+    Ok(TemporalHostcap {
+        bus_id: bus,
+        node_label,
+        hosting_capacity_mw,
+        limiting_hour,
+        limiting_constraint: hourly_binding.swap_remove(limiting_hour),
+        hourly_capacity_mw,
+        feasible_fraction: feasible_h / total_h,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{
+        Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Megavars, MegavoltAmperes,
+        Megawatts,
+    };
+
+    /// Substation, a 10 MVA trunk and a 50 MVA lateral to a 4 MW load.
+    fn feeder() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (0..3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    base_kv: gat_core::Kilovolts(12.47),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (i, rating) in [10.0, 50.0].into_iter().enumerate() {
+            network.graph.add_edge(
+                buses[i],
+                buses[i + 1],
+                Edge::Branch(Branch {
+                    s_max: Some(MegavoltAmperes(rating)),
+                    ..Branch::new(
+                        BranchId::new(i),
+                        format!("seg{}", i),
+                        BusId::new(i),
+                        BusId::new(i + 1),
+                        0.01,
+                        0.01,
+                    )
+                }),
+            );
+        }
+        network.graph.add_node(Node::Gen(Gen {
+            pmin: Megawatts(-100.0),
+            pmax: Megawatts(100.0),
+            ..Gen::new(GenId::new(0), "substation".to_string(), BusId::new(0))
+        }));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(0),
+            name: "load2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(4.0),
+            reactive_power: Megavars(0.0),
+            zip: None,
+        }));
+        network
+    }
+
+    fn hour(load_scale: f64, der_availability: f64) -> HostcapHour {
+        HostcapHour {
+            load_scale,
+            der_availability,
+            duration_h: 6.0,
+        }
+    }
+
+    #[test]
+    fn test_midday_solar_peak_sets_capacity() -> Result<()> {
+        // Night, morning, midday and evening
+        let profile = [
+            hour(1.0, 0.0),
+            hour(0.8, 0.5),
+            hour(0.3, 1.0),
+            hour(1.0, 0.2),
+        ];
+
+        let all_hours = solve_temporal(&feeder(), 2, &profile, 1.0)?;
+        // Full sun against 1.2 MW of load backfeeds the 10 MVA trunk first
+        assert_eq!(all_hours.limiting_hour, 2);
+        assert_eq!(all_hours.limiting_constraint, "thermal seg0");
+        assert!((all_hours.hosting_capacity_mw - 11.2).abs() < 1e-9);
+        assert_eq!(all_hours.feasible_fraction, 1.0);
+        assert_eq!(all_hours.hourly_capacity_mw[0], Some(f64::INFINITY));
+
+        // Giving up the midday quarter moves the limit to the morning
+        let most_hours = solve_temporal(&feeder(), 2, &profile, 0.75)?;
+        assert_eq!(most_hours.limiting_hour, 1);
+        assert!((most_hours.hosting_capacity_mw - 26.4).abs() < 1e-9);
+        assert!((most_hours.feasible_fraction - 0.75).abs() < 1e-12);
+
+        assert!(solve_temporal(&feeder(), 2, &profile, 1.5).is_err());
+        assert!(solve_temporal(&feeder(), 7, &profile, 1.0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+//! Radial feeder topology shared by the feeder-level studies.
+
+use anyhow::{anyhow, bail, Result};
+use gat_core::{Edge, Network, Node};
+use std::collections::{HashMap, HashSet};
+
+/// System base for converting MW/MVAr and per-unit impedances.
+pub(crate) const BASE_MVA: f64 = 100.0;
+
+/// In-service branch of a radial feeder.
+pub(crate) struct FeederBranch {
+    pub label: String,
+    pub resistance: f64,
+    pub reactance: f64,
+    pub rating_mva: Option<f64>,
+}
+
+/// Feeder rooted at its substation.
+pub(crate) struct RadialFeeder {
+    pub root: usize,
+    pub branches: Vec<FeederBranch>,
+    /// Branch indices from the substation to each reachable bus
+    pub paths: HashMap<usize, Vec<usize>>,
+    /// Reachable buses in depth-first order, so each lateral follows the bus it taps off
+    pub order: Vec<usize>,
+}
+
+/// Bus of the largest in-service generator, taken to be the feeder's substation.
+pub(crate) fn substation_bus(network: &Network) -> Option<usize> {
+    network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Gen(gen) if gen.status => Some(gen),
+            _ => None,
+        })
+        .max_by(|a, b| a.pmax.value().total_cmp(&b.pmax.value()))
+        .map(|gen| gen.bus.value())
+}
+
+/// Buses joined to `root` through in-service branches and transformers.
+pub(crate) fn energized_buses(network: &Network, root: usize) -> HashSet<usize> {
+    let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+    for edge in network.graph.edge_weights() {
+        let (from, to) = match edge {
+            Edge::Branch(branch) if branch.status => (branch.from_bus, branch.to_bus),
+            Edge::Transformer(tx) => (tx.from_bus, tx.to_bus),
+            Edge::Branch(_) => continue,
+        };
+        adjacency.entry(from.value()).or_default().push(to.value());
+        adjacency.entry(to.value()).or_default().push(from.value());
+    }
+
+    let mut reached = HashSet::from([root]);
+    let mut stack = vec![root];
+    while let Some(bus) = stack.pop() {
+        for &next in adjacency.get(&bus).into_iter().flatten() {
+            if reached.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    reached
+}
+
+impl RadialFeeder {
+    /// Walk the in-service branches outward from the substation, failing on any loop.
+    pub fn from_network(network: &Network) -> Result<Self> {
+        let root = substation_bus(network).ok_or_else(|| {
+            anyhow!("feeder has no in-service generator to act as the substation")
+        })?;
+
+        let mut branches = Vec::new();
+        let mut adjacency: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+        for edge in network.graph.edge_weights() {
+            if let Edge::Branch(branch) = edge {
+                if !branch.status {
+                    continue;
+                }
+                let idx = branches.len();
+                let (from, to) = (branch.from_bus.value(), branch.to_bus.value());
+                adjacency.entry(from).or_default().push((to, idx));
+                adjacency.entry(to).or_default().push((from, idx));
+                branches.push(FeederBranch {
+                    label: if branch.name.is_empty() {
+                        format!("branch {}", branch.id.value())
+                    } else {
+                        branch.name.clone()
+                    },
+                    resistance: branch.resistance,
+                    reactance: branch.reactance,
+                    rating_mva: branch.s_max.or(branch.rating_a).map(|r| r.value()),
+                });
+            }
+        }
+
+        let mut paths: HashMap<usize, Vec<usize>> = HashMap::from([(root, Vec::new())]);
+        let mut order = Vec::new();
+        let mut used = HashSet::new();
+        let mut stack = vec![root];
+        while let Some(bus) = stack.pop() {
+            order.push(bus);
+            let mut children = Vec::new();
+            for &(next, idx) in adjacency.get(&bus).into_iter().flatten() {
+                if !used.insert(idx) {
+                    continue;
+                }
+                if paths.contains_key(&next) {
+                    bail!(
+                        "feeder must be radial; branch '{}' closes a loop",
+                        branches[idx].label
+                    );
+                }
+                let mut path = paths[&bus].clone();
+                path.push(idx);
+                paths.insert(next, path);
+                children.push(next);
+            }
+            // Reversed so the first branch out of a bus is walked first
+            stack.extend(children.into_iter().rev());
+        }
+
+        Ok(Self {
+            root,
+            branches,
+            paths,
+            order,
+        })
+    }
+}
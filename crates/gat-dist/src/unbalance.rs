@@ -0,0 +1,118 @@
+//! Voltage unbalance from per-phase bus voltages.
+//!
+//! The dist tables still describe a single-phase equivalent (every node is
+//! exported as phase `ABC`), so nothing here is wired into
+//! `dist_nodes`/`dist_branches` yet. This is the piece a three-phase power
+//! flow result needs to report a per-bus voltage-unbalance factor.
+
+use gat_core::BusId;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// Common planning limit on the voltage-unbalance factor (2 %, as in
+/// IEC 61000-2-2 and EN 50160)
+pub const VUF_LIMIT: f64 = 0.02;
+
+/// One phase-to-neutral voltage phasor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseVoltage {
+    /// Magnitude (p.u.)
+    pub magnitude_pu: f64,
+    /// Angle (radians), phase b nominally at -120°
+    pub angle_rad: f64,
+}
+
+impl PhaseVoltage {
+    pub fn new(magnitude_pu: f64, angle_rad: f64) -> Self {
+        Self {
+            magnitude_pu,
+            angle_rad,
+        }
+    }
+
+    /// Rectangular components after rotating by `shift_rad`
+    fn rotated(self, shift_rad: f64) -> (f64, f64) {
+        let angle = self.angle_rad + shift_rad;
+        (
+            self.magnitude_pu * angle.cos(),
+            self.magnitude_pu * angle.sin(),
+        )
+    }
+}
+
+/// Voltage-unbalance factor `|V₂| / |V₁|` of phases `[a, b, c]`.
+///
+/// The sequence voltages are the symmetrical components
+/// `V₁ = (Va + α·Vb + α²·Vc) / 3` and `V₂ = (Va + α²·Vb + α·Vc) / 3` with
+/// `α = 1∠120°`. A balanced set gives 0; a set without positive sequence
+/// gives infinity.
+pub fn voltage_unbalance_factor(phases: [PhaseVoltage; 3]) -> f64 {
+    let shift = 2.0 * PI / 3.0;
+    let sequence = |b_shift: f64, c_shift: f64| {
+        let parts = [
+            phases[0].rotated(0.0),
+            phases[1].rotated(b_shift),
+            phases[2].rotated(c_shift),
+        ];
+        let (re, im) = parts
+            .iter()
+            .fold((0.0, 0.0), |(re, im), (r, i)| (re + r, im + i));
+        re.hypot(im) / 3.0
+    };
+    let positive = sequence(shift, 2.0 * shift);
+    let negative = sequence(2.0 * shift, shift);
+    if positive == 0.0 {
+        f64::INFINITY
+    } else {
+        negative / positive
+    }
+}
+
+/// Voltage-unbalance factor of every bus
+pub fn bus_unbalance_factors(
+    phase_voltages: &HashMap<BusId, [PhaseVoltage; 3]>,
+) -> HashMap<BusId, f64> {
+    phase_voltages
+        .iter()
+        .map(|(&bus, &phases)| (bus, voltage_unbalance_factor(phases)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phases(a: f64, b: f64, c: f64) -> [PhaseVoltage; 3] {
+        let shift = 2.0 * PI / 3.0;
+        [
+            PhaseVoltage::new(a, 0.0),
+            PhaseVoltage::new(b, -shift),
+            PhaseVoltage::new(c, shift),
+        ]
+    }
+
+    #[test]
+    fn test_balanced_set_has_no_unbalance() {
+        assert!(voltage_unbalance_factor(phases(1.0, 1.0, 1.0)) < 1e-12);
+    }
+
+    #[test]
+    fn test_unbalanced_feeder_exceeds_limit_at_worst_bus() {
+        // Phase b loaded most heavily, sagging further down the feeder
+        let feeder: HashMap<BusId, [PhaseVoltage; 3]> = [
+            (BusId::new(1), phases(1.0, 1.0, 1.0)),
+            (BusId::new(2), phases(0.99, 0.97, 0.99)),
+            (BusId::new(3), phases(0.98, 0.92, 0.98)),
+        ]
+        .into_iter()
+        .collect();
+
+        let vuf = bus_unbalance_factors(&feeder);
+        let (&worst, &worst_vuf) = vuf.iter().max_by(|a, b| a.1.total_cmp(b.1)).unwrap();
+        assert_eq!(worst, BusId::new(3));
+        // |V₂| = 0.06 / 3, |V₁| = 2.88 / 3
+        assert!((worst_vuf - 0.06 / 2.88).abs() < 1e-12);
+        assert!(worst_vuf > VUF_LIMIT);
+        assert!(vuf[&BusId::new(2)] < VUF_LIMIT);
+    }
+}
@@ -1819,6 +1819,8 @@ pub struct DcOpfResult {
     pub total_generation_mw: f64,
     pub total_load_mw: f64,
     pub total_losses_mw: f64,
+    /// Generator Q is zero or estimated, not optimized (always true for DC-OPF)
+    pub reactive_estimated: bool,
     pub generators: Vec<GeneratorDispatch>,
     pub branches: Vec<BranchFlow>,
     pub lmps: Vec<LmpResult>,
@@ -1831,6 +1833,7 @@ pub struct GeneratorDispatch {
     pub bus: usize,
     pub name: String,
     pub p_dispatch_mw: f64,
+    pub q_dispatch_mvar: f64,
     pub p_min_mw: f64,
     pub p_max_mw: f64,
     pub marginal_cost: f64,
@@ -1895,6 +1898,7 @@ pub fn solve_dc_opf(path: &str) -> Result<DcOpfResult, String> {
                 bus: gen.bus.value(),
                 name: gen.name.clone(),
                 p_dispatch_mw: p_dispatch,
+                q_dispatch_mvar: solution.generator_q.get(&gen.name).copied().unwrap_or(0.0),
                 p_min_mw: gen.pmin.value(),
                 p_max_mw: gen.pmax.value(),
                 marginal_cost: gen.cost_model.marginal_cost(p_dispatch),
@@ -1975,6 +1979,7 @@ pub fn solve_dc_opf(path: &str) -> Result<DcOpfResult, String> {
         total_generation_mw,
         total_load_mw,
         total_losses_mw: solution.total_losses_mw,
+        reactive_estimated: !solution.method_used.reactive_is_exact(),
         generators,
         branches,
         lmps,
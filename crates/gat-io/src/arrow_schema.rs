@@ -9,10 +9,14 @@
 //!
 //! The normalized format enables lossless roundtrips from MATPOWER, PandaPower,
 //! and other power system formats while supporting efficient columnar access.
+//!
+//! It also defines the layout of solved OPF result tables (generators, buses,
+//! branches, summary), shared by the desktop and WASM exporters.
 
 use std::sync::Arc;
 
-use arrow_schema::{DataType, Field, Schema};
+// Re-exported so crates on a different arrow version can read the schemas
+pub use arrow_schema::{DataType, Field, Schema};
 
 /// Schema version for migration support (semver)
 pub const SCHEMA_VERSION: &str = "2.0.0";
@@ -188,6 +192,62 @@ pub fn branches_schema() -> Schema {
 /// Valid branch element types for dictionary encoding
 pub const BRANCH_TYPES: &[&str] = &["line", "transformer"];
 
+// =============================================================================
+// OPF Result Schemas
+// =============================================================================
+//
+// Layout of solved OPF results, shared by the desktop exporter
+// (`opf_arrow::opf_solution_to_arrow`) and the WASM Arrow IPC export so results
+// from either path can be compared table for table. Rows are sorted by ID.
+
+/// Create the schema for per-generator OPF dispatch.
+pub fn opf_generators_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("gen_id", DataType::Utf8, false),
+        Field::new("p_mw", DataType::Float64, false),
+        // Zero or a post-solve estimate for DC formulations (see summary)
+        Field::new("q_mvar", DataType::Float64, false),
+    ])
+}
+
+/// Create the schema for per-bus OPF voltages and prices.
+///
+/// Angles are in degrees (solvers report radians).
+pub fn opf_buses_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("bus_id", DataType::Utf8, false),
+        Field::new("v_mag", DataType::Float64, true),
+        Field::new("v_ang_deg", DataType::Float64, true),
+        Field::new("lmp", DataType::Float64, true),
+    ])
+}
+
+/// Create the schema for per-branch OPF flows (from-bus to to-bus).
+pub fn opf_branches_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("branch_id", DataType::Utf8, false),
+        Field::new("p_flow_mw", DataType::Float64, false),
+        Field::new("q_flow_mvar", DataType::Float64, true),
+    ])
+}
+
+/// Create the schema for the one-row OPF summary.
+pub fn opf_summary_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("converged", DataType::Boolean, false),
+        Field::new("objective_value", DataType::Float64, false),
+        Field::new("solve_time_ms", DataType::UInt64, false),
+        Field::new("method", DataType::Utf8, false),
+        Field::new("total_generation_mw", DataType::Float64, false),
+        Field::new("total_load_mw", DataType::Float64, false),
+        Field::new("total_losses_mw", DataType::Float64, false),
+        // True when q_mvar was estimated rather than optimized (DC, economic)
+        Field::new("reactive_estimated", DataType::Boolean, false),
+        // Solver warnings, one message per line; empty when there are none
+        Field::new("warnings", DataType::Utf8, false),
+    ])
+}
+
 // =============================================================================
 // Schema Accessors
 // =============================================================================
@@ -284,6 +344,21 @@ mod tests {
         assert!(rate_a.is_nullable());
     }
 
+    #[test]
+    fn test_opf_result_schemas_construction() {
+        let generators = opf_generators_schema();
+        assert_eq!(generators.field(0).name(), "gen_id");
+        assert!(!generators.field(1).is_nullable()); // p_mw always solved
+        assert!(!generators.field(2).is_nullable()); // q_mvar zero-filled for DC
+
+        assert_eq!(opf_buses_schema().fields().len(), 4);
+        assert_eq!(opf_branches_schema().field(1).name(), "p_flow_mw");
+
+        let summary = opf_summary_schema();
+        assert_eq!(summary.fields().len(), 9);
+        assert_eq!(summary.field(2).data_type(), &DataType::UInt64);
+    }
+
     #[test]
     fn test_schema_for_table() {
         assert!(schema_for_table("system").is_some());
@@ -11,6 +11,7 @@ use std::fs::File;
 use std::path::{Path, PathBuf};
 
 use crate::arrow_manifest::ArrowManifest;
+use polars::export::arrow::io::ipc::read::{read_file_metadata, FileReader};
 use polars::io::ipc::IpcReader;
 use polars::prelude::{DataFrame, SerReader};
 use std::collections::HashMap;
@@ -133,6 +134,30 @@ impl ArrowDirectoryReader {
 
         tables.map(|vec| vec.into_iter().collect())
     }
+
+    /// Iterate over one table's record batches as DataFrames.
+    ///
+    /// Only the file footer is read up front; each batch is decoded when the
+    /// iterator reaches it, so memory stays bounded by the largest batch.
+    pub fn table_batches(
+        &self,
+        table_name: &str,
+    ) -> Result<impl Iterator<Item = Result<DataFrame>>> {
+        let path = self.table_path(table_name);
+        let mut file =
+            File::open(&path).with_context(|| format!("opening table file {}", path.display()))?;
+        let metadata = read_file_metadata(&mut file)
+            .with_context(|| format!("reading footer of table {}", table_name))?;
+        let fields = metadata.schema.fields.clone();
+        let table = table_name.to_string();
+
+        let batches = FileReader::new(file, metadata, None, None).map(move |chunk| {
+            let chunk = chunk.with_context(|| format!("reading batch of table {}", table))?;
+            DataFrame::try_from((chunk, fields.as_slice()))
+                .with_context(|| format!("converting batch of table {}", table))
+        });
+        Ok(batches)
+    }
 }
 
 /// Open an Arrow network directory and validate it
@@ -38,6 +38,8 @@ pub struct ArrowDirectoryWriter {
     temp_dir: PathBuf,
     /// Final output directory path
     final_dir: PathBuf,
+    /// Rows per record batch; `None` writes each table as one batch
+    batch_rows: Option<usize>,
 }
 
 impl ArrowDirectoryWriter {
@@ -60,9 +62,18 @@ impl ArrowDirectoryWriter {
         Ok(Self {
             temp_dir,
             final_dir,
+            batch_rows: None,
         })
     }
 
+    /// Split every table into record batches of at most `rows` rows, so
+    /// [`ArrowDirectoryReader::table_batches`](crate::exporters::ArrowDirectoryReader::table_batches)
+    /// can stream large networks back in pieces.
+    pub fn with_batch_rows(mut self, rows: usize) -> Self {
+        self.batch_rows = Some(rows.max(1));
+        self
+    }
+
     /// Write network to Arrow directory with atomic commit
     pub fn write_network(
         &self,
@@ -91,7 +102,7 @@ impl ArrowDirectoryWriter {
     }
 
     /// Write manifest.json to temp directory
-    fn write_manifest(&self, manifest: &ArrowManifest) -> Result<()> {
+    pub(crate) fn write_manifest(&self, manifest: &ArrowManifest) -> Result<()> {
         let manifest_path = self.temp_dir.join("manifest.json");
         let json =
             serde_json::to_string_pretty(manifest).context("serializing manifest to JSON")?;
@@ -103,7 +114,7 @@ impl ArrowDirectoryWriter {
     }
 
     /// Atomically commit writes by renaming temp directory to final location
-    fn commit(&self) -> Result<()> {
+    pub(crate) fn commit(&self) -> Result<()> {
         // Remove existing directory if present
         if self.final_dir.exists() {
             fs::remove_dir_all(&self.final_dir).with_context(|| {
@@ -146,13 +157,21 @@ impl ArrowDirectoryWriter {
         &self.final_dir
     }
 
-    fn write_table(
+    pub(crate) fn write_table(
         &self,
         name: &str,
         df: &mut DataFrame,
         manifest: &mut ArrowManifest,
     ) -> Result<()> {
         let path = self.temp_dir.join(format!("{}.arrow", name));
+        // The IPC writer emits one record batch per chunk
+        if let Some(rows) = self.batch_rows {
+            let mut batched = df.slice(0, rows);
+            for offset in (rows..df.height()).step_by(rows) {
+                batched.vstack_mut(&df.slice(offset as i64, rows))?;
+            }
+            *df = batched;
+        }
         {
             let mut file = fs::File::create(&path)
                 .with_context(|| format!("creating table file {}", path.display()))?;
@@ -0,0 +1,248 @@
+//! Merge a solved operating point back into an Arrow grid directory.
+//!
+//! OPF and power-flow solvers report their results as per-name maps. This module
+//! writes those values into the `buses` and `generators` tables of an existing
+//! Arrow grid so that the solved state can be re-imported with
+//! [`crate::importers::load_grid_from_arrow`] and used as a warm start or a
+//! baseline for follow-up studies. All other tables are copied unchanged.
+
+use anyhow::{bail, Context, Result};
+use polars::prelude::{DataFrame, NamedFrom, Series};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::arrow_manifest::ArrowManifest;
+use crate::exporters::{ArrowDirectoryReader, ArrowDirectoryWriter};
+
+/// Solved network state keyed by bus and generator name.
+///
+/// Field names and units follow `gat_algo::opf::OpfSolution`, so a solution can be
+/// carried over field by field. Buses or generators missing from a map keep the
+/// value already stored in the grid.
+#[derive(Debug, Clone, Default)]
+pub struct SolvedState {
+    /// Voltage magnitude in p.u.
+    pub bus_voltage_mag: HashMap<String, f64>,
+    /// Voltage angle in degrees
+    pub bus_voltage_ang: HashMap<String, f64>,
+    /// Active power dispatch in MW
+    pub generator_p: HashMap<String, f64>,
+    /// Reactive power dispatch in MVAr
+    pub generator_q: HashMap<String, f64>,
+}
+
+/// Write a copy of the Arrow grid at `grid_path` to `out_path` with the solved bus
+/// voltages and generator dispatch from `solution` merged in.
+///
+/// Fails if the solution names a bus or generator that is not in the grid, since
+/// that almost always means the solution belongs to a different network.
+pub fn apply_solution_to_arrow(
+    grid_path: impl AsRef<Path>,
+    solution: &SolvedState,
+    out_path: impl AsRef<Path>,
+) -> Result<()> {
+    let reader = ArrowDirectoryReader::open(&grid_path)?;
+    let mut tables = reader.load_tables()?;
+
+    let buses = tables
+        .get_mut("buses")
+        .context("Arrow grid has no buses table")?;
+    overwrite_by_name(buses, "bus", "voltage_pu", &solution.bus_voltage_mag, |v| v)?;
+    overwrite_by_name(
+        buses,
+        "bus",
+        "angle_rad",
+        &solution.bus_voltage_ang,
+        f64::to_radians,
+    )?;
+
+    let generators = tables
+        .get_mut("generators")
+        .context("Arrow grid has no generators table")?;
+    overwrite_by_name(
+        generators,
+        "generator",
+        "active_power_mw",
+        &solution.generator_p,
+        |v| v,
+    )?;
+    overwrite_by_name(
+        generators,
+        "generator",
+        "reactive_power_mvar",
+        &solution.generator_q,
+        |v| v,
+    )?;
+
+    let writer = ArrowDirectoryWriter::new(out_path)?;
+    match write_tables(&writer, reader.manifest(), &mut tables) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            // Best effort cleanup
+            let _ = writer.cleanup();
+            Err(e)
+        }
+    }
+}
+
+/// Replace `column` with `updates[name]` for every row whose name has an update.
+fn overwrite_by_name(
+    df: &mut DataFrame,
+    kind: &str,
+    column: &str,
+    updates: &HashMap<String, f64>,
+    convert: fn(f64) -> f64,
+) -> Result<()> {
+    if updates.is_empty() {
+        return Ok(());
+    }
+
+    let names = df.column("name")?.utf8()?.clone();
+    let known: HashSet<&str> = names.into_iter().flatten().collect();
+    if let Some(unknown) = updates.keys().find(|name| !known.contains(name.as_str())) {
+        bail!("solution references unknown {} '{}'", kind, unknown);
+    }
+
+    let current = df
+        .column(column)
+        .with_context(|| format!("{} table has no {} column", kind, column))?
+        .f64()?
+        .clone();
+    let merged: Vec<Option<f64>> = names
+        .into_iter()
+        .zip(current.into_iter())
+        .map(|(name, value)| match name.and_then(|n| updates.get(n)) {
+            Some(solved) => Some(convert(*solved)),
+            None => value,
+        })
+        .collect();
+    df.with_column(Series::new(column, merged))?;
+    Ok(())
+}
+
+fn write_tables(
+    writer: &ArrowDirectoryWriter,
+    source: &ArrowManifest,
+    tables: &mut HashMap<String, DataFrame>,
+) -> Result<()> {
+    let mut manifest =
+        ArrowManifest::new(env!("CARGO_PKG_VERSION").to_string(), source.source.clone());
+
+    let mut names: Vec<String> = tables.keys().cloned().collect();
+    names.sort();
+    for name in names {
+        let df = tables.get_mut(&name).expect("table listed above");
+        writer.write_table(&name, df, &mut manifest)?;
+    }
+
+    writer
+        .write_manifest(&manifest)
+        .context("writing manifest")?;
+    writer.commit().context("atomic commit")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exporters::write_network_to_arrow_directory;
+    use crate::importers::load_grid_from_arrow;
+    use gat_algo::opf::OpfSolution;
+    use gat_core::{Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Network, Node};
+    use tempfile::TempDir;
+
+    fn two_bus_network() -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus 1".to_string(),
+            base_kv: gat_core::Kilovolts(138.0),
+            ..Default::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "Bus 2".to_string(),
+            base_kv: gat_core::Kilovolts(138.0),
+            ..Default::default()
+        }));
+        network.graph.add_node(Node::Gen(Gen {
+            active_power: gat_core::Megawatts(10.0),
+            pmax: gat_core::Megawatts(200.0),
+            ..Gen::new(GenId::new(1), "Gen 1".to_string(), BusId::new(1))
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "Line 1-2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        network
+    }
+
+    fn solved_state(solution: &OpfSolution) -> SolvedState {
+        SolvedState {
+            bus_voltage_mag: solution.bus_voltage_mag.clone(),
+            bus_voltage_ang: solution.bus_voltage_ang.clone(),
+            generator_p: solution.generator_p.clone(),
+            generator_q: solution.generator_q.clone(),
+        }
+    }
+
+    #[test]
+    fn test_solution_survives_reload() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let grid = tmp.path().join("grid");
+        let solved = tmp.path().join("solved");
+        write_network_to_arrow_directory(&two_bus_network(), &grid)?;
+
+        let mut solution = OpfSolution::default();
+        solution.bus_voltage_mag.insert("Bus 2".to_string(), 0.97);
+        solution.bus_voltage_ang.insert("Bus 2".to_string(), -4.5);
+        solution.generator_p.insert("Gen 1".to_string(), 85.0);
+        solution.generator_q.insert("Gen 1".to_string(), 12.0);
+        apply_solution_to_arrow(&grid, &solved_state(&solution), &solved)?;
+
+        let network = load_grid_from_arrow(&solved)?;
+        for node in network.graph.node_weights() {
+            match node {
+                Node::Bus(bus) if bus.name == "Bus 1" => {
+                    // Untouched by the solution
+                    assert_eq!(bus.voltage_pu.value(), 1.0);
+                    assert_eq!(bus.angle_rad.value(), 0.0);
+                }
+                Node::Bus(bus) => {
+                    assert_eq!(bus.voltage_pu.value(), 0.97);
+                    assert!((bus.angle_rad.value() - (-4.5f64).to_radians()).abs() < 1e-12);
+                }
+                Node::Gen(gen) => {
+                    assert_eq!(gen.active_power.value(), 85.0);
+                    assert_eq!(gen.reactive_power.value(), 12.0);
+                    assert_eq!(gen.pmax.value(), 200.0);
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(network.graph.edge_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_generator_is_rejected() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let grid = tmp.path().join("grid");
+        let solved = tmp.path().join("solved");
+        write_network_to_arrow_directory(&two_bus_network(), &grid)?;
+
+        let mut state = SolvedState::default();
+        state.generator_p.insert("Gen 9".to_string(), 50.0);
+        let err = apply_solution_to_arrow(&grid, &state, &solved).unwrap_err();
+        assert!(err.to_string().contains("Gen 9"));
+        assert!(!solved.exists());
+        Ok(())
+    }
+}
@@ -1,11 +1,12 @@
 //! CIM RDF/XML file exporter
 //!
 //! Serializes a [`Network`] into a minimal CIM RDF document that can be re-imported by the CIM
-//! parser.
+//! parser. Elements imported from CIM keep their source mRIDs as `rdf:ID`s (see
+//! [`gat_core::ExternalIds`]); others get generated IDs.
 
 use crate::exporters::ExportMetadata;
 use anyhow::{Context, Result};
-use gat_core::{Branch, BranchId, Edge, Network, Node};
+use gat_core::{Branch, BranchId, BusId, Edge, Network, Node};
 use std::{
     fs::File,
     io::{BufWriter, Write},
@@ -19,8 +20,14 @@ fn xml_escape(value: &str) -> String {
         .replace('>', "&gt;")
 }
 
-fn bus_id_string(bus_id: usize) -> String {
-    format!("Bus{}", bus_id)
+/// rdf:ID of a bus: its source mRID when known, else `Bus<n>`
+fn bus_id_string(network: &Network, bus_id: BusId) -> String {
+    network
+        .external_ids
+        .buses
+        .get(&bus_id)
+        .cloned()
+        .unwrap_or_else(|| format!("Bus{}", bus_id.value()))
 }
 
 fn write_component_header(
@@ -87,28 +94,34 @@ pub fn export_network_to_cim(
         .graph
         .edge_weights()
         .filter_map(|edge| match edge {
-            Edge::Branch(branch) => Some(Branch {
-                id: branch.id,
-                name: branch.name.clone(),
-                from_bus: branch.from_bus,
-                to_bus: branch.to_bus,
-                resistance: branch.resistance,
-                reactance: branch.reactance,
-                ..Branch::default()
-            }),
-            Edge::Transformer(tx) => Some(Branch {
-                id: BranchId::new(tx.id.value()),
-                name: tx.name.clone(),
-                from_bus: tx.from_bus,
-                to_bus: tx.to_bus,
-                resistance: 0.0,
-                reactance: 0.0,
-                tap_ratio: tx.ratio,
-                ..Branch::default()
-            }),
+            Edge::Branch(branch) => Some((
+                network.external_ids.branches.get(&branch.id).cloned(),
+                Branch {
+                    id: branch.id,
+                    name: branch.name.clone(),
+                    from_bus: branch.from_bus,
+                    to_bus: branch.to_bus,
+                    resistance: branch.resistance,
+                    reactance: branch.reactance,
+                    ..Branch::default()
+                },
+            )),
+            Edge::Transformer(tx) => Some((
+                None,
+                Branch {
+                    id: BranchId::new(tx.id.value()),
+                    name: tx.name.clone(),
+                    from_bus: tx.from_bus,
+                    to_bus: tx.to_bus,
+                    resistance: 0.0,
+                    reactance: 0.0,
+                    tap_ratio: tx.ratio,
+                    ..Branch::default()
+                },
+            )),
         })
         .collect();
-    branches.sort_by(|a, b| {
+    branches.sort_by(|(_, a), (_, b)| {
         a.from_bus
             .value()
             .cmp(&b.from_bus.value())
@@ -128,7 +141,7 @@ pub fn export_network_to_cim(
     write_cim_metadata(&mut writer, metadata)?;
 
     for bus in &buses {
-        let id_str = bus_id_string(bus.id.value());
+        let id_str = bus_id_string(network, bus.id);
         write_component_header(&mut writer, "BusbarSection", &id_str, "  ")?;
         writeln!(
             writer,
@@ -138,7 +151,7 @@ pub fn export_network_to_cim(
         write_component_footer(&mut writer, "BusbarSection", "  ")?;
     }
 
-    for (idx, branch) in branches.iter().enumerate() {
+    for (idx, (mrid, branch)) in branches.iter().enumerate() {
         let name = if branch.name.is_empty() {
             format!(
                 "Branch {}-{}",
@@ -148,7 +161,7 @@ pub fn export_network_to_cim(
         } else {
             branch.name.clone()
         };
-        let id_str = format!("Line{}", idx + 1);
+        let id_str = mrid.clone().unwrap_or_else(|| format!("Line{}", idx + 1));
         write_component_header(&mut writer, "ACLineSegment", &id_str, "  ")?;
         writeln!(
             writer,
@@ -158,12 +171,12 @@ pub fn export_network_to_cim(
         writeln!(
             writer,
             "    <cim:ACLineSegment.end1 rdf:resource=\"#{}\"/>",
-            bus_id_string(branch.from_bus.value())
+            bus_id_string(network, branch.from_bus)
         )?;
         writeln!(
             writer,
             "    <cim:ACLineSegment.end2 rdf:resource=\"#{}\"/>",
-            bus_id_string(branch.to_bus.value())
+            bus_id_string(network, branch.to_bus)
         )?;
         writeln!(
             writer,
@@ -179,7 +192,12 @@ pub fn export_network_to_cim(
     }
 
     for (idx, load) in loads.iter().enumerate() {
-        let id_str = format!("Load{}", idx + 1);
+        let id_str = network
+            .external_ids
+            .loads
+            .get(&load.id)
+            .cloned()
+            .unwrap_or_else(|| format!("Load{}", idx + 1));
         write_component_header(&mut writer, "Load", &id_str, "  ")?;
         writeln!(
             writer,
@@ -199,13 +217,18 @@ pub fn export_network_to_cim(
         writeln!(
             writer,
             "    <cim:Load.ConnectivityNode rdf:resource=\"#{}\"/>",
-            bus_id_string(load.bus.value())
+            bus_id_string(network, load.bus)
         )?;
         write_component_footer(&mut writer, "Load", "  ")?;
     }
 
     for (idx, gen) in gens.iter().enumerate() {
-        let id_str = format!("Gen{}", idx + 1);
+        let id_str = network
+            .external_ids
+            .gens
+            .get(&gen.id)
+            .cloned()
+            .unwrap_or_else(|| format!("Gen{}", idx + 1));
         write_component_header(&mut writer, "SynchronousMachine", &id_str, "  ")?;
         writeln!(
             writer,
@@ -225,7 +248,7 @@ pub fn export_network_to_cim(
         writeln!(
             writer,
             "    <cim:SynchronousMachine.ConnectivityNode rdf:resource=\"#{}\"/>",
-            bus_id_string(gen.bus.value())
+            bus_id_string(network, gen.bus)
         )?;
         write_component_footer(&mut writer, "SynchronousMachine", "  ")?;
     }
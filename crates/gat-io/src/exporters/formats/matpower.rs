@@ -4,7 +4,7 @@
 
 use anyhow::{Context, Result};
 use gat_core::{CostModel, Edge, Network, Node};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
@@ -36,6 +36,7 @@ fn network_to_matpower_case(network: &Network) -> Result<MatpowerCase> {
 
     // Collect buses and build mapping
     let mut buses_data: Vec<(usize, MatpowerBus)> = Vec::new();
+    let mut typed_buses: HashSet<usize> = HashSet::new();
     let mut loads_by_bus: HashMap<usize, (f64, f64)> = HashMap::new(); // bus_id -> (Pd, Qd)
 
     for node in network.graph.node_weights() {
@@ -45,8 +46,14 @@ fn network_to_matpower_case(network: &Network) -> Result<MatpowerCase> {
                 if !bus_id_to_idx.contains_key(&bus_id) {
                     bus_id_to_idx.insert(bus_id, bus_idx);
 
-                    // Determine bus type (will be updated when we process generators)
-                    let bus_type = 1; // PQ bus by default
+                    // Keep a classified bus's type; otherwise PQ until generators are seen
+                    let bus_type = match bus.bus_type {
+                        Some(bus_type) => {
+                            typed_buses.insert(bus_id);
+                            bus_type.code() as i32
+                        }
+                        None => 1,
+                    };
 
                     buses_data.push((
                         bus_id,
@@ -167,8 +174,11 @@ fn network_to_matpower_case(network: &Network) -> Result<MatpowerCase> {
         }
     }
 
-    // Update bus types based on generators
+    // Infer types of unclassified buses from their generators
     for (bus_id, bus) in &mut buses_data {
+        if typed_buses.contains(bus_id) {
+            continue;
+        }
         if let Some(is_slack) = gen_buses.get(bus_id) {
             bus.bus_type = if *is_slack { 3 } else { 2 }; // 3 = slack, 2 = PV
         }
@@ -416,6 +426,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         }));
 
         let bus2_idx = network.graph.add_node(Node::Bus(Bus {
@@ -428,6 +439,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         }));
 
         // Add a generator
@@ -454,6 +466,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(80.0),
             reactive_power: gat_core::Megavars(40.0),
+            zip: None,
         }));
 
         // Add a branch
@@ -332,6 +332,10 @@ pub fn export_network_to_powermodels_string(
 
 /// Determine bus type: 1=PQ, 2=PV, 3=ref (slack)
 fn determine_bus_type(bus: &Bus, gens: &[Gen]) -> i32 {
+    if let Some(bus_type) = bus.bus_type {
+        return bus_type.code() as i32;
+    }
+
     // Check if this bus has a generator
     let has_gen = gens.iter().any(|g| g.bus == bus.id && g.status);
 
@@ -391,6 +395,7 @@ mod tests {
             vmin_pu: Some(gat_core::PerUnit(0.9)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         };
         let bus2 = Bus {
             id: BusId::new(2),
@@ -402,6 +407,7 @@ mod tests {
             vmin_pu: Some(gat_core::PerUnit(0.9)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         };
 
         let b1_idx = network.graph.add_node(Node::Bus(bus1));
@@ -433,6 +439,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(90.0),
             reactive_power: gat_core::Megavars(40.0),
+            zip: None,
         };
         network.graph.add_node(Node::Load(load));
 
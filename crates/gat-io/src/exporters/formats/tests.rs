@@ -17,8 +17,8 @@ mod tests {
         export_network_to_psse,
     };
     use crate::exporters::{ArrowDirectoryWriter, ExportMetadata};
-    use crate::importers::parse_powermodels_string;
     use crate::importers::{load_grid_from_arrow, parse_matpower};
+    use crate::importers::{parse_cim, parse_powermodels_string};
     use anyhow::Result;
     use chrono::{TimeZone, Utc};
     use gat_core::{
@@ -145,6 +145,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         }));
 
         let bus2_idx = network.graph.add_node(Node::Bus(Bus {
@@ -157,6 +158,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         }));
 
         // Add generator with quadratic cost
@@ -183,6 +185,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(80.0),
             reactive_power: gat_core::Megavars(40.0),
+            zip: None,
         }));
 
         // Add branch
@@ -333,6 +336,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(85.0),
             reactive_power: gat_core::Megavars(35.0),
+            zip: None,
         }));
 
         network.graph.add_edge(
@@ -431,6 +435,65 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cim_mrids_survive_round_trip() -> Result<()> {
+        let rdf = r##"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:cim="http://iec.ch/TC57/2013/CIM-schema-cim16#">
+  <cim:BusbarSection rdf:ID="_9f2c-bus-north">
+    <cim:IdentifiedObject.name>North</cim:IdentifiedObject.name>
+  </cim:BusbarSection>
+  <cim:BusbarSection rdf:ID="_41d7-bus-south">
+    <cim:IdentifiedObject.name>South</cim:IdentifiedObject.name>
+  </cim:BusbarSection>
+  <cim:ACLineSegment rdf:ID="_7a10-line">
+    <cim:IdentifiedObject.name>North-South</cim:IdentifiedObject.name>
+    <cim:ACLineSegment.end1 rdf:resource="#_9f2c-bus-north"/>
+    <cim:ACLineSegment.end2 rdf:resource="#_41d7-bus-south"/>
+    <cim:ACLineSegment.r>0.01</cim:ACLineSegment.r>
+    <cim:ACLineSegment.x>0.05</cim:ACLineSegment.x>
+  </cim:ACLineSegment>
+  <cim:Load rdf:ID="_c3e8-load">
+    <cim:IdentifiedObject.name>City</cim:IdentifiedObject.name>
+    <cim:Load.p>40.0</cim:Load.p>
+    <cim:Load.q>10.0</cim:Load.q>
+    <cim:Load.ConnectivityNode rdf:resource="#_41d7-bus-south"/>
+  </cim:Load>
+  <cim:SynchronousMachine rdf:ID="_e5b2-unit">
+    <cim:IdentifiedObject.name>Unit 1</cim:IdentifiedObject.name>
+    <cim:SynchronousMachine.p>40.0</cim:SynchronousMachine.p>
+    <cim:SynchronousMachine.q>5.0</cim:SynchronousMachine.q>
+    <cim:SynchronousMachine.ConnectivityNode rdf:resource="#_9f2c-bus-north"/>
+  </cim:SynchronousMachine>
+</rdf:RDF>
+"##;
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("source.rdf");
+        fs::write(&source, rdf)?;
+        let imported = parse_cim(source.to_str().unwrap())?.network;
+        assert_eq!(imported.external_ids.buses.len(), 2);
+
+        let exported = temp_dir.path().join("exported.rdf");
+        export_network_to_cim(&imported, &exported, None)?;
+        let content = fs::read_to_string(&exported)?;
+        for mrid in [
+            "_9f2c-bus-north",
+            "_41d7-bus-south",
+            "_7a10-line",
+            "_c3e8-load",
+            "_e5b2-unit",
+        ] {
+            assert!(
+                content.contains(&format!("rdf:ID=\"{}\"", mrid)),
+                "{} missing from export",
+                mrid
+            );
+        }
+
+        let reimported = parse_cim(exported.to_str().unwrap())?.network;
+        assert_eq!(reimported.external_ids, imported.external_ids);
+        Ok(())
+    }
+
     #[test]
     fn test_pandapower_export_structure() -> Result<()> {
         let network = build_sample_network();
@@ -818,6 +881,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         }));
 
         let bus2_idx = network.graph.add_node(Node::Bus(Bus {
@@ -830,6 +894,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         }));
 
         // Generator with polynomial cost
@@ -857,6 +922,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(90.0),
             reactive_power: gat_core::Megavars(40.0),
+            zip: None,
         }));
 
         // Branch
@@ -5,6 +5,7 @@
 
 pub mod arrow_directory_reader;
 pub mod arrow_directory_writer;
+pub mod arrow_solution;
 pub mod formats;
 pub mod metadata;
 pub mod psse;
@@ -13,5 +14,6 @@ pub use arrow_directory_reader::{open_arrow_directory, ArrowDirectoryReader};
 pub use arrow_directory_writer::{
     write_network_to_arrow_directory, ArrowDirectoryWriter, SystemInfo,
 };
+pub use arrow_solution::{apply_solution_to_arrow, SolvedState};
 pub use metadata::ExportMetadata;
 pub use psse::{export_to_psse, export_to_psse_string};
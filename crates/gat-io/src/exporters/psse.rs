@@ -44,7 +44,7 @@ fn write_bus_section(network: &Network, output: &mut String) {
     for bus in buses {
         // PSS/E v33 bus format:
         // I, 'NAME', BASKV, IDE, AREA, ZONE, OWNER, VM, VA, NVHI, NVLO, EVHI, EVLO
-        let ide = 1; // PQ bus by default
+        let ide = bus.bus_type.map_or(1, |t| t.code()); // Unclassified buses as PQ
         let area = bus.area_id.unwrap_or(1);
         let zone = bus.zone_id.unwrap_or(1);
         let vmax = bus.vmax_pu.map(|v| v.value()).unwrap_or(1.1);
@@ -290,6 +290,7 @@ mod tests {
             bus: BusId::new(1),
             active_power: gat_core::Megawatts(100.0),
             reactive_power: gat_core::Megavars(50.0),
+            zip: None,
         }));
 
         let output = export_to_psse_string(&network, "test").unwrap();
@@ -16,8 +16,8 @@
 use std::collections::HashMap;
 
 use gat_core::{
-    Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Network, Node, NodeIndex, Shunt,
-    ShuntId,
+    Branch, BranchId, Bus, BusId, BusType, Edge, Gen, GenId, Load, LoadId, Network, Node,
+    NodeIndex, Shunt, ShuntId,
 };
 
 use super::ImportDiagnostics;
@@ -34,6 +34,7 @@ pub struct BusInput {
     pub vmax_pu: Option<f64>,
     pub area_id: Option<i64>,
     pub zone_id: Option<i64>,
+    pub bus_type: Option<BusType>,
 }
 
 /// Generic input data for load creation
@@ -238,6 +239,7 @@ impl<'a> NetworkBuilder<'a> {
             vmax_pu: input.vmax_pu.map(gat_core::PerUnit),
             area_id: input.area_id,
             zone_id: input.zone_id,
+            bus_type: input.bus_type,
         }));
 
         self.bus_map.insert(input.id, node_idx);
@@ -283,6 +285,7 @@ impl<'a> NetworkBuilder<'a> {
             bus: BusId::new(input.bus_id),
             active_power: gat_core::Megawatts(input.active_power_mw),
             reactive_power: gat_core::Megavars(input.reactive_power_mvar),
+            zip: None,
         }));
 
         self.next_load_id += 1;
@@ -496,6 +499,7 @@ mod tests {
             vmax_pu: None,
             area_id: None,
             zone_id: None,
+            bus_type: None,
         });
         builder.add_bus(BusInput {
             id: 2,
@@ -507,6 +511,7 @@ mod tests {
             vmax_pu: None,
             area_id: None,
             zone_id: None,
+            bus_type: None,
         });
 
         // Add load
@@ -557,6 +562,7 @@ mod tests {
             vmax_pu: None,
             area_id: None,
             zone_id: None,
+            bus_type: None,
         });
 
         // Try to add load on non-existent bus
@@ -607,6 +613,7 @@ mod tests {
             vmax_pu: None,
             area_id: None,
             zone_id: None,
+            bus_type: None,
         });
         builder.add_load(LoadInput {
             bus_id: 1,
@@ -652,6 +659,7 @@ mod tests {
             vmax_pu: None,
             area_id: None,
             zone_id: None,
+            bus_type: None,
         });
         builder.add_bus(BusInput {
             id: 200,
@@ -663,6 +671,7 @@ mod tests {
             vmax_pu: None,
             area_id: None,
             zone_id: None,
+            bus_type: None,
         });
 
         // Add multiple loads - IDs should be 0, 1, 2
@@ -619,6 +619,7 @@ fn validate_power_balance(network: &Network, diag: &mut ImportDiagnostics) {
     let mut _total_qmax = 0.0;
     let mut _total_qmin = 0.0;
     let mut total_load_p = 0.0;
+    let mut total_load_abs_p = 0.0;
     let mut _total_load_q = 0.0;
     let mut gen_count = 0;
     let mut load_count = 0;
@@ -659,6 +660,7 @@ fn validate_power_balance(network: &Network, diag: &mut ImportDiagnostics) {
             }
             Node::Load(load) => {
                 total_load_p += load.active_power.value();
+                total_load_abs_p += load.active_power.value().abs();
                 _total_load_q += load.reactive_power.value();
                 load_count += 1;
 
@@ -720,8 +722,9 @@ fn validate_power_balance(network: &Network, diag: &mut ImportDiagnostics) {
         }
     }
 
-    // Check for zero total load (suspicious unless islanding study)
-    if total_load_p.abs() < 1e-6 && load_count > 0 {
+    // Check for all-zero loads (suspicious unless islanding study). Negative
+    // loads netting out positive ones are embedded generation, not missing data.
+    if total_load_abs_p < 1e-6 && load_count > 0 {
         diag.add_validation_warning(
             "PowerBalance",
             &format!(
@@ -769,6 +772,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(10.0),
+            zip: None,
         }));
 
         network.graph.add_edge(
@@ -927,6 +931,7 @@ mod tests {
             vmax_pu: Some(gat_core::PerUnit(1.05)),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
 
         let mut diag = ImportDiagnostics::new();
@@ -1165,6 +1170,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(100.0),
             reactive_power: gat_core::Megavars(10.0),
+            zip: None,
         }));
 
         network.graph.add_edge(
@@ -1222,6 +1228,7 @@ mod tests {
             bus: BusId::new(2),
             active_power: gat_core::Megawatts(50.0),
             reactive_power: gat_core::Megavars(10.0),
+            zip: None,
         }));
 
         network.graph.add_edge(
@@ -1300,6 +1307,7 @@ mod tests {
             bus: BusId::new(1),
             active_power: gat_core::Megawatts(-50.0), // Negative!
             reactive_power: gat_core::Megavars(0.0),
+            zip: None,
         }));
 
         // Need a gen to avoid "no generators" warning
@@ -1339,6 +1347,7 @@ mod tests {
             bus: BusId::new(1),
             active_power: gat_core::Megawatts(0.0),
             reactive_power: gat_core::Megavars(0.0),
+            zip: None,
         }));
 
         let mut diag = ImportDiagnostics::new();
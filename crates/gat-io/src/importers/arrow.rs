@@ -7,6 +7,7 @@ use gat_core::{
     Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Network, Node, NodeIndex,
     Transformer, TransformerId,
 };
+use polars::prelude::DataFrame;
 
 pub fn export_network_to_arrow(network: &Network, output_dir: impl AsRef<Path>) -> Result<()> {
     let writer = crate::exporters::ArrowDirectoryWriter::new(output_dir)?;
@@ -26,6 +27,34 @@ pub fn load_grid_from_arrow_with_manifest(
     Ok((network, manifest))
 }
 
+/// Stream an Arrow network directory into a [`Network`] one record batch at
+/// a time.
+///
+/// [`load_grid_from_arrow`] materialises every table before building the
+/// graph. Here each table is read batch by batch and the rows are added to
+/// the graph as they arrive, so peak memory is the graph plus one batch
+/// rather than the graph plus every table. The manifest and checksums are
+/// validated up front as for the full load, and the result is identical.
+pub fn load_grid_from_arrow_streaming(input_dir: impl AsRef<Path>) -> Result<Network> {
+    let reader = crate::exporters::ArrowDirectoryReader::open(&input_dir)?;
+    let mut network = Network::new();
+    let mut bus_node_map: HashMap<i64, NodeIndex> = HashMap::new();
+
+    for batch in reader.table_batches("buses")? {
+        add_buses(&batch?, &mut network, &mut bus_node_map)?;
+    }
+    for batch in reader.table_batches("generators")? {
+        add_generators(&batch?, &mut network, &bus_node_map)?;
+    }
+    for batch in reader.table_batches("loads")? {
+        add_loads(&batch?, &mut network, &bus_node_map)?;
+    }
+    for batch in reader.table_batches("branches")? {
+        add_branches(&batch?, &mut network, &bus_node_map)?;
+    }
+    Ok(network)
+}
+
 fn network_from_directory_reader(
     reader: &crate::exporters::ArrowDirectoryReader,
 ) -> Result<Network> {
@@ -46,7 +75,22 @@ fn network_from_directory_reader(
 
     let mut network = Network::new();
     let mut bus_node_map: HashMap<i64, NodeIndex> = HashMap::new();
+    add_buses(buses_df, &mut network, &mut bus_node_map)?;
+    add_generators(generators_df, &mut network, &bus_node_map)?;
+    add_loads(loads_df, &mut network, &bus_node_map)?;
+    add_branches(branches_df, &mut network, &bus_node_map)?;
+    // NetworkValidator::validate(&network_to_validator_data(&network))
+    //     .context("imported network failed integrity validation")?;
 
+    Ok(network)
+}
+
+/// Add the rows of a `buses` table (or batch) to `network`
+fn add_buses(
+    buses_df: &DataFrame,
+    network: &mut Network,
+    bus_node_map: &mut HashMap<i64, NodeIndex>,
+) -> Result<()> {
     // =========================================================================
     // 1. Load Buses
     // =========================================================================
@@ -87,10 +131,20 @@ fn network_from_directory_reader(
             vmax_pu: vmax_pu.map(gat_core::PerUnit),
             area_id: area_id.map(|v| v as i64),
             zone_id: zone_id.map(|v| v as i64),
+            bus_type: None,
         }));
         bus_node_map.insert(id_value, node_idx);
     }
 
+    Ok(())
+}
+
+/// Add the rows of a `generators` table (or batch) to `network`
+fn add_generators(
+    generators_df: &DataFrame,
+    network: &mut Network,
+    bus_node_map: &HashMap<i64, NodeIndex>,
+) -> Result<()> {
     // =========================================================================
     // 2. Load Generators
     // =========================================================================
@@ -208,6 +262,15 @@ fn network_from_directory_reader(
         }));
     }
 
+    Ok(())
+}
+
+/// Add the rows of a `loads` table (or batch) to `network`
+fn add_loads(
+    loads_df: &DataFrame,
+    network: &mut Network,
+    bus_node_map: &HashMap<i64, NodeIndex>,
+) -> Result<()> {
     // =========================================================================
     // 3. Load Loads
     // =========================================================================
@@ -252,9 +315,19 @@ fn network_from_directory_reader(
             ),
             active_power: gat_core::Megawatts(active_power_mw),
             reactive_power: gat_core::Megavars(reactive_power_mvar),
+            zip: None,
         }));
     }
 
+    Ok(())
+}
+
+/// Add the rows of a `branches` table (or batch) to `network`
+fn add_branches(
+    branches_df: &DataFrame,
+    network: &mut Network,
+    bus_node_map: &HashMap<i64, NodeIndex>,
+) -> Result<()> {
     // =========================================================================
     // 4. Load Branches
     // =========================================================================
@@ -380,8 +453,5 @@ fn network_from_directory_reader(
             _ => return Err(anyhow!("Unknown element type: {}", element_type)),
         }
     }
-    // NetworkValidator::validate(&network_to_validator_data(&network))
-    //     .context("imported network failed integrity validation")?;
-
-    Ok(network)
+    Ok(())
 }
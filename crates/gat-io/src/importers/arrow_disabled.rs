@@ -12,3 +12,7 @@ pub fn export_network_to_arrow(_network: &Network, _output_file: &str) -> Result
 pub fn load_grid_from_arrow(_grid_file: &str) -> Result<Network> {
     bail!("Arrow IPC support is disabled; build with the 'ipc' feature to enable it")
 }
+
+pub fn load_grid_from_arrow_streaming(_grid_file: &str) -> Result<Network> {
+    bail!("Arrow IPC support is disabled; build with the 'ipc' feature to enable it")
+}
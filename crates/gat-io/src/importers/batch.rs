@@ -0,0 +1,234 @@
+//! Batch import of a directory of cases.
+//!
+//! [`import_directory`] parses every file in a directory whose extension
+//! matches a [`Format`], keeps going past files that fail, and records the
+//! outcome of each file in a JSON manifest ([`BATCH_MANIFEST_FILE`]) written
+//! next to the cases. Files are imported in name order and subdirectories are
+//! not searched.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use gat_core::Network;
+use serde::Serialize;
+
+use super::Format;
+
+/// Name of the manifest written into the imported directory
+pub const BATCH_MANIFEST_FILE: &str = "import_manifest.json";
+
+/// Outcome of importing one directory.
+pub struct BatchImport {
+    /// Format the files were parsed as
+    pub format: Format,
+    /// File name and import result for each matching file, in name order
+    pub results: Vec<(String, Result<Network>)>,
+    /// Aggregate counts, as written to the manifest
+    pub summary: BatchSummary,
+    /// Per-file manifest entries, in the same order as `results`
+    pub entries: Vec<BatchEntry>,
+}
+
+impl BatchImport {
+    /// Successfully imported networks, by file name.
+    pub fn networks(&self) -> impl Iterator<Item = (&str, &Network)> {
+        self.results.iter().filter_map(|(file, result)| {
+            result.as_ref().ok().map(|network| (file.as_str(), network))
+        })
+    }
+}
+
+/// Aggregate counts over a batch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BatchSummary {
+    /// Files matching the format's extensions
+    pub files: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// Import warnings across all successful files
+    pub warnings: usize,
+}
+
+/// Manifest record for one file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BatchEntry {
+    pub file: String,
+    /// `"ok"` or `"error"`
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buses: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branches: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generators: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loads: Option<usize>,
+    /// Import warnings for this file
+    pub warnings: usize,
+    /// Why the import failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Manifest<'a> {
+    format: &'static str,
+    summary: &'a BatchSummary,
+    files: &'a [BatchEntry],
+}
+
+/// Import every `format` file in `dir`, then write [`BATCH_MANIFEST_FILE`].
+///
+/// A file that fails to parse is recorded as an error and does not stop the
+/// batch. The outer `Err` is reserved for an unreadable directory or a
+/// manifest that cannot be written.
+pub fn import_directory(dir: impl AsRef<Path>, format: Format) -> Result<BatchImport> {
+    let dir = dir.as_ref();
+    let mut paths = Vec::new();
+    for entry in
+        fs::read_dir(dir).with_context(|| format!("reading case directory {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let matches = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                format
+                    .extensions()
+                    .iter()
+                    .any(|e| e.eq_ignore_ascii_case(ext))
+            });
+        let is_manifest = path
+            .file_name()
+            .is_some_and(|name| name == BATCH_MANIFEST_FILE);
+        if path.is_file() && matches && !is_manifest {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut batch = BatchImport {
+        format,
+        results: Vec::with_capacity(paths.len()),
+        summary: BatchSummary {
+            files: paths.len(),
+            ..BatchSummary::default()
+        },
+        entries: Vec::with_capacity(paths.len()),
+    };
+
+    for path in paths {
+        let file = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let entry = match format.parse(&path.to_string_lossy()) {
+            Ok(imported) => {
+                let stats = imported.network.stats();
+                let warnings = imported.diagnostics.warning_count();
+                batch.summary.succeeded += 1;
+                batch.summary.warnings += warnings;
+                batch.results.push((file.clone(), Ok(imported.network)));
+                BatchEntry {
+                    file,
+                    status: "ok",
+                    buses: Some(stats.num_buses),
+                    branches: Some(stats.num_branches),
+                    generators: Some(stats.num_gens),
+                    loads: Some(stats.num_loads),
+                    warnings,
+                    error: None,
+                }
+            }
+            Err(err) => {
+                batch.summary.failed += 1;
+                let error = format!("{:#}", err);
+                batch.results.push((file.clone(), Err(err)));
+                BatchEntry {
+                    file,
+                    status: "error",
+                    buses: None,
+                    branches: None,
+                    generators: None,
+                    loads: None,
+                    warnings: 0,
+                    error: Some(error),
+                }
+            }
+        };
+        batch.entries.push(entry);
+    }
+
+    let manifest = Manifest {
+        format: format.command_name(),
+        summary: &batch.summary,
+        files: &batch.entries,
+    };
+    let manifest_path = dir.join(BATCH_MANIFEST_FILE);
+    fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)
+        .with_context(|| format!("writing {}", manifest_path.display()))?;
+
+    Ok(batch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const VALID_CASE: &str = r#"
+function mpc = case2
+mpc.version = '2';
+mpc.baseMVA = 100.0;
+mpc.bus = [
+    1   3   0.0    0.0   0.0   0.0   1   1.0   0.0   230.0   1   1.1   0.9;
+    2   1   50.0   10.0  0.0   0.0   1   1.0   0.0   230.0   1   1.1   0.9;
+];
+mpc.gen = [
+    1   50.0   0.0   30.0   -30.0   1.0   100.0   1   100.0   0.0;
+];
+mpc.branch = [
+    1   2   0.01   0.1   0.0   100.0   100.0   100.0   0.0   0.0   1   -360.0   360.0;
+];
+"#;
+
+    #[test]
+    fn test_import_directory_reports_each_file() {
+        let dir = tempdir().expect("tmp dir");
+        fs::write(dir.path().join("a_valid.m"), VALID_CASE).unwrap();
+        fs::write(dir.path().join("b_broken.m"), "mpc.baseMVA = 100;\n").unwrap();
+        fs::write(dir.path().join("notes.txt"), "not a case").unwrap();
+
+        let batch = import_directory(dir.path(), Format::Matpower).unwrap();
+
+        assert_eq!(
+            batch.summary,
+            BatchSummary {
+                files: 2,
+                succeeded: 1,
+                failed: 1,
+                warnings: batch.summary.warnings,
+            }
+        );
+        assert_eq!(batch.results[0].0, "a_valid.m");
+        let network = batch.results[0].1.as_ref().expect("valid case imports");
+        assert_eq!(network.stats().num_buses, 2);
+        assert_eq!(batch.results[1].0, "b_broken.m");
+        assert!(batch.results[1].1.is_err());
+        assert_eq!(batch.networks().count(), 1);
+
+        let manifest: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(dir.path().join(BATCH_MANIFEST_FILE)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(manifest["summary"]["failed"], 1);
+        assert_eq!(manifest["files"][0]["status"], "ok");
+        assert_eq!(manifest["files"][0]["buses"], 2);
+        assert_eq!(manifest["files"][1]["status"], "error");
+        assert!(manifest["files"][1]["error"]
+            .as_str()
+            .unwrap()
+            .contains("mpc.bus"));
+    }
+}
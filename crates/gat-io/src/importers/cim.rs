@@ -78,6 +78,7 @@ pub(crate) struct CimBus {
 }
 
 pub(crate) struct CimLine {
+    pub id: String,
     pub name: String,
     pub from: String,
     pub to: String,
@@ -86,6 +87,7 @@ pub(crate) struct CimLine {
 }
 
 pub(crate) struct CimLoad {
+    pub id: Option<String>,
     pub name: String,
     pub bus_id: String,
     pub active_power_mw: f64,
@@ -93,6 +95,7 @@ pub(crate) struct CimLoad {
 }
 
 pub(crate) struct CimGen {
+    pub id: Option<String>,
     pub name: String,
     pub bus_id: String,
     pub active_power_mw: f64,
@@ -136,6 +139,7 @@ type CimImportResult = (
 );
 
 struct PendingCimGen {
+    id: Option<String>,
     name: String,
     terminal_ref: Option<String>,
     bus_ref: Option<String>,
@@ -144,8 +148,9 @@ struct PendingCimGen {
 }
 
 impl PendingCimGen {
-    fn new() -> Self {
+    fn new(id: Option<String>) -> Self {
         Self {
+            id,
             name: String::new(),
             terminal_ref: None,
             bus_ref: None,
@@ -156,6 +161,7 @@ impl PendingCimGen {
 }
 
 struct PendingCimLoad {
+    id: Option<String>,
     name: String,
     bus_ref: Option<String>,
     terminal_ref: Option<String>,
@@ -169,8 +175,9 @@ struct PendingCimTerminal {
 }
 
 impl PendingCimLoad {
-    fn new() -> Self {
+    fn new(id: Option<String>) -> Self {
         Self {
+            id,
             name: String::new(),
             bus_ref: None,
             terminal_ref: None,
@@ -290,8 +297,9 @@ pub(crate) fn parse_cim_documents(documents: &[String]) -> Result<CimImportResul
                             }
                         }
                         "ACLineSegment" => {
-                            if attribute_value(e, "ID")?.is_some() {
+                            if let Some(id) = attribute_value(e, "ID")? {
                                 current_line = Some(CimLine {
+                                    id,
                                     name: String::new(),
                                     from: String::new(),
                                     to: String::new(),
@@ -301,10 +309,10 @@ pub(crate) fn parse_cim_documents(documents: &[String]) -> Result<CimImportResul
                             }
                         }
                         "Load" => {
-                            current_load = Some(PendingCimLoad::new());
+                            current_load = Some(PendingCimLoad::new(attribute_value(e, "ID")?));
                         }
                         "SynchronousMachine" => {
-                            current_gen = Some(PendingCimGen::new());
+                            current_gen = Some(PendingCimGen::new(attribute_value(e, "ID")?));
                         }
                         "Terminal" => {
                             if let Some(id) = attribute_value(e, "ID")? {
@@ -464,6 +472,7 @@ pub(crate) fn parse_cim_documents(documents: &[String]) -> Result<CimImportResul
                                 });
                                 if let Some(bus_id) = bus_ref {
                                     loads.push(CimLoad {
+                                        id: load.id,
                                         name: load.name,
                                         bus_id,
                                         active_power_mw: load.active_power_mw,
@@ -481,6 +490,7 @@ pub(crate) fn parse_cim_documents(documents: &[String]) -> Result<CimImportResul
                                 });
                                 if let Some(bus_id) = bus_ref {
                                     gens.push(CimGen {
+                                        id: gen.id,
                                         name: gen.name,
                                         bus_id,
                                         active_power_mw: gen.active_power_mw,
@@ -535,6 +545,7 @@ fn build_network_from_cim(
             base_kv: gat_core::Kilovolts(138.0),
             ..Bus::default()
         }));
+        network.external_ids.buses.insert(bus_id, bus.id.clone());
         node_map.insert(bus.id, (bus_id, node_idx));
     }
 
@@ -549,12 +560,19 @@ fn build_network_from_cim(
             } else {
                 load.name.clone()
             };
+            if let Some(mrid) = &load.id {
+                network
+                    .external_ids
+                    .loads
+                    .insert(LoadId::new(load_counter), mrid.clone());
+            }
             network.graph.add_node(Node::Load(Load {
                 id: LoadId::new(load_counter),
                 name,
                 bus: *bus_id,
                 active_power: gat_core::Megawatts(load.active_power_mw),
                 reactive_power: gat_core::Megavars(load.reactive_power_mvar),
+                zip: None,
             }));
             load_counter += 1;
         }
@@ -571,6 +589,12 @@ fn build_network_from_cim(
             } else {
                 gen.name.clone()
             };
+            if let Some(mrid) = &gen.id {
+                network
+                    .external_ids
+                    .gens
+                    .insert(GenId::new(gen_counter), mrid.clone());
+            }
             network.graph.add_node(Node::Gen(Gen {
                 id: GenId::new(gen_counter),
                 name,
@@ -611,6 +635,7 @@ fn build_network_from_cim(
             ..Branch::default()
         };
 
+        network.external_ids.branches.insert(branch.id, line.id);
         network
             .graph
             .add_edge(*from_idx, *to_idx, Edge::Branch(branch));
@@ -670,6 +695,7 @@ fn build_network_from_cim_with_diagnostics(
             base_kv: gat_core::Kilovolts(138.0),
             ..Bus::default()
         }));
+        network.external_ids.buses.insert(bus_id, bus.id.clone());
         node_map.insert(bus.id, (bus_id, node_idx));
         diag.stats.buses += 1;
     }
@@ -686,12 +712,19 @@ fn build_network_from_cim_with_diagnostics(
             } else {
                 load.name.clone()
             };
+            if let Some(mrid) = &load.id {
+                network
+                    .external_ids
+                    .loads
+                    .insert(LoadId::new(load_counter), mrid.clone());
+            }
             network.graph.add_node(Node::Load(Load {
                 id: LoadId::new(load_counter),
                 name,
                 bus: *bus_id,
                 active_power: gat_core::Megawatts(load.active_power_mw),
                 reactive_power: gat_core::Megavars(load.reactive_power_mvar),
+                zip: None,
             }));
             load_counter += 1;
             diag.stats.loads += 1;
@@ -718,6 +751,12 @@ fn build_network_from_cim_with_diagnostics(
             } else {
                 gen.name.clone()
             };
+            if let Some(mrid) = &gen.id {
+                network
+                    .external_ids
+                    .gens
+                    .insert(GenId::new(gen_counter), mrid.clone());
+            }
             network.graph.add_node(Node::Gen(Gen {
                 id: GenId::new(gen_counter),
                 name,
@@ -790,6 +829,7 @@ fn build_network_from_cim_with_diagnostics(
             ..Branch::default()
         };
 
+        network.external_ids.branches.insert(branch.id, line.id);
         network
             .graph
             .add_edge(*from_idx, *to_idx, Edge::Branch(branch));
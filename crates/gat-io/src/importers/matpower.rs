@@ -4,7 +4,7 @@ use std::path::Path;
 
 use anyhow::{anyhow, Context, Result};
 use caseformat::{read_dir, read_zip, Branch as CaseBranch, Bus as CaseBus, Gen as CaseGen};
-use gat_core::Network;
+use gat_core::{BusType, Network};
 
 use super::matpower_parser::{parse_matpower_file, MatpowerCase, MatpowerGenCost};
 use crate::arrow_manifest::{compute_sha256, SourceInfo};
@@ -325,6 +325,7 @@ fn build_network_from_matpower_case_impl(
             vmax_pu: Some(bus.vmax),
             area_id: Some(bus.area as i64),
             zone_id: Some(bus.zone as i64),
+            bus_type: BusType::from_code(bus.bus_type as i64),
         });
     }
 
@@ -494,6 +495,7 @@ fn build_network_from_case_impl(
             vmax_pu: Some(case_bus.vmax),
             area_id: None,
             zone_id: Some(case_bus.zone as i64),
+            bus_type: BusType::from_code(case_bus.bus_type as i64),
         });
     }
 
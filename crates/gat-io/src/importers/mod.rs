@@ -117,7 +117,9 @@
 //! - [`parse_psse`] - Import PSS/E RAW files
 //! - [`parse_cim`] - Import CIM RDF/XML files
 //! - [`parse_pandapower`] - Import pandapower JSON files
+//! - [`import_directory`] - Import every case in a directory, with a results manifest
 //! - [`ArrowDirectoryReader`] - Read networks from Arrow directory format
+//! - [`load_grid_from_arrow_streaming`] - Batch-by-batch Arrow load for very large networks
 //! - [`ArrowDirectoryWriter`] - Write networks to Arrow directory format
 //!
 //! ## Module Organization
@@ -135,6 +137,7 @@ mod arrow;
 mod arrow_disabled;
 #[cfg(not(feature = "ipc"))]
 use arrow_disabled as arrow;
+mod batch;
 pub mod cim;
 mod cim_validator;
 mod format;
@@ -146,8 +149,10 @@ pub mod psse;
 
 pub use crate::exporters::{ArrowDirectoryReader, ArrowDirectoryWriter};
 pub use arrow::{
-    export_network_to_arrow, load_grid_from_arrow, load_grid_from_arrow_with_manifest,
+    export_network_to_arrow, load_grid_from_arrow, load_grid_from_arrow_streaming,
+    load_grid_from_arrow_with_manifest,
 };
+pub use batch::{import_directory, BatchEntry, BatchImport, BatchSummary, BATCH_MANIFEST_FILE};
 pub use cim_validator::{
     validate_cim_with_warnings, validate_network_from_cim, CimValidationError,
 };
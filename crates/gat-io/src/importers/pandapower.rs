@@ -260,6 +260,7 @@ fn build_network_from_pandapower(
                 bus: BusId::new(bus),
                 active_power: gat_core::Megawatts(p_mw),
                 reactive_power: gat_core::Megavars(q_mvar),
+                zip: None,
             }));
             load_id += 1;
             diag.stats.loads += 1;
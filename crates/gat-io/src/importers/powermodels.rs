@@ -9,8 +9,8 @@ use std::{collections::HashMap, fs, path::Path};
 
 use anyhow::{anyhow, Context, Result};
 use gat_core::{
-    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Load, LoadId, Network, Node,
-    NodeIndex,
+    Branch, BranchId, Bus, BusId, BusType, CostModel, Edge, Gen, GenId, Load, LoadId, Network,
+    Node, NodeIndex,
 };
 use serde::Deserialize;
 use serde_json::Value;
@@ -395,6 +395,7 @@ fn import_bus(data: &BusData) -> Result<Bus> {
         vmin_pu: Some(gat_core::PerUnit(data.vmin)),
         area_id: data.area,
         zone_id: data.zone,
+        bus_type: BusType::from_code(data.bus_type),
     })
 }
 
@@ -475,6 +476,7 @@ fn import_load(data: &LoadData, bus_map: &HashMap<i64, NodeIndex>) -> Result<Loa
         bus: BusId::new(data.load_bus as usize),
         active_power: gat_core::Megawatts(data.pd),
         reactive_power: gat_core::Megavars(data.qd),
+        zip: None,
     })
 }
 
@@ -2,7 +2,7 @@ use std::{collections::HashMap, fs, path::Path};
 
 use anyhow::{Context, Result};
 use gat_core::{
-    Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Network, Node, NodeIndex,
+    Branch, BranchId, Bus, BusId, BusType, Edge, Gen, GenId, Load, LoadId, Network, Node, NodeIndex,
 };
 
 use super::arrow::export_network_to_arrow;
@@ -50,6 +50,7 @@ struct PsseBus {
     id: usize,
     name: String,
     voltage_kv: f64,
+    bus_type: Option<BusType>,
 }
 
 struct PsseBranch {
@@ -380,11 +381,16 @@ fn parse_psse_bus_line(line: &str) -> Option<PsseBus> {
         .trim()
         .to_string();
     let voltage_kv = columns[2].parse::<f64>().unwrap_or(0.0);
+    let bus_type = columns
+        .get(3)
+        .and_then(|ide| ide.parse::<i64>().ok())
+        .and_then(BusType::from_code);
 
     Some(PsseBus {
         id,
         name,
         voltage_kv,
+        bus_type,
     })
 }
 
@@ -490,11 +496,16 @@ fn parse_psse_bus_line_v33(line: &str) -> Option<PsseBus> {
         .trim()
         .to_string();
     let voltage_kv = columns[2].parse::<f64>().unwrap_or(0.0);
+    let bus_type = columns
+        .get(3)
+        .and_then(|ide| ide.parse::<i64>().ok())
+        .and_then(BusType::from_code);
 
     Some(PsseBus {
         id,
         name,
         voltage_kv,
+        bus_type,
     })
 }
 
@@ -692,6 +703,7 @@ fn build_network_from_psse(
             id,
             name: bus.name,
             base_kv: gat_core::Kilovolts(bus.voltage_kv),
+            bus_type: bus.bus_type,
             ..Bus::default()
         }));
         bus_index_map.insert(bus.id, node_idx);
@@ -717,6 +729,7 @@ fn build_network_from_psse(
             bus: BusId::new(bus_idx),
             active_power: gat_core::Megawatts(pd),
             reactive_power: gat_core::Megavars(qd),
+            zip: None,
         }));
         load_id += 1;
     }
@@ -60,6 +60,32 @@ fn import_matpower_case_sample() {
     assert!(load_count >= 1);
 }
 
+#[test]
+fn streaming_arrow_import_matches_full_load() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let case_path = manifest_dir.join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+    let network = load_matpower_network(&case_path).expect("case14 should load");
+
+    // Four-row batches split every table into several record batches
+    let temp_dir = tempdir().expect("tmp dir");
+    let output_path = temp_dir.path().join("batched_arrow_dir");
+    ArrowDirectoryWriter::new(&output_path)
+        .expect("writer")
+        .with_batch_rows(4)
+        .write_network(&network, None, None)
+        .expect("batched export should succeed");
+
+    let reader = ArrowDirectoryReader::open(&output_path).expect("should open arrow directory");
+    let bus_batches = reader.table_batches("buses").unwrap().count();
+    assert_eq!(bus_batches, 4, "14 buses in batches of 4");
+
+    let full = load_grid_from_arrow(&output_path).expect("full load");
+    let streamed = load_grid_from_arrow_streaming(&output_path).expect("streaming load");
+    assert_eq!(streamed.stats(), full.stats());
+    assert_eq!(streamed.graph.node_count(), full.graph.node_count());
+    assert_eq!(streamed.graph.edge_count(), full.graph.edge_count());
+}
+
 #[test]
 fn import_matpower_case_records_system_metadata() {
     let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -280,6 +306,7 @@ fn test_cim_validation_invalid_voltage() {
         vmax_pu: Some(gat_core::PerUnit(1.05)),
         area_id: None,
         zone_id: None,
+        bus_type: None,
     };
     network.graph.add_node(Node::Bus(bus));
 
@@ -342,6 +369,7 @@ fn test_cim_validation_warnings() {
         vmax_pu: Some(gat_core::PerUnit(1.05)),
         area_id: None,
         zone_id: None,
+        bus_type: None,
     };
     network.graph.add_node(Node::Bus(bus));
 
@@ -393,3 +421,16 @@ fn test_gencost_polynomial_loaded() {
         "At least one generator should have polynomial cost from gencost"
     );
 }
+
+#[test]
+fn test_network_report_case14() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let path = manifest_dir.join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+    let network = load_matpower_network(&path).expect("Failed to load case14");
+
+    let report = network.report();
+    assert!(report.contains("Buses:      14"), "{}", report);
+    assert!(report.contains("Branches:   20"), "{}", report);
+    assert!(report.contains(" kV: "), "{}", report);
+    assert_eq!(report, network.report(), "report should be deterministic");
+}
@@ -49,6 +49,11 @@
 //! - [`importers::parse_cim`] - CIM RDF/XML format
 //! - [`importers::parse_pandapower`] - pandapower JSON format
 //! - [`importers::Format`] - Format detection and unified interface
+//! - [`importers::import_directory`] - Batch import of a case directory with a results manifest
+//!
+//! ### Overrides ([`overrides`])
+//! - [`apply_limits_csv`] - Branch ratings and bus voltage bands from CSV
+//! - [`apply_costs_csv`] - Generator cost curves from CSV
 //!
 //! ### Arrow Schema & Validation ([`arrow_schema`], [`arrow_validator`])
 //! - Normalized multi-file Arrow schema for lossless storage
@@ -75,6 +80,8 @@
 //! ## Feature Flags
 //!
 //! - **Default**: All import formats enabled
+//! - `parquet`: Parquet result tagging ([`result_metadata`]: `gat_result_type`, `gat_schema_version`, `solver`)
+//!   and OPF result tables ([`opf_solution_to_arrow`])
 //! - `wasm`: WASM-compatible build (disables file I/O, stubs network access)
 //!
 //! ## Error Handling
@@ -128,17 +135,39 @@ pub mod export;
 #[cfg(feature = "native-io")]
 pub mod exporters;
 #[cfg(feature = "native-io")]
+pub use exporters::{apply_solution_to_arrow, SolvedState};
+#[cfg(feature = "native-io")]
 pub mod validate;
+#[cfg(feature = "native-io")]
+pub use validate::{validate_arrow_schema, SchemaKind};
 
 // Re-export SolutionExport trait for convenience
 #[cfg(feature = "native-io")]
 pub use export::SolutionExport;
 
+// Result-type tags in Parquet key-value metadata (uses the arrow/parquet crates)
+#[cfg(feature = "parquet")]
+pub mod result_metadata;
+#[cfg(feature = "parquet")]
+pub use result_metadata::{
+    read_result_metadata, read_result_type, write_result_metadata, ResultMetadata,
+};
+
+// OPF results as Arrow record batches, in the layout shared with gat-wasm
+#[cfg(feature = "parquet")]
+pub mod opf_arrow;
+#[cfg(feature = "parquet")]
+pub use opf_arrow::{opf_solution_to_arrow, OpfRecordBatches, OpfResultData};
+
 // Modules requiring non-WASM filesystem access AND native-io (polars)
 // These modules use both std::fs and polars DataFrame operations
 #[cfg(all(not(target_arch = "wasm32"), feature = "native-io"))]
 pub mod importers;
 #[cfg(all(not(target_arch = "wasm32"), feature = "native-io"))]
+pub mod overrides;
+#[cfg(all(not(target_arch = "wasm32"), feature = "native-io"))]
+pub use overrides::{apply_costs_csv, apply_limits_csv};
+#[cfg(all(not(target_arch = "wasm32"), feature = "native-io"))]
 pub mod sources;
 
 // For wasm builds we stub IO; web demo should provide data from host/JS.
@@ -0,0 +1,263 @@
+//! Arrow tables for solved OPF results.
+//!
+//! Converts an OPF solution into four `RecordBatch`es laid out by the OPF
+//! result schemas in [`crate::arrow_schema`]:
+//!
+//! | Table        | Schema                                     | Rows            |
+//! |--------------|--------------------------------------------|-----------------|
+//! | `generators` | [`opf_generators_schema`]                  | One per unit    |
+//! | `buses`      | [`opf_buses_schema`]                       | One per bus     |
+//! | `branches`   | [`opf_branches_schema`]                    | One per branch  |
+//! | `summary`    | [`opf_summary_schema`]                     | Exactly one     |
+//!
+//! The WASM crate's Arrow IPC export builds its tables from the same schemas,
+//! so desktop and browser results can be compared directly. Batches can be
+//! written with any Arrow writer (Parquet, IPC).
+
+use anyhow::{Context, Result};
+use arrow::array::{ArrayRef, BooleanArray, Float64Array, StringArray, UInt64Array};
+use arrow::record_batch::RecordBatch;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use crate::arrow_schema::{
+    opf_branches_schema, opf_buses_schema, opf_generators_schema, opf_summary_schema,
+};
+
+/// Solved OPF result keyed by generator, bus, and branch name.
+///
+/// Field names and units follow `gat_algo::opf::OpfSolution`, so a solution can be
+/// carried over field by field.
+#[derive(Debug, Clone, Default)]
+pub struct OpfResultData {
+    pub converged: bool,
+    /// Solve method label (e.g. `DcOpf`)
+    pub method: String,
+    pub objective_value: f64,
+    pub solve_time_ms: u128,
+    /// Total load served in MW (not part of the solution; taken from the network)
+    pub total_load_mw: f64,
+    pub total_losses_mw: f64,
+    /// Active power dispatch in MW
+    pub generator_p: HashMap<String, f64>,
+    /// Reactive power dispatch in MVAr; generators without an entry export as zero
+    pub generator_q: HashMap<String, f64>,
+    /// True when `generator_q` is zero or estimated rather than optimized
+    /// (`gat_algo::OpfMethod::reactive_is_exact` is false)
+    pub reactive_estimated: bool,
+    /// Solver warning messages (`gat_algo::opf::SolverWarning` display text)
+    pub warnings: Vec<String>,
+    /// Voltage magnitude in p.u.
+    pub bus_voltage_mag: HashMap<String, f64>,
+    /// Voltage angle in radians
+    pub bus_voltage_ang: HashMap<String, f64>,
+    /// Locational marginal price in $/MWh
+    pub bus_lmp: HashMap<String, f64>,
+    /// Active power flow in MW
+    pub branch_p_flow: HashMap<String, f64>,
+    /// Reactive power flow in MVAr
+    pub branch_q_flow: HashMap<String, f64>,
+}
+
+/// OPF result tables in the shared OPF result layout.
+#[derive(Debug, Clone)]
+pub struct OpfRecordBatches {
+    pub generators: RecordBatch,
+    pub buses: RecordBatch,
+    pub branches: RecordBatch,
+    pub summary: RecordBatch,
+}
+
+/// Convert a solved OPF result into generator, bus, branch, and summary tables.
+pub fn opf_solution_to_arrow(solution: &OpfResultData) -> Result<OpfRecordBatches> {
+    let gen_ids = sorted_ids([&solution.generator_p]);
+    let generators = RecordBatch::try_new(
+        Arc::new(opf_generators_schema()),
+        vec![
+            string_column(&gen_ids),
+            float_column(&gen_ids, &solution.generator_p, |v| v),
+            Arc::new(
+                gen_ids
+                    .iter()
+                    .map(|id| solution.generator_q.get(*id).copied().unwrap_or(0.0))
+                    .collect::<Float64Array>(),
+            ),
+        ],
+    )
+    .context("building OPF generators table")?;
+
+    let bus_ids = sorted_ids([
+        &solution.bus_voltage_mag,
+        &solution.bus_voltage_ang,
+        &solution.bus_lmp,
+    ]);
+    let buses = RecordBatch::try_new(
+        Arc::new(opf_buses_schema()),
+        vec![
+            string_column(&bus_ids),
+            float_column(&bus_ids, &solution.bus_voltage_mag, |v| v),
+            float_column(&bus_ids, &solution.bus_voltage_ang, f64::to_degrees),
+            float_column(&bus_ids, &solution.bus_lmp, |v| v),
+        ],
+    )
+    .context("building OPF buses table")?;
+
+    let branch_ids = sorted_ids([&solution.branch_p_flow]);
+    let branches = RecordBatch::try_new(
+        Arc::new(opf_branches_schema()),
+        vec![
+            string_column(&branch_ids),
+            float_column(&branch_ids, &solution.branch_p_flow, |v| v),
+            float_column(&branch_ids, &solution.branch_q_flow, |v| v),
+        ],
+    )
+    .context("building OPF branches table")?;
+
+    let summary = RecordBatch::try_new(
+        Arc::new(opf_summary_schema()),
+        vec![
+            Arc::new(BooleanArray::from(vec![solution.converged])) as ArrayRef,
+            Arc::new(Float64Array::from(vec![solution.objective_value])),
+            Arc::new(UInt64Array::from(vec![solution.solve_time_ms as u64])),
+            Arc::new(StringArray::from(vec![solution.method.as_str()])),
+            Arc::new(Float64Array::from(vec![solution
+                .generator_p
+                .values()
+                .sum::<f64>()])),
+            Arc::new(Float64Array::from(vec![solution.total_load_mw])),
+            Arc::new(Float64Array::from(vec![solution.total_losses_mw])),
+            Arc::new(BooleanArray::from(vec![solution.reactive_estimated])),
+            Arc::new(StringArray::from(vec![solution.warnings.join("\n")])),
+        ],
+    )
+    .context("building OPF summary table")?;
+
+    Ok(OpfRecordBatches {
+        generators,
+        buses,
+        branches,
+        summary,
+    })
+}
+
+/// Union of the keys of `maps`, in sorted order.
+fn sorted_ids<const N: usize>(maps: [&HashMap<String, f64>; N]) -> Vec<&str> {
+    let ids: BTreeSet<&str> = maps
+        .iter()
+        .flat_map(|map| map.keys().map(String::as_str))
+        .collect();
+    ids.into_iter().collect()
+}
+
+fn string_column(ids: &[&str]) -> ArrayRef {
+    Arc::new(StringArray::from(ids.to_vec()))
+}
+
+/// Values of `map` for each of `ids` (null where absent), passed through `convert`.
+fn float_column(
+    ids: &[&str],
+    map: &HashMap<String, f64>,
+    convert: impl Fn(f64) -> f64,
+) -> ArrayRef {
+    Arc::new(
+        ids.iter()
+            .map(|id| map.get(*id).copied().map(&convert))
+            .collect::<Float64Array>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::importers::parse_matpower;
+    use gat_algo::{OpfMethod, OpfSolver};
+    use gat_core::Node;
+    use std::path::PathBuf;
+
+    fn ieee14() -> gat_core::Network {
+        let case_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../test_data/matpower/pglib/pglib_opf_case14_ieee.m");
+        parse_matpower(case_path.to_str().unwrap())
+            .expect("IEEE 14 should import")
+            .network
+    }
+
+    fn solve_to_arrow(network: &gat_core::Network, method: OpfMethod) -> OpfRecordBatches {
+        let solution = OpfSolver::new()
+            .with_method(method)
+            .solve(network)
+            .unwrap_or_else(|e| panic!("IEEE 14 {} OPF should solve: {}", method, e));
+
+        let total_load_mw = network
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                Node::Load(load) => Some(load.active_power.value()),
+                _ => None,
+            })
+            .sum();
+        let result = OpfResultData {
+            converged: solution.converged,
+            method: format!("{:?}", solution.method_used),
+            objective_value: solution.objective_value,
+            solve_time_ms: solution.solve_time_ms,
+            total_load_mw,
+            total_losses_mw: solution.total_losses_mw,
+            generator_p: solution.generator_p.clone(),
+            generator_q: solution.generator_q.clone(),
+            reactive_estimated: !solution.method_used.reactive_is_exact(),
+            warnings: solution.warnings.iter().map(|w| w.to_string()).collect(),
+            bus_voltage_mag: solution.bus_voltage_mag.clone(),
+            bus_voltage_ang: solution.bus_voltage_ang.clone(),
+            bus_lmp: solution.bus_lmp.clone(),
+            branch_p_flow: solution.branch_p_flow.clone(),
+            branch_q_flow: solution.branch_q_flow.clone(),
+        };
+        opf_solution_to_arrow(&result).unwrap()
+    }
+
+    #[test]
+    fn test_ieee14_opf_to_arrow() {
+        let tables = solve_to_arrow(&ieee14(), OpfMethod::DcOpf);
+
+        let columns: Vec<&str> = tables
+            .generators
+            .schema_ref()
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect();
+        assert_eq!(columns, ["gen_id", "p_mw", "q_mvar"]);
+        assert_eq!(tables.generators.num_rows(), 5);
+        assert_eq!(tables.buses.num_rows(), 14);
+        assert_eq!(tables.summary.num_rows(), 1);
+
+        let ids = tables.generators.column(0).as_any();
+        let ids = ids.downcast_ref::<StringArray>().unwrap();
+        assert!(ids.value(0) < ids.value(1), "rows sorted by gen_id");
+    }
+
+    #[test]
+    fn test_generator_table_has_q_for_every_method() {
+        let network = ieee14();
+        for method in [
+            OpfMethod::EconomicDispatch,
+            OpfMethod::DcOpf,
+            OpfMethod::SocpRelaxation,
+        ] {
+            let tables = solve_to_arrow(&network, method);
+            let q = tables.generators.column_by_name("q_mvar").unwrap();
+            assert_eq!(q.len(), 5, "{}", method);
+            assert_eq!(q.null_count(), 0, "{}", method);
+
+            let estimated = tables.summary.column_by_name("reactive_estimated").unwrap();
+            let estimated = estimated.as_any().downcast_ref::<BooleanArray>().unwrap();
+            assert_eq!(
+                estimated.value(0),
+                !method.reactive_is_exact(),
+                "{}",
+                method
+            );
+        }
+    }
+}
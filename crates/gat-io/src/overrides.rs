@@ -0,0 +1,319 @@
+//! Limit and cost overrides applied to an imported network.
+//!
+//! Studies often keep ratings, voltage bands and generator costs in CSV
+//! files next to the case rather than editing the case itself.
+//! [`apply_limits_csv`] and [`apply_costs_csv`] overlay those files onto a
+//! [`Network`]. Every row must name an element that exists; an unknown ID
+//! fails the whole file before anything is changed.
+//!
+//! Limits file, one branch or bus per row; empty cells leave a value as is:
+//!
+//! ```text
+//! element,id,rating_mva,vmin_pu,vmax_pu
+//! branch,3,250,,
+//! bus,7,,0.95,1.05
+//! ```
+//!
+//! Costs file, a polynomial `c0 + c1·P + c2·P²` per generator ($/hr, P in MW):
+//!
+//! ```text
+//! gen_id,c0,c1,c2
+//! 1,0,20.5,0.01
+//! 2,100,35,
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+use csv::ReaderBuilder;
+use gat_core::{CostModel, Edge, EdgeIndex, MegavoltAmperes, Network, Node, NodeIndex, PerUnit};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct LimitRow {
+    element: String,
+    id: usize,
+    rating_mva: Option<f64>,
+    vmin_pu: Option<f64>,
+    vmax_pu: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct CostRow {
+    gen_id: usize,
+    c0: Option<f64>,
+    c1: f64,
+    c2: Option<f64>,
+}
+
+/// Read every row of a headed CSV, numbering errors by file line
+fn read_rows<T: for<'de> Deserialize<'de>>(path: &Path, what: &str) -> Result<Vec<T>> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_path(path)
+        .with_context(|| format!("opening {} CSV '{}'", what, path.display()))?;
+    reader
+        .deserialize()
+        .enumerate()
+        .map(|(row, record)| record.with_context(|| format!("{} CSV line {}", what, row + 2)))
+        .collect()
+}
+
+/// Overlay branch ratings and bus voltage bands from a limits CSV.
+///
+/// A branch `rating_mva` sets both Rate A and `s_max`, so DC and AC
+/// formulations see the same limit. Ratings must be positive and a bus band
+/// must have `vmin_pu ≤ vmax_pu` once merged with the bus's current values.
+/// Returns the number of rows applied.
+pub fn apply_limits_csv(network: &mut Network, path: impl AsRef<Path>) -> Result<usize> {
+    let rows: Vec<LimitRow> = read_rows(path.as_ref(), "limits")?;
+
+    let mut branches: HashMap<usize, EdgeIndex> = HashMap::new();
+    for edge in network.graph.edge_indices() {
+        if let Edge::Branch(branch) = &network.graph[edge] {
+            branches.insert(branch.id.value(), edge);
+        }
+    }
+    let mut buses: HashMap<usize, NodeIndex> = HashMap::new();
+    for node in network.graph.node_indices() {
+        if let Node::Bus(bus) = &network.graph[node] {
+            buses.insert(bus.id.value(), node);
+        }
+    }
+
+    // Resolve and check every row before touching the network
+    enum Target {
+        Branch(EdgeIndex, f64),
+        Bus(NodeIndex, Option<f64>, Option<f64>),
+    }
+    let mut targets = Vec::with_capacity(rows.len());
+    for (row, limit) in rows.iter().enumerate() {
+        let line = row + 2;
+        let target = match limit.element.to_ascii_lowercase().as_str() {
+            "branch" => {
+                let edge = *branches.get(&limit.id).ok_or_else(|| {
+                    anyhow!("limits CSV line {}: unknown branch {}", line, limit.id)
+                })?;
+                let rating = limit.rating_mva.ok_or_else(|| {
+                    anyhow!(
+                        "limits CSV line {}: branch {} has no rating_mva",
+                        line,
+                        limit.id
+                    )
+                })?;
+                if rating.is_nan() || rating <= 0.0 {
+                    bail!(
+                        "limits CSV line {}: branch {} rating {} MVA is not positive",
+                        line,
+                        limit.id,
+                        rating
+                    );
+                }
+                Target::Branch(edge, rating)
+            }
+            "bus" => {
+                let node = *buses
+                    .get(&limit.id)
+                    .ok_or_else(|| anyhow!("limits CSV line {}: unknown bus {}", line, limit.id))?;
+                let Node::Bus(bus) = &network.graph[node] else {
+                    unreachable!("bus index maps to a bus node");
+                };
+                let vmin = limit.vmin_pu.or(bus.vmin_pu.map(|v| v.value()));
+                let vmax = limit.vmax_pu.or(bus.vmax_pu.map(|v| v.value()));
+                if let (Some(vmin), Some(vmax)) = (vmin, vmax) {
+                    if vmin > vmax {
+                        bail!(
+                            "limits CSV line {}: bus {} band [{}, {}] p.u. is inverted",
+                            line,
+                            limit.id,
+                            vmin,
+                            vmax
+                        );
+                    }
+                }
+                Target::Bus(node, limit.vmin_pu, limit.vmax_pu)
+            }
+            other => bail!(
+                "limits CSV line {}: element '{}' is not 'branch' or 'bus'",
+                line,
+                other
+            ),
+        };
+        targets.push(target);
+    }
+
+    for target in &targets {
+        match *target {
+            Target::Branch(edge, rating) => {
+                if let Edge::Branch(branch) = &mut network.graph[edge] {
+                    branch.rating_a = Some(MegavoltAmperes(rating));
+                    branch.s_max = Some(MegavoltAmperes(rating));
+                }
+            }
+            Target::Bus(node, vmin, vmax) => {
+                if let Node::Bus(bus) = &mut network.graph[node] {
+                    if let Some(vmin) = vmin {
+                        bus.vmin_pu = Some(PerUnit(vmin));
+                    }
+                    if let Some(vmax) = vmax {
+                        bus.vmax_pu = Some(PerUnit(vmax));
+                    }
+                }
+            }
+        }
+    }
+    Ok(targets.len())
+}
+
+/// Replace generator cost curves from a costs CSV.
+///
+/// Each row sets a polynomial cost; a missing `c0` or `c2` is zero. Returns
+/// the number of generators updated.
+pub fn apply_costs_csv(network: &mut Network, path: impl AsRef<Path>) -> Result<usize> {
+    let rows: Vec<CostRow> = read_rows(path.as_ref(), "costs")?;
+
+    let mut gens: HashMap<usize, NodeIndex> = HashMap::new();
+    for node in network.graph.node_indices() {
+        if let Node::Gen(gen) = &network.graph[node] {
+            gens.insert(gen.id.value(), node);
+        }
+    }
+
+    let mut targets = Vec::with_capacity(rows.len());
+    for (row, cost) in rows.iter().enumerate() {
+        let node = *gens.get(&cost.gen_id).ok_or_else(|| {
+            anyhow!(
+                "costs CSV line {}: unknown generator {}",
+                row + 2,
+                cost.gen_id
+            )
+        })?;
+        let model = match cost.c2 {
+            Some(c2) => CostModel::quadratic(cost.c0.unwrap_or(0.0), cost.c1, c2),
+            None => CostModel::linear(cost.c0.unwrap_or(0.0), cost.c1),
+        };
+        targets.push((node, model));
+    }
+
+    for (node, model) in &targets {
+        if let Node::Gen(gen) = &mut network.graph[*node] {
+            gen.cost_model = model.clone();
+        }
+    }
+    Ok(targets.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{Branch, BranchId, Bus, BusId, Gen, GenId};
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn two_bus() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=2)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("bus{}", i),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        network.graph.add_edge(
+            buses[0],
+            buses[1],
+            Edge::Branch(
+                Branch::new(
+                    BranchId::new(1),
+                    "line1_2".to_string(),
+                    BusId::new(1),
+                    BusId::new(2),
+                    0.01,
+                    0.1,
+                )
+                .with_s_max(Some(100.0)),
+            ),
+        );
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "gen1".to_string(), BusId::new(1))
+                .with_cost(CostModel::linear(0.0, 10.0)),
+        ));
+        network
+    }
+
+    #[test]
+    fn test_overrides_update_branch_rating_and_generator_cost() {
+        let dir = tempdir().unwrap();
+        let limits = dir.path().join("limits.csv");
+        let costs = dir.path().join("costs.csv");
+        fs::write(
+            &limits,
+            "element,id,rating_mva,vmin_pu,vmax_pu\nbranch,1,250,,\nbus,2,,0.95,1.05\n",
+        )
+        .unwrap();
+        fs::write(&costs, "gen_id,c0,c1,c2\n1,5,30,0.01\n").unwrap();
+
+        let mut network = two_bus();
+        assert_eq!(apply_limits_csv(&mut network, &limits).unwrap(), 2);
+        assert_eq!(apply_costs_csv(&mut network, &costs).unwrap(), 1);
+
+        let branch = network
+            .graph
+            .edge_weights()
+            .find_map(|edge| match edge {
+                Edge::Branch(branch) => Some(branch),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(branch.rating_a, Some(MegavoltAmperes(250.0)));
+        assert_eq!(branch.s_max, Some(MegavoltAmperes(250.0)));
+
+        for node in network.graph.node_weights() {
+            match node {
+                Node::Gen(gen) => assert!(matches!(
+                    &gen.cost_model,
+                    CostModel::Polynomial(c) if c == &[5.0, 30.0, 0.01]
+                )),
+                Node::Bus(bus) if bus.id.value() == 2 => {
+                    assert_eq!(bus.vmin_pu, Some(PerUnit(0.95)));
+                    assert_eq!(bus.vmax_pu, Some(PerUnit(1.05)));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_ids_are_rejected_without_changes() {
+        let dir = tempdir().unwrap();
+        let limits = dir.path().join("limits.csv");
+        let costs = dir.path().join("costs.csv");
+        fs::write(
+            &limits,
+            "element,id,rating_mva,vmin_pu,vmax_pu\nbranch,1,250,,\nbranch,9,80,,\n",
+        )
+        .unwrap();
+        fs::write(&costs, "gen_id,c0,c1,c2\n7,0,30,\n").unwrap();
+
+        let mut network = two_bus();
+        let err = apply_limits_csv(&mut network, &limits).unwrap_err();
+        assert!(
+            err.to_string().contains("line 3: unknown branch 9"),
+            "{}",
+            err
+        );
+        let err = apply_costs_csv(&mut network, &costs).unwrap_err();
+        assert!(err.to_string().contains("unknown generator 7"), "{}", err);
+
+        // The valid first row was not applied either
+        let rated = network
+            .graph
+            .edge_weights()
+            .any(|edge| matches!(edge, Edge::Branch(branch) if branch.rating_a.is_some()));
+        assert!(!rated);
+    }
+}
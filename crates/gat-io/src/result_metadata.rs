@@ -0,0 +1,105 @@
+//! Result-type tagging for Parquet outputs.
+//!
+//! The tag keys, [`ResultMetadata`] and the footer helpers live in
+//! [`gat_schemas::result`] so solver crates can tag their outputs at write time
+//! without depending on gat-io; they are re-exported here for readers of result
+//! files. See that module for the key layout.
+
+pub use gat_schemas::result::{
+    read_result_metadata, read_result_type, result_types, write_result_metadata, ResultMetadata,
+    RESULT_SCHEMA_VERSION, RESULT_TYPE_KEY, SCHEMA_VERSION_KEY, SOLVER_KEY,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_algo::opf::{OpfMethod, OpfSolution};
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use polars::prelude::{DataFrame, NamedFrom, ParquetWriter, Series};
+    use std::fs::File;
+    use tempfile::TempDir;
+
+    fn opf_result(dir: &TempDir) -> std::path::PathBuf {
+        let mut solution = OpfSolution {
+            converged: true,
+            method_used: OpfMethod::DcOpf,
+            ..OpfSolution::default()
+        };
+        solution.generator_p.insert("Gen1".to_string(), 100.0);
+        solution.generator_p.insert("Gen2".to_string(), 150.0);
+        solution.generator_q.insert("Gen1".to_string(), 0.0);
+        solution.generator_q.insert("Gen2".to_string(), 0.0);
+
+        let path = dir.path().join("opf.parquet");
+        solution.to_parquet(&path).unwrap();
+        path
+    }
+
+    /// A two-row table written straight through Polars, without tags
+    fn untagged_result(dir: &TempDir) -> std::path::PathBuf {
+        let mut df = DataFrame::new(vec![
+            Series::new("generator", ["Gen1", "Gen2"]),
+            Series::new("p_mw", [100.0, 150.0]),
+        ])
+        .unwrap();
+        let path = dir.path().join("untagged.parquet");
+        let mut file = File::create(&path).unwrap();
+        ParquetWriter::new(&mut file).finish(&mut df).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_opf_writer_tags_at_write_time() {
+        let dir = TempDir::new().unwrap();
+        let path = opf_result(&dir);
+        assert_eq!(
+            read_result_metadata(&path).unwrap(),
+            Some(ResultMetadata::new(result_types::OPF).with_solver("dc-opf"))
+        );
+    }
+
+    #[test]
+    fn test_tag_and_read_result() {
+        let dir = TempDir::new().unwrap();
+        let path = untagged_result(&dir);
+
+        let tags = ResultMetadata::new(result_types::OPF).with_solver("dc-opf");
+        write_result_metadata(&path, &tags).unwrap();
+
+        assert_eq!(read_result_metadata(&path).unwrap(), Some(tags));
+        assert_eq!(
+            read_result_type(&path).unwrap().as_deref(),
+            Some(result_types::OPF)
+        );
+
+        // Table contents survive the rewrite
+        let file = File::open(&path).unwrap();
+        let reader = SerializedFileReader::new(file).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 2);
+    }
+
+    #[test]
+    fn test_retagging_replaces_previous_tags() {
+        let dir = TempDir::new().unwrap();
+        let path = opf_result(&dir);
+
+        write_result_metadata(&path, &ResultMetadata::new(result_types::PF)).unwrap();
+        write_result_metadata(
+            &path,
+            &ResultMetadata::new(result_types::OPF).with_solver("clarabel"),
+        )
+        .unwrap();
+
+        let meta = read_result_metadata(&path).unwrap().unwrap();
+        assert_eq!(meta.result_type, result_types::OPF);
+        assert_eq!(meta.solver.as_deref(), Some("clarabel"));
+        assert_eq!(meta.schema_version, RESULT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_untagged_file_has_no_result_type() {
+        let dir = TempDir::new().unwrap();
+        let path = untagged_result(&dir);
+        assert_eq!(read_result_type(&path).unwrap(), None);
+    }
+}
@@ -75,6 +75,7 @@ pub fn build_cigre_mv_network(config: &CigreMvConfig) -> Network {
             vmax_pu: Some(PerUnit(1.1)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         }));
         bus_nodes.insert(i, node_idx);
     }
@@ -121,6 +122,7 @@ pub fn build_cigre_mv_network(config: &CigreMvConfig) -> Network {
             bus: BusId::new(*bus_id),
             active_power: Megawatts(*p_mw * config.load_scale),
             reactive_power: Megavars(*q_mvar * config.load_scale),
+            zip: None,
         }));
     }
 
@@ -361,6 +361,7 @@ fn build_network_from_opfdata(
                     bus: BusId::new(bus_idx + 1),
                     active_power: gat_core::Megawatts(pd_pu * 100.0), // p.u. to MW
                     reactive_power: gat_core::Megavars(qd_pu * 100.0),
+                    zip: None,
                 }));
             }
         }
@@ -451,6 +451,7 @@ fn convert_pfdelta_to_network(data: &Value) -> Result<Network> {
                 bus: BusId::new(bus_id),
                 active_power: gat_core::Megawatts(pd),
                 reactive_power: gat_core::Megavars(qd),
+                zip: None,
             }));
         }
     }
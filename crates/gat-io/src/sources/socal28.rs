@@ -418,6 +418,7 @@ pub fn build_socal28_network(config: &SoCal28Config) -> Network {
             vmax_pu: Some(PerUnit(1.05)),
             area_id: Some(1),
             zone_id: Some(1),
+            bus_type: None,
         }));
         bus_nodes.insert(*bus_id, node_idx);
     }
@@ -516,6 +517,7 @@ pub fn build_socal28_network(config: &SoCal28Config) -> Network {
             bus: BusId::new(*bus_id),
             active_power: Megawatts(*p_mw * config.load_scale),
             reactive_power: Megavars(*q_mvar * config.load_scale),
+            zip: None,
         }));
     }
 
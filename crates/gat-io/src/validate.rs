@@ -1,14 +1,19 @@
 #![cfg(not(target_arch = "wasm32"))]
 
-use std::{fs::File, path::Path};
+use std::{collections::HashSet, fmt, fs::File, path::Path, str::FromStr};
 
 use anyhow::{anyhow, bail, Context, Result};
+use arrow_schema::{DataType as ArrowType, Field as ArrowField, Schema};
+use gat_core::{DiagnosticIssue, Diagnostics, Severity};
+use polars::io::ipc::IpcReader;
 #[cfg(feature = "parquet")]
 use polars::prelude::ParquetReader;
-use polars::prelude::{CsvReader, DataFrame, SerReader};
+use polars::prelude::{CsvReader, DataFrame, DataType, Field, SerReader};
 use serde::Deserialize;
 use serde_json;
 
+use crate::arrow_schema as grid_schema;
+
 #[derive(Deserialize)]
 struct DatasetSpec {
     dataset: String,
@@ -62,6 +67,284 @@ pub fn validate_dataset(spec_file: &str) -> Result<()> {
     Ok(())
 }
 
+/// Built-in dataset schemas checked by [`validate_arrow_schema`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaKind {
+    /// Normalized Arrow grid directory (`buses.arrow`, `branches.arrow`, ...)
+    /// as described in [`crate::arrow_schema`]
+    Grid,
+    /// Branch flow limits table: `branch_id`, `flow_limit`
+    BranchLimits,
+    /// Bus voltage table: `bus_id`, `vm_pu`, optional `va_deg`
+    BusVoltage,
+}
+
+impl FromStr for SchemaKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "grid" => Ok(Self::Grid),
+            "branch_limits" => Ok(Self::BranchLimits),
+            "bus_voltage" => Ok(Self::BusVoltage),
+            other => Err(anyhow!(
+                "unknown schema '{}'; use grid, branch_limits, or bus_voltage",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SchemaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Grid => "grid",
+            Self::BranchLimits => "branch_limits",
+            Self::BusVoltage => "bus_voltage",
+        })
+    }
+}
+
+/// Check a dataset against one of the built-in schemas.
+///
+/// Every problem found is reported rather than stopping at the first one:
+/// unreadable files and missing tables (category `io`), missing required
+/// columns and wrong dtypes (`schema`), duplicate ids and dangling bus
+/// references (`reference`), and out-of-range values (`value`). Missing
+/// nullable columns are not reported. Integer columns are accepted where
+/// floats are expected, since CSV inference reads `100` as an integer.
+///
+/// For [`SchemaKind::Grid`], `path` is the Arrow directory; otherwise it is a
+/// CSV or Parquet file.
+pub fn validate_arrow_schema(path: &Path, schema: SchemaKind) -> Diagnostics {
+    let mut diag = Diagnostics::new();
+    match schema {
+        SchemaKind::Grid => validate_grid(path, &mut diag),
+        SchemaKind::BranchLimits => match read_dataframe(path) {
+            Ok(df) => validate_branch_limits(&df, &mut diag),
+            Err(e) => diag.add_error("io", &format!("{:#}", e)),
+        },
+        SchemaKind::BusVoltage => match read_dataframe(path) {
+            Ok(df) => validate_bus_voltage(&df, &mut diag),
+            Err(e) => diag.add_error("io", &format!("{:#}", e)),
+        },
+    }
+    diag
+}
+
+fn validate_grid(dir: &Path, diag: &mut Diagnostics) {
+    let mut tables = Vec::new();
+    for &name in grid_schema::table_names() {
+        let path = dir.join(format!("{}.arrow", name));
+        let df = File::open(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|file| IpcReader::new(file).finish().map_err(Into::into));
+        match df {
+            Ok(df) => {
+                if let Some(schema) = grid_schema::schema_for_table(name) {
+                    check_columns(&df, &schema, name, diag);
+                }
+                tables.push((name, df));
+            }
+            Err(e) => diag.add(
+                DiagnosticIssue::new(Severity::Error, "io", format!("cannot read table: {:#}", e))
+                    .with_entity(name),
+            ),
+        }
+    }
+
+    let table = |name: &str| tables.iter().find(|(t, _)| *t == name).map(|(_, df)| df);
+    let Some(buses) = table("buses") else {
+        return;
+    };
+    let bus_ids = unique_ids(buses, "buses", "id", diag);
+    for (name, keys) in [
+        ("generators", &["bus"][..]),
+        ("loads", &["bus"][..]),
+        ("branches", &["from_bus", "to_bus"][..]),
+    ] {
+        let Some(df) = table(name) else {
+            continue;
+        };
+        unique_ids(df, name, "id", diag);
+        for &key in keys {
+            check_bus_references(df, name, key, &bus_ids, diag);
+        }
+    }
+}
+
+fn validate_branch_limits(df: &DataFrame, diag: &mut Diagnostics) {
+    let schema = Schema::new(vec![
+        ArrowField::new("branch_id", ArrowType::Int64, false),
+        ArrowField::new("flow_limit", ArrowType::Float64, false),
+    ]);
+    check_columns(df, &schema, "branch_limits", diag);
+    unique_ids(df, "branch_limits", "branch_id", diag);
+    for (row, limit) in float_values(df, "flow_limit").into_iter().enumerate() {
+        match limit {
+            Some(limit) if limit > 0.0 => {}
+            Some(limit) => diag.add(
+                DiagnosticIssue::new(
+                    Severity::Error,
+                    "value",
+                    format!("flow_limit must be positive, found {}", limit),
+                )
+                .with_entity(format!("branch_limits row {}", row)),
+            ),
+            None => diag.add(
+                DiagnosticIssue::new(Severity::Error, "value", "flow_limit is null")
+                    .with_entity(format!("branch_limits row {}", row)),
+            ),
+        }
+    }
+}
+
+/// Voltage magnitudes outside this band (p.u.) are flagged as suspicious
+const PLAUSIBLE_VM_PU: (f64, f64) = (0.5, 1.5);
+
+fn validate_bus_voltage(df: &DataFrame, diag: &mut Diagnostics) {
+    let schema = Schema::new(vec![
+        ArrowField::new("bus_id", ArrowType::Int64, false),
+        ArrowField::new("vm_pu", ArrowType::Float64, false),
+        ArrowField::new("va_deg", ArrowType::Float64, true),
+    ]);
+    check_columns(df, &schema, "bus_voltage", diag);
+    unique_ids(df, "bus_voltage", "bus_id", diag);
+    for (row, vm) in float_values(df, "vm_pu").into_iter().enumerate() {
+        if let Some(vm) = vm {
+            if vm < PLAUSIBLE_VM_PU.0 || vm > PLAUSIBLE_VM_PU.1 {
+                diag.add(
+                    DiagnosticIssue::new(
+                        Severity::Warning,
+                        "value",
+                        format!("vm_pu {} is outside the plausible range", vm),
+                    )
+                    .with_entity(format!("bus_voltage row {}", row)),
+                );
+            }
+        }
+    }
+}
+
+/// Report missing required columns and dtype mismatches against `schema`.
+fn check_columns(df: &DataFrame, schema: &Schema, table: &str, diag: &mut Diagnostics) {
+    for field in schema.fields() {
+        match df.schema().get_field(field.name()) {
+            None if !field.is_nullable() => diag.add(
+                DiagnosticIssue::new(
+                    Severity::Error,
+                    "schema",
+                    format!("missing required column '{}'", field.name()),
+                )
+                .with_entity(table),
+            ),
+            None => {}
+            Some(Field { dtype, .. }) if !dtype_compatible(field.data_type(), &dtype) => diag.add(
+                DiagnosticIssue::new(
+                    Severity::Error,
+                    "schema",
+                    format!(
+                        "column '{}' has dtype {}, expected {}",
+                        field.name(),
+                        dtype,
+                        field.data_type()
+                    ),
+                )
+                .with_entity(table),
+            ),
+            Some(_) => {}
+        }
+    }
+}
+
+fn dtype_compatible(expected: &ArrowType, actual: &DataType) -> bool {
+    match expected {
+        ArrowType::Int8
+        | ArrowType::Int16
+        | ArrowType::Int32
+        | ArrowType::Int64
+        | ArrowType::UInt8
+        | ArrowType::UInt16
+        | ArrowType::UInt32
+        | ArrowType::UInt64 => actual.is_integer(),
+        ArrowType::Float32 | ArrowType::Float64 => actual.is_numeric(),
+        ArrowType::Boolean => matches!(actual, DataType::Boolean),
+        // Dictionary-encoded strings are written as plain strings
+        ArrowType::Utf8 | ArrowType::LargeUtf8 | ArrowType::Dictionary(_, _) => {
+            matches!(actual, DataType::Utf8) || actual.to_string().starts_with("cat")
+        }
+        ArrowType::List(_) | ArrowType::LargeList(_) => matches!(actual, DataType::List(_)),
+        _ => true,
+    }
+}
+
+/// Ids in `column`, reporting duplicates. Empty if the column is unusable.
+fn unique_ids(df: &DataFrame, table: &str, column: &str, diag: &mut Diagnostics) -> HashSet<i64> {
+    let mut seen = HashSet::new();
+    for id in int_values(df, column).into_iter().flatten() {
+        if !seen.insert(id) {
+            diag.add(
+                DiagnosticIssue::new(
+                    Severity::Error,
+                    "reference",
+                    format!("duplicate {} {}", column, id),
+                )
+                .with_entity(table),
+            );
+        }
+    }
+    seen
+}
+
+fn check_bus_references(
+    df: &DataFrame,
+    table: &str,
+    column: &str,
+    bus_ids: &HashSet<i64>,
+    diag: &mut Diagnostics,
+) {
+    let ids = int_values(df, "id");
+    for (row, bus) in int_values(df, column).into_iter().enumerate() {
+        let Some(bus) = bus else {
+            continue;
+        };
+        if !bus_ids.contains(&bus) {
+            let element = match ids.get(row).copied().flatten() {
+                Some(id) => format!("{} {}", table, id),
+                None => format!("{} row {}", table, row),
+            };
+            diag.add(
+                DiagnosticIssue::new(
+                    Severity::Error,
+                    "reference",
+                    format!("{} references missing bus {}", column, bus),
+                )
+                .with_entity(element),
+            );
+        }
+    }
+}
+
+/// Column values as i64; empty when absent or not integer (already reported).
+fn int_values(df: &DataFrame, column: &str) -> Vec<Option<i64>> {
+    df.column(column)
+        .ok()
+        .filter(|series| series.dtype().is_integer())
+        .and_then(|series| series.cast(&DataType::Int64).ok())
+        .and_then(|series| series.i64().ok().map(|ca| ca.into_iter().collect()))
+        .unwrap_or_default()
+}
+
+/// Column values as f64; empty when absent or not numeric (already reported).
+fn float_values(df: &DataFrame, column: &str) -> Vec<Option<f64>> {
+    df.column(column)
+        .ok()
+        .filter(|series| series.dtype().is_numeric())
+        .and_then(|series| series.cast(&DataType::Float64).ok())
+        .and_then(|series| series.f64().ok().map(|ca| ca.into_iter().collect()))
+        .unwrap_or_default()
+}
+
 fn read_dataframe(path: &Path) -> Result<DataFrame> {
     let extension = path
         .extension()
@@ -159,3 +442,144 @@ mod tests {
         assert!(validate_dataset(spec_path.to_str().unwrap()).is_err());
     }
 }
+
+#[cfg(test)]
+mod schema_tests {
+    use super::*;
+    use crate::exporters::write_network_to_arrow_directory;
+    use gat_core::{
+        Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Megavars, Megawatts, Network,
+        Node,
+    };
+    use polars::io::ipc::IpcWriter;
+    use polars::prelude::SerWriter;
+    use tempfile::tempdir;
+
+    fn two_bus_network() -> Network {
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "bus1".to_string(),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "bus2".to_string(),
+            ..Bus::default()
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "line1_2".to_string(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        network.graph.add_node(Node::Gen(Gen::new(
+            GenId::new(1),
+            "gen1".to_string(),
+            BusId::new(1),
+        )));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load2".to_string(),
+            bus: BusId::new(2),
+            active_power: Megawatts(50.0),
+            reactive_power: Megavars(10.0),
+            zip: None,
+        }));
+        network
+    }
+
+    #[test]
+    fn valid_grid_has_no_issues() {
+        let dir = tempdir().unwrap();
+        let grid = dir.path().join("grid");
+        write_network_to_arrow_directory(&two_bus_network(), &grid).unwrap();
+
+        let diag = validate_arrow_schema(&grid, SchemaKind::Grid);
+        assert!(!diag.has_issues(), "{}", diag.summary());
+    }
+
+    #[test]
+    fn grid_missing_required_column_is_reported() {
+        let dir = tempdir().unwrap();
+        let grid = dir.path().join("grid");
+        write_network_to_arrow_directory(&two_bus_network(), &grid).unwrap();
+
+        let branches_path = grid.join("branches.arrow");
+        let branches = IpcReader::new(File::open(&branches_path).unwrap())
+            .finish()
+            .unwrap();
+        let mut branches = branches.drop("reactance_pu").unwrap();
+        IpcWriter::new(&mut File::create(&branches_path).unwrap())
+            .finish(&mut branches)
+            .unwrap();
+
+        let diag = validate_arrow_schema(&grid, SchemaKind::Grid);
+        let errors: Vec<_> = diag.errors().collect();
+        assert_eq!(errors.len(), 1, "{}", diag.summary());
+        assert_eq!(errors[0].category, "schema");
+        assert!(errors[0].message.contains("reactance_pu"));
+        assert_eq!(errors[0].entity.as_deref(), Some("branches"));
+    }
+
+    #[test]
+    fn dangling_branch_bus_is_reported() {
+        let dir = tempdir().unwrap();
+        let grid = dir.path().join("grid");
+        write_network_to_arrow_directory(&two_bus_network(), &grid).unwrap();
+
+        let buses_path = grid.join("buses.arrow");
+        let buses = IpcReader::new(File::open(&buses_path).unwrap())
+            .finish()
+            .unwrap();
+        // Keep only bus 1, so the branch and load point at a missing bus 2
+        let mut buses = buses.head(Some(1));
+        IpcWriter::new(&mut File::create(&buses_path).unwrap())
+            .finish(&mut buses)
+            .unwrap();
+
+        let diag = validate_arrow_schema(&grid, SchemaKind::Grid);
+        let references: Vec<_> = diag
+            .errors()
+            .filter(|issue| issue.category == "reference")
+            .collect();
+        assert_eq!(references.len(), 2, "{}", diag.summary());
+        assert!(references
+            .iter()
+            .any(|issue| issue.entity.as_deref() == Some("branches 1")));
+    }
+
+    #[test]
+    fn branch_limits_flags_bad_rows() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("limits.csv");
+        std::fs::write(&path, "branch_id,flow_limit\n1,100\n1,-5.0\n").unwrap();
+
+        let diag = validate_arrow_schema(&path, SchemaKind::BranchLimits);
+        assert_eq!(diag.error_count(), 2, "{}", diag.summary());
+        assert!(diag
+            .errors()
+            .any(|issue| issue.message.contains("duplicate")));
+        assert!(diag
+            .errors()
+            .any(|issue| issue.message.contains("positive")));
+    }
+
+    #[test]
+    fn schema_kind_parses_cli_names() {
+        for kind in [
+            SchemaKind::Grid,
+            SchemaKind::BranchLimits,
+            SchemaKind::BusVoltage,
+        ] {
+            assert_eq!(kind.to_string().parse::<SchemaKind>().unwrap(), kind);
+        }
+        assert!("voltages".parse::<SchemaKind>().is_err());
+    }
+}
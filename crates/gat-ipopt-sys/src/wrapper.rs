@@ -31,7 +31,7 @@
 use crate::{
     AddIpoptIntOption, AddIpoptNumOption, AddIpoptStrOption, ApplicationReturnStatus,
     CreateIpoptProblem, FreeIpoptProblem, Index, IpoptProblem, IpoptSolve, Number,
-    SetIntermediateCallback, UserDataPtr,
+    SetIntermediateCallback, SetIpoptProblemScaling, UserDataPtr,
 };
 use std::cell::Cell;
 use std::ffi::CString;
@@ -399,6 +399,29 @@ impl<P: ConstrainedProblem> Ipopt<P> {
         }
     }
 
+    /// Set user scaling factors for the objective, variables and constraints.
+    ///
+    /// `None` leaves the variables or constraints unscaled. IPOPT only uses
+    /// these factors with `nlp_scaling_method = user-scaling`.
+    ///
+    /// # Returns
+    /// False if a scaling slice has the wrong length or IPOPT rejects it.
+    pub fn set_problem_scaling(
+        &mut self,
+        obj_scaling: Number,
+        x_scaling: Option<&[Number]>,
+        g_scaling: Option<&[Number]>,
+    ) -> bool {
+        if x_scaling.is_some_and(|x| x.len() != self.n)
+            || g_scaling.is_some_and(|g| g.len() != self.m)
+        {
+            return false;
+        }
+        let x_ptr = x_scaling.map_or(std::ptr::null(), |x| x.as_ptr());
+        let g_ptr = g_scaling.map_or(std::ptr::null(), |g| g.as_ptr());
+        unsafe { SetIpoptProblemScaling(self.problem, obj_scaling, x_ptr, g_ptr) != 0 }
+    }
+
     /// Solve the optimization problem.
     ///
     /// # Returns
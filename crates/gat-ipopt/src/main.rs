@@ -183,6 +183,7 @@ fn solve_with_ipopt(problem: &ProblemBatch) -> Result<SolutionBatch> {
             vmax_pu: Some(gat_core::PerUnit(problem.bus_v_max[i])),
             area_id: None,
             zone_id: None,
+            bus_type: None,
         }));
     }
 
@@ -322,6 +323,7 @@ fn solve_with_ipopt(problem: &ProblemBatch) -> Result<SolutionBatch> {
             cost_coeffs,
             cost_model,
             capability_curve: Vec::new(),
+            reactive_cost: 0.0,
         });
     }
 
@@ -381,9 +383,13 @@ fn solve_with_ipopt(problem: &ProblemBatch) -> Result<SolutionBatch> {
         theta_offset: n_bus,
         pg_offset: 2 * n_bus,
         qg_offset: 2 * n_bus + n_gen,
+        tap_offset: n_var,
         gen_bus_idx,
         branches,
         n_branch,
+        objective: gat_algo::AcObjective::MinCost,
+        tap_controls: Vec::new(),
+        soft_voltage_weight: None,
     };
 
     info!(
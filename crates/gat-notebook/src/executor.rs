@@ -0,0 +1,421 @@
+//! Execute the command cells embedded in a demo notebook.
+//!
+//! Demo notebooks are plain markdown. Every fenced `bash`/`sh`/`shell` block (or an
+//! untagged fence) is scanned for lines that invoke the configured program — `gat` by
+//! default — and each such line becomes a cell. Cells run in order through the
+//! platform shell, and their exit status and captured output are written to a JSON
+//! results file next to the notebook.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::history::record_run;
+
+/// Fence languages treated as runnable shell snippets.
+const SHELL_FENCES: &[&str] = &["", "bash", "sh", "shell", "console"];
+
+/// Options controlling how a notebook is executed.
+#[derive(Debug, Clone)]
+pub struct ExecuteOptions {
+    /// Program whose invocations are picked up as cells (`gat` by default).
+    pub program: String,
+    /// List the cells without running them.
+    pub dry_run: bool,
+    /// Directory commands run in; defaults to the current directory.
+    pub working_dir: Option<PathBuf>,
+    /// Where to write the results file; defaults to `<notebook>.results.json`.
+    pub results_path: Option<PathBuf>,
+    /// Workspace whose `context/history.jsonl` executed cells are appended to.
+    pub history_workspace: Option<PathBuf>,
+}
+
+impl Default for ExecuteOptions {
+    fn default() -> Self {
+        Self {
+            program: "gat".to_string(),
+            dry_run: false,
+            working_dir: None,
+            results_path: None,
+            history_workspace: None,
+        }
+    }
+}
+
+/// Outcome of a single notebook cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CellStatus {
+    /// Listed by a dry run, not executed.
+    Planned,
+    Succeeded,
+    Failed,
+    /// Not executed because an earlier cell failed.
+    Skipped,
+}
+
+/// A command cell and, once executed, what it produced.
+#[derive(Debug, Clone, Serialize)]
+pub struct CellResult {
+    pub index: usize,
+    /// 1-based line of the command in the notebook source.
+    pub line: usize,
+    pub command: String,
+    pub status: CellStatus,
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Results of executing (or dry-running) a notebook.
+#[derive(Debug, Clone, Serialize)]
+pub struct NotebookRun {
+    pub notebook: PathBuf,
+    pub results_path: PathBuf,
+    pub dry_run: bool,
+    pub cells: Vec<CellResult>,
+}
+
+impl NotebookRun {
+    /// True when no cell failed or was skipped.
+    pub fn succeeded(&self) -> bool {
+        self.cells
+            .iter()
+            .all(|cell| matches!(cell.status, CellStatus::Planned | CellStatus::Succeeded))
+    }
+}
+
+/// A command found in the notebook source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotebookCommand {
+    /// 1-based line where the command starts.
+    pub line: usize,
+    pub command: String,
+}
+
+/// Extract the commands invoking `program` from fenced shell blocks in `markdown`.
+///
+/// Comment lines are ignored and trailing-backslash continuations are joined into a
+/// single command.
+pub fn extract_commands(markdown: &str, program: &str) -> Vec<NotebookCommand> {
+    let mut commands = Vec::new();
+    let mut in_shell_fence = false;
+    let mut in_other_fence = false;
+    let mut pending: Option<NotebookCommand> = None;
+
+    for (idx, raw) in markdown.lines().enumerate() {
+        let line = raw.trim();
+
+        if let Some(lang) = line.strip_prefix("```") {
+            if in_shell_fence || in_other_fence {
+                in_shell_fence = false;
+                in_other_fence = false;
+                commands.extend(pending.take());
+            } else if SHELL_FENCES.contains(&lang.trim()) {
+                in_shell_fence = true;
+            } else {
+                in_other_fence = true;
+            }
+            continue;
+        }
+        if !in_shell_fence {
+            continue;
+        }
+
+        if let Some(mut cmd) = pending.take() {
+            let (body, continues) = split_continuation(line);
+            cmd.command.push(' ');
+            cmd.command.push_str(body);
+            if continues {
+                pending = Some(cmd);
+            } else {
+                commands.push(cmd);
+            }
+            continue;
+        }
+
+        if line.starts_with('#') || !invokes(line, program) {
+            continue;
+        }
+        let (body, continues) = split_continuation(line);
+        let cmd = NotebookCommand {
+            line: idx + 1,
+            command: body.to_string(),
+        };
+        if continues {
+            pending = Some(cmd);
+        } else {
+            commands.push(cmd);
+        }
+    }
+
+    commands.extend(pending);
+    commands
+}
+
+/// Run the commands in the notebook at `path` and write a results file.
+///
+/// Execution stops at the first failing cell; the remaining cells are recorded as
+/// skipped. In dry-run mode every cell is recorded as planned and nothing is run.
+pub fn execute_notebook(path: &Path, options: &ExecuteOptions) -> Result<NotebookRun> {
+    let markdown = fs::read_to_string(path)
+        .with_context(|| format!("failed to read notebook at {}", path.display()))?;
+    let results_path = options
+        .results_path
+        .clone()
+        .unwrap_or_else(|| default_results_path(path));
+
+    let mut failed = false;
+    let mut cells = Vec::new();
+    for (index, cmd) in extract_commands(&markdown, &options.program)
+        .into_iter()
+        .enumerate()
+    {
+        let mut cell = CellResult {
+            index,
+            line: cmd.line,
+            command: cmd.command,
+            status: CellStatus::Planned,
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+        };
+        if failed {
+            cell.status = CellStatus::Skipped;
+        } else if !options.dry_run {
+            let output = shell_command(&cell.command, options.working_dir.as_deref())
+                .output()
+                .with_context(|| format!("failed to spawn `{}`", cell.command))?;
+            cell.exit_code = output.status.code();
+            cell.stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+            cell.stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+            cell.status = if output.status.success() {
+                CellStatus::Succeeded
+            } else {
+                failed = true;
+                CellStatus::Failed
+            };
+            if let Some(workspace) = &options.history_workspace {
+                record_run(
+                    workspace,
+                    &cell.command,
+                    cell.exit_code,
+                    output_argument(&cell.command).map(Path::new),
+                )?;
+            }
+        }
+        cells.push(cell);
+    }
+
+    let run = NotebookRun {
+        notebook: path.to_path_buf(),
+        results_path,
+        dry_run: options.dry_run,
+        cells,
+    };
+    let body = serde_json::to_string_pretty(&run)?;
+    fs::write(&run.results_path, body).with_context(|| {
+        format!(
+            "failed to write notebook results at {}",
+            run.results_path.display()
+        )
+    })?;
+
+    Ok(run)
+}
+
+fn default_results_path(notebook: &Path) -> PathBuf {
+    let mut name = notebook
+        .file_stem()
+        .map(|stem| stem.to_os_string())
+        .unwrap_or_default();
+    name.push(".results.json");
+    notebook.with_file_name(name)
+}
+
+fn invokes(line: &str, program: &str) -> bool {
+    line == program
+        || line
+            .strip_prefix(program)
+            .is_some_and(|rest| rest.starts_with(char::is_whitespace))
+}
+
+/// Value passed to `--out`/`-o`, the conventional output flag of `gat` commands.
+fn output_argument(command: &str) -> Option<&str> {
+    let mut args = command.split_whitespace();
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--out=") {
+            return Some(value);
+        }
+        if arg == "--out" || arg == "-o" {
+            return args.next();
+        }
+    }
+    None
+}
+
+fn split_continuation(line: &str) -> (&str, bool) {
+    match line.strip_suffix('\\') {
+        Some(body) => (body.trim_end(), true),
+        None => (line, false),
+    }
+}
+
+fn shell_command(command: &str, working_dir: Option<&Path>) -> Command {
+    #[cfg(target_os = "windows")]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    };
+
+    #[cfg(not(target_os = "windows"))]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+
+    if let Some(dir) = working_dir {
+        cmd.current_dir(dir);
+    }
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const NOTEBOOK: &str = r#"# Echo demo
+
+```bash
+# a comment that mentions echo
+echo hello notebook
+echo second \
+  cell
+```
+
+```rust
+echo not a shell cell
+```
+"#;
+
+    fn echo_options() -> ExecuteOptions {
+        ExecuteOptions {
+            program: "echo".to_string(),
+            ..ExecuteOptions::default()
+        }
+    }
+
+    #[test]
+    fn extract_commands_reads_shell_fences_only() {
+        let commands = extract_commands(NOTEBOOK, "echo");
+        assert_eq!(
+            commands,
+            vec![
+                NotebookCommand {
+                    line: 5,
+                    command: "echo hello notebook".to_string(),
+                },
+                NotebookCommand {
+                    line: 6,
+                    command: "echo second cell".to_string(),
+                },
+            ]
+        );
+        assert!(extract_commands(NOTEBOOK, "ech").is_empty());
+    }
+
+    #[test]
+    fn execute_notebook_captures_output() {
+        let dir = tempdir().unwrap();
+        let notebook = dir.path().join("echo.md");
+        fs::write(&notebook, NOTEBOOK).unwrap();
+
+        let run = execute_notebook(&notebook, &echo_options()).unwrap();
+        assert!(run.succeeded());
+        assert_eq!(run.cells.len(), 2);
+        assert_eq!(run.cells[0].status, CellStatus::Succeeded);
+        assert_eq!(run.cells[0].exit_code, Some(0));
+        assert_eq!(run.cells[0].stdout.trim(), "hello notebook");
+        assert_eq!(run.cells[1].stdout.trim(), "second cell");
+
+        assert_eq!(run.results_path, dir.path().join("echo.results.json"));
+        let results: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&run.results_path).unwrap()).unwrap();
+        assert_eq!(results["cells"][0]["status"], "succeeded");
+        assert_eq!(
+            results["cells"][1]["stdout"].as_str().unwrap().trim(),
+            "second cell"
+        );
+    }
+
+    #[test]
+    fn executed_cells_are_appended_to_history() {
+        let dir = tempdir().unwrap();
+        let notebook = dir.path().join("echo.md");
+        fs::write(&notebook, "```bash\necho pf --out runs/pf.parquet\n```\n").unwrap();
+
+        let options = ExecuteOptions {
+            history_workspace: Some(dir.path().to_path_buf()),
+            ..echo_options()
+        };
+        execute_notebook(&notebook, &options).unwrap();
+        execute_notebook(
+            &notebook,
+            &ExecuteOptions {
+                dry_run: true,
+                ..options
+            },
+        )
+        .unwrap();
+
+        let history = crate::load_history(dir.path()).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].command, "echo pf --out runs/pf.parquet");
+        assert_eq!(history[0].exit_code, Some(0));
+        assert_eq!(
+            history[0].output_path,
+            Some(PathBuf::from("runs/pf.parquet"))
+        );
+    }
+
+    #[test]
+    fn dry_run_lists_without_executing() {
+        let dir = tempdir().unwrap();
+        let notebook = dir.path().join("echo.md");
+        fs::write(&notebook, NOTEBOOK).unwrap();
+
+        let options = ExecuteOptions {
+            dry_run: true,
+            ..echo_options()
+        };
+        let run = execute_notebook(&notebook, &options).unwrap();
+        assert!(run.dry_run);
+        assert!(run
+            .cells
+            .iter()
+            .all(|cell| cell.status == CellStatus::Planned && cell.exit_code.is_none()));
+        assert!(run.cells.iter().all(|cell| cell.stdout.is_empty()));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn failing_cell_skips_the_rest() {
+        let dir = tempdir().unwrap();
+        let notebook = dir.path().join("fail.md");
+        fs::write(&notebook, "```sh\necho first; false\necho second\n```\n").unwrap();
+
+        let run = execute_notebook(&notebook, &echo_options()).unwrap();
+        assert!(!run.succeeded());
+        assert_eq!(run.cells[0].status, CellStatus::Failed);
+        assert_eq!(run.cells[0].exit_code, Some(1));
+        assert_eq!(run.cells[0].stdout.trim(), "first");
+        assert_eq!(run.cells[1].status, CellStatus::Skipped);
+        assert!(run.cells[1].stdout.is_empty());
+    }
+}
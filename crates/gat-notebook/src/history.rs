@@ -0,0 +1,135 @@
+//! Append-only run history stored at `context/history.jsonl` in a notebook workspace.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Location of the history log relative to the workspace root.
+const HISTORY_FILE: &str = "context/history.jsonl";
+
+/// One command executed from a notebook workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// Seconds since the Unix epoch when the run was recorded.
+    pub timestamp: u64,
+    pub command: String,
+    /// Exit code, or `None` if the process was terminated by a signal.
+    pub exit_code: Option<i32>,
+    /// Primary artifact written by the command, if known.
+    pub output_path: Option<PathBuf>,
+}
+
+/// Path of the history log for `workspace`.
+pub fn history_path(workspace: &Path) -> PathBuf {
+    workspace.join(HISTORY_FILE)
+}
+
+/// Append a run to the workspace history and return the stored record.
+pub fn record_run(
+    workspace: &Path,
+    command: &str,
+    exit_code: Option<i32>,
+    output_path: Option<&Path>,
+) -> Result<RunRecord> {
+    let record = RunRecord {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        command: command.to_string(),
+        exit_code,
+        output_path: output_path.map(Path::to_path_buf),
+    };
+
+    let path = history_path(workspace);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("failed to open run history at {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(&record)?)
+        .with_context(|| format!("failed to append to run history at {}", path.display()))?;
+
+    Ok(record)
+}
+
+/// Read the workspace history in the order runs were recorded.
+///
+/// A workspace without a history file has an empty history.
+pub fn load_history(workspace: &Path) -> Result<Vec<RunRecord>> {
+    let path = history_path(workspace);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read run history at {}", path.display()))?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line).with_context(|| {
+                format!(
+                    "malformed run history entry at {}:{}",
+                    path.display(),
+                    idx + 1
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn records_are_read_back_in_order() {
+        let dir = tempdir().unwrap();
+        let workspace = dir.path();
+        assert!(load_history(workspace).unwrap().is_empty());
+
+        let first = record_run(
+            workspace,
+            "gat pf dc datasets/ieee14.arrow --out runs/pf.parquet",
+            Some(0),
+            Some(Path::new("runs/pf.parquet")),
+        )
+        .unwrap();
+        let second = record_run(workspace, "gat validate --spec bad.yaml", Some(2), None).unwrap();
+
+        let history = load_history(workspace).unwrap();
+        assert_eq!(history, vec![first, second]);
+        assert_eq!(
+            history[0].output_path,
+            Some(PathBuf::from("runs/pf.parquet"))
+        );
+        assert_eq!(history[1].exit_code, Some(2));
+        assert!(history[0].timestamp <= history[1].timestamp);
+    }
+
+    #[test]
+    fn malformed_entries_are_reported() {
+        let dir = tempdir().unwrap();
+        let workspace = dir.path();
+        record_run(workspace, "gat --version", Some(0), None).unwrap();
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(history_path(workspace))
+            .unwrap();
+        writeln!(file, "not json").unwrap();
+
+        let err = load_history(workspace).unwrap_err();
+        assert!(format!("{err:#}").contains("history.jsonl:2"));
+    }
+}
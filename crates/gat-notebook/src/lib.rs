@@ -6,6 +6,15 @@ use anyhow::{Context, Result};
 use serde::Serialize;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+mod executor;
+mod history;
+
+pub use executor::{
+    execute_notebook, extract_commands, CellResult, CellStatus, ExecuteOptions, NotebookCommand,
+    NotebookRun,
+};
+pub use history::{history_path, load_history, record_run, RunRecord};
+
 /// Default localhost port used by the embedded notebook server stub.
 const DEFAULT_PORT: u16 = 8787;
 
@@ -83,7 +92,8 @@ struct QuickAction<'a> {
 /// Initialize a GAT-focused notebook environment inspired by the Twinsong workflow.
 ///
 /// The current implementation seeds a workspace with a manifest and helper README so that
-/// downstream tooling (or a real GUI server) can reuse the same layout.
+/// downstream tooling (or a real GUI server) can reuse the same layout. The seeded demos
+/// can be run with [`execute_notebook`].
 pub fn launch(options: NotebookOptions) -> Result<NotebookLaunch> {
     let workspace = normalize_workspace(&options.workspace)?;
     let manifest_path = workspace.join("notebook.manifest.json");
@@ -597,6 +607,9 @@ EOF
 duckdb "SELECT COUNT(*) AS branches, SUM(overload) AS total_violation FROM read_parquet('notebooks/runs/$RUN/pf.parquet')"
 ```
 
+Commands run through the notebook executor are also appended to `context/history.jsonl`
+with their exit code and output path, so the log above only needs the *why*.
+
 ## Follow-ups
 - [ ] Commit `context/experiment_log.md` as a checkpoint
 - [ ] Copy timings into `context/run_metadata.csv`
@@ -1,8 +1,9 @@
 use anyhow::{anyhow, Result};
-use gat_core::{Branch, Edge, Gen, Network, Node};
+use gat_core::{Branch, BusId, Edge, Gen, MegavoltAmperes, Network, Node};
 use petgraph::graph::EdgeIndex;
+use std::collections::HashMap;
 
-use crate::spec::{OutageSpec, ResolvedScenario};
+use crate::spec::{OutageSpec, ResolvedScenario, ScenarioSpec};
 
 /// Options for applying a scenario to a network topology.
 ///
@@ -27,8 +28,9 @@ impl Default for ScenarioApplyOptions {
 ///
 /// **Algorithm:**
 /// 1. Apply outages: remove branches or disable generators based on outage specs.
-/// 2. Scale loads: multiply all load P/Q by `scenario.load_scale`.
+/// 2. Scale loads: multiply all load P/Q by `scenario.load_scale` (and any zone multiplier).
 /// 3. Scale renewables: multiply all generator P/Q by `scenario.renewable_scale`.
+/// 4. Scale branch thermal ratings by `scenario.branch_limit_scale`.
 ///
 /// This implements the standard N-1/N-k contingency analysis pattern used in reliability
 /// assessment (see doi:10.1109/TPWRS.2007.899019 for DC contingency analysis).
@@ -72,31 +74,175 @@ pub fn apply_scenario_to_network(
         }
     }
 
-    // Step 3: Scale loads and renewable generation according to scenario multipliers
+    // Step 3: Scale loads, generation, and branch limits according to scenario multipliers
     // This models demand growth scenarios, renewable penetration scenarios, etc.
-    for node_idx in network.graph.node_indices() {
-        if let Some(node) = network.graph.node_weight_mut(node_idx) {
-            match node {
-                Node::Load(load) => {
-                    // Scale load by scenario's load_scale (e.g., 1.1 = 10% demand growth)
-                    load.active_power =
-                        gat_core::Megawatts(load.active_power.value() * scenario.load_scale);
-                    load.reactive_power =
-                        gat_core::Megavars(load.reactive_power.value() * scenario.load_scale);
+    scale_network(
+        network,
+        &Multipliers {
+            load: scenario.load_scale,
+            zone_load: &scenario.zone_load_scale,
+            generation: scenario.renewable_scale,
+            branch_limit: scenario.branch_limit_scale,
+        },
+    );
+    Ok(())
+}
+
+/// Apply a scenario spec to a copy of `network`, leaving the original untouched.
+///
+/// Unlike [`apply_scenario_to_network`], every outage must reference an element that
+/// exists in the network; an unknown branch or generator is an error rather than a
+/// silent no-op. Multipliers left unset in the spec default to 1.0:
+///
+/// - `load_scale`: global load multiplier (P and Q)
+/// - `zone_load_scale`: per-zone load multipliers, composed with the global one
+/// - `renewable_scale`: generation multiplier (P and Q of every generator)
+/// - `branch_limit_scale`: multiplier on branch thermal ratings
+///
+/// Outaged branches are removed from the graph; outaged generators are kept but
+/// switched off with zero output.
+pub fn apply(network: &Network, spec: &ScenarioSpec) -> Result<Network> {
+    let zone_load = spec.zone_load_scale.clone().unwrap_or_default();
+    let multipliers = Multipliers {
+        load: spec.load_scale.unwrap_or(1.0),
+        zone_load: &zone_load,
+        generation: spec.renewable_scale.unwrap_or(1.0),
+        branch_limit: spec.branch_limit_scale.unwrap_or(1.0),
+    };
+    multipliers.validate(&spec.scenario_id)?;
+
+    let mut result = network.clone();
+
+    let mut branch_edges = Vec::new();
+    for outage in &spec.outages {
+        match outage {
+            OutageSpec::Branch { id } => {
+                let matches = find_matching_branches(&result, id);
+                if matches.is_empty() {
+                    return Err(anyhow!(
+                        "scenario '{}': outage references unknown branch '{}'",
+                        spec.scenario_id,
+                        id
+                    ));
                 }
-                Node::Gen(gen) => {
-                    // Scale renewable generation by scenario's renewable_scale
-                    // Note: This applies to all generators; in v1 we may want per-generator scaling
-                    gen.active_power =
-                        gat_core::Megawatts(gen.active_power.value() * scenario.renewable_scale);
-                    gen.reactive_power =
-                        gat_core::Megavars(gen.reactive_power.value() * scenario.renewable_scale);
+                branch_edges.extend(matches);
+            }
+            OutageSpec::Gen { id } => {
+                if disable_generator(&mut result, id) == 0 {
+                    return Err(anyhow!(
+                        "scenario '{}': outage references unknown generator '{}'",
+                        spec.scenario_id,
+                        id
+                    ));
                 }
-                _ => {}
+            }
+            OutageSpec::Bus { id } => {
+                return Err(anyhow!(
+                    "bus outages are not supported yet ({}); consider modeling as branch/gen outages",
+                    id
+                ));
+            }
+        }
+    }
+    // Remove from the highest index down; petgraph swaps the last edge into a removed slot
+    branch_edges.sort_unstable();
+    branch_edges.dedup();
+    for edge in branch_edges.into_iter().rev() {
+        result.graph.remove_edge(edge);
+    }
+
+    scale_network(&mut result, &multipliers);
+    Ok(result)
+}
+
+/// Scenario scaling factors shared by [`apply`] and [`apply_scenario_to_network`].
+struct Multipliers<'a> {
+    load: f64,
+    zone_load: &'a HashMap<i64, f64>,
+    generation: f64,
+    branch_limit: f64,
+}
+
+impl Multipliers<'_> {
+    fn validate(&self, scenario_id: &str) -> Result<()> {
+        let named = [
+            ("load_scale", self.load),
+            ("renewable_scale", self.generation),
+            ("branch_limit_scale", self.branch_limit),
+        ];
+        for (name, value) in named {
+            if !value.is_finite() || value < 0.0 {
+                return Err(anyhow!(
+                    "scenario '{}': {} must be a non-negative number (got {})",
+                    scenario_id,
+                    name,
+                    value
+                ));
+            }
+        }
+        for (zone, value) in self.zone_load {
+            if !value.is_finite() || *value < 0.0 {
+                return Err(anyhow!(
+                    "scenario '{}': zone_load_scale for zone {} must be a non-negative number (got {})",
+                    scenario_id,
+                    zone,
+                    value
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn scale_network(network: &mut Network, multipliers: &Multipliers) {
+    // Resolve each load's zone through its bus
+    let bus_zones: HashMap<BusId, i64> = network
+        .graph
+        .node_weights()
+        .filter_map(|node| match node {
+            Node::Bus(bus) => bus.zone_id.map(|zone| (bus.id, zone)),
+            _ => None,
+        })
+        .collect();
+
+    for node in network.graph.node_weights_mut() {
+        match node {
+            Node::Load(load) => {
+                // Scale load by scenario's load_scale (e.g., 1.1 = 10% demand growth)
+                let zone_scale = bus_zones
+                    .get(&load.bus)
+                    .and_then(|zone| multipliers.zone_load.get(zone))
+                    .copied()
+                    .unwrap_or(1.0);
+                let scale = multipliers.load * zone_scale;
+                load.active_power = gat_core::Megawatts(load.active_power.value() * scale);
+                load.reactive_power = gat_core::Megavars(load.reactive_power.value() * scale);
+            }
+            Node::Gen(gen) => {
+                // Scale renewable generation by scenario's renewable_scale
+                // Note: This applies to all generators; in v1 we may want per-generator scaling
+                gen.active_power =
+                    gat_core::Megawatts(gen.active_power.value() * multipliers.generation);
+                gen.reactive_power =
+                    gat_core::Megavars(gen.reactive_power.value() * multipliers.generation);
+            }
+            _ => {}
+        }
+    }
+
+    if multipliers.branch_limit != 1.0 {
+        let scale = |rating: Option<MegavoltAmperes>| {
+            rating.map(|r| MegavoltAmperes(r.value() * multipliers.branch_limit))
+        };
+        for edge in network.graph.edge_weights_mut() {
+            if let Edge::Branch(branch) = edge {
+                branch.s_max = scale(branch.s_max);
+                branch.rating_a = scale(branch.rating_a);
+                branch.rating_b = scale(branch.rating_b);
+                branch.rating_c = scale(branch.rating_c);
             }
         }
     }
-    Ok(())
 }
 
 /// Find all branch edges matching the given identifier (by name or ID).
@@ -119,16 +265,20 @@ fn find_matching_branches(network: &Network, needle: &str) -> Vec<EdgeIndex> {
 /// Disable a generator by setting its active and reactive power to zero.
 ///
 /// **Purpose:** Models generator outages in contingency analysis. The generator remains in the
-/// network topology but produces no power.
-fn disable_generator(network: &mut Network, needle: &str) {
+/// network topology but produces no power. Returns the number of generators matched.
+fn disable_generator(network: &mut Network, needle: &str) -> usize {
+    let mut matched = 0;
     for node_idx in network.graph.node_indices() {
         if let Some(Node::Gen(gen)) = network.graph.node_weight_mut(node_idx) {
             if generator_matches(gen, needle) {
                 gen.active_power = gat_core::Megawatts(0.0);
                 gen.reactive_power = gat_core::Megavars(0.0);
+                gen.status = false;
+                matched += 1;
             }
         }
     }
+    matched
 }
 
 /// Check if a branch matches the given identifier (name or numeric ID).
@@ -165,3 +315,135 @@ impl EdgeBranchExt for Edge {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gat_core::{BranchId, Bus, GenId, Load, LoadId, Megavars, Megawatts};
+
+    /// Three buses in a line, zones 1/1/2, with a load on buses 2 and 3.
+    fn three_bus() -> Network {
+        let mut network = Network::new();
+        let buses: Vec<_> = (1..=3)
+            .map(|i| {
+                network.graph.add_node(Node::Bus(Bus {
+                    id: BusId::new(i),
+                    name: format!("Bus {}", i),
+                    zone_id: Some(if i < 3 { 1 } else { 2 }),
+                    ..Bus::default()
+                }))
+            })
+            .collect();
+        for (id, from, to) in [(1, 1, 2), (2, 2, 3)] {
+            network.graph.add_edge(
+                buses[from - 1],
+                buses[to - 1],
+                Edge::Branch(Branch {
+                    id: BranchId::new(id),
+                    name: format!("line_{}_{}", from, to),
+                    from_bus: BusId::new(from),
+                    to_bus: BusId::new(to),
+                    reactance: 0.1,
+                    rating_a: Some(MegavoltAmperes(100.0)),
+                    ..Branch::default()
+                }),
+            );
+        }
+        let mut gen = Gen::new(GenId::new(1), "gen_1".into(), BusId::new(1));
+        gen.active_power = Megawatts(150.0);
+        network.graph.add_node(Node::Gen(gen));
+        for (id, bus, mw) in [(1, 2, 60.0), (2, 3, 40.0)] {
+            network.graph.add_node(Node::Load(Load {
+                id: LoadId::new(id),
+                name: format!("load_{}", id),
+                bus: BusId::new(bus),
+                active_power: Megawatts(mw),
+                reactive_power: Megavars(mw / 4.0),
+                zip: None,
+            }));
+        }
+        network
+    }
+
+    fn spec(id: &str) -> ScenarioSpec {
+        ScenarioSpec {
+            scenario_id: id.into(),
+            description: None,
+            tags: None,
+            outages: Vec::new(),
+            dispatch_overrides: None,
+            load_scale: None,
+            zone_load_scale: None,
+            renewable_scale: None,
+            branch_limit_scale: None,
+            time_slices: None,
+            weight: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn apply_scales_load_and_removes_outaged_branch() {
+        let base = three_bus();
+        let mut scenario = spec("peak_n-1");
+        scenario.load_scale = Some(1.05);
+        scenario.outages = vec![OutageSpec::Branch {
+            id: "line_2_3".into(),
+        }];
+
+        let result = apply(&base, &scenario).unwrap();
+
+        assert!((result.total_load_mw() - 105.0).abs() < 1e-9);
+        let branches = result.branches();
+        assert_eq!(branches.len(), 1);
+        assert_eq!(branches[0].id, BranchId::new(1));
+
+        // The base network is untouched
+        assert!((base.total_load_mw() - 100.0).abs() < 1e-9);
+        assert_eq!(base.branches().len(), 2);
+    }
+
+    #[test]
+    fn apply_composes_zone_multipliers_and_scales_limits() {
+        let base = three_bus();
+        let mut scenario = spec("zone_2_heatwave");
+        scenario.load_scale = Some(1.1);
+        scenario.zone_load_scale = Some(HashMap::from([(2, 2.0)]));
+        scenario.branch_limit_scale = Some(0.8);
+        scenario.renewable_scale = Some(0.5);
+
+        let result = apply(&base, &scenario).unwrap();
+
+        // Zone 1: 60 × 1.1; zone 2: 40 × 1.1 × 2.0
+        assert!((result.total_load_mw() - (66.0 + 88.0)).abs() < 1e-9);
+        assert!((result.total_generation_mw() - 75.0).abs() < 1e-9);
+        for branch in result.branches() {
+            assert!((branch.rating_a.unwrap().value() - 80.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn apply_rejects_unknown_outage() {
+        let mut scenario = spec("bad");
+        scenario.outages = vec![OutageSpec::Branch {
+            id: "line_9_9".into(),
+        }];
+        let err = apply(&three_bus(), &scenario).unwrap_err();
+        assert!(err.to_string().contains("unknown branch 'line_9_9'"));
+
+        scenario.outages = vec![OutageSpec::Gen { id: "gen_7".into() }];
+        let err = apply(&three_bus(), &scenario).unwrap_err();
+        assert!(err.to_string().contains("unknown generator 'gen_7'"));
+    }
+
+    #[test]
+    fn apply_switches_off_outaged_generator() {
+        let mut scenario = spec("gen_out");
+        scenario.outages = vec![OutageSpec::Gen { id: "1".into() }];
+        let result = apply(&three_bus(), &scenario).unwrap();
+        let gens = result.generators();
+        assert_eq!(gens.len(), 1);
+        assert!(!gens[0].status);
+        assert_eq!(gens[0].active_power.value(), 0.0);
+    }
+}
@@ -1,16 +1,19 @@
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Utc};
+use gat_core::Network;
+use gat_io::arrow_manifest::ArrowManifest;
 use gat_io::importers;
 use serde::{Deserialize, Serialize};
 use serde_json;
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::fs::File;
 use std::path::Path;
 
 use crate::apply::apply_scenario_to_network;
-use crate::apply::ScenarioApplyOptions;
-use crate::spec::ResolvedScenario;
+use crate::apply::{apply, ScenarioApplyOptions};
+use crate::spec::{parse_time_slices, ResolvedScenario, ScenarioSpec};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScenarioArtifact {
@@ -25,6 +28,9 @@ pub struct ScenarioArtifact {
     pub tags: Vec<String>,
     #[serde(default)]
     pub metadata: HashMap<String, String>,
+    /// SHA256 over the grid's table checksums (see [`grid_checksum`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
 }
 
 /// Materialize scenarios by applying them to a base grid and saving per-scenario artifacts.
@@ -69,6 +75,7 @@ pub fn materialize_scenarios(
         let grid_path = scenario_dir.join("grid.arrow");
         let grid_path_str = grid_path.display().to_string();
         importers::export_network_to_arrow(&network, &grid_path_str)?;
+        let checksum = grid_checksum(&grid_path)?;
         artifacts.push(ScenarioArtifact {
             scenario_id: scenario.scenario_id.clone(),
             description: scenario.description.clone(),
@@ -79,6 +86,7 @@ pub fn materialize_scenarios(
             weight: scenario.weight,
             tags: scenario.tags.clone(),
             metadata: scenario.metadata.clone(),
+            checksum: Some(checksum),
         });
     }
     let manifest_path = out_dir.join("scenario_manifest.json");
@@ -86,6 +94,98 @@ pub fn materialize_scenarios(
     Ok(artifacts)
 }
 
+/// Materialize scenario specs against an in-memory base network.
+///
+/// Each spec is applied with [`apply`] (so unknown outage references are errors) and
+/// written to `out_dir/<scenario_id>/grid.arrow`. A `manifest.json` in `out_dir` lists
+/// the artifacts in spec order, in the same format `gat batch pf`/`opf` read via
+/// [`load_manifest`].
+///
+/// **Output structure:**
+/// ```text
+/// out_dir/
+///   manifest.json                   # ScenarioArtifact list, one per spec
+///   <scenario_id>/
+///     grid.arrow                    # Scenario-specific grid snapshot
+/// ```
+///
+/// Directory names depend only on the scenario ID, and each artifact carries a
+/// [`grid_checksum`] of its grid, so reruns are easy to compare.
+pub fn materialize(
+    base: &Network,
+    specs: &[ScenarioSpec],
+    out_dir: &Path,
+) -> Result<Vec<ScenarioArtifact>> {
+    let mut seen = HashSet::new();
+    for spec in specs {
+        if spec.scenario_id.trim().is_empty() {
+            return Err(anyhow!("scenario_id cannot be empty"));
+        }
+        if !seen.insert(sanitize_name(&spec.scenario_id)) {
+            return Err(anyhow!(
+                "duplicate scenario_id '{}' (after path sanitization)",
+                spec.scenario_id
+            ));
+        }
+    }
+
+    fs::create_dir_all(out_dir)
+        .with_context(|| format!("creating scenario output directory '{}'", out_dir.display()))?;
+    let mut artifacts = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let network = apply(base, spec)?;
+        let scenario_dir = out_dir.join(sanitize_name(&spec.scenario_id));
+        fs::create_dir_all(&scenario_dir)
+            .with_context(|| format!("creating scenario directory '{}'", scenario_dir.display()))?;
+        let grid_path = scenario_dir.join("grid.arrow");
+        let grid_path_str = grid_path.display().to_string();
+        importers::export_network_to_arrow(&network, &grid_path_str)?;
+        let time_slices = match &spec.time_slices {
+            Some(values) => parse_time_slices(values).with_context(|| {
+                format!("parsing time slices for scenario '{}'", spec.scenario_id)
+            })?,
+            None => Vec::new(),
+        };
+        artifacts.push(ScenarioArtifact {
+            scenario_id: spec.scenario_id.clone(),
+            description: spec.description.clone(),
+            grid_file: grid_path_str,
+            time_slices,
+            load_scale: spec.load_scale.unwrap_or(1.0),
+            renewable_scale: spec.renewable_scale.unwrap_or(1.0),
+            weight: spec.weight.unwrap_or(1.0),
+            tags: spec.tags.clone().unwrap_or_default(),
+            metadata: spec.metadata.clone().unwrap_or_default(),
+            checksum: Some(grid_checksum(&grid_path)?),
+        });
+    }
+    write_manifest(&out_dir.join("manifest.json"), &artifacts)?;
+    Ok(artifacts)
+}
+
+/// Content checksum of an Arrow grid directory.
+///
+/// Hashes the per-table SHA256 values recorded in the grid's own `manifest.json`,
+/// sorted by table name. The grid manifest's creation timestamp is excluded, so
+/// identical networks yield identical checksums across runs.
+pub fn grid_checksum(grid_dir: &Path) -> Result<String> {
+    let manifest_path = grid_dir.join("manifest.json");
+    let file = File::open(&manifest_path)
+        .with_context(|| format!("opening grid manifest '{}'", manifest_path.display()))?;
+    let manifest: ArrowManifest = serde_json::from_reader(file)
+        .with_context(|| format!("parsing grid manifest '{}'", manifest_path.display()))?;
+    let mut tables: Vec<_> = manifest.tables.iter().collect();
+    tables.sort_by(|a, b| a.0.cmp(b.0));
+    let mut hasher = Sha256::new();
+    for (name, info) in tables {
+        hasher.update(name.as_bytes());
+        hasher.update(b":");
+        hasher.update(info.sha256.as_bytes());
+        hasher.update(b"\n");
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 pub fn write_manifest(path: &Path, artifacts: &[ScenarioArtifact]) -> Result<()> {
     let file = fs::File::create(path)
         .with_context(|| format!("creating scenario manifest '{}'", path.display()))?;
@@ -132,6 +232,7 @@ mod tests {
             weight: 1.0,
             tags: vec!["foo".into()],
             metadata: HashMap::new(),
+            checksum: None,
         };
         let tmp = NamedTempFile::new().unwrap();
         write_manifest(tmp.path(), std::slice::from_ref(&artifact)).unwrap();
@@ -139,4 +240,93 @@ mod tests {
         let parsed: Vec<ScenarioArtifact> = serde_json::from_str(&text).unwrap();
         assert_eq!(parsed.first().unwrap().scenario_id, "test");
     }
+
+    fn two_bus() -> Network {
+        use gat_core::{Branch, BranchId, Bus, BusId, Edge, Gen, GenId, Load, LoadId, Node};
+        let mut network = Network::new();
+        let b1 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(1),
+            name: "Bus 1".into(),
+            ..Bus::default()
+        }));
+        let b2 = network.graph.add_node(Node::Bus(Bus {
+            id: BusId::new(2),
+            name: "Bus 2".into(),
+            ..Bus::default()
+        }));
+        network.graph.add_edge(
+            b1,
+            b2,
+            Edge::Branch(Branch::new(
+                BranchId::new(1),
+                "line_1_2".into(),
+                BusId::new(1),
+                BusId::new(2),
+                0.01,
+                0.1,
+            )),
+        );
+        network.graph.add_node(Node::Gen(
+            Gen::new(GenId::new(1), "gen_1".into(), BusId::new(1)).with_p_limits(0.0, 200.0),
+        ));
+        network.graph.add_node(Node::Load(Load {
+            id: LoadId::new(1),
+            name: "load_1".into(),
+            bus: BusId::new(2),
+            active_power: gat_core::Megawatts(100.0),
+            reactive_power: gat_core::Megavars(20.0),
+            zip: None,
+        }));
+        network
+    }
+
+    fn spec(id: &str, load_scale: f64) -> ScenarioSpec {
+        ScenarioSpec {
+            scenario_id: id.into(),
+            description: None,
+            tags: Some(vec!["test".into()]),
+            outages: Vec::new(),
+            dispatch_overrides: None,
+            load_scale: Some(load_scale),
+            zone_load_scale: None,
+            renewable_scale: None,
+            branch_limit_scale: None,
+            time_slices: Some(vec!["2025-01-01T00:00:00Z".into()]),
+            weight: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn materialize_writes_grids_and_manifest() {
+        let out = tempfile::tempdir().unwrap();
+        let specs = vec![spec("base", 1.0), spec("peak", 1.2)];
+        let artifacts = materialize(&two_bus(), &specs, out.path()).unwrap();
+        assert_eq!(artifacts.len(), 2);
+
+        let manifest = load_manifest(&out.path().join("manifest.json")).unwrap();
+        let ids: Vec<_> = manifest.iter().map(|a| a.scenario_id.as_str()).collect();
+        assert_eq!(ids, vec!["base", "peak"]);
+
+        for (artifact, expected_load) in manifest.iter().zip([100.0, 120.0]) {
+            let expected_path = out.path().join(&artifact.scenario_id).join("grid.arrow");
+            assert_eq!(artifact.grid_file, expected_path.display().to_string());
+            assert_eq!(artifact.time_slices.len(), 1);
+
+            let checksum = artifact.checksum.as_deref().unwrap();
+            assert_eq!(checksum.len(), 64);
+            assert_eq!(checksum, grid_checksum(&expected_path).unwrap());
+
+            let network = importers::load_grid_from_arrow(&artifact.grid_file).unwrap();
+            assert!((network.total_load_mw() - expected_load).abs() < 1e-9);
+        }
+        assert_ne!(manifest[0].checksum, manifest[1].checksum);
+    }
+
+    #[test]
+    fn materialize_rejects_duplicate_ids() {
+        let out = tempfile::tempdir().unwrap();
+        let specs = vec![spec("base", 1.0), spec("base", 1.1)];
+        assert!(materialize(&two_bus(), &specs, out.path()).is_err());
+    }
 }
@@ -21,6 +21,8 @@ pub struct ScenarioDefaults {
     pub load_scale: f64,
     #[serde(default = "default_scale")]
     pub renewable_scale: f64,
+    #[serde(default = "default_scale")]
+    pub branch_limit_scale: f64,
     #[serde(default)]
     pub time_slices: Vec<String>,
     #[serde(default = "default_weight")]
@@ -44,6 +46,7 @@ impl Default for ScenarioDefaults {
         Self {
             load_scale: default_scale(),
             renewable_scale: default_scale(),
+            branch_limit_scale: default_scale(),
             time_slices: Vec::new(),
             weight: default_weight(),
             tags: Vec::new(),
@@ -63,7 +66,13 @@ pub struct ScenarioSpec {
     #[serde(default)]
     pub dispatch_overrides: Option<Vec<DispatchOverrideSpec>>,
     pub load_scale: Option<f64>,
+    /// Per-zone load multipliers (zone ID → scale), applied on top of `load_scale`
+    #[serde(default)]
+    pub zone_load_scale: Option<HashMap<i64, f64>>,
     pub renewable_scale: Option<f64>,
+    /// Multiplier on branch thermal ratings (s_max, rate A/B/C)
+    #[serde(default)]
+    pub branch_limit_scale: Option<f64>,
     #[serde(default)]
     pub time_slices: Option<Vec<String>>,
     pub weight: Option<f64>,
@@ -96,7 +105,11 @@ pub struct ResolvedScenario {
     pub outages: Vec<OutageSpec>,
     pub dispatch_overrides: Vec<DispatchOverrideSpec>,
     pub load_scale: f64,
+    #[serde(default)]
+    pub zone_load_scale: HashMap<i64, f64>,
     pub renewable_scale: f64,
+    #[serde(default = "default_scale")]
+    pub branch_limit_scale: f64,
     pub time_slices: Vec<DateTime<Utc>>,
     pub weight: f64,
     pub metadata: HashMap<String, String>,
@@ -173,7 +186,11 @@ pub fn resolve_scenarios(set: &ScenarioSet) -> Result<Vec<ResolvedScenario>> {
             outages: scenario.outages.clone(),
             dispatch_overrides,
             load_scale: scenario.load_scale.unwrap_or(defaults.load_scale),
+            zone_load_scale: scenario.zone_load_scale.clone().unwrap_or_default(),
             renewable_scale: scenario.renewable_scale.unwrap_or(defaults.renewable_scale),
+            branch_limit_scale: scenario
+                .branch_limit_scale
+                .unwrap_or(defaults.branch_limit_scale),
             time_slices,
             weight: scenario.weight.unwrap_or(defaults.weight),
             metadata,
@@ -186,7 +203,7 @@ pub fn validate(set: &ScenarioSet) -> Result<()> {
     resolve_scenarios(set).map(|_| ())
 }
 
-fn parse_time_slices(values: &[String]) -> Result<Vec<DateTime<Utc>>> {
+pub(crate) fn parse_time_slices(values: &[String]) -> Result<Vec<DateTime<Utc>>> {
     let mut slices = Vec::with_capacity(values.len());
     for value in values {
         let parsed = DateTime::parse_from_rfc3339(value)
@@ -219,7 +236,9 @@ mod tests {
                 outages: Vec::new(),
                 dispatch_overrides: None,
                 load_scale: None,
+                zone_load_scale: None,
                 renewable_scale: None,
+                branch_limit_scale: None,
                 time_slices: None,
                 weight: None,
                 metadata: None,
@@ -248,7 +267,9 @@ mod tests {
             outages: Vec::new(),
             dispatch_overrides: None,
             load_scale: None,
+            zone_load_scale: None,
             renewable_scale: None,
+            branch_limit_scale: None,
             time_slices: None,
             weight: None,
             metadata: None,
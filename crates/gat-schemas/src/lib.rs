@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod result;
+
 /// Schema definition for bus/node data in power systems
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct BusSchema {
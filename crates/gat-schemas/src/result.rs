@@ -0,0 +1,199 @@
+//! Result-type tagging for Parquet outputs.
+//!
+//! Solver outputs from different modules (PF, OPF, contingency screening, ...) are
+//! plain Parquet tables with ad-hoc column sets. To let downstream consumers
+//! (DuckDB, notebooks, `gat batch`) tell them apart without sniffing columns, each
+//! result file carries three entries in the Parquet footer's key-value metadata:
+//!
+//! | Key                  | Example     | Meaning                               |
+//! |----------------------|-------------|---------------------------------------|
+//! | `gat_result_type`    | `opf`       | Kind of result (see [`result_types`]) |
+//! | `gat_schema_version` | `1.0.0`     | Column layout version for that kind   |
+//! | `solver`             | `dc-opf`    | Solver that produced the result       |
+//!
+//! The key names and [`ResultMetadata`] are always available. With the `parquet`
+//! feature, writers that build their own [`WriterProperties`] start from
+//! [`writer_properties`], and writers that go through a library without footer
+//! metadata support (Polars) call [`write_result_metadata`] afterwards, which
+//! rewrites the file with the tags attached. Consumers call [`read_result_type`]
+//! (or [`read_result_metadata`]) to branch.
+
+/// Metadata key holding the result type.
+pub const RESULT_TYPE_KEY: &str = "gat_result_type";
+/// Metadata key holding the schema version of the result table.
+pub const SCHEMA_VERSION_KEY: &str = "gat_schema_version";
+/// Metadata key holding the solver name.
+pub const SOLVER_KEY: &str = "solver";
+
+/// Current result schema version
+pub const RESULT_SCHEMA_VERSION: &str = "1.0.0";
+
+/// Well-known values for `gat_result_type`.
+pub mod result_types {
+    pub const PF: &str = "pf";
+    pub const OPF: &str = "opf";
+    pub const CONTINGENCY: &str = "contingency";
+    pub const STATE_ESTIMATION: &str = "state_estimation";
+    pub const RELIABILITY: &str = "reliability";
+    pub const PTDF: &str = "ptdf";
+    pub const LODF: &str = "lodf";
+}
+
+/// Result tags stored in a Parquet file's key-value metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultMetadata {
+    pub result_type: String,
+    pub schema_version: String,
+    pub solver: Option<String>,
+}
+
+impl ResultMetadata {
+    /// Tags for `result_type` at the current [`RESULT_SCHEMA_VERSION`].
+    pub fn new(result_type: impl Into<String>) -> Self {
+        Self {
+            result_type: result_type.into(),
+            schema_version: RESULT_SCHEMA_VERSION.to_string(),
+            solver: None,
+        }
+    }
+
+    /// Record the solver that produced the result.
+    pub fn with_solver(mut self, solver: impl Into<String>) -> Self {
+        self.solver = Some(solver.into());
+        self
+    }
+}
+
+#[cfg(feature = "parquet")]
+pub use self::parquet_io::*;
+
+#[cfg(feature = "parquet")]
+mod parquet_io {
+    use super::*;
+    use anyhow::{Context, Result};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::arrow::ArrowWriter;
+    use parquet::basic::{Compression, ZstdLevel};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::format::KeyValue;
+    use std::fs::{self, File};
+    use std::path::Path;
+
+    impl ResultMetadata {
+        /// The tags as footer key-value entries.
+        pub fn to_key_values(&self) -> Vec<KeyValue> {
+            let mut entries = vec![
+                key_value(RESULT_TYPE_KEY, &self.result_type),
+                key_value(SCHEMA_VERSION_KEY, &self.schema_version),
+            ];
+            if let Some(solver) = &self.solver {
+                entries.push(key_value(SOLVER_KEY, solver));
+            }
+            entries
+        }
+    }
+
+    /// A single footer key-value entry.
+    pub fn key_value(key: &str, value: impl ToString) -> KeyValue {
+        KeyValue::new(key.to_string(), value.to_string())
+    }
+
+    /// ZSTD writer properties carrying `tags` followed by any writer-specific `extra` entries.
+    pub fn writer_properties(tags: &ResultMetadata, extra: Vec<KeyValue>) -> WriterProperties {
+        let mut key_values = tags.to_key_values();
+        key_values.extend(extra);
+        WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .set_key_value_metadata(Some(key_values))
+            .build()
+    }
+
+    /// Attach result tags to an existing Parquet file, rewriting it in place.
+    ///
+    /// Existing key-value metadata is preserved except for the three tag keys,
+    /// which are replaced. The rewrite goes through a sibling temp file and a
+    /// rename, so a failure never leaves a truncated result behind.
+    pub fn write_result_metadata(path: &Path, metadata: &ResultMetadata) -> Result<()> {
+        let file = File::open(path)
+            .with_context(|| format!("opening Parquet file '{}'", path.display()))?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+            .with_context(|| format!("reading Parquet metadata from '{}'", path.display()))?;
+
+        // ArrowWriter re-embeds the Arrow schema itself; drop the stale copy
+        let extra: Vec<KeyValue> = builder
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .map(|kv| {
+                kv.iter()
+                    .filter(|entry| {
+                        !matches!(
+                            entry.key.as_str(),
+                            RESULT_TYPE_KEY | SCHEMA_VERSION_KEY | SOLVER_KEY | "ARROW:schema"
+                        )
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let schema = builder.schema().clone();
+        let reader = builder
+            .build()
+            .with_context(|| format!("reading Parquet file '{}'", path.display()))?;
+        let props = writer_properties(metadata, extra);
+
+        let tmp_path = path.with_extension("parquet.tmp");
+        let rewrite = || -> Result<()> {
+            let out = File::create(&tmp_path)
+                .with_context(|| format!("creating '{}'", tmp_path.display()))?;
+            let mut writer = ArrowWriter::try_new(out, schema, Some(props))
+                .context("creating Parquet writer")?;
+            for batch in reader {
+                writer
+                    .write(&batch.context("reading record batch")?)
+                    .context("writing record batch")?;
+            }
+            writer.close().context("finalizing Parquet file")?;
+            Ok(())
+        };
+        if let Err(err) = rewrite() {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err);
+        }
+        fs::rename(&tmp_path, path)
+            .with_context(|| format!("replacing '{}' with tagged copy", path.display()))?;
+        Ok(())
+    }
+
+    /// Read result tags from a Parquet file.
+    ///
+    /// Returns `Ok(None)` for files without a `gat_result_type` entry (untagged
+    /// or written by other tools).
+    pub fn read_result_metadata(path: &Path) -> Result<Option<ResultMetadata>> {
+        let file = File::open(path)
+            .with_context(|| format!("opening Parquet file '{}'", path.display()))?;
+        let reader = SerializedFileReader::new(file)
+            .with_context(|| format!("reading Parquet footer from '{}'", path.display()))?;
+        let Some(key_values) = reader.metadata().file_metadata().key_value_metadata() else {
+            return Ok(None);
+        };
+        let lookup = |key: &str| {
+            key_values
+                .iter()
+                .find(|entry| entry.key == key)
+                .and_then(|entry| entry.value.clone())
+        };
+        Ok(lookup(RESULT_TYPE_KEY).map(|result_type| ResultMetadata {
+            result_type,
+            schema_version: lookup(SCHEMA_VERSION_KEY).unwrap_or_default(),
+            solver: lookup(SOLVER_KEY),
+        }))
+    }
+
+    /// Read just the `gat_result_type` tag from a Parquet file.
+    pub fn read_result_type(path: &Path) -> Result<Option<String>> {
+        Ok(read_result_metadata(path)?.map(|meta| meta.result_type))
+    }
+}
@@ -47,12 +47,14 @@ pub mod error;
 pub mod ipc;
 pub mod plugin;
 pub mod problem;
+pub mod recommend;
 pub mod solution;
 pub mod subprocess;
 
 pub use error::{ExitCode, SolverError};
 pub use plugin::{run_solver_plugin, SolverPlugin};
 pub use problem::{ProblemBatch, ProblemType};
+pub use recommend::{recommend_solver, recommend_solver_from, LARGE_LP_BUSES};
 pub use solution::{SolutionBatch, SolutionStatus};
 pub use subprocess::SolverProcess;
 
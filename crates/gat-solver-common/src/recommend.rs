@@ -0,0 +1,137 @@
+//! Solver recommendation by problem class and size.
+//!
+//! [`recommend_solver`] picks a solver for a problem from the ones that can
+//! actually run: the pure-Rust solvers always, native plugins only when
+//! installed.
+//!
+//! | Problem | Recommendation |
+//! |---------|----------------|
+//! | LP, DC-OPF | HiGHS, then CLP, from [`LARGE_LP_BUSES`]; Clarabel otherwise |
+//! | SOCP | Clarabel |
+//! | AC-OPF | IPOPT, else L-BFGS |
+//! | MIP | HiGHS from [`LARGE_LP_BUSES`], else CBC, HiGHS, SYMPHONY |
+//! | MINLP | Bonmin, else Couenne |
+
+use crate::error::{SolverError, SolverResult};
+use crate::subprocess::list_installed_solvers;
+use crate::{ProblemType, PureRustSolver, SolverChoice, SolverId};
+
+/// Network size (buses) from which a native simplex solver is preferred to
+/// Clarabel's interior point for linear programs.
+pub const LARGE_LP_BUSES: usize = 1_000;
+
+impl SolverChoice {
+    /// Whether this solver can solve problems of the given class.
+    pub fn supports(&self, problem_type: ProblemType) -> bool {
+        use ProblemType::*;
+        match self {
+            SolverChoice::PureRust(PureRustSolver::Clarabel) => {
+                matches!(problem_type, Lp | DcOpf | Socp)
+            }
+            SolverChoice::PureRust(PureRustSolver::Lbfgs) => matches!(problem_type, AcOpf),
+            SolverChoice::Native(SolverId::Ipopt) => matches!(problem_type, AcOpf | Lp | DcOpf),
+            SolverChoice::Native(SolverId::Clp) => matches!(problem_type, Lp | DcOpf),
+            SolverChoice::Native(SolverId::Highs) => matches!(problem_type, Lp | DcOpf | Mip),
+            SolverChoice::Native(SolverId::Cbc | SolverId::Symphony) => {
+                matches!(problem_type, Lp | DcOpf | Mip)
+            }
+            SolverChoice::Native(SolverId::Bonmin | SolverId::Couenne) => {
+                matches!(problem_type, Minlp)
+            }
+        }
+    }
+}
+
+/// Recommend a solver for a problem of `num_buses` buses among the installed
+/// native plugins and the pure-Rust solvers.
+pub fn recommend_solver(problem_type: ProblemType, num_buses: usize) -> SolverResult<SolverChoice> {
+    recommend_solver_from(problem_type, num_buses, &list_installed_solvers())
+}
+
+/// [`recommend_solver`] with an explicit list of installed native solvers.
+///
+/// Fails with [`SolverError::NoSolverAvailable`] for integer problems when
+/// no suitable native solver is installed.
+pub fn recommend_solver_from(
+    problem_type: ProblemType,
+    num_buses: usize,
+    installed: &[SolverId],
+) -> SolverResult<SolverChoice> {
+    let first_installed = |candidates: &[SolverId]| {
+        candidates
+            .iter()
+            .find(|id| installed.contains(id))
+            .map(|&id| SolverChoice::Native(id))
+    };
+    let large = num_buses >= LARGE_LP_BUSES;
+
+    let choice = match problem_type {
+        ProblemType::Lp | ProblemType::DcOpf => {
+            let native = if large {
+                first_installed(&[SolverId::Highs, SolverId::Clp])
+            } else {
+                None
+            };
+            native.or(Some(SolverChoice::PureRust(PureRustSolver::Clarabel)))
+        }
+        ProblemType::Socp => Some(SolverChoice::PureRust(PureRustSolver::Clarabel)),
+        ProblemType::AcOpf => first_installed(&[SolverId::Ipopt])
+            .or(Some(SolverChoice::PureRust(PureRustSolver::Lbfgs))),
+        ProblemType::Mip => {
+            let order: &[SolverId] = if large {
+                &[SolverId::Highs, SolverId::Cbc, SolverId::Symphony]
+            } else {
+                &[SolverId::Cbc, SolverId::Highs, SolverId::Symphony]
+            };
+            first_installed(order)
+        }
+        ProblemType::Minlp => first_installed(&[SolverId::Bonmin, SolverId::Couenne]),
+    };
+
+    choice.ok_or_else(|| SolverError::NoSolverAvailable {
+        problem_type,
+        hint: match problem_type {
+            ProblemType::Minlp => "Install Bonmin or Couenne: `gat install bonmin`".to_string(),
+            _ => "Install CBC or HiGHS: `gat install cbc`".to_string(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recommendation_matches_problem_class() {
+        let all = SolverId::all();
+
+        // Conic problems need Clarabel even when every native plugin is installed
+        let socp = recommend_solver_from(ProblemType::Socp, 10_000, all).unwrap();
+        assert_eq!(socp, SolverChoice::PureRust(PureRustSolver::Clarabel));
+        assert!(socp.supports(ProblemType::Socp));
+
+        let large_lp = recommend_solver_from(ProblemType::Lp, 10_000, all).unwrap();
+        assert_eq!(large_lp, SolverChoice::Native(SolverId::Highs));
+        assert!(large_lp.supports(ProblemType::Lp));
+        assert!(!large_lp.supports(ProblemType::Socp));
+
+        // Small LPs, or nothing installed, stay in process
+        for (buses, installed) in [(100, all), (10_000, &[][..])] {
+            let lp = recommend_solver_from(ProblemType::DcOpf, buses, installed).unwrap();
+            assert_eq!(lp, SolverChoice::PureRust(PureRustSolver::Clarabel));
+        }
+
+        assert_eq!(
+            recommend_solver_from(ProblemType::AcOpf, 14, &[SolverId::Ipopt]).unwrap(),
+            SolverChoice::Native(SolverId::Ipopt)
+        );
+        assert_eq!(
+            recommend_solver_from(ProblemType::AcOpf, 14, &[]).unwrap(),
+            SolverChoice::PureRust(PureRustSolver::Lbfgs)
+        );
+        assert!(matches!(
+            recommend_solver_from(ProblemType::Mip, 14, &[SolverId::Ipopt]),
+            Err(SolverError::NoSolverAvailable { .. })
+        ));
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashMap},
     ffi::OsStr,
     fs::{self, File},
     path::{Path, PathBuf},
@@ -7,11 +7,19 @@ use std::{
 
 use anyhow::{anyhow, bail, Context, Result};
 use polars::datatypes::IdxSize;
+use polars::export::num::{Bounded, NumCast, ToPrimitive, Zero};
+use polars::frame::group_by::aggregations::TakeExtremum;
 use polars::frame::group_by::GroupsIndicator;
 use polars::prelude::*;
 #[cfg(feature = "parquet")]
 use polars::prelude::{ParquetReader, ParquetWriter};
 
+/// Resample `value_column` into fixed-width time buckets.
+///
+/// With `chunk_rows`, Parquet input is read in batches of about that many
+/// rows and bucket statistics accumulate across batches, so memory stays
+/// bounded by the number of buckets rather than the file size. Both modes
+/// fold rows in file order and produce identical output.
 pub fn resample_timeseries(
     input_path: &str,
     timestamp_column: &str,
@@ -19,19 +27,8 @@ pub fn resample_timeseries(
     rule: &str,
     output_path: &str,
     partitions: &[String],
+    chunk_rows: Option<usize>,
 ) -> Result<()> {
-    let df = read_frame(input_path)?;
-    let timestamp_series = df
-        .column(timestamp_column)?
-        .cast(&DataType::Int64)
-        .context("casting timestamp column to Int64")?;
-    let value_series = df
-        .column(value_column)?
-        .cast(&DataType::Float64)
-        .context("casting value column to Float64")?;
-
-    let timestamps = timestamp_series.i64()?;
-    let values = value_series.f64()?;
     let period = parse_rule(rule)?;
 
     if period <= 0 {
@@ -39,16 +36,35 @@ pub fn resample_timeseries(
     }
 
     let mut buckets: BTreeMap<i64, BucketStats> = BTreeMap::new();
-    for (ts_opt, val_opt) in timestamps.into_iter().zip(values.into_iter()) {
-        if let (Some(ts), Some(value)) = (ts_opt, val_opt) {
-            let bucket = floor_bucket(ts, period);
-            let entry = buckets.entry(bucket).or_default();
-            entry.count += 1;
-            entry.sum += value;
-            entry.min = entry.min.min(value);
-            entry.max = entry.max.max(value);
-        }
-    }
+    for_each_chunk(
+        input_path,
+        &[timestamp_column, value_column],
+        chunk_rows,
+        |df| {
+            let timestamp_series = df
+                .column(timestamp_column)?
+                .cast(&DataType::Int64)
+                .context("casting timestamp column to Int64")?;
+            let value_series = df
+                .column(value_column)?
+                .cast(&DataType::Float64)
+                .context("casting value column to Float64")?;
+
+            let timestamps = timestamp_series.i64()?;
+            let values = value_series.f64()?;
+            for (ts_opt, val_opt) in timestamps.into_iter().zip(values.into_iter()) {
+                if let (Some(ts), Some(value)) = (ts_opt, val_opt) {
+                    let bucket = floor_bucket(ts, period);
+                    let entry = buckets.entry(bucket).or_default();
+                    entry.count += 1;
+                    entry.sum += value;
+                    entry.min = entry.min.min(value);
+                    entry.max = entry.max.max(value);
+                }
+            }
+            Ok(())
+        },
+    )?;
 
     let mut bucket_start = Vec::with_capacity(buckets.len());
     let mut means = Vec::with_capacity(buckets.len());
@@ -76,15 +92,45 @@ pub fn resample_timeseries(
     Ok(())
 }
 
+/// Outer join two frames on the exact key column `on`.
+///
+/// The key appears once in the output, holding the key from whichever side
+/// had the row. Non-key columns present in both frames are renamed to
+/// `<name><left_suffix>` and `<name><right_suffix>` (for example
+/// `value_left` / `value_right`) before joining, with a warning listing them;
+/// all other columns keep their names.
 pub fn join_timeseries(
     left_path: &str,
     right_path: &str,
     on: &str,
+    left_suffix: &str,
+    right_suffix: &str,
     output_path: &str,
     partitions: &[String],
 ) -> Result<()> {
-    let left_df = read_frame(left_path)?;
-    let right_df = read_frame(right_path)?;
+    if left_suffix == right_suffix {
+        bail!("left and right suffixes must differ");
+    }
+    let mut left_df = read_frame(left_path)?;
+    let mut right_df = read_frame(right_path)?;
+
+    let collisions: Vec<String> = left_df
+        .get_column_names()
+        .into_iter()
+        .filter(|name| *name != on && right_df.get_column_names().contains(name))
+        .map(str::to_string)
+        .collect();
+    if !collisions.is_empty() {
+        eprintln!(
+            "warning: columns {:?} appear in both inputs; renaming with suffixes '{}' and '{}'",
+            collisions, left_suffix, right_suffix
+        );
+    }
+    for name in &collisions {
+        left_df.rename(name, &format!("{name}{left_suffix}"))?;
+        right_df.rename(name, &format!("{name}{right_suffix}"))?;
+    }
+
     let joined = left_df
         .outer_join(&right_df, &[on], &[on])
         .context("joining time series")?;
@@ -94,46 +140,474 @@ pub fn join_timeseries(
     Ok(())
 }
 
+/// As-of join: match each left row to the latest right row at or before it.
+///
+/// Both `on` columns are read as Int64 timestamps. A left row at `t` takes the
+/// right row with the largest timestamp `<= t` (the last one in file order on
+/// ties); with `tolerance`, only right rows with `t - tolerance <= ts` qualify.
+/// Unmatched left rows keep nulls in the right-hand columns. Left rows keep
+/// their order, the key column appears once, and right columns whose names
+/// clash with a left column get a `_right` suffix.
+pub fn asof_join_timeseries(
+    left_path: &str,
+    right_path: &str,
+    on: &str,
+    tolerance: Option<i64>,
+    output_path: &str,
+    partitions: &[String],
+) -> Result<()> {
+    if tolerance.is_some_and(|tol| tol < 0) {
+        bail!("as-of tolerance must be non-negative");
+    }
+    let left_df = read_frame(left_path)?;
+    let right_df = read_frame(right_path)?;
+    let left_ts = left_df
+        .column(on)?
+        .cast(&DataType::Int64)
+        .context("casting left timestamp column to Int64")?;
+    let right_ts = right_df
+        .column(on)?
+        .cast(&DataType::Int64)
+        .context("casting right timestamp column to Int64")?;
+
+    let mut right_sorted: Vec<(i64, IdxSize)> = right_ts
+        .i64()?
+        .into_iter()
+        .enumerate()
+        .filter_map(|(row, ts)| ts.map(|ts| (ts, row as IdxSize)))
+        .collect();
+    right_sorted.sort_by_key(|&(ts, _)| ts);
+
+    let matches: IdxCa = left_ts
+        .i64()?
+        .into_iter()
+        .map(|ts| {
+            let ts = ts?;
+            let after = right_sorted.partition_point(|&(right_ts, _)| right_ts <= ts);
+            let &(right_ts, row) = right_sorted.get(after.checked_sub(1)?)?;
+            match tolerance {
+                Some(tol) if ts - right_ts > tol => None,
+                _ => Some(row),
+            }
+        })
+        .collect();
+
+    let right_values = right_df.drop(on)?.take(&matches)?;
+    let mut right_columns = Vec::with_capacity(right_values.width());
+    for series in right_values.get_columns() {
+        let mut series = series.clone();
+        if left_df.get_column_names().contains(&series.name()) {
+            let renamed = format!("{}_right", series.name());
+            series.rename(&renamed);
+        }
+        right_columns.push(series);
+    }
+    let mut joined = left_df
+        .hstack(&right_columns)
+        .context("joining time series as-of")?;
+    write_frame_staged(&mut joined, output_path, "ts-asof", partitions)?;
+    Ok(())
+}
+
+/// Aggregate `value_column` per `group_col`.
+///
+/// `agg` is one of sum, mean, min, max, count, or wmean. `wmean` is the
+/// weighted mean `Σ(value·weight) / Σweight` and requires `weight_column`;
+/// groups whose total weight is zero get a null result and a warning.
+///
+/// By default the whole input goes through one Polars `group_by`. With
+/// `chunk_rows`, Parquet input is read in batches and each group's values
+/// are folded across them the way the Polars group-by kernels fold them: in
+/// file order, into the same dtypes. Both modes then write the same values
+/// bit for bit, in an unspecified group order. (Polars may split in-memory
+/// inputs of 1000 rows or more across threads, which regroups float sums.)
+#[allow(clippy::too_many_arguments)]
 pub fn aggregate_timeseries(
     input_path: &str,
     group_col: &str,
     value_column: &str,
     agg: &str,
+    weight_column: Option<&str>,
     output_path: &str,
     partitions: &[String],
+    chunk_rows: Option<usize>,
 ) -> Result<()> {
-    let df = read_frame(input_path)?;
+    if weight_column.is_some() && agg != "wmean" {
+        bail!("a weight column only applies to the wmean aggregation");
+    }
     let (suffix, expr) = match agg {
         "sum" => ("_sum", col(value_column).sum()),
         "mean" => ("_mean", col(value_column).mean()),
         "min" => ("_min", col(value_column).min()),
         "max" => ("_max", col(value_column).max()),
         "count" => ("_count", col(value_column).count()),
+        "wmean" => {
+            let weight_column =
+                weight_column.ok_or_else(|| anyhow!("wmean requires a weight column"))?;
+            ("_wmean", weighted_mean(value_column, weight_column))
+        }
         other => {
             return Err(anyhow!(
-                "unsupported aggregation '{}'; use sum, mean, min, max, or count",
+                "unsupported aggregation '{}'; use sum, mean, min, max, count, or wmean",
                 other
             ));
         }
     };
 
-    let mut agg_df = df
-        .lazy()
-        .group_by([col(group_col)])
-        .agg([expr])
-        .collect()
-        .context("running groupby aggregation")?;
+    let mut agg_df = match chunk_rows {
+        None => read_frame(input_path)?
+            .lazy()
+            .group_by([col(group_col)])
+            .agg([expr])
+            .collect()
+            .context("running groupby aggregation")?,
+        Some(_) => {
+            let mut columns = vec![group_col, value_column];
+            columns.extend(weight_column);
+            let mut groups = GroupedFolds::new(agg);
+            for_each_chunk(input_path, &columns, chunk_rows, |df| {
+                groups.accumulate(df, group_col, value_column, weight_column)
+            })?;
+            groups.finish(group_col, value_column)?
+        }
+    };
+
+    if agg == "wmean" {
+        let zero_weight = agg_df.column(value_column)?.null_count();
+        if zero_weight > 0 {
+            eprintln!(
+                "warning: {} group(s) have zero total weight; their weighted mean is null",
+                zero_weight
+            );
+        }
+    }
 
     let alias_name = format!("{value_column}{suffix}");
     agg_df
         .rename(value_column, alias_name.as_str())
         .context("renaming aggregated column")?;
+    write_frame_staged(&mut agg_df, output_path, "ts-agg", partitions)?;
+    Ok(())
+}
+
+/// `Σ(value·weight) / Σweight` over rows with a value, null when the weights
+/// sum to zero.
+fn weighted_mean(value_column: &str, weight_column: &str) -> Expr {
+    let value = col(value_column).cast(DataType::Float64);
+    let weight = col(weight_column).cast(DataType::Float64);
+    let total_weight = weight.clone().filter(value.clone().is_not_null()).sum();
+    when(total_weight.clone().eq(lit(0.0)))
+        .then(lit(NULL).cast(DataType::Float64))
+        .otherwise((value * weight).sum() / total_weight)
+        .alias(value_column)
+}
+
+/// Per-group state for chunked aggregation, keyed by the typed group value.
+struct GroupedFolds {
+    agg: String,
+    index: HashMap<AnyValue<'static>, usize>,
+    /// One-row slice of the group column per group, in first-seen order
+    keys: Vec<Series>,
+    /// Rows per group, nulls included, as `count` reports them
+    rows: Vec<IdxSize>,
+    /// Value folds for sum, mean, min, and max, typed on the first batch
+    values: Option<Box<dyn ValueFolds>>,
+    /// `Σ(value·weight)` and `Σweight` over rows with a value, for wmean
+    weighted: Vec<(Fold<f64>, Fold<f64>)>,
+}
+
+impl GroupedFolds {
+    fn new(agg: &str) -> Self {
+        GroupedFolds {
+            agg: agg.to_string(),
+            index: HashMap::new(),
+            keys: Vec::new(),
+            rows: Vec::new(),
+            values: None,
+            weighted: Vec::new(),
+        }
+    }
+
+    fn accumulate(
+        &mut self,
+        df: &DataFrame,
+        group_col: &str,
+        value_column: &str,
+        weight_column: Option<&str>,
+    ) -> Result<()> {
+        let group = df.column(group_col)?;
+        let mut slots = Vec::with_capacity(df.height());
+        for row in 0..df.height() {
+            let key = group.get(row)?.into_static()?;
+            let next = self.keys.len();
+            let slot = *self.index.entry(key).or_insert(next);
+            if slot == next {
+                self.keys.push(group.slice(row as i64, 1));
+                self.rows.push(0);
+            }
+            self.rows[slot] += 1;
+            slots.push(slot);
+        }
 
-    let mut out = agg_df.clone();
-    write_frame_staged(&mut out, output_path, "ts-agg", partitions)?;
+        let values = df.column(value_column)?;
+        match (self.agg.as_str(), weight_column) {
+            ("count", _) => {}
+            ("wmean", Some(weight_column)) => {
+                let values = values
+                    .cast(&DataType::Float64)
+                    .context("casting value column to Float64")?;
+                let weights = df
+                    .column(weight_column)?
+                    .cast(&DataType::Float64)
+                    .context("casting weight column to Float64")?;
+                let empty = (Fold::new(0.0), Fold::new(0.0));
+                self.weighted.resize(self.keys.len(), empty);
+                for ((&slot, value), weight) in slots.iter().zip(values.f64()?).zip(weights.f64()?)
+                {
+                    let (product, total) = &mut self.weighted[slot];
+                    product.push(value.zip(weight).map(|(v, w)| v * w), |a, b| a + b);
+                    if value.is_some() {
+                        total.push(weight, |a, b| a + b);
+                    }
+                }
+            }
+            _ => {
+                if self.values.is_none() {
+                    self.values = Some(value_folds(&self.agg, values.dtype())?);
+                }
+                if let Some(folds) = self.values.as_mut() {
+                    folds.push(&slots, self.keys.len(), values)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Group keys and the aggregated values, with the same columns and
+    /// dtypes as the in-memory `group_by`.
+    fn finish(&self, group_col: &str, value_column: &str) -> Result<DataFrame> {
+        let values = match (self.agg.as_str(), &self.values) {
+            ("count", _) => IdxCa::from_vec(value_column, self.rows.clone()).into_series(),
+            ("wmean", _) => Series::new(
+                value_column,
+                self.weighted
+                    .iter()
+                    .map(|(product, total)| {
+                        let total = total.result().unwrap_or(0.0);
+                        (total != 0.0).then(|| product.result().unwrap_or(0.0) / total)
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            (_, Some(folds)) => folds.finish(value_column),
+            (_, None) => Series::new_empty(value_column, &DataType::Float64),
+        };
+        Ok(DataFrame::new(vec![self.keys(group_col)?, values])?)
+    }
+
+    fn keys(&self, group_col: &str) -> Result<Series> {
+        let mut keys = match self.keys.first() {
+            Some(first) => first.clone(),
+            None => return Ok(Series::new_empty(group_col, &DataType::Utf8)),
+        };
+        for key in &self.keys[1..] {
+            keys.append(key)?;
+        }
+        Ok(keys)
+    }
+}
+
+/// Values of one group combined in row order, as the Polars group-by
+/// kernels combine them: from `init`, skipping nulls, with a single-row
+/// group keeping its own value.
+#[derive(Clone, Copy)]
+struct Fold<N> {
+    rows: IdxSize,
+    valid: IdxSize,
+    first: Option<N>,
+    acc: N,
+}
+
+impl<N: Copy> Fold<N> {
+    fn new(init: N) -> Self {
+        Fold {
+            rows: 0,
+            valid: 0,
+            first: None,
+            acc: init,
+        }
+    }
+
+    fn push(&mut self, value: Option<N>, combine: impl Fn(N, N) -> N) {
+        if self.rows == 0 {
+            self.first = value;
+        }
+        self.rows += 1;
+        if let Some(value) = value {
+            self.valid += 1;
+            self.acc = combine(self.acc, value);
+        }
+    }
+
+    /// Combined value, or `None` when no row had one
+    fn result(&self) -> Option<N> {
+        match self.rows {
+            1 => self.first,
+            _ => (self.valid > 0).then_some(self.acc),
+        }
+    }
+}
+
+/// Typed per-group folds of the value column.
+trait ValueFolds {
+    /// Fold `values`, whose row `i` belongs to group `slots[i]` of `groups`
+    fn push(&mut self, slots: &[usize], groups: usize, values: &Series) -> Result<()>;
+
+    fn finish(&self, name: &str) -> Series;
+}
+
+/// Folds for `agg` over `dtype` values, in the dtype Polars aggregates in:
+/// small integers sum as Int64, and means other than Float32 as Float64.
+fn value_folds(agg: &str, dtype: &DataType) -> Result<Box<dyn ValueFolds>> {
+    let native = matches!(
+        dtype,
+        DataType::Int32
+            | DataType::Int64
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+    );
+    let fold_dtype = match agg {
+        "mean" if *dtype != DataType::Float32 => DataType::Float64,
+        "sum" if dtype.is_integer() && !native => DataType::Int64,
+        _ => dtype.clone(),
+    };
+    Ok(match fold_dtype {
+        DataType::Int32 => Box::new(NativeFolds::<Int32Type>::new(agg)),
+        DataType::Int64 => Box::new(NativeFolds::<Int64Type>::new(agg)),
+        DataType::UInt32 => Box::new(NativeFolds::<UInt32Type>::new(agg)),
+        DataType::UInt64 => Box::new(NativeFolds::<UInt64Type>::new(agg)),
+        DataType::Float32 => Box::new(NativeFolds::<Float32Type>::new(agg)),
+        DataType::Float64 => Box::new(NativeFolds::<Float64Type>::new(agg)),
+        other => bail!("chunked {} does not support {} values", agg, other),
+    })
+}
+
+struct NativeFolds<T: PolarsNumericType> {
+    agg: String,
+    init: T::Native,
+    combine: fn(T::Native, T::Native) -> T::Native,
+    folds: Vec<Fold<T::Native>>,
+}
+
+impl<T> NativeFolds<T>
+where
+    T: PolarsNumericType,
+    T::Native: TakeExtremum,
+{
+    fn new(agg: &str) -> Self {
+        let (init, combine): (T::Native, fn(T::Native, T::Native) -> T::Native) = match agg {
+            "min" => (<T::Native as Bounded>::max_value(), |a, b| a.take_min(b)),
+            "max" => (<T::Native as Bounded>::min_value(), |a, b| a.take_max(b)),
+            _ => (<T::Native as Zero>::zero(), |a, b| a + b),
+        };
+        NativeFolds {
+            agg: agg.to_string(),
+            init,
+            combine,
+            folds: Vec::new(),
+        }
+    }
+}
+
+impl<T> ValueFolds for NativeFolds<T>
+where
+    T: PolarsNumericType,
+    T::Native: TakeExtremum,
+    ChunkedArray<T>: IntoSeries,
+{
+    fn push(&mut self, slots: &[usize], groups: usize, values: &Series) -> Result<()> {
+        let values = values
+            .cast(&T::get_dtype())
+            .context("casting value column")?;
+        self.folds.resize(groups, Fold::new(self.init));
+        for (&slot, value) in slots.iter().zip(values.unpack::<T>()?) {
+            self.folds[slot].push(value, self.combine);
+        }
+        Ok(())
+    }
+
+    fn finish(&self, name: &str) -> Series {
+        let values = self.folds.iter().map(|fold| match self.agg.as_str() {
+            "sum" => Some(fold.result().unwrap_or(<T::Native as Zero>::zero())),
+            // Sum in the fold dtype, divided in f64 and cast back
+            "mean" => match fold.rows {
+                1 => fold.first.and_then(|value| value.to_f64()),
+                _ if fold.valid > 0 => fold.acc.to_f64().map(|sum| sum / fold.valid as f64),
+                _ => None,
+            }
+            .and_then(<T::Native as NumCast>::from),
+            _ => fold.result(),
+        });
+        ChunkedArray::<T>::from_iter_options(name, values).into_series()
+    }
+}
+
+/// Call `f` on the input, either whole or in Parquet batches of about
+/// `chunk_rows` rows (only `columns` are read in chunked mode).
+fn for_each_chunk(
+    path: &str,
+    columns: &[&str],
+    chunk_rows: Option<usize>,
+    mut f: impl FnMut(&DataFrame) -> Result<()>,
+) -> Result<()> {
+    let Some(chunk_rows) = chunk_rows else {
+        return f(&read_frame(path)?);
+    };
+    if chunk_rows == 0 {
+        bail!("chunk size must be at least one row");
+    }
+    read_parquet_chunks(path, columns, chunk_rows, &mut f)
+}
+
+#[cfg(feature = "parquet")]
+fn read_parquet_chunks(
+    path: &str,
+    columns: &[&str],
+    chunk_rows: usize,
+    f: &mut dyn FnMut(&DataFrame) -> Result<()>,
+) -> Result<()> {
+    let path = Path::new(path);
+    let is_parquet = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("parquet"));
+    if !is_parquet {
+        bail!("chunked reads require a .parquet input");
+    }
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    let mut reader = ParquetReader::new(file)
+        .with_columns(Some(columns.iter().map(|c| c.to_string()).collect()))
+        .batched(chunk_rows)
+        .context("opening batched Parquet reader")?;
+    while let Some(batches) = reader.next_batches(1).context("reading Parquet batch")? {
+        for batch in &batches {
+            f(batch)?;
+        }
+    }
     Ok(())
 }
 
+#[cfg(not(feature = "parquet"))]
+fn read_parquet_chunks(
+    _path: &str,
+    _columns: &[&str],
+    _chunk_rows: usize,
+    _f: &mut dyn FnMut(&DataFrame) -> Result<()>,
+) -> Result<()> {
+    bail!("chunked reads require parquet support; rebuild with the 'parquet' feature")
+}
+
 fn read_frame(path: &str) -> Result<DataFrame> {
     let path = Path::new(path);
     let extension = path
@@ -355,13 +829,16 @@ mod tests {
         write_frame_staged(&mut df_clone, left.to_str().unwrap(), "ts-test", &[]).unwrap();
         let mut df_clone2 = df.clone();
         write_frame_staged(&mut df_clone2, right.to_str().unwrap(), "ts-test", &[]).unwrap();
-        let partitions = vec!["sensor".to_string()];
+        // Both inputs carry `sensor`, so the join suffixes it
+        let partitions = vec!["sensor_left".to_string()];
         let left_stage = staged_path(&left, "ts-test");
         let right_stage = staged_path(&right, "ts-test");
         join_timeseries(
             left_stage.to_str().unwrap(),
             right_stage.to_str().unwrap(),
             "timestamp",
+            "_left",
+            "_right",
             out.to_str().unwrap(),
             &partitions,
         )
@@ -382,8 +859,10 @@ mod tests {
             "sensor",
             "value",
             "sum",
+            None,
             out.to_str().unwrap(),
             &partitions,
+            None,
         )
         .unwrap();
         let staged = staged_path(&out, "ts-agg");
@@ -408,6 +887,7 @@ mod tests {
             "5s",
             output.to_str().unwrap(),
             &[],
+            None,
         )
         .unwrap();
         let staged = staged_path(&output, "ts-resample");
@@ -441,6 +921,8 @@ mod tests {
             left_path.to_str().unwrap(),
             right_path.to_str().unwrap(),
             "timestamp",
+            "_left",
+            "_right",
             output.to_str().unwrap(),
             &[],
         )
@@ -463,12 +945,342 @@ mod tests {
             "sensor",
             "value",
             "sum",
+            None,
             output.to_str().unwrap(),
             &[],
+            None,
         )
         .unwrap();
         let staged = staged_path(&output, "ts-agg");
         let result = read_frame(staged.to_str().unwrap()).unwrap();
         assert_eq!(result.height(), 2);
     }
+
+    #[test]
+    fn aggregate_timeseries_weighted_mean() {
+        let dir = tempdir().unwrap();
+        let input = dir.path().join("lmp.csv");
+        fs::write(
+            &input,
+            "zone,lmp,capacity\nA,10,3\nA,20,1\nB,30,0\nB,40,0\n",
+        )
+        .unwrap();
+        let output = dir.path().join("wmean.parquet");
+        aggregate_timeseries(
+            input.to_str().unwrap(),
+            "zone",
+            "lmp",
+            "wmean",
+            Some("capacity"),
+            output.to_str().unwrap(),
+            &[],
+            None,
+        )
+        .unwrap();
+        let staged = staged_path(&output, "ts-agg");
+        let result = read_frame(staged.to_str().unwrap())
+            .unwrap()
+            .sort(["zone"], false, false)
+            .unwrap();
+        let wmean = result.column("lmp_wmean").unwrap().f64().unwrap();
+        // (10·3 + 20·1) / 4 = 12.5, pulled toward the heavier value; plain mean is 15
+        assert!((wmean.get(0).unwrap() - 12.5).abs() < 1e-12);
+        assert_eq!(wmean.get(1), None, "zero total weight gives null");
+    }
+
+    #[test]
+    fn aggregate_timeseries_wmean_requires_weight() {
+        let dir = tempdir().unwrap();
+        let input = dir.path().join("lmp.csv");
+        fs::write(&input, "zone,lmp\nA,10\n").unwrap();
+        let output = dir.path().join("wmean.parquet");
+        let err = aggregate_timeseries(
+            input.to_str().unwrap(),
+            "zone",
+            "lmp",
+            "wmean",
+            None,
+            output.to_str().unwrap(),
+            &[],
+            None,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("weight"));
+    }
+
+    #[test]
+    fn asof_join_picks_latest_prior_value() {
+        let mut fast = df![
+            "timestamp" => &[0i64, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+            "value" => &[0.0f64, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0],
+        ]
+        .unwrap();
+        // 5 s cadence starting at 2 s; first rows have nothing prior
+        let mut slow = df![
+            "timestamp" => &[2i64, 7],
+            "value" => &[100.0f64, 200.0],
+        ]
+        .unwrap();
+        let dir = tempdir().unwrap();
+        let left = dir.path().join("fast.parquet");
+        let right = dir.path().join("slow.parquet");
+        write_parquet(&mut fast, &left).unwrap();
+        write_parquet(&mut slow, &right).unwrap();
+
+        let output = dir.path().join("asof.parquet");
+        asof_join_timeseries(
+            left.to_str().unwrap(),
+            right.to_str().unwrap(),
+            "timestamp",
+            None,
+            output.to_str().unwrap(),
+            &[],
+        )
+        .unwrap();
+        let result = read_frame(staged_path(&output, "ts-asof").to_str().unwrap()).unwrap();
+        assert_eq!(result.height(), 12);
+        let joined: Vec<Option<f64>> = result
+            .column("value_right")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect();
+        let mut expected = vec![None, None];
+        expected.extend([Some(100.0); 5]);
+        expected.extend([Some(200.0); 5]);
+        assert_eq!(joined, expected);
+
+        // With a 3 s tolerance, right samples older than 3 s are dropped
+        let output = dir.path().join("asof_tol.parquet");
+        asof_join_timeseries(
+            left.to_str().unwrap(),
+            right.to_str().unwrap(),
+            "timestamp",
+            Some(3),
+            output.to_str().unwrap(),
+            &[],
+        )
+        .unwrap();
+        let result = read_frame(staged_path(&output, "ts-asof").to_str().unwrap()).unwrap();
+        let joined = result.column("value_right").unwrap().f64().unwrap();
+        assert_eq!(joined.get(5), Some(100.0));
+        assert_eq!(joined.get(6), None);
+        assert_eq!(joined.get(7), Some(200.0));
+        assert_eq!(joined.get(11), None);
+    }
+
+    #[test]
+    fn chunked_reads_match_in_memory() {
+        // Below the 1000 rows at which Polars partitions in-memory aggregations
+        let n = 900;
+        let timestamps: Vec<i64> = (0..n).collect();
+        let values: Vec<f64> = (0..n)
+            .map(|i| (i as f64 * 0.37).sin() * 1e3 + 0.1)
+            .collect();
+        let weights: Vec<f64> = (0..n).map(|i| (i % 7) as f64 * 0.3).collect();
+        let sensors: Vec<&str> = (0..n).map(|i| ["A", "B", "C"][i as usize % 3]).collect();
+        let mut df = df![
+            "timestamp" => timestamps,
+            "value" => values,
+            "weight" => weights,
+            "sensor" => sensors,
+        ]
+        .unwrap();
+        let dir = tempdir().unwrap();
+        let input = dir.path().join("large.parquet");
+        let mut file = File::create(&input).unwrap();
+        ParquetWriter::new(&mut file)
+            .with_row_group_size(Some(128))
+            .finish(&mut df)
+            .unwrap();
+
+        let read_staged = |out: &Path, stage: &str| {
+            read_frame(staged_path(out, stage).to_str().unwrap()).unwrap()
+        };
+        for chunk_rows in [None, Some(100)] {
+            let tag = chunk_rows.map_or("full".to_string(), |rows| rows.to_string());
+            let out = dir.path().join(format!("resampled_{tag}.parquet"));
+            resample_timeseries(
+                input.to_str().unwrap(),
+                "timestamp",
+                "value",
+                "60s",
+                out.to_str().unwrap(),
+                &[],
+                chunk_rows,
+            )
+            .unwrap();
+            for agg in ["sum", "mean", "min", "max", "count", "wmean"] {
+                let out = dir.path().join(format!("agg_{agg}_{tag}.parquet"));
+                aggregate_timeseries(
+                    input.to_str().unwrap(),
+                    "sensor",
+                    "value",
+                    agg,
+                    (agg == "wmean").then_some("weight"),
+                    out.to_str().unwrap(),
+                    &[],
+                    chunk_rows,
+                )
+                .unwrap();
+            }
+        }
+
+        let resampled = read_staged(&dir.path().join("resampled_full.parquet"), "ts-resample");
+        let streamed = read_staged(&dir.path().join("resampled_100.parquet"), "ts-resample");
+        assert_eq!(resampled.height(), 15);
+        assert!(resampled.frame_equal_missing(&streamed));
+        for agg in ["sum", "mean", "min", "max", "count", "wmean"] {
+            let full = read_staged(
+                &dir.path().join(format!("agg_{agg}_full.parquet")),
+                "ts-agg",
+            );
+            let streamed =
+                read_staged(&dir.path().join(format!("agg_{agg}_100.parquet")), "ts-agg");
+            assert_eq!(full.height(), 3);
+            assert_eq!(full.dtypes(), streamed.dtypes());
+            let sorted = |df: DataFrame| df.sort(["sensor"], false, false).unwrap();
+            assert!(
+                sorted(full).frame_equal_missing(&sorted(streamed)),
+                "{agg} differs"
+            );
+        }
+    }
+
+    #[test]
+    fn chunked_aggregate_keeps_typed_keys() {
+        let mut df = df![
+            "feeder" => &[Some(7i64), None, Some(7), Some(12), None, Some(12)],
+            "value" => &[1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0],
+        ]
+        .unwrap();
+        let dir = tempdir().unwrap();
+        let input = dir.path().join("feeders.parquet");
+        write_parquet(&mut df, &input).unwrap();
+
+        let mut results = Vec::new();
+        for chunk_rows in [None, Some(2)] {
+            let tag = chunk_rows.map_or("full".to_string(), |rows| rows.to_string());
+            let out = dir.path().join(format!("feeders_{tag}.parquet"));
+            aggregate_timeseries(
+                input.to_str().unwrap(),
+                "feeder",
+                "value",
+                "sum",
+                None,
+                out.to_str().unwrap(),
+                &[],
+                chunk_rows,
+            )
+            .unwrap();
+            let result = read_frame(staged_path(&out, "ts-agg").to_str().unwrap()).unwrap();
+            results.push(result.sort(["feeder"], false, false).unwrap());
+        }
+
+        for result in &results {
+            let feeders: Vec<Option<i64>> = result
+                .column("feeder")
+                .unwrap()
+                .i64()
+                .unwrap()
+                .into_iter()
+                .collect();
+            assert_eq!(feeders, vec![None, Some(7), Some(12)]);
+            let sums: Vec<f64> = result
+                .column("value_sum")
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_no_null_iter()
+                .collect();
+            assert_eq!(sums, vec![7.0, 4.0, 10.0]);
+        }
+    }
+
+    #[test]
+    fn chunked_reads_require_parquet() {
+        let dir = tempdir().unwrap();
+        let input = dir.path().join("agg.csv");
+        fs::write(&input, "sensor,value\nA,1\n").unwrap();
+        let out = dir.path().join("agg.parquet");
+        let err = aggregate_timeseries(
+            input.to_str().unwrap(),
+            "sensor",
+            "value",
+            "sum",
+            None,
+            out.to_str().unwrap(),
+            &[],
+            Some(10),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("parquet"));
+    }
+
+    #[test]
+    fn join_suffixes_colliding_columns() {
+        let mut left = df![
+            "timestamp" => &[1i64, 2],
+            "value" => &[10.0, 20.0],
+            "quality" => &[1i64, 1],
+        ]
+        .unwrap();
+        let mut right = df![
+            "timestamp" => &[2i64, 3],
+            "value" => &[25.0, 35.0],
+        ]
+        .unwrap();
+        let dir = tempdir().unwrap();
+        let left_path = dir.path().join("left.parquet");
+        let right_path = dir.path().join("right.parquet");
+        write_parquet(&mut left, &left_path).unwrap();
+        write_parquet(&mut right, &right_path).unwrap();
+        let output = dir.path().join("joined.parquet");
+        join_timeseries(
+            left_path.to_str().unwrap(),
+            right_path.to_str().unwrap(),
+            "timestamp",
+            "_left",
+            "_right",
+            output.to_str().unwrap(),
+            &[],
+        )
+        .unwrap();
+        let result = read_frame(staged_path(&output, "ts-join").to_str().unwrap())
+            .unwrap()
+            .sort(["timestamp"], false, false)
+            .unwrap();
+
+        let mut names = result.get_column_names();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            vec!["quality", "timestamp", "value_left", "value_right"]
+        );
+        let timestamps: Vec<Option<i64>> = result
+            .column("timestamp")
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(timestamps, vec![Some(1), Some(2), Some(3)]);
+        let left_values: Vec<Option<f64>> = result
+            .column("value_left")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect();
+        let right_values: Vec<Option<f64>> = result
+            .column("value_right")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(left_values, vec![Some(10.0), Some(20.0), None]);
+        assert_eq!(right_values, vec![None, Some(25.0), Some(35.0)]);
+    }
 }
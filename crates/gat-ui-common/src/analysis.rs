@@ -448,12 +448,18 @@ impl AnalysisService {
 
         // Convert to our result format
         let pg: Vec<f64> = result.generator_p.values().copied().collect();
+        let qg: Vec<f64> = result
+            .generator_p
+            .keys()
+            .map(|name| result.generator_q.get(name).copied().unwrap_or(0.0))
+            .collect();
         let angles: Vec<f64> = result.bus_voltage_ang.values().copied().collect();
         let branch_flows: Vec<f64> = result.branch_p_flow.values().copied().collect();
         let lmps: Vec<f64> = result.bus_lmp.values().copied().collect();
 
         let opf_result = DcOpfResult {
             pg,
+            qg,
             angles,
             branch_flows,
             lmps,
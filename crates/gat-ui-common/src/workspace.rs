@@ -92,6 +92,8 @@ pub struct AcPfResult {
 pub struct DcOpfResult {
     /// Generator real power dispatch in per-unit.
     pub pg: Vec<f64>,
+    /// Generator reactive power in per-unit (zero or estimated, not optimized).
+    pub qg: Vec<f64>,
     /// Bus voltage angles in radians.
     pub angles: Vec<f64>,
     /// Branch real power flows in per-unit.
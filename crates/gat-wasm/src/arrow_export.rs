@@ -7,26 +7,54 @@
 //! - Apache Arrow JS (`@apache-arrow/ts`)
 //! - DuckDB-WASM (for SQL queries on results)
 //! - Arquero (for DataFrame transforms)
+//!
+//! Table layouts come from the OPF result schemas in `gat_io::arrow_schema`,
+//! which the desktop exporter (`gat_io::opf_solution_to_arrow`) also uses, so
+//! browser and desktop results share identical columns.
 
 use arrow::array::{ArrayRef, Float64Array, StringArray};
 use arrow::datatypes::{DataType, Field, Schema};
 use arrow::ipc::writer::StreamWriter;
 use arrow::record_batch::RecordBatch;
+use gat_algo::opf::SolverWarning;
+use gat_io::arrow_schema as shared;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Rebuild one of gat-io's shared schemas with this crate's Arrow version.
+fn shared_schema(schema: shared::Schema) -> Schema {
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            Field::new(
+                field.name(),
+                local_type(field.data_type()),
+                field.is_nullable(),
+            )
+        })
+        .collect();
+    Schema::new(fields)
+}
+
+fn local_type(data_type: &shared::DataType) -> DataType {
+    match data_type {
+        shared::DataType::Utf8 => DataType::Utf8,
+        shared::DataType::Float64 => DataType::Float64,
+        shared::DataType::UInt64 => DataType::UInt64,
+        shared::DataType::Boolean => DataType::Boolean,
+        other => unreachable!("OPF result schemas do not use {other}"),
+    }
+}
+
 /// Create an Arrow IPC stream containing generator dispatch results
 ///
-/// Schema: gen_id (string), p_mw (float64), q_mvar (float64)
+/// Schema: gen_id (string), p_mw (float64), q_mvar (float64, zero where absent)
 pub fn generators_to_arrow(
     generator_p: &HashMap<String, f64>,
     generator_q: &HashMap<String, f64>,
 ) -> Result<Vec<u8>, String> {
-    let schema = Schema::new(vec![
-        Field::new("gen_id", DataType::Utf8, false),
-        Field::new("p_mw", DataType::Float64, false),
-        Field::new("q_mvar", DataType::Float64, true),
-    ]);
+    let schema = shared_schema(shared::opf_generators_schema());
 
     // Collect in consistent order
     let mut gen_ids: Vec<&String> = generator_p.keys().collect();
@@ -39,7 +67,7 @@ pub fn generators_to_arrow(
         .collect();
     let q_array: Float64Array = gen_ids
         .iter()
-        .map(|id| generator_q.get(*id).copied())
+        .map(|id| generator_q.get(*id).copied().unwrap_or(0.0))
         .collect();
 
     let batch = RecordBatch::try_new(
@@ -75,12 +103,7 @@ pub fn buses_to_arrow(
     bus_voltage_ang: &HashMap<String, f64>,
     bus_lmp: &HashMap<String, f64>,
 ) -> Result<Vec<u8>, String> {
-    let schema = Schema::new(vec![
-        Field::new("bus_id", DataType::Utf8, false),
-        Field::new("v_mag", DataType::Float64, true),
-        Field::new("v_ang_deg", DataType::Float64, true),
-        Field::new("lmp", DataType::Float64, true),
-    ]);
+    let schema = shared_schema(shared::opf_buses_schema());
 
     // Use all unique bus IDs
     let mut bus_ids: Vec<&String> = bus_voltage_mag
@@ -135,11 +158,7 @@ pub fn branches_to_arrow(
     branch_p_flow: &HashMap<String, f64>,
     branch_q_flow: &HashMap<String, f64>,
 ) -> Result<Vec<u8>, String> {
-    let schema = Schema::new(vec![
-        Field::new("branch_id", DataType::Utf8, false),
-        Field::new("p_flow_mw", DataType::Float64, false),
-        Field::new("q_flow_mvar", DataType::Float64, true),
-    ]);
+    let schema = shared_schema(shared::opf_branches_schema());
 
     let mut branch_ids: Vec<&String> = branch_p_flow.keys().collect();
     branch_ids.sort();
@@ -192,6 +211,8 @@ pub struct OpfArrowResult {
 }
 
 /// Summary data included as JSON alongside Arrow tables
+///
+/// Keys match the columns of `gat_io::arrow_schema::opf_summary_schema`.
 #[derive(serde::Serialize)]
 pub struct OpfSummary {
     pub converged: bool,
@@ -201,6 +222,10 @@ pub struct OpfSummary {
     pub total_generation_mw: f64,
     pub total_load_mw: f64,
     pub total_losses_mw: f64,
+    /// True when generator Q was estimated rather than optimized (DC, economic)
+    pub reactive_estimated: bool,
+    /// Solver warnings, e.g. an iteration cap or a voltage at its limit
+    pub warnings: Vec<SolverWarning>,
 }
 
 #[cfg(test)]
@@ -240,4 +265,25 @@ mod tests {
         let bytes = buses_to_arrow(&v_mag, &v_ang, &lmp).unwrap();
         assert!(!bytes.is_empty());
     }
+
+    #[test]
+    fn test_layout_matches_desktop_schema() {
+        let schema = shared_schema(shared::opf_summary_schema());
+        let summary = serde_json::to_value(OpfSummary {
+            converged: true,
+            objective_value: 0.0,
+            solve_time_ms: 0,
+            method: "DcOpf".to_string(),
+            total_generation_mw: 0.0,
+            total_load_mw: 0.0,
+            total_losses_mw: 0.0,
+            reactive_estimated: true,
+            warnings: Vec::new(),
+        })
+        .unwrap();
+        for field in schema.fields() {
+            assert!(summary.get(field.name()).is_some(), "{}", field.name());
+        }
+        assert_eq!(summary.as_object().unwrap().len(), schema.fields().len());
+    }
 }
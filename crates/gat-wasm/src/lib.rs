@@ -10,10 +10,12 @@ mod arrow_export;
 
 use std::collections::HashMap;
 
+use gat_algo::opf::SolverWarning;
 use gat_algo::{OpfMethod, OpfSolver};
 use gat_core::{
-    Branch, BranchId, Bus, BusId, CostModel, Edge, Gen, GenId, Kilovolts, Load, LoadId, Megavars,
-    MegavoltAmperes, Megawatts, Network, Node, NodeIndex, PerUnit, Radians, Shunt, ShuntId,
+    Branch, BranchId, Bus, BusId, BusType, CostModel, DispatchMode, Edge, Gen, GenId, Kilovolts,
+    Load, LoadId, Megavars, MegavoltAmperes, Megawatts, Network, Node, NodeIndex, PerUnit, Radians,
+    Shunt, ShuntId,
 };
 use gat_io::wasm_parsers::{parse_matpower_string, MatpowerCase};
 use serde::Serialize;
@@ -80,11 +82,17 @@ pub struct DcOpfResult {
     pub solve_time_ms: u128,
     pub method: String,
     pub generator_dispatch: HashMap<String, f64>,
+    /// Reactive output (MVAr): zero unless a reactive estimate was requested
+    pub generator_reactive: HashMap<String, f64>,
+    /// Always true for DC-OPF: `generator_reactive` is not optimized
+    pub reactive_estimated: bool,
     pub bus_angles_deg: HashMap<String, f64>,
     pub branch_flows_mw: HashMap<String, f64>,
     pub bus_lmp: HashMap<String, f64>,
     pub total_generation_mw: f64,
     pub total_load_mw: f64,
+    /// Solver warnings, e.g. an iteration cap or a voltage at its limit
+    pub warnings: Vec<SolverWarning>,
 }
 
 /// Run DC optimal power flow on MATPOWER content and return results as JSON
@@ -119,6 +127,8 @@ pub fn run_dc_power_flow(content: &str) -> Result<String, JsValue> {
         solve_time_ms: solution.solve_time_ms,
         method: format!("{:?}", solution.method_used),
         generator_dispatch: solution.generator_p,
+        generator_reactive: solution.generator_q,
+        reactive_estimated: !solution.method_used.reactive_is_exact(),
         bus_angles_deg: solution
             .bus_voltage_ang
             .into_iter()
@@ -128,6 +138,7 @@ pub fn run_dc_power_flow(content: &str) -> Result<String, JsValue> {
         bus_lmp: solution.bus_lmp,
         total_generation_mw: total_gen,
         total_load_mw: total_load,
+        warnings: solution.warnings,
     };
 
     serde_json::to_string(&result).map_err(|e| JsValue::from_str(&e.to_string()))
@@ -155,6 +166,7 @@ fn matpower_to_network(case: &MatpowerCase) -> Result<Network, String> {
             vmax_pu: Some(PerUnit(bus.vmax)),
             area_id: Some(bus.area as i64),
             zone_id: Some(bus.zone as i64),
+            bus_type: BusType::from_code(bus.bus_type as i64),
         }));
         bus_index_map.insert(bus.bus_i, node_idx);
 
@@ -166,6 +178,7 @@ fn matpower_to_network(case: &MatpowerCase) -> Result<Network, String> {
                 bus: BusId::new(bus.bus_i),
                 active_power: Megawatts(bus.pd),
                 reactive_power: Megavars(bus.qd),
+                zip: None,
             }));
         }
 
@@ -224,9 +237,17 @@ fn matpower_to_network(case: &MatpowerCase) -> Result<Network, String> {
             voltage_setpoint: Some(PerUnit(gen.vg)),
             mbase: Some(MegavoltAmperes(gen.mbase)),
             cost_startup: None,
+            startup_costs: None,
             cost_shutdown: None,
             cost_model,
+            reactive_cost: None,
             is_synchronous_condenser: false,
+            p_available: None,
+            emissions_rate: None,
+            dispatch_mode: DispatchMode::Auto,
+            inertia_h: None,
+            xd_subtransient: None,
+            fuel: None,
         }));
     }
 
@@ -334,6 +355,8 @@ pub struct SocpOpfResult {
     pub method: String,
     pub generator_dispatch: HashMap<String, f64>,
     pub generator_reactive: HashMap<String, f64>,
+    /// False: SOCP optimizes reactive dispatch
+    pub reactive_estimated: bool,
     pub bus_voltage_mag: HashMap<String, f64>,
     pub bus_voltage_ang_deg: HashMap<String, f64>,
     pub branch_flows_mw: HashMap<String, f64>,
@@ -342,6 +365,8 @@ pub struct SocpOpfResult {
     pub total_generation_mw: f64,
     pub total_load_mw: f64,
     pub total_losses_mw: f64,
+    /// Solver warnings, e.g. an iteration cap or a voltage at its limit
+    pub warnings: Vec<SolverWarning>,
 }
 
 /// Run SOCP (Second-Order Cone Programming) relaxation OPF on MATPOWER content
@@ -378,6 +403,7 @@ pub fn run_socp_power_flow(content: &str) -> Result<String, JsValue> {
         method: format!("{:?}", solution.method_used),
         generator_dispatch: solution.generator_p,
         generator_reactive: solution.generator_q,
+        reactive_estimated: !solution.method_used.reactive_is_exact(),
         bus_voltage_mag: solution.bus_voltage_mag,
         bus_voltage_ang_deg: solution
             .bus_voltage_ang
@@ -390,6 +416,7 @@ pub fn run_socp_power_flow(content: &str) -> Result<String, JsValue> {
         total_generation_mw: total_gen,
         total_load_mw: total_load,
         total_losses_mw: solution.total_losses_mw,
+        warnings: solution.warnings,
     };
 
     serde_json::to_string(&result).map_err(|e| JsValue::from_str(&e.to_string()))
@@ -403,9 +430,15 @@ pub struct EconomicDispatchResult {
     pub solve_time_ms: u128,
     pub method: String,
     pub generator_dispatch: HashMap<String, f64>,
+    /// Reactive output (MVAr): zero unless reactive dispatch was requested
+    pub generator_reactive: HashMap<String, f64>,
+    /// Always true for economic dispatch: `generator_reactive` is not optimized
+    pub reactive_estimated: bool,
     pub total_generation_mw: f64,
     pub total_load_mw: f64,
     pub estimated_losses_mw: f64,
+    /// Solver warnings, e.g. an iteration cap or a voltage at its limit
+    pub warnings: Vec<SolverWarning>,
 }
 
 /// Run merit-order economic dispatch on MATPOWER content
@@ -441,9 +474,12 @@ pub fn run_economic_dispatch(content: &str) -> Result<String, JsValue> {
         solve_time_ms: solution.solve_time_ms,
         method: format!("{:?}", solution.method_used),
         generator_dispatch: solution.generator_p,
+        generator_reactive: solution.generator_q,
+        reactive_estimated: !solution.method_used.reactive_is_exact(),
         total_generation_mw: total_gen,
         total_load_mw: total_load,
         estimated_losses_mw: solution.total_losses_mw,
+        warnings: solution.warnings,
     };
 
     serde_json::to_string(&result).map_err(|e| JsValue::from_str(&e.to_string()))
@@ -766,6 +802,8 @@ pub fn run_dc_opf_arrow(content: &str) -> Result<OpfArrowTables, JsValue> {
         total_generation_mw: total_gen,
         total_load_mw: total_load,
         total_losses_mw: solution.total_losses_mw,
+        reactive_estimated: !solution.method_used.reactive_is_exact(),
+        warnings: solution.warnings,
     };
     let summary_json =
         serde_json::to_string(&summary).map_err(|e| JsValue::from_str(&e.to_string()))?;
@@ -827,6 +865,8 @@ pub fn run_socp_opf_arrow(content: &str) -> Result<OpfArrowTables, JsValue> {
         total_generation_mw: total_gen,
         total_load_mw: total_load,
         total_losses_mw: solution.total_losses_mw,
+        reactive_estimated: !solution.method_used.reactive_is_exact(),
+        warnings: solution.warnings,
     };
     let summary_json =
         serde_json::to_string(&summary).map_err(|e| JsValue::from_str(&e.to_string()))?;
@@ -896,6 +936,8 @@ pub fn run_socp_opf_fast_arrow(content: &str) -> Result<OpfArrowTables, JsValue>
         total_generation_mw: total_gen,
         total_load_mw: total_load,
         total_losses_mw: solution.total_losses_mw,
+        reactive_estimated: !solution.method_used.reactive_is_exact(),
+        warnings: solution.warnings,
     };
     let summary_json =
         serde_json::to_string(&summary).map_err(|e| JsValue::from_str(&e.to_string()))?;